@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nitroterm::commands::release_notes::clean_tag_name;
+
+fuzz_target!(|tag: &str| {
+    let cleaned = clean_tag_name(tag);
+    assert!(!cleaned.is_empty());
+    assert!(!cleaned.contains(['/', ':', ' ']));
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nitroterm::commands::release_notes::compare_version_tags;
+use nitroterm::utils::version_check::compare_versions;
+
+// Input is two versions separated by a newline
+fuzz_target!(|input: &str| {
+    let (a, b) = input.split_once('\n').unwrap_or((input, ""));
+    let _ = compare_versions(a, b);
+
+    assert_eq!(
+        compare_version_tags(a, b),
+        compare_version_tags(b, a).reverse()
+    );
+    let mut tags: Vec<&str> = input.lines().collect();
+    tags.sort_by(|a, b| compare_version_tags(a, b));
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nitroterm::commands::release_notes::{parse_git_url, RepositoryInfo};
+
+fuzz_target!(|url: &str| {
+    let mut info = RepositoryInfo::default();
+    parse_git_url(&mut info, url);
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nitroterm::commands::translation_sync::{
+    extract_all_paths, get_nested_value, set_nested_value,
+};
+use serde_json::{json, Value};
+
+// Any JSON document, including keys with dots and deep nesting: copying every
+// extracted path into another catalog must not panic
+fuzz_target!(|data: &[u8]| {
+    let Ok(source) = serde_json::from_slice::<Value>(data) else {
+        return;
+    };
+
+    let mut target = json!({ "stale": "value" });
+    for path in extract_all_paths(&source, "") {
+        let value = get_nested_value(&source, &path)
+            .cloned()
+            .unwrap_or(Value::Null);
+        set_nested_value(&mut target, &path, value).unwrap();
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nitroterm::commands::translation_sync::parse_translation_response;
+
+// First line lists the requested paths (comma separated), the rest is the
+// model response
+fuzz_target!(|input: &str| {
+    let (header, response) = input.split_once('\n').unwrap_or(("", input));
+    let paths: Vec<String> = header.split(',').map(|p| p.to_string()).collect();
+
+    for (path, _) in parse_translation_response(response, &paths) {
+        assert!(paths.contains(&path));
+    }
+});
@@ -0,0 +1,511 @@
+use crate::commands::org::Severity;
+use crate::utils::error::ChecksFailed;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Native audit tools, picked by the project files present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditTool {
+    Cargo,
+    Npm,
+    Pip,
+    Composer,
+}
+
+impl AuditTool {
+    pub fn all() -> [AuditTool; 4] {
+        [
+            AuditTool::Cargo,
+            AuditTool::Npm,
+            AuditTool::Pip,
+            AuditTool::Composer,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuditTool::Cargo => "cargo audit",
+            AuditTool::Npm => "npm audit",
+            AuditTool::Pip => "pip-audit",
+            AuditTool::Composer => "composer audit",
+        }
+    }
+
+    pub fn ecosystem(&self) -> &'static str {
+        match self {
+            AuditTool::Cargo => "crates.io",
+            AuditTool::Npm => "npm",
+            AuditTool::Pip => "PyPI",
+            AuditTool::Composer => "Packagist",
+        }
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        let any = |files: &[&str]| files.iter().any(|file| root.join(file).exists());
+        match self {
+            AuditTool::Cargo => any(&["Cargo.lock", "Cargo.toml"]),
+            // npm audit needs a lockfile
+            AuditTool::Npm => any(&["package-lock.json", "npm-shrinkwrap.json"]),
+            AuditTool::Pip => any(&["requirements.txt", "pyproject.toml"]),
+            AuditTool::Composer => any(&["composer.lock"]),
+        }
+    }
+
+    fn command(&self, root: &Path) -> (&'static str, Vec<&'static str>) {
+        match self {
+            AuditTool::Cargo => ("cargo", vec!["audit", "--json"]),
+            AuditTool::Npm => ("npm", vec!["audit", "--json"]),
+            AuditTool::Pip if root.join("requirements.txt").exists() => (
+                "pip-audit",
+                vec!["-r", "requirements.txt", "--format", "json"],
+            ),
+            AuditTool::Pip => ("pip-audit", vec!["--format", "json", "."]),
+            AuditTool::Composer => ("composer", vec!["audit", "--format=json", "--locked"]),
+        }
+    }
+
+    pub fn parse(&self, output: &str) -> Result<Vec<Vulnerability>> {
+        let json: Value = serde_json::from_str(output)
+            .map_err(|e| anyhow!("Unexpected {} output: {}", self.name(), e))?;
+        Ok(match self {
+            AuditTool::Cargo => parse_cargo_audit(&json),
+            AuditTool::Npm => parse_npm_audit(&json),
+            AuditTool::Pip => parse_pip_audit(&json),
+            AuditTool::Composer => parse_composer_audit(&json),
+        })
+    }
+}
+
+pub fn detect_tools(root: &Path) -> Vec<AuditTool> {
+    AuditTool::all()
+        .into_iter()
+        .filter(|tool| tool.detect(root))
+        .collect()
+}
+
+/// One advisory affecting one package, whichever tool reported it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Vulnerability {
+    pub ecosystem: &'static str,
+    pub package: String,
+    pub version: Option<String>,
+    pub advisory: String,
+    pub title: String,
+    pub severity: Severity,
+    pub fixed: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedTool {
+    pub tool: &'static str,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditReport {
+    pub audited: Vec<&'static str>,
+    pub skipped: Vec<SkippedTool>,
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+impl AuditReport {
+    pub fn at_or_above(&self, severity: Severity) -> usize {
+        self.vulnerabilities
+            .iter()
+            .filter(|v| v.severity >= severity)
+            .count()
+    }
+}
+
+/// CVSS v3 base score from a vector like `CVSS:3.1/AV:N/AC:L/...`.
+pub fn cvss_base_score(vector: &str) -> Option<f64> {
+    if !vector.starts_with("CVSS:3") {
+        return None;
+    }
+    let metric = |name: &str| {
+        vector
+            .split('/')
+            .find_map(|part| part.strip_prefix(name)?.strip_prefix(':'))
+    };
+    let changed = metric("S")? == "C";
+    let impact_weight = |value: &str| match value {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    };
+
+    let av = match metric("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match metric("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let pr = match (metric("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match metric("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let iss: f64 = 1.0
+        - (1.0 - impact_weight(metric("C")?)?)
+            * (1.0 - impact_weight(metric("I")?)?)
+            * (1.0 - impact_weight(metric("A")?)?);
+
+    let impact = if changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability: f64 = 8.22 * av * ac * pr * ui;
+    let score = if changed {
+        (1.08 * (impact + exploitability)).min(10.0)
+    } else {
+        (impact + exploitability).min(10.0)
+    };
+    // CVSS rounds up to one decimal
+    Some((score * 10.0 - 1e-9).ceil() / 10.0)
+}
+
+pub fn severity_from_score(score: f64) -> Severity {
+    match score {
+        s if s >= 9.0 => Severity::Critical,
+        s if s >= 7.0 => Severity::High,
+        s if s >= 4.0 => Severity::Moderate,
+        s if s > 0.0 => Severity::Low,
+        _ => Severity::Unknown,
+    }
+}
+
+fn string(value: &Value) -> Option<String> {
+    value.as_str().map(|s| s.to_string())
+}
+
+/// `cargo audit --json`; severity comes from the advisory's CVSS vector.
+fn parse_cargo_audit(json: &Value) -> Vec<Vulnerability> {
+    json["vulnerabilities"]["list"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|entry| {
+            let advisory = &entry["advisory"];
+            let patched: Vec<String> = entry["versions"]["patched"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(string)
+                .collect();
+            Vulnerability {
+                ecosystem: AuditTool::Cargo.ecosystem(),
+                package: string(&entry["package"]["name"])
+                    .or_else(|| string(&advisory["package"]))
+                    .unwrap_or_default(),
+                version: string(&entry["package"]["version"]),
+                advisory: string(&advisory["id"]).unwrap_or_default(),
+                title: string(&advisory["title"]).unwrap_or_default(),
+                severity: advisory["cvss"]
+                    .as_str()
+                    .and_then(cvss_base_score)
+                    .map(severity_from_score)
+                    .unwrap_or(Severity::Unknown),
+                fixed: (!patched.is_empty()).then(|| patched.join(", ")),
+            }
+        })
+        .collect()
+}
+
+/// `npm audit --json` from npm 7 on. Only `via` entries that are advisories
+/// are reported; plain names point at the vulnerable dependency, which has
+/// its own entry.
+fn parse_npm_audit(json: &Value) -> Vec<Vulnerability> {
+    let mut vulnerabilities = Vec::new();
+    let mut seen = HashSet::new();
+    let Some(packages) = json["vulnerabilities"].as_object() else {
+        return vulnerabilities;
+    };
+
+    for (name, entry) in packages {
+        let fixed = match &entry["fixAvailable"] {
+            Value::Object(fix) => {
+                let fix_name = fix.get("name").and_then(Value::as_str).unwrap_or(name);
+                let version = fix.get("version").and_then(Value::as_str).unwrap_or("");
+                if fix_name == name {
+                    Some(version.to_string())
+                } else {
+                    Some(format!("{}@{}", fix_name, version))
+                }
+            }
+            _ => None,
+        };
+
+        for via in entry["via"].as_array().into_iter().flatten() {
+            if !via.is_object() {
+                continue;
+            }
+            let advisory = via["url"]
+                .as_str()
+                .and_then(|url| url.rsplit('/').next())
+                .map(|id| id.to_string())
+                .or_else(|| via["source"].as_u64().map(|id| id.to_string()))
+                .unwrap_or_default();
+            if !seen.insert((name.clone(), advisory.clone())) {
+                continue;
+            }
+            vulnerabilities.push(Vulnerability {
+                ecosystem: AuditTool::Npm.ecosystem(),
+                package: name.clone(),
+                version: string(&via["range"]),
+                advisory,
+                title: string(&via["title"]).unwrap_or_default(),
+                severity: via["severity"]
+                    .as_str()
+                    .or(entry["severity"].as_str())
+                    .and_then(|severity| severity.parse().ok())
+                    .unwrap_or(Severity::Unknown),
+                fixed: fixed.clone(),
+            });
+        }
+    }
+    vulnerabilities
+}
+
+/// `pip-audit --format json`, both the current object and the older
+/// top-level array. pip-audit doesn't report severities.
+fn parse_pip_audit(json: &Value) -> Vec<Vulnerability> {
+    let dependencies = json["dependencies"].as_array().or(json.as_array());
+    dependencies
+        .into_iter()
+        .flatten()
+        .flat_map(|dependency| {
+            dependency["vulns"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |vuln| {
+                    let fixed: Vec<String> = vuln["fix_versions"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(string)
+                        .collect();
+                    Vulnerability {
+                        ecosystem: AuditTool::Pip.ecosystem(),
+                        package: string(&dependency["name"]).unwrap_or_default(),
+                        version: string(&dependency["version"]),
+                        advisory: string(&vuln["id"]).unwrap_or_default(),
+                        title: vuln["description"]
+                            .as_str()
+                            .and_then(|d| d.lines().next())
+                            .unwrap_or_default()
+                            .to_string(),
+                        severity: Severity::Unknown,
+                        fixed: (!fixed.is_empty()).then(|| fixed.join(", ")),
+                    }
+                })
+        })
+        .collect()
+}
+
+/// `composer audit --format=json`. `advisories` is an empty array when
+/// nothing was found and a package map otherwise.
+fn parse_composer_audit(json: &Value) -> Vec<Vulnerability> {
+    let Some(packages) = json["advisories"].as_object() else {
+        return Vec::new();
+    };
+    packages
+        .iter()
+        .flat_map(|(name, advisories)| {
+            advisories
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |advisory| Vulnerability {
+                    ecosystem: AuditTool::Composer.ecosystem(),
+                    package: name.clone(),
+                    version: string(&advisory["affectedVersions"]),
+                    advisory: string(&advisory["cve"])
+                        .or_else(|| string(&advisory["advisoryId"]))
+                        .unwrap_or_default(),
+                    title: string(&advisory["title"]).unwrap_or_default(),
+                    severity: advisory["severity"]
+                        .as_str()
+                        .and_then(|severity| severity.parse().ok())
+                        .unwrap_or(Severity::Unknown),
+                    fixed: None,
+                })
+        })
+        .collect()
+}
+
+/// Runs every detected audit tool. Tools that are missing or fail are
+/// skipped with a reason, a non-zero exit alone just means findings.
+pub fn run_audit_tools(root: &Path, runner: &dyn CommandRunner) -> AuditReport {
+    let mut report = AuditReport::default();
+    for tool in detect_tools(root) {
+        let (program, args) = tool.command(root);
+        let result = runner.run(program, &args, Some(root)).and_then(|output| {
+            if output.stdout.trim().is_empty() {
+                let stderr = output.stderr.trim();
+                return Err(anyhow!(if stderr.is_empty() {
+                    "no output".to_string()
+                } else {
+                    stderr.lines().next().unwrap_or_default().to_string()
+                }));
+            }
+            tool.parse(&output.stdout)
+        });
+
+        match result {
+            Ok(vulnerabilities) => {
+                report.audited.push(tool.name());
+                report.vulnerabilities.extend(vulnerabilities);
+            }
+            Err(e) => report.skipped.push(SkippedTool {
+                tool: tool.name(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    report.vulnerabilities.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| a.ecosystem.cmp(b.ecosystem))
+            .then_with(|| a.package.cmp(&b.package))
+    });
+    report
+}
+
+fn severity_label(severity: Severity) -> ColoredString {
+    let label = format!("{:<8}", severity.name().to_uppercase());
+    match severity {
+        Severity::Critical => label.red().bold(),
+        Severity::High => label.red(),
+        Severity::Moderate => label.yellow(),
+        Severity::Low => label.normal(),
+        Severity::Unknown => label.dimmed(),
+    }
+}
+
+fn print_audit_report(report: &AuditReport) {
+    println!("\n{}", "🔒 Security audit".cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+
+    for skipped in &report.skipped {
+        log_warning(&format!("Skipped {}: {}", skipped.tool, skipped.reason));
+    }
+    if report.audited.is_empty() {
+        return;
+    }
+    log_info(&format!("Audited with {}", report.audited.join(", ")));
+
+    if report.vulnerabilities.is_empty() {
+        log_success("No known vulnerabilities found");
+        return;
+    }
+
+    for vulnerability in &report.vulnerabilities {
+        println!(
+            "\n  {} {} {} {}",
+            severity_label(vulnerability.severity),
+            vulnerability.package.bold(),
+            vulnerability
+                .version
+                .as_deref()
+                .map(|v| format!("({})", v))
+                .unwrap_or_default()
+                .dimmed(),
+            format!("[{}]", vulnerability.ecosystem).dimmed()
+        );
+        println!(
+            "           {} {}",
+            vulnerability.advisory.cyan(),
+            vulnerability.title
+        );
+        match &vulnerability.fixed {
+            Some(fixed) => println!("           {} {}", "Fixed in:".dimmed(), fixed.green()),
+            None => println!("           {}", "No fixed version".dimmed()),
+        }
+    }
+
+    println!(
+        "\n{}",
+        format!(
+            "Found {} vulnerabilit(ies): {} critical, {} high, {} moderate, {} low",
+            report.vulnerabilities.len(),
+            count(report, Severity::Critical),
+            count(report, Severity::High),
+            count(report, Severity::Moderate),
+            count(report, Severity::Low),
+        )
+        .yellow()
+    );
+}
+
+fn count(report: &AuditReport, severity: Severity) -> usize {
+    report
+        .vulnerabilities
+        .iter()
+        .filter(|v| v.severity == severity)
+        .count()
+}
+
+/// `nitroterm audit`: one vulnerability report from cargo audit, npm audit,
+/// pip-audit and composer audit. `fail_on` turns findings at or above that
+/// severity into a failing exit for CI.
+pub fn run_audit(path: &Path, fail_on: Option<&str>, json: bool) -> Result<()> {
+    ensure_not_sandboxed("audit")?;
+    let fail_on: Option<Severity> = fail_on.map(str::parse).transpose()?;
+    if detect_tools(path).is_empty() {
+        return Err(anyhow!(
+            "No Cargo, npm, Python or Composer project found in {}",
+            path.display()
+        ));
+    }
+
+    let report = run_audit_tools(path, &SystemRunner);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_audit_report(&report);
+    }
+
+    if report.audited.is_empty() {
+        return Err(anyhow!("None of the audit tools could run"));
+    }
+    if let Some(severity) = fail_on {
+        let failing = report.at_or_above(severity);
+        if failing > 0 {
+            return Err(ChecksFailed(format!(
+                "{} vulnerabilit(ies) at or above {} severity",
+                failing,
+                severity.name()
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
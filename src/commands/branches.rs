@@ -0,0 +1,240 @@
+use crate::commands::config::load_saved_config;
+use crate::utils::git::{current_branch, default_branch, list_branches, BranchInfo};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::prompt::{self, ChecklistItem};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+
+/// Branches never offered for deletion unless the config says otherwise.
+pub const DEFAULT_PROTECTED_BRANCHES: [&str; 4] = ["main", "master", "develop", "release/*"];
+
+#[derive(Debug, Clone)]
+pub struct CleanupOptions {
+    pub stale_days: i64,
+    pub dry_run: bool,
+    pub local_only: bool,
+}
+
+/// Matches a branch name against a pattern where `*` stands for any run
+/// of characters, so `release/*` covers `release/1.2`.
+pub fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == name;
+    }
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !name.starts_with(first)
+        || !name.ends_with(last)
+        || name.len() < first.len() + last.len()
+    {
+        return false;
+    }
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+pub fn is_protected(branch: &BranchInfo, patterns: &[String]) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| matches_pattern(pattern, branch.short_name()))
+}
+
+/// Merged or stale branches that are neither protected nor checked out.
+pub fn cleanup_candidates(
+    branches: Vec<BranchInfo>,
+    now: i64,
+    stale_days: i64,
+    protected: &[String],
+    current: Option<&str>,
+) -> Vec<BranchInfo> {
+    branches
+        .into_iter()
+        .filter(|branch| branch.is_stale(now, stale_days))
+        .filter(|branch| !is_protected(branch, protected))
+        .filter(|branch| branch.remote || Some(branch.name.as_str()) != current)
+        .collect()
+}
+
+/// Arguments for `git` that delete the branch. Local branches are removed
+/// with `-D`: whether they are merged was checked against the default
+/// branch, which `-d` would compare with HEAD instead.
+pub fn delete_args(branch: &BranchInfo) -> Vec<String> {
+    if branch.remote {
+        let remote = branch
+            .name
+            .split_once('/')
+            .map_or("origin", |(remote, _)| remote);
+        vec![
+            "push".to_string(),
+            remote.to_string(),
+            "--delete".to_string(),
+            branch.short_name().to_string(),
+        ]
+    } else {
+        vec!["branch".to_string(), "-D".to_string(), branch.name.clone()]
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CleanupResult {
+    pub deleted: Vec<String>,
+    pub failed: Vec<(String, String)>,
+}
+
+pub fn delete_branches(runner: &dyn CommandRunner, branches: &[&BranchInfo]) -> CleanupResult {
+    let mut result = CleanupResult::default();
+    for branch in branches {
+        let args = delete_args(branch);
+        let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+        match runner
+            .run("git", &args, None)
+            .and_then(|output| output.check("git"))
+        {
+            Ok(_) => result.deleted.push(branch.name.clone()),
+            Err(e) => result.failed.push((branch.name.clone(), e.to_string())),
+        }
+    }
+    result
+}
+
+fn describe(branch: &BranchInfo, default: &str, now: i64) -> String {
+    if branch.merged {
+        format!("merged into {}", default)
+    } else {
+        format!("{} days without commits", branch.age_days(now))
+    }
+}
+
+/// Items for the selection, merged branches first and preselected. Stale
+/// branches with unmerged work have to be picked by hand.
+pub fn checklist_items(
+    candidates: &[BranchInfo],
+    default: &str,
+    stale_days: i64,
+    now: i64,
+) -> (Vec<ChecklistItem>, Vec<usize>) {
+    let order: Vec<usize> = (0..candidates.len())
+        .filter(|&index| candidates[index].merged)
+        .chain((0..candidates.len()).filter(|&index| !candidates[index].merged))
+        .collect();
+    let items = order
+        .iter()
+        .map(|&index| {
+            let branch = &candidates[index];
+            let group = if branch.merged {
+                format!("Merged into {}", default)
+            } else {
+                format!("No commits in {} days", stale_days)
+            };
+            let kind = if branch.remote { "remote" } else { "local" };
+            ChecklistItem {
+                group,
+                label: format!(
+                    "{} ({}, {})",
+                    branch.name,
+                    kind,
+                    describe(branch, default, now)
+                ),
+                checked: branch.merged,
+            }
+        })
+        .collect();
+    (items, order)
+}
+
+/// Lists merged and stale branches and deletes the ones picked.
+pub async fn run_branch_cleanup(options: CleanupOptions) -> Result<()> {
+    let repo = Repository::discover(".").map_err(|_| anyhow!("Not a git repository"))?;
+    let default = default_branch(&repo).ok_or_else(|| anyhow!("No default branch found"))?;
+    let mut protected = load_saved_config().await.protected_branches;
+    protected.push(default.clone());
+
+    let now = chrono::Utc::now().timestamp();
+    let mut branches = list_branches(&repo, &default)?;
+    if options.local_only {
+        branches.retain(|branch| !branch.remote);
+    }
+    let total = branches.len();
+    let current = current_branch(&repo);
+    let candidates = cleanup_candidates(
+        branches,
+        now,
+        options.stale_days,
+        &protected,
+        current.as_deref(),
+    );
+
+    println!("{}", "🌿 Branch cleanup".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+    println!(
+        "{}",
+        format!(
+            "{} of {} branches merged into {} or without commits in {} days",
+            candidates.len(),
+            total,
+            default,
+            options.stale_days
+        )
+        .dimmed()
+    );
+    if candidates.is_empty() {
+        println!("{}", "✅ Nothing to clean up".green());
+        return Ok(());
+    }
+
+    if options.dry_run {
+        for branch in &candidates {
+            println!(
+                "  {} {}",
+                branch.name.yellow(),
+                format!("({})", describe(branch, &default, now)).dimmed()
+            );
+        }
+        println!("{}", "🔍 Dry run, no branches deleted".cyan());
+        return Ok(());
+    }
+
+    let (items, order) = checklist_items(&candidates, &default, options.stale_days, now);
+    let selected: Vec<&BranchInfo> = prompt::checklist("Select the branches to delete", &items)?
+        .into_iter()
+        .map(|index| &candidates[order[index]])
+        .collect();
+    if selected.is_empty() {
+        println!("{}", "No branches selected".yellow());
+        return Ok(());
+    }
+    ensure_not_sandboxed("Deleting branches")?;
+    if !prompt::confirm(format!("Delete {} branch(es)? (y/N): ", selected.len()).cyan())? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    let result = delete_branches(&SystemRunner, &selected);
+    for name in &result.deleted {
+        println!("  {} {}", "🗑️ ".green(), name);
+    }
+    for (name, error) in &result.failed {
+        println!("  {} {}: {}", "❌".red(), name, error.red());
+    }
+    if result.failed.is_empty() {
+        println!(
+            "{}",
+            format!("✅ Deleted {} branch(es)", result.deleted.len()).green()
+        );
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} of {} branch(es) could not be deleted",
+            result.failed.len(),
+            selected.len()
+        ))
+    }
+}
@@ -0,0 +1,631 @@
+use crate::commands::dependency_update::update_rules;
+use crate::commands::lockfiles::RegistryClient;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::progress::{ProgressBar, Spinner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use semver::{Op, Version, VersionReq};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+/// Which workspace members `update-dependencies` looks at, cargo style:
+/// `-p` picks members, `--workspace` all of them, neither the root package
+/// (or every member of a virtual workspace).
+#[derive(Debug, Clone, Default)]
+pub struct CargoUpdateOptions {
+    pub packages: Vec<String>,
+    pub workspace: bool,
+    /// Rewrite version requirements in Cargo.toml, to the newest release
+    /// they allow unless `incompatible` is set
+    pub save: bool,
+    /// With `save`, also move requirements to new major versions
+    pub incompatible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CargoDependency {
+    pub name: String,
+    /// Key in Cargo.toml, differs from `name` for renamed dependencies
+    pub key: String,
+    pub req: String,
+    /// `normal`, `dev` or `build`
+    pub kind: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceMember {
+    pub name: String,
+    pub manifest_path: PathBuf,
+    /// crates.io dependencies only, path and git dependencies are skipped
+    pub dependencies: Vec<CargoDependency>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CargoWorkspace {
+    pub root: PathBuf,
+    pub members: Vec<WorkspaceMember>,
+    /// Package in the root Cargo.toml, None for a virtual workspace
+    pub root_package: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutdatedRequirement {
+    pub name: String,
+    pub key: String,
+    pub req: String,
+    pub latest: Version,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CrateReport {
+    pub member: String,
+    pub manifest_path: PathBuf,
+    pub outdated: Vec<OutdatedRequirement>,
+}
+
+/// Reads `cargo metadata --no-deps` output.
+pub fn parse_cargo_metadata(json: &str) -> Result<CargoWorkspace> {
+    let metadata: Value =
+        serde_json::from_str(json).map_err(|e| anyhow!("Invalid cargo metadata: {}", e))?;
+    let root = PathBuf::from(
+        metadata["workspace_root"]
+            .as_str()
+            .ok_or_else(|| anyhow!("No workspace_root in cargo metadata"))?,
+    );
+    let root_manifest = root.join("Cargo.toml");
+
+    let mut members = Vec::new();
+    let mut root_package = None;
+    for package in metadata["packages"].as_array().into_iter().flatten() {
+        let name = package["name"].as_str().unwrap_or_default().to_string();
+        let manifest_path = PathBuf::from(package["manifest_path"].as_str().unwrap_or_default());
+        if manifest_path == root_manifest {
+            root_package = Some(name.clone());
+        }
+
+        let dependencies = package["dependencies"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|dependency| {
+                let source = dependency["source"].as_str().unwrap_or_default();
+                // Alternative registries have their own index
+                dependency["registry"].is_null()
+                    && (source.starts_with("registry+") || source.starts_with("sparse+"))
+            })
+            .map(|dependency| {
+                let name = dependency["name"].as_str().unwrap_or_default().to_string();
+                CargoDependency {
+                    key: dependency["rename"].as_str().unwrap_or(&name).to_string(),
+                    name,
+                    req: dependency["req"].as_str().unwrap_or("*").to_string(),
+                    kind: dependency["kind"].as_str().unwrap_or("normal").to_string(),
+                }
+            })
+            .collect();
+
+        members.push(WorkspaceMember {
+            name,
+            manifest_path,
+            dependencies,
+        });
+    }
+
+    Ok(CargoWorkspace {
+        root,
+        members,
+        root_package,
+    })
+}
+
+pub fn load_workspace(dir: &Path, runner: &dyn CommandRunner) -> Result<CargoWorkspace> {
+    let output = runner
+        .run(
+            "cargo",
+            &["metadata", "--format-version", "1", "--no-deps"],
+            Some(dir),
+        )?
+        .check("cargo metadata")?;
+    parse_cargo_metadata(&output.stdout)
+}
+
+impl CargoWorkspace {
+    pub fn select_members(&self, packages: &[String], all: bool) -> Result<Vec<&WorkspaceMember>> {
+        if all || (packages.is_empty() && self.root_package.is_none()) {
+            return Ok(self.members.iter().collect());
+        }
+
+        let wanted: Vec<String> = if packages.is_empty() {
+            self.root_package.iter().cloned().collect()
+        } else {
+            packages.to_vec()
+        };
+        wanted
+            .iter()
+            .map(|name| {
+                self.members
+                    .iter()
+                    .find(|member| &member.name == name)
+                    .ok_or_else(|| {
+                        let names: Vec<&str> =
+                            self.members.iter().map(|m| m.name.as_str()).collect();
+                        anyhow!(
+                            "Package `{}` is not a workspace member (members: {})",
+                            name,
+                            names.join(", ")
+                        )
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Highest stable version, or the highest pre-release if nothing is stable.
+pub fn latest_stable(versions: &[Version]) -> Option<Version> {
+    versions
+        .iter()
+        .filter(|version| version.pre.is_empty())
+        .max()
+        .or_else(|| versions.iter().max())
+        .cloned()
+}
+
+/// Lowest version a requirement accepts, from its first comparator.
+fn requirement_floor(req: &VersionReq) -> Option<Version> {
+    let comparator = req.comparators.first()?;
+    Some(Version::new(
+        comparator.major,
+        comparator.minor.unwrap_or(0),
+        comparator.patch.unwrap_or(0),
+    ))
+}
+
+/// A requirement is outdated when it excludes a newer release, i.e.
+/// `cargo update` alone can't reach it.
+pub fn is_requirement_outdated(req: &str, latest: &Version) -> bool {
+    match VersionReq::parse(req) {
+        Ok(req) => {
+            !req.matches(latest) && requirement_floor(&req).is_some_and(|floor| latest > &floor)
+        }
+        Err(_) => false,
+    }
+}
+
+/// `1.0` → `2.3`, `=0.4.1` → `=0.5.0`, `~1` → `~2`: same operator and
+/// precision, new version. Multi-comparator requirements are left alone.
+pub fn bumped_requirement(old: &str, latest: &Version) -> Option<String> {
+    let parsed = VersionReq::parse(old).ok()?;
+    if parsed.comparators.len() != 1 {
+        return None;
+    }
+    let operator = match parsed.comparators[0].op {
+        Op::Caret | Op::Tilde | Op::Exact => {
+            let trimmed = old.trim();
+            let version_start = trimmed
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(trimmed.len());
+            trimmed[..version_start].trim().to_string()
+        }
+        _ => return None,
+    };
+
+    let precision = old.trim().matches('.').count() + 1;
+    let version = match precision {
+        1 => latest.major.to_string(),
+        2 => format!("{}.{}", latest.major, latest.minor),
+        _ => latest.to_string(),
+    };
+    Some(format!("{}{}", operator, version))
+}
+
+/// Newest stable release `req` allows. Pre-releases only count when the
+/// requirement asks for one, as in cargo.
+pub fn latest_compatible(req: &str, versions: &[Version]) -> Option<Version> {
+    let req = VersionReq::parse(req).ok()?;
+    versions
+        .iter()
+        .filter(|version| req.matches(version))
+        .max()
+        .cloned()
+}
+
+/// The release `update-dependencies --save` moves `req` to: the newest one
+/// it allows, or with `incompatible` the latest one when the requirement
+/// excludes it. None when the rewritten requirement would read the same,
+/// e.g. `1` is already as high as it goes within `1.x`.
+pub fn upgrade_target(req: &str, versions: &[Version], incompatible: bool) -> Option<Version> {
+    let target = latest_stable(versions)
+        .filter(|latest| incompatible && is_requirement_outdated(req, latest))
+        .or_else(|| latest_compatible(req, versions))?;
+    let bumped = VersionReq::parse(&bumped_requirement(req, &target)?).ok()?;
+    (bumped != VersionReq::parse(req).ok()?).then_some(target)
+}
+
+/// Looks up the releases of every dependency once, yanked ones left out.
+pub async fn fetch_versions(
+    registry: &RegistryClient,
+    names: &[String],
+) -> HashMap<String, Vec<Version>> {
+    let mut found = HashMap::new();
+    let mut progress = ProgressBar::new("📦 crates.io", names.len());
+    for name in names {
+        progress.tick(name);
+        match registry.index_versions(name).await {
+            Ok(versions) => {
+                found.insert(name.clone(), versions);
+            }
+            Err(e) => {
+                progress.clear();
+                log_warning(&format!("Could not check {}: {}", name, e));
+            }
+        }
+        progress.inc(1);
+    }
+    if !names.is_empty() {
+        progress.finish();
+    }
+    found
+}
+
+/// The latest release of each dependency, see `latest_stable`.
+pub fn latest_versions(versions: &HashMap<String, Vec<Version>>) -> HashMap<String, Version> {
+    versions
+        .iter()
+        .filter_map(|(name, releases)| Some((name.clone(), latest_stable(releases)?)))
+        .collect()
+}
+
+pub fn build_reports(
+    members: &[&WorkspaceMember],
+    latest: &HashMap<String, Version>,
+) -> Vec<CrateReport> {
+    members
+        .iter()
+        .map(|member| CrateReport {
+            member: member.name.clone(),
+            manifest_path: member.manifest_path.clone(),
+            outdated: member
+                .dependencies
+                .iter()
+                .filter_map(|dependency| {
+                    let newest = latest.get(&dependency.name)?;
+                    is_requirement_outdated(&dependency.req, newest).then(|| OutdatedRequirement {
+                        name: dependency.name.clone(),
+                        key: dependency.key.clone(),
+                        req: dependency.req.clone(),
+                        latest: newest.clone(),
+                        kind: dependency.kind.clone(),
+                    })
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Like `build_reports`, with `latest` set to what `--save` rewrites each
+/// requirement to (see `upgrade_target`).
+pub fn build_upgrade_reports(
+    members: &[&WorkspaceMember],
+    versions: &HashMap<String, Vec<Version>>,
+    incompatible: bool,
+) -> Vec<CrateReport> {
+    members
+        .iter()
+        .map(|member| CrateReport {
+            member: member.name.clone(),
+            manifest_path: member.manifest_path.clone(),
+            outdated: member
+                .dependencies
+                .iter()
+                .filter_map(|dependency| {
+                    let versions = versions.get(&dependency.name)?;
+                    let target = upgrade_target(&dependency.req, versions, incompatible)?;
+                    Some(OutdatedRequirement {
+                        name: dependency.name.clone(),
+                        key: dependency.key.clone(),
+                        req: dependency.req.clone(),
+                        latest: target,
+                        kind: dependency.kind.clone(),
+                    })
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Every dependency table of a manifest: the top-level ones, those of
+/// `[target.'cfg(...)'.dependencies]` and `[workspace.dependencies]`.
+fn dependency_tables(document: &mut DocumentMut) -> Vec<&mut dyn TableLike> {
+    let mut tables = Vec::new();
+    for (key, item) in document.as_table_mut().iter_mut() {
+        match key.get() {
+            "workspace" => tables.extend(
+                item.get_mut("dependencies")
+                    .and_then(|item| item.as_table_like_mut()),
+            ),
+            "target" => {
+                for (_, target) in item
+                    .as_table_like_mut()
+                    .into_iter()
+                    .flat_map(|t| t.iter_mut())
+                {
+                    for (kind, table) in target
+                        .as_table_like_mut()
+                        .into_iter()
+                        .flat_map(|t| t.iter_mut())
+                    {
+                        if DEPENDENCY_TABLES.contains(&kind.get()) {
+                            tables.extend(table.as_table_like_mut());
+                        }
+                    }
+                }
+            }
+            kind if DEPENDENCY_TABLES.contains(&kind) => tables.extend(item.as_table_like_mut()),
+            _ => {}
+        }
+    }
+    tables
+}
+
+/// The version string of a dependency entry, `"1.0"` itself or the
+/// `version` of its table. None for entries inheriting from the workspace.
+fn requirement_value(entry: &mut Item) -> Option<&mut toml_edit::Value> {
+    if entry.is_str() {
+        return entry.as_value_mut();
+    }
+    let table = entry.as_table_like_mut()?;
+    if table.contains_key("workspace") {
+        return None;
+    }
+    table.get_mut("version")?.as_value_mut()
+}
+
+/// Rewrites the requirement of `key` wherever it equals `req`, in inline
+/// (`serde = "1.0"`, `serde = { version = "1.0" }`) and table
+/// (`[dependencies.serde]`) form. Entries using `workspace = true` are left
+/// for the workspace root. Everything else, comments and formatting
+/// included, stays as it was. Returns None when nothing changed.
+pub fn bump_dependency_in_manifest(
+    manifest: &str,
+    key: &str,
+    req: &str,
+    latest: &Version,
+) -> Option<String> {
+    let req = VersionReq::parse(req).ok()?;
+    let mut document: DocumentMut = manifest.parse().ok()?;
+
+    let mut changed = false;
+    for table in dependency_tables(&mut document) {
+        let Some(value) = table.get_mut(key).and_then(requirement_value) else {
+            continue;
+        };
+        let Some(current) = value.as_str() else {
+            continue;
+        };
+        if VersionReq::parse(current).ok().as_ref() != Some(&req) {
+            continue;
+        }
+        let Some(bumped) = bumped_requirement(current, latest) else {
+            continue;
+        };
+        let decor = value.decor().clone();
+        *value = toml_edit::Value::from(bumped);
+        *value.decor_mut() = decor;
+        changed = true;
+    }
+
+    changed.then(|| document.to_string())
+}
+
+/// A Cargo.toml before and after its requirements were rewritten.
+#[derive(Debug, Clone)]
+pub struct ManifestChange {
+    pub path: PathBuf,
+    pub before: String,
+    pub after: String,
+}
+
+impl ManifestChange {
+    /// Line number, old and new text of every changed line. Requirements are
+    /// rewritten in place, so lines keep their numbers.
+    pub fn changed_lines(&self) -> Vec<(usize, &str, &str)> {
+        self.before
+            .lines()
+            .zip(self.after.lines())
+            .enumerate()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(index, (before, after))| (index + 1, before, after))
+            .collect()
+    }
+}
+
+/// What bumping the requirements in `reports` does to each Cargo.toml,
+/// falling back to the workspace root for inherited (`workspace = true`)
+/// dependencies. Nothing is written.
+pub fn plan_requirements(
+    workspace: &CargoWorkspace,
+    reports: &[CrateReport],
+) -> Result<Vec<ManifestChange>> {
+    let root_manifest = workspace.root.join("Cargo.toml");
+    let mut originals: HashMap<PathBuf, String> = HashMap::new();
+    let mut contents: HashMap<PathBuf, String> = HashMap::new();
+    let mut changed = BTreeSet::new();
+
+    for report in reports {
+        for outdated in &report.outdated {
+            let mut bumped = false;
+            for path in [&report.manifest_path, &root_manifest] {
+                if !contents.contains_key(path) {
+                    let manifest = std::fs::read_to_string(path)?;
+                    originals.insert(path.clone(), manifest.clone());
+                    contents.insert(path.clone(), manifest);
+                }
+                let manifest = &contents[path];
+                if let Some(updated) = bump_dependency_in_manifest(
+                    manifest,
+                    &outdated.key,
+                    &outdated.req,
+                    &outdated.latest,
+                ) {
+                    contents.insert(path.clone(), updated);
+                    changed.insert(path.clone());
+                    bumped = true;
+                    break;
+                }
+            }
+            if !bumped {
+                log_warning(&format!(
+                    "{}: could not update `{}` requirement {}",
+                    report.member, outdated.key, outdated.req
+                ));
+            }
+        }
+    }
+
+    Ok(changed
+        .into_iter()
+        .map(|path| ManifestChange {
+            before: originals.remove(&path).unwrap_or_default(),
+            after: contents.remove(&path).unwrap_or_default(),
+            path,
+        })
+        .collect())
+}
+
+/// Writes the planned manifests. Returns the paths written.
+pub fn save_requirements(changes: &[ManifestChange]) -> Result<Vec<PathBuf>> {
+    for change in changes {
+        std::fs::write(&change.path, &change.after)?;
+    }
+    Ok(changes.iter().map(|change| change.path.clone()).collect())
+}
+
+fn print_changes(root: &Path, changes: &[ManifestChange]) {
+    println!("{}", "📝 Changes to Cargo.toml:".cyan().bold());
+    for change in changes {
+        let path = change.path.strip_prefix(root).unwrap_or(&change.path);
+        println!("  {}", path.display().to_string().bold());
+        for (line, before, after) in change.changed_lines() {
+            println!(
+                "  {:>5} {}",
+                line.to_string().dimmed(),
+                format!("- {}", before.trim()).red()
+            );
+            println!(
+                "  {:>5} {}",
+                line.to_string().dimmed(),
+                format!("+ {}", after.trim()).green()
+            );
+        }
+    }
+}
+
+fn print_reports(reports: &[CrateReport]) {
+    println!("{}", "🦀 Outdated requirements per crate:".cyan().bold());
+    for report in reports {
+        if report.outdated.is_empty() {
+            println!("  {} {}", "✅".green(), report.member.bold());
+            continue;
+        }
+        println!("  {} {}", "📦".yellow(), report.member.bold());
+        for outdated in &report.outdated {
+            let kind = if outdated.kind == "normal" {
+                String::new()
+            } else {
+                format!(" ({})", outdated.kind)
+            };
+            println!(
+                "      {:<28} {:>12} → {:<12}{}",
+                outdated.key.green(),
+                outdated.req,
+                outdated.latest.to_string().cyan(),
+                kind.dimmed()
+            );
+        }
+    }
+}
+
+pub async fn run_cargo_update(dir: &Path, options: CargoUpdateOptions) -> Result<()> {
+    if options.save {
+        ensure_not_sandboxed("update-dependencies --save")?;
+    }
+
+    let workspace = load_workspace(dir, &SystemRunner)?;
+    let members = workspace.select_members(&options.packages, options.workspace)?;
+    log_info(&format!(
+        "Checking {} of {} workspace crate(s)...",
+        members.len(),
+        workspace.members.len()
+    ));
+
+    let names: Vec<String> = members
+        .iter()
+        .flat_map(|member| member.dependencies.iter().map(|d| d.name.clone()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let mut versions = fetch_versions(&RegistryClient::new()?, &names).await;
+    let rules = update_rules();
+    for (name, releases) in versions.iter_mut() {
+        releases.retain(|version| rules.allows("rust", name, &version.to_string()));
+    }
+    let reports = build_reports(&members, &latest_versions(&versions));
+    print_reports(&reports);
+    let outdated: usize = reports.iter().map(|report| report.outdated.len()).sum();
+
+    if !options.save {
+        if outdated == 0 {
+            log_success("All version requirements allow the latest releases");
+        } else {
+            log_info(
+                "Run with --save to raise requirements within their major version, \
+                 add --incompatible to move to the latest releases",
+            );
+        }
+        return Ok(());
+    }
+
+    let upgrades = build_upgrade_reports(&members, &versions, options.incompatible);
+    let changes = plan_requirements(&workspace, &upgrades)?;
+    if changes.is_empty() {
+        log_success("No version requirements to rewrite");
+        if outdated > 0 && !options.incompatible {
+            log_info("Add --incompatible to move to new major versions");
+        }
+        return Ok(());
+    }
+    print_changes(&workspace.root, &changes);
+    if !prompt::confirm("Write these changes? (y/N): ".cyan())? {
+        log_info("No manifests changed");
+        return Ok(());
+    }
+    save_requirements(&changes)?;
+
+    let bumped: usize = changes
+        .iter()
+        .map(|change| change.changed_lines().len())
+        .sum();
+    let spinner = Spinner::start("Updating Cargo.lock...");
+    match SystemRunner
+        .run("cargo", &["update"], Some(dir))
+        .and_then(|output| output.check("cargo update"))
+    {
+        Ok(_) => {
+            spinner.finish();
+            log_success(&format!("Bumped {} requirement(s)", bumped));
+            Ok(())
+        }
+        Err(e) => {
+            spinner.fail();
+            Err(e)
+        }
+    }
+}
@@ -1,16 +1,111 @@
+use crate::commands::notifications::{notify, NotifyEvent};
+use crate::commands::quality_history::record_quality_run;
+use crate::commands::report::write_html_report;
+use crate::commands::secret_scan::{run_secrets_check, SECRETS_CHECK};
+use crate::utils::error::ChecksFailed;
+use crate::utils::file_system::{walk_dirs, PathFilter, ScanOptions};
+use crate::utils::output::{is_json_output, print_json};
+use crate::utils::process::{supervise_async, CommandRunner, SystemRunner};
+use crate::utils::progress::ProgressBar;
+use crate::utils::sandbox::{ensure_not_sandboxed, ensure_write_allowed};
+use crate::utils::theme::{label, paint, Role};
 use anyhow::Result;
 use colored::*;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
 use tokio::fs;
+use tokio::process::Command;
+
+/// Upper bound for any configured timeout; larger values are clamped.
+pub const MAX_TIMEOUT_SECONDS: u64 = 4 * 60 * 60;
+/// Upper bound for retries, so a flaky tool can't stall the run forever.
+pub const MAX_RETRIES: u32 = 5;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeQualityConfig {
     pub enabled_checks: Vec<String>,
     pub skip_dependencies: bool,
     pub max_parallel_jobs: usize,
+    /// Fallback timeout for tools and checks without their own policy
     pub timeout_seconds: u64,
+    #[serde(default)]
+    pub report_path: Option<String>,
+    /// Timeout/retry overrides keyed by tool (`pytest`, `eslint`, `gh`) or
+    /// check name (`test`, `lint`). Check names win over tool names.
+    #[serde(default)]
+    pub tool_policies: HashMap<String, ToolPolicy>,
+    /// Extra checks run alongside the generated ones; one with the name of a
+    /// generated check replaces it.
+    #[serde(default)]
+    pub custom_checks: Vec<CustomCheck>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomCheck {
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Relative to the project root, defaults to the root itself
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    /// Overrides `timeout_seconds` and `tool_policies` for this check
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    /// Exit code that counts as a pass
+    #[serde(default)]
+    pub expected_exit_code: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolPolicy {
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    #[serde(default)]
+    pub retries: Option<u32>,
+}
+
+impl ToolPolicy {
+    fn merge(&mut self, other: &ToolPolicy) {
+        if other.timeout_seconds.is_some() {
+            self.timeout_seconds = other.timeout_seconds;
+        }
+        if other.retries.is_some() {
+            self.retries = other.retries;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedPolicy {
+    pub timeout_seconds: u64,
+    pub retries: u32,
+}
+
+/// Built-in limits for tools whose runtime differs a lot from the default.
+pub fn default_tool_policy(tool: &str) -> Option<ToolPolicy> {
+    let (timeout, retries) = match tool {
+        "pytest" | "mvn" | "gradle" | "gradlew" => (1200, 0),
+        "eslint" | "flake8" | "black" | "gofmt" => (120, 0),
+        "gh" => (30, 2),
+        _ => return None,
+    };
+    Some(ToolPolicy {
+        timeout_seconds: Some(timeout),
+        retries: Some(retries),
+    })
+}
+
+/// `/repo/gradlew` -> `gradlew`, `npm.cmd` -> `npm`
+pub fn tool_name(command: &str) -> String {
+    Path::new(command)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| command.to_string())
 }
 
 impl Default for CodeQualityConfig {
@@ -25,10 +120,78 @@ impl Default for CodeQualityConfig {
             skip_dependencies: false,
             max_parallel_jobs: 4,
             timeout_seconds: 300,
+            report_path: None,
+            tool_policies: HashMap::new(),
+            custom_checks: Vec::new(),
         }
     }
 }
 
+impl CodeQualityConfig {
+    /// Resolves the limits for a check: global fallback, then built-in tool
+    /// defaults, then configured tool policy, then configured check policy.
+    pub fn policy_for(&self, check_name: &str, command: &str) -> ResolvedPolicy {
+        let tool = tool_name(command);
+        let mut policy = ToolPolicy {
+            timeout_seconds: Some(self.timeout_seconds),
+            retries: Some(0),
+        };
+
+        if let Some(defaults) = default_tool_policy(&tool) {
+            policy.merge(&defaults);
+        }
+        for key in [tool.as_str(), check_name] {
+            if let Some(configured) = self.tool_policies.get(key) {
+                policy.merge(configured);
+            }
+        }
+
+        let timeout = match policy.timeout_seconds {
+            Some(0) | None => self.timeout_seconds,
+            Some(timeout) => timeout,
+        };
+        ResolvedPolicy {
+            timeout_seconds: timeout.clamp(1, MAX_TIMEOUT_SECONDS),
+            retries: policy.retries.unwrap_or(0).min(MAX_RETRIES),
+        }
+    }
+
+    /// Warnings for configured values that `policy_for` has to correct.
+    pub fn policy_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.timeout_seconds == 0 || self.timeout_seconds > MAX_TIMEOUT_SECONDS {
+            warnings.push(format!(
+                "timeout_seconds = {} is out of range, using 1..={}",
+                self.timeout_seconds, MAX_TIMEOUT_SECONDS
+            ));
+        }
+
+        let mut keys: Vec<_> = self.tool_policies.keys().collect();
+        keys.sort();
+        for key in keys {
+            let policy = &self.tool_policies[key];
+            match policy.timeout_seconds {
+                Some(0) => warnings.push(format!(
+                    "{}: timeout_seconds = 0, using the global {}s",
+                    key, self.timeout_seconds
+                )),
+                Some(timeout) if timeout > MAX_TIMEOUT_SECONDS => warnings.push(format!(
+                    "{}: timeout_seconds = {} exceeds {}, clamped",
+                    key, timeout, MAX_TIMEOUT_SECONDS
+                )),
+                _ => {}
+            }
+            if let Some(retries) = policy.retries.filter(|r| *r > MAX_RETRIES) {
+                warnings.push(format!(
+                    "{}: retries = {} exceeds {}, clamped",
+                    key, retries, MAX_RETRIES
+                ));
+            }
+        }
+        warnings
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
 pub enum ProjectType {
@@ -41,6 +204,8 @@ pub enum ProjectType {
     JavaScript,
     Rust,
     Python,
+    Go,
+    Java,
     Unknown,
 }
 
@@ -52,6 +217,9 @@ pub enum PackageManager {
     Bun,
     Cargo,
     Pip,
+    GoModules,
+    Maven,
+    Gradle,
     Unknown,
 }
 
@@ -73,18 +241,236 @@ pub struct QualityCheck {
     pub args: Vec<String>,
     pub working_dir: PathBuf,
     pub timeout: u64,
+    pub expected_exit_code: i32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct CheckResult {
     pub check_name: String,
+    pub command: String,
     pub success: bool,
     pub output: String,
     pub error: Option<String>,
     pub duration_ms: u128,
 }
 
+/// One located problem pulled out of a check's output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    pub check: String,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub severity: String,
+    pub message: String,
+}
+
+impl Finding {
+    /// Stable, uncolored line that editor problem matchers can rely on:
+    /// `nitroterm: <file>:<line>:<col>: <severity>: <message> [<check>]`
+    pub fn to_line(&self) -> String {
+        format!(
+            "nitroterm: {}:{}:{}: {}: {} [{}]",
+            self.file, self.line, self.column, self.severity, self.message, self.check
+        )
+    }
+}
+
+/// Regex for `Finding::to_line`, shared with the generated editor config.
+pub const FINDING_PATTERN: &str =
+    r"^\s*nitroterm: (.+?):(\d+):(\d+): (error|warning|info): (.*) \[(.+)\]$";
+
+/// Picks up the common diagnostic layouts: rustc/clippy (`error: ...` followed
+/// by `--> file:line:col`), tsc (`file(line,col): error ...`) and the
+/// `file:line:col: message` form used by eslint (unix), flake8, go vet and gcc.
+pub fn extract_findings(result: &CheckResult) -> Vec<Finding> {
+    static PATTERNS: OnceLock<(Regex, Regex, Regex, Regex)> = OnceLock::new();
+    let (rust_header, rust_location, tsc, generic) = PATTERNS.get_or_init(|| {
+        (
+            Regex::new(r"^(error|warning)(\[\w+\])?: (.+)$").unwrap(),
+            Regex::new(r"^\s*--> (.+?):(\d+):(\d+)$").unwrap(),
+            Regex::new(r"^(.+?)\((\d+),(\d+)\): (error|warning) (.+)$").unwrap(),
+            Regex::new(
+                r"^([^\s:][^:]*):(\d+):(\d+):\s*(?:(error|warning|note|info)\s*:?\s*)?(.+)$",
+            )
+            .unwrap(),
+        )
+    });
+
+    let severity = |value: Option<&str>| match value {
+        Some("warning") => "warning".to_string(),
+        Some("note") | Some("info") => "info".to_string(),
+        _ => "error".to_string(),
+    };
+
+    let mut findings = Vec::new();
+    let mut pending: Option<(String, String)> = None;
+    let text = format!(
+        "{}\n{}",
+        result.output,
+        result.error.as_deref().unwrap_or("")
+    );
+
+    for line in text.lines() {
+        let line = line.trim_end();
+        if let Some(caps) = rust_header.captures(line) {
+            pending = Some((severity(Some(&caps[1])), caps[3].to_string()));
+            continue;
+        }
+        if let Some(caps) = rust_location.captures(line) {
+            if let Some((level, message)) = pending.take() {
+                findings.push(Finding {
+                    check: result.check_name.clone(),
+                    file: caps[1].to_string(),
+                    line: caps[2].parse().unwrap_or(1),
+                    column: caps[3].parse().unwrap_or(1),
+                    severity: level,
+                    message,
+                });
+            }
+            continue;
+        }
+        if let Some(caps) = tsc.captures(line) {
+            findings.push(Finding {
+                check: result.check_name.clone(),
+                file: caps[1].to_string(),
+                line: caps[2].parse().unwrap_or(1),
+                column: caps[3].parse().unwrap_or(1),
+                severity: severity(Some(&caps[4])),
+                message: caps[5].to_string(),
+            });
+            continue;
+        }
+        if let Some(caps) = generic.captures(line) {
+            findings.push(Finding {
+                check: result.check_name.clone(),
+                file: caps[1].to_string(),
+                line: caps[2].parse().unwrap_or(1),
+                column: caps[3].parse().unwrap_or(1),
+                severity: severity(caps.get(4).map(|m| m.as_str())),
+                message: caps[5].trim().to_string(),
+            });
+        }
+    }
+
+    findings
+}
+
+/// Manifests that mark a project root for `code-quality --recursive`.
+const PROJECT_MARKERS: [&str; 3] = ["Cargo.toml", "package.json", "pyproject.toml"];
+/// Project roots at or below `root` within the reach of `options`. Members
+/// of a Cargo or npm/pnpm/yarn workspace are left to the workspace root,
+/// whose checks already cover them.
+pub fn find_project_roots(root: &Path, options: &ScanOptions) -> Vec<PathBuf> {
+    let filter = PathFilter::new(options);
+    let mut cargo_workspaces: Vec<PathBuf> = Vec::new();
+    let mut js_workspaces: Vec<PathBuf> = Vec::new();
+    let mut roots = Vec::new();
+
+    for relative in walk_dirs(root, options) {
+        let dir = root.join(&relative);
+        let has = |file: &str| dir.join(file).is_file();
+        let read = |file: &str| std::fs::read_to_string(dir.join(file)).unwrap_or_default();
+        let within = |workspaces: &[PathBuf]| workspaces.iter().any(|w| relative.starts_with(w));
+
+        let markers: Vec<&str> = PROJECT_MARKERS.into_iter().filter(|m| has(m)).collect();
+        let covered = markers.iter().all(|marker| match *marker {
+            "Cargo.toml" => within(&cargo_workspaces),
+            "package.json" => within(&js_workspaces),
+            _ => false,
+        });
+        if !markers.is_empty() && !covered && filter.included(&relative) {
+            roots.push(dir.clone());
+        }
+
+        if has("Cargo.toml") && read("Cargo.toml").contains("[workspace]") {
+            cargo_workspaces.push(relative.clone());
+        }
+        if has("pnpm-workspace.yaml")
+            || (has("package.json") && read("package.json").contains("\"workspaces\""))
+        {
+            js_workspaces.push(relative.clone());
+        }
+    }
+    roots
+}
+
+/// Checks of one project found by `--recursive`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectResults {
+    /// Relative to the scanned directory, `.` for the directory itself
+    pub project: String,
+    pub results: Vec<CheckResult>,
+}
+
+/// Flattens per-project results for reports, naming checks `project/check`.
+pub fn flatten_project_results(projects: &[ProjectResults]) -> Vec<CheckResult> {
+    projects
+        .iter()
+        .flat_map(|project| {
+            project.results.iter().map(move |result| CheckResult {
+                check_name: if project.project == "." {
+                    result.check_name.clone()
+                } else {
+                    format!("{}/{}", project.project, result.check_name)
+                },
+                ..result.clone()
+            })
+        })
+        .collect()
+}
+
+/// A file touched by `code-quality --fix`, with its current diff size.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangedFile {
+    pub path: String,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// `git diff --numstat` keyed by path; binary files count as 0/0.
+pub fn parse_numstat(output: &str) -> BTreeMap<String, (usize, usize)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let added = parts.next()?.parse().unwrap_or(0);
+            let removed = parts.next()?.parse().unwrap_or(0);
+            Some((parts.next()?.to_string(), (added, removed)))
+        })
+        .collect()
+}
+
+fn diff_numstat(
+    root: &Path,
+    runner: &dyn CommandRunner,
+) -> Option<BTreeMap<String, (usize, usize)>> {
+    let output = runner
+        .run("git", &["diff", "--numstat"], Some(root))
+        .ok()?
+        .check("git diff")
+        .ok()?;
+    Some(parse_numstat(&output.stdout))
+}
+
+/// Files whose diff differs from the one before the fixers ran, so changes
+/// that were already in the working tree aren't attributed to them.
+pub fn changed_files(
+    before: &BTreeMap<String, (usize, usize)>,
+    after: &BTreeMap<String, (usize, usize)>,
+) -> Vec<ChangedFile> {
+    after
+        .iter()
+        .filter(|(path, stat)| before.get(*path) != Some(*stat))
+        .map(|(path, (added, removed))| ChangedFile {
+            path: path.clone(),
+            added: *added,
+            removed: *removed,
+        })
+        .collect()
+}
+
 pub struct CodeQualityManager {
     config: CodeQualityConfig,
 }
@@ -95,30 +481,162 @@ impl CodeQualityManager {
     }
 
     pub async fn run_quality_checks(&self, path: &Path) -> Result<Vec<CheckResult>> {
-        println!("{}", "🔍 Analyzing project structure...".blue().bold());
+        ensure_not_sandboxed("code-quality")?;
+        // With --json the results are the only output
+        let human = !is_json_output();
+        if human {
+            println!("{}", "🔍 Analyzing project structure...".blue().bold());
+        }
 
         let project_info = self.detect_project_type(path).await?;
-        self.print_project_info(&project_info);
-
-        println!("{}", "🚀 Running code quality checks...".green().bold());
+        if human {
+            self.print_project_info(&project_info);
+            for warning in self.config.policy_warnings() {
+                println!("{}", format!("⚠️  {}", warning).yellow());
+            }
+            println!("{}", "🚀 Running code quality checks...".green().bold());
+        }
 
-        let checks = self.generate_quality_checks(&project_info)?;
+        let checks = self.planned_checks(&project_info)?;
+        // The secret scan is built in, it needs no tool for the project type
+        let scan_secrets = self
+            .config
+            .enabled_checks
+            .iter()
+            .any(|check| check == SECRETS_CHECK);
         let mut results = Vec::new();
+        let mut progress = ProgressBar::new("🔍 Checks", checks.len() + scan_secrets as usize);
 
         for check in checks {
-            if self.config.enabled_checks.contains(&check.name) {
-                println!("{}", format!("  ▶ Running {}...", check.name).yellow());
+            progress.tick(&format!("Running {}...", check.name));
 
-                let result = self.run_check(&check).await;
+            let result = self.run_check(&check).await;
+            progress.clear();
+            if human {
                 self.print_check_result(&result);
-                results.push(result);
             }
+            results.push(result);
+            progress.inc(1);
+        }
+        if scan_secrets {
+            progress.tick("Scanning for secrets...");
+            let result = run_secrets_check(path);
+            progress.clear();
+            if human {
+                self.print_check_result(&result);
+            }
+            results.push(result);
+            progress.inc(1);
+        }
+        if !results.is_empty() {
+            progress.finish();
         }
 
-        self.print_summary(&results);
+        if human {
+            self.print_summary(&results);
+        }
         Ok(results)
     }
 
+    /// Runs the checks of every project root found below `path` and prints a
+    /// summary grouped by project.
+    pub async fn run_recursive(
+        &self,
+        path: &Path,
+        scan: &ScanOptions,
+    ) -> Result<Vec<ProjectResults>> {
+        let human = !is_json_output();
+        let roots = find_project_roots(path, scan);
+        if roots.is_empty() && human {
+            println!(
+                "{}",
+                "ℹ️  No nested projects found, checking the directory itself".yellow()
+            );
+        }
+        let roots = if roots.is_empty() {
+            vec![path.to_path_buf()]
+        } else {
+            roots
+        };
+
+        if human {
+            println!(
+                "{}",
+                format!("📂 Found {} project(s)", roots.len()).cyan().bold()
+            );
+        }
+        let mut projects = Vec::new();
+        for root in roots {
+            let project = match root.strip_prefix(path) {
+                Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+                Ok(relative) => relative.to_string_lossy().to_string(),
+                Err(_) => root.to_string_lossy().to_string(),
+            };
+            if human {
+                println!("\n{}", format!("📁 {}", project).bold());
+                println!("{}", "─".repeat(50).dimmed());
+            }
+            let results = self.run_quality_checks(&root).await?;
+            projects.push(ProjectResults { project, results });
+        }
+
+        if human {
+            self.print_project_summary(&projects);
+        }
+        Ok(projects)
+    }
+
+    /// Runs the fixing variants of the format and lint tools and reports the
+    /// files they changed. `None` when the project isn't a git checkout.
+    pub async fn run_fixes(
+        &self,
+        path: &Path,
+    ) -> Result<(Vec<CheckResult>, Option<Vec<ChangedFile>>)> {
+        ensure_not_sandboxed("code-quality --fix")?;
+        let human = !is_json_output();
+        let project_info = self.detect_project_type(path).await?;
+        if human {
+            self.print_project_info(&project_info);
+        }
+
+        let fixes = self.generate_fix_checks(&project_info);
+        if fixes.is_empty() {
+            if human {
+                println!(
+                    "{}",
+                    format!("ℹ️  No fixers for {:?} projects", project_info.project_type).yellow()
+                );
+            }
+            return Ok((Vec::new(), None));
+        }
+
+        if human {
+            println!("{}", "🔧 Applying automatic fixes...".green().bold());
+        }
+        let before = diff_numstat(path, &SystemRunner);
+        let mut results = Vec::new();
+        for mut fix in fixes {
+            fix.timeout = self
+                .config
+                .policy_for(&fix.name, &fix.command)
+                .timeout_seconds;
+            let result = self.run_check(&fix).await;
+            if human {
+                self.print_check_result(&result);
+            }
+            results.push(result);
+        }
+
+        let changed = match (before, diff_numstat(path, &SystemRunner)) {
+            (Some(before), Some(after)) => Some(changed_files(&before, &after)),
+            _ => None,
+        };
+        if human {
+            self.print_fix_summary(&results, changed.as_deref());
+        }
+        Ok((results, changed))
+    }
+
     pub async fn detect_project_type(&self, path: &Path) -> Result<ProjectInfo> {
         let mut project_info = ProjectInfo {
             project_type: ProjectType::Unknown,
@@ -139,6 +657,10 @@ impl CodeQualityManager {
             ("requirements.txt", PackageManager::Pip),
             ("pyproject.toml", PackageManager::Pip), // Python projects
             ("poetry.lock", PackageManager::Pip),    // Poetry projects
+            ("go.mod", PackageManager::GoModules),
+            ("pom.xml", PackageManager::Maven),
+            ("build.gradle", PackageManager::Gradle),
+            ("build.gradle.kts", PackageManager::Gradle),
         ];
 
         for (file, pm) in pm_files {
@@ -155,6 +677,27 @@ impl CodeQualityManager {
             return Ok(project_info);
         }
 
+        // Detect Go project
+        if path.join("go.mod").exists() {
+            project_info.project_type = ProjectType::Go;
+            project_info.frameworks.push("Go".to_string());
+            return Ok(project_info);
+        }
+
+        // Detect Java/Kotlin project (Maven or Gradle)
+        if path.join("pom.xml").exists()
+            || path.join("build.gradle").exists()
+            || path.join("build.gradle.kts").exists()
+        {
+            project_info.project_type = ProjectType::Java;
+            if path.join("build.gradle.kts").exists() || path.join("src/main/kotlin").exists() {
+                project_info.frameworks.push("Kotlin".to_string());
+            } else {
+                project_info.frameworks.push("Java".to_string());
+            }
+            return Ok(project_info);
+        }
+
         // Detect Python project
         if path.join("requirements.txt").exists()
             || path.join("pyproject.toml").exists()
@@ -293,7 +836,39 @@ impl CodeQualityManager {
         Ok(config_files)
     }
 
-    fn generate_quality_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
+    fn custom_check(&self, name: &str) -> Option<&CustomCheck> {
+        self.config
+            .custom_checks
+            .iter()
+            .find(|custom| custom.name == name)
+    }
+
+    /// Checks that will actually run: generated and custom checks with their
+    /// resolved timeouts. Custom checks always run, generated ones only when
+    /// listed in `enabled_checks`.
+    pub fn planned_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
+        let mut checks = self.generate_quality_checks(project_info)?;
+        for check in &mut checks {
+            let custom_timeout = self
+                .custom_check(&check.name)
+                .and_then(|custom| custom.timeout_seconds);
+            check.timeout = match custom_timeout {
+                Some(timeout) => timeout.clamp(1, MAX_TIMEOUT_SECONDS),
+                None => {
+                    self.config
+                        .policy_for(&check.name, &check.command)
+                        .timeout_seconds
+                }
+            };
+        }
+        checks.retain(|check| {
+            self.config.enabled_checks.contains(&check.name)
+                || self.custom_check(&check.name).is_some()
+        });
+        Ok(checks)
+    }
+
+    pub fn generate_quality_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
         let mut checks = Vec::new();
 
         match project_info.project_type {
@@ -309,12 +884,35 @@ impl CodeQualityManager {
             ProjectType::Python => {
                 checks.extend(self.generate_python_checks(project_info)?);
             }
+            ProjectType::Go => {
+                checks.extend(self.generate_go_checks(project_info)?);
+            }
+            ProjectType::Java => {
+                checks.extend(self.generate_java_checks(project_info)?);
+            }
             _ => {
                 // Fallback to basic checks
                 checks.extend(self.generate_basic_checks(project_info)?);
             }
         }
 
+        for custom in &self.config.custom_checks {
+            checks.retain(|check| check.name != custom.name);
+            checks.push(QualityCheck {
+                name: custom.name.clone(),
+                command: custom.command.clone(),
+                args: custom.args.clone(),
+                working_dir: match &custom.working_dir {
+                    Some(dir) => project_info.root_path.join(dir),
+                    None => project_info.root_path.clone(),
+                },
+                timeout: custom
+                    .timeout_seconds
+                    .unwrap_or(self.config.timeout_seconds),
+                expected_exit_code: custom.expected_exit_code,
+            });
+        }
+
         Ok(checks)
     }
 
@@ -326,6 +924,7 @@ impl CodeQualityManager {
                 args: vec![".".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "format".to_string(),
@@ -333,6 +932,7 @@ impl CodeQualityManager {
                 args: vec!["--check".to_string(), ".".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "test".to_string(),
@@ -340,6 +940,7 @@ impl CodeQualityManager {
                 args: vec![],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "security".to_string(),
@@ -347,6 +948,7 @@ impl CodeQualityManager {
                 args: vec!["-r".to_string(), ".".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
         ];
 
@@ -365,6 +967,7 @@ impl CodeQualityManager {
                 args: vec!["run".to_string(), "lint".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "test".to_string(),
@@ -372,6 +975,7 @@ impl CodeQualityManager {
                 args: vec!["test".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "security".to_string(),
@@ -379,6 +983,7 @@ impl CodeQualityManager {
                 args: vec!["audit".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
         ];
 
@@ -390,6 +995,7 @@ impl CodeQualityManager {
                 args: vec!["run".to_string(), "type-check".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             });
         }
 
@@ -406,6 +1012,7 @@ impl CodeQualityManager {
                 args: vec!["run".to_string(), "lint".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "test".to_string(),
@@ -413,6 +1020,7 @@ impl CodeQualityManager {
                 args: vec!["test".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "security".to_string(),
@@ -420,6 +1028,7 @@ impl CodeQualityManager {
                 args: vec!["audit".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
         ];
 
@@ -434,6 +1043,7 @@ impl CodeQualityManager {
                 args: vec!["fmt".to_string(), "--check".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "lint".to_string(),
@@ -446,6 +1056,7 @@ impl CodeQualityManager {
                 ],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
             QualityCheck {
                 name: "test".to_string(),
@@ -453,12 +1064,103 @@ impl CodeQualityManager {
                 args: vec!["test".to_string()],
                 working_dir: project_info.root_path.clone(),
                 timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
             },
         ];
 
         Ok(checks)
     }
 
+    pub fn generate_go_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
+        let checks = vec![
+            // gofmt -l exits 0 either way, unformatted files are its output
+            QualityCheck {
+                name: "format".to_string(),
+                command: "sh".to_string(),
+                args: vec![
+                    "-c".to_string(),
+                    "files=$(gofmt -l .) && echo \"$files\" && test -z \"$files\"".to_string(),
+                ],
+                working_dir: project_info.root_path.clone(),
+                timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
+            },
+            QualityCheck {
+                name: "lint".to_string(),
+                command: "golangci-lint".to_string(),
+                args: vec!["run".to_string()],
+                working_dir: project_info.root_path.clone(),
+                timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
+            },
+            QualityCheck {
+                name: "test".to_string(),
+                command: "go".to_string(),
+                args: vec!["test".to_string(), "./...".to_string()],
+                working_dir: project_info.root_path.clone(),
+                timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
+            },
+            QualityCheck {
+                name: "security".to_string(),
+                command: "go".to_string(),
+                args: vec!["vet".to_string(), "./...".to_string()],
+                working_dir: project_info.root_path.clone(),
+                timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
+            },
+        ];
+
+        Ok(checks)
+    }
+
+    pub fn generate_java_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
+        let command = self.get_package_manager_command(&project_info.package_manager);
+
+        let checks = if project_info.package_manager == PackageManager::Maven {
+            vec![QualityCheck {
+                name: "test".to_string(),
+                command,
+                args: vec!["-B".to_string(), "verify".to_string()],
+                working_dir: project_info.root_path.clone(),
+                timeout: self.config.timeout_seconds,
+                expected_exit_code: 0,
+            }]
+        } else {
+            // Prefer the Gradle wrapper when the project ships one
+            let command = if project_info.root_path.join("gradlew").exists() {
+                project_info
+                    .root_path
+                    .join("gradlew")
+                    .to_string_lossy()
+                    .to_string()
+            } else {
+                command
+            };
+
+            vec![
+                QualityCheck {
+                    name: "lint".to_string(),
+                    command: command.clone(),
+                    args: vec!["check".to_string(), "-x".to_string(), "test".to_string()],
+                    working_dir: project_info.root_path.clone(),
+                    timeout: self.config.timeout_seconds,
+                    expected_exit_code: 0,
+                },
+                QualityCheck {
+                    name: "test".to_string(),
+                    command,
+                    args: vec!["test".to_string()],
+                    working_dir: project_info.root_path.clone(),
+                    timeout: self.config.timeout_seconds,
+                    expected_exit_code: 0,
+                },
+            ]
+        };
+
+        Ok(checks)
+    }
+
     pub fn generate_basic_checks(&self, project_info: &ProjectInfo) -> Result<Vec<QualityCheck>> {
         let checks = vec![QualityCheck {
             name: "validate".to_string(),
@@ -466,11 +1168,79 @@ impl CodeQualityManager {
             args: vec!["Basic validation completed".to_string()],
             working_dir: project_info.root_path.clone(),
             timeout: self.config.timeout_seconds,
+            expected_exit_code: 0,
         }];
 
         Ok(checks)
     }
 
+    /// Fixing counterparts of the `format` and `lint` checks, only for the
+    /// ones enabled. Tools without a fix mode (flake8, go vet) are left out.
+    pub fn generate_fix_checks(&self, project_info: &ProjectInfo) -> Vec<QualityCheck> {
+        let fix = |name: &str, command: &str, args: &[&str]| QualityCheck {
+            name: name.to_string(),
+            command: command.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            working_dir: project_info.root_path.clone(),
+            timeout: self.config.timeout_seconds,
+            expected_exit_code: 0,
+        };
+
+        let mut fixes = match project_info.project_type {
+            // clippy first, its fixes may need formatting
+            ProjectType::Rust => vec![
+                fix(
+                    "lint",
+                    "cargo",
+                    &["clippy", "--fix", "--allow-dirty", "--allow-staged"],
+                ),
+                fix("format", "cargo", &["fmt"]),
+            ],
+            ProjectType::NextJs
+            | ProjectType::React
+            | ProjectType::Angular
+            | ProjectType::Vue
+            | ProjectType::NodeJs
+            | ProjectType::TypeScript
+            | ProjectType::JavaScript => {
+                let (command, prefix): (&str, &[&str]) = match project_info.package_manager {
+                    PackageManager::Yarn => ("yarn", &[]),
+                    PackageManager::Pnpm => ("pnpm", &["exec"]),
+                    PackageManager::Bun => ("bunx", &[]),
+                    _ => ("npx", &[]),
+                };
+                let with_prefix =
+                    |args: &[&'static str]| prefix.iter().chain(args).copied().collect::<Vec<_>>();
+
+                let mut fixes = vec![fix(
+                    "lint",
+                    command,
+                    &with_prefix(&["eslint", "--fix", "."]),
+                )];
+                let uses_prettier =
+                    project_info.config_files.iter().any(|file| {
+                        file.file_name()
+                            .is_some_and(|name| name.to_string_lossy().starts_with(".prettierrc"))
+                    }) || std::fs::read_to_string(project_info.root_path.join("package.json"))
+                        .is_ok_and(|content| content.contains("\"prettier\""));
+                if uses_prettier {
+                    fixes.push(fix(
+                        "format",
+                        command,
+                        &with_prefix(&["prettier", "--write", "."]),
+                    ));
+                }
+                fixes
+            }
+            ProjectType::Python => vec![fix("format", "black", &["."])],
+            ProjectType::Go => vec![fix("format", "gofmt", &["-w", "."])],
+            _ => Vec::new(),
+        };
+
+        fixes.retain(|fix| self.config.enabled_checks.contains(&fix.name));
+        fixes
+    }
+
     pub fn get_package_manager_command(&self, pm: &PackageManager) -> String {
         match pm {
             PackageManager::Npm => "npm".to_string(),
@@ -479,24 +1249,62 @@ impl CodeQualityManager {
             PackageManager::Bun => "bun".to_string(),
             PackageManager::Cargo => "cargo".to_string(),
             PackageManager::Pip => "pip".to_string(),
+            PackageManager::GoModules => "go".to_string(),
+            PackageManager::Maven => "mvn".to_string(),
+            PackageManager::Gradle => "gradle".to_string(),
             PackageManager::Unknown => "npm".to_string(), // fallback
         }
     }
 
-    async fn run_check(&self, check: &QualityCheck) -> CheckResult {
+    /// Runs a check with its timeout, retrying failed attempts as configured.
+    pub async fn run_check(&self, check: &QualityCheck) -> CheckResult {
+        let retries = self.config.policy_for(&check.name, &check.command).retries;
+        let mut attempt = 0;
+
+        loop {
+            let result = self.run_check_once(check).await;
+            if result.success || attempt >= retries {
+                self.warn_on_timeout_overflow(check, &result);
+                return result;
+            }
+
+            attempt += 1;
+            if is_json_output() {
+                continue;
+            }
+            println!(
+                "{}",
+                format!(
+                    "    ↻ Retrying {} (attempt {}/{})...",
+                    check.name,
+                    attempt + 1,
+                    retries + 1
+                )
+                .yellow()
+            );
+        }
+    }
+
+    async fn run_check_once(&self, check: &QualityCheck) -> CheckResult {
         let start = std::time::Instant::now();
+        let command_line = std::iter::once(check.command.clone())
+            .chain(check.args.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(" ");
 
         let mut command = Command::new(&check.command);
         command.args(&check.args).current_dir(&check.working_dir);
 
-        match command.output() {
+        let timeout = Duration::from_secs(check.timeout.max(1));
+        match supervise_async(&mut command, timeout).await {
             Ok(output) => {
-                let success = output.status.success();
+                let success = output.status.code() == Some(check.expected_exit_code);
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
                 let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
                 CheckResult {
                     check_name: check.name.clone(),
+                    command: command_line,
                     success,
                     output: stdout,
                     error: if stderr.is_empty() {
@@ -507,8 +1315,17 @@ impl CodeQualityManager {
                     duration_ms: start.elapsed().as_millis(),
                 }
             }
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => CheckResult {
+                check_name: check.name.clone(),
+                command: command_line,
+                success: false,
+                output: String::new(),
+                error: Some(format!("Timed out after {}s", check.timeout)),
+                duration_ms: start.elapsed().as_millis(),
+            },
             Err(e) => CheckResult {
                 check_name: check.name.clone(),
+                command: command_line,
                 success: false,
                 output: String::new(),
                 error: Some(e.to_string()),
@@ -517,6 +1334,34 @@ impl CodeQualityManager {
         }
     }
 
+    /// Points at the policy to raise when a check ran out of (or nearly out
+    /// of) its time budget.
+    fn warn_on_timeout_overflow(&self, check: &QualityCheck, result: &CheckResult) {
+        let budget_ms = u128::from(check.timeout.max(1)) * 1000;
+        let used = result.duration_ms * 100 / budget_ms;
+        if used < 80 {
+            return;
+        }
+
+        let key = tool_name(&check.command);
+        let message = if used >= 100 {
+            format!(
+                "⚠️  {} hit its {}s timeout; raise tool_policies.{}.timeout_seconds",
+                check.name, check.timeout, key
+            )
+        } else {
+            format!(
+                "⚠️  {} used {}% of its {}s timeout; consider raising tool_policies.{}.timeout_seconds",
+                check.name, used, check.timeout, key
+            )
+        };
+        if is_json_output() {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message.yellow());
+        }
+    }
+
     fn print_project_info(&self, info: &ProjectInfo) {
         println!("{}", "📋 Project Information:".cyan().bold());
         println!("  Type: {:?}", info.project_type);
@@ -534,9 +1379,9 @@ impl CodeQualityManager {
 
     fn print_check_result(&self, result: &CheckResult) {
         let status = if result.success {
-            "✅ PASS".green()
+            label("PASS", Role::Success)
         } else {
-            "❌ FAIL".red()
+            label("FAIL", Role::Error)
         };
 
         println!(
@@ -545,55 +1390,485 @@ impl CodeQualityManager {
         );
 
         if !result.success {
-            if let Some(error) = &result.error {
-                println!("      Error: {}", error.red());
+            let findings = extract_findings(result);
+            if findings.is_empty() {
+                if let Some(error) = &result.error {
+                    println!("      Error: {}", paint(error, Role::Error));
+                }
+            }
+            // Printed without color so editor problem matchers can parse them
+            for finding in findings {
+                println!("      {}", finding.to_line());
+            }
+        }
+    }
+
+    fn print_project_summary(&self, projects: &[ProjectResults]) {
+        println!();
+        println!("{}", paint("📊 Summary by project:", Role::Accent).bold());
+
+        let width = projects.iter().map(|p| p.project.len()).max().unwrap_or(0);
+        for project in projects {
+            let failed: Vec<&str> = project
+                .results
+                .iter()
+                .filter(|r| !r.success)
+                .map(|r| r.check_name.as_str())
+                .collect();
+            let passed = project.results.len() - failed.len();
+            let status = if failed.is_empty() {
+                label("PASS", Role::Success)
+            } else {
+                label("FAIL", Role::Error)
+            };
+            let mut line = format!(
+                "  {} {:<width$}  {}/{} passed",
+                status,
+                project.project,
+                passed,
+                project.results.len(),
+                width = width
+            );
+            if !failed.is_empty() {
+                line.push_str(&format!(
+                    "  {}",
+                    paint(&format!("failed: {}", failed.join(", ")), Role::Error)
+                ));
+            }
+            println!("{}", line);
+        }
+
+        let failing = projects
+            .iter()
+            .filter(|p| p.results.iter().any(|r| !r.success))
+            .count();
+        println!(
+            "  {} of {} project(s) passed",
+            projects.len() - failing,
+            projects.len()
+        );
+    }
+
+    fn print_fix_summary(&self, results: &[CheckResult], changed: Option<&[ChangedFile]>) {
+        println!();
+        println!("{}", paint("📊 Fix summary:", Role::Accent).bold());
+
+        let failed: Vec<&str> = results
+            .iter()
+            .filter(|r| !r.success)
+            .map(|r| r.check_name.as_str())
+            .collect();
+        if !failed.is_empty() {
+            println!(
+                "  {} Fixers failed: {}",
+                Role::Error.symbol(),
+                paint(&failed.join(", "), Role::Error)
+            );
+        }
+
+        match changed {
+            None => println!("  Not a git repository, changed files can't be listed"),
+            Some([]) => println!(
+                "  {} Nothing to fix, no files changed",
+                Role::Success.symbol()
+            ),
+            Some(files) => {
+                let added: usize = files.iter().map(|f| f.added).sum();
+                let removed: usize = files.iter().map(|f| f.removed).sum();
+                println!(
+                    "  {} {} file(s) changed, {} insertion(s), {} deletion(s)",
+                    Role::Success.symbol(),
+                    files.len(),
+                    paint(&format!("+{}", added), Role::Success),
+                    paint(&format!("-{}", removed), Role::Error)
+                );
+                for file in files {
+                    println!(
+                        "    {} {}",
+                        file.path,
+                        format!("+{} -{}", file.added, file.removed).dimmed()
+                    );
+                }
             }
         }
     }
 
     fn print_summary(&self, results: &[CheckResult]) {
         println!();
-        println!("{}", "📊 Summary:".cyan().bold());
+        println!("{}", paint("📊 Summary:", Role::Accent).bold());
 
         let passed = results.iter().filter(|r| r.success).count();
         let failed = results.len() - passed;
         let total_duration: u128 = results.iter().map(|r| r.duration_ms).sum();
 
         println!("  Total checks: {}", results.len());
-        println!("  Passed: {}", passed.to_string().green());
-        println!("  Failed: {}", failed.to_string().red());
+        println!(
+            "  {} Passed: {}",
+            Role::Success.symbol(),
+            paint(&passed.to_string(), Role::Success)
+        );
+        println!(
+            "  {} Failed: {}",
+            Role::Error.symbol(),
+            paint(&failed.to_string(), Role::Error)
+        );
         println!("  Total time: {}ms", total_duration);
 
         if failed > 0 {
             println!();
-            println!("{}", "Failed checks:".red().bold());
+            println!("{}", paint("Failed checks:", Role::Error).bold());
             for result in results.iter().filter(|r| !r.success) {
-                println!("  - {}", result.check_name.red());
+                println!("  {}", label(&result.check_name, Role::Error));
             }
         }
     }
 }
 
-// CLI command handler
-pub async fn run_code_quality(path: Option<String>, config_path: Option<String>) -> Result<()> {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Junit,
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn default_file_name(&self) -> &'static str {
+        match self {
+            ReportFormat::Json => "code-quality-report.json",
+            ReportFormat::Junit => "code-quality-report.xml",
+            ReportFormat::Sarif => "code-quality-report.sarif",
+        }
+    }
+}
+
+impl std::str::FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ReportFormat::Json),
+            "junit" | "xml" => Ok(ReportFormat::Junit),
+            "sarif" => Ok(ReportFormat::Sarif),
+            other => Err(anyhow::anyhow!(
+                "Unknown report format: {}. Use 'junit', 'sarif' or 'json'",
+                other
+            )),
+        }
+    }
+}
+
+pub fn render_report(format: ReportFormat, results: &[CheckResult]) -> Result<String> {
+    match format {
+        ReportFormat::Json => render_json_report(results),
+        ReportFormat::Junit => Ok(render_junit_report(results)),
+        ReportFormat::Sarif => render_sarif_report(results),
+    }
+}
+
+pub fn render_json_report(results: &[CheckResult]) -> Result<String> {
+    let passed = results.iter().filter(|r| r.success).count();
+    let report = serde_json::json!({
+        "tool": "nitroterm",
+        "version": env!("CARGO_PKG_VERSION"),
+        "summary": {
+            "total": results.len(),
+            "passed": passed,
+            "failed": results.len() - passed,
+            "duration_ms": results.iter().map(|r| r.duration_ms).sum::<u128>(),
+        },
+        "checks": results,
+    });
+
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+pub fn render_junit_report(results: &[CheckResult]) -> String {
+    let failures = results.iter().filter(|r| !r.success).count();
+    let total_seconds = results.iter().map(|r| r.duration_ms).sum::<u128>() as f64 / 1000.0;
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"nitroterm code-quality\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        results.len(),
+        failures,
+        total_seconds
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"code-quality\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        results.len(),
+        failures,
+        total_seconds
+    ));
+
+    for result in results {
+        xml.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"code-quality.{}\" time=\"{:.3}\">\n",
+            xml_escape(&result.check_name),
+            xml_escape(&result.check_name),
+            result.duration_ms as f64 / 1000.0
+        ));
+        xml.push_str(&format!(
+            "      <properties><property name=\"command\" value=\"{}\"/></properties>\n",
+            xml_escape(&result.command)
+        ));
+
+        if !result.success {
+            let message = result
+                .error
+                .as_deref()
+                .and_then(|e| e.lines().next())
+                .unwrap_or("Check failed");
+            xml.push_str(&format!(
+                "      <failure message=\"{}\">{}</failure>\n",
+                xml_escape(message),
+                xml_escape(result.error.as_deref().unwrap_or(""))
+            ));
+        }
+
+        if !result.output.is_empty() {
+            xml.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                xml_escape(&result.output)
+            ));
+        }
+        if let Some(error) = &result.error {
+            xml.push_str(&format!(
+                "      <system-err>{}</system-err>\n",
+                xml_escape(error)
+            ));
+        }
+
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Where a failed check points in SARIF: the files named in its output or,
+/// when it names none, the project directory it ran in. Code scanning
+/// drops results without a location.
+fn sarif_locations(result: &CheckResult) -> Vec<serde_json::Value> {
+    // `project/check` for checks of a `--recursive` run
+    let project = result
+        .check_name
+        .rsplit_once('/')
+        .map(|(project, _)| project);
+    let location = |uri: String, region: Option<serde_json::Value>| {
+        let mut physical = serde_json::json!({
+            "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+        });
+        if let Some(region) = region {
+            physical["region"] = region;
+        }
+        serde_json::json!({ "physicalLocation": physical })
+    };
+
+    let findings = extract_findings(result);
+    if findings.is_empty() {
+        return vec![location(project.unwrap_or(".").to_string(), None)];
+    }
+    findings
+        .iter()
+        .map(|finding| {
+            let uri = match project {
+                Some(project) => format!("{}/{}", project, finding.file),
+                None => finding.file.clone(),
+            };
+            location(
+                uri,
+                Some(serde_json::json!({
+                    "startLine": finding.line.max(1),
+                    "startColumn": finding.column.max(1),
+                })),
+            )
+        })
+        .collect()
+}
+
+pub fn render_sarif_report(results: &[CheckResult]) -> Result<String> {
+    let rules: Vec<serde_json::Value> = results
+        .iter()
+        .map(|r| {
+            serde_json::json!({
+                "id": r.check_name,
+                "name": r.check_name,
+                "shortDescription": { "text": format!("nitroterm {} check", r.check_name) },
+                "fullDescription": { "text": format!("Runs `{}`", r.command) },
+            })
+        })
+        .collect();
+
+    let sarif_results: Vec<serde_json::Value> = results
+        .iter()
+        .filter(|r| !r.success)
+        .map(|r| {
+            let text = r
+                .error
+                .as_deref()
+                .filter(|e| !e.trim().is_empty())
+                .unwrap_or(r.output.as_str());
+            serde_json::json!({
+                "ruleId": r.check_name,
+                "level": "error",
+                "message": {
+                    "text": if text.trim().is_empty() {
+                        format!("{} check failed", r.check_name)
+                    } else {
+                        text.trim().to_string()
+                    }
+                },
+                "locations": sarif_locations(r),
+                "properties": {
+                    "command": r.command,
+                    "durationMs": r.duration_ms,
+                },
+            })
+        })
+        .collect();
+
+    let sarif = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "nitroterm",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://nitrokit.tr",
+                    "rules": rules,
+                }
+            },
+            "results": sarif_results,
+        }]
+    });
+
+    Ok(serde_json::to_string_pretty(&sarif)?)
+}
+
+pub async fn write_report(
+    format: ReportFormat,
+    results: &[CheckResult],
+    report_path: &Path,
+) -> Result<()> {
+    ensure_write_allowed(report_path)?;
+    let content = render_report(format, results)?;
+
+    if let Some(parent) = report_path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent).await?;
+        }
+    }
+    fs::write(report_path, content).await?;
+
+    let message = format!("📄 Report written to: {}", report_path.display());
+    if is_json_output() {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message.green());
+    }
+    Ok(())
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| matches!(c, '\t' | '\n' | '\r') || !c.is_control())
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// `code-quality --fix`: applies fixes instead of checking.
+pub async fn run_code_quality_fix(path: Option<String>, config: CodeQualityConfig) -> Result<()> {
     let project_path = path
         .map(PathBuf::from)
         .unwrap_or_else(|| std::env::current_dir().unwrap());
 
-    let config = if let Some(config_file) = config_path {
-        let config_content = fs::read_to_string(config_file).await?;
-        serde_json::from_str(&config_content)?
+    let manager = CodeQualityManager::new(config);
+    let (results, changed) = manager.run_fixes(&project_path).await?;
+    if is_json_output() {
+        print_json(&serde_json::json!({ "results": results, "changed_files": changed }))?;
+    }
+    let failed = results.iter().filter(|r| !r.success).count();
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} fixer(s) failed", failed));
+    }
+    Ok(())
+}
+
+// CLI command handler. `report_html` is a directory for the static HTML
+// report (checks, project, dependencies and translations).
+pub async fn run_code_quality(
+    path: Option<String>,
+    config: CodeQualityConfig,
+    output_format: Option<ReportFormat>,
+    report_path: Option<String>,
+    recursive: Option<ScanOptions>,
+    report_html: Option<String>,
+) -> Result<()> {
+    let project_path = path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::current_dir().unwrap());
+
+    let report_path = report_path.or_else(|| config.report_path.clone());
+
+    let manager = CodeQualityManager::new(config);
+    let results = if let Some(scan) = &recursive {
+        let projects = manager.run_recursive(&project_path, scan).await?;
+        if is_json_output() {
+            print_json(&projects)?;
+        }
+        flatten_project_results(&projects)
     } else {
-        CodeQualityConfig::default()
+        let results = manager.run_quality_checks(&project_path).await?;
+        if is_json_output() {
+            print_json(&results)?;
+        }
+        results
     };
+    record_quality_run(&project_path, &results);
 
-    let manager = CodeQualityManager::new(config);
-    let results = manager.run_quality_checks(&project_path).await?;
+    if let Some(format) = output_format {
+        let report_path = report_path
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(format.default_file_name()));
+        write_report(format, &results, &report_path).await?;
+    }
+    if let Some(dir) = report_html {
+        let project_info = manager.detect_project_type(&project_path).await?;
+        write_html_report(Path::new(&dir), &project_path, &results, project_info).await?;
+    }
 
     // Exit with error code if any checks failed
-    let failed_count = results.iter().filter(|r| !r.success).count();
-    if failed_count > 0 {
-        std::process::exit(1);
+    let failed: Vec<&str> = results
+        .iter()
+        .filter(|r| !r.success)
+        .map(|r| r.check_name.as_str())
+        .collect();
+    if !failed.is_empty() {
+        notify(
+            &project_path,
+            NotifyEvent::QualityFailed,
+            serde_json::json!({
+                "failed": failed,
+                "failed_count": failed.len(),
+                "passed": results.len() - failed.len(),
+                "total": results.len(),
+            }),
+        )
+        .await;
+        return Err(ChecksFailed(format!(
+            "{} ({} of {})",
+            failed.join(", "),
+            failed.len(),
+            results.len()
+        ))
+        .into());
     }
 
     Ok(())
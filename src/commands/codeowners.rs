@@ -0,0 +1,219 @@
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Where GitHub looks for the file, in order.
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// One `pattern @owner...` line.
+#[derive(Debug, Clone)]
+pub struct OwnershipRule {
+    /// Users, teams (`@org/web`) or emails; empty unassigns the paths
+    pub owners: Vec<String>,
+    /// The `# Frontend` comment above the rule's block, or the GitLab
+    /// `[Frontend]` section it is in
+    pub area: Option<String>,
+    regex: Regex,
+}
+
+impl OwnershipRule {
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path.trim_start_matches('/'))
+    }
+}
+
+/// Translates a CODEOWNERS pattern (gitignore rules) to a regex over
+/// repository-relative paths. Patterns also match everything below a
+/// matching directory, except `dir/*` which stops at the first level.
+fn pattern_regex(pattern: &str) -> Result<Regex> {
+    let directory_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(.*/)?" });
+    let chars: Vec<char> = trimmed.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '*' if chars.get(index + 1) == Some(&'*') => {
+                if chars.get(index + 2) == Some(&'/') {
+                    regex.push_str("(.*/)?");
+                    index += 3;
+                } else {
+                    regex.push_str(".*");
+                    index += 2;
+                }
+                continue;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        index += 1;
+    }
+
+    if directory_only {
+        regex.push_str("/.*$");
+    } else if trimmed == "*" || trimmed.ends_with("/*") {
+        regex.push('$');
+    } else {
+        regex.push_str("(/.*)?$");
+    }
+    Regex::new(&regex).map_err(|e| anyhow!("Invalid CODEOWNERS pattern '{}': {}", pattern, e))
+}
+
+/// A parsed CODEOWNERS file. The last matching rule wins, as on GitHub.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    pub rules: Vec<OwnershipRule>,
+}
+
+impl CodeOwners {
+    /// Parses GitHub CODEOWNERS files, plus GitLab `[Section] @owners`
+    /// headers whose owners apply to the section's rules without any.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut rules = Vec::new();
+        let mut comment: Option<String> = None;
+        let mut section: Option<(String, Vec<String>)> = None;
+        let mut in_comment = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                comment = None;
+                in_comment = false;
+                continue;
+            }
+            if let Some(text) = line.strip_prefix('#') {
+                // The first line of a comment names the block below it
+                if !in_comment {
+                    comment = Some(text.trim().to_string()).filter(|text| !text.is_empty());
+                }
+                in_comment = true;
+                continue;
+            }
+            in_comment = false;
+            if let Some(header) = line.strip_prefix('^').unwrap_or(line).strip_prefix('[') {
+                if let Some((name, rest)) = header.split_once(']') {
+                    // `[Docs][2] @org/docs`: the approval count is skipped
+                    let rest = match rest.trim_start().strip_prefix('[') {
+                        Some(count) => count.split_once(']').map_or("", |(_, rest)| rest),
+                        None => rest,
+                    };
+                    let owners = rest.split_whitespace().map(str::to_string).collect();
+                    section = Some((name.trim().to_string(), owners));
+                    comment = None;
+                    continue;
+                }
+            }
+
+            let mut parts = line.split_whitespace();
+            let Some(pattern) = parts.next() else {
+                continue;
+            };
+            let mut owners: Vec<String> = parts
+                .take_while(|part| !part.starts_with('#'))
+                .map(str::to_string)
+                .collect();
+            if owners.is_empty() {
+                if let Some((_, defaults)) = &section {
+                    owners = defaults.clone();
+                }
+            }
+            rules.push(OwnershipRule {
+                owners,
+                area: comment
+                    .clone()
+                    .or_else(|| section.as_ref().map(|(name, _)| name.clone())),
+                regex: pattern_regex(pattern)?,
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    /// The CODEOWNERS file of the repository at `root`, if it has one.
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        for location in CODEOWNERS_LOCATIONS {
+            let path = root.join(location);
+            if path.is_file() {
+                return Self::parse(&std::fs::read_to_string(&path)?).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// The rule that decides who owns `path`; `None` when no rule matches
+    /// or the last matching one has no owners.
+    pub fn owner_of(&self, path: &str) -> Option<&OwnershipRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(path))
+            .filter(|rule| !rule.owners.is_empty())
+    }
+}
+
+/// Commits that touched the paths of one owner set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TeamChanges {
+    /// Name of the area from the CODEOWNERS comments or sections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    pub owners: Vec<String>,
+    pub commits: usize,
+}
+
+/// Commits per owning team over a release range. A commit counts once for
+/// every team whose paths it touched.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TeamSummary {
+    /// Keyed by the space-joined owners
+    pub teams: BTreeMap<String, TeamChanges>,
+    /// Commits that only touched paths without an owner
+    pub unowned: usize,
+}
+
+impl TeamSummary {
+    pub fn add<'a>(&mut self, codeowners: &CodeOwners, paths: impl IntoIterator<Item = &'a str>) {
+        let mut owned = false;
+        let mut counted: Vec<String> = Vec::new();
+        for path in paths {
+            let Some(rule) = codeowners.owner_of(path) else {
+                continue;
+            };
+            owned = true;
+            let key = rule.owners.join(" ");
+            if counted.contains(&key) {
+                continue;
+            }
+            let team = self
+                .teams
+                .entry(key.clone())
+                .or_insert_with(|| TeamChanges {
+                    area: rule.area.clone(),
+                    owners: rule.owners.clone(),
+                    commits: 0,
+                });
+            team.area = team.area.take().or_else(|| rule.area.clone());
+            team.commits += 1;
+            counted.push(key);
+        }
+        if !owned {
+            self.unowned += 1;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty()
+    }
+
+    /// Teams with the most commits first.
+    pub fn ranked(&self) -> Vec<&TeamChanges> {
+        let mut teams: Vec<&TeamChanges> = self.teams.values().collect();
+        teams.sort_by(|a, b| b.commits.cmp(&a.commits).then(a.owners.cmp(&b.owners)));
+        teams
+    }
+}
@@ -0,0 +1,451 @@
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Types the wizard offers and the lint accepts, as in commitlint's
+/// `config-conventional`.
+pub const COMMIT_TYPES: [(&str, &str); 11] = [
+    ("feat", "A new feature"),
+    ("fix", "A bug fix"),
+    ("docs", "Documentation only changes"),
+    ("style", "Formatting, no code change"),
+    ("refactor", "Neither fixes a bug nor adds a feature"),
+    ("perf", "Improves performance"),
+    ("test", "Adds or corrects tests"),
+    ("build", "Build system or dependencies"),
+    ("ci", "CI configuration and scripts"),
+    ("chore", "Other changes that don't touch src or tests"),
+    ("revert", "Reverts a previous commit"),
+];
+pub const HEADER_MAX_LENGTH: usize = 100;
+pub const BODY_MAX_LINE_LENGTH: usize = 100;
+const BREAKING_FOOTER: &str = "BREAKING CHANGE: ";
+
+fn header_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(
+            r"^(?P<type>[^\s(!:]+)(?:\((?P<scope>[^()]*)\))?(?P<bang>!)?:(?: (?P<subject>.*))?$",
+        )
+        .unwrap()
+    })
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitOptions {
+    pub amend: bool,
+    pub signoff: bool,
+}
+
+/// The parts of a conventional commit message the wizard asks for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitDraft {
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub subject: String,
+    pub body: Option<String>,
+    /// Description for the `BREAKING CHANGE:` footer
+    pub breaking: Option<String>,
+}
+
+impl CommitDraft {
+    /// `type(scope)!: subject`
+    pub fn header(&self) -> String {
+        let scope = self
+            .scope
+            .as_ref()
+            .map(|scope| format!("({})", scope))
+            .unwrap_or_default();
+        let bang = if self.breaking.is_some() { "!" } else { "" };
+        format!("{}{}{}: {}", self.commit_type, scope, bang, self.subject)
+    }
+
+    /// Header, body and breaking change footer, separated by blank lines.
+    pub fn message(&self) -> String {
+        let mut parts = vec![self.header()];
+        parts.extend(self.body.clone());
+        parts.extend(
+            self.breaking
+                .as_ref()
+                .map(|breaking| format!("{}{}", BREAKING_FOOTER, breaking)),
+        );
+        parts.join("\n\n")
+    }
+
+    /// Reads an existing conventional message back, for `--amend`.
+    pub fn parse(message: &str) -> Option<CommitDraft> {
+        let mut sections = message.trim().split("\n\n");
+        let caps = header_pattern().captures(sections.next()?.trim())?;
+        let mut draft = CommitDraft {
+            commit_type: caps["type"].to_string(),
+            scope: caps.name("scope").map(|scope| scope.as_str().to_string()),
+            subject: caps.name("subject")?.as_str().to_string(),
+            body: None,
+            breaking: None,
+        };
+        let mut body = Vec::new();
+        // Sign-offs are added again by `--signoff`
+        for section in sections.filter(|section| {
+            !section
+                .lines()
+                .all(|line| line.starts_with("Signed-off-by:"))
+        }) {
+            match section.strip_prefix(BREAKING_FOOTER) {
+                Some(breaking) => draft.breaking = Some(breaking.trim().to_string()),
+                None => body.push(section.trim()),
+            }
+        }
+        if caps.name("bang").is_some() && draft.breaking.is_none() {
+            draft.breaking = Some(draft.subject.clone());
+        }
+        draft.body = Some(body.join("\n\n")).filter(|body| !body.is_empty());
+        Some(draft)
+    }
+}
+
+/// A broken commit rule, named like the commitlint rule it mirrors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    pub rule: &'static str,
+    pub message: String,
+}
+
+fn issue(rule: &'static str, message: impl Into<String>) -> LintIssue {
+    LintIssue {
+        rule,
+        message: message.into(),
+    }
+}
+
+/// Messages git writes itself, which commitlint ignores too.
+fn is_generated(header: &str) -> bool {
+    header.starts_with("Merge ")
+        || header.starts_with("Revert \"")
+        || header.starts_with("fixup! ")
+        || header.starts_with("squash! ")
+}
+
+/// Checks a commit message against the conventional commit rules of
+/// commitlint's `config-conventional`. Empty when it passes.
+pub fn lint_commit_message(message: &str) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let lines: Vec<&str> = message.trim_end().lines().collect();
+    let Some(header) = lines.first().map(|line| line.trim_end()) else {
+        return vec![issue("header-empty", "message may not be empty")];
+    };
+    if is_generated(header) {
+        return issues;
+    }
+
+    if header.chars().count() > HEADER_MAX_LENGTH {
+        issues.push(issue(
+            "header-max-length",
+            format!(
+                "header must not be longer than {} characters, it has {}",
+                HEADER_MAX_LENGTH,
+                header.chars().count()
+            ),
+        ));
+    }
+    match header_pattern().captures(header) {
+        Some(caps) => {
+            let commit_type = &caps["type"];
+            if commit_type != commit_type.to_lowercase() {
+                issues.push(issue("type-case", "type must be lower-case"));
+            }
+            if !COMMIT_TYPES
+                .iter()
+                .any(|(name, _)| *name == commit_type.to_lowercase())
+            {
+                let names: Vec<&str> = COMMIT_TYPES.iter().map(|(name, _)| *name).collect();
+                issues.push(issue(
+                    "type-enum",
+                    format!("type must be one of {}", names.join(", ")),
+                ));
+            }
+            if let Some(scope) = caps.name("scope") {
+                if scope.as_str().is_empty() {
+                    issues.push(issue("scope-empty", "scope may not be empty, drop the ()"));
+                } else if scope.as_str() != scope.as_str().to_lowercase() {
+                    issues.push(issue("scope-case", "scope must be lower-case"));
+                }
+            }
+            let subject = caps
+                .name("subject")
+                .map_or("", |subject| subject.as_str().trim());
+            if subject.is_empty() {
+                issues.push(issue("subject-empty", "subject may not be empty"));
+            } else {
+                if subject.chars().next().is_some_and(char::is_uppercase) {
+                    issues.push(issue(
+                        "subject-case",
+                        "subject must not start with an upper-case letter",
+                    ));
+                }
+                if subject.ends_with('.') {
+                    issues.push(issue(
+                        "subject-full-stop",
+                        "subject may not end with a period",
+                    ));
+                }
+            }
+        }
+        None => issues.push(issue(
+            "header-format",
+            "header must look like `type(scope): subject`",
+        )),
+    }
+
+    if lines.len() > 1 && !lines[1].trim().is_empty() {
+        issues.push(issue(
+            "body-leading-blank",
+            "body must start after a blank line",
+        ));
+    }
+    if let Some(index) = lines.iter().position(|line| {
+        line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:")
+    }) {
+        if index > 0 && !lines[index - 1].trim().is_empty() {
+            issues.push(issue(
+                "footer-leading-blank",
+                "footer must start after a blank line",
+            ));
+        }
+    }
+    if let Some((number, line)) = lines
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, line)| line.chars().count() > BODY_MAX_LINE_LENGTH)
+    {
+        issues.push(issue(
+            "body-max-line-length",
+            format!(
+                "line {} is {} characters, the limit is {}",
+                number + 1,
+                line.chars().count(),
+                BODY_MAX_LINE_LENGTH
+            ),
+        ));
+    }
+    issues
+}
+
+pub fn print_lint_issues(issues: &[LintIssue]) {
+    for found in issues {
+        println!(
+            "  {} {} {}",
+            "✖".red(),
+            found.message,
+            format!("[{}]", found.rule).dimmed()
+        );
+    }
+}
+
+/// `feat`, `Feat`, or the number of the type in the list.
+pub fn parse_commit_type(answer: &str) -> Option<&'static str> {
+    let answer = answer.trim().to_lowercase();
+    if let Ok(number) = answer.parse::<usize>() {
+        return COMMIT_TYPES
+            .get(number.checked_sub(1)?)
+            .map(|(name, _)| *name);
+    }
+    COMMIT_TYPES
+        .iter()
+        .find(|(name, _)| *name == answer)
+        .map(|(name, _)| *name)
+}
+
+/// Arguments of the `git commit` that records `message`.
+pub fn commit_args(message: &str, options: &CommitOptions) -> Vec<String> {
+    let mut args = vec!["commit".to_string()];
+    if options.amend {
+        args.push("--amend".to_string());
+    }
+    if options.signoff {
+        args.push("--signoff".to_string());
+    }
+    args.extend(["-m".to_string(), message.to_string()]);
+    args
+}
+
+/// Lints `message` and commits the staged changes with it.
+pub fn commit_with(
+    runner: &dyn CommandRunner,
+    message: &str,
+    options: &CommitOptions,
+) -> Result<String> {
+    let issues = lint_commit_message(message);
+    if !issues.is_empty() {
+        print_lint_issues(&issues);
+        return Err(anyhow!(
+            "Commit message breaks {} rule(s), nothing was committed",
+            issues.len()
+        ));
+    }
+    if !options.amend {
+        // `git diff --cached --quiet` exits 1 when something is staged
+        let staged = runner.run("git", &["diff", "--cached", "--quiet"], None)?;
+        if staged.success {
+            return Err(anyhow!(
+                "Nothing staged, `git add` the changes to commit first"
+            ));
+        }
+    }
+    let args = commit_args(message, options);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let output = runner.run("git", &args, None)?.check("git commit")?;
+    Ok(output.stdout)
+}
+
+fn ask_type(current: Option<&str>) -> Result<&'static str> {
+    println!("{}", "Type of change:".cyan().bold());
+    for (index, (name, description)) in COMMIT_TYPES.iter().enumerate() {
+        println!(
+            "  {:>2}. {:<9} {}",
+            index + 1,
+            name.green(),
+            description.dimmed()
+        );
+    }
+    loop {
+        let hint = current.map(|c| format!(" [{}]", c)).unwrap_or_default();
+        let answer = match current {
+            Some(current) => prompt::ask(format!("Type{}: ", hint).cyan(), current)?,
+            None => prompt::ask_required("Type: ".cyan(), "commit type")?,
+        };
+        match parse_commit_type(&answer) {
+            Some(commit_type) => return Ok(commit_type),
+            None => println!("{}", format!("⚠️  Unknown type '{}'", answer).yellow()),
+        }
+    }
+}
+
+fn ask_subject(draft: &CommitDraft, current: Option<&str>) -> Result<String> {
+    loop {
+        let subject = match current {
+            Some(current) => prompt::ask(format!("Subject [{}]: ", current).cyan(), current)?,
+            None => prompt::ask_required("Subject (imperative, lower-case): ".cyan(), "subject")?,
+        };
+        let header = CommitDraft {
+            subject: subject.clone(),
+            ..draft.clone()
+        };
+        let issues = lint_commit_message(&header.header());
+        if issues.is_empty() {
+            return Ok(subject);
+        }
+        print_lint_issues(&issues);
+        if prompt::is_non_interactive() {
+            return Err(anyhow!("Invalid subject '{}'", subject));
+        }
+    }
+}
+
+/// Lines until an empty one.
+fn ask_body() -> Result<Option<String>> {
+    println!(
+        "{}",
+        "Body, why the change was made (empty line to finish):".cyan()
+    );
+    let mut lines = Vec::new();
+    loop {
+        let line = prompt::ask("  > ", "")?;
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(Some(lines.join("\n")).filter(|body| !body.is_empty()))
+}
+
+fn previous_message() -> Option<String> {
+    let output = SystemRunner
+        .run("git", &["log", "-1", "--format=%B"], None)
+        .ok()?;
+    output.success.then(|| output.stdout.trim().to_string())
+}
+
+/// `nitroterm commit`: asks for the parts of a conventional commit,
+/// previews and lints the message, then runs `git commit`.
+pub fn run_commit_wizard(options: CommitOptions) -> Result<()> {
+    ensure_not_sandboxed("commit")?;
+    let previous = if options.amend {
+        previous_message().and_then(|message| CommitDraft::parse(&message))
+    } else {
+        None
+    };
+
+    println!("{}", "📝 Conventional commit".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+    let mut draft = CommitDraft {
+        commit_type: ask_type(previous.as_ref().map(|p| p.commit_type.as_str()))?.to_string(),
+        ..CommitDraft::default()
+    };
+    let scope_default = previous
+        .as_ref()
+        .and_then(|p| p.scope.clone())
+        .unwrap_or_default();
+    let scope = prompt::ask(
+        format!("Scope (optional){}: ", bracketed(&scope_default)).cyan(),
+        &scope_default,
+    )?;
+    draft.scope = Some(scope.to_lowercase()).filter(|scope| !scope.is_empty());
+    draft.subject = ask_subject(&draft, previous.as_ref().map(|p| p.subject.as_str()))?;
+    draft.body = ask_body()?.or_else(|| previous.as_ref().and_then(|p| p.body.clone()));
+    let breaking_default = previous
+        .as_ref()
+        .and_then(|p| p.breaking.clone())
+        .unwrap_or_default();
+    let breaking = prompt::ask(
+        format!(
+            "Breaking change, describe it (Enter for none){}: ",
+            bracketed(&breaking_default)
+        )
+        .cyan(),
+        &breaking_default,
+    )?;
+    draft.breaking = Some(breaking).filter(|breaking| !breaking.is_empty());
+
+    let message = draft.message();
+    println!();
+    println!("{}", "Preview:".yellow().bold());
+    println!("{}", "─".repeat(50).dimmed());
+    for line in message.lines() {
+        println!("  {}", line);
+    }
+    println!("{}", "─".repeat(50).dimmed());
+
+    let issues = lint_commit_message(&message);
+    if !issues.is_empty() {
+        print_lint_issues(&issues);
+        return Err(anyhow!(
+            "Commit message breaks {} rule(s), nothing was committed",
+            issues.len()
+        ));
+    }
+    let action = if options.amend { "Amend" } else { "Commit" };
+    if !prompt::confirm(format!("{} with this message? (y/N): ", action).cyan())? {
+        println!("{}", "❌ Commit cancelled".yellow());
+        return Ok(());
+    }
+
+    let output = commit_with(&SystemRunner, &message, &options)?;
+    if !output.trim().is_empty() {
+        println!("{}", output.trim_end().dimmed());
+    }
+    println!("{}", format!("✅ {}", draft.header()).green().bold());
+    Ok(())
+}
+
+fn bracketed(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", value)
+    }
+}
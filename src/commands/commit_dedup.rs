@@ -0,0 +1,232 @@
+use git2::{Oid, Repository};
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+const CHERRY_PICK_TRAILER: &str = "(cherry picked from commit ";
+
+/// A change reverted before it was released. Both commits are left out of
+/// the categorized notes and listed under "Reverted" instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RevertedChange {
+    pub subject: String,
+    pub hash: String,
+    pub revert_hash: String,
+}
+
+/// What a revert commit points at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RevertTarget {
+    /// Full or abbreviated, from `This reverts commit ...` or a `Refs:` line
+    pub hashes: Vec<String>,
+    /// From `Revert "..."` or `revert: ...`
+    pub subject: Option<String>,
+}
+
+impl RevertTarget {
+    pub fn matches(&self, oid: Oid, subject: &str) -> bool {
+        let hash = oid.to_string();
+        self.hashes.iter().any(|prefix| hash.starts_with(prefix))
+            || self.subject.as_deref() == Some(subject.trim())
+    }
+}
+
+fn is_hash(token: &str) -> bool {
+    token.len() >= 7 && token.len() <= 40 && token.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Conventional `revert: subject` (also `revert(scope): ...`), None for
+/// other types.
+fn conventional_revert_subject(subject: &str) -> Option<&str> {
+    let (header, rest) = subject.split_once(':')?;
+    let kind = header.split(['(', '!']).next().unwrap_or(header);
+    kind.trim()
+        .eq_ignore_ascii_case("revert")
+        .then_some(rest.trim())
+}
+
+/// The target of a revert commit, as written by `git revert` or in the
+/// Conventional Commits style with `Refs: <hash>`. None for other commits.
+pub fn revert_target(message: &str) -> Option<RevertTarget> {
+    let subject = message.lines().next().unwrap_or("").trim();
+    let git_revert = subject
+        .strip_prefix("Revert \"")
+        .and_then(|rest| rest.strip_suffix('"'));
+    let conventional = conventional_revert_subject(subject);
+
+    let mut target = RevertTarget {
+        hashes: Vec::new(),
+        subject: git_revert.or(conventional).map(str::to_string),
+    };
+    for line in message.lines().skip(1).map(str::trim) {
+        if let Some(rest) = line.strip_prefix("This reverts commit ") {
+            let hash = rest.trim_end_matches('.').trim();
+            if is_hash(hash) {
+                target.hashes.push(hash.to_lowercase());
+            }
+        } else if let (Some(_), Some(refs)) = (conventional, line.strip_prefix("Refs:")) {
+            target.hashes.extend(
+                refs.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|token| is_hash(token))
+                    .map(str::to_lowercase),
+            );
+        }
+    }
+
+    (target.subject.is_some() || !target.hashes.is_empty()).then_some(target)
+}
+
+/// Commits named by `git cherry-pick -x` trailers.
+pub fn cherry_pick_sources(message: &str) -> Vec<Oid> {
+    message
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(CHERRY_PICK_TRAILER))
+        .map(|rest| rest.trim_end_matches(')').trim())
+        .filter(|hash| hash.len() == 40)
+        .filter_map(|hash| Oid::from_str(hash).ok())
+        .collect()
+}
+
+/// The message without cherry-pick trailers, so a pick and its source
+/// compare equal.
+fn message_key(message: &str) -> u64 {
+    let normalized: Vec<&str> = message
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim_start().starts_with(CHERRY_PICK_TRAILER))
+        .collect();
+    let mut hasher = DefaultHasher::new();
+    normalized.join("\n").trim().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Stable id of a commit's changes (`git patch-id`), None for merges.
+fn patch_id(repo: &Repository, oid: Oid) -> Option<Oid> {
+    let commit = repo.find_commit(oid).ok()?;
+    if commit.parent_count() > 1 {
+        return None;
+    }
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree().ok()?),
+        Err(_) => None,
+    };
+    let diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree().ok()?), None)
+        .ok()?;
+    diff.patchid(None).ok()
+}
+
+fn same_patch(repo: &Repository, a: Oid, b: Oid) -> bool {
+    matches!((patch_id(repo, a), patch_id(repo, b)), (Some(a), Some(b)) if a == b)
+}
+
+/// Commits of a release range that shouldn't be listed: reverts together
+/// with the change they undo, and cherry-picks of a change that is already
+/// listed or was already released.
+#[derive(Debug, Default)]
+pub struct RangeCleanup {
+    pub dropped: HashSet<Oid>,
+    /// One entry per revert and the change it undoes
+    pub reverted: Vec<RevertedChange>,
+    /// Cherry-picks left out, not part of `reverted`
+    pub duplicates: usize,
+    pending_reverts: Vec<(Oid, RevertTarget)>,
+    /// Message to the oldest commit with it seen so far
+    messages: HashMap<u64, Oid>,
+    /// Message to a commit only the previous release has
+    released: HashMap<u64, Oid>,
+}
+
+impl RangeCleanup {
+    /// Scans the commits reachable from `current` but not `previous`, and
+    /// the other way round for picks onto the previous release's branch.
+    pub fn scan(
+        repo: &Repository,
+        previous: Option<Oid>,
+        current: Oid,
+    ) -> Result<Self, git2::Error> {
+        let mut cleanup = Self::default();
+        if let Some(previous) = previous {
+            let mut revwalk = repo.revwalk()?;
+            revwalk.push(previous)?;
+            revwalk.hide(current)?;
+            for oid in revwalk {
+                let oid = oid?;
+                let message = repo.find_commit(oid)?.message().unwrap_or("").to_string();
+                cleanup.released.insert(message_key(&message), oid);
+            }
+        }
+
+        // Children before parents, so a revert is seen before its target
+        let mut revwalk = repo.revwalk()?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+        revwalk.push(current)?;
+        if let Some(previous) = previous {
+            revwalk.hide(previous)?;
+        }
+        for oid in revwalk {
+            cleanup.add(repo, oid?, previous)?;
+        }
+        Ok(cleanup)
+    }
+
+    fn add(
+        &mut self,
+        repo: &Repository,
+        oid: Oid,
+        previous: Option<Oid>,
+    ) -> Result<(), git2::Error> {
+        let commit = repo.find_commit(oid)?;
+        let message = commit.message().unwrap_or("");
+        let subject = message.lines().next().unwrap_or("").trim();
+
+        // A reverted revert isn't a revert anymore, its target stays
+        let reverts = self
+            .pending_reverts
+            .iter()
+            .filter(|(_, target)| target.matches(oid, subject))
+            .map(|(revert, _)| *revert)
+            .collect::<Vec<_>>();
+        if !reverts.is_empty() {
+            self.dropped.insert(oid);
+            for revert in reverts {
+                self.dropped.insert(revert);
+                self.reverted.push(RevertedChange {
+                    subject: subject.to_string(),
+                    hash: oid.to_string(),
+                    revert_hash: revert.to_string(),
+                });
+            }
+            return Ok(());
+        }
+        if let Some(target) = revert_target(message) {
+            self.pending_reverts.push((oid, target));
+            return Ok(());
+        }
+
+        let key = message_key(message);
+        let already_released = cherry_pick_sources(message).iter().any(|source| {
+            previous.is_some_and(|previous| {
+                *source == previous || repo.graph_descendant_of(previous, *source).unwrap_or(false)
+            })
+        }) || self
+            .released
+            .get(&key)
+            .is_some_and(|released| same_patch(repo, oid, *released));
+        if already_released {
+            self.dropped.insert(oid);
+            self.duplicates += 1;
+            return Ok(());
+        }
+
+        // The original is listed, the newer pick of it is left out
+        if let Some(newer) = self.messages.insert(key, oid) {
+            if !self.dropped.contains(&newer) && same_patch(repo, newer, oid) {
+                self.dropped.insert(newer);
+                self.duplicates += 1;
+            }
+        }
+        Ok(())
+    }
+}
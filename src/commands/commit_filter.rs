@@ -0,0 +1,63 @@
+use crate::commands::config::{load_saved_config, AppConfig};
+use crate::commands::release_notes::CommitInfo;
+
+/// Commits left out of release notes, `version auto` and the changelog of
+/// `create-release`. Matching is case-insensitive on substrings, so
+/// `dependabot` also matches `dependabot[bot] <49699333+dependabot[bot]@...>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommitFilter {
+    /// Matched against the author name and email
+    pub authors: Vec<String>,
+    /// Matched against the whole message, body included
+    pub patterns: Vec<String>,
+}
+
+fn normalized(values: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for value in values.iter().map(|value| value.trim().to_lowercase()) {
+        if !value.is_empty() && !normalized.contains(&value) {
+            normalized.push(value);
+        }
+    }
+    normalized
+}
+
+impl CommitFilter {
+    pub fn new(authors: &[String], patterns: &[String]) -> Self {
+        Self {
+            authors: normalized(authors),
+            patterns: normalized(patterns),
+        }
+    }
+
+    /// The configured excludes plus the ones given on the command line.
+    pub fn from_config(config: &AppConfig, authors: &[String], patterns: &[String]) -> Self {
+        Self::new(
+            &[config.release_exclude_authors.as_slice(), authors].concat(),
+            &[config.release_exclude_patterns.as_slice(), patterns].concat(),
+        )
+    }
+
+    pub fn excludes(&self, author_name: &str, author_email: &str, message: &str) -> bool {
+        let name = author_name.to_lowercase();
+        let email = author_email.to_lowercase();
+        let message = message.to_lowercase();
+        self.authors
+            .iter()
+            .any(|author| name.contains(author) || email.contains(author))
+            || self
+                .patterns
+                .iter()
+                .any(|pattern| message.contains(pattern))
+    }
+
+    pub fn excludes_commit(&self, commit: &CommitInfo) -> bool {
+        self.excludes(&commit.author_name, &commit.author_email, &commit.message)
+    }
+}
+
+/// [`CommitFilter::from_config`] with the saved config, or only the given
+/// excludes when there is none.
+pub async fn load_commit_filter(authors: &[String], patterns: &[String]) -> CommitFilter {
+    CommitFilter::from_config(&load_saved_config().await, authors, patterns)
+}
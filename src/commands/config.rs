@@ -1,9 +1,36 @@
+use crate::commands::branches::DEFAULT_PROTECTED_BRANCHES;
+use crate::commands::dependency_update::UpdateRules;
+use crate::commands::release_hosting::{ReleaseHost, BITBUCKET_PASSWORD_NAME, GITLAB_TOKEN_NAME};
+use crate::utils::document_locale::{DocumentLocale, DocumentTimezone};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::secrets::{self, SecretStorage};
+use crate::utils::theme::Theme;
 use anyhow::{anyhow, Result};
 use colored::*;
 use serde::{Deserialize, Serialize};
 use sqlx::{sqlite::SqlitePool, Pool, Row, Sqlite};
-use std::io::{self, Write};
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Settings that are missing or don't make sense, exit code 2.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("{provider} API key not configured, run 'nitroterm config' or set {env}")]
+    MissingApiKey {
+        provider: &'static str,
+        env: &'static str,
+    },
+    #[error("{0}")]
+    Invalid(String),
+}
+
+impl ConfigError {
+    pub fn invalid(error: anyhow::Error) -> Self {
+        ConfigError::Invalid(error.to_string())
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -12,6 +39,279 @@ pub struct AppConfig {
     pub translation_delay_seconds: u64,
     pub messages_dir: String,
     pub source_file: String,
+    #[serde(default)]
+    pub templates: HashMap<String, String>,
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// Committer identity `git setup-identity` applies
+    #[serde(default)]
+    pub git_identity: Option<IdentityProfile>,
+    #[serde(default = "default_translation_provider")]
+    pub translation_provider: String,
+    #[serde(default)]
+    pub openai_api_key: Option<String>,
+    #[serde(default = "default_openai_model")]
+    pub openai_model: String,
+    #[serde(default)]
+    pub anthropic_api_key: Option<String>,
+    #[serde(default = "default_anthropic_model")]
+    pub anthropic_model: String,
+    /// Provider to switch to when the primary one fails during translation sync
+    #[serde(default)]
+    pub translation_fallback_provider: Option<String>,
+    /// Retries of a provider request on rate limits (429) and server errors
+    #[serde(default = "default_translation_max_retries")]
+    pub translation_max_retries: u32,
+    /// Languages translated at the same time during translation sync
+    #[serde(default = "default_translation_concurrency")]
+    pub translation_concurrency: usize,
+    /// Where new API keys go: plain, keychain or encrypted
+    #[serde(default = "default_secret_storage")]
+    pub secret_storage: String,
+    /// Default label file for `github-labels`
+    #[serde(default)]
+    pub labels_file: Option<String>,
+    /// `gh` (CLI) or `api` (REST with GITHUB_TOKEN) for `github-labels`
+    #[serde(default = "default_github_backend")]
+    pub github_backend: String,
+    /// Language of generated documents (release notes, reports): en, tr, de
+    #[serde(default = "default_document_locale")]
+    pub document_locale: String,
+    /// `utc`, `local` or an offset like `+03:00` for dates in generated
+    /// documents and logs
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    /// Slack, Discord, Teams or generic webhooks for `maintain` summaries
+    #[serde(default)]
+    pub notify_webhooks: Vec<String>,
+    /// Repositories registered for `org` commands
+    #[serde(default)]
+    pub org_projects: Vec<String>,
+    /// Key for `create-release --sign`; git's `user.signingkey` when unset
+    #[serde(default)]
+    pub release_signing_key: Option<String>,
+    /// Branch release tags must be on for `verify-release` (default branch when unset)
+    #[serde(default)]
+    pub release_branch: Option<String>,
+    /// Self-hosted GitLab instance, e.g. `https://gitlab.example.com`
+    #[serde(default)]
+    pub gitlab_url: Option<String>,
+    /// Token for `create-release --publish` on GitLab, plain or protected
+    #[serde(default)]
+    pub gitlab_token: Option<String>,
+    #[serde(default)]
+    pub bitbucket_username: Option<String>,
+    /// App password for `create-release --publish` on Bitbucket, plain or protected
+    #[serde(default)]
+    pub bitbucket_app_password: Option<String>,
+    /// Commit authors (name or email) left out of release notes and `version auto`
+    #[serde(default)]
+    pub release_exclude_authors: Vec<String>,
+    /// Commit message patterns like `chore(deps)` or `[skip changelog]`, left out the same way
+    #[serde(default)]
+    pub release_exclude_patterns: Vec<String>,
+    /// Whether the interactive menu looks for a newer nitroterm release
+    #[serde(default = "default_update_check")]
+    pub update_check: bool,
+    #[serde(default = "default_update_check_interval")]
+    pub update_check_interval_hours: u64,
+    /// `stable` or `prerelease`
+    #[serde(default = "default_update_channel")]
+    pub update_channel: String,
+    /// `owner/name` used when the current repository has no GitHub remote
+    #[serde(default)]
+    pub github_repo: Option<String>,
+    /// Organization used when a command needs one and none is given, e.g. `new`
+    #[serde(default)]
+    pub github_org: Option<String>,
+    /// `full` or `summary` (same as `release-notes --summary-only`)
+    #[serde(default = "default_release_notes_format")]
+    pub release_notes_format: String,
+    /// Checks `code-quality` runs without --checks or --config; the built-in set when empty
+    #[serde(default)]
+    pub quality_checks: Vec<String>,
+    /// Packages `update-dependencies` skips or holds at a version
+    #[serde(default)]
+    pub dependency_update: UpdateRules,
+    /// Branch patterns `branches cleanup` never deletes, `*` as wildcard
+    #[serde(default = "default_protected_branches")]
+    pub protected_branches: Vec<String>,
+    /// Named sets of settings picked with `--profile` or NITROTERM_PROFILE
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ConfigProfile>,
+}
+
+/// Picks a profile when `--profile` isn't given.
+pub const PROFILE_ENV: &str = "NITROTERM_PROFILE";
+
+/// Settings a profile can hold: API keys, the GitHub repository,
+/// organization and label set, the `org` projects, the release and release
+/// hosting settings, and the git identity. Everything else is shared by all
+/// profiles.
+pub const PROFILE_KEYS: [&str; 19] = [
+    "translation_provider",
+    "gemini_api_key",
+    "openai_api_key",
+    "anthropic_api_key",
+    "github_repo",
+    "github_org",
+    "github_backend",
+    "labels_file",
+    "org_projects",
+    "release_signing_key",
+    "release_branch",
+    "release_notes_format",
+    "release_exclude_authors",
+    "release_exclude_patterns",
+    "gitlab_url",
+    "gitlab_token",
+    "bitbucket_username",
+    "bitbucket_app_password",
+    "git_identity",
+];
+
+/// Settings used instead of the main ones while the profile is active,
+/// keyed like `PROFILE_KEYS`. Keys it doesn't have come from the main config.
+pub type ConfigProfile = BTreeMap<String, serde_json::Value>;
+
+static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+pub fn active_profile() -> Option<&'static str> {
+    ACTIVE_PROFILE.get().map(String::as_str)
+}
+
+/// `config` with the settings of `profile` in place of its own.
+pub fn apply_profile(config: &AppConfig, profile: &ConfigProfile) -> AppConfig {
+    let Ok(mut value) = serde_json::to_value(config) else {
+        return config.clone();
+    };
+    for (key, setting) in profile {
+        if PROFILE_KEYS.contains(&key.as_str()) {
+            value[key] = setting.clone();
+        }
+    }
+    serde_json::from_value(value).unwrap_or_else(|_| config.clone())
+}
+
+/// Every setting a profile can hold, as `config` has it.
+pub fn profile_of(config: &AppConfig) -> ConfigProfile {
+    let value = serde_json::to_value(config).unwrap_or_default();
+    PROFILE_KEYS
+        .iter()
+        .map(|key| (key.to_string(), value[*key].clone()))
+        .collect()
+}
+
+/// What `profile` holds once `config` is saved while it's active: the
+/// settings it had, with their new values, and those that now differ from
+/// the main config `base`.
+pub fn updated_profile(
+    profile: &ConfigProfile,
+    base: &AppConfig,
+    config: &AppConfig,
+) -> ConfigProfile {
+    let base = profile_of(base);
+    profile_of(config)
+        .into_iter()
+        .filter(|(key, value)| profile.contains_key(key) || base.get(key) != Some(value))
+        .collect()
+}
+
+/// Profile names end up in keychain entries, so they stay simple.
+pub fn check_profile_name(name: &str) -> Result<(), ConfigError> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(())
+    } else {
+        Err(ConfigError::Invalid(format!(
+            "Invalid profile name '{}', use letters, digits, - and _",
+            name
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdentityProfile {
+    pub name: String,
+    pub email: String,
+    pub signing_key: Option<String>,
+    pub signing_format: Option<String>,
+}
+
+fn default_theme() -> String {
+    "default".to_string()
+}
+
+fn default_translation_provider() -> String {
+    "gemini".to_string()
+}
+
+fn default_openai_model() -> String {
+    "gpt-4o-mini".to_string()
+}
+
+fn default_document_locale() -> String {
+    "en".to_string()
+}
+
+fn default_timezone() -> String {
+    "utc".to_string()
+}
+
+fn default_github_backend() -> String {
+    "gh".to_string()
+}
+
+fn default_anthropic_model() -> String {
+    "claude-3-5-haiku-latest".to_string()
+}
+
+fn default_translation_max_retries() -> u32 {
+    3
+}
+
+fn default_translation_concurrency() -> usize {
+    4
+}
+
+fn default_secret_storage() -> String {
+    "plain".to_string()
+}
+
+fn default_update_check() -> bool {
+    true
+}
+
+fn default_update_check_interval() -> u64 {
+    24
+}
+
+fn default_update_channel() -> String {
+    "stable".to_string()
+}
+
+fn default_protected_branches() -> Vec<String> {
+    DEFAULT_PROTECTED_BRANCHES
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+fn default_release_notes_format() -> String {
+    "full".to_string()
+}
+
+/// A stored API key for display: the start of a plain key, or where a
+/// protected one is kept.
+fn mask_api_key(stored: &str) -> String {
+    match SecretStorage::of(stored) {
+        SecretStorage::Plain => format!("{}***", &stored[..std::cmp::min(8, stored.len())]),
+        SecretStorage::Keychain => "(keychain)".to_string(),
+        SecretStorage::Encrypted => "(encrypted)".to_string(),
+    }
 }
 
 impl Default for AppConfig {
@@ -22,6 +322,42 @@ impl Default for AppConfig {
             translation_delay_seconds: 2,
             messages_dir: "messages".to_string(),
             source_file: "source.json".to_string(),
+            templates: HashMap::new(),
+            theme: default_theme(),
+            git_identity: None,
+            translation_provider: default_translation_provider(),
+            openai_api_key: None,
+            openai_model: default_openai_model(),
+            anthropic_api_key: None,
+            anthropic_model: default_anthropic_model(),
+            translation_fallback_provider: None,
+            translation_max_retries: default_translation_max_retries(),
+            translation_concurrency: default_translation_concurrency(),
+            secret_storage: default_secret_storage(),
+            labels_file: None,
+            github_backend: default_github_backend(),
+            document_locale: default_document_locale(),
+            timezone: default_timezone(),
+            notify_webhooks: Vec::new(),
+            org_projects: Vec::new(),
+            release_signing_key: None,
+            release_branch: None,
+            gitlab_url: None,
+            gitlab_token: None,
+            bitbucket_username: None,
+            bitbucket_app_password: None,
+            release_exclude_authors: Vec::new(),
+            release_exclude_patterns: Vec::new(),
+            update_check: default_update_check(),
+            update_check_interval_hours: default_update_check_interval(),
+            update_channel: default_update_channel(),
+            github_repo: None,
+            github_org: None,
+            release_notes_format: default_release_notes_format(),
+            quality_checks: Vec::new(),
+            dependency_update: UpdateRules::default(),
+            protected_branches: default_protected_branches(),
+            profiles: BTreeMap::new(),
         }
     }
 }
@@ -33,6 +369,32 @@ pub struct ConfigManager {
 
 impl ConfigManager {
     pub async fn new() -> Result<Self> {
+        Self::connect(true).await
+    }
+
+    /// Same as `new`, without printing the config locations.
+    pub async fn new_quiet() -> Result<Self> {
+        Self::connect(false).await
+    }
+
+    /// Same as `new_quiet`, but `None` while there is no config database,
+    /// so reading settings (on startup, for `--help`) never creates one.
+    pub async fn open_existing() -> Result<Option<Self>> {
+        for dir in Self::config_dir_candidates()? {
+            if dir.join("nitroterm.db").exists() {
+                return Self::new_quiet().await.map(Some);
+            }
+            // The one `get_config_dir` settles on, without a database yet
+            if dir.exists() {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    async fn connect(verbose: bool) -> Result<Self> {
+        ensure_not_sandboxed("Configuration storage")?;
+
         let config_dir = Self::get_config_dir()?;
 
         // Ensure config directory exists
@@ -56,14 +418,16 @@ impl ConfigManager {
 
         let database_url = format!("sqlite:{}?mode=rwc", db_path.display());
 
-        println!(
-            "{}",
-            format!("📁 Config directory: {}", config_dir.display()).dimmed()
-        );
-        println!(
-            "{}",
-            format!("🗄️  Database path: {}", db_path.display()).dimmed()
-        );
+        if verbose {
+            println!(
+                "{}",
+                format!("📁 Config directory: {}", config_dir.display()).dimmed()
+            );
+            println!(
+                "{}",
+                format!("🗄️  Database path: {}", db_path.display()).dimmed()
+            );
+        }
 
         let pool = match SqlitePool::connect(&database_url).await {
             Ok(pool) => pool,
@@ -115,31 +479,30 @@ impl ConfigManager {
     }
 
     pub fn get_config_dir() -> Result<PathBuf> {
-        // Try multiple fallback locations
+        // The first writable location, the temp directory as a last resort
+        let mut candidates = Self::config_dir_candidates()?;
+        let temp_dir = candidates
+            .pop()
+            .unwrap_or_else(|| std::env::temp_dir().join("nitroterm"));
+        Ok(candidates
+            .into_iter()
+            .find(Self::test_directory_writable)
+            .unwrap_or(temp_dir))
+    }
+
+    /// Where the config directory can be, in order of preference: home,
+    /// XDG_CONFIG_HOME, the current directory and the temp directory.
+    fn config_dir_candidates() -> Result<Vec<PathBuf>> {
+        let mut candidates = Vec::new();
         if let Some(home_dir) = dirs::home_dir() {
-            let config_dir = home_dir.join(".config").join("nitroterm");
-            if Self::test_directory_writable(&config_dir) {
-                return Ok(config_dir);
-            }
+            candidates.push(home_dir.join(".config").join("nitroterm"));
         }
-
-        // Fallback 1: XDG_CONFIG_HOME
         if let Ok(xdg_config) = std::env::var("XDG_CONFIG_HOME") {
-            let config_dir = PathBuf::from(xdg_config).join("nitroterm");
-            if Self::test_directory_writable(&config_dir) {
-                return Ok(config_dir);
-            }
-        }
-
-        // Fallback 2: Current directory
-        let current_dir = std::env::current_dir()?.join(".nitroterm");
-        if Self::test_directory_writable(&current_dir) {
-            return Ok(current_dir);
+            candidates.push(PathBuf::from(xdg_config).join("nitroterm"));
         }
-
-        // Fallback 3: Temp directory
-        let temp_dir = std::env::temp_dir().join("nitroterm");
-        Ok(temp_dir)
+        candidates.push(std::env::current_dir()?.join(".nitroterm"));
+        candidates.push(std::env::temp_dir().join("nitroterm"));
+        Ok(candidates)
     }
 
     pub fn test_directory_writable(dir: &PathBuf) -> bool {
@@ -158,7 +521,19 @@ impl ConfigManager {
         false
     }
 
+    /// The saved config, with the settings of the active profile.
     pub async fn get_config(&self) -> Result<AppConfig> {
+        let config = self.get_main_config().await?;
+        Ok(
+            match active_profile().and_then(|name| config.profiles.get(name)) {
+                Some(profile) => apply_profile(&config, profile),
+                None => config,
+            },
+        )
+    }
+
+    /// The saved config as it is without a profile.
+    pub async fn get_main_config(&self) -> Result<AppConfig> {
         let mut config = AppConfig::default();
 
         // Load from database using runtime queries
@@ -178,17 +553,85 @@ impl ConfigManager {
             let value: String = row.get("value");
 
             match key.as_str() {
-                "gemini_api_key" => {
-                    if !value.is_empty() {
-                        config.gemini_api_key = Some(value);
-                    }
-                }
+                "gemini_api_key" if !value.is_empty() => config.gemini_api_key = Some(value),
                 "gemini_model" => config.gemini_model = value,
                 "translation_delay_seconds" => {
                     config.translation_delay_seconds = value.parse().unwrap_or(2);
                 }
                 "messages_dir" => config.messages_dir = value,
                 "source_file" => config.source_file = value,
+                "templates" => config.templates = serde_json::from_str(&value).unwrap_or_default(),
+                "theme" => config.theme = value,
+                "git_identity" => {
+                    config.git_identity = serde_json::from_str(&value).unwrap_or_default()
+                }
+                "translation_provider" => config.translation_provider = value,
+                "openai_api_key" if !value.is_empty() => config.openai_api_key = Some(value),
+                "openai_model" => config.openai_model = value,
+                "anthropic_api_key" if !value.is_empty() => config.anthropic_api_key = Some(value),
+                "anthropic_model" => config.anthropic_model = value,
+                "translation_fallback_provider" if !value.is_empty() => {
+                    config.translation_fallback_provider = Some(value)
+                }
+                "translation_max_retries" => {
+                    config.translation_max_retries =
+                        value.parse().unwrap_or(default_translation_max_retries())
+                }
+                "translation_concurrency" => {
+                    config.translation_concurrency =
+                        value.parse().unwrap_or(default_translation_concurrency())
+                }
+                "secret_storage" => config.secret_storage = value,
+                "labels_file" if !value.is_empty() => config.labels_file = Some(value),
+                "github_backend" => config.github_backend = value,
+                "document_locale" => config.document_locale = value,
+                "timezone" => config.timezone = value,
+                "notify_webhooks" => {
+                    config.notify_webhooks = serde_json::from_str(&value).unwrap_or_default()
+                }
+                "org_projects" => {
+                    config.org_projects = serde_json::from_str(&value).unwrap_or_default()
+                }
+                "release_exclude_authors" => {
+                    config.release_exclude_authors =
+                        serde_json::from_str(&value).unwrap_or_default()
+                }
+                "release_exclude_patterns" => {
+                    config.release_exclude_patterns =
+                        serde_json::from_str(&value).unwrap_or_default()
+                }
+                "release_signing_key" if !value.is_empty() => {
+                    config.release_signing_key = Some(value)
+                }
+                "release_branch" if !value.is_empty() => config.release_branch = Some(value),
+                "gitlab_url" if !value.is_empty() => config.gitlab_url = Some(value),
+                "gitlab_token" if !value.is_empty() => config.gitlab_token = Some(value),
+                "bitbucket_username" if !value.is_empty() => {
+                    config.bitbucket_username = Some(value)
+                }
+                "bitbucket_app_password" if !value.is_empty() => {
+                    config.bitbucket_app_password = Some(value)
+                }
+                "update_check" => config.update_check = value != "false",
+                "update_check_interval_hours" => {
+                    config.update_check_interval_hours =
+                        value.parse().unwrap_or(default_update_check_interval())
+                }
+                "update_channel" => config.update_channel = value,
+                "github_repo" if !value.is_empty() => config.github_repo = Some(value),
+                "github_org" if !value.is_empty() => config.github_org = Some(value),
+                "release_notes_format" => config.release_notes_format = value,
+                "quality_checks" => {
+                    config.quality_checks = serde_json::from_str(&value).unwrap_or_default()
+                }
+                "dependency_update" => {
+                    config.dependency_update = serde_json::from_str(&value).unwrap_or_default()
+                }
+                "protected_branches" => {
+                    config.protected_branches = serde_json::from_str(&value)
+                        .unwrap_or_else(|_| default_protected_branches())
+                }
+                "profiles" => config.profiles = serde_json::from_str(&value).unwrap_or_default(),
                 _ => {}
             }
         }
@@ -196,8 +639,52 @@ impl ConfigManager {
         Ok(config)
     }
 
+    /// Saves `config`. While a profile is active, the settings it can hold
+    /// go to the profile and the main ones stay as they were.
     pub async fn save_config(&self, config: &AppConfig) -> Result<()> {
+        let Some(name) = active_profile().filter(|name| config.profiles.contains_key(*name)) else {
+            return self.write_config(config).await;
+        };
+        let base = self.get_main_config().await?;
+        let profile = updated_profile(&config.profiles[name], &base, config);
+        let mut main = apply_profile(config, &profile_of(&base));
+        main.profiles.insert(name.to_string(), profile);
+        self.write_config(&main).await
+    }
+
+    /// Sets one setting of profile `name`, creating the profile if needed.
+    pub async fn set_profile_setting(
+        &self,
+        name: &str,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<()> {
+        check_profile_name(name)?;
+        let mut config = self.get_main_config().await?;
+        config
+            .profiles
+            .entry(name.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+        self.write_config(&config).await
+    }
+
+    async fn write_config(&self, config: &AppConfig) -> Result<()> {
         let delay_string = config.translation_delay_seconds.to_string();
+        let templates_json = serde_json::to_string(&config.templates)?;
+        let identity_json = serde_json::to_string(&config.git_identity)?;
+        let webhooks_json = serde_json::to_string(&config.notify_webhooks)?;
+        let org_projects_json = serde_json::to_string(&config.org_projects)?;
+        let exclude_authors_json = serde_json::to_string(&config.release_exclude_authors)?;
+        let exclude_patterns_json = serde_json::to_string(&config.release_exclude_patterns)?;
+        let update_check_string = config.update_check.to_string();
+        let update_interval_string = config.update_check_interval_hours.to_string();
+        let max_retries_string = config.translation_max_retries.to_string();
+        let concurrency_string = config.translation_concurrency.to_string();
+        let quality_checks_json = serde_json::to_string(&config.quality_checks)?;
+        let dependency_update_json = serde_json::to_string(&config.dependency_update)?;
+        let protected_branches_json = serde_json::to_string(&config.protected_branches)?;
+        let config_profiles_json = serde_json::to_string(&config.profiles)?;
         let config_items = vec![
             (
                 "gemini_api_key",
@@ -207,6 +694,66 @@ impl ConfigManager {
             ("translation_delay_seconds", &delay_string),
             ("messages_dir", &config.messages_dir),
             ("source_file", &config.source_file),
+            ("templates", &templates_json),
+            ("theme", &config.theme),
+            ("git_identity", &identity_json),
+            ("translation_provider", &config.translation_provider),
+            (
+                "openai_api_key",
+                config.openai_api_key.as_deref().unwrap_or(""),
+            ),
+            ("openai_model", &config.openai_model),
+            (
+                "anthropic_api_key",
+                config.anthropic_api_key.as_deref().unwrap_or(""),
+            ),
+            ("anthropic_model", &config.anthropic_model),
+            (
+                "translation_fallback_provider",
+                config
+                    .translation_fallback_provider
+                    .as_deref()
+                    .unwrap_or(""),
+            ),
+            ("translation_max_retries", &max_retries_string),
+            ("translation_concurrency", &concurrency_string),
+            ("secret_storage", &config.secret_storage),
+            ("labels_file", config.labels_file.as_deref().unwrap_or("")),
+            ("github_backend", &config.github_backend),
+            ("document_locale", &config.document_locale),
+            ("timezone", &config.timezone),
+            ("notify_webhooks", &webhooks_json),
+            ("org_projects", &org_projects_json),
+            (
+                "release_signing_key",
+                config.release_signing_key.as_deref().unwrap_or(""),
+            ),
+            (
+                "release_branch",
+                config.release_branch.as_deref().unwrap_or(""),
+            ),
+            ("gitlab_url", config.gitlab_url.as_deref().unwrap_or("")),
+            ("gitlab_token", config.gitlab_token.as_deref().unwrap_or("")),
+            (
+                "bitbucket_username",
+                config.bitbucket_username.as_deref().unwrap_or(""),
+            ),
+            (
+                "bitbucket_app_password",
+                config.bitbucket_app_password.as_deref().unwrap_or(""),
+            ),
+            ("release_exclude_authors", &exclude_authors_json),
+            ("release_exclude_patterns", &exclude_patterns_json),
+            ("update_check", &update_check_string),
+            ("update_check_interval_hours", &update_interval_string),
+            ("update_channel", &config.update_channel),
+            ("github_repo", config.github_repo.as_deref().unwrap_or("")),
+            ("github_org", config.github_org.as_deref().unwrap_or("")),
+            ("release_notes_format", &config.release_notes_format),
+            ("quality_checks", &quality_checks_json),
+            ("dependency_update", &dependency_update_json),
+            ("protected_branches", &protected_branches_json),
+            ("profiles", &config_profiles_json),
         ];
 
         for (key, value) in config_items {
@@ -251,9 +798,15 @@ impl ConfigManager {
 
         // Gemini API Key
         println!("{}", "🔑 Gemini API Configuration".yellow().bold());
-        config.gemini_api_key = self
-            .prompt_for_api_key(config.gemini_api_key.as_deref())
-            .await?;
+        let current_key = config.gemini_api_key.clone();
+        config.gemini_api_key = match self.prompt_for_api_key(current_key.as_deref()).await? {
+            Some(key) if Some(&key) != current_key.as_ref() => Some(secrets::protect(
+                "gemini_api_key",
+                &key,
+                config.secret_storage.parse().unwrap_or_default(),
+            )?),
+            key => key,
+        };
 
         // Gemini Model
         config.gemini_model = self.prompt_for_model(&config.gemini_model).await?;
@@ -304,20 +857,15 @@ impl ConfigManager {
             }
         }
 
-        let prompt = if let Some(current_key) = current {
-            let masked = format!("{}***", &current_key[..std::cmp::min(8, current_key.len())]);
-            format!("Gemini API Key [current: {}]: ", masked)
+        let input = if let Some(current_key) = current {
+            prompt::ask(
+                format!("Gemini API Key [current: {}]: ", mask_api_key(current_key)).cyan(),
+                "",
+            )?
         } else {
-            "Gemini API Key (required): ".to_string()
+            prompt::ask_required("Gemini API Key (required): ".cyan(), "set GEMINI_API_KEY")?
         };
 
-        print!("{}", prompt.cyan());
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
-
         if input.is_empty() {
             if let Some(current_key) = current {
                 println!("{}", "✅ Keeping current API key".green());
@@ -342,12 +890,8 @@ impl ConfigManager {
             "2.".dimmed(),
         );
 
-        print!("{}", format!("Model [current: {}]: ", current).cyan());
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
+        let input = prompt::ask(format!("Model [current: {}]: ", current).cyan(), "")?;
+        let input = input.as_str();
 
         if input.is_empty() {
             return Ok(current.to_string());
@@ -367,15 +911,11 @@ impl ConfigManager {
     }
 
     async fn prompt_for_delay(&self, current: u64) -> Result<u64> {
-        print!(
-            "{}",
-            format!("Delay between API calls (seconds) [current: {}]: ", current).cyan()
-        );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
+        let input = prompt::ask(
+            format!("Delay between API calls (seconds) [current: {}]: ", current).cyan(),
+            "",
+        )?;
+        let input = input.as_str();
 
         if input.is_empty() {
             return Ok(current);
@@ -395,15 +935,11 @@ impl ConfigManager {
     }
 
     async fn prompt_for_messages_dir(&self, current: &str) -> Result<String> {
-        print!(
-            "{}",
-            format!("Messages directory [current: {}]: ", current).cyan()
-        );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
+        let input = prompt::ask(
+            format!("Messages directory [current: {}]: ", current).cyan(),
+            "",
+        )?;
+        let input = input.as_str();
 
         if input.is_empty() {
             return Ok(current.to_string());
@@ -418,15 +954,11 @@ impl ConfigManager {
     }
 
     async fn prompt_for_source_file(&self, current: &str) -> Result<String> {
-        print!(
-            "{}",
-            format!("Source file name [current: {}]: ", current).cyan()
-        );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
+        let input = prompt::ask(
+            format!("Source file name [current: {}]: ", current).cyan(),
+            "",
+        )?;
+        let input = input.as_str();
 
         if input.is_empty() {
             return Ok(current.to_string());
@@ -444,29 +976,227 @@ impl ConfigManager {
 
         println!("{}", "⚙️  Current Configuration".cyan().bold());
         println!("{}", "═".repeat(40).dimmed());
+        if let Some(profile) = active_profile() {
+            println!("{}: {}", "Profile".yellow(), profile.green().bold());
+        }
 
-        let api_key_display = match &config.gemini_api_key {
-            Some(key) => format!("{}***", &key[..std::cmp::min(8, key.len())]),
+        let mask = |key: &Option<String>| match key {
+            Some(key) => mask_api_key(key),
             None => "Not set".red().to_string(),
         };
 
-        println!("{}: {}", "Gemini API Key".yellow(), api_key_display);
+        println!(
+            "{}: {}",
+            "Translation Provider".yellow(),
+            config.translation_provider.green()
+        );
+        if let Some(fallback) = &config.translation_fallback_provider {
+            println!("{}: {}", "Fallback Provider".yellow(), fallback.green());
+        }
+        println!(
+            "{}: {}",
+            "Gemini API Key".yellow(),
+            mask(&config.gemini_api_key)
+        );
         println!(
             "{}: {}",
             "Gemini Model".yellow(),
             config.gemini_model.green()
         );
+        if config.translation_provider == "openai" || config.openai_api_key.is_some() {
+            println!(
+                "{}: {}",
+                "OpenAI API Key".yellow(),
+                mask(&config.openai_api_key)
+            );
+            println!(
+                "{}: {}",
+                "OpenAI Model".yellow(),
+                config.openai_model.green()
+            );
+        }
+        if config.translation_provider == "anthropic" || config.anthropic_api_key.is_some() {
+            println!(
+                "{}: {}",
+                "Anthropic API Key".yellow(),
+                mask(&config.anthropic_api_key)
+            );
+            println!(
+                "{}: {}",
+                "Anthropic Model".yellow(),
+                config.anthropic_model.green()
+            );
+        }
         println!(
             "{}: {}",
             "Delay (seconds)".yellow(),
             config.translation_delay_seconds.to_string().green()
         );
+        println!(
+            "{}: {}",
+            "Max Retries".yellow(),
+            config.translation_max_retries.to_string().green()
+        );
+        println!(
+            "{}: {}",
+            "Concurrent Languages".yellow(),
+            config.translation_concurrency.to_string().green()
+        );
+        println!(
+            "{}: {}",
+            "Secret Storage".yellow(),
+            config.secret_storage.green()
+        );
         println!(
             "{}: {}",
             "Messages Directory".yellow(),
             config.messages_dir.green()
         );
         println!("{}: {}", "Source File".yellow(), config.source_file.green());
+        println!("{}: {}", "Theme".yellow(), config.theme.green());
+        if let Some(labels_file) = &config.labels_file {
+            println!("{}: {}", "Labels File".yellow(), labels_file.green());
+        }
+        println!(
+            "{}: {}",
+            "GitHub Backend".yellow(),
+            config.github_backend.green()
+        );
+        println!(
+            "{}: {}",
+            "Document Locale".yellow(),
+            config.document_locale.green()
+        );
+        println!("{}: {}", "Timezone".yellow(), config.timezone.green());
+        if !config.notify_webhooks.is_empty() {
+            // Webhook URLs carry their credentials, so only the hosts are shown
+            let hosts: Vec<String> = config
+                .notify_webhooks
+                .iter()
+                .map(|url| crate::utils::notify::webhook_host(url))
+                .collect();
+            println!(
+                "{}: {}",
+                "Notification Webhooks".yellow(),
+                hosts.join(", ").green()
+            );
+        }
+        if !config.org_projects.is_empty() {
+            println!(
+                "{}: {}",
+                "Org Projects".yellow(),
+                config.org_projects.len().to_string().green()
+            );
+        }
+        if let Some(key) = &config.release_signing_key {
+            println!("{}: {}", "Release Signing Key".yellow(), key.green());
+        }
+        if let Some(branch) = &config.release_branch {
+            println!("{}: {}", "Release Branch".yellow(), branch.green());
+        }
+        if config.gitlab_token.is_some() || config.gitlab_url.is_some() {
+            println!(
+                "{}: {} {}",
+                "GitLab Releases".yellow(),
+                config
+                    .gitlab_url
+                    .as_deref()
+                    .unwrap_or("https://gitlab.com")
+                    .green(),
+                config
+                    .gitlab_token
+                    .as_deref()
+                    .map(mask_api_key)
+                    .unwrap_or_else(|| "(no token)".to_string())
+                    .dimmed()
+            );
+        }
+        if let Some(username) = &config.bitbucket_username {
+            println!(
+                "{}: {} {}",
+                "Bitbucket Releases".yellow(),
+                username.green(),
+                config
+                    .bitbucket_app_password
+                    .as_deref()
+                    .map(mask_api_key)
+                    .unwrap_or_else(|| "(no app password)".to_string())
+                    .dimmed()
+            );
+        }
+        if !config.release_exclude_authors.is_empty() {
+            println!(
+                "{}: {}",
+                "Excluded Authors".yellow(),
+                config.release_exclude_authors.join(", ").green()
+            );
+        }
+        if !config.release_exclude_patterns.is_empty() {
+            println!(
+                "{}: {}",
+                "Excluded Patterns".yellow(),
+                config.release_exclude_patterns.join(", ").green()
+            );
+        }
+        let update_check = if config.update_check {
+            format!(
+                "every {}h, {} channel",
+                config.update_check_interval_hours, config.update_channel
+            )
+            .green()
+        } else {
+            "disabled".yellow()
+        };
+        println!("{}: {}", "Update Check".yellow(), update_check);
+        if let Some(repo) = &config.github_repo {
+            println!("{}: {}", "GitHub Repository".yellow(), repo.green());
+        }
+        if let Some(org) = &config.github_org {
+            println!("{}: {}", "GitHub Organization".yellow(), org.green());
+        }
+        println!(
+            "{}: {}",
+            "Release Notes Format".yellow(),
+            config.release_notes_format.green()
+        );
+        if !config.quality_checks.is_empty() {
+            println!(
+                "{}: {}",
+                "Quality Checks".yellow(),
+                config.quality_checks.join(", ").green()
+            );
+        }
+        if !config.dependency_update.is_empty() {
+            println!("{}:", "Dependency Update Rules".yellow());
+            for rule in config.dependency_update.describe() {
+                println!("  {}", rule.green());
+            }
+        }
+        if !config.protected_branches.is_empty() {
+            println!(
+                "{}: {}",
+                "Protected Branches".yellow(),
+                config.protected_branches.join(", ").green()
+            );
+        }
+
+        if !config.templates.is_empty() {
+            println!("{}:", "Project Templates".yellow());
+            let mut templates: Vec<_> = config.templates.iter().collect();
+            templates.sort();
+            for (name, url) in templates {
+                println!("  {} → {}", name.green(), url.dimmed());
+            }
+        }
+
+        if let Some(identity) = &config.git_identity {
+            println!(
+                "{}: {} <{}>",
+                "Git Identity".yellow(),
+                identity.name.green(),
+                identity.email.dimmed()
+            );
+        }
         println!();
         println!(
             "{}",
@@ -496,3 +1226,540 @@ impl ConfigManager {
         Ok(())
     }
 }
+
+/// `--locale` wins over the `document_locale` config key.
+/// The saved config, or the defaults when there is none (or in sandbox mode).
+pub async fn load_saved_config() -> AppConfig {
+    match ConfigManager::open_existing().await {
+        Ok(Some(manager)) => manager.get_config().await.unwrap_or_default(),
+        _ => AppConfig::default(),
+    }
+}
+
+pub async fn resolve_document_locale(flag: Option<&str>) -> Result<DocumentLocale> {
+    if let Some(locale) = flag {
+        return Ok(locale.parse().map_err(ConfigError::invalid)?);
+    }
+    let config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    Ok(config.document_locale.parse().unwrap_or_default())
+}
+
+pub async fn set_document_locale(name: &str) -> Result<()> {
+    let locale = name
+        .parse::<DocumentLocale>()
+        .map_err(ConfigError::invalid)?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.document_locale = locale.code().to_string();
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!("🌐 Document locale set to '{}'", locale.code()).green()
+    );
+    Ok(())
+}
+
+/// Uses the `timezone` config key for the rest of the run, or UTC when
+/// `utc` (`--utc`) is set or the key doesn't parse.
+pub async fn load_timezone(utc: bool) {
+    let timezone = if utc {
+        DocumentTimezone::Utc
+    } else {
+        load_saved_config()
+            .await
+            .timezone
+            .parse()
+            .unwrap_or_default()
+    };
+    crate::utils::document_locale::set_timezone(timezone);
+}
+
+pub async fn set_timezone(name: &str) -> Result<()> {
+    let timezone = name
+        .parse::<DocumentTimezone>()
+        .map_err(ConfigError::invalid)?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.timezone = timezone.to_string();
+    config_manager.save_config(&config).await?;
+
+    println!("{}", format!("🕒 Timezone set to '{}'", timezone).green());
+    Ok(())
+}
+
+/// Sets the release signing key and branch; an empty value clears one.
+pub async fn set_release_settings(signing_key: Option<&str>, branch: Option<&str>) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    let value = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+    if let Some(key) = signing_key {
+        config.release_signing_key = value(key);
+    }
+    if let Some(branch) = branch {
+        config.release_branch = value(branch);
+    }
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🔏 Release tags: signing key {}, branch {}",
+            config
+                .release_signing_key
+                .as_deref()
+                .unwrap_or("from git config"),
+            config.release_branch.as_deref().unwrap_or("default branch")
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Stores the GitLab token or the Bitbucket username and app password used
+/// by `create-release --publish`, with the secret kept like API keys.
+/// `clear` removes the host's settings.
+pub async fn set_release_host(
+    host: &str,
+    url: Option<&str>,
+    username: Option<&str>,
+    storage: Option<SecretStorage>,
+    clear: bool,
+) -> Result<()> {
+    let host = host.parse::<ReleaseHost>()?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+
+    let (secret_name, env_hint) = match host {
+        ReleaseHost::GitHub => {
+            return Err(anyhow!(
+                "GitHub releases use GITHUB_TOKEN or GH_TOKEN, nothing to store"
+            ))
+        }
+        ReleaseHost::GitLab => (GITLAB_TOKEN_NAME, "set GITLAB_TOKEN"),
+        ReleaseHost::Bitbucket => (BITBUCKET_PASSWORD_NAME, "set BITBUCKET_APP_PASSWORD"),
+    };
+    if url.is_some() && host != ReleaseHost::GitLab {
+        return Err(anyhow!("--url only applies to self-hosted GitLab"));
+    }
+    if username.is_some() && host != ReleaseHost::Bitbucket {
+        return Err(anyhow!("--username only applies to Bitbucket"));
+    }
+
+    let slot = match host {
+        ReleaseHost::Bitbucket => &mut config.bitbucket_app_password,
+        _ => &mut config.gitlab_token,
+    };
+    if clear {
+        if let Some(stored) = slot.take() {
+            secrets::forget(secret_name, &stored)?;
+        }
+        match host {
+            ReleaseHost::Bitbucket => config.bitbucket_username = None,
+            _ => config.gitlab_url = None,
+        }
+        config_manager.save_config(&config).await?;
+        println!(
+            "{}",
+            format!("🗑️  {} release settings removed", host.display_name()).green()
+        );
+        return Ok(());
+    }
+
+    let storage = match storage {
+        Some(storage) => storage,
+        None => config.secret_storage.parse()?,
+    };
+    let current = slot.clone();
+    let label = match host {
+        ReleaseHost::Bitbucket => "app password",
+        _ => "access token",
+    };
+    let answer = prompt::ask_secret(
+        format!(
+            "🔑 {} {}{}: ",
+            host.display_name(),
+            label,
+            if current.is_some() {
+                " [Enter keeps the current one]"
+            } else {
+                ""
+            }
+        )
+        .cyan(),
+        env_hint,
+    )?;
+    let secret = match (answer.is_empty(), &current) {
+        (false, _) => answer,
+        (true, Some(stored)) => secrets::reveal(secret_name, stored)?,
+        (true, None) => return Err(anyhow!("No {} entered", label)),
+    };
+    if let Some(stored) = &current {
+        if storage != SecretStorage::Keychain {
+            secrets::forget(secret_name, stored)?;
+        }
+    }
+    let stored = secrets::protect(secret_name, &secret, storage)?;
+
+    let value = |value: &str| {
+        Some(value.trim().trim_end_matches('/').to_string()).filter(|v| !v.is_empty())
+    };
+    match host {
+        ReleaseHost::Bitbucket => {
+            if let Some(username) = username {
+                config.bitbucket_username = value(username);
+            }
+            if config.bitbucket_username.is_none() {
+                return Err(anyhow!(
+                    "Pass --username, the Bitbucket account of the app password"
+                ));
+            }
+            config.bitbucket_app_password = Some(stored);
+        }
+        _ => {
+            if let Some(url) = url {
+                config.gitlab_url = value(url);
+            }
+            config.gitlab_token = Some(stored);
+        }
+    }
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🔑 {} {} stored ({}), used by `create-release --publish`",
+            host.display_name(),
+            label,
+            storage.name()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Adds authors and message patterns skipped by release notes, `version
+/// auto` and `create-release`, or removes them all with `clear`.
+pub async fn set_release_excludes(
+    authors: &[String],
+    patterns: &[String],
+    clear: bool,
+) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    if clear {
+        config.release_exclude_authors.clear();
+        config.release_exclude_patterns.clear();
+    }
+    for (values, target) in [
+        (authors, &mut config.release_exclude_authors),
+        (patterns, &mut config.release_exclude_patterns),
+    ] {
+        for value in values.iter().map(|value| value.trim()) {
+            if !value.is_empty() && !target.iter().any(|existing| existing == value) {
+                target.push(value.to_string());
+            }
+        }
+    }
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🧹 Release notes skip {} author(s) and {} pattern(s)",
+            config.release_exclude_authors.len(),
+            config.release_exclude_patterns.len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Adds ignore and pin rules for `update-dependencies`, removes rules for
+/// the `unset` packages, or all of them with `clear`.
+pub async fn set_dependency_update_rules(
+    ignore: &[String],
+    pin: &[String],
+    unset: &[String],
+    clear: bool,
+) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    let rules = &mut config.dependency_update;
+    if clear {
+        *rules = UpdateRules::default();
+    }
+    for spec in unset {
+        if !rules.remove(spec) {
+            println!("{}", format!("⚠️  No rule for {}", spec).yellow());
+        }
+    }
+    for spec in ignore {
+        rules.add_ignore(spec);
+    }
+    for spec in pin {
+        rules.add_pin(spec)?;
+    }
+    config_manager.save_config(&config).await?;
+
+    let described = config.dependency_update.describe();
+    if described.is_empty() {
+        println!("{}", "📦 No dependency update rules".green());
+    } else {
+        println!("{}", "📦 Dependency update rules:".green());
+        for rule in described {
+            println!("  {}", rule.green());
+        }
+    }
+    Ok(())
+}
+
+/// Adds branch patterns `branches cleanup` leaves alone, replacing the
+/// current ones with `clear`.
+pub async fn set_protected_branches(patterns: &[String], clear: bool) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    if clear {
+        config.protected_branches.clear();
+    }
+    for pattern in patterns {
+        if !config.protected_branches.contains(pattern) {
+            config.protected_branches.push(pattern.clone());
+        }
+    }
+    config_manager.save_config(&config).await?;
+
+    if config.protected_branches.is_empty() {
+        println!(
+            "{}",
+            "🌿 No protected branches besides the default branch".green()
+        );
+    } else {
+        println!(
+            "{}",
+            format!(
+                "🌿 Protected branches: {}",
+                config.protected_branches.join(", ")
+            )
+            .green()
+        );
+    }
+    Ok(())
+}
+
+/// Changes how often and on which channel the menu checks for updates.
+pub async fn set_update_check(
+    enabled: Option<bool>,
+    interval_hours: Option<u64>,
+    channel: Option<&str>,
+) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    if let Some(enabled) = enabled {
+        config.update_check = enabled;
+    }
+    if let Some(hours) = interval_hours {
+        config.update_check_interval_hours = hours;
+    }
+    if let Some(channel) = channel {
+        let channel: crate::utils::version_check::UpdateChannel = channel.parse()?;
+        config.update_channel = channel.name().to_string();
+    }
+    config_manager.save_config(&config).await?;
+
+    if config.update_check {
+        println!(
+            "{}",
+            format!(
+                "🔔 Update check every {}h on the {} channel",
+                config.update_check_interval_hours, config.update_channel
+            )
+            .green()
+        );
+    } else {
+        println!("{}", "🔕 Update check disabled".green());
+    }
+    Ok(())
+}
+
+/// Adds webhooks for notifications, or removes them all with `clear`.
+pub async fn set_notify_webhooks(urls: &[String], clear: bool) -> Result<()> {
+    for url in urls {
+        reqwest::Url::parse(url).map_err(|e| anyhow!("Invalid webhook URL '{}': {}", url, e))?;
+    }
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    if clear {
+        config.notify_webhooks.clear();
+    }
+    for url in urls {
+        if !config.notify_webhooks.contains(url) {
+            config.notify_webhooks.push(url.clone());
+        }
+    }
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🔔 {} notification webhook(s) configured",
+            config.notify_webhooks.len()
+        )
+        .green()
+    );
+    Ok(())
+}
+
+pub async fn set_github_org(org: &str) -> Result<()> {
+    let org = org.trim();
+    if org.is_empty() || org.contains('/') {
+        return Err(ConfigError::Invalid(format!("Invalid organization '{}'", org)).into());
+    }
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.github_org = Some(org.to_string());
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!("🏢 Default GitHub organization set to '{}'", org).green()
+    );
+    Ok(())
+}
+
+pub async fn set_theme(name: &str) -> Result<()> {
+    let theme = name.parse::<Theme>()?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.theme = theme.name().to_string();
+    config_manager.save_config(&config).await?;
+
+    println!("{}", format!("🎨 Theme set to '{}'", theme.name()).green());
+    Ok(())
+}
+
+/// Activates `--profile`, or the profile named by NITROTERM_PROFILE, for
+/// the rest of the run. A profile that doesn't exist is an error.
+pub async fn load_profile(flag: Option<&str>) -> Result<()> {
+    let name = match flag {
+        Some(name) => name.to_string(),
+        None => match std::env::var(PROFILE_ENV) {
+            Ok(name) if !name.trim().is_empty() => name.trim().to_string(),
+            _ => return Ok(()),
+        },
+    };
+    // Without a config database there is nothing to check against
+    if let Ok(Some(config_manager)) = ConfigManager::open_existing().await {
+        let config = config_manager.get_main_config().await?;
+        if !config.profiles.contains_key(&name) {
+            return Err(ConfigError::Invalid(format!(
+                "No profile named '{}', create it with `nitroterm config profiles create {}`",
+                name, name
+            ))
+            .into());
+        }
+    }
+    let _ = ACTIVE_PROFILE.set(name);
+    Ok(())
+}
+
+/// Profile settings that hold a credential.
+fn is_secret_setting(key: &str) -> bool {
+    key.ends_with("_api_key") || matches!(key, "gitlab_token" | "bitbucket_app_password")
+}
+
+/// The settings of a profile as `key = value` lines, credentials masked.
+pub fn describe_profile(profile: &ConfigProfile) -> Vec<String> {
+    profile
+        .iter()
+        .map(|(key, value)| {
+            let shown = match value {
+                serde_json::Value::Null => "(unset)".to_string(),
+                serde_json::Value::String(value) if is_secret_setting(key) => mask_api_key(value),
+                serde_json::Value::String(value) => value.clone(),
+                value => value.to_string(),
+            };
+            format!("{} = {}", key, shown)
+        })
+        .collect()
+}
+
+pub async fn list_profiles() -> Result<()> {
+    let config = ConfigManager::new_quiet().await?.get_main_config().await?;
+    if config.profiles.is_empty() {
+        println!(
+            "{}",
+            "No profiles yet, add one with `nitroterm config profiles create <name>`".dimmed()
+        );
+        return Ok(());
+    }
+
+    println!("{}", "👤 Config profiles:".cyan().bold());
+    for (name, profile) in &config.profiles {
+        let marker = if active_profile() == Some(name.as_str()) {
+            "●"
+        } else {
+            " "
+        };
+        println!("  {} {}", marker.green(), name.bold());
+        if profile.is_empty() {
+            println!("      {}", "(uses the main settings)".dimmed());
+        }
+        for line in describe_profile(profile) {
+            println!("      {}", line.dimmed());
+        }
+    }
+    Ok(())
+}
+
+/// Adds an empty profile, or with `copy` one holding the current values of
+/// the settings a profile can have.
+pub async fn create_profile(name: &str, copy: bool) -> Result<()> {
+    check_profile_name(name)?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_main_config().await?;
+    if config.profiles.contains_key(name) {
+        return Err(ConfigError::Invalid(format!("Profile '{}' already exists", name)).into());
+    }
+    let profile = if copy {
+        profile_of(&config)
+    } else {
+        ConfigProfile::new()
+    };
+    config.profiles.insert(name.to_string(), profile);
+    config_manager.write_config(&config).await?;
+
+    println!("{}", format!("👤 Profile '{}' created", name).green());
+    println!(
+        "{}",
+        format!(
+            "Fill it in with `nitroterm --profile {} config set-key gemini`, `config labels-file`, \
+             `config release`, ... and use it with --profile {} or {}={}",
+            name, name, PROFILE_ENV, name
+        )
+        .dimmed()
+    );
+    Ok(())
+}
+
+/// Removes a profile and the keychain entries that only it used.
+pub async fn delete_profile(name: &str) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_main_config().await?;
+    let Some(profile) = config.profiles.remove(name) else {
+        return Err(ConfigError::Invalid(format!("No profile named '{}'", name)).into());
+    };
+    let own_entries = format!("{}{}/", secrets::KEYCHAIN_MARKER, name);
+    for (key, value) in &profile {
+        if let Some(stored) = value.as_str().filter(|v| v.starts_with(&own_entries)) {
+            secrets::forget(key, stored)?;
+        }
+    }
+    config_manager.write_config(&config).await?;
+
+    println!("{}", format!("🗑️  Profile '{}' deleted", name).green());
+    Ok(())
+}
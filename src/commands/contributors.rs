@@ -0,0 +1,201 @@
+use crate::commands::release_notes::{get_repository_info, github_username};
+use crate::utils::get_repository;
+use anyhow::{anyhow, Result};
+use chrono::{TimeZone, Utc};
+use colored::*;
+use git2::{DiffOptions, Repository, Sort};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default)]
+pub struct ContributorsOptions {
+    pub path: PathBuf,
+    /// Start of the range (exclusive), e.g. the previous release tag
+    pub from: Option<String>,
+    /// End of the range, HEAD by default
+    pub to: Option<String>,
+    pub json: bool,
+    pub markdown: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContributorStats {
+    pub name: String,
+    pub email: String,
+    pub github: Option<String>,
+    pub commits: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Unix timestamps
+    pub first_commit: i64,
+    pub last_commit: i64,
+}
+
+fn format_day(timestamp: i64) -> String {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn resolve_commit(repo: &Repository, reference: &str) -> Result<git2::Oid> {
+    Ok(repo
+        .revparse_single(reference)
+        .map_err(|e| anyhow!("Unknown ref '{}': {}", reference, e))?
+        .peel_to_commit()?
+        .id())
+}
+
+/// Commits and line changes per author email over `from..to`. Merge commits
+/// are skipped, their changes are already counted on the merged branch.
+pub fn collect_contributors(
+    repo: &Repository,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<Vec<ContributorStats>> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(Sort::TIME)?;
+    revwalk.push(resolve_commit(repo, to.unwrap_or("HEAD"))?)?;
+    if let Some(from) = from {
+        revwalk.hide(resolve_commit(repo, from)?)?;
+    }
+
+    let is_github = get_repository_info(repo).is_github;
+    let mut by_email: HashMap<String, ContributorStats> = HashMap::new();
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff = repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&tree),
+            Some(DiffOptions::new().ignore_submodules(true)),
+        )?;
+        let stats = diff.stats()?;
+
+        let author = commit.author();
+        let email = author.email().unwrap_or_default().to_lowercase();
+        let time = commit.time().seconds();
+        let entry = by_email
+            .entry(email.clone())
+            .or_insert_with(|| ContributorStats {
+                name: author.name().unwrap_or("unknown").to_string(),
+                github: github_username(&email).filter(|_| is_github),
+                email,
+                commits: 0,
+                additions: 0,
+                deletions: 0,
+                first_commit: time,
+                last_commit: time,
+            });
+        entry.commits += 1;
+        entry.additions += stats.insertions();
+        entry.deletions += stats.deletions();
+        entry.first_commit = entry.first_commit.min(time);
+        entry.last_commit = entry.last_commit.max(time);
+    }
+
+    let mut contributors: Vec<ContributorStats> = by_email.into_values().collect();
+    contributors.sort_by(|a, b| {
+        b.commits
+            .cmp(&a.commits)
+            .then((b.additions + b.deletions).cmp(&(a.additions + a.deletions)))
+            .then(a.name.cmp(&b.name))
+    });
+    Ok(contributors)
+}
+
+/// Markdown table for docs, GitHub handles linked.
+pub fn render_markdown(contributors: &[ContributorStats]) -> String {
+    let mut output = String::from(
+        "| # | Contributor | Commits | Lines added | Lines removed | First commit | Last commit |\n\
+         |---|-------------|---------|-------------|---------------|--------------|-------------|\n",
+    );
+    for (index, contributor) in contributors.iter().enumerate() {
+        let who = match &contributor.github {
+            Some(user) => format!(
+                "[@{}](https://github.com/{}) ({})",
+                user, user, contributor.name
+            ),
+            None => contributor.name.clone(),
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} | +{} | -{} | {} | {} |\n",
+            index + 1,
+            who,
+            contributor.commits,
+            contributor.additions,
+            contributor.deletions,
+            format_day(contributor.first_commit),
+            format_day(contributor.last_commit)
+        ));
+    }
+    output
+}
+
+fn print_table(contributors: &[ContributorStats]) {
+    println!("{}", "👥 Contributors".cyan().bold());
+    println!("{}", "─".repeat(92).dimmed());
+    println!(
+        "{}",
+        format!(
+            "{:>3}  {:<32} {:>7} {:>9} {:>9}  {:<10}  {:<10}",
+            "#", "Contributor", "Commits", "Added", "Removed", "First", "Last"
+        )
+        .bold()
+    );
+    for (index, contributor) in contributors.iter().enumerate() {
+        let who = match &contributor.github {
+            Some(user) => format!("@{} ({})", user, contributor.name),
+            None => contributor.name.clone(),
+        };
+        let who: String = who.chars().take(32).collect();
+        println!(
+            "{:>3}  {:<32} {:>7} {:>9} {:>9}  {:<10}  {:<10}",
+            index + 1,
+            who,
+            contributor.commits.to_string().cyan(),
+            format!("+{}", contributor.additions).green(),
+            format!("-{}", contributor.deletions).red(),
+            format_day(contributor.first_commit),
+            format_day(contributor.last_commit)
+        );
+    }
+    let commits: usize = contributors.iter().map(|c| c.commits).sum();
+    println!("{}", "─".repeat(92).dimmed());
+    println!(
+        "{}",
+        format!(
+            "{} contributor(s), {} commit(s)",
+            contributors.len(),
+            commits
+        )
+        .dimmed()
+    );
+}
+
+pub async fn run_contributors(options: ContributorsOptions) -> Result<()> {
+    let repo = get_repository(&options.path.to_string_lossy())
+        .map_err(|e| anyhow!("Not a git repository: {}", e))?;
+    let contributors = collect_contributors(&repo, options.from.as_deref(), options.to.as_deref())?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&contributors)?);
+    } else if options.markdown {
+        print!("{}", render_markdown(&contributors));
+    } else if contributors.is_empty() {
+        println!("{}", "No commits in the selected range".yellow());
+    } else {
+        print_table(&contributors);
+    }
+    Ok(())
+}
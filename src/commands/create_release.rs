@@ -1,9 +1,55 @@
+use crate::commands::notifications::{notify, NotifyEvent};
+use crate::commands::release_hosting::{detect_release_target, ReleaseAsset, ReleasePublisher};
+use crate::commands::release_preflight::{enforce_preflight, print_preflight, run_preflight};
+use crate::commands::sbom::{write_release_sbom, SbomFormat};
+use crate::utils::git::default_branch;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
 use anyhow::Result;
 use colored::*;
-use std::io::{self, Write};
-use std::process::Command;
 
-pub async fn create_release_with_args(version: &str, message: Option<&str>) -> Result<()> {
+/// How the release tag is created: annotated, or GPG-signed with git's
+/// default key or an explicit one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TagSigning {
+    #[default]
+    None,
+    DefaultKey,
+    Key(String),
+}
+
+impl TagSigning {
+    /// The configured key only applies with `--sign`, a key given on the
+    /// command line implies signing and wins over it.
+    pub fn resolve(sign: bool, key_flag: Option<&str>, config_key: Option<&str>) -> Self {
+        match (key_flag, sign, config_key) {
+            (Some(key), _, _) => TagSigning::Key(key.to_string()),
+            (None, true, Some(key)) => TagSigning::Key(key.to_string()),
+            (None, true, None) => TagSigning::DefaultKey,
+            (None, false, _) => TagSigning::None,
+        }
+    }
+
+    /// `git tag` flags creating the tag with this signing mode.
+    pub fn tag_args(&self) -> Vec<&str> {
+        match self {
+            TagSigning::None => vec!["-a"],
+            TagSigning::DefaultKey => vec!["-s"],
+            TagSigning::Key(key) => vec!["-u", key.as_str()],
+        }
+    }
+}
+
+pub async fn create_release_with_args(
+    version: &str,
+    message: Option<&str>,
+    signing: TagSigning,
+    force: bool,
+    publish: bool,
+    sbom: Option<SbomFormat>,
+) -> Result<()> {
+    ensure_not_sandboxed("create-release")?;
     println!(
         "{}",
         format!("🚀 Creating release with version: {}", version).cyan()
@@ -12,14 +58,22 @@ pub async fn create_release_with_args(version: &str, message: Option<&str>) -> R
     // Version string'ini analiz et ve bump type belirle
     let bump_type = determine_bump_type(version)?;
 
+    show_release_risk();
+
     // Version management'ı kullanarak release oluştur
-    bump_and_release(bump_type, message).await?;
+    bump_and_release(bump_type, message, &signing, force, publish, sbom).await?;
 
     println!("{}", "✅ Release created successfully!".green());
     Ok(())
 }
 
-pub async fn create_release_interactive() -> Result<()> {
+pub async fn create_release_interactive(
+    signing: TagSigning,
+    force: bool,
+    publish: bool,
+    sbom: Option<SbomFormat>,
+) -> Result<()> {
+    ensure_not_sandboxed("create-release")?;
     println!("{}", "\n🚀 Interactive Release Creation".cyan().bold());
     println!("{}", "═".repeat(35).dimmed());
 
@@ -31,13 +85,12 @@ pub async fn create_release_interactive() -> Result<()> {
         "2.".dimmed()
     );
     println!("  {} Major (breaking changes)", "3.".dimmed());
-    print!("\n{}", "Select option (1-3): ".cyan());
+    let input = prompt::ask_required(
+        format!("\n{}", "Select option (1-3): ".cyan()),
+        "pass the version, e.g. `nitroterm create-release patch`",
+    )?;
 
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-
-    let bump_type = match input.trim() {
+    let bump_type = match input.as_str() {
         "1" | "patch" => "patch",
         "2" | "minor" => "minor",
         "3" | "major" => "major",
@@ -48,11 +101,8 @@ pub async fn create_release_interactive() -> Result<()> {
     };
 
     // Release mesajı al
-    print!("{}", "Enter release message (optional): ".cyan());
-    io::stdout().flush().unwrap();
-    let mut message = String::new();
-    io::stdin().read_line(&mut message).unwrap();
-    let message = message.trim();
+    let message = prompt::ask("Enter release message (optional): ".cyan(), "")?;
+    let message = message.as_str();
 
     let release_message = if message.is_empty() {
         None
@@ -67,24 +117,32 @@ pub async fn create_release_interactive() -> Result<()> {
     if let Some(msg) = release_message {
         println!("  {} {}", "Message:".dimmed(), msg.green());
     }
-    print!("\n{}", "Proceed with release? (y/N): ".cyan());
 
-    io::stdout().flush().unwrap();
-    let mut confirm = String::new();
-    io::stdin().read_line(&mut confirm).unwrap();
+    show_release_risk();
 
-    if confirm.trim().to_lowercase() != "y" && confirm.trim().to_lowercase() != "yes" {
+    if !prompt::confirm(format!("\n{}", "Proceed with release? (y/N): ".cyan()))? {
         println!("{}", "Release cancelled.".yellow());
         return Ok(());
     }
 
     // Release oluştur
-    bump_and_release(bump_type, release_message).await?;
+    bump_and_release(bump_type, release_message, &signing, force, publish, sbom).await?;
 
     println!("{}", "✅ Release created successfully!".green());
     Ok(())
 }
 
+fn show_release_risk() {
+    // Risk tahmini sadece bilgi amaçlı, hata release'i engellemesin
+    match crate::commands::release_risk::assess_release_risk(std::path::Path::new(".")) {
+        Ok(report) => crate::commands::release_risk::print_risk_report(&report),
+        Err(e) => println!(
+            "{}",
+            format!("⚠️  Could not estimate release risk: {}", e).yellow()
+        ),
+    }
+}
+
 fn determine_bump_type(version: &str) -> Result<&'static str> {
     // v prefix'ini kaldır
     let version = version.strip_prefix('v').unwrap_or(version);
@@ -126,7 +184,27 @@ fn determine_bump_type(version: &str) -> Result<&'static str> {
     }
 }
 
-pub async fn bump_and_release(bump_type: &str, message: Option<&str>) -> Result<()> {
+/// `config release --branch`, else the repository's default branch.
+async fn release_branch() -> Option<String> {
+    crate::commands::config::load_saved_config()
+        .await
+        .release_branch
+        .or_else(|| {
+            git2::Repository::discover(".")
+                .ok()
+                .and_then(|repo| default_branch(&repo))
+        })
+}
+
+pub async fn bump_and_release(
+    bump_type: &str,
+    message: Option<&str>,
+    signing: &TagSigning,
+    force: bool,
+    publish: bool,
+    sbom: Option<SbomFormat>,
+) -> Result<()> {
+    ensure_not_sandboxed("Version bump")?;
     // 1. Current version'u al
     let current_version = env!("CARGO_PKG_VERSION");
     let new_version = bump_version(bump_type, current_version)?;
@@ -136,14 +214,35 @@ pub async fn bump_and_release(bump_type: &str, message: Option<&str>) -> Result<
         current_version, new_version
     );
 
+    // Pre-flight: temiz çalışma alanı, branch, remote, tag ve CI
+    let checks = run_preflight(
+        &SystemRunner,
+        &format!("v{}", new_version),
+        release_branch().await.as_deref(),
+    );
+    print_preflight(&checks);
+    enforce_preflight(&checks, force)
+        .map_err(|e| anyhow::anyhow!("{} (release blocked, no tag was created)", e))?;
+
+    // Release policy, Cargo.toml'a dokunmadan önce kontrol edilir
+    crate::commands::policy::enforce_policy(std::path::Path::new("."))
+        .await
+        .map_err(|e| anyhow::anyhow!("{} (release blocked, no tag was created)", e))?;
+
+    // Smoke test'ler de Cargo.toml'a dokunmadan önce çalışır, başarısız
+    // olursa release durur
+    crate::commands::smoke_test::run_smoke_tests(std::path::Path::new("."))
+        .await
+        .map_err(|e| anyhow::anyhow!("{} (release blocked, no tag was created)", e))?;
+
     // 2. Cargo.toml'u güncelle
     update_cargo_toml(&new_version)?;
 
     // 3. Git repository kontrolü
-    check_git_repository()?;
+    check_git_repository(&SystemRunner)?;
 
     // 4. Release notes oluştur (opsiyonel, hata verirse devam et)
-    let _release_notes = match generate_release_notes_safely().await {
+    let release_notes = match generate_release_notes_safely().await {
         Ok(notes) => Some(notes),
         Err(e) => {
             println!(
@@ -155,30 +254,101 @@ pub async fn bump_and_release(bump_type: &str, message: Option<&str>) -> Result<
     };
 
     // 5. Git commit ve tag
-    create_git_tag(&new_version, message).await?;
+    let tag_name = publish_release_tag(&SystemRunner, &new_version, message, signing)?;
+
+    // 6. SBOM, yayınlanırsa release'e eklenir
+    let mut assets = Vec::new();
+    if let Some(format) = sbom {
+        match write_release_sbom(format, &tag_name).and_then(|path| ReleaseAsset::from_file(&path))
+        {
+            Ok(asset) => assets.push(asset),
+            Err(e) => println!(
+                "{}",
+                format!("⚠️  Could not generate the SBOM: {}", e).yellow()
+            ),
+        }
+    }
+
+    // 7. GitHub, GitLab veya Bitbucket'ta release yayınla
+    let notes = release_notes
+        .or_else(|| message.map(str::to_string))
+        .unwrap_or_else(|| format!("Release {}", tag_name));
+    let url = if publish {
+        Some(
+            publish_hosted_release(&tag_name, &notes, &assets)
+                .await
+                .map_err(|e| anyhow::anyhow!("{} (tag {} was already created)", e, tag_name))?,
+        )
+    } else {
+        None
+    };
 
     println!("🎉 Successfully released version {}", new_version.green());
+
+    // 8. Slack/Discord/Teams/e-posta bildirimi
+    notify(
+        std::path::Path::new("."),
+        NotifyEvent::Release,
+        serde_json::json!({
+            "version": new_version,
+            "tag": tag_name,
+            "url": url,
+            "notes": notes,
+        }),
+    )
+    .await;
     Ok(())
 }
 
-fn check_git_repository() -> Result<()> {
+/// Publishes the release of a pushed tag on the host of the origin remote:
+/// a GitHub or GitLab release, or the notes in Bitbucket Downloads, with
+/// `assets` attached.
+pub async fn publish_hosted_release(
+    tag: &str,
+    notes: &str,
+    assets: &[ReleaseAsset],
+) -> Result<String> {
+    let config = crate::commands::config::load_saved_config().await;
+    let repo = git2::Repository::discover(".")?;
+    let remote_url = match repo.find_remote("origin") {
+        Ok(remote) => remote.url().map(str::to_string),
+        Err(_) => Some(crate::commands::release_notes::get_repository_info(&repo).url),
+    }
+    .unwrap_or_default();
+
+    let target =
+        detect_release_target(&remote_url, config.gitlab_url.as_deref()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot publish to '{}', only GitHub, GitLab and Bitbucket remotes are supported",
+                remote_url
+            )
+        })?;
+    println!(
+        "📦 Publishing {} release of {}...",
+        target.host.display_name(),
+        target.project
+    );
+    let url = ReleasePublisher::from_config(target, &config)?
+        .publish(tag, notes, assets)
+        .await?;
+    println!("✅ Published release: {}", url.green());
+    Ok(url)
+}
+
+pub fn check_git_repository(runner: &dyn CommandRunner) -> Result<()> {
     // Git repository olup olmadığını kontrol et
-    let output = Command::new("git")
-        .args(["rev-parse", "--git-dir"])
-        .output()?;
+    let output = runner.run("git", &["rev-parse", "--git-dir"], None)?;
 
-    if !output.status.success() {
+    if !output.success {
         return Err(anyhow::anyhow!(
             "Not a git repository. Please initialize git first with 'git init'"
         ));
     }
 
     // Git'te herhangi bir commit olup olmadığını kontrol et
-    let output = Command::new("git")
-        .args(["log", "--oneline", "-1"])
-        .output()?;
+    let output = runner.run("git", &["log", "--oneline", "-1"], None)?;
 
-    if !output.status.success() {
+    if !output.success {
         return Err(anyhow::anyhow!(
             "No commits found. Please make an initial commit first"
         ));
@@ -189,24 +359,34 @@ fn check_git_repository() -> Result<()> {
 
 async fn generate_release_notes_safely() -> Result<String> {
     // Son tag'i bul (yoksa None)
-    let latest_tag = get_latest_tag_safe()?;
+    let latest_tag = get_latest_tag_safe(&SystemRunner)?;
+
+    let filter = crate::commands::commit_filter::load_commit_filter(&[], &[]).await;
 
     // Release notes oluştur
     if let Some(tag) = latest_tag {
-        crate::commands::release_notes::generate_release_notes_for_version(Some(&tag), None)
+        crate::commands::release_notes::generate_release_notes_for_version(
+            std::path::Path::new("."),
+            Some(&tag),
+            None,
+            &filter,
+        )
     } else {
         // Eğer hiç tag yoksa, tüm commit'leri al
-        crate::commands::release_notes::generate_release_notes_for_version(None, None)
+        crate::commands::release_notes::generate_release_notes_for_version(
+            std::path::Path::new("."),
+            None,
+            None,
+            &filter,
+        )
     }
 }
 
-fn get_latest_tag_safe() -> Result<Option<String>> {
-    let output = Command::new("git")
-        .args(["describe", "--tags", "--abbrev=0"])
-        .output()?;
+pub fn get_latest_tag_safe(runner: &dyn CommandRunner) -> Result<Option<String>> {
+    let output = runner.run("git", &["describe", "--tags", "--abbrev=0"], None)?;
 
-    if output.status.success() {
-        let tag = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.success {
+        let tag = output.stdout.trim().to_string();
         if tag.is_empty() {
             Ok(None)
         } else {
@@ -266,31 +446,37 @@ fn update_cargo_toml(new_version: &str) -> Result<()> {
     Ok(())
 }
 
-async fn create_git_tag(version: &str, message: Option<&str>) -> Result<()> {
+/// Commits the version bump, tags it and pushes both. Push failures only
+/// warn, the tag stays local. Returns the tag name.
+pub fn publish_release_tag(
+    runner: &dyn CommandRunner,
+    version: &str,
+    message: Option<&str>,
+    signing: &TagSigning,
+) -> Result<String> {
     let tag_name = format!("v{}", version);
 
     // Working directory'de değişiklik olup olmadığını kontrol et
-    let status_output = Command::new("git")
-        .args(["status", "--porcelain"])
-        .output()?;
+    let status_output = runner.run("git", &["status", "--porcelain"], None)?;
 
-    if !status_output.stdout.is_empty() {
+    if !status_output.stdout.trim().is_empty() {
         // Değişiklikleri commit et
         println!("📝 Committing changes...");
 
-        let add_output = Command::new("git").args(["add", "Cargo.toml"]).output()?;
+        let add_output = runner.run("git", &["add", "Cargo.toml"], None)?;
 
-        if !add_output.status.success() {
+        if !add_output.success {
             return Err(anyhow::anyhow!("Failed to stage Cargo.toml changes"));
         }
 
-        let commit_output = Command::new("git")
-            .args(["commit", "-m", &format!("bump: version {}", version)])
-            .output()?;
+        let commit_message = format!("bump: version {}", version);
+        let commit_output = runner.run("git", &["commit", "-m", &commit_message], None)?;
 
-        if !commit_output.status.success() {
-            let error = String::from_utf8_lossy(&commit_output.stderr);
-            return Err(anyhow::anyhow!("Failed to commit changes: {}", error));
+        if !commit_output.success {
+            return Err(anyhow::anyhow!(
+                "Failed to commit changes: {}",
+                commit_output.stderr
+            ));
         }
 
         println!("✅ Changes committed");
@@ -301,30 +487,30 @@ async fn create_git_tag(version: &str, message: Option<&str>) -> Result<()> {
     let default_message = format!("Release {}", tag_name);
     let tag_message = message.unwrap_or(&default_message);
 
-    let tag_output = Command::new("git")
-        .args(["tag", "-a", &tag_name, "-m", tag_message])
-        .output()?;
+    let mut tag_args = vec!["tag"];
+    tag_args.extend(signing.tag_args());
+    tag_args.extend([tag_name.as_str(), "-m", tag_message]);
+    let tag_output = runner.run("git", &tag_args, None)?;
 
-    if !tag_output.status.success() {
-        let error = String::from_utf8_lossy(&tag_output.stderr);
-        return Err(anyhow::anyhow!("Failed to create tag: {}", error));
+    if !tag_output.success {
+        return Err(anyhow::anyhow!(
+            "Failed to create tag: {}",
+            tag_output.stderr
+        ));
     }
 
     // Remote'a push et (opsiyonel)
     println!("🚀 Pushing changes to remote...");
 
     // Önce commit'leri push et
-    let push_output = Command::new("git").args(["push"]).output();
-
-    match push_output {
-        Ok(output) if output.status.success() => {
+    match runner.run("git", &["push"], None) {
+        Ok(output) if output.success => {
             println!("✅ Pushed commits to remote");
         }
         Ok(output) => {
-            let error = String::from_utf8_lossy(&output.stderr);
             println!(
                 "{}",
-                format!("⚠️  Could not push commits: {}", error).yellow()
+                format!("⚠️  Could not push commits: {}", output.stderr).yellow()
             );
         }
         Err(e) => {
@@ -333,19 +519,18 @@ async fn create_git_tag(version: &str, message: Option<&str>) -> Result<()> {
     }
 
     // Tag'i push et
-    let push_tag_output = Command::new("git")
-        .args(["push", "origin", &tag_name])
-        .output();
-
-    match push_tag_output {
-        Ok(output) if output.status.success() => {
+    match runner.run("git", &["push", "origin", &tag_name], None) {
+        Ok(output) if output.success => {
             println!("✅ Pushed tag to remote: {}", tag_name.green());
         }
         Ok(output) => {
-            let error = String::from_utf8_lossy(&output.stderr);
             println!(
                 "{}",
-                format!("⚠️  Could not push tag: {} (Tag created locally)", error).yellow()
+                format!(
+                    "⚠️  Could not push tag: {} (Tag created locally)",
+                    output.stderr
+                )
+                .yellow()
             );
         }
         Err(e) => {
@@ -357,5 +542,5 @@ async fn create_git_tag(version: &str, message: Option<&str>) -> Result<()> {
     }
 
     println!("✅ Created tag: {}", tag_name.green());
-    Ok(())
+    Ok(tag_name)
 }
@@ -1,94 +1,483 @@
+use crate::commands::doctor::is_command_available;
+use crate::commands::notifications::{notify, NotifyEvent};
+use crate::commands::selective_update::{lenient_version, update_with_rules};
+use crate::utils::file_system::{self, ScanOptions};
+use crate::utils::output::is_json_output;
+use crate::utils::process::SupervisedCommand;
+use crate::utils::progress::Spinner;
 use crate::utils::{log_error, log_info, log_success, log_warning, read_file_to_string};
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Local};
 use colored::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::Instant;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateStatus {
+    Updated,
+    Failed,
+    Skipped,
+}
+
+/// Ecosystems a rule can be scoped to, the `ecosystem` of [`EcosystemResult`].
+pub const RULE_ECOSYSTEMS: [&str; 5] = ["node", "deno", "rust", "python", "php"];
+/// Key of the rules that apply to every ecosystem.
+const ANY_ECOSYSTEM: &str = "*";
+
+/// The `dependency_update` config section: packages that are never updated
+/// and versions others are held at, keyed by ecosystem or `*`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateRules {
+    #[serde(default)]
+    pub ignore: BTreeMap<String, Vec<String>>,
+    /// Package → version prefix; `18` keeps react on 18.x
+    #[serde(default)]
+    pub pin: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// `node:react` → (`node`, `react`); without a known ecosystem prefix the
+/// rule applies to all of them, so Deno's `npm:chalk` stays one name.
+fn split_rule(spec: &str) -> (String, String) {
+    match spec.trim().split_once(':') {
+        Some((ecosystem, name)) if RULE_ECOSYSTEMS.contains(&ecosystem) => {
+            (ecosystem.to_string(), name.trim().to_string())
+        }
+        _ => (ANY_ECOSYSTEM.to_string(), spec.trim().to_string()),
+    }
+}
+
+/// Whether `version` is inside `pin`: every component the pin names must
+/// match, so `18` allows 18.3.1 and `1.2` allows 1.2.9 but not 1.3.0.
+pub fn pin_allows(pin: &str, version: &str) -> bool {
+    let Some(version) = lenient_version(version) else {
+        return false;
+    };
+    let pin = pin.trim().trim_start_matches(['v', '^', '~', '=']);
+    pin.split('.')
+        .zip([version.major, version.minor, version.patch])
+        .all(|(part, actual)| match part {
+            "x" | "X" | "*" => true,
+            part => part.parse::<u64>().is_ok_and(|wanted| wanted == actual),
+        })
+}
+
+impl UpdateRules {
+    /// `left-pad` or `node:left-pad`.
+    pub fn add_ignore(&mut self, spec: &str) {
+        let (ecosystem, name) = split_rule(spec);
+        if name.is_empty() {
+            return;
+        }
+        let names = self.ignore.entry(ecosystem).or_default();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    /// `react@18` or `node:@types/node@20`.
+    pub fn add_pin(&mut self, spec: &str) -> Result<()> {
+        let (ecosystem, rest) = split_rule(spec);
+        match rest.rsplit_once('@') {
+            Some((name, version)) if !name.is_empty() && !version.is_empty() => {
+                self.pin
+                    .entry(ecosystem)
+                    .or_default()
+                    .insert(name.to_string(), version.to_string());
+                Ok(())
+            }
+            _ => Err(anyhow!(
+                "Invalid pin '{}', use package@version, e.g. react@18",
+                spec
+            )),
+        }
+    }
+
+    /// Drops every ignore and pin for `spec`; false when there was none.
+    pub fn remove(&mut self, spec: &str) -> bool {
+        let (ecosystem, name) = split_rule(spec);
+        let mut removed = false;
+        if let Some(names) = self.ignore.get_mut(&ecosystem) {
+            let before = names.len();
+            names.retain(|existing| existing != &name);
+            removed |= names.len() != before;
+        }
+        if let Some(pins) = self.pin.get_mut(&ecosystem) {
+            removed |= pins.remove(&name).is_some();
+        }
+        self.ignore.retain(|_, names| !names.is_empty());
+        self.pin.retain(|_, pins| !pins.is_empty());
+        removed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ignore.values().all(Vec::is_empty) && self.pin.values().all(BTreeMap::is_empty)
+    }
+
+    /// Whether any rule covers `ecosystem`.
+    pub fn applies_to(&self, ecosystem: &str) -> bool {
+        [ecosystem, ANY_ECOSYSTEM].iter().any(|key| {
+            self.ignore.get(*key).is_some_and(|names| !names.is_empty())
+                || self.pin.get(*key).is_some_and(|pins| !pins.is_empty())
+        })
+    }
+
+    pub fn is_ignored(&self, ecosystem: &str, name: &str) -> bool {
+        [ecosystem, ANY_ECOSYSTEM].iter().any(|key| {
+            self.ignore
+                .get(*key)
+                .is_some_and(|names| names.iter().any(|ignored| ignored == name))
+        })
+    }
+
+    /// The pin of `name`; one for the ecosystem wins over a `*` pin.
+    pub fn pin(&self, ecosystem: &str, name: &str) -> Option<&str> {
+        [ecosystem, ANY_ECOSYSTEM]
+            .iter()
+            .find_map(|key| self.pin.get(*key)?.get(name))
+            .map(String::as_str)
+    }
+
+    /// Whether `name` may be updated to `version`.
+    pub fn allows(&self, ecosystem: &str, name: &str, version: &str) -> bool {
+        !self.is_ignored(ecosystem, name)
+            && self
+                .pin(ecosystem, name)
+                .is_none_or(|pin| pin_allows(pin, version))
+    }
+
+    /// One line per rule, e.g. `node: react pinned to 18`.
+    pub fn describe(&self) -> Vec<String> {
+        let scope = |ecosystem: &str| {
+            if ecosystem == ANY_ECOSYSTEM {
+                "all".to_string()
+            } else {
+                ecosystem.to_string()
+            }
+        };
+        let mut lines = Vec::new();
+        for (ecosystem, names) in &self.ignore {
+            for name in names {
+                lines.push(format!("{}: {} ignored", scope(ecosystem), name));
+            }
+        }
+        for (ecosystem, pins) in &self.pin {
+            for (name, pin) in pins {
+                lines.push(format!("{}: {} pinned to {}", scope(ecosystem), name, pin));
+            }
+        }
+        lines
+    }
+}
+
+static UPDATE_RULES: OnceLock<UpdateRules> = OnceLock::new();
+
+/// Reads the rules from the saved config and adds the `--ignore` packages
+/// of this run.
+pub async fn load_update_rules(ignore: &[String]) {
+    let mut rules = crate::commands::config::load_saved_config()
+        .await
+        .dependency_update;
+    for spec in ignore {
+        rules.add_ignore(spec);
+    }
+    let _ = UPDATE_RULES.set(rules);
+}
+
+/// The rules loaded by [`load_update_rules`], none before that.
+pub fn update_rules() -> &'static UpdateRules {
+    UPDATE_RULES.get_or_init(UpdateRules::default)
+}
+
+/// What happened to one manifest, the `update-dependencies --json` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct EcosystemResult {
+    pub ecosystem: &'static str,
+    pub manifest: String,
+    pub package_manager: Option<String>,
+    pub status: UpdateStatus,
+    pub error: Option<String>,
+    /// Output of the outdated check when anything is left behind
+    pub outdated: Option<String>,
+    pub backup_dir: Option<String>,
+}
+
+impl EcosystemResult {
+    /// A skipped result for `manifest` until an update runs.
+    pub fn new(manifest: &str) -> Self {
+        let ecosystem = match split_manifest(manifest).1 {
+            "package.json" => "node",
+            "deno.json" | "deno.jsonc" => "deno",
+            "Cargo.toml" => "rust",
+            "requirements.txt" | "pyproject.toml" => "python",
+            "composer.json" => "php",
+            _ => "unknown",
+        };
+        Self {
+            ecosystem,
+            manifest: manifest.to_string(),
+            package_manager: None,
+            status: UpdateStatus::Skipped,
+            error: None,
+            outdated: None,
+            backup_dir: None,
+        }
+    }
 
-pub fn update_dependencies() {
+    pub fn fail(&mut self, error: String) {
+        log_error(&error);
+        self.status = UpdateStatus::Failed;
+        self.error = Some(error);
+    }
+
+    /// Keeps the outdated check output without ignored packages; empty
+    /// output means up to date.
+    fn record_outdated(&mut self, output: &str) -> bool {
+        let output = without_ignored(output, self.ecosystem, update_rules());
+        if output.trim().is_empty() {
+            return false;
+        }
+        self.outdated = Some(output.trim_end().to_string());
+        print_section("📊 Outdated packages:".yellow().bold(), &output);
+        true
+    }
+}
+
+/// Outdated report lines whose first column isn't an ignored package. A
+/// report with nothing but its header left is empty.
+pub fn without_ignored(output: &str, ecosystem: &str, rules: &UpdateRules) -> String {
+    if !rules.applies_to(ecosystem) {
+        return output.to_string();
+    }
+    let first_column = |line: &str| line.split_whitespace().next().unwrap_or("").to_string();
+    let lines: Vec<&str> = output
+        .lines()
+        .filter(|line| !rules.is_ignored(ecosystem, &first_column(line)))
+        .collect();
+    let only_header = lines.iter().all(|line| {
+        let column = first_column(line);
+        column.is_empty() || column == "Package" || column.chars().all(|c| c == '-')
+    });
+    if only_header {
+        return String::new();
+    }
+    lines.join("\n")
+}
+
+/// Tool output under a heading; with `--json` only the results are printed.
+fn print_section(title: ColoredString, body: &str) {
+    if !is_json_output() {
+        println!("{}", title);
+        println!("{}", body);
+    }
+}
+
+fn print_failed() {
+    if !is_json_output() {
+        println!(" {}", "❌ Failed".red());
+    }
+}
+
+/// Updates every manifest `scan` finds below `root`. The package managers
+/// run in the directory of their manifest.
+pub fn update_dependencies(root: &Path, scan: &ScanOptions) -> Vec<EcosystemResult> {
     log_info("Scanning for dependency files...");
 
-    let project_files = find_project_files();
+    let project_files = find_project_files(root, scan);
 
     if project_files.is_empty() {
-        log_warning("No dependency files found in current directory");
-        return;
+        log_warning(&format!("No dependency files found in {}", root.display()));
+        return Vec::new();
     }
 
+    let human = !is_json_output();
+    // Sandbox mode only reads the manifests, nothing is installed or backed up
+    if crate::utils::sandbox::is_sandboxed() {
+        log_info("Sandbox mode: showing dependency inventory only");
+        for file in &project_files {
+            log_info(&format!("Analyzing: {}", file));
+            if !human {
+                continue;
+            }
+            let dir = manifest_dir(root, file);
+            match split_manifest(file).1 {
+                "package.json" => analyze_package_json(&dir),
+                name @ ("deno.json" | "deno.jsonc") => analyze_deno_json(&dir, name),
+                "Cargo.toml" => analyze_cargo_toml(&dir),
+                "requirements.txt" => analyze_requirements_txt(&dir),
+                "pyproject.toml" => analyze_pyproject_toml(&dir),
+                "composer.json" => analyze_composer_json(&dir),
+                _ => log_warning(&format!("Unknown file type: {}", file)),
+            }
+        }
+        return project_files
+            .iter()
+            .map(|file| EcosystemResult::new(file))
+            .collect();
+    }
+
+    let mut results = Vec::new();
     for file in project_files {
         log_info(&format!("Analyzing: {}", file));
+        let mut result = EcosystemResult::new(&file);
+        let dir = manifest_dir(root, &file);
+        let name = split_manifest(&file).1;
+        // Bulk updates can't skip packages, so rules mean one package at a time
+        if update_rules().applies_to(result.ecosystem) {
+            log_info(&format!(
+                "Update rules for {}: updating package by package",
+                result.ecosystem
+            ));
+            let mut result = update_with_rules(&dir, name, update_rules());
+            result.manifest = file.clone();
+            results.push(result);
+            continue;
+        }
 
-        match file.as_str() {
+        match name {
             "package.json" => {
-                analyze_package_json();
-                update_node_dependencies();
+                if human {
+                    analyze_package_json(&dir);
+                }
+                update_node_dependencies(&dir, &mut result);
+            }
+            "deno.json" | "deno.jsonc" => {
+                if human {
+                    analyze_deno_json(&dir, name);
+                }
+                update_deno_dependencies(&dir, &mut result);
             }
             "Cargo.toml" => {
-                analyze_cargo_toml();
-                update_cargo_dependencies();
+                if human {
+                    analyze_cargo_toml(&dir);
+                }
+                update_cargo_dependencies(&dir, &mut result);
             }
             "requirements.txt" => {
-                analyze_requirements_txt();
-                update_pip_dependencies();
+                if human {
+                    analyze_requirements_txt(&dir);
+                }
+                update_pip_dependencies(&dir, &mut result);
+            }
+            "pyproject.toml" => {
+                if human {
+                    analyze_pyproject_toml(&dir);
+                }
+                update_pyproject_dependencies(&dir, &mut result);
             }
             "composer.json" => {
-                analyze_composer_json();
-                update_composer_dependencies();
+                if human {
+                    analyze_composer_json(&dir);
+                }
+                update_composer_dependencies(&dir, &mut result);
             }
             _ => {
                 log_warning(&format!("Unknown file type: {}", file));
             }
         }
+        results.push(result);
     }
 
-    println!();
-    println!(
-        "{}",
-        "🎉 All dependency operations completed successfully!"
-            .green()
-            .bold()
-    );
+    if human {
+        println!();
+        println!(
+            "{}",
+            "🎉 All dependency operations completed successfully!"
+                .green()
+                .bold()
+        );
+    }
     log_success("Dependency analysis and update completed!");
+    results
 }
 
-fn find_project_files() -> Vec<String> {
-    let mut files = Vec::new();
-
-    // Package.json (Node.js/npm/yarn/pnpm)
-    if crate::utils::file_exists("package.json") {
-        files.push("package.json".to_string());
+/// Posts the results as a `dependency-update` notification, see the
+/// `[notifications]` section of `.nitrokit.toml`.
+pub async fn notify_update_results(root: &Path, results: &[EcosystemResult]) {
+    if results.is_empty() {
+        return;
     }
+    let count = |status: UpdateStatus| results.iter().filter(|r| r.status == status).count();
+    notify(
+        root,
+        NotifyEvent::DependencyUpdate,
+        serde_json::json!({
+            "updated": count(UpdateStatus::Updated),
+            "failed": count(UpdateStatus::Failed),
+            "skipped": count(UpdateStatus::Skipped),
+            "results": results,
+        }),
+    )
+    .await;
+}
 
-    // Cargo.toml (Rust)
-    if crate::utils::file_exists("Cargo.toml") {
-        files.push("Cargo.toml".to_string());
-    }
+/// Manifests `update-dependencies` handles, in the order it goes through
+/// them within one directory.
+const PROJECT_FILES: [&str; 7] = [
+    "package.json",
+    "deno.json",
+    "deno.jsonc",
+    "Cargo.toml",
+    "requirements.txt",
+    "pyproject.toml",
+    "composer.json",
+];
+
+/// Manifests in `root`, and below it as far as `options` reach, as paths
+/// relative to it. deno.json wins over a deno.jsonc next to it.
+pub fn find_project_files(root: &Path, options: &ScanOptions) -> Vec<String> {
+    let files = file_system::find_project_files(root, &PROJECT_FILES, options);
+    files
+        .iter()
+        .filter(|file| {
+            !(file.ends_with("deno.jsonc") && files.contains(&file.with_file_name("deno.json")))
+        })
+        .map(|file| file.to_string_lossy().to_string())
+        .collect()
+}
 
-    // requirements.txt (Python)
-    if crate::utils::file_exists("requirements.txt") {
-        files.push("requirements.txt".to_string());
-    }
+/// Directory and file name of a manifest from `find_project_files`, the
+/// directory empty for the root.
+pub fn split_manifest(manifest: &str) -> (&Path, &str) {
+    let path = Path::new(manifest);
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(manifest);
+    (path.parent().unwrap_or(Path::new("")), name)
+}
 
-    // composer.json (PHP)
-    if crate::utils::file_exists("composer.json") {
-        files.push("composer.json".to_string());
+/// The directory of a manifest from `find_project_files(root, ..)`, where
+/// its package manager runs.
+pub fn manifest_dir(root: &Path, manifest: &str) -> PathBuf {
+    let dir = split_manifest(manifest).0;
+    if dir.as_os_str().is_empty() {
+        root.to_path_buf()
+    } else {
+        root.join(dir)
     }
-
-    files
 }
 
-fn detect_node_package_manager() -> Option<String> {
+pub fn detect_node_package_manager(dir: &Path) -> Option<String> {
     // Check for lock files to determine package manager
-    if crate::utils::file_exists("pnpm-lock.yaml") {
+    if crate::utils::file_exists(dir.join("bun.lockb"))
+        || crate::utils::file_exists(dir.join("bun.lock"))
+    {
+        Some("bun".to_string())
+    } else if crate::utils::file_exists(dir.join("pnpm-lock.yaml")) {
         Some("pnpm".to_string())
-    } else if crate::utils::file_exists("yarn.lock") {
+    } else if crate::utils::file_exists(dir.join("yarn.lock")) {
         Some("yarn".to_string())
-    } else if crate::utils::file_exists("package-lock.json") {
+    } else if crate::utils::file_exists(dir.join("package-lock.json")) {
         Some("npm".to_string())
     } else {
         // Default to checking which package managers are available
-        for pm in &["pnpm", "yarn", "npm"] {
+        for pm in &["pnpm", "yarn", "npm", "bun"] {
             if is_command_available(pm) {
                 return Some(pm.to_string());
             }
@@ -97,51 +486,70 @@ fn detect_node_package_manager() -> Option<String> {
     }
 }
 
-fn is_command_available(command: &str) -> bool {
-    // Windows için .cmd uzantısı da kontrol et
-    if cfg!(target_os = "windows") {
-        let cmd_version = format!("{}.cmd", command);
-        Command::new(command).arg("--version").output().is_ok()
-            || Command::new(&cmd_version).arg("--version").output().is_ok()
-    } else {
-        Command::new(command).arg("--version").output().is_ok()
+/// Poetry or uv, by lock file first and then by their `[tool.*]` table.
+/// None for projects that only list `[project]` dependencies.
+pub fn detect_python_package_manager(root: &Path) -> Option<String> {
+    if root.join("poetry.lock").exists() {
+        return Some("poetry".to_string());
+    }
+    if root.join("uv.lock").exists() {
+        return Some("uv".to_string());
     }
+    let content = fs::read_to_string(root.join("pyproject.toml")).ok()?;
+    content.lines().map(str::trim).find_map(|line| {
+        if line.starts_with("[tool.poetry") {
+            Some("poetry".to_string())
+        } else if line.starts_with("[tool.uv") {
+            Some("uv".to_string())
+        } else {
+            None
+        }
+    })
 }
 
-fn update_node_dependencies() {
+fn update_node_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Detecting Node.js package manager...");
 
-    match detect_node_package_manager() {
+    match detect_node_package_manager(dir) {
         Some(pm) => {
             log_info(&format!("Using package manager: {}", pm.cyan().bold()));
             // Backup lock files before updating
-            backup_lock_files(&pm);
+            result.backup_dir = backup_lock_files(dir, &pm);
+            result.package_manager = Some(pm.clone());
             match pm.as_str() {
-                "pnpm" => update_pnpm_dependencies(),
-                "yarn" => update_yarn_dependencies(),
-                "npm" => update_npm_dependencies(),
+                "pnpm" => update_pnpm_dependencies(dir, result),
+                "yarn" => update_yarn_dependencies(dir, result),
+                "npm" => update_npm_dependencies(dir, result),
+                "bun" => update_bun_dependencies(dir, result),
                 _ => log_warning("Unknown package manager detected"),
             }
         }
         None => {
-            log_warning("No Node.js package manager found (npm, yarn, or pnpm)");
+            log_warning("No Node.js package manager found (npm, yarn, pnpm or bun)");
+            log_info(
+                "Run 'nitroterm update-dependencies --native' for a lockfile-based outdated report",
+            );
         }
     }
 }
 
-fn backup_lock_files(package_manager: &str) {
+/// Copies the manifest and lock file in `dir` to `backup/<timestamp>` next
+/// to them and returns that directory when anything was copied.
+pub fn backup_lock_files(dir: &Path, package_manager: &str) -> Option<String> {
     log_info("Creating backup of lock files...");
-    let now: DateTime<Local> = Local::now();
-    let backup_dir = format!("./backup/{}", now.format("%Y%m%d%H%M%S"));
+    let backup_dir = backup_dir_in(dir);
     // Create backup directory
     if let Err(e) = fs::create_dir_all(&backup_dir) {
         log_error(&format!("Failed to create backup directory: {}", e));
-        return;
+        return None;
     }
     let mut backed_up_files = Vec::new();
     // Backup package.json first
-    if crate::utils::file_exists("package.json") {
-        if let Err(e) = fs::copy("package.json", format!("{}/package.json", backup_dir)) {
+    if crate::utils::file_exists(dir.join("package.json")) {
+        if let Err(e) = fs::copy(
+            dir.join("package.json"),
+            format!("{}/package.json", backup_dir),
+        ) {
             log_warning(&format!("Failed to backup package.json: {}", e));
         } else {
             backed_up_files.push("package.json".to_string());
@@ -152,11 +560,18 @@ fn backup_lock_files(package_manager: &str) {
         "pnpm" => vec!["pnpm-lock.yaml"],
         "yarn" => vec!["yarn.lock"],
         "npm" => vec!["package-lock.json"],
-        _ => vec!["package-lock.json", "yarn.lock", "pnpm-lock.yaml"], // Backup all if unknown
+        "bun" => vec!["bun.lockb", "bun.lock"],
+        _ => vec![
+            "package-lock.json",
+            "yarn.lock",
+            "pnpm-lock.yaml",
+            "bun.lockb",
+            "bun.lock",
+        ], // Backup all if unknown
     };
     for lock_file in lock_files {
-        if crate::utils::file_exists(lock_file) {
-            match fs::copy(lock_file, format!("{}/{}", backup_dir, lock_file)) {
+        if crate::utils::file_exists(dir.join(lock_file)) {
+            match fs::copy(dir.join(lock_file), format!("{}/{}", backup_dir, lock_file)) {
                 Ok(_) => {
                     backed_up_files.push(lock_file.to_string());
                 }
@@ -172,20 +587,18 @@ fn backup_lock_files(package_manager: &str) {
             backed_up_files.len(),
             backup_dir.cyan()
         ));
-        println!("  📁 Backed up files:");
-        for file in backed_up_files {
-            println!("    ✓ {}", file.green());
-        }
-        println!();
+        print_backed_up(&backed_up_files);
+        Some(backup_dir)
     } else {
         log_warning("No files were backed up");
         // Remove empty backup directory
         let _ = fs::remove_dir(&backup_dir);
+        None
     }
 }
 
-fn analyze_package_json() {
-    match read_file_to_string("package.json") {
+fn analyze_package_json(dir: &Path) {
+    match read_file_to_string(dir.join("package.json")) {
         Ok(content) => match serde_json::from_str::<Value>(&content) {
             Ok(package_json) => {
                 println!("{}", "📦 Node.js Dependencies:".blue().bold());
@@ -221,15 +634,24 @@ fn analyze_package_json() {
     }
 }
 
-fn update_yarn_dependencies() {
+fn update_yarn_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating yarn dependencies...");
 
     // Check yarn availability
     let yarn_available = if cfg!(target_os = "windows") {
-        Command::new("yarn").arg("--version").output().is_ok()
-            || Command::new("yarn.cmd").arg("--version").output().is_ok()
+        Command::new("yarn")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
+            || Command::new("yarn.cmd")
+                .arg("--version")
+                .supervised_output()
+                .is_ok()
     } else {
-        Command::new("yarn").arg("--version").output().is_ok()
+        Command::new("yarn")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
     };
 
     if !yarn_available {
@@ -239,24 +661,32 @@ fn update_yarn_dependencies() {
         if Command::new("npx")
             .arg("yarn")
             .arg("--version")
-            .output()
+            .supervised_output()
             .is_ok()
         {
             log_info("Found yarn via npx, using npx yarn...");
-            update_yarn_via_npx();
+            update_yarn_via_npx(dir, result);
             return;
         }
 
-        log_error("yarn not found in PATH. Please ensure yarn is installed and available in PATH.");
-        log_info("You can install yarn via:");
-        println!("  {} npm install -g yarn", "npm:".cyan());
-        println!("  {} choco install yarn", "chocolatey:".cyan());
-        println!("  {} scoop install yarn", "scoop:".cyan());
+        result.fail(
+            "yarn not found in PATH. Please ensure yarn is installed and available in PATH."
+                .to_string(),
+        );
+        if !is_json_output() {
+            log_info("You can install yarn via:");
+            println!("  {} npm install -g yarn", "npm:".cyan());
+            println!("  {} choco install yarn", "chocolatey:".cyan());
+            println!("  {} scoop install yarn", "scoop:".cyan());
+        }
         return;
     }
 
     let yarn_cmd = if cfg!(target_os = "windows")
-        && Command::new("yarn.cmd").arg("--version").output().is_ok()
+        && Command::new("yarn.cmd")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
     {
         "yarn.cmd"
     } else {
@@ -265,136 +695,135 @@ fn update_yarn_dependencies() {
 
     log_info(&format!("Using yarn command: {}", yarn_cmd.green()));
 
-    if let Ok(output) = Command::new(yarn_cmd).arg("--version").output() {
+    if let Ok(output) = Command::new(yarn_cmd).arg("--version").supervised_output() {
         let version = String::from_utf8_lossy(&output.stdout);
         log_info(&format!("Yarn version: {}", version.trim().cyan()));
     }
 
     log_info("Running yarn upgrade...");
-    print!("{}", "⏳ Upgrading packages...".yellow());
-    std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-    let start_time = Instant::now();
-    match Command::new(yarn_cmd).arg("upgrade").output() {
+    let spinner = Spinner::start("Upgrading packages...");
+    match Command::new(yarn_cmd)
+        .current_dir(dir)
+        .arg("upgrade")
+        .supervised_output()
+    {
         Ok(output) => {
-            let duration = start_time.elapsed();
-            println!(
-                " {}",
-                format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-            );
+            spinner.finish();
 
             if output.status.success() {
                 log_success("yarn dependencies updated successfully!");
+                result.status = UpdateStatus::Updated;
 
                 let stdout_str = String::from_utf8_lossy(&output.stdout);
                 if !stdout_str.trim().is_empty() {
-                    println!("{}", "📊 Update output:".cyan().bold());
-                    println!("{}", stdout_str);
+                    print_section("📊 Update output:".cyan().bold(), &stdout_str);
                 }
 
                 // Check for outdated packages
                 log_info("Checking for outdated packages...");
-                print!("{}", "⏳ Scanning for outdated packages...".yellow());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-                let start_time = Instant::now();
-                match Command::new(yarn_cmd).arg("outdated").output() {
+                let spinner = Spinner::start("Scanning for outdated packages...");
+                match Command::new(yarn_cmd)
+                    .current_dir(dir)
+                    .arg("outdated")
+                    .supervised_output()
+                {
                     Ok(outdated_output) => {
-                        let duration = start_time.elapsed();
-                        println!(
-                            " {}",
-                            format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                        );
+                        spinner.finish();
 
                         let outdated_str = String::from_utf8_lossy(&outdated_output.stdout);
-                        if !outdated_str.trim().is_empty() {
-                            println!("{}", "📊 Outdated packages:".yellow().bold());
-                            println!("{}", outdated_str);
-                        } else {
+                        if !result.record_outdated(&outdated_str) {
                             log_success("All yarn packages are up to date!");
                         }
                     }
                     Err(e) => {
-                        println!(" {}", "❌ Failed".red());
+                        spinner.fail();
                         log_warning(&format!("Could not check outdated packages: {}", e));
                     }
                 }
 
                 // Show yarn audit for security
                 log_info("Running security audit...");
-                print!("{}", "⏳ Running security audit...".yellow());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-                let start_time = Instant::now();
-                match Command::new(yarn_cmd).arg("audit").output() {
+                let spinner = Spinner::start("Running security audit...");
+                match Command::new(yarn_cmd)
+                    .current_dir(dir)
+                    .arg("audit")
+                    .supervised_output()
+                {
                     Ok(audit_output) => {
-                        let duration = start_time.elapsed();
-                        println!(
-                            " {}",
-                            format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                        );
+                        spinner.finish();
 
                         let audit_str = String::from_utf8_lossy(&audit_output.stdout);
                         if audit_str.contains("vulnerabilities") {
-                            println!("{}", "🔒 Security audit:".red().bold());
-                            println!("{}", audit_str);
+                            print_section("🔒 Security audit:".red().bold(), &audit_str);
                         } else {
                             log_success("No security vulnerabilities found!");
                         }
                     }
                     Err(e) => {
-                        println!(" {}", "❌ Failed".red());
+                        spinner.fail();
                         log_warning(&format!("Could not run security audit: {}", e));
                     }
                 }
             } else {
-                println!(" {}", "❌ Failed".red());
+                print_failed();
                 let error_msg = String::from_utf8_lossy(&output.stderr);
-                log_error(&format!("yarn upgrade failed: {}", error_msg));
+                result.fail(format!("yarn upgrade failed: {}", error_msg));
             }
         }
         Err(e) => {
-            println!(" {}", "❌ Failed".red());
-            log_error(&format!("Failed to run yarn upgrade: {}", e));
+            spinner.fail();
+            result.fail(format!("Failed to run yarn upgrade: {}", e));
         }
     }
 }
 
-fn update_yarn_via_npx() {
+fn update_yarn_via_npx(dir: &Path, result: &mut EcosystemResult) {
     log_info("Running yarn upgrade via npx...");
-    match Command::new("npx").arg("yarn").arg("upgrade").output() {
+    match Command::new("npx")
+        .current_dir(dir)
+        .arg("yarn")
+        .arg("upgrade")
+        .supervised_output()
+    {
         Ok(output) => {
             if output.status.success() {
                 log_success("yarn dependencies updated successfully via npx!");
+                result.status = UpdateStatus::Updated;
 
                 let stdout_str = String::from_utf8_lossy(&output.stdout);
                 if !stdout_str.trim().is_empty() {
-                    println!("{}", "📊 Update output:".cyan().bold());
-                    println!("{}", stdout_str);
+                    print_section("📊 Update output:".cyan().bold(), &stdout_str);
                 }
             } else {
                 let error_msg = String::from_utf8_lossy(&output.stderr);
-                log_error(&format!("yarn upgrade via npx failed: {}", error_msg));
+                result.fail(format!("yarn upgrade via npx failed: {}", error_msg));
             }
         }
         Err(e) => {
-            log_error(&format!("Failed to run yarn via npx: {}", e));
+            result.fail(format!("Failed to run yarn via npx: {}", e));
         }
     }
 }
 
-fn update_npm_dependencies() {
+fn update_npm_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating npm dependencies...");
 
     let npm_cmd = if cfg!(target_os = "windows")
-        && Command::new("npm.cmd").arg("--version").output().is_ok()
+        && Command::new("npm.cmd")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
     {
         "npm.cmd"
     } else {
         "npm"
     };
 
-    if Command::new(npm_cmd).arg("--version").output().is_err() {
+    if Command::new(npm_cmd)
+        .arg("--version")
+        .supervised_output()
+        .is_err()
+    {
         log_warning("npm not found. Skipping npm update.");
         return;
     }
@@ -402,73 +831,72 @@ fn update_npm_dependencies() {
     log_info(&format!("Using npm command: {}", npm_cmd.green()));
 
     log_info("Running npm update...");
-    print!("{}", "⏳ Updating packages...".yellow());
-    std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-    let start_time = Instant::now();
-    match Command::new(npm_cmd).arg("update").output() {
+    let spinner = Spinner::start("Updating packages...");
+    match Command::new(npm_cmd)
+        .current_dir(dir)
+        .arg("update")
+        .supervised_output()
+    {
         Ok(output) => {
-            let duration = start_time.elapsed();
-            println!(
-                " {}",
-                format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-            );
+            spinner.finish();
 
             if output.status.success() {
                 log_success("npm dependencies updated successfully!");
+                result.status = UpdateStatus::Updated;
 
                 // Check for outdated packages
                 log_info("Checking for outdated packages...");
-                print!("{}", "⏳ Scanning for outdated packages...".yellow());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-                let start_time = Instant::now();
-                match Command::new(npm_cmd).arg("outdated").output() {
+                let spinner = Spinner::start("Scanning for outdated packages...");
+                match Command::new(npm_cmd)
+                    .current_dir(dir)
+                    .arg("outdated")
+                    .supervised_output()
+                {
                     Ok(outdated_output) => {
-                        let duration = start_time.elapsed();
-                        println!(
-                            " {}",
-                            format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                        );
+                        spinner.finish();
 
                         let outdated_str = String::from_utf8_lossy(&outdated_output.stdout);
-                        if !outdated_str.trim().is_empty() {
-                            println!("{}", "📊 Outdated packages:".yellow().bold());
-                            println!("{}", outdated_str);
-                        } else {
+                        if !result.record_outdated(&outdated_str) {
                             log_success("All npm packages are up to date!");
                         }
                     }
                     Err(e) => {
-                        println!(" {}", "❌ Failed".red());
+                        spinner.fail();
                         log_warning(&format!("Could not check outdated packages: {}", e));
                     }
                 }
             } else {
-                println!(" {}", "❌ Failed".red());
+                print_failed();
                 let error_msg = String::from_utf8_lossy(&output.stderr);
-                log_error(&format!("npm update failed: {}", error_msg));
+                result.fail(format!("npm update failed: {}", error_msg));
             }
         }
         Err(e) => {
-            println!(" {}", "❌ Failed".red());
-            log_error(&format!("Failed to run npm update: {}", e));
+            spinner.fail();
+            result.fail(format!("Failed to run npm update: {}", e));
         }
     }
 }
 
-fn update_pnpm_dependencies() {
+fn update_pnpm_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating pnpm dependencies...");
 
     let pnpm_cmd = if cfg!(target_os = "windows")
-        && Command::new("pnpm.cmd").arg("--version").output().is_ok()
+        && Command::new("pnpm.cmd")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
     {
         "pnpm.cmd"
     } else {
         "pnpm"
     };
 
-    if Command::new(pnpm_cmd).arg("--version").output().is_err() {
+    if Command::new(pnpm_cmd)
+        .arg("--version")
+        .supervised_output()
+        .is_err()
+    {
         log_warning("pnpm not found. Skipping pnpm update.");
         return;
     }
@@ -476,91 +904,123 @@ fn update_pnpm_dependencies() {
     log_info(&format!("Using pnpm command: {}", pnpm_cmd.green()));
 
     log_info("Running pnpm update...");
-    print!("{}", "⏳ Updating packages...".yellow());
-    std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-    let start_time = Instant::now();
-    match Command::new(pnpm_cmd).arg("update").output() {
+    let spinner = Spinner::start("Updating packages...");
+    match Command::new(pnpm_cmd)
+        .current_dir(dir)
+        .arg("update")
+        .supervised_output()
+    {
         Ok(output) => {
-            let duration = start_time.elapsed();
-            println!(
-                " {}",
-                format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-            );
+            spinner.finish();
 
             if output.status.success() {
                 log_success("pnpm dependencies updated successfully!");
+                result.status = UpdateStatus::Updated;
 
                 // Check for outdated packages
                 log_info("Checking for outdated packages...");
-                print!("{}", "⏳ Scanning for outdated packages...".yellow());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-                let start_time = Instant::now();
-                match Command::new(pnpm_cmd).arg("outdated").output() {
+                let spinner = Spinner::start("Scanning for outdated packages...");
+                match Command::new(pnpm_cmd)
+                    .current_dir(dir)
+                    .arg("outdated")
+                    .supervised_output()
+                {
                     Ok(outdated_output) => {
-                        let duration = start_time.elapsed();
-                        println!(
-                            " {}",
-                            format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                        );
+                        spinner.finish();
 
                         let outdated_str = String::from_utf8_lossy(&outdated_output.stdout);
-                        if !outdated_str.trim().is_empty() {
-                            println!("{}", "📊 Outdated packages:".yellow().bold());
-                            println!("{}", outdated_str);
-                        } else {
+                        if !result.record_outdated(&outdated_str) {
                             log_success("All pnpm packages are up to date!");
                         }
                     }
                     Err(e) => {
-                        println!(" {}", "❌ Failed".red());
+                        spinner.fail();
                         log_warning(&format!("Could not check outdated packages: {}", e));
                     }
                 }
 
                 // Show pnpm audit for security
                 log_info("Running security audit...");
-                print!("{}", "⏳ Running security audit...".yellow());
-                std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-                let start_time = Instant::now();
-                match Command::new(pnpm_cmd).arg("audit").output() {
+                let spinner = Spinner::start("Running security audit...");
+                match Command::new(pnpm_cmd)
+                    .current_dir(dir)
+                    .arg("audit")
+                    .supervised_output()
+                {
                     Ok(audit_output) => {
-                        let duration = start_time.elapsed();
-                        println!(
-                            " {}",
-                            format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                        );
+                        spinner.finish();
 
                         let audit_str = String::from_utf8_lossy(&audit_output.stdout);
                         if !audit_str.trim().is_empty() && audit_str.contains("vulnerabilities") {
-                            println!("{}", "🔒 Security audit:".red().bold());
-                            println!("{}", audit_str);
+                            print_section("🔒 Security audit:".red().bold(), &audit_str);
                         } else {
                             log_success("No security vulnerabilities found!");
                         }
                     }
                     Err(e) => {
-                        println!(" {}", "❌ Failed".red());
+                        spinner.fail();
                         log_warning(&format!("Could not run security audit: {}", e));
                     }
                 }
             } else {
-                println!(" {}", "❌ Failed".red());
+                print_failed();
                 let error_msg = String::from_utf8_lossy(&output.stderr);
-                log_error(&format!("pnpm update failed: {}", error_msg));
+                result.fail(format!("pnpm update failed: {}", error_msg));
             }
         }
         Err(e) => {
-            println!(" {}", "❌ Failed".red());
-            log_error(&format!("Failed to run pnpm update: {}", e));
+            spinner.fail();
+            result.fail(format!("Failed to run pnpm update: {}", e));
         }
     }
 }
 
-fn analyze_cargo_toml() {
-    match read_file_to_string("Cargo.toml") {
+fn update_bun_dependencies(dir: &Path, result: &mut EcosystemResult) {
+    log_info("Updating bun dependencies...");
+    if !is_command_available("bun") {
+        log_warning("bun not found. Skipping bun update.");
+        return;
+    }
+    run_update(dir, result, &["update"], &["outdated"]);
+}
+
+fn analyze_deno_json(dir: &Path, file: &str) {
+    match read_file_to_string(dir.join(file)) {
+        Ok(content) => match serde_json::from_str::<Value>(&content) {
+            Ok(deno_json) => {
+                println!("{}", "🦕 Deno Imports:".green().bold());
+                if let Some(imports) = deno_json["imports"].as_object() {
+                    for (name, specifier) in imports {
+                        println!(
+                            "  {} -> {}",
+                            name.green(),
+                            specifier.as_str().unwrap_or("unknown")
+                        );
+                    }
+                }
+            }
+            // deno.jsonc may have comments, the update still works
+            Err(e) => log_warning(&format!("Could not parse {}: {}", file, e)),
+        },
+        Err(e) => {
+            log_error(&format!("Failed to read {}: {}", file, e));
+        }
+    }
+}
+
+fn update_deno_dependencies(dir: &Path, result: &mut EcosystemResult) {
+    log_info("Updating Deno dependencies...");
+    if !is_command_available("deno") {
+        log_warning("deno not found. Skipping deno update.");
+        return;
+    }
+    result.package_manager = Some("deno".to_string());
+    result.backup_dir = backup_deno_files(dir);
+    run_update(dir, result, &["outdated", "--update"], &["outdated"]);
+}
+
+fn analyze_cargo_toml(dir: &Path) {
+    match read_file_to_string(dir.join("Cargo.toml")) {
         Ok(content) => {
             println!("{}", "🦀 Rust Dependencies:".red().bold());
 
@@ -590,74 +1050,80 @@ fn analyze_cargo_toml() {
     }
 }
 
-fn update_cargo_dependencies() {
+fn update_cargo_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating Cargo dependencies...");
+    result.package_manager = Some("cargo".to_string());
     // Backup Cargo files before updating
-    backup_cargo_files();
+    result.backup_dir = backup_cargo_files(dir);
     // Check if cargo is available
-    match Command::new("cargo").arg("--version").output() {
+    match Command::new("cargo").arg("--version").supervised_output() {
         Ok(_) => {
             log_info("Running cargo update...");
-            print!("{}", "⏳ Updating packages...".yellow());
-            std::io::Write::flush(&mut std::io::stdout()).unwrap();
-
-            let start_time = Instant::now();
-            match Command::new("cargo").arg("update").output() {
+            let spinner = Spinner::start("Updating packages...");
+            match Command::new("cargo")
+                .current_dir(dir)
+                .arg("update")
+                .supervised_output()
+            {
                 Ok(output) => {
-                    let duration = start_time.elapsed();
-                    println!(
-                        " {}",
-                        format!("✅ Completed in {:.2}s", duration.as_secs_f64()).green()
-                    );
+                    spinner.finish();
 
                     if output.status.success() {
                         log_success("Cargo dependencies updated successfully!");
+                        result.status = UpdateStatus::Updated;
+                        if read_file_to_string(dir.join("Cargo.toml"))
+                            .is_ok_and(|content| content.contains("[workspace]"))
+                        {
+                            log_info("Cargo workspace: run 'nitroterm update-dependencies --workspace' for outdated requirements per crate");
+                        }
 
                         // Show updated dependencies
                         let output_str = String::from_utf8_lossy(&output.stderr);
                         if !output_str.trim().is_empty() {
-                            println!("{}", "📊 Update details:".cyan().bold());
-                            println!("{}", output_str);
+                            print_section("📊 Update details:".cyan().bold(), &output_str);
                         }
                     } else {
-                        println!(" {}", "❌ Failed".red());
+                        print_failed();
                         let error_msg = String::from_utf8_lossy(&output.stderr);
-                        log_error(&format!("cargo update failed: {}", error_msg));
+                        result.fail(format!("cargo update failed: {}", error_msg));
                     }
                 }
                 Err(e) => {
-                    println!(" {}", "❌ Failed".red());
-                    log_error(&format!("Failed to run cargo update: {}", e));
+                    spinner.fail();
+                    result.fail(format!("Failed to run cargo update: {}", e));
                 }
             }
         }
         Err(_) => {
             log_warning("cargo not found. Skipping cargo update.");
+            log_info(
+                "Run 'nitroterm update-dependencies --native' for a lockfile-based outdated report",
+            );
         }
     }
 }
 
-fn backup_cargo_files() {
+/// Copies Cargo.toml and Cargo.lock in `dir` to `backup/<timestamp>`.
+pub fn backup_cargo_files(dir: &Path) -> Option<String> {
     log_info("Creating backup of Cargo files...");
-    let now: DateTime<Local> = Local::now();
-    let backup_dir = format!("./backup/{}", now.format("%Y%m%d%H%M%S"));
+    let backup_dir = backup_dir_in(dir);
     // Create backup directory
     if let Err(e) = fs::create_dir_all(&backup_dir) {
         log_error(&format!("Failed to create backup directory: {}", e));
-        return;
+        return None;
     }
     let mut backed_up_files = Vec::new();
     // Backup Cargo.toml
-    if crate::utils::file_exists("Cargo.toml") {
-        if let Err(e) = fs::copy("Cargo.toml", format!("{}/Cargo.toml", backup_dir)) {
+    if crate::utils::file_exists(dir.join("Cargo.toml")) {
+        if let Err(e) = fs::copy(dir.join("Cargo.toml"), format!("{}/Cargo.toml", backup_dir)) {
             log_warning(&format!("Failed to backup Cargo.toml: {}", e));
         } else {
             backed_up_files.push("Cargo.toml".to_string());
         }
     }
     // Backup Cargo.lock
-    if crate::utils::file_exists("Cargo.lock") {
-        if let Err(e) = fs::copy("Cargo.lock", format!("{}/Cargo.lock", backup_dir)) {
+    if crate::utils::file_exists(dir.join("Cargo.lock")) {
+        if let Err(e) = fs::copy(dir.join("Cargo.lock"), format!("{}/Cargo.lock", backup_dir)) {
             log_warning(&format!("Failed to backup Cargo.lock: {}", e));
         } else {
             backed_up_files.push("Cargo.lock".to_string());
@@ -669,20 +1135,29 @@ fn backup_cargo_files() {
             backed_up_files.len(),
             backup_dir.cyan()
         ));
-        println!("  📁 Backed up files:");
-        for file in backed_up_files {
-            println!("    ✓ {}", file.green());
-        }
-        println!();
+        print_backed_up(&backed_up_files);
+        Some(backup_dir)
     } else {
         log_warning("No Cargo files were backed up");
         // Remove empty backup directory
         let _ = fs::remove_dir(&backup_dir);
+        None
+    }
+}
+
+fn print_backed_up(files: &[String]) {
+    if is_json_output() {
+        return;
+    }
+    println!("  📁 Backed up files:");
+    for file in files {
+        println!("    ✓ {}", file.green());
     }
+    println!();
 }
 
-fn analyze_requirements_txt() {
-    match read_file_to_string("requirements.txt") {
+fn analyze_requirements_txt(dir: &Path) {
+    match read_file_to_string(dir.join("requirements.txt")) {
         Ok(content) => {
             println!("{}", "🐍 Python Dependencies:".yellow().bold());
 
@@ -699,45 +1174,49 @@ fn analyze_requirements_txt() {
     }
 }
 
-fn update_pip_dependencies() {
+fn update_pip_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating pip dependencies...");
+    result.package_manager = Some("pip".to_string());
 
     // Check if pip is available
-    match Command::new("pip").arg("--version").output() {
+    match Command::new("pip").arg("--version").supervised_output() {
         Ok(_) => {
             // Try to update packages from requirements.txt
-            if crate::utils::file_exists("requirements.txt") {
+            if crate::utils::file_exists(dir.join("requirements.txt")) {
                 log_info("Upgrading packages from requirements.txt...");
                 match Command::new("pip")
+                    .current_dir(dir)
                     .arg("install")
                     .arg("--upgrade")
                     .arg("-r")
                     .arg("requirements.txt")
-                    .output()
+                    .supervised_output()
                 {
                     Ok(output) => {
                         if output.status.success() {
                             log_success("pip dependencies updated successfully!");
+                            result.status = UpdateStatus::Updated;
                         } else {
                             let error_msg = String::from_utf8_lossy(&output.stderr);
-                            log_error(&format!("pip update failed: {}", error_msg));
+                            result.fail(format!("pip update failed: {}", error_msg));
                         }
                     }
                     Err(e) => {
-                        log_error(&format!("Failed to run pip update: {}", e));
+                        result.fail(format!("Failed to run pip update: {}", e));
                     }
                 }
             }
 
             // Check for outdated packages
             log_info("Checking for outdated packages...");
-            match Command::new("pip").arg("list").arg("--outdated").output() {
+            match Command::new("pip")
+                .arg("list")
+                .arg("--outdated")
+                .supervised_output()
+            {
                 Ok(output) => {
                     let outdated_str = String::from_utf8_lossy(&output.stdout);
-                    if !outdated_str.trim().is_empty() {
-                        println!("{}", "📊 Outdated packages:".yellow().bold());
-                        println!("{}", outdated_str);
-                    } else {
+                    if !result.record_outdated(&outdated_str) {
                         log_success("All pip packages are up to date!");
                     }
                 }
@@ -748,12 +1227,247 @@ fn update_pip_dependencies() {
         }
         Err(_) => {
             log_warning("pip not found. Skipping pip update.");
+            log_info(
+                "Run 'nitroterm update-dependencies --native' for a lockfile-based outdated report",
+            );
+        }
+    }
+}
+
+/// Requirements from `[project] dependencies` and the Poetry dependency
+/// tables, as written; Poetry's `python` constraint is left out.
+pub fn pyproject_dependencies(content: &str) -> Vec<String> {
+    let mut deps = Vec::new();
+    let mut section = String::new();
+    let mut in_array = false;
+    let quoted = |item: &str| -> Option<String> {
+        let item = item.trim().trim_end_matches(',').trim();
+        let unquoted = item.trim_matches(|c| c == '"' || c == '\'');
+        (unquoted.len() + 2 == item.len() && !unquoted.is_empty()).then(|| unquoted.to_string())
+    };
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if in_array {
+            let (items, closed) = match trimmed.split_once(']') {
+                Some((items, _)) => (items, true),
+                None => (trimmed, false),
+            };
+            deps.extend(items.split(',').filter_map(quoted));
+            in_array = !closed;
+            continue;
+        }
+        if trimmed.starts_with('[') {
+            section = trimmed.trim_matches(|c| c == '[' || c == ']').to_string();
+            continue;
+        }
+        if section == "project" {
+            if let Some(rest) = trimmed.strip_prefix("dependencies") {
+                if let Some(rest) = rest.trim_start().strip_prefix('=') {
+                    let rest = rest.trim().trim_start_matches('[');
+                    let (items, closed) = match rest.split_once(']') {
+                        Some((items, _)) => (items, true),
+                        None => (rest, false),
+                    };
+                    deps.extend(items.split(',').filter_map(quoted));
+                    in_array = !closed;
+                }
+            }
+        } else if section.starts_with("tool.poetry") && section.ends_with("dependencies") {
+            if let Some((name, constraint)) = trimmed.split_once('=') {
+                let name = name.trim();
+                if !name.is_empty() && name != "python" && !name.starts_with('#') {
+                    deps.push(format!("{} {}", name, constraint.trim()));
+                }
+            }
         }
     }
+    deps
 }
 
-fn analyze_composer_json() {
-    match read_file_to_string("composer.json") {
+fn analyze_pyproject_toml(dir: &Path) {
+    match read_file_to_string(dir.join("pyproject.toml")) {
+        Ok(content) => {
+            println!(
+                "{}",
+                "🐍 Python Dependencies (pyproject.toml):".yellow().bold()
+            );
+            for dependency in pyproject_dependencies(&content) {
+                println!("  {}", dependency.green());
+            }
+        }
+        Err(e) => {
+            log_error(&format!("Failed to read pyproject.toml: {}", e));
+        }
+    }
+}
+
+fn update_pyproject_dependencies(dir: &Path, result: &mut EcosystemResult) {
+    let package_manager = match detect_python_package_manager(dir) {
+        Some(package_manager) => package_manager,
+        None => {
+            log_warning("pyproject.toml without Poetry or uv, skipping update");
+            log_info("Add a poetry.lock or uv.lock to let nitroterm update these dependencies");
+            return;
+        }
+    };
+    if !is_command_available(&package_manager) {
+        log_warning(&format!(
+            "{} not found. Skipping pyproject.toml update.",
+            package_manager
+        ));
+        return;
+    }
+    log_info(&format!(
+        "Using package manager: {}",
+        package_manager.cyan().bold()
+    ));
+    result.package_manager = Some(package_manager.clone());
+    result.backup_dir = backup_python_files(dir, &package_manager);
+    match package_manager.as_str() {
+        "poetry" => run_update(
+            dir,
+            result,
+            &["update"],
+            &["show", "--outdated", "--top-level"],
+        ),
+        _ => run_update(
+            dir,
+            result,
+            &["lock", "--upgrade"],
+            &["pip", "list", "--outdated"],
+        ),
+    }
+}
+
+/// Runs the update with `result.package_manager`, then lists what is still
+/// outdated.
+fn run_update(
+    dir: &Path,
+    result: &mut EcosystemResult,
+    update_args: &[&str],
+    outdated_args: &[&str],
+) {
+    let package_manager = result.package_manager.clone().unwrap_or_default();
+    let command = format!("{} {}", package_manager, update_args.join(" "));
+    log_info(&format!("Running {}...", command));
+    let spinner = Spinner::start("Updating packages...");
+    match Command::new(&package_manager)
+        .current_dir(dir)
+        .args(update_args)
+        .supervised_output()
+    {
+        Ok(output) => {
+            if output.status.success() {
+                spinner.finish();
+                log_success(&format!(
+                    "{} dependencies updated successfully!",
+                    package_manager
+                ));
+                result.status = UpdateStatus::Updated;
+                // uv and deno report the changed versions on stderr
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let details = if stdout.trim().is_empty() {
+                    String::from_utf8_lossy(&output.stderr)
+                } else {
+                    stdout
+                };
+                if !details.trim().is_empty() {
+                    print_section("📊 Update details:".cyan().bold(), &details);
+                }
+            } else {
+                spinner.fail();
+                print_failed();
+                let error_msg = String::from_utf8_lossy(&output.stderr);
+                result.fail(format!("{} failed: {}", command, error_msg));
+                return;
+            }
+        }
+        Err(e) => {
+            spinner.fail();
+            result.fail(format!("Failed to run {}: {}", command, e));
+            return;
+        }
+    }
+
+    log_info("Checking for outdated packages...");
+    match Command::new(&package_manager)
+        .current_dir(dir)
+        .args(outdated_args)
+        .supervised_output()
+    {
+        Ok(output) => {
+            let outdated_str = String::from_utf8_lossy(&output.stdout);
+            if !result.record_outdated(&outdated_str) {
+                log_success(&format!("All {} packages are up to date!", package_manager));
+            }
+        }
+        Err(e) => {
+            log_warning(&format!("Could not check outdated packages: {}", e));
+        }
+    }
+}
+
+/// Copies pyproject.toml and the Poetry or uv lock file in `dir` to
+/// `backup/<timestamp>`.
+pub fn backup_python_files(dir: &Path, package_manager: &str) -> Option<String> {
+    let files: &[&str] = match package_manager {
+        "poetry" => &["pyproject.toml", "poetry.lock"],
+        "uv" => &["pyproject.toml", "uv.lock"],
+        _ => &["pyproject.toml", "poetry.lock", "uv.lock"],
+    };
+    backup_files(dir, "Python", files)
+}
+
+/// Copies deno.json(c) and deno.lock in `dir` to `backup/<timestamp>`.
+pub fn backup_deno_files(dir: &Path) -> Option<String> {
+    backup_files(dir, "Deno", &["deno.json", "deno.jsonc", "deno.lock"])
+}
+
+/// `backup/<timestamp>` in `dir`, for a backup made now.
+fn backup_dir_in(dir: &Path) -> String {
+    let now: DateTime<Local> = Local::now();
+    dir.join("backup")
+        .join(now.format("%Y%m%d%H%M%S").to_string())
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Copies the `files` in `dir` that exist to `backup/<timestamp>`.
+fn backup_files(dir: &Path, kind: &str, files: &[&str]) -> Option<String> {
+    log_info(&format!("Creating backup of {} files...", kind));
+    let backup_dir = backup_dir_in(dir);
+    if let Err(e) = fs::create_dir_all(&backup_dir) {
+        log_error(&format!("Failed to create backup directory: {}", e));
+        return None;
+    }
+    let mut backed_up_files = Vec::new();
+    for file in files {
+        if crate::utils::file_exists(dir.join(file)) {
+            match fs::copy(dir.join(file), format!("{}/{}", backup_dir, file)) {
+                Ok(_) => backed_up_files.push(file.to_string()),
+                Err(e) => log_warning(&format!("Failed to backup {}: {}", file, e)),
+            }
+        }
+    }
+    if !backed_up_files.is_empty() {
+        log_success(&format!(
+            "Backed up {} {} files to: {}",
+            backed_up_files.len(),
+            kind,
+            backup_dir.cyan()
+        ));
+        print_backed_up(&backed_up_files);
+        Some(backup_dir)
+    } else {
+        log_warning(&format!("No {} files were backed up", kind));
+        let _ = fs::remove_dir(&backup_dir);
+        None
+    }
+}
+
+fn analyze_composer_json(dir: &Path) {
+    match read_file_to_string(dir.join("composer.json")) {
         Ok(content) => match serde_json::from_str::<Value>(&content) {
             Ok(composer_json) => {
                 println!("{}", "🐘 PHP Dependencies:".purple().bold());
@@ -789,27 +1503,37 @@ fn analyze_composer_json() {
     }
 }
 
-fn update_composer_dependencies() {
+fn update_composer_dependencies(dir: &Path, result: &mut EcosystemResult) {
     log_info("Updating Composer dependencies...");
+    result.package_manager = Some("composer".to_string());
 
     // Check if composer is available
-    match Command::new("composer").arg("--version").output() {
+    match Command::new("composer")
+        .arg("--version")
+        .supervised_output()
+    {
         Ok(_) => {
             log_info("Running composer update...");
-            match Command::new("composer").arg("update").output() {
+            match Command::new("composer")
+                .current_dir(dir)
+                .arg("update")
+                .supervised_output()
+            {
                 Ok(output) => {
                     if output.status.success() {
                         log_success("Composer dependencies updated successfully!");
+                        result.status = UpdateStatus::Updated;
 
                         // Show outdated packages
                         log_info("Checking for outdated packages...");
-                        match Command::new("composer").arg("outdated").output() {
+                        match Command::new("composer")
+                            .current_dir(dir)
+                            .arg("outdated")
+                            .supervised_output()
+                        {
                             Ok(outdated_output) => {
                                 let outdated_str = String::from_utf8_lossy(&outdated_output.stdout);
-                                if !outdated_str.trim().is_empty() {
-                                    println!("{}", "📊 Outdated packages:".yellow().bold());
-                                    println!("{}", outdated_str);
-                                } else {
+                                if !result.record_outdated(&outdated_str) {
                                     log_success("All Composer packages are up to date!");
                                 }
                             }
@@ -819,11 +1543,11 @@ fn update_composer_dependencies() {
                         }
                     } else {
                         let error_msg = String::from_utf8_lossy(&output.stderr);
-                        log_error(&format!("composer update failed: {}", error_msg));
+                        result.fail(format!("composer update failed: {}", error_msg));
                     }
                 }
                 Err(e) => {
-                    log_error(&format!("Failed to run composer update: {}", e));
+                    result.fail(format!("Failed to run composer update: {}", e));
                 }
             }
         }
@@ -0,0 +1,323 @@
+use crate::commands::contributors::{self, collect_contributors, resolve_commit, ContributorStats};
+use crate::commands::release_notes::DiffStats;
+use crate::utils::{get_repository, write_string_to_file};
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::{Delta, DiffFindOptions, DiffOptions, Patch, Repository};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default)]
+pub struct DiffReportOptions {
+    pub path: PathBuf,
+    pub from: String,
+    /// HEAD by default
+    pub to: Option<String>,
+    /// Group by the first N path components instead of the full directory
+    pub depth: Option<usize>,
+    /// Write the Markdown here instead of printing it
+    pub output: Option<String>,
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+impl FileStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            FileStatus::Added => "added",
+            FileStatus::Modified => "modified",
+            FileStatus::Deleted => "deleted",
+            FileStatus::Renamed => "renamed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangedFile {
+    pub path: String,
+    /// Path in `from`, only set for renamed files
+    pub old_path: Option<String>,
+    pub status: FileStatus,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DirectoryChanges {
+    pub directory: String,
+    pub insertions: usize,
+    pub deletions: usize,
+    /// Most changed lines first
+    pub files: Vec<ChangedFile>,
+}
+
+impl DirectoryChanges {
+    pub fn lines(&self) -> usize {
+        self.insertions + self.deletions
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffReport {
+    pub from: String,
+    pub to: String,
+    pub totals: DiffStats,
+    /// Non-merge commits in `from..to`
+    pub commits: usize,
+    pub directories: Vec<DirectoryChanges>,
+    pub contributors: Vec<ContributorStats>,
+}
+
+impl DiffReport {
+    pub fn files(&self) -> impl Iterator<Item = &ChangedFile> {
+        self.directories
+            .iter()
+            .flat_map(|directory| directory.files.iter())
+    }
+
+    pub fn count(&self, status: FileStatus) -> usize {
+        self.files().filter(|file| file.status == status).count()
+    }
+}
+
+/// Files changed between two trees. Renames are detected, so a moved file
+/// shows up once with the lines changed while moving it.
+pub fn changed_files(
+    repo: &Repository,
+    old_tree: &git2::Tree,
+    new_tree: &git2::Tree,
+) -> Result<Vec<ChangedFile>> {
+    let mut diff = repo.diff_tree_to_tree(
+        Some(old_tree),
+        Some(new_tree),
+        Some(DiffOptions::new().ignore_submodules(true)),
+    )?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true)))?;
+
+    let path_of = |file: git2::DiffFile| {
+        file.path()
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default()
+    };
+    let mut files = Vec::new();
+    for index in 0..diff.deltas().len() {
+        let Some(delta) = diff.get_delta(index) else {
+            continue;
+        };
+        let status = match delta.status() {
+            Delta::Added | Delta::Copied => FileStatus::Added,
+            Delta::Deleted => FileStatus::Deleted,
+            Delta::Renamed => FileStatus::Renamed,
+            _ => FileStatus::Modified,
+        };
+        let path = match status {
+            FileStatus::Deleted => path_of(delta.old_file()),
+            _ => path_of(delta.new_file()),
+        };
+        let old_path = (status == FileStatus::Renamed).then(|| path_of(delta.old_file()));
+        // Binary files have no patch lines but still count as changed
+        let (insertions, deletions) = match Patch::from_diff(&diff, index)? {
+            Some(patch) => {
+                let (_, insertions, deletions) = patch.line_stats()?;
+                (insertions, deletions)
+            }
+            None => (0, 0),
+        };
+        files.push(ChangedFile {
+            path,
+            old_path,
+            status,
+            insertions,
+            deletions,
+        });
+    }
+    Ok(files)
+}
+
+/// Parent directory of `path`, cut to `depth` components when given. Files
+/// in the root are grouped under `.`.
+pub fn directory_of(path: &str, depth: Option<usize>) -> String {
+    let parent = match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => {
+            parent.to_string_lossy().replace('\\', "/")
+        }
+        _ => return ".".to_string(),
+    };
+    match depth {
+        Some(depth) if depth > 0 => parent.split('/').take(depth).collect::<Vec<_>>().join("/"),
+        _ => parent,
+    }
+}
+
+/// Directories with the most changed lines first, and the same order for
+/// the files inside each.
+pub fn group_by_directory(files: Vec<ChangedFile>, depth: Option<usize>) -> Vec<DirectoryChanges> {
+    let mut grouped: BTreeMap<String, Vec<ChangedFile>> = BTreeMap::new();
+    for file in files {
+        grouped
+            .entry(directory_of(&file.path, depth))
+            .or_default()
+            .push(file);
+    }
+
+    let mut directories: Vec<DirectoryChanges> = grouped
+        .into_iter()
+        .map(|(directory, mut files)| {
+            files.sort_by(|a, b| {
+                (b.insertions + b.deletions)
+                    .cmp(&(a.insertions + a.deletions))
+                    .then(a.path.cmp(&b.path))
+            });
+            DirectoryChanges {
+                directory,
+                insertions: files.iter().map(|file| file.insertions).sum(),
+                deletions: files.iter().map(|file| file.deletions).sum(),
+                files,
+            }
+        })
+        .collect();
+    directories.sort_by(|a, b| {
+        b.lines()
+            .cmp(&a.lines())
+            .then(a.directory.cmp(&b.directory))
+    });
+    directories
+}
+
+pub fn build_diff_report(
+    repo: &Repository,
+    from: &str,
+    to: Option<&str>,
+    depth: Option<usize>,
+) -> Result<DiffReport> {
+    let to = to.unwrap_or("HEAD");
+    let old_tree = repo.find_commit(resolve_commit(repo, from)?)?.tree()?;
+    let new_tree = repo.find_commit(resolve_commit(repo, to)?)?.tree()?;
+    let files = changed_files(repo, &old_tree, &new_tree)?;
+    let totals = DiffStats {
+        files_changed: files.len(),
+        insertions: files.iter().map(|file| file.insertions).sum(),
+        deletions: files.iter().map(|file| file.deletions).sum(),
+    };
+    let contributors = collect_contributors(repo, Some(from), Some(to))?;
+
+    Ok(DiffReport {
+        from: from.to_string(),
+        to: to.to_string(),
+        totals,
+        commits: contributors.iter().map(|c| c.commits).sum(),
+        directories: group_by_directory(files, depth),
+        contributors,
+    })
+}
+
+pub fn render_markdown(report: &DiffReport) -> String {
+    let mut output = format!("# Changes from {} to {}\n\n", report.from, report.to);
+    output.push_str(&format!(
+        "**{} file(s) changed**, +{} / -{} lines in {} commit(s) by {} contributor(s).\n\n",
+        report.totals.files_changed,
+        report.totals.insertions,
+        report.totals.deletions,
+        report.commits,
+        report.contributors.len()
+    ));
+    if report.totals.files_changed == 0 {
+        return output;
+    }
+
+    output.push_str("| Added | Modified | Deleted | Renamed |\n|---|---|---|---|\n");
+    output.push_str(&format!(
+        "| {} | {} | {} | {} |\n\n",
+        report.count(FileStatus::Added),
+        report.count(FileStatus::Modified),
+        report.count(FileStatus::Deleted),
+        report.count(FileStatus::Renamed)
+    ));
+
+    output.push_str("## Changes by directory\n\n");
+    output.push_str("| Directory | Files | Lines added | Lines removed |\n|---|---|---|---|\n");
+    for directory in &report.directories {
+        output.push_str(&format!(
+            "| `{}` | {} | +{} | -{} |\n",
+            directory.directory,
+            directory.files.len(),
+            directory.insertions,
+            directory.deletions
+        ));
+    }
+    for directory in &report.directories {
+        output.push_str(&format!("\n### `{}`\n\n", directory.directory));
+        output.push_str("| File | Status | Lines added | Lines removed |\n|---|---|---|---|\n");
+        for file in &directory.files {
+            output.push_str(&format!(
+                "| `{}` | {} | +{} | -{} |\n",
+                file.path,
+                file.status.label(),
+                file.insertions,
+                file.deletions
+            ));
+        }
+    }
+
+    let renamed: Vec<&ChangedFile> = report
+        .files()
+        .filter(|file| file.status == FileStatus::Renamed)
+        .collect();
+    if !renamed.is_empty() {
+        output.push_str("\n## Renamed files\n\n");
+        for file in renamed {
+            output.push_str(&format!(
+                "- `{}` → `{}`\n",
+                file.old_path.as_deref().unwrap_or_default(),
+                file.path
+            ));
+        }
+    }
+
+    if !report.contributors.is_empty() {
+        output.push_str("\n## Contributors\n\n");
+        output.push_str(&contributors::render_markdown(&report.contributors));
+    }
+    output
+}
+
+pub async fn run_diff_report(options: DiffReportOptions) -> Result<()> {
+    let repo = get_repository(&options.path.to_string_lossy())
+        .map_err(|e| anyhow!("Not a git repository: {}", e))?;
+    let report = build_diff_report(&repo, &options.from, options.to.as_deref(), options.depth)?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    let markdown = render_markdown(&report);
+    match &options.output {
+        Some(path) => {
+            write_string_to_file(path, &markdown)?;
+            println!(
+                "{}",
+                format!(
+                    "📄 Diff report for {}..{} written to {} ({} files, +{} / -{})",
+                    report.from,
+                    report.to,
+                    path,
+                    report.totals.files_changed,
+                    report.totals.insertions,
+                    report.totals.deletions
+                )
+                .green()
+            );
+        }
+        None => print!("{}", markdown),
+    }
+    Ok(())
+}
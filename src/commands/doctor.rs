@@ -0,0 +1,278 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::translation_sync::provider_settings;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::{ensure_network_allowed, ensure_not_sandboxed};
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+use std::time::Duration;
+
+/// Any HTTP response from here counts as "online".
+pub const NETWORK_CHECK_URL: &str = "https://api.github.com";
+
+/// External tools the features shell out to, with the fix for a missing one.
+pub const TOOLS: [(&str, &str); 8] = [
+    ("git", "Install git from https://git-scm.com/downloads"),
+    (
+        "gh",
+        "Install the GitHub CLI from https://cli.github.com, then run 'gh auth login'",
+    ),
+    ("npm", "Install Node.js from https://nodejs.org"),
+    ("yarn", "Run 'corepack enable' or 'npm install -g yarn'"),
+    ("pnpm", "Run 'corepack enable' or 'npm install -g pnpm'"),
+    ("cargo", "Install Rust with https://rustup.rs"),
+    (
+        "pip",
+        "Install Python 3 with pip from https://www.python.org/downloads",
+    ),
+    (
+        "composer",
+        "Install Composer from https://getcomposer.org/download",
+    ),
+];
+
+/// Features `doctor --feature` can check the requirements of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    Release,
+    Github,
+    Dependencies,
+    Translation,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 4] = [
+        Feature::Release,
+        Feature::Github,
+        Feature::Dependencies,
+        Feature::Translation,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feature::Release => "release",
+            Feature::Github => "github",
+            Feature::Dependencies => "dependencies",
+            Feature::Translation => "translation",
+        }
+    }
+
+    /// Checks the feature needs in `root`. Each entry is a set of
+    /// alternatives, one passing check of a set is enough.
+    pub fn requirements(&self, root: &Path) -> Vec<Vec<&'static str>> {
+        match self {
+            Feature::Release => vec![vec!["git"]],
+            Feature::Github => vec![vec!["git"], vec!["gh"], vec!["network"]],
+            Feature::Dependencies => {
+                let mut requirements = Vec::new();
+                if root.join("Cargo.toml").exists() {
+                    requirements.push(vec!["cargo"]);
+                }
+                if root.join("package.json").exists() {
+                    requirements.push(vec!["npm", "yarn", "pnpm"]);
+                }
+                if ["requirements.txt", "pyproject.toml"]
+                    .iter()
+                    .any(|file| root.join(file).exists())
+                {
+                    requirements.push(vec!["pip"]);
+                }
+                if root.join("composer.json").exists() {
+                    requirements.push(vec!["composer"]);
+                }
+                requirements
+            }
+            Feature::Translation => vec![vec!["api key"], vec!["network"]],
+        }
+    }
+}
+
+impl std::str::FromStr for Feature {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Feature::ALL
+            .into_iter()
+            .find(|feature| feature.name() == value)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown feature '{}', use release, github, dependencies or translation",
+                    value
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoctorCheck {
+    pub name: String,
+    pub passed: bool,
+    /// Version, key source or error
+    pub detail: String,
+    pub hint: String,
+}
+
+/// Runs `<command> --version`, with the `.cmd` shim on Windows and `pip3`
+/// for `pip`. Returns the first line of the output.
+pub fn tool_version(runner: &dyn CommandRunner, command: &str) -> Option<String> {
+    let mut candidates = vec![command.to_string()];
+    if cfg!(target_os = "windows") {
+        candidates.push(format!("{}.cmd", command));
+    }
+    if command == "pip" {
+        candidates.push("pip3".to_string());
+    }
+
+    candidates.iter().find_map(|candidate| {
+        let output = runner.run(candidate, &["--version"], None).ok()?;
+        if !output.success {
+            return None;
+        }
+        let text = if output.stdout.trim().is_empty() {
+            output.stderr
+        } else {
+            output.stdout
+        };
+        Some(text.lines().next().unwrap_or_default().trim().to_string())
+    })
+}
+
+/// Whether `command` can be run on this machine.
+pub fn is_command_available(command: &str) -> bool {
+    tool_version(&SystemRunner, command).is_some()
+}
+
+pub fn check_tools(runner: &dyn CommandRunner) -> Vec<DoctorCheck> {
+    TOOLS
+        .iter()
+        .map(|(tool, hint)| {
+            let version = tool_version(runner, tool);
+            DoctorCheck {
+                name: tool.to_string(),
+                passed: version.is_some(),
+                detail: version.unwrap_or_else(|| "not found".to_string()),
+                hint: hint.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// The configured translation provider's key, from the config or its
+/// environment variable.
+pub fn check_api_key(config: &AppConfig) -> DoctorCheck {
+    let (provider, api_key, _) = provider_settings(config);
+    DoctorCheck {
+        name: "api key".to_string(),
+        passed: api_key.is_some(),
+        detail: match api_key {
+            Some(_) => format!("{} key configured", provider.display_name()),
+            None => format!("no {} key", provider.display_name()),
+        },
+        hint: format!(
+            "Run 'nitroterm config setup' or set {}",
+            provider.api_key_env()
+        ),
+    }
+}
+
+pub async fn check_network(url: &str) -> DoctorCheck {
+    let result = async {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .user_agent("nitroterm")
+            .build()?;
+        Ok::<_, anyhow::Error>(client.get(url).send().await?)
+    }
+    .await;
+
+    DoctorCheck {
+        name: "network".to_string(),
+        passed: result.is_ok(),
+        detail: match result {
+            Ok(response) => format!("{} reachable ({})", url, response.status().as_u16()),
+            Err(e) => format!("{} unreachable: {}", url, e),
+        },
+        hint: "Check your connection and proxy settings (HTTPS_PROXY)".to_string(),
+    }
+}
+
+/// Requirement sets of `feature` without a passing check, joined with `/`.
+pub fn missing_for(feature: Feature, root: &Path, checks: &[DoctorCheck]) -> Vec<String> {
+    let passed = |name: &str| {
+        checks
+            .iter()
+            .any(|check| check.name == name && check.passed)
+    };
+    feature
+        .requirements(root)
+        .into_iter()
+        .filter(|alternatives| !alternatives.iter().any(|name| passed(name)))
+        .map(|alternatives| alternatives.join("/"))
+        .collect()
+}
+
+fn print_checks(checks: &[DoctorCheck]) {
+    println!("\n{}", "🩺 Environment check".cyan().bold());
+    println!("{}", "─".repeat(70).dimmed());
+    println!(
+        "  {:<10} {:<8} {}",
+        "Check".bold(),
+        "Status".bold(),
+        "Details".bold()
+    );
+    for check in checks {
+        let status = if check.passed {
+            format!("{:<8}", "✅ ok").green()
+        } else {
+            format!("{:<8}", "❌ fail").red()
+        };
+        println!("  {:<10} {} {}", check.name, status, check.detail.dimmed());
+    }
+
+    let failed: Vec<&DoctorCheck> = checks.iter().filter(|check| !check.passed).collect();
+    if !failed.is_empty() {
+        println!("\n{}", "💡 How to fix:".yellow().bold());
+        for check in failed {
+            println!("  {} {}: {}", "•".dimmed(), check.name.bold(), check.hint);
+        }
+    }
+}
+
+/// Checks every tool, the API key and the network. Fails when a requested
+/// feature misses something it needs; without features it only reports.
+pub async fn run_doctor(path: &Path, features: &[Feature]) -> Result<()> {
+    ensure_not_sandboxed("doctor")?;
+    let config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+
+    let mut checks = check_tools(&SystemRunner);
+    checks.push(check_api_key(&config));
+    checks.push(check_network(NETWORK_CHECK_URL).await);
+    print_checks(&checks);
+
+    if features.is_empty() {
+        return Ok(());
+    }
+    println!();
+    let mut blocked = Vec::new();
+    for feature in features {
+        let missing = missing_for(*feature, path, &checks);
+        if missing.is_empty() {
+            println!("{}", format!("✅ {} is ready", feature.name()).green());
+        } else {
+            println!(
+                "{}",
+                format!("❌ {} needs: {}", feature.name(), missing.join(", ")).red()
+            );
+            blocked.push(feature.name());
+        }
+    }
+
+    if !blocked.is_empty() {
+        return Err(anyhow!("Missing requirements for {}", blocked.join(", ")));
+    }
+    Ok(())
+}
@@ -0,0 +1,320 @@
+use crate::commands::code_quality::{CheckResult, ReportFormat};
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::lockfiles::{direct_dependency_names, read_locked_packages};
+use crate::commands::translation_sync::get_sync_statistics;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, SqliteConnection};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Relational layout of the export. Commits and tags are keyed by hash/name,
+/// everything else is a plain snapshot of the time of the export.
+pub const SCHEMA: &[&str] = &[
+    "CREATE TABLE export_meta (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+    "CREATE TABLE commits (
+        hash TEXT PRIMARY KEY,
+        author_name TEXT NOT NULL,
+        author_email TEXT NOT NULL,
+        committed_at INTEGER NOT NULL,
+        summary TEXT NOT NULL,
+        message TEXT NOT NULL,
+        parent_count INTEGER NOT NULL
+    )",
+    "CREATE TABLE tags (
+        name TEXT PRIMARY KEY,
+        commit_hash TEXT REFERENCES commits(hash),
+        tagged_at INTEGER
+    )",
+    "CREATE TABLE contributors (
+        email TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        commit_count INTEGER NOT NULL,
+        first_commit_at INTEGER NOT NULL,
+        last_commit_at INTEGER NOT NULL
+    )",
+    "CREATE TABLE dependencies (
+        ecosystem TEXT NOT NULL,
+        name TEXT NOT NULL,
+        version TEXT NOT NULL,
+        direct INTEGER NOT NULL,
+        PRIMARY KEY (ecosystem, name, version)
+    )",
+    "CREATE TABLE check_results (
+        id INTEGER PRIMARY KEY,
+        check_name TEXT NOT NULL,
+        command TEXT NOT NULL,
+        success INTEGER NOT NULL,
+        duration_ms INTEGER NOT NULL,
+        error TEXT
+    )",
+    "CREATE TABLE translation_stats (
+        language TEXT PRIMARY KEY,
+        file TEXT NOT NULL,
+        total_keys INTEGER NOT NULL,
+        translated INTEGER NOT NULL,
+        missing INTEGER NOT NULL,
+        percentage REAL NOT NULL,
+        last_modified TEXT
+    )",
+    "CREATE INDEX commits_author ON commits(author_email)",
+    "CREATE INDEX commits_time ON commits(committed_at)",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Code quality JSON report to import; defaults to `code-quality-report.json`
+    pub quality_report: Option<String>,
+    pub messages_dir: Option<String>,
+    pub source_file: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportSummary {
+    pub commits: usize,
+    pub tags: usize,
+    pub contributors: usize,
+    pub dependencies: usize,
+    pub check_results: usize,
+    pub translation_languages: usize,
+}
+
+struct Contributor {
+    name: String,
+    commits: i64,
+    first: i64,
+    last: i64,
+}
+
+/// Writes the project's history and analysis results into a fresh SQLite
+/// database at `output`. An existing file is replaced.
+pub async fn export_sqlite(
+    root: &Path,
+    output: &Path,
+    options: &ExportOptions,
+) -> Result<ExportSummary> {
+    if output.exists() {
+        std::fs::remove_file(output)?;
+    }
+    let connect_options = SqliteConnectOptions::new()
+        .filename(output)
+        .create_if_missing(true);
+    let mut conn = SqliteConnection::connect_with(&connect_options).await?;
+    let mut tx = conn.begin().await?;
+
+    for statement in SCHEMA {
+        sqlx::query(statement).execute(&mut *tx).await?;
+    }
+
+    let mut summary = ExportSummary::default();
+    let meta = [
+        ("tool_version", env!("CARGO_PKG_VERSION").to_string()),
+        ("exported_at", chrono::Utc::now().to_rfc3339()),
+        (
+            "project_root",
+            root.canonicalize()?.to_string_lossy().to_string(),
+        ),
+    ];
+    for (key, value) in meta {
+        sqlx::query("INSERT INTO export_meta (key, value) VALUES (?, ?)")
+            .bind(key)
+            .bind(value)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    // Commits, contributors and tags
+    if let Ok(repo) = Repository::discover(root) {
+        let mut contributors: HashMap<String, Contributor> = HashMap::new();
+        let mut revwalk = repo.revwalk()?;
+        if revwalk.push_head().is_ok() {
+            for oid in revwalk {
+                let commit = repo.find_commit(oid?)?;
+                let author = commit.author();
+                let email = author.email().unwrap_or("").to_string();
+                let name = author.name().unwrap_or("").to_string();
+                let time = commit.time().seconds();
+
+                sqlx::query(
+                    "INSERT INTO commits (hash, author_name, author_email, committed_at, summary, message, parent_count)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(commit.id().to_string())
+                .bind(&name)
+                .bind(&email)
+                .bind(time)
+                .bind(commit.summary().unwrap_or(""))
+                .bind(commit.message().unwrap_or(""))
+                .bind(commit.parent_count() as i64)
+                .execute(&mut *tx)
+                .await?;
+                summary.commits += 1;
+
+                let entry = contributors.entry(email).or_insert(Contributor {
+                    name,
+                    commits: 0,
+                    first: time,
+                    last: time,
+                });
+                entry.commits += 1;
+                entry.first = entry.first.min(time);
+                entry.last = entry.last.max(time);
+            }
+        }
+
+        for (email, contributor) in &contributors {
+            sqlx::query(
+                "INSERT INTO contributors (email, name, commit_count, first_commit_at, last_commit_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(email)
+            .bind(&contributor.name)
+            .bind(contributor.commits)
+            .bind(contributor.first)
+            .bind(contributor.last)
+            .execute(&mut *tx)
+            .await?;
+        }
+        summary.contributors = contributors.len();
+
+        let tag_names = repo.tag_names(None)?;
+        for name in tag_names.iter().flatten() {
+            let commit = repo
+                .revparse_single(&format!("refs/tags/{}", name))
+                .and_then(|object| object.peel_to_commit())
+                .ok();
+            sqlx::query("INSERT INTO tags (name, commit_hash, tagged_at) VALUES (?, ?, ?)")
+                .bind(name)
+                .bind(commit.as_ref().map(|c| c.id().to_string()))
+                .bind(commit.as_ref().map(|c| c.time().seconds()))
+                .execute(&mut *tx)
+                .await?;
+            summary.tags += 1;
+        }
+    }
+
+    // Dependency inventory from lockfiles
+    let direct = direct_dependency_names(root);
+    for package in read_locked_packages(root)? {
+        let is_direct = direct.contains(&(package.ecosystem, package.name.to_lowercase()));
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO dependencies (ecosystem, name, version, direct) VALUES (?, ?, ?, ?)",
+        )
+        .bind(package.ecosystem.name())
+        .bind(&package.name)
+        .bind(&package.version)
+        .bind(is_direct)
+        .execute(&mut *tx)
+        .await?;
+        summary.dependencies += result.rows_affected() as usize;
+    }
+
+    // Code quality results from the last JSON report
+    let report_path = root.join(
+        options
+            .quality_report
+            .as_deref()
+            .unwrap_or(ReportFormat::Json.default_file_name()),
+    );
+    if report_path.exists() {
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&report_path)?)?;
+        let checks: Vec<CheckResult> = serde_json::from_value(
+            report
+                .get("checks")
+                .cloned()
+                .ok_or_else(|| anyhow!("{} has no checks", report_path.display()))?,
+        )?;
+        for check in &checks {
+            sqlx::query(
+                "INSERT INTO check_results (check_name, command, success, duration_ms, error)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&check.check_name)
+            .bind(&check.command)
+            .bind(check.success)
+            .bind(check.duration_ms as i64)
+            .bind(&check.error)
+            .execute(&mut *tx)
+            .await?;
+        }
+        summary.check_results = checks.len();
+    }
+
+    // Translation completeness
+    if let (Some(messages_dir), Some(source_file)) = (&options.messages_dir, &options.source_file) {
+        let messages_dir = root.join(messages_dir);
+        if messages_dir.join(source_file).exists() {
+            let stats = get_sync_statistics(&messages_dir, source_file)?;
+            for language in &stats.languages {
+                sqlx::query(
+                    "INSERT INTO translation_stats (language, file, total_keys, translated, missing, percentage, last_modified)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                )
+                .bind(&language.code)
+                .bind(&language.file)
+                .bind(language.progress.total_keys as i64)
+                .bind(language.progress.translated as i64)
+                .bind(language.progress.missing() as i64)
+                .bind(language.progress.percentage())
+                .bind(language.last_modified.map(|time| time.to_rfc3339()))
+                .execute(&mut *tx)
+                .await?;
+            }
+            summary.translation_languages = stats.languages.len();
+        }
+    }
+
+    tx.commit().await?;
+    conn.close().await?;
+    Ok(summary)
+}
+
+pub async fn run_export_sqlite(
+    path: Option<&str>,
+    output: &str,
+    mut options: ExportOptions,
+) -> Result<()> {
+    ensure_not_sandboxed("export sqlite")?;
+    let root = Path::new(path.unwrap_or("."));
+
+    // Translation settings come from the config unless given explicitly
+    if options.messages_dir.is_none() || options.source_file.is_none() {
+        let app_config = match ConfigManager::new_quiet().await {
+            Ok(manager) => manager.get_config().await.unwrap_or_default(),
+            Err(_) => AppConfig::default(),
+        };
+        options.messages_dir.get_or_insert(app_config.messages_dir);
+        options.source_file.get_or_insert(app_config.source_file);
+    }
+
+    println!(
+        "{}",
+        "🗄️  Exporting project data to SQLite...".cyan().bold()
+    );
+    let summary = export_sqlite(root, Path::new(output), &options).await?;
+
+    let rows = [
+        ("Commits", summary.commits),
+        ("Tags", summary.tags),
+        ("Contributors", summary.contributors),
+        ("Dependencies", summary.dependencies),
+        ("Check results", summary.check_results),
+        ("Translation languages", summary.translation_languages),
+    ];
+    for (label, count) in rows {
+        println!("  {:<22} {}", label, count.to_string().bold());
+    }
+    println!(
+        "{}",
+        format!(
+            "✅ Exported to {} — try: sqlite3 {} '.tables'",
+            output, output
+        )
+        .green()
+    );
+    Ok(())
+}
@@ -0,0 +1,354 @@
+use crate::commands::release_notes::{parse_git_url, RepositoryInfo};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::{ensure_network_allowed, ensure_not_sandboxed};
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::time::Duration;
+
+pub const UPSTREAM_REMOTE: &str = "upstream";
+
+#[derive(Debug, Clone, Default)]
+pub struct ForkSyncConfig {
+    /// Upstream remote name or URL (auto-detected from GitHub when unset)
+    pub upstream: Option<String>,
+    /// Branch to sync (defaults to upstream's default branch)
+    pub branch: Option<String>,
+    /// Rebase local commits onto upstream instead of refusing to sync
+    pub rebase: bool,
+    pub no_push: bool,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncOutcome {
+    UpToDate,
+    FastForwarded { behind: usize },
+    Rebased { ahead: usize, behind: usize },
+}
+
+/// A local branch that has commits of its own and is behind upstream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchDivergence {
+    pub branch: String,
+    pub ahead: usize,
+    pub behind: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForkSyncReport {
+    pub upstream: String,
+    pub branch: String,
+    pub outcome: SyncOutcome,
+    pub pushed: bool,
+    pub diverged: Vec<BranchDivergence>,
+}
+
+/// Default branch from `git ls-remote --symref <remote> HEAD`
+/// (`ref: refs/heads/main\tHEAD`).
+pub fn parse_symref_head(output: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let target = line.strip_prefix("ref:")?.split_whitespace().next()?;
+        target
+            .strip_prefix("refs/heads/")
+            .map(|branch| branch.to_string())
+    })
+}
+
+/// `(ahead, behind)` from `git rev-list --left-right --count a...b`.
+pub fn parse_ahead_behind(output: &str) -> Option<(usize, usize)> {
+    let mut counts = output.split_whitespace().map(|count| count.parse().ok());
+    Some((counts.next()??, counts.next()??))
+}
+
+fn git(runner: &dyn CommandRunner, args: &[&str]) -> Result<String> {
+    let output = runner
+        .run("git", args, None)?
+        .check(&format!("git {}", args.join(" ")))?;
+    Ok(output.stdout.trim().to_string())
+}
+
+fn ahead_behind(runner: &dyn CommandRunner, local: &str, upstream: &str) -> Result<(usize, usize)> {
+    let range = format!("{}...{}", local, upstream);
+    let output = git(runner, &["rev-list", "--left-right", "--count", &range])?;
+    parse_ahead_behind(&output).ok_or_else(|| anyhow!("Unexpected rev-list output: {}", output))
+}
+
+fn add_upstream_remote(runner: &dyn CommandRunner, url: &str, remotes: &[String]) -> Result<()> {
+    if remotes.iter().any(|remote| remote == UPSTREAM_REMOTE) {
+        git(runner, &["remote", "set-url", UPSTREAM_REMOTE, url])?;
+    } else {
+        git(runner, &["remote", "add", UPSTREAM_REMOTE, url])?;
+    }
+    println!(
+        "{}",
+        format!("🔗 Remote '{}' → {}", UPSTREAM_REMOTE, url).blue()
+    );
+    Ok(())
+}
+
+/// The upstream remote to sync from, without touching the network: a
+/// configured remote name, a configured URL (added as `upstream`), or an
+/// existing `upstream` remote. None means it has to be detected.
+pub fn resolve_upstream(
+    runner: &dyn CommandRunner,
+    configured: Option<&str>,
+) -> Result<Option<String>> {
+    let remotes: Vec<String> = git(runner, &["remote"])?
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect();
+
+    match configured {
+        Some(name) if remotes.iter().any(|remote| remote == name) => Ok(Some(name.to_string())),
+        Some(url) => {
+            add_upstream_remote(runner, url, &remotes)?;
+            Ok(Some(UPSTREAM_REMOTE.to_string()))
+        }
+        None if remotes.iter().any(|remote| remote == UPSTREAM_REMOTE) => {
+            Ok(Some(UPSTREAM_REMOTE.to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Clone URL of the repository `origin_url` was forked from, via the GitHub API.
+pub async fn detect_fork_parent(api_url: &str, origin_url: &str) -> Result<Option<String>> {
+    let mut info = RepositoryInfo::default();
+    parse_git_url(&mut info, origin_url);
+    if !info.is_github {
+        return Ok(None);
+    }
+
+    ensure_network_allowed()?;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(15))
+        .user_agent("nitroterm")
+        .build()?;
+    let mut request = client.get(format!(
+        "{}/repos/{}/{}",
+        api_url.trim_end_matches('/'),
+        info.owner,
+        info.name
+    ));
+    if let Ok(token) = std::env::var("GITHUB_TOKEN").or_else(|_| std::env::var("GH_TOKEN")) {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("GitHub API returned status: {}", response.status()));
+    }
+    let repository: serde_json::Value = response.json().await?;
+    Ok(repository["parent"]["clone_url"]
+        .as_str()
+        .map(|url| url.to_string()))
+}
+
+/// Fetches `upstream`, brings the default branch up to date with it and
+/// pushes it to origin. The checked-out branch is restored afterwards.
+pub fn sync_fork(
+    runner: &dyn CommandRunner,
+    upstream: &str,
+    config: &ForkSyncConfig,
+) -> Result<ForkSyncReport> {
+    let original = git(runner, &["rev-parse", "--abbrev-ref", "HEAD"])
+        .map_err(|_| anyhow!("Not a git repository"))?;
+    if !git(runner, &["status", "--porcelain"])?.is_empty() {
+        return Err(anyhow!(
+            "Working tree has uncommitted changes, commit or stash them first"
+        ));
+    }
+
+    println!(
+        "{}",
+        format!("📥 Fetching {} and origin...", upstream).blue()
+    );
+    git(runner, &["fetch", upstream, "--prune"])?;
+    git(runner, &["fetch", "origin", "--prune"])?;
+
+    let branch = match &config.branch {
+        Some(branch) => branch.clone(),
+        None => parse_symref_head(&git(runner, &["ls-remote", "--symref", upstream, "HEAD"])?)
+            .unwrap_or_else(|| "main".to_string()),
+    };
+    let upstream_ref = format!("{}/{}", upstream, branch);
+
+    let has_local = runner
+        .run(
+            "git",
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("refs/heads/{}", branch),
+            ],
+            None,
+        )?
+        .success;
+    if !config.dry_run && original != branch {
+        if has_local {
+            git(runner, &["checkout", &branch])?;
+        } else {
+            git(runner, &["checkout", "-b", &branch, &upstream_ref])?;
+        }
+    }
+
+    let (ahead, behind) = if has_local {
+        ahead_behind(runner, &branch, &upstream_ref)?
+    } else {
+        (0, 0)
+    };
+    let outcome = if behind == 0 {
+        SyncOutcome::UpToDate
+    } else if ahead == 0 {
+        if !config.dry_run {
+            git(runner, &["merge", "--ff-only", &upstream_ref])?;
+        }
+        SyncOutcome::FastForwarded { behind }
+    } else if config.rebase {
+        if !config.dry_run {
+            if let Err(e) = git(runner, &["rebase", &upstream_ref]) {
+                let _ = git(runner, &["rebase", "--abort"]);
+                let _ = git(runner, &["checkout", &original]);
+                return Err(e);
+            }
+        }
+        SyncOutcome::Rebased { ahead, behind }
+    } else {
+        if original != branch && !config.dry_run {
+            git(runner, &["checkout", &original])?;
+        }
+        return Err(anyhow!(
+            "{} has {} commit(s) that are not in {}, rerun with --rebase to replay them on top",
+            branch,
+            ahead,
+            upstream_ref
+        ));
+    };
+
+    let pushed = !config.no_push && !config.dry_run;
+    if pushed {
+        if matches!(outcome, SyncOutcome::Rebased { .. }) {
+            git(runner, &["push", "--force-with-lease", "origin", &branch])?;
+        } else {
+            git(runner, &["push", "origin", &branch])?;
+        }
+    }
+
+    let mut diverged = Vec::new();
+    for local in git(
+        runner,
+        &["for-each-ref", "--format=%(refname:short)", "refs/heads"],
+    )?
+    .lines()
+    {
+        if local == branch {
+            continue;
+        }
+        let (ahead, behind) = ahead_behind(runner, local, &upstream_ref)?;
+        if ahead > 0 && behind > 0 {
+            diverged.push(BranchDivergence {
+                branch: local.to_string(),
+                ahead,
+                behind,
+            });
+        }
+    }
+
+    if original != branch && !config.dry_run {
+        git(runner, &["checkout", &original])?;
+    }
+
+    Ok(ForkSyncReport {
+        upstream: upstream.to_string(),
+        branch,
+        outcome,
+        pushed,
+        diverged,
+    })
+}
+
+fn print_report(report: &ForkSyncReport, dry_run: bool) {
+    let upstream_ref = format!("{}/{}", report.upstream, report.branch);
+    let prefix = if dry_run { "Would be " } else { "" };
+    match &report.outcome {
+        SyncOutcome::UpToDate => println!(
+            "{}",
+            format!("✅ {} is up to date with {}", report.branch, upstream_ref).green()
+        ),
+        SyncOutcome::FastForwarded { behind } => println!(
+            "{}",
+            format!(
+                "⏩ {}{} fast-forwarded by {} commit(s)",
+                prefix, report.branch, behind
+            )
+            .green()
+        ),
+        SyncOutcome::Rebased { ahead, behind } => println!(
+            "{}",
+            format!(
+                "🔀 {}{} rebased: {} local commit(s) replayed on {} new upstream commit(s)",
+                prefix, report.branch, ahead, behind
+            )
+            .green()
+        ),
+    }
+    if report.pushed {
+        println!(
+            "{}",
+            format!("🚀 Pushed {} to origin", report.branch).green()
+        );
+    }
+
+    if report.diverged.is_empty() {
+        println!("{}", "🌿 No other branches have diverged".dimmed());
+    } else {
+        println!(
+            "{}",
+            format!("⚠️  Branches diverged from {}:", upstream_ref).yellow()
+        );
+        for divergence in &report.diverged {
+            println!(
+                "  {} {} ({} ahead, {} behind)",
+                "•".dimmed(),
+                divergence.branch.cyan(),
+                divergence.ahead,
+                divergence.behind
+            );
+        }
+        println!(
+            "{}",
+            format!("💡 Rebase them with: git rebase {}", upstream_ref).dimmed()
+        );
+    }
+}
+
+pub async fn run_fork_sync(config: ForkSyncConfig) -> Result<()> {
+    ensure_not_sandboxed("fork sync")?;
+    let runner = SystemRunner;
+
+    println!("{}", "🍴 Syncing fork with upstream...".cyan().bold());
+    let upstream = match resolve_upstream(&runner, config.upstream.as_deref())? {
+        Some(upstream) => upstream,
+        None => {
+            let origin = git(&runner, &["remote", "get-url", "origin"])
+                .map_err(|_| anyhow!("No origin remote found"))?;
+            let api_url = std::env::var("GITHUB_API_URL")
+                .unwrap_or_else(|_| "https://api.github.com".to_string());
+            let parent = detect_fork_parent(&api_url, &origin)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!("Could not detect the upstream repository, pass --upstream <url>")
+                })?;
+            resolve_upstream(&runner, Some(&parent))?
+                .ok_or_else(|| anyhow!("Could not add the upstream remote"))?
+        }
+    };
+
+    let report = sync_fork(&runner, &upstream, &config)?;
+    print_report(&report, config.dry_run);
+    if config.dry_run {
+        println!("{}", "🔍 DRY RUN: Nothing was changed".yellow());
+    }
+    Ok(())
+}
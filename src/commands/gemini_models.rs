@@ -0,0 +1,286 @@
+use crate::commands::config::ConfigManager;
+use crate::commands::translation_sync::{
+    api_base_url, settings_for, ApiError, ProviderKind, GEMINI_API_URL,
+};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_network_allowed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A model from the Gemini `models` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiModel {
+    /// `models/gemini-1.5-flash`
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub input_token_limit: u64,
+    #[serde(default)]
+    pub output_token_limit: u64,
+    #[serde(default)]
+    pub supported_generation_methods: Vec<String>,
+}
+
+impl GeminiModel {
+    /// The name as the config and the API paths use it, `gemini-1.5-flash`.
+    pub fn id(&self) -> &str {
+        self.name.strip_prefix("models/").unwrap_or(&self.name)
+    }
+
+    /// Only these can translate; embedding models can't.
+    pub fn can_generate(&self) -> bool {
+        self.supported_generation_methods
+            .iter()
+            .any(|method| method == "generateContent")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelPage {
+    #[serde(default)]
+    models: Vec<GeminiModel>,
+    next_page_token: Option<String>,
+}
+
+/// Whether the configured model exists, with similar ones when it doesn't.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelCheck {
+    Available,
+    Unknown { suggestions: Vec<String> },
+}
+
+fn client() -> Result<reqwest::Client> {
+    ensure_network_allowed()?;
+    Ok(reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()?)
+}
+
+/// Models that support `generateContent`, sorted by name. Follows the
+/// page tokens until the list is complete.
+pub async fn list_models(base_url: &str, api_key: &str) -> Result<Vec<GeminiModel>> {
+    let client = client()?;
+    let base_url = base_url.trim_end_matches('/');
+    let mut models = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let mut query = vec![
+            ("key", api_key.to_string()),
+            ("pageSize", "1000".to_string()),
+        ];
+        if let Some(token) = &page_token {
+            query.push(("pageToken", token.clone()));
+        }
+        let response = client
+            .get(format!("{}/v1beta/models", base_url))
+            .query(&query)
+            .send()
+            .await
+            // The URL carries the API key
+            .map_err(|e| e.without_url())?;
+        if !response.status().is_success() {
+            return Err(ApiError::from_response("Gemini", response).await);
+        }
+        let page: ModelPage = response.json().await?;
+        models.extend(page.models.into_iter().filter(|model| model.can_generate()));
+        match page.next_page_token.filter(|token| !token.is_empty()) {
+            Some(token) => page_token = Some(token),
+            None => break,
+        }
+    }
+    models.sort_by(|a, b| a.id().cmp(b.id()));
+    Ok(models)
+}
+
+/// Up to `limit` model names closest to `wanted`: the most shared
+/// `-`-separated parts first, then newer versions.
+pub fn suggest_models(models: &[GeminiModel], wanted: &str, limit: usize) -> Vec<String> {
+    let wanted_parts: Vec<&str> = wanted.split('-').collect();
+    let mut scored: Vec<(usize, &str)> = models
+        .iter()
+        .map(|model| {
+            let shared = model
+                .id()
+                .split('-')
+                .filter(|part| wanted_parts.contains(part))
+                .count();
+            (shared, model.id())
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(a.1)));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// Looks the model up; a 404 lists the available models for suggestions.
+pub async fn check_model(base_url: &str, api_key: &str, model: &str) -> Result<ModelCheck> {
+    let response = client()?
+        .get(format!(
+            "{}/v1beta/models/{}",
+            base_url.trim_end_matches('/'),
+            model
+        ))
+        .query(&[("key", api_key)])
+        .send()
+        .await
+        .map_err(|e| e.without_url())?;
+    if response.status().is_success() {
+        return Ok(ModelCheck::Available);
+    }
+    if response.status() != reqwest::StatusCode::NOT_FOUND {
+        return Err(ApiError::from_response("Gemini", response).await);
+    }
+    let models = list_models(base_url, api_key).await?;
+    Ok(ModelCheck::Unknown {
+        suggestions: suggest_models(&models, model, 3),
+    })
+}
+
+pub fn unknown_model_error(model: &str, suggestions: &[String]) -> anyhow::Error {
+    if suggestions.is_empty() {
+        anyhow!(
+            "Gemini model '{}' not found. Pick one with `nitroterm config models`",
+            model
+        )
+    } else {
+        anyhow!(
+            "Gemini model '{}' not found, try {}. Pick one with `nitroterm config models`",
+            model,
+            suggestions.join(", ")
+        )
+    }
+}
+
+/// Token limits the way the model docs write them, `1M` or `8K`. The API
+/// reports both binary (1048576) and decimal (1000000) limits.
+pub fn format_tokens(tokens: u64) -> String {
+    for (unit, sizes) in [("M", [1_048_576, 1_000_000]), ("K", [1024, 1000])] {
+        for size in sizes {
+            if tokens >= size && tokens.is_multiple_of(size) {
+                return format!("{}{}", tokens / size, unit);
+            }
+        }
+    }
+    tokens.to_string()
+}
+
+/// A model number from the list (1-based) or a model name.
+pub fn parse_model_choice(input: &str, models: &[GeminiModel]) -> Option<String> {
+    let input = input.trim();
+    if let Ok(number) = input.parse::<usize>() {
+        return models
+            .get(number.checked_sub(1)?)
+            .map(|model| model.id().to_string());
+    }
+    let name = input.strip_prefix("models/").unwrap_or(input);
+    models
+        .iter()
+        .find(|model| model.id() == name)
+        .map(|model| model.id().to_string())
+}
+
+fn print_models(models: &[GeminiModel], current: &str) {
+    println!("{}", "🤖 Gemini models".cyan().bold());
+    println!("{}", "─".repeat(80).dimmed());
+    println!(
+        "{}",
+        format!(
+            "{:>3}  {:<36} {:>8} {:>8}  {}",
+            "#", "Model", "Input", "Output", "Name"
+        )
+        .bold()
+    );
+    for (index, model) in models.iter().enumerate() {
+        let marker = if model.id() == current { "*" } else { " " };
+        let line = format!(
+            "{:>3}{} {:<36} {:>8} {:>8}  {}",
+            index + 1,
+            marker,
+            model.id(),
+            format_tokens(model.input_token_limit),
+            format_tokens(model.output_token_limit),
+            model.display_name
+        );
+        if model.id() == current {
+            println!("{}", line.green());
+        } else {
+            println!("{}", line);
+        }
+    }
+    println!("{}", "─".repeat(80).dimmed());
+}
+
+/// Lists the Gemini models the API key can use and stores the picked one,
+/// or `model` directly after checking that it exists.
+pub async fn run_models(model: Option<&str>, json: bool) -> Result<()> {
+    let config_manager = ConfigManager::new_quiet().await?;
+    let mut config = config_manager.get_config().await?;
+    let (api_key, current) = settings_for(&config, ProviderKind::Gemini);
+    let api_key = api_key.ok_or_else(|| {
+        anyhow!(
+            "Gemini API key not configured, run `nitroterm config setup` or set {}",
+            ProviderKind::Gemini.api_key_env()
+        )
+    })?;
+    let base_url = api_base_url("GEMINI_BASE_URL", GEMINI_API_URL);
+    let models = list_models(&base_url, &api_key).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&models)?);
+        return Ok(());
+    }
+
+    let chosen = match model {
+        Some(model) => match parse_model_choice(model, &models) {
+            Some(chosen) => chosen,
+            None => {
+                return Err(unknown_model_error(
+                    model,
+                    &suggest_models(&models, model, 3),
+                ))
+            }
+        },
+        None => {
+            if models.is_empty() {
+                println!("{}", "No models with generateContent available".yellow());
+                return Ok(());
+            }
+            print_models(&models, &current);
+            let answer = prompt::ask(
+                format!("Model number or name [current: {}]: ", current).cyan(),
+                "",
+            )?;
+            if answer.trim().is_empty() {
+                println!("{}", format!("🤖 Keeping {}", current).dimmed());
+                return Ok(());
+            }
+            parse_model_choice(&answer, &models)
+                .ok_or_else(|| anyhow!("Unknown model '{}'", answer.trim()))?
+        }
+    };
+
+    config.gemini_model = chosen.clone();
+    config_manager.save_config(&config).await?;
+    println!("{}", format!("🤖 Gemini model set to {}", chosen).green());
+    if !config.translation_provider.eq_ignore_ascii_case("gemini") {
+        println!(
+            "{}",
+            format!(
+                "💡 The active translation provider is {}, switch with `nitroterm config provider gemini`",
+                config.translation_provider
+            )
+            .dimmed()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::commands::config::{ConfigManager, IdentityProfile};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::{ConfigLevel, Repository};
+use std::io::{self, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Default)]
+pub struct IdentityOptions {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub signing_key: Option<String>,
+    pub signing_format: Option<String>,
+    pub global: bool,
+    pub save_profile: Option<String>,
+    pub skip_verify: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SigningFormat {
+    Gpg,
+    Ssh,
+}
+
+impl SigningFormat {
+    /// Value for git's `gpg.format`
+    pub fn git_value(&self) -> &'static str {
+        match self {
+            SigningFormat::Gpg => "openpgp",
+            SigningFormat::Ssh => "ssh",
+        }
+    }
+}
+
+impl std::str::FromStr for SigningFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "gpg" | "openpgp" => Ok(SigningFormat::Gpg),
+            "ssh" => Ok(SigningFormat::Ssh),
+            other => Err(anyhow!(
+                "Unknown signing format: {}. Use 'gpg' or 'ssh'",
+                other
+            )),
+        }
+    }
+}
+
+/// Guesses the signing format from the key: SSH keys are given as a public
+/// key file or an inline `ssh-...` key, everything else is a GPG key id.
+pub fn detect_signing_format(signing_key: &str) -> SigningFormat {
+    let key = signing_key.trim();
+    if key.starts_with("ssh-") || key.starts_with("key::") || key.ends_with(".pub") {
+        SigningFormat::Ssh
+    } else {
+        SigningFormat::Gpg
+    }
+}
+
+/// Merges the stored identity with command line overrides.
+pub fn resolve_identity(
+    profile: Option<&IdentityProfile>,
+    options: &IdentityOptions,
+) -> IdentityProfile {
+    let mut identity = profile.cloned().unwrap_or_default();
+
+    if let Some(name) = &options.name {
+        identity.name = name.clone();
+    }
+    if let Some(email) = &options.email {
+        identity.email = email.clone();
+    }
+    if let Some(key) = &options.signing_key {
+        identity.signing_key = Some(key.clone());
+    }
+    if let Some(format) = &options.signing_format {
+        identity.signing_format = Some(format.clone());
+    }
+
+    identity
+}
+
+pub fn signing_format_for(identity: &IdentityProfile) -> Result<Option<SigningFormat>> {
+    match (&identity.signing_key, &identity.signing_format) {
+        (None, _) => Ok(None),
+        (Some(_), Some(format)) => Ok(Some(format.parse()?)),
+        (Some(key), None) => Ok(Some(detect_signing_format(key))),
+    }
+}
+
+/// Set next to the signing settings [`apply_identity`] writes, so switching
+/// to a profile without a key only removes what nitroterm set up itself.
+pub const SIGNING_MARKER: &str = "nitroterm.signing";
+
+const SIGNING_SETTINGS: [&str; 4] = [
+    "user.signingkey",
+    "gpg.format",
+    "commit.gpgsign",
+    "tag.gpgsign",
+];
+
+/// Writes user.name/email and the signing settings into a git config.
+/// Without a signing key, signing nitroterm enabled earlier in this config
+/// is removed again; signing set up some other way is left alone. The
+/// identity is checked before anything is written.
+pub fn apply_identity(config: &mut git2::Config, identity: &IdentityProfile) -> Result<()> {
+    if identity.name.trim().is_empty() || identity.email.trim().is_empty() {
+        return Err(anyhow!("Both name and email are required"));
+    }
+    let format = signing_format_for(identity)?;
+
+    config.set_str("user.name", &identity.name)?;
+    config.set_str("user.email", &identity.email)?;
+
+    match (format, &identity.signing_key) {
+        (Some(format), Some(key)) => {
+            config.set_str("user.signingkey", key)?;
+            config.set_str("gpg.format", format.git_value())?;
+            config.set_bool("commit.gpgsign", true)?;
+            config.set_bool("tag.gpgsign", true)?;
+            config.set_bool(SIGNING_MARKER, true)?;
+        }
+        _ if config.get_bool(SIGNING_MARKER).unwrap_or(false) => {
+            for name in SIGNING_SETTINGS.iter().chain([&SIGNING_MARKER]) {
+                // Already gone is fine
+                let _ = config.remove(name);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Makes a commit in a throwaway repository with the given identity and
+/// checks that it carries the expected author and, if configured, a signature.
+pub fn verify_identity(identity: &IdentityProfile) -> Result<()> {
+    let temp_dir = tempfile::tempdir()?;
+    let repo = Repository::init(temp_dir.path())?;
+    let mut config = repo.config()?.open_level(ConfigLevel::Local)?;
+    apply_identity(&mut config, identity)?;
+    // Signing from the global config has no place in an unsigned check
+    if identity.signing_key.is_none() {
+        config.set_bool("commit.gpgsign", false)?;
+    }
+
+    std::fs::write(
+        temp_dir.path().join("README.md"),
+        "nitroterm identity check\n",
+    )?;
+    run_git(temp_dir.path(), &["add", "README.md"])?;
+    run_git(
+        temp_dir.path(),
+        &["commit", "-m", "nitroterm identity check"],
+    )?;
+
+    let commit = repo.head()?.peel_to_commit()?;
+    let author = commit.author();
+    if author.name() != Some(identity.name.as_str())
+        || author.email() != Some(identity.email.as_str())
+    {
+        return Err(anyhow!("Test commit was created with a different author"));
+    }
+
+    if identity.signing_key.is_some() && repo.extract_signature(&commit.id(), None).is_err() {
+        return Err(anyhow!("Test commit is not signed"));
+    }
+
+    Ok(())
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> Result<()> {
+    SystemRunner
+        .run("git", args, Some(dir))?
+        .check(&format!("git {}", args.join(" ")))?;
+    Ok(())
+}
+
+fn prompt(message: &str, current: &str) -> Result<String> {
+    if current.is_empty() {
+        prompt::ask_required(
+            format!("{}: ", message).cyan(),
+            &format!("pass --{}", message.to_lowercase()),
+        )
+    } else {
+        prompt::ask(
+            format!("{} [current: {}]: ", message, current).cyan(),
+            current,
+        )
+    }
+}
+
+pub async fn setup_identity(options: IdentityOptions) -> Result<()> {
+    ensure_not_sandboxed("git setup-identity")?;
+    println!("{}", "🪪 Git Identity Setup".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+
+    let config_manager = ConfigManager::new().await?;
+    // The identity of the active config profile, if any
+    let app_config = config_manager.get_config().await?;
+    let mut identity = resolve_identity(app_config.git_identity.as_ref(), &options);
+
+    if identity.name.is_empty() {
+        identity.name = prompt("Name", "")?;
+    }
+    if identity.email.is_empty() {
+        identity.email = prompt("Email", "")?;
+    }
+
+    let format = signing_format_for(&identity)?;
+    let (mut git_config, scope) = if options.global {
+        (git2::Config::open_default()?.open_global()?, "global")
+    } else {
+        let repo = Repository::discover(".")
+            .map_err(|_| anyhow!("Not a git repository (use --global to configure globally)"))?;
+        (repo.config()?.open_level(ConfigLevel::Local)?, "repository")
+    };
+
+    // Checked in a throwaway repository first, so a broken identity or
+    // signing key never reaches the real config
+    if options.skip_verify {
+        println!("{}", "⏭️  Skipping verification".dimmed());
+    } else {
+        print!("{}", "🧪 Verifying with a test commit...".yellow());
+        io::stdout().flush()?;
+        match verify_identity(&identity) {
+            Ok(_) => println!(" {}", "✅ OK".green()),
+            Err(e) => {
+                println!(" {}", "❌ Failed".red());
+                return Err(e);
+            }
+        }
+    }
+
+    apply_identity(&mut git_config, &identity)?;
+    println!(
+        "{}",
+        format!(
+            "✅ Configured {} identity: {} <{}>",
+            scope, identity.name, identity.email
+        )
+        .green()
+    );
+    if let Some(format) = format {
+        println!(
+            "{}",
+            format!("🔏 Commit signing enabled ({})", format.git_value()).green()
+        );
+    }
+
+    if let Some(profile_name) = &options.save_profile {
+        config_manager
+            .set_profile_setting(
+                profile_name,
+                "git_identity",
+                serde_json::to_value(&identity)?,
+            )
+            .await?;
+        println!(
+            "{}",
+            format!("💾 Saved identity to profile '{}'", profile_name).green()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,288 @@
+use crate::commands::github_labels::{parse_label_list_json, GitHubLabel};
+use crate::commands::github_milestones::{parse_milestone_list_json, GitHubMilestone};
+use crate::commands::github_triage::{parse_issue_list_json, TriageItem};
+use crate::commands::release_hosting::{content_type, ReleaseAsset};
+use crate::commands::release_notes::{parse_reference_json, Reference};
+use crate::commands::releases::resolve_repo_in;
+use crate::utils::sandbox::ensure_network_allowed;
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::time::Duration;
+
+/// Minimal GitHub REST client for environments without the `gh` CLI
+/// (containers, CI). Authenticates with `GITHUB_TOKEN` or `GH_TOKEN`.
+pub struct GitHubApiClient {
+    client: reqwest::Client,
+    pub api_url: String,
+    pub owner: String,
+    pub repo: String,
+    token: String,
+}
+
+impl GitHubApiClient {
+    pub fn new(owner: &str, repo: &str, token: &str) -> Result<Self> {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("nitroterm/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            client,
+            api_url: std::env::var("GITHUB_API_URL")
+                .unwrap_or_else(|_| "https://api.github.com".to_string()),
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    /// Uses `repo` when given, then `GITHUB_REPOSITORY` (set on Actions),
+    /// then the origin remote.
+    pub fn from_env(repo: Option<&str>) -> Result<Self> {
+        Self::from_env_in(Path::new("."), repo)
+    }
+
+    /// [`GitHubApiClient::from_env`] with the origin remote of the
+    /// repository `dir` is in.
+    pub fn from_env_in(dir: &Path, repo: Option<&str>) -> Result<Self> {
+        let token = std::env::var("GITHUB_TOKEN")
+            .or_else(|_| std::env::var("GH_TOKEN"))
+            .ok()
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| anyhow!("GITHUB_TOKEN or GH_TOKEN is required for API mode"))?;
+        let env_repo = std::env::var("GITHUB_REPOSITORY").ok();
+        let (owner, name) = resolve_repo_in(dir, repo.or(env_repo.as_deref()))?;
+        Self::new(&owner, &name, &token)
+    }
+
+    pub fn repo_slug(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+
+    /// `/repos/{owner}/{repo}/labels[/{name}]`, with the label name
+    /// percent-encoded (emoji and spaces are common in label names).
+    pub fn labels_url(&self, name: Option<&str>) -> Result<reqwest::Url> {
+        self.repo_url(&["labels"], name)
+    }
+
+    /// `/repos/{owner}/{repo}/milestones[/{number}]`
+    pub fn milestones_url(&self, number: Option<u64>) -> Result<reqwest::Url> {
+        self.repo_url(&["milestones"], number.map(|n| n.to_string()).as_deref())
+    }
+
+    fn repo_url(&self, path: &[&str], item: Option<&str>) -> Result<reqwest::Url> {
+        let mut url = reqwest::Url::parse(&self.api_url)?;
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| anyhow!("Invalid GitHub API URL: {}", self.api_url))?;
+            segments
+                .pop_if_empty()
+                .extend(["repos", &self.owner, &self.repo])
+                .extend(path);
+            if let Some(item) = item {
+                segments.push(item);
+            }
+        }
+        Ok(url)
+    }
+
+    fn request(&self, method: reqwest::Method, url: reqwest::Url) -> reqwest::RequestBuilder {
+        self.client
+            .request(method, url)
+            .bearer_auth(&self.token)
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            let message = serde_json::from_str::<serde_json::Value>(&body)
+                .ok()
+                .and_then(|json| json["message"].as_str().map(|m| m.to_string()))
+                .unwrap_or(body);
+            return Err(anyhow!("GitHub API returned {}: {}", status, message));
+        }
+        Ok(body)
+    }
+
+    pub async fn list_labels(&self) -> Result<Vec<GitHubLabel>> {
+        let mut labels = Vec::new();
+        for page in 1..=10 {
+            let mut url = self.labels_url(None)?;
+            url.query_pairs_mut()
+                .append_pair("per_page", "100")
+                .append_pair("page", &page.to_string());
+
+            let body = self.send(self.request(reqwest::Method::GET, url)).await?;
+            let batch = parse_label_list_json(&body)?;
+            let done = batch.len() < 100;
+            labels.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(labels)
+    }
+
+    pub async fn create_label(&self, label: &GitHubLabel) -> Result<()> {
+        let url = self.labels_url(None)?;
+        let body = json!({
+            "name": label.name,
+            "color": label.color,
+            "description": label.description,
+        });
+        self.send(self.request(reqwest::Method::POST, url).json(&body))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_label(&self, current_name: &str, label: &GitHubLabel) -> Result<()> {
+        let url = self.labels_url(Some(current_name))?;
+        let body = json!({
+            "new_name": label.name,
+            "color": label.color,
+            "description": label.description,
+        });
+        self.send(self.request(reqwest::Method::PATCH, url).json(&body))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_label(&self, name: &str) -> Result<()> {
+        let url = self.labels_url(Some(name))?;
+        self.send(self.request(reqwest::Method::DELETE, url))
+            .await?;
+        Ok(())
+    }
+
+    /// An issue or pull request; GitHub serves both from the issues endpoint.
+    pub async fn get_reference(&self, number: u64) -> Result<Reference> {
+        let url = self.repo_url(&["issues"], Some(&number.to_string()))?;
+        let body = self.send(self.request(reqwest::Method::GET, url)).await?;
+        parse_reference_json(&body)
+    }
+
+    /// Open issues and pull requests, for `github-triage`.
+    pub async fn list_open_issues(&self) -> Result<Vec<TriageItem>> {
+        let mut items = Vec::new();
+        for page in 1..=10 {
+            let mut url = self.repo_url(&["issues"], None)?;
+            url.query_pairs_mut()
+                .append_pair("state", "open")
+                .append_pair("per_page", "100")
+                .append_pair("page", &page.to_string());
+
+            let body = self.send(self.request(reqwest::Method::GET, url)).await?;
+            let batch = parse_issue_list_json(&body)?;
+            let done = batch.len() < 100;
+            items.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Adds labels to an issue or pull request, keeping the ones it has.
+    pub async fn add_labels(&self, number: u64, labels: &[String]) -> Result<()> {
+        let url = self.repo_url(&["issues", &number.to_string()], Some("labels"))?;
+        self.send(
+            self.request(reqwest::Method::POST, url)
+                .json(&json!({ "labels": labels })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Open and closed milestones.
+    pub async fn list_milestones(&self) -> Result<Vec<GitHubMilestone>> {
+        let mut milestones = Vec::new();
+        for page in 1..=10 {
+            let mut url = self.milestones_url(None)?;
+            url.query_pairs_mut()
+                .append_pair("state", "all")
+                .append_pair("per_page", "100")
+                .append_pair("page", &page.to_string());
+
+            let body = self.send(self.request(reqwest::Method::GET, url)).await?;
+            let batch = parse_milestone_list_json(&body)?;
+            let done = batch.len() < 100;
+            milestones.extend(batch);
+            if done {
+                break;
+            }
+        }
+        Ok(milestones)
+    }
+
+    pub async fn create_milestone(&self, fields: &Value) -> Result<()> {
+        let url = self.milestones_url(None)?;
+        self.send(self.request(reqwest::Method::POST, url).json(fields))
+            .await?;
+        Ok(())
+    }
+
+    /// Publishes the release of an already pushed tag, uploads `assets` to
+    /// its `upload_url` and returns its page.
+    pub async fn create_release(
+        &self,
+        tag: &str,
+        notes: &str,
+        assets: &[ReleaseAsset],
+    ) -> Result<String> {
+        let url = self.repo_url(&["releases"], None)?;
+        let body = json!({
+            "tag_name": tag,
+            "name": tag,
+            "body": notes,
+        });
+        let response = self
+            .send(self.request(reqwest::Method::POST, url).json(&body))
+            .await?;
+        let release: Value = serde_json::from_str(&response)?;
+        for asset in assets {
+            self.upload_release_asset(&release, asset).await?;
+        }
+        Ok(release["html_url"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                format!(
+                    "https://github.com/{}/releases/tag/{}",
+                    self.repo_slug(),
+                    tag
+                )
+            }))
+    }
+
+    /// `POST <upload_url>?name=...`, on uploads.github.com for github.com.
+    async fn upload_release_asset(&self, release: &Value, asset: &ReleaseAsset) -> Result<()> {
+        let upload_url = release["upload_url"]
+            .as_str()
+            .ok_or_else(|| anyhow!("GitHub returned no upload URL for the release"))?;
+        // `https://uploads.github.com/repos/o/r/releases/1/assets{?name,label}`
+        let mut url = reqwest::Url::parse(upload_url.split('{').next().unwrap_or(upload_url))?;
+        url.query_pairs_mut().append_pair("name", &asset.name);
+        self.send(
+            self.request(reqwest::Method::POST, url)
+                .header("Content-Type", content_type(&asset.name))
+                .body(asset.content.clone()),
+        )
+        .await
+        .map_err(|e| anyhow!("Uploading {} failed: {}", asset.name, e))?;
+        Ok(())
+    }
+
+    pub async fn update_milestone(&self, number: u64, fields: &Value) -> Result<()> {
+        let url = self.milestones_url(Some(number))?;
+        self.send(self.request(reqwest::Method::PATCH, url).json(fields))
+            .await?;
+        Ok(())
+    }
+}
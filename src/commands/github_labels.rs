@@ -1,9 +1,20 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::github_api::GitHubApiClient;
+use crate::commands::releases::resolve_repo;
+use crate::utils::output::{is_json_output, print_human, print_json};
+use crate::utils::process::{CommandOutput, CommandRunner, SupervisedCommand, SystemRunner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::theme::{paint, Role};
 use anyhow::{anyhow, Result};
 use colored::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::{Arc, Mutex};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitHubLabel {
     pub name: String,
     pub description: String,
@@ -18,6 +29,26 @@ pub struct LabelUpdate {
     pub color: String,
 }
 
+/// One entry of a custom label file. `aliases` are existing label names
+/// that get renamed to `name`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelDefinition {
+    pub name: String,
+    pub color: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Label files are either a bare list or a `labels:` table.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LabelFile {
+    List(Vec<LabelDefinition>),
+    Table { labels: Vec<LabelDefinition> },
+}
+
 #[derive(Debug, Clone)]
 pub struct GitHubLabelsConfig {
     pub skip_auth: bool,
@@ -26,6 +57,16 @@ pub struct GitHubLabelsConfig {
     pub list_only: bool,
     pub delete_all: bool,
     pub update_only: bool,
+    /// Custom label taxonomy; the built-in Nitroterm set is used when unset
+    pub labels_file: Option<String>,
+    /// Make the repo match the label set exactly, deleting extra labels
+    pub sync: bool,
+    /// Talk to the GitHub REST API with `GITHUB_TOKEN` instead of the `gh` CLI
+    pub api: bool,
+    /// `owner/name` to manage; the repo in `dir` when unset
+    pub repo: Option<String>,
+    /// Where `gh` runs and relative label files are read from
+    pub dir: PathBuf,
 }
 
 impl Default for GitHubLabelsConfig {
@@ -37,35 +78,340 @@ impl Default for GitHubLabelsConfig {
             list_only: false,
             delete_all: false,
             update_only: false,
+            labels_file: None,
+            sync: false,
+            api: false,
+            repo: None,
+            dir: PathBuf::from("."),
+        }
+    }
+}
+
+/// `#d73a49`, `D73A49` and `d73a49` all become `D73A49`, the form `gh` expects.
+pub fn normalize_color(color: &str) -> Option<String> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(hex.to_uppercase())
+    } else {
+        None
+    }
+}
+
+/// Parses a YAML or JSON label file without validating it.
+pub fn parse_labels(content: &str) -> Result<Vec<LabelDefinition>> {
+    match serde_yaml::from_str::<LabelFile>(content)? {
+        LabelFile::List(labels) | LabelFile::Table { labels } => Ok(labels),
+    }
+}
+
+/// Returns every problem in the set, so a file can be fixed in one pass.
+/// GitHub label names are case-insensitive, and so are the duplicate checks.
+pub fn validate_labels(labels: &[LabelDefinition]) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut seen: HashMap<String, String> = HashMap::new();
+
+    for label in labels {
+        if label.name.trim().is_empty() {
+            errors.push("Label with an empty name".to_string());
+            continue;
         }
+        if normalize_color(&label.color).is_none() {
+            errors.push(format!(
+                "'{}': invalid color '{}' (expected 6 hex digits like D73A49)",
+                label.name, label.color
+            ));
+        }
+        if label.description.chars().count() > 100 {
+            errors.push(format!(
+                "'{}': description is longer than GitHub's 100 characters",
+                label.name
+            ));
+        }
+
+        for (name, role) in std::iter::once((&label.name, "label"))
+            .chain(label.aliases.iter().map(|alias| (alias, "alias")))
+        {
+            let key = name.trim().to_lowercase();
+            if role == "alias" && key == label.name.trim().to_lowercase() {
+                continue;
+            }
+            match seen.get(&key) {
+                Some(owner) => errors.push(format!(
+                    "Duplicate name '{}' ({} of '{}', already used by '{}')",
+                    name, role, label.name, owner
+                )),
+                None => {
+                    seen.insert(key, label.name.clone());
+                }
+            }
+        }
+    }
+
+    errors
+}
+
+/// Loads, validates and normalizes a label file.
+pub fn load_labels_file(path: &Path) -> Result<Vec<LabelDefinition>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Cannot read labels file {}: {}", path.display(), e))?;
+    let mut labels = parse_labels(&content)
+        .map_err(|e| anyhow!("Invalid labels file {}: {}", path.display(), e))?;
+    if labels.is_empty() {
+        return Err(anyhow!("{} defines no labels", path.display()));
+    }
+
+    let errors = validate_labels(&labels);
+    if !errors.is_empty() {
+        return Err(anyhow!(
+            "{} has {} problem(s):\n  - {}",
+            path.display(),
+            errors.len(),
+            errors.join("\n  - ")
+        ));
+    }
+
+    for label in &mut labels {
+        label.color = normalize_color(&label.color).unwrap_or_default();
+    }
+    Ok(labels)
+}
+
+/// Reads `gh label list --json name,color,description` output, which has
+/// the same shape as the REST API's label objects.
+pub fn parse_label_list_json(content: &str) -> Result<Vec<GitHubLabel>> {
+    #[derive(Deserialize)]
+    struct ListedLabel {
+        name: String,
+        #[serde(default)]
+        color: String,
+        #[serde(default)]
+        description: Option<String>,
+    }
+
+    let listed: Vec<ListedLabel> =
+        serde_json::from_str(content).map_err(|e| anyhow!("Unexpected label list: {}", e))?;
+    Ok(listed
+        .into_iter()
+        .map(|label| GitHubLabel {
+            name: label.name,
+            color: label.color.to_uppercase(),
+            description: label.description.unwrap_or_default(),
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LabelChange {
+    Create(GitHubLabel),
+    /// `current_name` is the label on GitHub; `changes` lists what differs
+    Update {
+        current_name: String,
+        label: GitHubLabel,
+        changes: Vec<String>,
+    },
+    Delete(GitHubLabel),
+    Unchanged(String),
+}
+
+/// Diffs the desired set against the repo's labels. Each current label is
+/// matched by name first, then by alias; whatever is left over gets deleted.
+pub fn compute_label_diff(
+    desired: &[LabelDefinition],
+    current: &[GitHubLabel],
+) -> Vec<LabelChange> {
+    let mut used = vec![false; current.len()];
+    let mut changes = Vec::new();
+
+    let find = |name: &str, used: &[bool]| {
+        current
+            .iter()
+            .enumerate()
+            .position(|(i, label)| !used[i] && label.name.eq_ignore_ascii_case(name))
+    };
+
+    for definition in desired {
+        let label = GitHubLabel {
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+            color: definition.color.to_uppercase(),
+        };
+
+        let matched = find(&definition.name, &used).or_else(|| {
+            definition
+                .aliases
+                .iter()
+                .find_map(|alias| find(alias, &used))
+        });
+        let index = match matched {
+            Some(index) => index,
+            None => {
+                changes.push(LabelChange::Create(label));
+                continue;
+            }
+        };
+        used[index] = true;
+        let existing = &current[index];
+
+        let mut differences = Vec::new();
+        if existing.name != label.name {
+            differences.push(format!("name: {} → {}", existing.name, label.name));
+        }
+        if !existing.color.eq_ignore_ascii_case(&label.color) {
+            differences.push(format!("color: {} → {}", existing.color, label.color));
+        }
+        if existing.description != label.description {
+            differences.push("description".to_string());
+        }
+
+        if differences.is_empty() {
+            changes.push(LabelChange::Unchanged(label.name));
+        } else {
+            changes.push(LabelChange::Update {
+                current_name: existing.name.clone(),
+                label,
+                changes: differences,
+            });
+        }
+    }
+
+    for (index, label) in current.iter().enumerate() {
+        if !used[index] {
+            changes.push(LabelChange::Delete(label.clone()));
+        }
+    }
+
+    changes
+}
+
+/// Action, new name and current name of a change.
+fn change_summary(change: &LabelChange) -> (&'static str, &str, &str) {
+    match change {
+        LabelChange::Create(label) => ("create", &label.name, &label.name),
+        LabelChange::Update {
+            current_name,
+            label,
+            ..
+        } => ("update", &label.name, current_name),
+        LabelChange::Delete(label) => ("delete", &label.name, &label.name),
+        LabelChange::Unchanged(name) => ("unchanged", name, name),
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionOutcome {
+    Applied,
+    Failed,
+    DryRun,
+}
+
+/// A label change the run made or, in dry-run mode, would have made.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LabelAction {
+    /// `create`, `update` or `delete`
+    pub action: &'static str,
+    pub label: String,
+    /// Name on GitHub before an update renamed it
+    pub from: Option<String>,
+    pub outcome: ActionOutcome,
+    pub error: Option<String>,
+}
+
+/// `github-labels --json` output for one repository of a `--repos` run.
+#[derive(Debug, Serialize)]
+pub struct RepoLabelActions {
+    pub repo: String,
+    pub actions: Vec<LabelAction>,
+    pub error: Option<String>,
+}
+
 pub struct GitHubLabelsManager {
     pub config: GitHubLabelsConfig,
+    /// Loaded from `config.labels_file`; `None` means the built-in set
+    pub labels: Option<Vec<LabelDefinition>>,
+    /// REST client in API mode; `None` shells out to `gh`
+    pub api: Option<GitHubApiClient>,
+    /// Runs `gh` label commands; swapped for a mock in tests
+    pub runner: Arc<dyn CommandRunner>,
+    /// Changes made so far, for `--json`
+    actions: Mutex<Vec<LabelAction>>,
 }
 
 impl GitHubLabelsManager {
     pub fn new(config: GitHubLabelsConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            labels: None,
+            api: None,
+            runner: Arc::new(SystemRunner),
+            actions: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_labels(config: GitHubLabelsConfig, labels: Vec<LabelDefinition>) -> Self {
+        Self {
+            config,
+            labels: Some(labels),
+            api: None,
+            runner: Arc::new(SystemRunner),
+            actions: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_api(mut self, client: GitHubApiClient) -> Self {
+        self.api = Some(client);
+        self
+    }
+
+    /// Every create, update and delete of this run, in order.
+    pub fn actions(&self) -> Vec<LabelAction> {
+        self.actions.lock().unwrap().clone()
+    }
+
+    /// Logs a change; `result` is `None` for a dry run.
+    fn record(&self, action: &'static str, label: &str, from: &str, result: Option<&Result<()>>) {
+        let (outcome, error) = match result {
+            None => (ActionOutcome::DryRun, None),
+            Some(Ok(())) => (ActionOutcome::Applied, None),
+            Some(Err(e)) => (ActionOutcome::Failed, Some(e.to_string())),
+        };
+        self.actions.lock().unwrap().push(LabelAction {
+            action,
+            label: label.to_string(),
+            from: (from != label).then(|| from.to_string()),
+            outcome,
+            error,
+        });
     }
 
     pub async fn run(&self) -> Result<()> {
+        ensure_not_sandboxed("github-labels")?;
         self.print_banner();
         self.show_configuration();
+        self.apply().await
+    }
 
-        if !self.config.skip_install {
-            self.check_and_install_gh_cli().await?;
-        }
-
-        if !self.config.skip_auth {
-            self.check_authentication().await?;
+    /// Everything `run` does after the banner, for one repository.
+    pub async fn apply(&self) -> Result<()> {
+        if let Some(api) = &self.api {
+            print_human(format!("🌐 Using the GitHub REST API for {}", api.repo_slug()).blue());
+        } else {
+            ensure_gh_ready(
+                self.config.skip_install,
+                self.config.skip_auth,
+                self.config.dry_run,
+            )
+            .await?;
         }
 
         if self.config.list_only {
             return self.list_labels().await;
         }
 
+        if self.config.sync {
+            return self.sync_labels().await;
+        }
+
         if self.config.delete_all {
             self.delete_all_labels().await?;
         }
@@ -81,112 +427,111 @@ impl GitHubLabelsManager {
     }
 
     pub fn print_banner(&self) {
-        println!(
-            "{}",
+        print_human(
             "╔══════════════════════════════════════════════════════════════════════╗"
-                .bright_blue()
+                .bright_blue(),
         );
-        println!(
-            "{}",
+        print_human(
             "║                                                                      ║"
-                .bright_blue()
+                .bright_blue(),
         );
-        println!(
-            "{}",
+        print_human(
             "║                    🏷️  NITROTERM GITHUB LABELS                       ║"
                 .bright_cyan()
-                .bold()
+                .bold(),
         );
-        println!(
-            "{}",
+        print_human(
             "║              GitHub Repository Label Management Tool                 ║"
-                .bright_green()
+                .bright_green(),
         );
-        println!(
-            "{}",
+        print_human(
             "║                                                                      ║"
-                .bright_blue()
+                .bright_blue(),
         );
-        println!(
-            "{}",
+        print_human(
             "╚══════════════════════════════════════════════════════════════════════╝"
-                .bright_blue()
+                .bright_blue(),
         );
-        println!();
+        print_human("");
     }
 
     pub fn show_configuration(&self) {
-        println!(
-            "{}",
-            "🏷️  Managing GitHub labels for Nitroterm...".cyan().bold()
-        );
+        print_human("🏷️  Managing GitHub labels for Nitroterm...".cyan().bold());
 
         if self.config.dry_run {
-            println!(
-                "{}",
-                "🔍 DRY RUN MODE - No changes will be made".yellow().bold()
-            );
+            print_human("🔍 DRY RUN MODE - No changes will be made".yellow().bold());
+        }
+
+        if let Some(repo) = &self.config.repo {
+            print_human(format!("📦 Repository: {}", repo).blue());
+        }
+
+        if let (Some(file), Some(labels)) = (&self.config.labels_file, &self.labels) {
+            print_human(format!("📄 Using {} labels from {}", labels.len(), file).blue());
         }
 
         if self.config.list_only {
-            println!(
-                "{}",
+            print_human(
                 "📋 LIST ONLY MODE - Just showing current labels"
                     .blue()
-                    .bold()
+                    .bold(),
+            );
+        }
+
+        if self.config.sync {
+            print_human(
+                "🔁 SYNC MODE - Labels not in the set will be deleted"
+                    .magenta()
+                    .bold(),
             );
         }
 
         if self.config.delete_all {
-            println!(
-                "{}",
+            print_human(
                 "🗑️  DELETE ALL MODE - Will remove existing labels first"
                     .red()
-                    .bold()
+                    .bold(),
             );
         }
 
         if self.config.update_only {
-            println!(
-                "{}",
+            print_human(
                 "🔄 UPDATE ONLY MODE - Only updating existing labels"
                     .green()
-                    .bold()
+                    .bold(),
             );
         }
 
-        println!();
+        print_human("");
     }
 
     pub async fn check_and_install_gh_cli(&self) -> Result<()> {
-        println!("{}", "🔧 Checking GitHub CLI installation...".yellow());
+        print_human("🔧 Checking GitHub CLI installation...".yellow());
 
         if self.is_gh_cli_installed().await {
             let version = self.get_gh_version().await?;
-            println!("{}", format!("✅ GitHub CLI found: {}", version).green());
+            print_human(format!("✅ GitHub CLI found: {}", version).green());
             return Ok(());
         }
 
-        println!("{}", "❌ GitHub CLI (gh) is not installed.".red());
+        print_human("❌ GitHub CLI (gh) is not installed.".red());
 
         if self.config.dry_run {
-            println!("{}", "🔍 DRY RUN: Would install GitHub CLI".yellow());
+            print_human("🔍 DRY RUN: Would install GitHub CLI".yellow());
             return Ok(());
         }
 
-        let install = self
-            .prompt_user("🤔 Would you like to install it automatically? (y/N): ")
-            .await?;
-
-        if install.to_lowercase() == "y" || install.to_lowercase() == "yes" {
+        // Installing system packages is not something to do unattended
+        if !prompt::is_non_interactive()
+            && self
+                .confirm("🤔 Would you like to install it automatically? (y/N): ")
+                .await?
+        {
             self.install_gh_cli().await?;
 
             if self.is_gh_cli_installed().await {
                 let version = self.get_gh_version().await?;
-                println!(
-                    "{}",
-                    format!("✅ GitHub CLI installed successfully! {}", version).green()
-                );
+                print_human(format!("✅ GitHub CLI installed successfully! {}", version).green());
             } else {
                 return Err(anyhow!("❌ Installation failed. Please install manually."));
             }
@@ -200,13 +545,13 @@ impl GitHubLabelsManager {
     pub async fn is_gh_cli_installed(&self) -> bool {
         Command::new("gh")
             .arg("--version")
-            .output()
+            .supervised_output()
             .map(|output| output.status.success())
             .unwrap_or(false)
     }
 
     pub async fn get_gh_version(&self) -> Result<String> {
-        let output = Command::new("gh").arg("--version").output()?;
+        let output = Command::new("gh").arg("--version").supervised_output()?;
 
         let version = String::from_utf8(output.stdout)?;
         Ok(version
@@ -218,10 +563,7 @@ impl GitHubLabelsManager {
 
     pub async fn install_gh_cli(&self) -> Result<()> {
         let os = self.detect_os();
-        println!(
-            "{}",
-            format!("🔧 Installing GitHub CLI for {}...", os).yellow()
-        );
+        print_human(format!("🔧 Installing GitHub CLI for {}...", os).yellow());
 
         match os.as_str() {
             "macos" => self.install_macos().await,
@@ -229,22 +571,16 @@ impl GitHubLabelsManager {
             "centos" | "rhel" => self.install_centos().await,
             "fedora" => self.install_fedora().await,
             "windows" => {
-                println!(
-                    "{}",
-                    "❌ Windows detected. Please install GitHub CLI manually:".red()
-                );
-                println!("1. Download from: https://github.com/cli/cli/releases");
-                println!("2. Or use Chocolatey: choco install gh");
-                println!("3. Or use Scoop: scoop install gh");
-                println!("4. Or use Winget: winget install --id GitHub.cli");
+                print_human("❌ Windows detected. Please install GitHub CLI manually:".red());
+                print_human("1. Download from: https://github.com/cli/cli/releases");
+                print_human("2. Or use Chocolatey: choco install gh");
+                print_human("3. Or use Scoop: scoop install gh");
+                print_human("4. Or use Winget: winget install --id GitHub.cli");
                 Err(anyhow!("Manual installation required for Windows"))
             }
             _ => {
-                println!(
-                    "{}",
-                    format!("❌ Unsupported operating system: {}", os).red()
-                );
-                println!("Please install GitHub CLI manually from: https://cli.github.com/");
+                print_human(format!("❌ Unsupported operating system: {}", os).red());
+                print_human("Please install GitHub CLI manually from: https://cli.github.com/");
                 Err(anyhow!("Unsupported OS"))
             }
         }
@@ -272,14 +608,23 @@ impl GitHubLabelsManager {
     }
 
     pub async fn install_macos(&self) -> Result<()> {
-        if Command::new("brew").arg("--version").output().is_ok() {
-            println!("{}", "Installing via Homebrew...".blue());
-            let status = Command::new("brew").args(&["install", "gh"]).status()?;
-
-            if status.success() {
+        if Command::new("brew")
+            .arg("--version")
+            .supervised_output()
+            .is_ok()
+        {
+            print_human("Installing via Homebrew...".blue());
+            let output = Command::new("brew")
+                .args(["install", "gh"])
+                .streamed_output()?;
+
+            if output.status.success() {
                 Ok(())
             } else {
-                Err(anyhow!("Failed to install via Homebrew"))
+                Err(anyhow!(
+                    "Failed to install via Homebrew: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ))
             }
         } else {
             Err(anyhow!("❌ Homebrew not found. Please install Homebrew first:\n/bin/bash -c \"$(curl -fsSL https://raw.githubusercontent.com/Homebrew/install/HEAD/install.sh)\"\nThen run: brew install gh"))
@@ -287,7 +632,7 @@ impl GitHubLabelsManager {
     }
 
     pub async fn install_ubuntu(&self) -> Result<()> {
-        println!("{}", "Installing via apt-get...".blue());
+        print_human("Installing via apt-get...".blue());
 
         // Add GitHub CLI repository
         let commands = vec![
@@ -301,10 +646,14 @@ impl GitHubLabelsManager {
         ];
 
         for cmd in commands {
-            let status = Command::new(&cmd[0]).args(&cmd[1..]).status()?;
+            let output = Command::new(cmd[0]).args(&cmd[1..]).streamed_output()?;
 
-            if !status.success() {
-                return Err(anyhow!("Failed to execute: {}", cmd.join(" ")));
+            if !output.status.success() {
+                return Err(anyhow!(
+                    "Failed to execute: {}: {}",
+                    cmd.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
             }
         }
 
@@ -312,7 +661,7 @@ impl GitHubLabelsManager {
     }
 
     pub async fn install_centos(&self) -> Result<()> {
-        println!("{}", "Installing via yum...".blue());
+        print_human("Installing via yum...".blue());
 
         let commands = vec![
             vec!["sudo", "yum", "install", "-y", "dnf-plugins-core"],
@@ -327,10 +676,14 @@ impl GitHubLabelsManager {
         ];
 
         for cmd in commands {
-            let status = Command::new(&cmd[0]).args(&cmd[1..]).status()?;
+            let output = Command::new(cmd[0]).args(&cmd[1..]).streamed_output()?;
 
-            if !status.success() {
-                return Err(anyhow!("Failed to execute: {}", cmd.join(" ")));
+            if !output.status.success() {
+                return Err(anyhow!(
+                    "Failed to execute: {}: {}",
+                    cmd.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
             }
         }
 
@@ -338,49 +691,59 @@ impl GitHubLabelsManager {
     }
 
     pub async fn install_fedora(&self) -> Result<()> {
-        println!("{}", "Installing via dnf...".blue());
+        print_human("Installing via dnf...".blue());
 
-        let status = Command::new("sudo")
-            .args(&["dnf", "install", "gh", "-y"])
-            .status()?;
+        let output = Command::new("sudo")
+            .args(["dnf", "install", "gh", "-y"])
+            .streamed_output()?;
 
-        if status.success() {
+        if output.status.success() {
             Ok(())
         } else {
-            Err(anyhow!("Failed to install via dnf"))
+            Err(anyhow!(
+                "Failed to install via dnf: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
         }
     }
 
     pub async fn check_authentication(&self) -> Result<()> {
-        println!("{}", "🔐 Checking GitHub authentication...".yellow());
+        print_human("🔐 Checking GitHub authentication...".yellow());
 
-        let status = Command::new("gh").args(&["auth", "status"]).output()?;
+        let status = Command::new("gh")
+            .args(["auth", "status"])
+            .supervised_output()?;
 
         if status.status.success() {
-            println!("{}", "✅ Already authenticated with GitHub".green());
+            print_human("✅ Already authenticated with GitHub".green());
             let auth_info = String::from_utf8_lossy(&status.stderr);
-            println!("{}", auth_info.trim().dimmed());
+            print_human(auth_info.trim().dimmed());
         } else {
-            println!("{}", "❌ Not authenticated with GitHub.".red());
+            print_human("❌ Not authenticated with GitHub.".red());
 
             if self.config.dry_run {
-                println!("{}", "🔍 DRY RUN: Would authenticate with GitHub".yellow());
+                print_human("🔍 DRY RUN: Would authenticate with GitHub".yellow());
                 return Ok(());
             }
 
-            let authenticate = self
-                .prompt_user("🔑 Would you like to authenticate now? (y/N): ")
-                .await?;
-
-            if authenticate.to_lowercase() == "y" || authenticate.to_lowercase() == "yes" {
-                println!("{}", "🌐 Opening browser for authentication...".blue());
+            // Browser login can't complete without a user, use GH_TOKEN in CI
+            if prompt::is_non_interactive() {
+                return Err(anyhow!(
+                    "❌ GitHub authentication is required.\nRun: gh auth login (or set GH_TOKEN)"
+                ));
+            }
+            if self
+                .confirm("🔑 Would you like to authenticate now? (y/N): ")
+                .await?
+            {
+                print_human("🌐 Opening browser for authentication...".blue());
 
                 let status = Command::new("gh")
-                    .args(&["auth", "login", "--web"])
-                    .status()?;
+                    .args(["auth", "login", "--web"])
+                    .supervised_status()?;
 
                 if status.success() {
-                    println!("{}", "✅ Authentication successful!".green());
+                    print_human("✅ Authentication successful!".green());
                 } else {
                     return Err(anyhow!("❌ Authentication failed."));
                 }
@@ -394,26 +757,29 @@ impl GitHubLabelsManager {
         Ok(())
     }
 
-    pub async fn prompt_user(&self, message: &str) -> Result<String> {
-        print!("{}", message.cyan());
-        use std::io::{self, Write};
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-
-        Ok(input.trim().to_string())
+    pub async fn confirm(&self, message: &str) -> Result<bool> {
+        prompt::confirm(message.cyan())
     }
 
     pub async fn list_labels(&self) -> Result<()> {
-        println!("{}", "📋 Current labels:".cyan().bold());
+        print_human("📋 Current labels:".cyan().bold());
+
+        if self.api.is_some() {
+            for label in self.fetch_current_labels().await? {
+                print_human(format!(
+                    "{:<32} {} {}",
+                    label.name,
+                    format!("#{}", label.color).dimmed(),
+                    label.description
+                ));
+            }
+            return Ok(());
+        }
 
-        let output = Command::new("gh")
-            .args(&["label", "list", "--limit", "50"])
-            .output()?;
+        let output = self.gh_command(&["label", "list", "--limit", "50"])?;
 
-        if output.status.success() {
-            println!("{}", String::from_utf8_lossy(&output.stdout));
+        if output.success {
+            print_human(output.stdout);
         } else {
             return Err(anyhow!("Failed to list labels"));
         }
@@ -422,90 +788,309 @@ impl GitHubLabelsManager {
     }
 
     pub async fn delete_all_labels(&self) -> Result<()> {
-        println!("{}", "🗑️  Deleting all existing labels...".red().bold());
+        print_human("🗑️  Deleting all existing labels...".red().bold());
 
-        let output = Command::new("gh")
-            .args(&["label", "list", "--limit", "100"])
-            .output()?;
+        for label in self.fetch_current_labels().await? {
+            let label_name = label.name.as_str();
+            if self.config.dry_run {
+                print_human(format!("🔍 Would delete: {}", label_name).yellow());
+                self.record("delete", label_name, label_name, None);
+            } else {
+                print_human(format!("Deleting: {}", label_name));
+                let result = self.delete_label(label_name).await;
+                match &result {
+                    Ok(()) => {
+                        print_human("  ✅ Deleted successfully");
+                    }
+                    Err(_) => {
+                        print_human(format!("  ⚠️  Could not delete {}", label_name));
+                    }
+                }
+                self.record("delete", label_name, label_name, Some(&result));
+            }
+        }
 
-        if !output.status.success() {
-            return Err(anyhow!("Failed to list labels for deletion"));
+        Ok(())
+    }
+
+    pub async fn fetch_current_labels(&self) -> Result<Vec<GitHubLabel>> {
+        if let Some(api) = &self.api {
+            return api.list_labels().await;
         }
 
-        let labels_output = String::from_utf8_lossy(&output.stdout);
+        let output = self.gh_command(&[
+            "label",
+            "list",
+            "--limit",
+            "1000",
+            "--json",
+            "name,color,description",
+        ])?;
+
+        if !output.success {
+            return Err(anyhow!("Failed to list labels: {}", output.stderr.trim()));
+        }
 
-        for line in labels_output.lines() {
-            if let Some(label_name) = line.split_whitespace().next() {
-                if self.config.dry_run {
-                    println!("{}", format!("🔍 Would delete: {}", label_name).yellow());
-                } else {
-                    println!("Deleting: {}", label_name);
-                    let status = Command::new("gh")
-                        .args(&["label", "delete", label_name, "--yes"])
-                        .status();
-
-                    match status {
-                        Ok(status) if status.success() => {
-                            println!("  ✅ Deleted successfully");
-                        }
-                        _ => {
-                            println!("  ⚠️  Could not delete {}", label_name);
-                        }
-                    }
+        parse_label_list_json(&output.stdout)
+    }
+
+    /// Runs a `gh` command aimed at `config.repo` when one is set.
+    fn gh_command(&self, args: &[&str]) -> Result<CommandOutput> {
+        let mut args = args.to_vec();
+        if let Some(repo) = &self.config.repo {
+            args.extend(["--repo", repo.as_str()]);
+        }
+        self.runner.run("gh", &args, Some(&self.config.dir))
+    }
+
+    /// Runs `gh` and turns a non-zero exit into an error.
+    fn gh(&self, args: &[&str]) -> Result<()> {
+        self.gh_command(args)?
+            .check(&format!("gh {}", args[..2].join(" ")))?;
+        Ok(())
+    }
+
+    pub async fn create_label(&self, label: &GitHubLabel) -> Result<()> {
+        match &self.api {
+            Some(api) => api.create_label(label).await,
+            None => self.gh(&[
+                "label",
+                "create",
+                &label.name,
+                "--description",
+                &label.description,
+                "--color",
+                &label.color,
+            ]),
+        }
+    }
+
+    pub async fn edit_label(&self, current_name: &str, label: &GitHubLabel) -> Result<()> {
+        match &self.api {
+            Some(api) => api.update_label(current_name, label).await,
+            None => self.gh(&[
+                "label",
+                "edit",
+                current_name,
+                "--name",
+                &label.name,
+                "--description",
+                &label.description,
+                "--color",
+                &label.color,
+            ]),
+        }
+    }
+
+    pub async fn delete_label(&self, name: &str) -> Result<()> {
+        match &self.api {
+            Some(api) => api.delete_label(name).await,
+            None => self.gh(&["label", "delete", name, "--yes"]),
+        }
+    }
+
+    /// The label file, or the built-in set with its renames as aliases.
+    pub fn desired_labels(&self) -> Vec<LabelDefinition> {
+        if let Some(labels) = &self.labels {
+            return labels.clone();
+        }
+
+        let renamed = self
+            .get_existing_labels_to_update()
+            .into_iter()
+            .map(|update| LabelDefinition {
+                name: update.new_name,
+                color: update.color,
+                description: update.description,
+                aliases: vec![update.old_name],
+            });
+        let created = self
+            .get_new_labels_to_create()
+            .into_iter()
+            .map(|label| LabelDefinition {
+                name: label.name,
+                color: label.color,
+                description: label.description,
+                aliases: Vec::new(),
+            });
+        renamed.chain(created).collect()
+    }
+
+    pub fn print_label_diff(&self, changes: &[LabelChange]) {
+        print_human("📋 Label sync plan:".cyan().bold());
+        print_human("─".repeat(78).dimmed());
+        print_human(format!("{:<10} {:<32} Details", "Action", "Label"));
+        print_human("─".repeat(78).dimmed());
+
+        for change in changes {
+            match change {
+                LabelChange::Create(label) => print_human(format!(
+                    "{:<10} {:<32} {}",
+                    "+ create".green(),
+                    label.name,
+                    format!("#{} {}", label.color, label.description).dimmed()
+                )),
+                LabelChange::Update {
+                    current_name,
+                    changes,
+                    ..
+                } => print_human(format!(
+                    "{:<10} {:<32} {}",
+                    "~ update".yellow(),
+                    current_name,
+                    changes.join(", ").dimmed()
+                )),
+                LabelChange::Delete(label) => {
+                    print_human(format!("{:<10} {:<32}", "- delete".red(), label.name))
+                }
+                LabelChange::Unchanged(name) => {
+                    print_human(format!("{:<10} {}", "  ok".dimmed(), name.dimmed()))
                 }
             }
         }
+        print_human("─".repeat(78).dimmed());
+
+        let count = |f: fn(&LabelChange) -> bool| changes.iter().filter(|c| f(c)).count();
+        print_human(format!(
+            "{} to create, {} to update, {} to delete, {} unchanged",
+            count(|c| matches!(c, LabelChange::Create(_)))
+                .to_string()
+                .green(),
+            count(|c| matches!(c, LabelChange::Update { .. }))
+                .to_string()
+                .yellow(),
+            count(|c| matches!(c, LabelChange::Delete(_)))
+                .to_string()
+                .red(),
+            count(|c| matches!(c, LabelChange::Unchanged(_)))
+        ));
+    }
 
+    /// Shows the full diff and applies it only after confirmation.
+    pub async fn sync_labels(&self) -> Result<()> {
+        let current = self.fetch_current_labels().await?;
+        let changes = compute_label_diff(&self.desired_labels(), &current);
+        self.print_label_diff(&changes);
+
+        let pending: Vec<&LabelChange> = changes
+            .iter()
+            .filter(|change| !matches!(change, LabelChange::Unchanged(_)))
+            .collect();
+        if pending.is_empty() {
+            print_human("✅ Labels are already in sync".green());
+            return Ok(());
+        }
+        if self.config.dry_run {
+            for change in &pending {
+                let (action, label, from) = change_summary(change);
+                self.record(action, label, from, None);
+            }
+            print_human("🔍 DRY RUN: No changes applied".yellow());
+            return Ok(());
+        }
+
+        if !self
+            .confirm(&format!("Apply {} changes? (y/N): ", pending.len()))
+            .await?
+        {
+            print_human("❌ Sync cancelled".yellow());
+            return Ok(());
+        }
+
+        self.apply_label_changes(&changes).await?;
+        print_human("🎉 Labels are in sync".green().bold());
+        Ok(())
+    }
+
+    /// Applies a computed diff without asking; unchanged labels are skipped.
+    pub async fn apply_label_changes(&self, changes: &[LabelChange]) -> Result<()> {
+        let mut failed = 0;
+        for change in changes {
+            let result = match change {
+                LabelChange::Create(label) => {
+                    print_human(format!("Creating {}", label.name));
+                    self.create_label(label).await
+                }
+                LabelChange::Update {
+                    current_name,
+                    label,
+                    ..
+                } => {
+                    print_human(format!("Updating {}", current_name));
+                    self.edit_label(current_name, label).await
+                }
+                LabelChange::Delete(label) => {
+                    print_human(format!("Deleting {}", label.name));
+                    self.delete_label(&label.name).await
+                }
+                LabelChange::Unchanged(_) => continue,
+            };
+
+            match &result {
+                Ok(()) => print_human("  ✅ Done"),
+                Err(e) => {
+                    failed += 1;
+                    print_human(format!("  ⚠️  Failed: {}", e));
+                }
+            }
+            let (action, label, from) = change_summary(change);
+            self.record(action, label, from, Some(&result));
+        }
+
+        if failed > 0 {
+            return Err(anyhow!("{} label changes failed", failed));
+        }
         Ok(())
     }
 
     pub async fn update_existing_labels(&self) -> Result<()> {
-        println!(
-            "{}",
-            "🔄 Updating existing labels with emojis...".blue().bold()
-        );
+        print_human("🔄 Updating existing labels with emojis...".blue().bold());
 
         let existing_labels_to_update = self.get_existing_labels_to_update();
 
         for label_update in existing_labels_to_update {
-            println!(
-                "Updating: {} → {}",
-                label_update.old_name.yellow(),
-                label_update.new_name.green()
-            );
+            print_human(format!(
+                "Updating: - {} → + {}",
+                paint(&label_update.old_name, Role::Error),
+                paint(&label_update.new_name, Role::Success)
+            ));
 
             if self.config.dry_run {
-                println!(
-                    "{}",
+                print_human(
                     format!(
                         "🔍 DRY RUN: Would update {} to {}",
                         label_update.old_name, label_update.new_name
                     )
-                    .yellow()
+                    .yellow(),
+                );
+                self.record(
+                    "update",
+                    &label_update.new_name,
+                    &label_update.old_name,
+                    None,
                 );
             } else {
-                let status = Command::new("gh")
-                    .args(&[
-                        "label",
-                        "edit",
-                        &label_update.old_name,
-                        "--name",
-                        &label_update.new_name,
-                        "--description",
-                        &label_update.description,
-                        "--color",
-                        &label_update.color,
-                    ])
-                    .status();
-
-                match status {
-                    Ok(status) if status.success() => {
-                        println!("  ✅ Updated successfully");
+                let label = GitHubLabel {
+                    name: label_update.new_name.clone(),
+                    description: label_update.description.clone(),
+                    color: label_update.color.clone(),
+                };
+
+                let result = self.edit_label(&label_update.old_name, &label).await;
+                match &result {
+                    Ok(()) => {
+                        print_human("  ✅ Updated successfully");
                     }
-                    _ => {
-                        println!("  ⚠️  Error updating or label not found");
+                    Err(_) => {
+                        print_human("  ⚠️  Error updating or label not found");
                     }
                 }
+                self.record(
+                    "update",
+                    &label_update.new_name,
+                    &label_update.old_name,
+                    Some(&result),
+                );
             }
         }
 
@@ -517,39 +1102,27 @@ impl GitHubLabelsManager {
             return Ok(());
         }
 
-        println!("{}", "🎨 Creating new Nitroterm labels...".green().bold());
+        print_human("🎨 Creating new Nitroterm labels...".green().bold());
 
         let new_labels = self.get_new_labels_to_create();
 
         for label in new_labels {
-            println!("Creating: {}", label.name.bright_green());
+            print_human(format!("Creating: {}", label.name.bright_green()));
 
             if self.config.dry_run {
-                println!(
-                    "{}",
-                    format!("🔍 DRY RUN: Would create label '{}'", label.name).yellow()
-                );
+                print_human(format!("🔍 DRY RUN: Would create label '{}'", label.name).yellow());
+                self.record("create", &label.name, &label.name, None);
             } else {
-                let status = Command::new("gh")
-                    .args(&[
-                        "label",
-                        "create",
-                        &label.name,
-                        "--description",
-                        &label.description,
-                        "--color",
-                        &label.color,
-                    ])
-                    .status();
-
-                match status {
-                    Ok(status) if status.success() => {
-                        println!("  ✅ Created successfully");
+                let result = self.create_label(&label).await;
+                match &result {
+                    Ok(()) => {
+                        print_human("  ✅ Created successfully");
                     }
-                    _ => {
-                        println!("  ⚠️  Error creating label or already exists");
+                    Err(_) => {
+                        print_human("  ⚠️  Error creating label or already exists");
                     }
                 }
+                self.record("create", &label.name, &label.name, Some(&result));
             }
         }
 
@@ -557,6 +1130,24 @@ impl GitHubLabelsManager {
     }
 
     pub fn get_existing_labels_to_update(&self) -> Vec<LabelUpdate> {
+        if let Some(labels) = &self.labels {
+            return labels
+                .iter()
+                .flat_map(|label| {
+                    label
+                        .aliases
+                        .iter()
+                        .filter(|alias| !alias.eq_ignore_ascii_case(&label.name))
+                        .map(|alias| LabelUpdate {
+                            old_name: alias.clone(),
+                            new_name: label.name.clone(),
+                            description: label.description.clone(),
+                            color: label.color.clone(),
+                        })
+                })
+                .collect();
+        }
+
         vec![
             LabelUpdate {
                 old_name: "bug".to_string(),
@@ -628,6 +1219,19 @@ impl GitHubLabelsManager {
     }
 
     pub fn get_new_labels_to_create(&self) -> Vec<GitHubLabel> {
+        // Labels with aliases are usually renamed into place; creating them
+        // afterwards just reports that they already exist.
+        if let Some(labels) = &self.labels {
+            return labels
+                .iter()
+                .map(|label| GitHubLabel {
+                    name: label.name.clone(),
+                    description: label.description.clone(),
+                    color: label.color.clone(),
+                })
+                .collect();
+        }
+
         vec![
             // Priority Labels
             GitHubLabel {
@@ -748,55 +1352,230 @@ impl GitHubLabelsManager {
     }
 
     pub async fn show_completion_info(&self) -> Result<()> {
-        println!();
-        println!("{}", "🎉 Label management completed!".green().bold());
-        println!();
+        print_human("");
+        print_human("🎉 Label management completed!".green().bold());
+        print_human("");
 
         if !self.config.dry_run {
-            println!("{}", "📋 Current labels:".cyan().bold());
+            print_human("📋 Current labels:".cyan().bold());
             let _ = self.list_labels().await;
         }
 
-        println!();
-        println!("{}", "🔧 Useful commands:".blue().bold());
-        println!("📋 To view all labels: {}", "gh label list".green());
-        println!(
+        print_human("");
+        print_human("🔧 Useful commands:".blue().bold());
+        print_human(format!(
+            "📋 To view all labels: {}",
+            "gh label list".green()
+        ));
+        print_human(format!(
             "🗑️  To delete a label: {}",
             "gh label delete 'label-name' --yes".green()
-        );
-        println!(
+        ));
+        print_human(format!(
             "✏️  To edit a label: {}",
             "gh label edit 'label-name' --description 'new desc' --color 'FFFFFF'".green()
-        );
-        println!("❓ For help: {}", "nitroterm github-labels --help".green());
+        ));
+        print_human(format!(
+            "❓ For help: {}",
+            "nitroterm github-labels --help".green()
+        ));
 
         Ok(())
     }
 }
 
-// CLI command handlers
-pub async fn run_github_labels(
-    skip_auth: bool,
-    skip_install: bool,
-    dry_run: bool,
-    list_only: bool,
-    delete_all: bool,
-    update_only: bool,
-) -> Result<()> {
-    let config = GitHubLabelsConfig {
-        skip_auth,
+/// Builds the manager, loading the label file from the flag or the
+/// `labels_file` config key. The file is validated before anything touches GitHub.
+/// API mode comes from `--api` or `github_backend = api`; without a token
+/// it falls back to the `gh` CLI.
+async fn labels_manager(mut config: GitHubLabelsConfig) -> Result<GitHubLabelsManager> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    if config.labels_file.is_none() {
+        config.labels_file = app_config.labels_file;
+    }
+    config.api = config.api || app_config.github_backend == "api";
+
+    let manager = match config.labels_file.clone() {
+        Some(file) => {
+            let labels = load_labels_file(&config.dir.join(&file))?;
+            GitHubLabelsManager::with_labels(config, labels)
+        }
+        None => GitHubLabelsManager::new(config),
+    };
+
+    if !manager.config.api {
+        return Ok(manager);
+    }
+    match GitHubApiClient::from_env_in(&manager.config.dir, manager.config.repo.as_deref()) {
+        Ok(client) => Ok(manager.with_api(client)),
+        Err(e) => {
+            print_human(format!("⚠️  {}; falling back to the gh CLI", e).yellow());
+            Ok(manager)
+        }
+    }
+}
+
+/// The `gh` install and authentication checks, shared with `github-milestones`.
+pub async fn ensure_gh_ready(skip_install: bool, skip_auth: bool, dry_run: bool) -> Result<()> {
+    let manager = GitHubLabelsManager::new(GitHubLabelsConfig {
         skip_install,
+        skip_auth,
         dry_run,
-        list_only,
-        delete_all,
-        update_only,
-    };
+        ..GitHubLabelsConfig::default()
+    });
+    if !skip_install {
+        manager.check_and_install_gh_cli().await?;
+    }
+    if !skip_auth {
+        manager.check_authentication().await?;
+    }
+    Ok(())
+}
 
-    let manager = GitHubLabelsManager::new(config);
-    manager.run().await
+// CLI command handlers
+pub async fn run_github_labels(config: GitHubLabelsConfig) -> Result<()> {
+    let manager = labels_manager(config).await?;
+    let result = manager.run().await;
+    if is_json_output() {
+        if manager.config.list_only {
+            print_json(&manager.fetch_current_labels().await?)?;
+        } else {
+            print_json(&manager.actions())?;
+        }
+    }
+    result
+}
+
+/// `owner/name` per line; blank lines and `#` comments are skipped.
+pub fn parse_repo_list(content: &str) -> Result<Vec<String>> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(normalize_repo)
+        .collect()
+}
+
+fn normalize_repo(repo: &str) -> Result<String> {
+    let (owner, name) = resolve_repo(Some(repo))?;
+    Ok(format!("{}/{}", owner, name))
+}
+
+/// Applies the same label set to several repositories and prints a result
+/// per repository. One failing repo doesn't stop the others.
+pub async fn run_github_labels_for_repos(
+    config: GitHubLabelsConfig,
+    repos: &[String],
+) -> Result<()> {
+    ensure_not_sandboxed("github-labels")?;
+    let repos = repos
+        .iter()
+        .map(|repo| normalize_repo(repo))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut results = Vec::new();
+    let mut report = Vec::new();
+    for (index, repo) in repos.iter().enumerate() {
+        let config = GitHubLabelsConfig {
+            repo: Some(repo.clone()),
+            ..config.clone()
+        };
+        let manager = labels_manager(config).await?;
+        if index == 0 {
+            manager.print_banner();
+        }
+        print_human(
+            format!("━━━ [{}/{}] {} ━━━", index + 1, repos.len(), repo)
+                .cyan()
+                .bold(),
+        );
+        manager.show_configuration();
+        let result = manager.apply().await;
+        if let Err(e) = &result {
+            print_human(format!("❌ {}: {}", repo, e).red());
+        }
+        report.push(RepoLabelActions {
+            repo: repo.clone(),
+            actions: manager.actions(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        });
+        results.push((repo, result));
+    }
+    if is_json_output() {
+        print_json(&report)?;
+    }
+
+    print_human("");
+    print_human("📊 Repository summary:".cyan().bold());
+    for (repo, result) in &results {
+        match result {
+            Ok(()) => print_human(format!("  {} {}", "✅".green(), repo)),
+            Err(e) => print_human(format!(
+                "  {} {} {}",
+                "❌".red(),
+                repo,
+                format!("- {}", e).dimmed()
+            )),
+        }
+    }
+
+    let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+    if failed > 0 {
+        return Err(anyhow!(
+            "{} of {} repositories failed",
+            failed,
+            results.len()
+        ));
+    }
+    print_human(
+        format!("🎉 Labels applied to {} repositories", results.len())
+            .green()
+            .bold(),
+    );
+    Ok(())
 }
 
 pub async fn run_github_labels_interactive() -> Result<()> {
-    let manager = GitHubLabelsManager::new(GitHubLabelsConfig::default());
+    let manager = labels_manager(GitHubLabelsConfig::default()).await?;
     manager.run().await
 }
+
+/// Validates a label file and stores its absolute path as the default for
+/// `github-labels`.
+pub async fn set_labels_file(path: &str) -> Result<()> {
+    let path = Path::new(path).canonicalize()?;
+    let labels = load_labels_file(&path)?;
+
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.labels_file = Some(path.to_string_lossy().to_string());
+    config_manager.save_config(&config).await?;
+
+    print_human(
+        format!(
+            "🏷️  Labels file set to {} ({} labels)",
+            path.display(),
+            labels.len()
+        )
+        .green(),
+    );
+    Ok(())
+}
+
+/// Stores whether `github-labels` uses the `gh` CLI or the REST API by default.
+pub async fn set_github_backend(name: &str) -> Result<()> {
+    if !matches!(name, "gh" | "api") {
+        return Err(anyhow!("Unknown backend '{}', use gh or api", name));
+    }
+
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.github_backend = name.to_string();
+    config_manager.save_config(&config).await?;
+
+    print_human(format!("🔌 GitHub backend set to '{}'", name).green());
+    Ok(())
+}
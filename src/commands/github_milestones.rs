@@ -0,0 +1,543 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::github_api::GitHubApiClient;
+use crate::commands::github_labels::ensure_gh_ready;
+use crate::utils::process::{CommandOutput, CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+pub const DEFAULT_MILESTONES_FILE: &str = ".github/milestones.yml";
+
+/// One entry of the milestones file. Milestones are matched by title.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneDefinition {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// `YYYY-MM-DD`
+    #[serde(default)]
+    pub due_on: Option<String>,
+    /// `open` or `closed`
+    #[serde(default = "default_state")]
+    pub state: String,
+}
+
+fn default_state() -> String {
+    "open".to_string()
+}
+
+/// Milestones files are either a bare list or a `milestones:` table.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MilestoneFile {
+    List(Vec<MilestoneDefinition>),
+    Table {
+        milestones: Vec<MilestoneDefinition>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitHubMilestone {
+    pub number: u64,
+    pub title: String,
+    pub description: String,
+    pub state: String,
+    /// Date part of GitHub's `due_on` timestamp
+    pub due_on: Option<String>,
+    pub open_issues: u64,
+    pub closed_issues: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MilestoneChange {
+    Create(MilestoneDefinition),
+    /// `changes` lists what differs from the milestone on GitHub
+    Update {
+        number: u64,
+        definition: MilestoneDefinition,
+        changes: Vec<String>,
+    },
+    Close {
+        number: u64,
+        title: String,
+    },
+    Unchanged(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GitHubMilestonesConfig {
+    pub skip_auth: bool,
+    pub skip_install: bool,
+    pub dry_run: bool,
+    pub list_only: bool,
+    /// Milestones file; `.github/milestones.yml` when unset
+    pub file: Option<String>,
+    /// Also close open milestones that are missing from the file
+    pub sync: bool,
+    /// Close this one milestone and stop
+    pub close: Option<String>,
+    pub api: bool,
+    pub repo: Option<String>,
+}
+
+/// Parses a YAML or JSON milestones file without validating it.
+pub fn parse_milestones(content: &str) -> Result<Vec<MilestoneDefinition>> {
+    match serde_yaml::from_str::<MilestoneFile>(content)? {
+        MilestoneFile::List(milestones) | MilestoneFile::Table { milestones } => Ok(milestones),
+    }
+}
+
+/// Returns every problem in the set. Titles are compared case-insensitively.
+pub fn validate_milestones(milestones: &[MilestoneDefinition]) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+
+    for milestone in milestones {
+        let title = milestone.title.trim();
+        if title.is_empty() {
+            errors.push("Milestone with an empty title".to_string());
+            continue;
+        }
+        if !seen.insert(title.to_lowercase()) {
+            errors.push(format!("Duplicate milestone '{}'", title));
+        }
+        if let Some(due_on) = &milestone.due_on {
+            if NaiveDate::parse_from_str(due_on, "%Y-%m-%d").is_err() {
+                errors.push(format!(
+                    "'{}': invalid due date '{}' (expected YYYY-MM-DD)",
+                    title, due_on
+                ));
+            }
+        }
+        if !matches!(milestone.state.as_str(), "open" | "closed") {
+            errors.push(format!(
+                "'{}': state must be open or closed, not '{}'",
+                title, milestone.state
+            ));
+        }
+    }
+
+    errors
+}
+
+pub fn load_milestones_file(path: &Path) -> Result<Vec<MilestoneDefinition>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Cannot read milestones file {}: {}", path.display(), e))?;
+    let milestones = parse_milestones(&content)
+        .map_err(|e| anyhow!("Invalid milestones file {}: {}", path.display(), e))?;
+    if milestones.is_empty() {
+        return Err(anyhow!("{} defines no milestones", path.display()));
+    }
+
+    let errors = validate_milestones(&milestones);
+    if !errors.is_empty() {
+        return Err(anyhow!(
+            "{} has {} problem(s):\n  - {}",
+            path.display(),
+            errors.len(),
+            errors.join("\n  - ")
+        ));
+    }
+    Ok(milestones)
+}
+
+/// Reads the REST API's milestone objects (also what `gh api` prints).
+pub fn parse_milestone_list_json(content: &str) -> Result<Vec<GitHubMilestone>> {
+    #[derive(Deserialize)]
+    struct ListedMilestone {
+        number: u64,
+        title: String,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        state: String,
+        #[serde(default)]
+        due_on: Option<String>,
+        #[serde(default)]
+        open_issues: u64,
+        #[serde(default)]
+        closed_issues: u64,
+    }
+
+    let listed: Vec<ListedMilestone> =
+        serde_json::from_str(content).map_err(|e| anyhow!("Unexpected milestone list: {}", e))?;
+    Ok(listed
+        .into_iter()
+        .map(|milestone| GitHubMilestone {
+            number: milestone.number,
+            title: milestone.title,
+            description: milestone.description.unwrap_or_default(),
+            state: milestone.state,
+            due_on: milestone
+                .due_on
+                .map(|due| due.chars().take(10).collect::<String>()),
+            open_issues: milestone.open_issues,
+            closed_issues: milestone.closed_issues,
+        })
+        .collect())
+}
+
+/// Diffs the file against the repo. With `close_missing`, open milestones
+/// that aren't in the file get closed; closed ones are left alone.
+pub fn compute_milestone_diff(
+    desired: &[MilestoneDefinition],
+    current: &[GitHubMilestone],
+    close_missing: bool,
+) -> Vec<MilestoneChange> {
+    let mut matched = HashSet::new();
+    let mut changes = Vec::new();
+
+    for definition in desired {
+        let existing = current.iter().find(|milestone| {
+            milestone
+                .title
+                .eq_ignore_ascii_case(definition.title.trim())
+        });
+        let Some(existing) = existing else {
+            changes.push(MilestoneChange::Create(definition.clone()));
+            continue;
+        };
+        matched.insert(existing.number);
+
+        let mut differences = Vec::new();
+        if existing.title != definition.title {
+            differences.push(format!("title → {}", definition.title));
+        }
+        if existing.description != definition.description {
+            differences.push("description".to_string());
+        }
+        // A file entry without a due date leaves the one on GitHub alone
+        if definition.due_on.is_some() && existing.due_on != definition.due_on {
+            differences.push(format!(
+                "due {} → {}",
+                existing.due_on.as_deref().unwrap_or("none"),
+                definition.due_on.as_deref().unwrap_or("none")
+            ));
+        }
+        if existing.state != definition.state {
+            differences.push(format!("{} → {}", existing.state, definition.state));
+        }
+
+        if differences.is_empty() {
+            changes.push(MilestoneChange::Unchanged(existing.title.clone()));
+        } else {
+            changes.push(MilestoneChange::Update {
+                number: existing.number,
+                definition: definition.clone(),
+                changes: differences,
+            });
+        }
+    }
+
+    if close_missing {
+        for milestone in current {
+            if milestone.state == "open" && !matched.contains(&milestone.number) {
+                changes.push(MilestoneChange::Close {
+                    number: milestone.number,
+                    title: milestone.title.clone(),
+                });
+            }
+        }
+    }
+
+    changes
+}
+
+/// Request fields for a create or update. GitHub wants a full timestamp for `due_on`.
+pub fn milestone_fields(definition: &MilestoneDefinition) -> Value {
+    let mut fields = json!({
+        "title": definition.title,
+        "description": definition.description,
+        "state": definition.state,
+    });
+    if let Some(date) = &definition.due_on {
+        fields["due_on"] = json!(format!("{}T00:00:00Z", date));
+    }
+    fields
+}
+
+pub struct GitHubMilestonesManager {
+    pub config: GitHubMilestonesConfig,
+    pub milestones: Vec<MilestoneDefinition>,
+    /// REST client in API mode; `None` goes through `gh api`
+    pub api: Option<GitHubApiClient>,
+    pub runner: Arc<dyn CommandRunner>,
+}
+
+impl GitHubMilestonesManager {
+    pub fn new(config: GitHubMilestonesConfig, milestones: Vec<MilestoneDefinition>) -> Self {
+        Self {
+            config,
+            milestones,
+            api: None,
+            runner: Arc::new(SystemRunner),
+        }
+    }
+
+    pub fn with_api(mut self, client: GitHubApiClient) -> Self {
+        self.api = Some(client);
+        self
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        ensure_not_sandboxed("github-milestones")?;
+        println!("{}", "🎯 GitHub Milestones".cyan().bold());
+        println!("{}", "═".repeat(35).dimmed());
+
+        match &self.api {
+            Some(api) => println!(
+                "{}",
+                format!("🌐 Using the GitHub REST API for {}", api.repo_slug()).blue()
+            ),
+            None => {
+                ensure_gh_ready(
+                    self.config.skip_install,
+                    self.config.skip_auth,
+                    self.config.dry_run,
+                )
+                .await?
+            }
+        }
+
+        if self.config.list_only {
+            return self.list_milestones().await;
+        }
+        if let Some(title) = &self.config.close {
+            return self.close_by_title(title).await;
+        }
+        self.sync_milestones().await
+    }
+
+    /// `gh api` endpoint; `{owner}/{repo}` is filled in by gh from the current repo.
+    fn endpoint(&self, number: Option<u64>) -> String {
+        let repo = self.config.repo.as_deref().unwrap_or("{owner}/{repo}");
+        match number {
+            Some(number) => format!("repos/{}/milestones/{}", repo, number),
+            None => format!("repos/{}/milestones", repo),
+        }
+    }
+
+    fn gh_api(&self, endpoint: &str, method: &str, fields: &Value) -> Result<CommandOutput> {
+        let mut args = vec![
+            "api".to_string(),
+            endpoint.to_string(),
+            "--method".to_string(),
+            method.to_string(),
+        ];
+        for (key, value) in fields.as_object().into_iter().flatten() {
+            if let Some(value) = value.as_str() {
+                args.push("-f".to_string());
+                args.push(format!("{}={}", key, value));
+            }
+        }
+        let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+        self.runner
+            .run("gh", &args, None)?
+            .check(&format!("gh api {} {}", method, endpoint))
+    }
+
+    pub async fn fetch_current_milestones(&self) -> Result<Vec<GitHubMilestone>> {
+        if let Some(api) = &self.api {
+            return api.list_milestones().await;
+        }
+        let endpoint = format!("{}?state=all&per_page=100", self.endpoint(None));
+        let output = self.gh_api(&endpoint, "GET", &json!({}))?;
+        parse_milestone_list_json(&output.stdout)
+    }
+
+    async fn create_milestone(&self, definition: &MilestoneDefinition) -> Result<()> {
+        let fields = milestone_fields(definition);
+        match &self.api {
+            Some(api) => api.create_milestone(&fields).await,
+            None => self
+                .gh_api(&self.endpoint(None), "POST", &fields)
+                .map(|_| ()),
+        }
+    }
+
+    async fn update_milestone(&self, number: u64, fields: &Value) -> Result<()> {
+        match &self.api {
+            Some(api) => api.update_milestone(number, fields).await,
+            None => self
+                .gh_api(&self.endpoint(Some(number)), "PATCH", fields)
+                .map(|_| ()),
+        }
+    }
+
+    pub async fn list_milestones(&self) -> Result<()> {
+        println!("{}", "📋 Milestones:".cyan().bold());
+        for milestone in self.fetch_current_milestones().await? {
+            let state = if milestone.state == "open" {
+                milestone.state.green()
+            } else {
+                milestone.state.dimmed()
+            };
+            println!(
+                "#{:<4} {:<32} {:<8} {:<12} {}",
+                milestone.number,
+                milestone.title,
+                state,
+                milestone.due_on.as_deref().unwrap_or("-"),
+                format!(
+                    "{} open, {} closed",
+                    milestone.open_issues, milestone.closed_issues
+                )
+                .dimmed()
+            );
+        }
+        Ok(())
+    }
+
+    pub async fn close_by_title(&self, title: &str) -> Result<()> {
+        let current = self.fetch_current_milestones().await?;
+        let milestone = current
+            .iter()
+            .find(|milestone| milestone.title.eq_ignore_ascii_case(title))
+            .ok_or_else(|| anyhow!("Milestone '{}' not found", title))?;
+        if milestone.state == "closed" {
+            println!(
+                "{}",
+                format!("✅ '{}' is already closed", milestone.title).green()
+            );
+            return Ok(());
+        }
+        if self.config.dry_run {
+            println!(
+                "{}",
+                format!("🔍 DRY RUN: Would close '{}'", milestone.title).yellow()
+            );
+            return Ok(());
+        }
+        self.update_milestone(milestone.number, &json!({ "state": "closed" }))
+            .await?;
+        println!("{}", format!("✅ Closed '{}'", milestone.title).green());
+        Ok(())
+    }
+
+    pub fn print_milestone_diff(&self, changes: &[MilestoneChange]) {
+        println!("{}", "📋 Milestone plan:".cyan().bold());
+        println!("{}", "─".repeat(78).dimmed());
+        for change in changes {
+            match change {
+                MilestoneChange::Create(definition) => println!(
+                    "{:<10} {:<32} {}",
+                    "+ create".green(),
+                    definition.title,
+                    definition.due_on.as_deref().unwrap_or("").dimmed()
+                ),
+                MilestoneChange::Update {
+                    definition,
+                    changes,
+                    ..
+                } => println!(
+                    "{:<10} {:<32} {}",
+                    "~ update".yellow(),
+                    definition.title,
+                    changes.join(", ").dimmed()
+                ),
+                MilestoneChange::Close { title, .. } => {
+                    println!("{:<10} {}", "- close".red(), title)
+                }
+                MilestoneChange::Unchanged(title) => {
+                    println!("{:<10} {}", "  ok".dimmed(), title.dimmed())
+                }
+            }
+        }
+        println!("{}", "─".repeat(78).dimmed());
+    }
+
+    pub async fn sync_milestones(&self) -> Result<()> {
+        let current = self.fetch_current_milestones().await?;
+        let changes = compute_milestone_diff(&self.milestones, &current, self.config.sync);
+        self.print_milestone_diff(&changes);
+
+        if changes
+            .iter()
+            .all(|change| matches!(change, MilestoneChange::Unchanged(_)))
+        {
+            println!("{}", "✅ Milestones are already in sync".green());
+            return Ok(());
+        }
+        if self.config.dry_run {
+            println!("{}", "🔍 DRY RUN: No changes applied".yellow());
+            return Ok(());
+        }
+
+        self.apply_milestone_changes(&changes).await?;
+        println!("{}", "🎉 Milestones are in sync".green().bold());
+        Ok(())
+    }
+
+    pub async fn apply_milestone_changes(&self, changes: &[MilestoneChange]) -> Result<()> {
+        let mut failed = 0;
+        for change in changes {
+            let result = match change {
+                MilestoneChange::Create(definition) => {
+                    println!("Creating {}", definition.title);
+                    self.create_milestone(definition).await
+                }
+                MilestoneChange::Update {
+                    number, definition, ..
+                } => {
+                    println!("Updating {}", definition.title);
+                    self.update_milestone(*number, &milestone_fields(definition))
+                        .await
+                }
+                MilestoneChange::Close { number, title } => {
+                    println!("Closing {}", title);
+                    self.update_milestone(*number, &json!({ "state": "closed" }))
+                        .await
+                }
+                MilestoneChange::Unchanged(_) => continue,
+            };
+
+            match result {
+                Ok(()) => println!("  ✅ Done"),
+                Err(e) => {
+                    failed += 1;
+                    println!("  ⚠️  Failed: {}", e);
+                }
+            }
+        }
+
+        if failed > 0 {
+            return Err(anyhow!("{} milestone changes failed", failed));
+        }
+        Ok(())
+    }
+}
+
+/// Loads the milestones file (not needed for `--list` and `--close`) and picks
+/// the backend the same way `github-labels` does.
+pub async fn run_github_milestones(config: GitHubMilestonesConfig) -> Result<()> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let api = config.api || app_config.github_backend == "api";
+
+    let milestones = if config.list_only || config.close.is_some() {
+        Vec::new()
+    } else {
+        let file = config.file.as_deref().unwrap_or(DEFAULT_MILESTONES_FILE);
+        load_milestones_file(Path::new(file))?
+    };
+
+    let mut manager = GitHubMilestonesManager::new(config, milestones);
+    if api {
+        match GitHubApiClient::from_env(manager.config.repo.as_deref()) {
+            Ok(client) => manager = manager.with_api(client),
+            Err(e) => println!(
+                "{}",
+                format!("⚠️  {}; falling back to the gh CLI", e).yellow()
+            ),
+        }
+    }
+    manager.run().await
+}
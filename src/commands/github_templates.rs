@@ -0,0 +1,207 @@
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+/// Labels come from the built-in `github-labels` set, so new issues land
+/// already triaged.
+const BUG_REPORT: &str = r#"name: 🐛 Bug report
+description: Something isn't working as expected
+labels: ["🐛 bug"]
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time to report a bug! Please search existing issues first.
+  - type: textarea
+    id: description
+    attributes:
+      label: What happened?
+      description: A clear description of the bug.
+    validations:
+      required: true
+  - type: textarea
+    id: reproduction
+    attributes:
+      label: Steps to reproduce
+      placeholder: |
+        1. Run '...'
+        2. See error
+    validations:
+      required: true
+  - type: textarea
+    id: expected
+    attributes:
+      label: Expected behavior
+  - type: input
+    id: version
+    attributes:
+      label: Version
+      placeholder: v1.2.3
+  - type: textarea
+    id: logs
+    attributes:
+      label: Relevant log output
+      render: shell
+"#;
+
+const FEATURE_REQUEST: &str = r#"name: ✨ Feature request
+description: Suggest an idea or improvement
+labels: ["✨ feature"]
+body:
+  - type: textarea
+    id: problem
+    attributes:
+      label: Problem
+      description: What are you trying to do, and what gets in the way?
+    validations:
+      required: true
+  - type: textarea
+    id: solution
+    attributes:
+      label: Proposed solution
+    validations:
+      required: true
+  - type: textarea
+    id: alternatives
+    attributes:
+      label: Alternatives considered
+"#;
+
+const ISSUE_CONFIG: &str = r#"blank_issues_enabled: true
+"#;
+
+const PULL_REQUEST: &str = r#"## Summary
+
+<!-- What does this change and why? Link the issue it closes: Closes #123 -->
+
+## Type of change
+
+- [ ] 🐞 Bug fix
+- [ ] ✨ New feature
+- [ ] 💥 Breaking change
+- [ ] 📚 Documentation
+
+## How was this tested?
+
+<!-- Commands you ran, screenshots, anything a reviewer should try -->
+
+## Checklist
+
+- [ ] Tests added or updated
+- [ ] Documentation updated
+- [ ] Release notes entry needed
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemplateKind {
+    Issues,
+    PullRequest,
+}
+
+impl std::str::FromStr for TemplateKind {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "issues" => Ok(TemplateKind::Issues),
+            "pr" => Ok(TemplateKind::PullRequest),
+            _ => Err(anyhow!(
+                "Unknown template kind '{}', use issues or pr",
+                value
+            )),
+        }
+    }
+}
+
+/// Relative path and content of every template of the given kind.
+pub fn templates(kind: Option<TemplateKind>) -> Vec<(&'static str, &'static str)> {
+    let mut files = Vec::new();
+    if kind != Some(TemplateKind::PullRequest) {
+        files.push((".github/ISSUE_TEMPLATE/bug_report.yml", BUG_REPORT));
+        files.push((
+            ".github/ISSUE_TEMPLATE/feature_request.yml",
+            FEATURE_REQUEST,
+        ));
+        files.push((".github/ISSUE_TEMPLATE/config.yml", ISSUE_CONFIG));
+    }
+    if kind != Some(TemplateKind::Issues) {
+        files.push((".github/pull_request_template.md", PULL_REQUEST));
+    }
+    files
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateOutcome {
+    Written(PathBuf),
+    Skipped(PathBuf),
+}
+
+/// Writes the templates under `root`. Existing files are kept unless `force`.
+pub fn write_templates(
+    root: &Path,
+    kind: Option<TemplateKind>,
+    force: bool,
+    dry_run: bool,
+) -> Result<Vec<TemplateOutcome>> {
+    let mut outcomes = Vec::new();
+    for (relative, content) in templates(kind) {
+        let path = root.join(relative);
+        if path.exists() && !force {
+            outcomes.push(TemplateOutcome::Skipped(path));
+            continue;
+        }
+        if !dry_run {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, content)?;
+        }
+        outcomes.push(TemplateOutcome::Written(path));
+    }
+    Ok(outcomes)
+}
+
+pub fn run_github_templates(
+    path: Option<&str>,
+    kind: Option<TemplateKind>,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    ensure_not_sandboxed("github-templates")?;
+    let root = Path::new(path.unwrap_or("."));
+
+    println!("{}", "📝 Scaffolding GitHub templates...".cyan().bold());
+    let outcomes = write_templates(root, kind, force, dry_run)?;
+    for outcome in &outcomes {
+        match outcome {
+            TemplateOutcome::Written(path) if dry_run => {
+                println!("  {} {}", "would write".yellow(), path.display())
+            }
+            TemplateOutcome::Written(path) => println!("  {} {}", "✅".green(), path.display()),
+            TemplateOutcome::Skipped(path) => println!(
+                "  {} {} {}",
+                "⏭️".dimmed(),
+                path.display(),
+                "(exists, use --force to overwrite)".dimmed()
+            ),
+        }
+    }
+
+    let written = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, TemplateOutcome::Written(_)))
+        .count();
+    if dry_run {
+        println!("{}", "🔍 DRY RUN: No files written".yellow());
+    } else {
+        println!(
+            "{}",
+            format!("🎉 Wrote {} template(s)", written).green().bold()
+        );
+        println!(
+            "{}",
+            "💡 Run `nitroterm github-labels` so the labels the templates use exist".dimmed()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,458 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::github_api::GitHubApiClient;
+use crate::commands::github_labels::{
+    ensure_gh_ready, load_labels_file, GitHubLabelsConfig, GitHubLabelsManager, LabelDefinition,
+};
+use crate::utils::output::{is_json_output, print_human, print_json};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+pub const DEFAULT_STALE_DAYS: i64 = 14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriageKind {
+    Issue,
+    PullRequest,
+}
+
+/// An open issue or pull request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TriageItem {
+    pub number: u64,
+    pub title: String,
+    pub kind: TriageKind,
+    pub labels: Vec<String>,
+    pub author: String,
+    pub updated_at: DateTime<Utc>,
+    pub draft: bool,
+}
+
+/// Reads the REST API's issue objects (also what `gh api` prints). Pull
+/// requests come from the same endpoint, marked by `pull_request`.
+pub fn parse_issue_list_json(content: &str) -> Result<Vec<TriageItem>> {
+    #[derive(Deserialize)]
+    struct ListedLabel {
+        name: String,
+    }
+    #[derive(Deserialize)]
+    struct ListedUser {
+        login: String,
+    }
+    #[derive(Deserialize)]
+    struct ListedIssue {
+        number: u64,
+        title: String,
+        #[serde(default)]
+        labels: Vec<ListedLabel>,
+        #[serde(default)]
+        user: Option<ListedUser>,
+        updated_at: DateTime<Utc>,
+        #[serde(default)]
+        draft: bool,
+        #[serde(default)]
+        pull_request: Option<serde_json::Value>,
+    }
+
+    let listed: Vec<ListedIssue> =
+        serde_json::from_str(content).map_err(|e| anyhow!("Unexpected issue list: {}", e))?;
+    Ok(listed
+        .into_iter()
+        .map(|issue| TriageItem {
+            number: issue.number,
+            title: issue.title,
+            kind: if issue.pull_request.is_some() {
+                TriageKind::PullRequest
+            } else {
+                TriageKind::Issue
+            },
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            author: issue.user.map(|user| user.login).unwrap_or_default(),
+            updated_at: issue.updated_at,
+            draft: issue.draft,
+        })
+        .collect())
+}
+
+/// Priority labels of the taxonomy, e.g. `🔴 priority: critical`.
+pub fn priority_labels(taxonomy: &[LabelDefinition]) -> Vec<String> {
+    taxonomy
+        .iter()
+        .filter(|label| label.name.to_lowercase().contains("priority"))
+        .map(|label| label.name.clone())
+        .collect()
+}
+
+/// Groups of the dashboard; each also names a `--to` target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TriageReport {
+    /// Issues without any label
+    pub unlabeled: Vec<TriageItem>,
+    /// Issues with labels, none of them a priority
+    pub missing_priority: Vec<TriageItem>,
+    /// Pull requests without activity for `stale_days`
+    pub stale_prs: Vec<TriageItem>,
+    pub open_issues: usize,
+    pub open_prs: usize,
+    pub stale_days: i64,
+}
+
+impl TriageReport {
+    pub fn build(
+        items: &[TriageItem],
+        priorities: &[String],
+        stale_days: i64,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut report = TriageReport {
+            stale_days,
+            ..TriageReport::default()
+        };
+        let cutoff = now - chrono::Duration::days(stale_days);
+
+        for item in items {
+            match item.kind {
+                TriageKind::Issue => {
+                    report.open_issues += 1;
+                    if item.labels.is_empty() {
+                        report.unlabeled.push(item.clone());
+                    } else if !priorities.is_empty()
+                        && !item.labels.iter().any(|label| {
+                            priorities
+                                .iter()
+                                .any(|priority| priority.eq_ignore_ascii_case(label))
+                        })
+                    {
+                        report.missing_priority.push(item.clone());
+                    }
+                }
+                TriageKind::PullRequest => {
+                    report.open_prs += 1;
+                    if item.updated_at < cutoff {
+                        report.stale_prs.push(item.clone());
+                    }
+                }
+            }
+        }
+        // Longest waiting first
+        report.stale_prs.sort_by_key(|item| item.updated_at);
+        report
+    }
+
+    /// Issue numbers of a `--to` target: `unlabeled`, `missing-priority`,
+    /// `stale` or a comma-separated list like `12,15`.
+    pub fn target(&self, to: &str) -> Result<Vec<u64>> {
+        let numbers = |items: &[TriageItem]| items.iter().map(|item| item.number).collect();
+        match to {
+            "unlabeled" => Ok(numbers(&self.unlabeled)),
+            "missing-priority" => Ok(numbers(&self.missing_priority)),
+            "stale" => Ok(numbers(&self.stale_prs)),
+            list => list
+                .split(',')
+                .map(|number| {
+                    number.trim().trim_start_matches('#').parse().map_err(|_| {
+                        anyhow!(
+                            "Invalid target '{}', use unlabeled, missing-priority, stale or issue numbers",
+                            to
+                        )
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GitHubTriageConfig {
+    pub skip_auth: bool,
+    pub skip_install: bool,
+    pub dry_run: bool,
+    /// Pull requests without activity for this many days are stale
+    pub stale_days: i64,
+    /// Label taxonomy; the `labels_file` config key or the built-in set when unset
+    pub labels_file: Option<String>,
+    /// Label to apply in bulk, with `to`
+    pub label: Option<String>,
+    pub to: Option<String>,
+    pub api: bool,
+    pub repo: Option<String>,
+}
+
+pub struct GitHubTriageManager {
+    pub config: GitHubTriageConfig,
+    pub taxonomy: Vec<LabelDefinition>,
+    /// REST client in API mode; `None` goes through `gh api`
+    pub api: Option<GitHubApiClient>,
+    pub runner: Arc<dyn CommandRunner>,
+}
+
+impl GitHubTriageManager {
+    pub fn new(config: GitHubTriageConfig, taxonomy: Vec<LabelDefinition>) -> Self {
+        Self {
+            config,
+            taxonomy,
+            api: None,
+            runner: Arc::new(SystemRunner),
+        }
+    }
+
+    pub fn with_api(mut self, client: GitHubApiClient) -> Self {
+        self.api = Some(client);
+        self
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        ensure_not_sandboxed("github-triage")?;
+        match &self.api {
+            Some(api) => {
+                print_human(format!("🌐 Using the GitHub REST API for {}", api.repo_slug()).blue())
+            }
+            None => {
+                ensure_gh_ready(
+                    self.config.skip_install,
+                    self.config.skip_auth,
+                    self.config.dry_run,
+                )
+                .await?
+            }
+        }
+
+        let items = self.fetch_open_items().await?;
+        let report = TriageReport::build(
+            &items,
+            &priority_labels(&self.taxonomy),
+            self.config.stale_days,
+            Utc::now(),
+        );
+
+        if let (Some(label), Some(to)) = (&self.config.label, &self.config.to) {
+            let numbers = report.target(to)?;
+            return self.apply_label(label, &numbers).await;
+        }
+        if is_json_output() {
+            return print_json(&report);
+        }
+        self.print_dashboard(&report);
+        Ok(())
+    }
+
+    /// `gh api` endpoint; `{owner}/{repo}` is filled in by gh from the current repo.
+    fn endpoint(&self, path: &str) -> String {
+        let repo = self.config.repo.as_deref().unwrap_or("{owner}/{repo}");
+        format!("repos/{}/{}", repo, path)
+    }
+
+    pub async fn fetch_open_items(&self) -> Result<Vec<TriageItem>> {
+        if let Some(api) = &self.api {
+            return api.list_open_issues().await;
+        }
+        let endpoint = self.endpoint("issues?state=open&per_page=100");
+        let output = self
+            .runner
+            .run("gh", &["api", &endpoint, "--method", "GET"], None)?
+            .check(&format!("gh api GET {}", endpoint))?;
+        parse_issue_list_json(&output.stdout)
+    }
+
+    async fn add_label(&self, number: u64, label: &str) -> Result<()> {
+        if let Some(api) = &self.api {
+            return api.add_labels(number, &[label.to_string()]).await;
+        }
+        let endpoint = self.endpoint(&format!("issues/{}/labels", number));
+        let field = format!("labels[]={}", label);
+        self.runner
+            .run(
+                "gh",
+                &["api", &endpoint, "--method", "POST", "-f", &field],
+                None,
+            )?
+            .check(&format!("gh api POST {}", endpoint))?;
+        Ok(())
+    }
+
+    /// Adds `label` to every issue or pull request in `numbers`. The label
+    /// has to be part of the taxonomy, so typos don't create new labels.
+    pub async fn apply_label(&self, label: &str, numbers: &[u64]) -> Result<()> {
+        let label = self
+            .taxonomy
+            .iter()
+            .find(|definition| definition.name.eq_ignore_ascii_case(label))
+            .map(|definition| definition.name.clone())
+            .ok_or_else(|| {
+                anyhow!(
+                    "'{}' is not in the label set, run `nitroterm github-labels` or pick one of: {}",
+                    label,
+                    self.taxonomy
+                        .iter()
+                        .map(|definition| definition.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })?;
+        if numbers.is_empty() {
+            print_human("✅ Nothing to label".green());
+            return Ok(());
+        }
+        if self.config.dry_run {
+            print_human(
+                format!(
+                    "🔍 DRY RUN: Would add '{}' to {} item(s): {}",
+                    label,
+                    numbers.len(),
+                    format_numbers(numbers)
+                )
+                .yellow(),
+            );
+            return Ok(());
+        }
+
+        let mut failed = 0;
+        for number in numbers {
+            match self.add_label(*number, &label).await {
+                Ok(()) => print_human(format!("  ✅ #{} ← {}", number, label)),
+                Err(e) => {
+                    failed += 1;
+                    print_human(format!("  ⚠️  #{}: {}", number, e).yellow());
+                }
+            }
+        }
+        if failed > 0 {
+            return Err(anyhow!(
+                "{} of {} label changes failed",
+                failed,
+                numbers.len()
+            ));
+        }
+        print_human(
+            format!("🏷️  Added '{}' to {} item(s)", label, numbers.len())
+                .green()
+                .bold(),
+        );
+        Ok(())
+    }
+
+    pub fn print_dashboard(&self, report: &TriageReport) {
+        println!("{}", "🩺 GitHub Triage".cyan().bold());
+        println!("{}", "═".repeat(35).dimmed());
+        println!(
+            "{} open issue(s), {} open pull request(s)",
+            report.open_issues.to_string().bold(),
+            report.open_prs.to_string().bold()
+        );
+
+        print_group("🏷️  Unlabeled issues", &report.unlabeled);
+        if priority_labels(&self.taxonomy).is_empty() {
+            println!(
+                "\n{}",
+                "💡 The label set has no priority labels, skipping the priority check".dimmed()
+            );
+        } else {
+            print_group("❗ Issues without a priority", &report.missing_priority);
+        }
+        print_group(
+            &format!("⏳ Pull requests idle for {}+ days", report.stale_days),
+            &report.stale_prs,
+        );
+
+        if report.unlabeled.is_empty()
+            && report.missing_priority.is_empty()
+            && report.stale_prs.is_empty()
+        {
+            println!("\n{}", "✅ Nothing needs triage".green());
+            return;
+        }
+
+        println!("\n{}", "Quick actions:".yellow().bold());
+        // A middle priority as the example, stale PRs rarely need a priority
+        let priorities = priority_labels(&self.taxonomy);
+        let priority = priorities
+            .get(priorities.len() / 2)
+            .cloned()
+            .unwrap_or_else(|| "LABEL".to_string());
+        let groups = [
+            ("unlabeled", &report.unlabeled, priority.as_str()),
+            (
+                "missing-priority",
+                &report.missing_priority,
+                priority.as_str(),
+            ),
+            ("stale", &report.stale_prs, "LABEL"),
+        ];
+        for (target, items, label) in groups {
+            if !items.is_empty() {
+                println!(
+                    "  nitroterm github-triage --label '{}' --to {}",
+                    label, target
+                );
+            }
+        }
+    }
+}
+
+fn format_numbers(numbers: &[u64]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("#{}", number))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_group(title: &str, items: &[TriageItem]) {
+    println!("\n{} ({})", title.yellow().bold(), items.len());
+    if items.is_empty() {
+        println!("  {}", "none".dimmed());
+        return;
+    }
+    let now = Utc::now();
+    for item in items.iter().take(20) {
+        let idle = (now - item.updated_at).num_days();
+        println!(
+            "  #{:<5} {:<56} {}",
+            item.number,
+            item.title.chars().take(56).collect::<String>(),
+            format!(
+                "@{} · {}d idle{}",
+                item.author,
+                idle,
+                if item.draft { " · draft" } else { "" }
+            )
+            .dimmed()
+        );
+    }
+    if items.len() > 20 {
+        println!("  {}", format!("… and {} more", items.len() - 20).dimmed());
+    }
+}
+
+/// Loads the label taxonomy like `github-labels` does and picks the backend
+/// the same way.
+pub async fn run_github_triage(mut config: GitHubTriageConfig) -> Result<()> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let api = config.api || app_config.github_backend == "api";
+    if config.labels_file.is_none() {
+        config.labels_file = app_config.labels_file;
+    }
+
+    let taxonomy = match &config.labels_file {
+        Some(file) => load_labels_file(Path::new(file))?,
+        None => GitHubLabelsManager::new(GitHubLabelsConfig::default()).desired_labels(),
+    };
+
+    let mut manager = GitHubTriageManager::new(config, taxonomy);
+    if api {
+        match GitHubApiClient::from_env(manager.config.repo.as_deref()) {
+            Ok(client) => manager = manager.with_api(client),
+            Err(e) => print_human(format!("⚠️  {}; falling back to the gh CLI", e).yellow()),
+        }
+    }
+    manager.run().await
+}
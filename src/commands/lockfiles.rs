@@ -0,0 +1,478 @@
+use crate::commands::dependency_update::update_rules;
+use crate::commands::release_risk::parse_manifest_dependencies;
+use crate::commands::state::{outdated_keys, record_run, Tracked};
+use crate::utils::sandbox::ensure_network_allowed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    PyPI,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    pub ecosystem: Ecosystem,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutdatedPackage {
+    pub name: String,
+    pub current: String,
+    pub latest: String,
+    pub ecosystem: Ecosystem,
+}
+
+impl Ecosystem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Ecosystem::Cargo => "crates.io",
+            Ecosystem::Npm => "npm",
+            Ecosystem::PyPI => "PyPI",
+        }
+    }
+
+    /// Key of the ecosystem in the `dependency_update` rules.
+    pub fn rule_key(&self) -> &'static str {
+        match self {
+            Ecosystem::Cargo => "rust",
+            Ecosystem::Npm => "node",
+            Ecosystem::PyPI => "python",
+        }
+    }
+}
+
+fn locked(name: &str, version: &str, ecosystem: Ecosystem) -> LockedPackage {
+    LockedPackage {
+        name: name.to_string(),
+        version: version.to_string(),
+        ecosystem,
+    }
+}
+
+/// Reads `name = "..."` / `version = "..."` pairs from `[[package]]` tables,
+/// the layout shared by Cargo.lock and poetry.lock.
+fn parse_toml_packages(
+    content: &str,
+    ecosystem: Ecosystem,
+    registry_only: bool,
+) -> Vec<LockedPackage> {
+    let mut packages = Vec::new();
+    let mut name = None;
+    let mut version = None;
+    let mut from_registry = !registry_only;
+
+    let mut flush =
+        |name: &mut Option<String>, version: &mut Option<String>, from_registry: bool| {
+            if let (Some(n), Some(v)) = (name.take(), version.take()) {
+                if from_registry {
+                    packages.push(locked(&n, &v, ecosystem));
+                }
+            }
+        };
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            flush(&mut name, &mut version, from_registry);
+            from_registry = !registry_only;
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().trim_matches('"').to_string();
+            match key.trim() {
+                "name" => name = Some(value),
+                "version" => version = Some(value),
+                "source" => {
+                    from_registry = value.starts_with("registry+") || value.starts_with("sparse+")
+                }
+                _ => {}
+            }
+        }
+    }
+    flush(&mut name, &mut version, from_registry);
+
+    packages
+}
+
+pub fn parse_cargo_lock(content: &str) -> Vec<LockedPackage> {
+    // Workspace members and git/path dependencies have no registry source
+    parse_toml_packages(content, Ecosystem::Cargo, true)
+}
+
+pub fn parse_poetry_lock(content: &str) -> Vec<LockedPackage> {
+    parse_toml_packages(content, Ecosystem::PyPI, false)
+}
+
+pub fn parse_package_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    let json: Value = serde_json::from_str(content)?;
+    let mut packages = Vec::new();
+
+    // lockfileVersion 2/3
+    if let Some(entries) = json["packages"].as_object() {
+        for (path, entry) in entries {
+            let name = match path.rsplit_once("node_modules/") {
+                Some((_, name)) => name,
+                None => continue,
+            };
+            if let Some(version) = entry["version"].as_str() {
+                packages.push(locked(name, version, Ecosystem::Npm));
+            }
+        }
+        return Ok(packages);
+    }
+
+    // lockfileVersion 1
+    if let Some(entries) = json["dependencies"].as_object() {
+        for (name, entry) in entries {
+            if let Some(version) = entry["version"].as_str() {
+                packages.push(locked(name, version, Ecosystem::Npm));
+            }
+        }
+    }
+
+    Ok(packages)
+}
+
+/// Splits `name@version` keys, keeping the leading `@` of scoped packages.
+fn split_name_version(spec: &str) -> Option<(&str, &str)> {
+    if spec.is_empty() {
+        return None;
+    }
+    let index = spec[1..].find('@')? + 1;
+    Some((&spec[..index], &spec[index + 1..]))
+}
+
+pub fn parse_pnpm_lock(content: &str) -> Vec<LockedPackage> {
+    let mut packages = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            in_packages = line.trim_end() == "packages:";
+            continue;
+        }
+        // Only the package keys, which are indented by exactly two spaces
+        if !in_packages || line.starts_with("   ") || !line.trim_end().ends_with(':') {
+            continue;
+        }
+
+        let key = line.trim().trim_end_matches(':').trim_matches('\'');
+        let key = key.trim_start_matches('/');
+        // Drop peer dependency suffixes like `(react@18.2.0)`
+        let key = key.split('(').next().unwrap_or(key);
+
+        // v5 uses `name/1.2.3`, v6+ uses `name@1.2.3`
+        let parsed = split_name_version(key).or_else(|| key.rsplit_once('/'));
+        if let Some((name, version)) = parsed {
+            packages.push(locked(name, version, Ecosystem::Npm));
+        }
+    }
+
+    packages
+}
+
+pub fn parse_yarn_lock(content: &str) -> Vec<LockedPackage> {
+    let mut packages = Vec::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            // `"@scope/pkg@^1.0.0", "@scope/pkg@npm:^1.1.0":`
+            let first = line.trim_end_matches(':').split(',').next().unwrap_or("");
+            let first = first.trim().trim_matches('"');
+            current = split_name_version(first).map(|(name, _)| name.to_string());
+            continue;
+        }
+
+        let trimmed = line.trim();
+        // Classic: `version "1.2.3"`, Berry: `version: 1.2.3`
+        if let Some(version) = trimmed
+            .strip_prefix("version ")
+            .or_else(|| trimmed.strip_prefix("version: "))
+        {
+            if let Some(name) = current.take() {
+                packages.push(locked(
+                    &name,
+                    version.trim().trim_matches('"'),
+                    Ecosystem::Npm,
+                ));
+            }
+        }
+    }
+
+    packages
+}
+
+/// Collects packages from every lockfile found in `dir`.
+pub fn read_locked_packages(dir: &Path) -> Result<Vec<LockedPackage>> {
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+    let mut packages = Vec::new();
+
+    if let Some(content) = read("Cargo.lock") {
+        packages.extend(parse_cargo_lock(&content));
+    }
+    if let Some(content) = read("package-lock.json") {
+        packages.extend(parse_package_lock(&content)?);
+    } else if let Some(content) = read("pnpm-lock.yaml") {
+        packages.extend(parse_pnpm_lock(&content));
+    } else if let Some(content) = read("yarn.lock") {
+        packages.extend(parse_yarn_lock(&content));
+    }
+    if let Some(content) = read("poetry.lock") {
+        packages.extend(parse_poetry_lock(&content));
+    }
+
+    Ok(packages)
+}
+
+/// Names declared directly in the manifests. Lockfiles also list transitive
+/// packages, which we don't want to query one by one.
+pub fn direct_dependency_names(dir: &Path) -> HashSet<(Ecosystem, String)> {
+    let mut names = HashSet::new();
+    let manifests = [
+        ("Cargo.toml", Ecosystem::Cargo),
+        ("package.json", Ecosystem::Npm),
+        ("pyproject.toml", Ecosystem::PyPI),
+    ];
+
+    for (manifest, ecosystem) in manifests {
+        if let Ok(content) = std::fs::read_to_string(dir.join(manifest)) {
+            // Poetry dependency tables use the same `name = "version"` layout as Cargo
+            let kind = if manifest == "package.json" {
+                "package.json"
+            } else {
+                "Cargo.toml"
+            };
+            for (name, _) in parse_manifest_dependencies(kind, &content) {
+                names.insert((ecosystem, name.to_lowercase()));
+            }
+        }
+    }
+
+    names
+}
+
+/// Locked versions of the dependencies declared in the manifests, once per
+/// name and version.
+pub fn direct_locked_packages(dir: &Path) -> Result<Vec<LockedPackage>> {
+    let mut packages = read_locked_packages(dir)?;
+
+    // Ecosystems without a readable manifest keep every locked package
+    let direct = direct_dependency_names(dir);
+    packages.retain(|p| {
+        !direct
+            .iter()
+            .any(|(ecosystem, _)| *ecosystem == p.ecosystem)
+            || direct.contains(&(p.ecosystem, p.name.to_lowercase()))
+    });
+
+    let mut seen = HashSet::new();
+    packages.retain(|p| seen.insert((p.ecosystem, p.name.clone(), p.version.clone())));
+
+    Ok(packages)
+}
+
+pub fn is_outdated(current: &str, latest: &str) -> bool {
+    match (
+        semver::Version::parse(current),
+        semver::Version::parse(latest),
+    ) {
+        (Ok(current), Ok(latest)) => latest > current,
+        _ => current != latest,
+    }
+}
+
+pub struct RegistryClient {
+    client: reqwest::Client,
+    pub crates_url: String,
+    /// crates.io sparse index, lists every published version
+    pub index_url: String,
+    pub npm_url: String,
+    pub pypi_url: String,
+}
+
+impl RegistryClient {
+    pub fn new() -> Result<Self> {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent(concat!("nitroterm/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            client,
+            crates_url: "https://crates.io/api/v1/crates".to_string(),
+            index_url: "https://index.crates.io".to_string(),
+            npm_url: "https://registry.npmjs.org".to_string(),
+            pypi_url: "https://pypi.org/pypi".to_string(),
+        })
+    }
+
+    pub async fn get_json(&self, url: &str) -> Result<Value> {
+        let response = self.client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("{} returned {}", url, response.status()));
+        }
+        Ok(response.json().await?)
+    }
+
+    pub async fn latest_version(&self, package: &LockedPackage) -> Result<String> {
+        let (url, pointer) = match package.ecosystem {
+            Ecosystem::Cargo => (
+                format!("{}/{}", self.crates_url, package.name),
+                "/crate/max_stable_version",
+            ),
+            Ecosystem::Npm => (
+                format!(
+                    "{}/{}/latest",
+                    self.npm_url,
+                    package.name.replace('/', "%2F")
+                ),
+                "/version",
+            ),
+            Ecosystem::PyPI => (
+                format!("{}/{}/json", self.pypi_url, package.name),
+                "/info/version",
+            ),
+        };
+
+        let json = self.get_json(&url).await?;
+        json.pointer(pointer)
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| anyhow!("No version in registry response for {}", package.name))
+    }
+
+    /// Unyanked versions of a crate from the sparse index.
+    pub async fn index_versions(&self, name: &str) -> Result<Vec<semver::Version>> {
+        let url = format!("{}/{}", self.index_url, sparse_index_path(name));
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("{} returned {}", url, response.status()));
+        }
+        Ok(parse_index_versions(&response.text().await?))
+    }
+}
+
+/// Location of a crate in the sparse index: `1/a`, `2/ab`, `3/a/abc`,
+/// `se/rd/serde`.
+pub fn sparse_index_path(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+/// Index files have one JSON record per published version.
+pub fn parse_index_versions(content: &str) -> Vec<semver::Version> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|record| !record["yanked"].as_bool().unwrap_or(false))
+        .filter_map(|record| semver::Version::parse(record["vers"].as_str()?).ok())
+        .collect()
+}
+
+/// The outdated ones among `packages`, and how many couldn't be looked up.
+pub async fn check_outdated(
+    registry: &RegistryClient,
+    packages: Vec<LockedPackage>,
+) -> (Vec<OutdatedPackage>, usize) {
+    let mut outdated = Vec::new();
+    let mut failed = 0;
+
+    for package in packages {
+        match registry.latest_version(&package).await {
+            Ok(latest) if is_outdated(&package.version, &latest) => {
+                outdated.push(OutdatedPackage {
+                    name: package.name,
+                    current: package.version,
+                    latest,
+                    ecosystem: package.ecosystem,
+                });
+            }
+            Ok(_) => {}
+            Err(e) => {
+                failed += 1;
+                log_warning(&format!("Could not check {}: {}", package.name, e));
+            }
+        }
+    }
+
+    (outdated, failed)
+}
+
+/// Builds an outdated report from lockfiles and registry APIs, without
+/// needing cargo/npm/pip to be installed. Fails when no package could be
+/// looked up, an empty report would read as "all up to date".
+pub async fn native_outdated_report(dir: &Path) -> Result<Vec<OutdatedPackage>> {
+    let packages = direct_locked_packages(dir)?;
+    if packages.is_empty() {
+        return Err(anyhow!("No supported lockfile found in {}", dir.display()));
+    }
+
+    let total = packages.len();
+    let (outdated, failed) = check_outdated(&RegistryClient::new()?, packages).await;
+    if failed == total {
+        return Err(anyhow!(
+            "None of the {} package(s) could be checked, are the registries reachable?",
+            total
+        ));
+    }
+    if failed > 0 {
+        log_warning(&format!("{} package(s) could not be checked", failed));
+    }
+
+    Ok(outdated)
+}
+
+pub async fn run_native_outdated(dir: &Path) -> Result<()> {
+    log_info("Reading lockfiles and querying registries...");
+    let mut outdated = native_outdated_report(dir).await?;
+    let rules = update_rules();
+    outdated.retain(|package| {
+        rules.allows(package.ecosystem.rule_key(), &package.name, &package.latest)
+    });
+    record_run(dir, Tracked::Dependencies, |record| {
+        record.outdated = outdated_keys(&outdated)
+    });
+
+    if outdated.is_empty() {
+        log_success("All locked dependencies are up to date!");
+        return Ok(());
+    }
+
+    println!("{}", "📊 Outdated packages:".yellow().bold());
+    for package in &outdated {
+        println!(
+            "  {:<30} {:>12} → {:<12} {}",
+            package.name.green(),
+            package.current,
+            package.latest.cyan(),
+            format!("({})", package.ecosystem.name()).dimmed()
+        );
+    }
+
+    Ok(())
+}
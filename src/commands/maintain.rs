@@ -0,0 +1,482 @@
+use crate::commands::code_quality::{CodeQualityConfig, CodeQualityManager};
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::lockfiles::native_outdated_report;
+use crate::commands::release_risk::RiskLevel;
+use crate::commands::report::{collect_git, ProjectReport};
+use crate::commands::translation_sync::get_sync_statistics;
+use crate::commands::verify_deps::{verify_dependencies, VerifyStatus};
+use crate::utils::git::{default_branch, list_branches};
+use crate::utils::notify::{send_notification, webhook_urls};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+use std::path::Path;
+
+pub const DEFAULT_STALE_DAYS: i64 = 90;
+
+/// Most entries listed per task in the summary.
+const MAX_DETAILS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceTask {
+    Dependencies,
+    Branches,
+    Translations,
+    Quality,
+    Health,
+}
+
+impl MaintenanceTask {
+    pub const ALL: [MaintenanceTask; 5] = [
+        MaintenanceTask::Dependencies,
+        MaintenanceTask::Branches,
+        MaintenanceTask::Translations,
+        MaintenanceTask::Quality,
+        MaintenanceTask::Health,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaintenanceTask::Dependencies => "deps",
+            MaintenanceTask::Branches => "branches",
+            MaintenanceTask::Translations => "translations",
+            MaintenanceTask::Quality => "quality",
+            MaintenanceTask::Health => "health",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            MaintenanceTask::Dependencies => "Dependency audit",
+            MaintenanceTask::Branches => "Stale branches",
+            MaintenanceTask::Translations => "Translation status",
+            MaintenanceTask::Quality => "Quality checks",
+            MaintenanceTask::Health => "Repository health",
+        }
+    }
+}
+
+impl std::str::FromStr for MaintenanceTask {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        MaintenanceTask::ALL
+            .into_iter()
+            .find(|task| task.name() == value)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unknown maintenance task '{}', use deps, branches, translations, quality or health",
+                    value
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskStatus {
+    Ok,
+    Warning,
+    Failed,
+    Skipped,
+}
+
+impl TaskStatus {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            TaskStatus::Ok => "✅",
+            TaskStatus::Warning => "⚠️",
+            TaskStatus::Failed => "❌",
+            TaskStatus::Skipped => "⏭️",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskSummary {
+    pub task: MaintenanceTask,
+    pub status: TaskStatus,
+    pub headline: String,
+    pub details: Vec<String>,
+}
+
+impl TaskSummary {
+    fn new(task: MaintenanceTask, status: TaskStatus, headline: impl Into<String>) -> Self {
+        Self {
+            task,
+            status,
+            headline: headline.into(),
+            details: Vec::new(),
+        }
+    }
+
+    fn with_details(mut self, details: Vec<String>) -> Self {
+        let total = details.len();
+        self.details = details.into_iter().take(MAX_DETAILS).collect();
+        if total > MAX_DETAILS {
+            self.details
+                .push(format!("… and {} more", total - MAX_DETAILS));
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MaintainConfig {
+    pub path: Option<String>,
+    /// Run only these tasks (all when empty)
+    pub only: Vec<MaintenanceTask>,
+    pub skip: Vec<MaintenanceTask>,
+    /// Branches without commits for this many days count as stale
+    pub stale_days: i64,
+    /// Post the summary to the configured webhooks
+    pub notify: bool,
+}
+
+impl Default for MaintainConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            only: Vec::new(),
+            skip: Vec::new(),
+            stale_days: DEFAULT_STALE_DAYS,
+            notify: true,
+        }
+    }
+}
+
+/// Tasks to run, in the fixed order of `MaintenanceTask::ALL`.
+pub fn selected_tasks(only: &[MaintenanceTask], skip: &[MaintenanceTask]) -> Vec<MaintenanceTask> {
+    MaintenanceTask::ALL
+        .into_iter()
+        .filter(|task| only.is_empty() || only.contains(task))
+        .filter(|task| !skip.contains(task))
+        .collect()
+}
+
+async fn audit_dependencies(root: &Path) -> TaskSummary {
+    let task = MaintenanceTask::Dependencies;
+    let outdated = match native_outdated_report(root).await {
+        Ok(outdated) => outdated,
+        Err(e) => return TaskSummary::new(task, TaskStatus::Skipped, e.to_string()),
+    };
+
+    let mut details: Vec<String> = Vec::new();
+    let mut status = TaskStatus::Ok;
+    // Checksums can only be verified for lockfiles that pin them
+    let mismatches = match verify_dependencies(root).await {
+        Ok(results) => results
+            .into_iter()
+            .filter_map(|result| match result.status {
+                VerifyStatus::Mismatch(reason) => Some(format!(
+                    "checksum mismatch: {} {} ({})",
+                    result.package.name, result.package.version, reason
+                )),
+                _ => None,
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    if !mismatches.is_empty() {
+        status = TaskStatus::Failed;
+    } else if !outdated.is_empty() {
+        status = TaskStatus::Warning;
+    }
+    details.extend(mismatches.iter().cloned());
+    details.extend(outdated.iter().map(|package| {
+        format!(
+            "{} {} → {} ({})",
+            package.name,
+            package.current,
+            package.latest,
+            package.ecosystem.name()
+        )
+    }));
+
+    let headline = format!(
+        "{} outdated, {} checksum mismatches",
+        outdated.len(),
+        mismatches.len()
+    );
+    TaskSummary::new(task, status, headline).with_details(details)
+}
+
+fn report_branches(root: &Path, stale_days: i64) -> TaskSummary {
+    let task = MaintenanceTask::Branches;
+    let repo = match Repository::discover(root) {
+        Ok(repo) => repo,
+        Err(_) => return TaskSummary::new(task, TaskStatus::Skipped, "not a git repository"),
+    };
+    let Some(default) = default_branch(&repo) else {
+        return TaskSummary::new(task, TaskStatus::Skipped, "no default branch found");
+    };
+    let branches = match list_branches(&repo, &default) {
+        Ok(branches) => branches,
+        Err(e) => return TaskSummary::new(task, TaskStatus::Failed, e.to_string()),
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let stale: Vec<String> = branches
+        .iter()
+        .filter(|branch| branch.is_stale(now, stale_days))
+        .map(|branch| {
+            if branch.merged {
+                format!("{} (merged into {})", branch.name, default)
+            } else {
+                format!(
+                    "{} ({} days without commits)",
+                    branch.name,
+                    branch.age_days(now)
+                )
+            }
+        })
+        .collect();
+
+    let status = if stale.is_empty() {
+        TaskStatus::Ok
+    } else {
+        TaskStatus::Warning
+    };
+    let headline = format!("{} of {} branches stale", stale.len(), branches.len());
+    TaskSummary::new(task, status, headline).with_details(stale)
+}
+
+fn translation_status(root: &Path, app_config: &AppConfig) -> TaskSummary {
+    let task = MaintenanceTask::Translations;
+    let messages_dir = root.join(&app_config.messages_dir);
+    if !messages_dir.join(&app_config.source_file).exists() {
+        return TaskSummary::new(task, TaskStatus::Skipped, "no translation source file");
+    }
+    let stats = match get_sync_statistics(&messages_dir, &app_config.source_file) {
+        Ok(stats) => stats,
+        Err(e) => return TaskSummary::new(task, TaskStatus::Failed, e.to_string()),
+    };
+
+    let incomplete: Vec<String> = stats
+        .languages
+        .iter()
+        .filter(|language| !language.progress.is_complete())
+        .map(|language| {
+            format!(
+                "{}: {:.1}% ({} missing)",
+                language.code,
+                language.progress.percentage(),
+                language.progress.missing()
+            )
+        })
+        .collect();
+
+    let status = if incomplete.is_empty() {
+        TaskStatus::Ok
+    } else {
+        TaskStatus::Warning
+    };
+    let headline = format!(
+        "{}/{} languages complete, {:.1}% overall",
+        stats.complete_languages(),
+        stats.languages.len(),
+        stats.overall_percentage()
+    );
+    TaskSummary::new(task, status, headline).with_details(incomplete)
+}
+
+async fn quality_checks(root: &Path) -> TaskSummary {
+    let task = MaintenanceTask::Quality;
+    if let Ok(repo) = Repository::discover(root) {
+        let current = repo
+            .head()
+            .ok()
+            .and_then(|head| head.shorthand().map(|name| name.to_string()));
+        if let (Some(current), Some(default)) = (current, default_branch(&repo)) {
+            if current != default {
+                return TaskSummary::new(
+                    task,
+                    TaskStatus::Skipped,
+                    format!("on '{}', quality checks only run on '{}'", current, default),
+                );
+            }
+        }
+    }
+
+    let manager = CodeQualityManager::new(CodeQualityConfig::default());
+    let results = match manager.run_quality_checks(root).await {
+        Ok(results) => results,
+        Err(e) => return TaskSummary::new(task, TaskStatus::Failed, e.to_string()),
+    };
+    let failed: Vec<String> = results
+        .iter()
+        .filter(|result| !result.success)
+        .map(|result| format!("{} failed ({})", result.check_name, result.command))
+        .collect();
+
+    let status = if failed.is_empty() {
+        TaskStatus::Ok
+    } else {
+        TaskStatus::Failed
+    };
+    let headline = format!(
+        "{}/{} checks passed",
+        results.len() - failed.len(),
+        results.len()
+    );
+    TaskSummary::new(task, status, headline).with_details(failed)
+}
+
+fn repository_health(root: &Path) -> TaskSummary {
+    let task = MaintenanceTask::Health;
+    let mut report = ProjectReport::default();
+    if let Err(e) = collect_git(root, &mut report) {
+        return TaskSummary::new(task, TaskStatus::Failed, e.to_string());
+    }
+
+    let problems: Vec<String> = report
+        .health
+        .iter()
+        .filter(|(label, value)| {
+            value == "missing"
+                || value == "not found"
+                || (label == "Working tree" && value != "clean")
+        })
+        .map(|(label, value)| format!("{}: {}", label, value))
+        .collect();
+    let high_risk = report
+        .risk
+        .as_ref()
+        .is_some_and(|risk| risk.level == RiskLevel::High);
+
+    let status = if !problems.is_empty() || high_risk {
+        TaskStatus::Warning
+    } else {
+        TaskStatus::Ok
+    };
+    let mut headline = format!(
+        "latest release {}, {} unreleased commits",
+        report.last_tag.as_deref().unwrap_or("none"),
+        report.unreleased.len()
+    );
+    if let Some(risk) = &report.risk {
+        let level = match risk.level {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        };
+        headline.push_str(&format!(", {} release risk", level));
+    }
+    TaskSummary::new(task, status, headline).with_details(problems)
+}
+
+/// Markdown summary posted to the notifiers: one line per task, with its
+/// findings indented below.
+pub fn render_summary(summaries: &[TaskSummary]) -> String {
+    let mut body = String::new();
+    for summary in summaries {
+        body.push_str(&format!(
+            "{} {}: {}\n",
+            summary.status.icon(),
+            summary.task.title(),
+            summary.headline
+        ));
+        for detail in &summary.details {
+            body.push_str(&format!("  • {}\n", detail));
+        }
+    }
+    body
+}
+
+/// Notification title with the overall result.
+pub fn summary_title(project: &str, summaries: &[TaskSummary]) -> String {
+    let count = |status: TaskStatus| summaries.iter().filter(|s| s.status == status).count();
+    let result = match (count(TaskStatus::Failed), count(TaskStatus::Warning)) {
+        (0, 0) => "all good".to_string(),
+        (0, warnings) => format!("{} warning(s)", warnings),
+        (failed, _) => format!("{} failed", failed),
+    };
+    format!("🛠️ Maintenance for {}: {}", project, result)
+}
+
+fn print_summary(summary: &TaskSummary) {
+    let headline = match summary.status {
+        TaskStatus::Ok => summary.headline.green(),
+        TaskStatus::Warning => summary.headline.yellow(),
+        TaskStatus::Failed => summary.headline.red(),
+        TaskStatus::Skipped => summary.headline.dimmed(),
+    };
+    println!(
+        "{} {} {}",
+        summary.status.icon(),
+        format!("{}:", summary.task.title()).bold(),
+        headline
+    );
+    for detail in &summary.details {
+        println!("   {} {}", "•".dimmed(), detail);
+    }
+}
+
+pub async fn run_maintain(config: MaintainConfig) -> Result<()> {
+    ensure_not_sandboxed("maintain")?;
+    let root = Path::new(config.path.as_deref().unwrap_or("."));
+    let project = root
+        .canonicalize()?
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".to_string());
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+
+    let tasks = selected_tasks(&config.only, &config.skip);
+    if tasks.is_empty() {
+        return Err(anyhow!("All maintenance tasks are skipped"));
+    }
+
+    println!(
+        "{}",
+        format!("🛠️  Running maintenance for {}...", project)
+            .cyan()
+            .bold()
+    );
+    let mut summaries = Vec::new();
+    for task in tasks {
+        println!("{}", format!("▶ {}...", task.title()).blue());
+        let summary = match task {
+            MaintenanceTask::Dependencies => audit_dependencies(root).await,
+            MaintenanceTask::Branches => report_branches(root, config.stale_days),
+            MaintenanceTask::Translations => translation_status(root, &app_config),
+            MaintenanceTask::Quality => quality_checks(root).await,
+            MaintenanceTask::Health => repository_health(root),
+        };
+        print_summary(&summary);
+        summaries.push(summary);
+    }
+
+    let title = summary_title(&project, &summaries);
+    println!();
+    println!("{}", title.bold());
+
+    let webhooks = webhook_urls(&app_config.notify_webhooks);
+    if config.notify && !webhooks.is_empty() {
+        match send_notification(&webhooks, &title, &render_summary(&summaries)).await {
+            Ok(()) => println!(
+                "{}",
+                format!("🔔 Summary sent to {} webhook(s)", webhooks.len()).green()
+            ),
+            Err(e) => println!("{}", format!("⚠️  {}", e).yellow()),
+        }
+    } else if config.notify {
+        println!(
+            "{}",
+            "💡 Add a webhook with `nitroterm config notify <URL>` to receive this summary"
+                .dimmed()
+        );
+    }
+
+    let failed = summaries
+        .iter()
+        .filter(|summary| summary.status == TaskStatus::Failed)
+        .count();
+    if failed > 0 {
+        return Err(anyhow!("{} maintenance task(s) failed", failed));
+    }
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::lockfiles::{direct_dependency_names, read_locked_packages};
+use crate::commands::report::{collect_report, ReportOptions};
+use crate::commands::serve::{
+    checks_json, read_cached_checks, release_notes_json, stats_json, translations_json,
+};
+use crate::commands::translation_sync::get_sync_statistics;
+use crate::commands::translation_validation::validate_translations;
+use crate::utils::file_system::path_within;
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Model Context Protocol server over stdio. Every tool only reads project
+/// state, so assistants can call them without asking the user first.
+pub struct McpServer {
+    root: PathBuf,
+    app_config: AppConfig,
+}
+
+impl McpServer {
+    pub fn new(root: PathBuf, app_config: AppConfig) -> Self {
+        Self { root, app_config }
+    }
+
+    pub fn tools() -> Value {
+        let translation_args = json!({
+            "type": "object",
+            "properties": {
+                "dir": { "type": "string", "description": "Messages directory, relative to the project" },
+                "source": { "type": "string", "description": "Source language file, e.g. en.json" }
+            }
+        });
+        let no_args = json!({ "type": "object", "properties": {} });
+
+        json!([
+            {
+                "name": "project_stats",
+                "description": "Project health, contributors, weekly activity, release risk and a summary of dependencies, checks and translations",
+                "inputSchema": no_args,
+            },
+            {
+                "name": "release_notes",
+                "description": "Commits since the latest tag, grouped by conventional commit type",
+                "inputSchema": no_args,
+            },
+            {
+                "name": "dependency_status",
+                "description": "Locked dependencies per ecosystem and which of them are direct",
+                "inputSchema": no_args,
+            },
+            {
+                "name": "quality_results",
+                "description": "Results of the last code-quality run (code-quality-report.json)",
+                "inputSchema": no_args,
+            },
+            {
+                "name": "translation_status",
+                "description": "Translation completeness per language, including missing keys",
+                "inputSchema": translation_args,
+            },
+            {
+                "name": "validate_translations",
+                "description": "Placeholder, HTML tag, empty and missing translation issues per language",
+                "inputSchema": translation_args,
+            },
+        ])
+    }
+
+    /// The messages directory and source file a tool call asks for. Both
+    /// have to be inside the project.
+    fn translation_paths(&self, arguments: &Value) -> Result<(PathBuf, String)> {
+        let dir = arguments["dir"]
+            .as_str()
+            .unwrap_or(&self.app_config.messages_dir);
+        let source = arguments["source"]
+            .as_str()
+            .unwrap_or(&self.app_config.source_file);
+        let dir = path_within(&self.root, dir)?;
+        path_within(&dir, source)?;
+        Ok((dir, source.to_string()))
+    }
+
+    pub async fn call_tool(&self, name: &str, arguments: &Value) -> Result<Value> {
+        match name {
+            "project_stats" => {
+                let report =
+                    collect_report(&self.root, &self.app_config, &ReportOptions::default()).await?;
+                Ok(stats_json(&report))
+            }
+            "release_notes" => {
+                let report =
+                    collect_report(&self.root, &self.app_config, &ReportOptions::default()).await?;
+                Ok(release_notes_json(&report))
+            }
+            "dependency_status" => dependency_status(&self.root),
+            "quality_results" => match read_cached_checks(&self.root)? {
+                Some(results) => Ok(checks_json(&results)),
+                None => Err(anyhow!(
+                    "No code quality results yet, run `nitroterm code-quality --format json` first"
+                )),
+            },
+            "translation_status" => {
+                let (dir, source) = self.translation_paths(arguments)?;
+                Ok(translations_json(&get_sync_statistics(&dir, &source)?))
+            }
+            "validate_translations" => {
+                let (dir, source) = self.translation_paths(arguments)?;
+                let report = validate_translations(&dir, &source)?;
+                let mut value = serde_json::to_value(&report)?;
+                value["error_count"] = json!(report.error_count());
+                value["warning_count"] = json!(report.warning_count());
+                Ok(value)
+            }
+            _ => Err(anyhow!("Unknown tool: {}", name)),
+        }
+    }
+
+    /// Handles one JSON-RPC message. Notifications get no response.
+    pub async fn handle_message(&self, message: &Value) -> Option<Value> {
+        let id = message.get("id").cloned()?;
+        let method = message["method"].as_str().unwrap_or_default();
+
+        let result = match method {
+            "initialize" => json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": { "name": "nitroterm", "version": env!("CARGO_PKG_VERSION") },
+            }),
+            "ping" => json!({}),
+            "tools/list" => json!({ "tools": Self::tools() }),
+            "tools/call" => {
+                let name = match message["params"]["name"].as_str() {
+                    Some(name) => name,
+                    None => return Some(error_response(id, INVALID_PARAMS, "Missing tool name")),
+                };
+                // Tool failures are reported to the model, not as protocol errors
+                match self.call_tool(name, &message["params"]["arguments"]).await {
+                    Ok(value) => json!({
+                        "content": [{
+                            "type": "text",
+                            "text": serde_json::to_string_pretty(&value).unwrap_or_default(),
+                        }],
+                        "isError": false,
+                    }),
+                    Err(e) => json!({
+                        "content": [{ "type": "text", "text": e.to_string() }],
+                        "isError": true,
+                    }),
+                }
+            }
+            _ => {
+                return Some(error_response(
+                    id,
+                    METHOD_NOT_FOUND,
+                    &format!("Method not found: {}", method),
+                ))
+            }
+        };
+
+        Some(json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+    }
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+pub fn dependency_status(root: &Path) -> Result<Value> {
+    let packages = read_locked_packages(root)?;
+    let direct = direct_dependency_names(root);
+
+    let mut ecosystems: BTreeMap<&str, usize> = BTreeMap::new();
+    let packages: Vec<Value> = packages
+        .iter()
+        .map(|package| {
+            *ecosystems.entry(package.ecosystem.name()).or_default() += 1;
+            json!({
+                "name": package.name,
+                "version": package.version,
+                "ecosystem": package.ecosystem.name(),
+                "direct": direct.contains(&(package.ecosystem, package.name.to_lowercase())),
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "total": packages.len(),
+        "direct": packages.iter().filter(|p| p["direct"] == true).count(),
+        "ecosystems": ecosystems,
+        "packages": packages,
+    }))
+}
+
+/// Serves newline-delimited JSON-RPC on stdin/stdout. Stdout carries only
+/// protocol messages; anything for humans goes to stderr.
+pub async fn run_mcp_server(path: Option<&str>) -> Result<()> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let root = Path::new(path.unwrap_or(".")).canonicalize()?;
+    eprintln!("nitroterm MCP server ready for {}", root.display());
+    let server = McpServer::new(root, app_config);
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let mut stdout = tokio::io::stdout();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => server.handle_message(&message).await,
+            Err(e) => Some(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                &format!("Parse error: {}", e),
+            )),
+        };
+        if let Some(response) = response {
+            stdout
+                .write_all(format!("{}\n", serde_json::to_string(&response)?).as_bytes())
+                .await?;
+            stdout.flush().await?;
+        }
+    }
+    Ok(())
+}
@@ -1,8 +1,63 @@
+pub mod audit;
+pub mod branches;
+pub mod cargo_workspace;
 pub mod code_quality;
+pub mod codeowners;
+pub mod commit_dedup;
+pub mod commit;
+pub mod commit_filter;
 pub mod config;
+pub mod contributors;
 pub mod create_release;
 pub mod dependency_update;
+pub mod diff_report;
+pub mod doctor;
+pub mod export;
+pub mod fork;
+pub mod gemini_models;
+pub mod git_identity;
+pub mod github_api;
 pub mod github_labels;
+pub mod github_milestones;
+pub mod github_templates;
+pub mod github_triage;
+pub mod lockfiles;
+pub mod maintain;
+pub mod mcp;
+pub mod new_project;
+pub mod notifications;
+pub mod onboarding;
+pub mod org;
+pub mod package_json;
+pub mod pipeline;
+pub mod policy;
+pub mod provenance;
+pub mod quality_history;
+pub mod release_hosting;
 pub mod release_notes;
+pub mod release_notes_summary;
+pub mod release_notes_template;
+pub mod release_notes_translation;
+pub mod release_preflight;
+pub mod release_risk;
+pub mod releases;
+pub mod report;
+pub mod retag;
+pub mod sbom;
+pub mod scaffold;
+pub mod secret_scan;
+pub mod selective_update;
+pub mod self_update;
+pub mod serve;
+pub mod smoke_test;
+pub mod state;
+pub mod translation_cache;
+pub mod translation_format;
+pub mod translation_glossary;
+pub mod translation_heatmap;
+pub mod translation_icu;
 pub mod translation_sync;
+pub mod translation_validation;
+pub mod verify_deps;
+pub mod verify_release;
 pub mod version_management;
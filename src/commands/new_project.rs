@@ -0,0 +1,266 @@
+use crate::commands::code_quality::{CodeQualityConfig, CodeQualityManager};
+use crate::commands::config::ConfigManager;
+use crate::commands::github_labels::{GitHubLabelsConfig, GitHubLabelsManager};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::{IndexAddOption, Repository, Signature};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct NewProjectConfig {
+    pub template: String,
+    pub name: String,
+    pub org: Option<String>,
+    pub license: String,
+    pub remote: Option<String>,
+    pub skip_labels: bool,
+    pub skip_quality: bool,
+}
+
+impl Default for NewProjectConfig {
+    fn default() -> Self {
+        Self {
+            template: String::new(),
+            name: String::new(),
+            org: None,
+            license: "MIT".to_string(),
+            remote: None,
+            skip_labels: false,
+            skip_quality: false,
+        }
+    }
+}
+
+/// Resolves a template name against the configured templates. Anything that
+/// is not a registered name is used as-is (git URL or local path).
+pub fn resolve_template(template: &str, templates: &HashMap<String, String>) -> String {
+    templates
+        .get(template)
+        .cloned()
+        .unwrap_or_else(|| template.to_string())
+}
+
+pub fn substitute_placeholders(content: &str, values: &[(&str, &str)]) -> String {
+    let mut result = content.to_string();
+    for (key, value) in values {
+        result = result.replace(&format!("{{{{{}}}}}", key), value);
+    }
+    result
+}
+
+/// Replaces placeholders in file contents and file names below `root`.
+/// Binary files are left untouched. Returns the number of files changed.
+pub fn apply_placeholders(root: &Path, values: &[(&str, &str)]) -> Result<usize> {
+    let mut changed = 0;
+
+    for entry in std::fs::read_dir(root)? {
+        let path = entry?.path();
+
+        if path.file_name().is_some_and(|name| name == ".git") {
+            continue;
+        }
+
+        let path = rename_with_placeholders(&path, values)?;
+
+        if path.is_dir() {
+            changed += apply_placeholders(&path, values)?;
+            continue;
+        }
+
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            let updated = substitute_placeholders(&content, values);
+            if updated != content {
+                std::fs::write(&path, updated)?;
+                changed += 1;
+            }
+        }
+    }
+
+    Ok(changed)
+}
+
+fn rename_with_placeholders(path: &Path, values: &[(&str, &str)]) -> Result<PathBuf> {
+    let file_name = match path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name,
+        None => return Ok(path.to_path_buf()),
+    };
+
+    let new_name = substitute_placeholders(file_name, values);
+    if new_name == file_name {
+        return Ok(path.to_path_buf());
+    }
+
+    let new_path = path.with_file_name(new_name);
+    std::fs::rename(path, &new_path)?;
+    Ok(new_path)
+}
+
+fn default_org() -> String {
+    git2::Config::open_default()
+        .ok()
+        .and_then(|config| {
+            config
+                .get_string("github.user")
+                .or_else(|_| config.get_string("user.name"))
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Clones the template into `target`, fills in placeholders and initializes
+/// a fresh git repository.
+pub fn scaffold_project(
+    config: &NewProjectConfig,
+    template_url: &str,
+    target: &Path,
+) -> Result<()> {
+    if target.exists() {
+        return Err(anyhow!("Directory '{}' already exists", target.display()));
+    }
+
+    println!(
+        "{}",
+        format!("📥 Cloning template from {}...", template_url).yellow()
+    );
+    Repository::clone(template_url, target)
+        .map_err(|e| anyhow!("Failed to clone template '{}': {}", template_url, e))?;
+
+    // Template history is not part of the new project
+    std::fs::remove_dir_all(target.join(".git"))?;
+
+    let org = config.org.clone().unwrap_or_else(default_org);
+    let values = [
+        ("name", config.name.as_str()),
+        ("org", org.as_str()),
+        ("license", config.license.as_str()),
+    ];
+    let changed = apply_placeholders(target, &values)?;
+    println!(
+        "{}",
+        format!("✏️  Filled in placeholders in {} files", changed).green()
+    );
+
+    let repo = Repository::init(target)?;
+    install_hooks(&repo, target)?;
+
+    if let Some(remote) = &config.remote {
+        repo.remote("origin", remote)?;
+        println!("{}", format!("🔗 Added remote origin: {}", remote).green());
+    }
+
+    create_initial_commit(&repo)?;
+
+    Ok(())
+}
+
+fn install_hooks(repo: &Repository, root: &Path) -> Result<()> {
+    if !root.join(".githooks").is_dir() {
+        return Ok(());
+    }
+
+    repo.config()?.set_str("core.hooksPath", ".githooks")?;
+    println!("{}", "🪝 Git hooks enabled from .githooks".green());
+    Ok(())
+}
+
+fn create_initial_commit(repo: &Repository) -> Result<()> {
+    let mut index = repo.index()?;
+    index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+
+    let signature = repo
+        .signature()
+        .or_else(|_| Signature::now("nitroterm", "nitroterm@localhost"))?;
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        "chore: initial commit from template",
+        &tree,
+        &[],
+    )?;
+
+    println!("{}", "📦 Initialized git repository".green());
+    Ok(())
+}
+
+async fn apply_labels(project_path: &Path) -> Result<()> {
+    // gh works on the repository of the current directory
+    let previous_dir = std::env::current_dir()?;
+    std::env::set_current_dir(project_path)?;
+    let result = GitHubLabelsManager::new(GitHubLabelsConfig::default())
+        .run()
+        .await;
+    std::env::set_current_dir(previous_dir)?;
+    result
+}
+
+async fn run_initial_quality_pass(project_path: &Path) -> Result<()> {
+    let manager = CodeQualityManager::new(CodeQualityConfig::default());
+    let results = manager.run_quality_checks(project_path).await?;
+
+    let failed = results.iter().filter(|r| !r.success).count();
+    if failed > 0 {
+        println!(
+            "{}",
+            format!("⚠️  {} quality checks failed in the new project", failed).yellow()
+        );
+    }
+    Ok(())
+}
+
+pub async fn create_new_project(mut config: NewProjectConfig) -> Result<()> {
+    ensure_not_sandboxed("new")?;
+    let config_manager = ConfigManager::new().await?;
+    let app_config = config_manager.get_config().await?;
+    if config.org.is_none() {
+        config.org = app_config.github_org.clone();
+    }
+    let template_url = resolve_template(&config.template, &app_config.templates);
+
+    let project_path = PathBuf::from(&config.name);
+    scaffold_project(&config, &template_url, &project_path)?;
+
+    if config.skip_labels {
+        println!("{}", "⏭️  Skipping GitHub labels".dimmed());
+    } else if config.remote.is_none() {
+        println!(
+            "{}",
+            "⏭️  No remote configured, skipping GitHub labels (use --remote)".dimmed()
+        );
+    } else if let Err(e) = apply_labels(&project_path).await {
+        println!("{}", format!("⚠️  Could not apply labels: {}", e).yellow());
+    }
+
+    if config.skip_quality {
+        println!("{}", "⏭️  Skipping initial code quality pass".dimmed());
+    } else {
+        run_initial_quality_pass(&project_path).await?;
+    }
+
+    println!(
+        "\n{}",
+        format!("🎉 Project '{}' is ready!", config.name)
+            .green()
+            .bold()
+    );
+    println!("{}", format!("   cd {}", project_path.display()).cyan());
+
+    Ok(())
+}
+
+pub async fn register_template(name: &str, url: &str) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.templates.insert(name.to_string(), url.to_string());
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!("✅ Template '{}' registered: {}", name, url).green()
+    );
+    Ok(())
+}
@@ -0,0 +1,316 @@
+use crate::commands::pipeline::{load_project_file, project_root, PROJECT_FILE};
+use crate::utils::notify::{send_notification, webhook_urls};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::is_sandboxed;
+use crate::utils::{log_success, log_warning};
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use handlebars::Handlebars;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const DEFAULT_SENDMAIL: &str = "sendmail";
+
+/// 0: follow `.nitrokit.toml`, 1: `--notify`, 2: `--no-notify`.
+static OVERRIDE: AtomicU8 = AtomicU8::new(0);
+
+/// `--notify` (Some(true)) sends for every event, also ones left out of
+/// `events`; `--no-notify` (Some(false)) sends nothing.
+pub fn set_notify_override(value: Option<bool>) {
+    let value = match value {
+        None => 0,
+        Some(true) => 1,
+        Some(false) => 2,
+    };
+    OVERRIDE.store(value, Ordering::Relaxed);
+}
+
+pub fn notify_override() -> Option<bool> {
+    match OVERRIDE.load(Ordering::Relaxed) {
+        1 => Some(true),
+        2 => Some(false),
+        _ => None,
+    }
+}
+
+/// Operations that post a notification when they finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotifyEvent {
+    /// `create-release` tagged (and maybe published) a version
+    Release,
+    /// `update-dependencies` went through the manifests
+    DependencyUpdate,
+    /// `code-quality` had failing checks
+    QualityFailed,
+}
+
+impl NotifyEvent {
+    pub const ALL: [NotifyEvent; 3] = [
+        NotifyEvent::Release,
+        NotifyEvent::DependencyUpdate,
+        NotifyEvent::QualityFailed,
+    ];
+
+    /// The name used in `events` and `[notifications.templates.<name>]`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotifyEvent::Release => "release",
+            NotifyEvent::DependencyUpdate => "dependency-update",
+            NotifyEvent::QualityFailed => "quality-failed",
+        }
+    }
+
+    fn default_template(&self) -> (&'static str, &'static str) {
+        match self {
+            NotifyEvent::Release => (
+                "🚀 {{project}} {{tag}} released",
+                "{{#if url}}{{url}}\n\n{{/if}}{{notes}}",
+            ),
+            NotifyEvent::DependencyUpdate => (
+                "📦 {{project}}: {{updated}} updated, {{failed}} failed",
+                "{{#each results}}- {{manifest}}: {{status}}{{#if error}} ({{error}}){{/if}}\n{{/each}}",
+            ),
+            NotifyEvent::QualityFailed => (
+                "❌ {{project}}: {{failed_count}} of {{total}} checks failed",
+                "{{#each failed}}- {{this}}\n{{/each}}",
+            ),
+        }
+    }
+}
+
+/// Title and body of one event's message, Handlebars with the event's fields.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MessageTemplate {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// `[notifications]` of `.nitrokit.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationSettings {
+    /// Slack, Discord, Teams or generic webhooks; `$NAME` reads the URL
+    /// from that environment variable so the secret stays out of the repo
+    #[serde(default)]
+    pub webhooks: Vec<String>,
+    /// Email recipients, sent through `sendmail`
+    #[serde(default)]
+    pub email: Vec<String>,
+    #[serde(default)]
+    pub email_from: Option<String>,
+    /// Command reading the message on stdin, `sendmail -t -i` style
+    #[serde(default)]
+    pub sendmail: Option<String>,
+    /// Events that notify, all of them when left out
+    #[serde(default)]
+    pub events: Option<Vec<NotifyEvent>>,
+    /// Event name to its message template
+    #[serde(default)]
+    pub templates: BTreeMap<String, MessageTemplate>,
+}
+
+impl NotificationSettings {
+    /// Whether `event` notifies, with `--notify`/`--no-notify` taking
+    /// precedence over `events`.
+    pub fn enabled(&self, event: NotifyEvent, override_flag: Option<bool>) -> bool {
+        override_flag.unwrap_or_else(|| {
+            self.events
+                .as_ref()
+                .is_none_or(|events| events.contains(&event))
+        })
+    }
+
+    /// The configured webhooks with `$NAME` entries read from the
+    /// environment (unset ones are skipped), plus `NITROTERM_WEBHOOKS`.
+    pub fn webhook_urls(&self) -> Vec<String> {
+        let configured: Vec<String> = self
+            .webhooks
+            .iter()
+            .filter_map(|url| match url.trim().strip_prefix('$') {
+                Some(name) => std::env::var(name).ok(),
+                None => Some(url.clone()),
+            })
+            .collect();
+        webhook_urls(&configured)
+    }
+
+    /// Template names that aren't events, so typos don't go unnoticed.
+    pub fn validate(&self) -> Result<()> {
+        for name in self.templates.keys() {
+            if !NotifyEvent::ALL.iter().any(|event| event.name() == name) {
+                let names: Vec<&str> = NotifyEvent::ALL.iter().map(|event| event.name()).collect();
+                return Err(anyhow!(
+                    "Unknown notification template '{}', expected one of: {}",
+                    name,
+                    names.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Title and body of `event`'s message for `context`.
+    pub fn render(&self, event: NotifyEvent, context: &Value) -> Result<(String, String)> {
+        let (default_title, default_body) = event.default_template();
+        let template = self.templates.get(event.name());
+        let title = template
+            .and_then(|template| template.title.as_deref())
+            .unwrap_or(default_title);
+        let body = template
+            .and_then(|template| template.body.as_deref())
+            .unwrap_or(default_body);
+
+        let mut handlebars = Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        let render = |template: &str| {
+            handlebars
+                .render_template(template, context)
+                .map_err(|e| anyhow!("Invalid {} notification template: {}", event.name(), e))
+        };
+        Ok((
+            render(title)?.trim().to_string(),
+            render(body)?.trim().to_string(),
+        ))
+    }
+}
+
+/// A header value, RFC 2047 encoded when it isn't plain ASCII.
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64::engine::general_purpose::STANDARD.encode(value)
+        )
+    }
+}
+
+/// A plain text mail for `sendmail -t`, which reads the recipients from
+/// the headers.
+pub fn email_message(settings: &NotificationSettings, title: &str, body: &str) -> String {
+    let mut message = format!("To: {}\n", settings.email.join(", "));
+    if let Some(from) = &settings.email_from {
+        message.push_str(&format!("From: {}\n", from));
+    }
+    message.push_str(&format!("Subject: {}\n", encode_header(title)));
+    message.push_str("MIME-Version: 1.0\n");
+    message.push_str("Content-Type: text/plain; charset=utf-8\n\n");
+    message.push_str(body);
+    message.push('\n');
+    message
+}
+
+pub fn send_email(
+    runner: &dyn CommandRunner,
+    settings: &NotificationSettings,
+    title: &str,
+    body: &str,
+) -> Result<()> {
+    let command = settings.sendmail.as_deref().unwrap_or(DEFAULT_SENDMAIL);
+    runner
+        .run_with_input(
+            command,
+            &["-t", "-i"],
+            &email_message(settings, title, body),
+            None,
+        )?
+        .check(command)?;
+    Ok(())
+}
+
+/// Sends `event`'s message to every webhook and recipient and returns how
+/// many destinations got it. All of them are tried before failures are
+/// reported.
+pub async fn deliver(
+    runner: &dyn CommandRunner,
+    settings: &NotificationSettings,
+    event: NotifyEvent,
+    context: &Value,
+) -> Result<usize> {
+    let (title, body) = settings.render(event, context)?;
+    let webhooks = settings.webhook_urls();
+
+    let mut failures = Vec::new();
+    if !webhooks.is_empty() {
+        if let Err(e) = send_notification(&webhooks, &title, &body).await {
+            failures.push(e.to_string());
+        }
+    }
+    if !settings.email.is_empty() {
+        if let Err(e) = send_email(runner, settings, &title, &body) {
+            failures.push(e.to_string());
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(webhooks.len() + usize::from(!settings.email.is_empty()))
+    } else {
+        Err(anyhow!(failures.join("; ")))
+    }
+}
+
+/// Posts `event` with `context` (plus `project`, the directory name) to the
+/// destinations in the `.nitrokit.toml` of `dir`. Failures only warn, a
+/// notification never fails the operation itself.
+pub async fn notify(dir: &Path, event: NotifyEvent, mut context: Value) {
+    let override_flag = notify_override();
+    if override_flag == Some(false) || is_sandboxed() {
+        return;
+    }
+    let root = project_root(dir);
+    let settings = match load_project_file(&root) {
+        Ok(project) => project.and_then(|project| project.notifications),
+        Err(e) => {
+            log_warning(&format!("Notifications skipped: {}", e));
+            return;
+        }
+    };
+    let Some(settings) = settings else {
+        if override_flag == Some(true) {
+            log_warning(&format!(
+                "--notify given, but {} has no [notifications] section",
+                PROJECT_FILE
+            ));
+        }
+        return;
+    };
+    if !settings.enabled(event, override_flag) {
+        return;
+    }
+    if let Err(e) = settings.validate() {
+        log_warning(&format!("Notifications skipped: {}", e));
+        return;
+    }
+
+    if let Some(fields) = context.as_object_mut() {
+        let project = root
+            .canonicalize()
+            .ok()
+            .and_then(|root| {
+                root.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .unwrap_or_default();
+        fields.entry("project").or_insert(Value::String(project));
+    }
+    match deliver(&SystemRunner, &settings, event, &context).await {
+        Ok(0) => log_warning(&format!(
+            "No webhooks or email recipients in the [notifications] of {}",
+            PROJECT_FILE
+        )),
+        Ok(sent) => log_success(&format!(
+            "🔔 {} notification sent to {} destination(s)",
+            event.name(),
+            sent
+        )),
+        Err(e) => log_warning(&format!("Notification failed: {}", e)),
+    }
+}
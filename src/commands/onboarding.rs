@@ -0,0 +1,243 @@
+use crate::commands::code_quality::{
+    CodeQualityConfig, CodeQualityManager, PackageManager, ProjectInfo, ProjectType,
+};
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::releases::{resolve_repo, set_configured_repo};
+use crate::commands::secret_scan::SECRETS_CHECK;
+use crate::utils::prompt::{self, ChecklistItem};
+use anyhow::Result;
+use colored::*;
+use std::io::IsTerminal;
+use std::path::Path;
+
+/// Checks the wizard offers, in the order they are listed.
+pub const OFFERED_CHECKS: [&str; 7] = [
+    "lint",
+    "format",
+    "typecheck",
+    "test",
+    "build",
+    "security",
+    SECRETS_CHECK,
+];
+
+/// Where translation files usually live, most common first.
+const MESSAGES_DIRS: [&str; 7] = [
+    "messages",
+    "locales",
+    "src/locales",
+    "public/locales",
+    "src/i18n",
+    "i18n",
+    "translations",
+];
+
+/// Answers collected by the wizard, applied on top of the saved config.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OnboardingAnswers {
+    pub gemini_api_key: Option<String>,
+    pub messages_dir: Option<String>,
+    pub release_notes_format: Option<String>,
+    pub github_repo: Option<String>,
+    pub quality_checks: Vec<String>,
+}
+
+impl OnboardingAnswers {
+    /// Unanswered questions keep the current value.
+    pub fn apply(&self, config: &mut AppConfig) {
+        if let Some(key) = &self.gemini_api_key {
+            config.gemini_api_key = Some(key.clone());
+        }
+        if let Some(dir) = &self.messages_dir {
+            config.messages_dir = dir.clone();
+        }
+        if let Some(format) = &self.release_notes_format {
+            config.release_notes_format = format.clone();
+        }
+        if let Some(repo) = &self.github_repo {
+            config.github_repo = Some(repo.clone());
+        }
+        config.quality_checks = self.quality_checks.clone();
+    }
+}
+
+/// Short description like `Next.js, TypeScript (pnpm)`.
+pub fn describe_project(info: &ProjectInfo, package_manager: Option<&str>) -> String {
+    let mut names = info.frameworks.clone();
+    if info.has_typescript && !names.iter().any(|name| name == "TypeScript") {
+        names.push("TypeScript".to_string());
+    }
+    if names.is_empty() {
+        return "Unknown project".to_string();
+    }
+    match package_manager {
+        Some(manager) => format!("{} ({})", names.join(", "), manager),
+        None => names.join(", "),
+    }
+}
+
+/// The built-in checks, plus type checking for TypeScript and the secrets
+/// scan, which needs no tooling.
+pub fn suggested_checks(info: &ProjectInfo) -> Vec<String> {
+    let mut checks = CodeQualityConfig::default().enabled_checks;
+    if info.has_typescript || info.project_type == ProjectType::TypeScript {
+        checks.push("typecheck".to_string());
+    }
+    checks.push(SECRETS_CHECK.to_string());
+    checks
+}
+
+/// First of the usual translation directories that exists below `root`.
+pub fn detect_messages_dir(root: &Path) -> Option<String> {
+    MESSAGES_DIRS
+        .iter()
+        .find(|dir| root.join(dir).is_dir())
+        .map(|dir| dir.to_string())
+}
+
+/// `full` or `summary` from an answer; an empty answer keeps `current`.
+pub fn parse_release_notes_format(input: &str, current: &str) -> Option<String> {
+    match input.trim().to_lowercase().as_str() {
+        "" => Some(current.to_string()),
+        "f" | "full" => Some("full".to_string()),
+        "s" | "summary" => Some("summary".to_string()),
+        _ => None,
+    }
+}
+
+fn section(title: &str) {
+    println!();
+    println!("{}", title.yellow().bold());
+}
+
+/// Guided setup for the first start: detects the project, then asks for
+/// the settings the other commands read from the saved config.
+pub async fn run_onboarding(manager: &ConfigManager) -> Result<AppConfig> {
+    let mut config = manager.get_config().await.unwrap_or_default();
+    let root = Path::new(".");
+    let quality = CodeQualityManager::new(CodeQualityConfig::default());
+    let info = quality.detect_project_type(root).await?;
+    let package_manager = (info.package_manager != PackageManager::Unknown)
+        .then(|| quality.get_package_manager_command(&info.package_manager));
+    let mut answers = OnboardingAnswers::default();
+
+    println!("{}", "👋 Welcome to Nitroterm!".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+    println!(
+        "{}",
+        "A few questions to set things up, press Enter to keep the suggestion.".dimmed()
+    );
+    println!(
+        "🔎 Detected project: {}",
+        describe_project(&info, package_manager.as_deref())
+            .green()
+            .bold()
+    );
+
+    section("🔑 Translations");
+    if std::env::var("GEMINI_API_KEY").is_ok_and(|key| !key.is_empty()) {
+        println!("{}", "✅ Using GEMINI_API_KEY from environment".green());
+    } else {
+        let key = prompt::ask(
+            "Gemini API key for sync-translations (Enter to skip): ".cyan(),
+            "",
+        )?;
+        answers.gemini_api_key = Some(key).filter(|key| !key.is_empty());
+    }
+    let messages_dir = detect_messages_dir(root).unwrap_or_else(|| config.messages_dir.clone());
+    answers.messages_dir = Some(prompt::ask(
+        format!("Messages directory [{}]: ", messages_dir).cyan(),
+        &messages_dir,
+    )?);
+
+    section("📝 Releases");
+    let answer = prompt::ask(
+        format!(
+            "Release notes format, full or summary [{}]: ",
+            config.release_notes_format
+        )
+        .cyan(),
+        "",
+    )?;
+    answers.release_notes_format =
+        parse_release_notes_format(&answer, &config.release_notes_format);
+    if answers.release_notes_format.is_none() {
+        println!("{}", "⚠️  Unknown format, keeping the current one".yellow());
+    }
+
+    let detected = resolve_repo(None)
+        .ok()
+        .map(|(owner, name)| format!("{}/{}", owner, name));
+    match &detected {
+        Some(repo) => println!("{}", format!("🐙 GitHub repository: {}", repo).dimmed()),
+        None => println!("{}", "🐙 No GitHub remote found".dimmed()),
+    }
+    let repo = prompt::ask(
+        "GitHub repository to use when a project has no GitHub remote (owner/name, Enter to skip): "
+            .cyan(),
+        "",
+    )?;
+    if !repo.is_empty() {
+        match resolve_repo(Some(&repo)) {
+            Ok(_) => answers.github_repo = Some(repo),
+            Err(e) => println!("{}", format!("⚠️  {}, skipped", e).yellow()),
+        }
+    }
+
+    section("🔍 Code quality");
+    let suggested = suggested_checks(&info);
+    let items: Vec<ChecklistItem> = OFFERED_CHECKS
+        .iter()
+        .map(|check| ChecklistItem {
+            group: "Checks run by code-quality".to_string(),
+            label: check.to_string(),
+            checked: suggested.iter().any(|name| name == check),
+        })
+        .collect();
+    answers.quality_checks = if std::io::stdin().is_terminal() {
+        prompt::checklist("Select the quality checks to run", &items)?
+            .into_iter()
+            .map(|index| OFFERED_CHECKS[index].to_string())
+            .collect()
+    } else {
+        println!("Checks: {}", suggested.join(", ").green());
+        suggested
+    };
+
+    answers.apply(&mut config);
+    manager.save_config(&config).await?;
+    if let Some(repo) = &config.github_repo {
+        set_configured_repo(repo.clone());
+    }
+
+    println!();
+    println!("{}", "✅ Configuration saved!".green().bold());
+    println!(
+        "{}",
+        format!("📁 Config stored in: {}", manager.config_dir.display()).dimmed()
+    );
+    println!(
+        "{}",
+        "Change any of it later with `nitroterm config setup` or run this again with `nitroterm config onboard`."
+            .dimmed()
+    );
+    Ok(config)
+}
+
+/// Runs the wizard when there is no saved config yet and someone is there
+/// to answer it.
+pub async fn onboard_if_first_run() {
+    if prompt::is_non_interactive() || !std::io::stdin().is_terminal() {
+        return;
+    }
+    let manager = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager,
+        Err(_) => return,
+    };
+    if !manager.is_first_run().await.unwrap_or(false) {
+        return;
+    }
+    if let Err(e) = run_onboarding(&manager).await {
+        println!("{}", format!("⚠️  Setup skipped: {}", e).yellow());
+    }
+}
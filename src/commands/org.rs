@@ -0,0 +1,566 @@
+use crate::commands::config::ConfigManager;
+use crate::commands::lockfiles::{direct_locked_packages, Ecosystem, LockedPackage};
+use crate::utils::sandbox::ensure_network_allowed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_json::{json, Value};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_OSV_API_URL: &str = "https://api.osv.dev/v1";
+
+/// OSV accepts up to 1000 queries per batch request.
+const OSV_BATCH_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Unknown,
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+impl std::str::FromStr for Severity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(Severity::Low),
+            "moderate" | "medium" => Ok(Severity::Moderate),
+            "high" => Ok(Severity::High),
+            "critical" => Ok(Severity::Critical),
+            "unknown" => Ok(Severity::Unknown),
+            other => Err(anyhow!(
+                "Unknown severity '{}'. Use low, moderate, high or critical",
+                other
+            )),
+        }
+    }
+}
+
+impl Severity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Unknown => "unknown",
+            Severity::Low => "low",
+            Severity::Moderate => "moderate",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+/// Locked direct dependencies of one registered repository.
+#[derive(Debug, Clone)]
+pub struct ProjectInventory {
+    pub name: String,
+    pub path: PathBuf,
+    pub packages: Vec<LockedPackage>,
+    pub error: Option<String>,
+}
+
+/// A library used by several repositories at different versions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionSkew {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    /// Newest first, with the repositories on each version
+    pub versions: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Advisory {
+    pub id: String,
+    pub summary: String,
+    pub severity: Severity,
+    pub fixed: Vec<String>,
+}
+
+/// A repository still locked to a version with a serious advisory.
+#[derive(Debug, Clone)]
+pub struct SecurityLag {
+    pub project: String,
+    pub package: LockedPackage,
+    pub advisories: Vec<Advisory>,
+}
+
+impl SecurityLag {
+    /// Lowest version that fixes every advisory, when OSV knows it.
+    pub fn fixed_in(&self) -> Option<String> {
+        let current = semver::Version::parse(&self.package.version).ok();
+        self.advisories
+            .iter()
+            .map(|advisory| {
+                advisory
+                    .fixed
+                    .iter()
+                    .filter_map(|fixed| semver::Version::parse(fixed).ok())
+                    .filter(|fixed| current.as_ref().is_none_or(|current| fixed > current))
+                    .min()
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max()
+            .map(|fixed| fixed.to_string())
+    }
+}
+
+pub struct OrgDepsReport {
+    pub projects: Vec<ProjectInventory>,
+    pub skew: Vec<VersionSkew>,
+    pub lagging: Vec<SecurityLag>,
+}
+
+pub struct OsvClient {
+    client: reqwest::Client,
+    pub base_url: String,
+}
+
+impl OsvClient {
+    pub fn new(base_url: &str) -> Result<Self> {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .user_agent(concat!("nitroterm/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Advisory IDs affecting each package, in the order given.
+    pub async fn query_batch(&self, packages: &[LockedPackage]) -> Result<Vec<Vec<String>>> {
+        let mut ids = Vec::with_capacity(packages.len());
+
+        for chunk in packages.chunks(OSV_BATCH_SIZE) {
+            let queries: Vec<Value> = chunk
+                .iter()
+                .map(|package| {
+                    json!({
+                        "package": {"name": package.name, "ecosystem": package.ecosystem.name()},
+                        "version": package.version,
+                    })
+                })
+                .collect();
+
+            let url = format!("{}/querybatch", self.base_url);
+            let response = self
+                .client
+                .post(&url)
+                .json(&json!({ "queries": queries }))
+                .send()
+                .await?;
+            if !response.status().is_success() {
+                return Err(anyhow!("{} returned {}", url, response.status()));
+            }
+            let body: Value = response.json().await?;
+            let results = body["results"].as_array().cloned().unwrap_or_default();
+
+            for index in 0..chunk.len() {
+                let vulns = results
+                    .get(index)
+                    .and_then(|result| result["vulns"].as_array())
+                    .map(|vulns| {
+                        vulns
+                            .iter()
+                            .filter_map(|vuln| vuln["id"].as_str().map(|id| id.to_string()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                ids.push(vulns);
+            }
+        }
+
+        Ok(ids)
+    }
+
+    pub async fn advisory(&self, id: &str) -> Result<Value> {
+        let url = format!("{}/vulns/{}", self.base_url, id);
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("{} returned {}", url, response.status()));
+        }
+        Ok(response.json().await?)
+    }
+}
+
+/// Reads the parts of an OSV record we report on. `fixed` only covers the
+/// ranges of the given package.
+pub fn parse_advisory(record: &Value, package: &LockedPackage) -> Advisory {
+    let severity = record["database_specific"]["severity"]
+        .as_str()
+        .and_then(|severity| severity.parse().ok())
+        .unwrap_or(Severity::Unknown);
+
+    let fixed = record["affected"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|affected| {
+            affected["package"]["name"]
+                .as_str()
+                .is_some_and(|name| name.eq_ignore_ascii_case(&package.name))
+        })
+        .flat_map(|affected| affected["ranges"].as_array().into_iter().flatten())
+        .flat_map(|range| range["events"].as_array().into_iter().flatten())
+        .filter_map(|event| event["fixed"].as_str().map(|fixed| fixed.to_string()))
+        .collect();
+
+    Advisory {
+        id: record["id"].as_str().unwrap_or_default().to_string(),
+        summary: record["summary"].as_str().unwrap_or_default().to_string(),
+        severity,
+        fixed,
+    }
+}
+
+pub fn project_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+pub fn collect_inventories(paths: &[String]) -> Vec<ProjectInventory> {
+    paths
+        .iter()
+        .map(|path| {
+            let path = PathBuf::from(path);
+            let (packages, error) = if !path.is_dir() {
+                (Vec::new(), Some("Directory not found".to_string()))
+            } else {
+                match direct_locked_packages(&path) {
+                    Ok(packages) if packages.is_empty() => {
+                        (packages, Some("No supported lockfile found".to_string()))
+                    }
+                    Ok(packages) => (packages, None),
+                    Err(e) => (Vec::new(), Some(e.to_string())),
+                }
+            };
+            ProjectInventory {
+                name: project_name(&path),
+                path,
+                packages,
+                error,
+            }
+        })
+        .collect()
+}
+
+type ReposByVersion = BTreeMap<String, Vec<String>>;
+
+/// Libraries locked by two or more repositories at different versions,
+/// the widest spread first.
+pub fn find_version_skew(projects: &[ProjectInventory]) -> Vec<VersionSkew> {
+    let mut usage: BTreeMap<(String, String), (Ecosystem, ReposByVersion)> = BTreeMap::new();
+
+    for project in projects {
+        for package in &project.packages {
+            let (_, versions) = usage
+                .entry((package.ecosystem.name().to_string(), package.name.clone()))
+                .or_insert_with(|| (package.ecosystem, BTreeMap::new()));
+            let repos = versions.entry(package.version.clone()).or_default();
+            if !repos.contains(&project.name) {
+                repos.push(project.name.clone());
+            }
+        }
+    }
+
+    let mut skew: Vec<VersionSkew> = usage
+        .into_iter()
+        .filter(|(_, (_, versions))| versions.len() > 1)
+        .map(|((_, name), (ecosystem, versions))| {
+            let mut versions: Vec<(String, Vec<String>)> = versions.into_iter().collect();
+            versions.sort_by(|(a, _), (b, _)| {
+                match (semver::Version::parse(a), semver::Version::parse(b)) {
+                    (Ok(a), Ok(b)) => b.cmp(&a),
+                    _ => b.cmp(a),
+                }
+            });
+            VersionSkew {
+                ecosystem,
+                name,
+                versions,
+            }
+        })
+        .collect();
+
+    skew.sort_by_key(|s| Reverse(s.versions.len()));
+    skew
+}
+
+/// Looks every locked package up on OSV once and lists the repositories
+/// holding versions with advisories at or above `min_severity`.
+pub async fn find_security_lag(
+    osv: &OsvClient,
+    projects: &[ProjectInventory],
+    min_severity: Severity,
+) -> Result<Vec<SecurityLag>> {
+    let mut unique: Vec<LockedPackage> = Vec::new();
+    for package in projects.iter().flat_map(|project| &project.packages) {
+        if !unique.contains(package) {
+            unique.push(package.clone());
+        }
+    }
+    if unique.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let ids = osv.query_batch(&unique).await?;
+    let mut records: HashMap<String, Value> = HashMap::new();
+    let mut advisories: Vec<(LockedPackage, Vec<Advisory>)> = Vec::new();
+
+    for (package, ids) in unique.into_iter().zip(ids) {
+        let mut found = Vec::new();
+        for id in ids {
+            if !records.contains_key(&id) {
+                match osv.advisory(&id).await {
+                    Ok(record) => {
+                        records.insert(id.clone(), record);
+                    }
+                    Err(e) => {
+                        log_warning(&format!("Could not fetch advisory {}: {}", id, e));
+                        continue;
+                    }
+                }
+            }
+            let advisory = parse_advisory(&records[&id], &package);
+            if advisory.severity >= min_severity {
+                found.push(advisory);
+            }
+        }
+        if !found.is_empty() {
+            advisories.push((package, found));
+        }
+    }
+
+    let mut lagging = Vec::new();
+    for project in projects {
+        for (package, found) in &advisories {
+            if project.packages.contains(package) {
+                lagging.push(SecurityLag {
+                    project: project.name.clone(),
+                    package: package.clone(),
+                    advisories: found.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(lagging)
+}
+
+pub async fn build_org_deps(
+    paths: &[String],
+    osv: Option<&OsvClient>,
+    min_severity: Severity,
+) -> Result<OrgDepsReport> {
+    let projects = collect_inventories(paths);
+    let skew = find_version_skew(&projects);
+    let lagging = match osv {
+        Some(osv) => find_security_lag(osv, &projects, min_severity).await?,
+        None => Vec::new(),
+    };
+
+    Ok(OrgDepsReport {
+        projects,
+        skew,
+        lagging,
+    })
+}
+
+fn print_org_deps(report: &OrgDepsReport, audited: bool, min_severity: Severity) {
+    println!("{}", "🏢 Organization dependency inventory".cyan().bold());
+    println!("{}", "─".repeat(60).dimmed());
+    for project in &report.projects {
+        match &project.error {
+            Some(error) => println!(
+                "  {} {} {}",
+                "⚠️ ".yellow(),
+                project.name.bold(),
+                format!("({}: {})", error, project.path.display()).dimmed()
+            ),
+            None => println!(
+                "  {} {} {}",
+                "📦".green(),
+                project.name.bold(),
+                format!("{} direct dependencies", project.packages.len()).dimmed()
+            ),
+        }
+    }
+
+    println!();
+    if report.skew.is_empty() {
+        log_success("Shared libraries are on the same version everywhere");
+    } else {
+        println!(
+            "{}",
+            format!("🔀 Version skew ({} libraries)", report.skew.len())
+                .yellow()
+                .bold()
+        );
+        for skew in &report.skew {
+            println!(
+                "  {} {}",
+                skew.name.bold(),
+                format!("({})", skew.ecosystem.name()).dimmed()
+            );
+            for (index, (version, repos)) in skew.versions.iter().enumerate() {
+                let version = if index == 0 {
+                    version.green()
+                } else {
+                    version.yellow()
+                };
+                println!("    {:<14} {}", version, repos.join(", "));
+            }
+        }
+    }
+
+    if !audited {
+        return;
+    }
+
+    println!();
+    if report.lagging.is_empty() {
+        log_success(&format!(
+            "No {}+ advisories in the locked versions",
+            min_severity.name()
+        ));
+        return;
+    }
+
+    println!(
+        "{}",
+        format!(
+            "🔒 Repositories lagging on security updates ({})",
+            report.lagging.len()
+        )
+        .red()
+        .bold()
+    );
+    for lag in &report.lagging {
+        let worst = lag
+            .advisories
+            .iter()
+            .map(|advisory| advisory.severity)
+            .max()
+            .unwrap_or(Severity::Unknown);
+        let fix = lag
+            .fixed_in()
+            .map(|fixed| format!(" → {}", fixed).green().to_string())
+            .unwrap_or_default();
+        println!(
+            "  {:<20} {} {}{} {}",
+            lag.project.bold(),
+            lag.package.name,
+            lag.package.version.red(),
+            fix,
+            format!("[{}]", worst.name()).red()
+        );
+        for advisory in &lag.advisories {
+            println!(
+                "      {} {}",
+                advisory.id.dimmed(),
+                advisory.summary.dimmed()
+            );
+        }
+    }
+}
+
+pub async fn run_org_deps(no_audit: bool, min_severity: &str) -> Result<()> {
+    let min_severity: Severity = min_severity.parse()?;
+    let config = ConfigManager::new_quiet().await?.get_config().await?;
+    if config.org_projects.is_empty() {
+        return Err(anyhow!(
+            "No projects registered, add them with `nitroterm org add <path>...`"
+        ));
+    }
+
+    log_info(&format!(
+        "Reading lockfiles of {} registered project(s)...",
+        config.org_projects.len()
+    ));
+    let osv = if no_audit {
+        None
+    } else {
+        let url = std::env::var("OSV_API_URL").unwrap_or_else(|_| DEFAULT_OSV_API_URL.to_string());
+        Some(OsvClient::new(&url)?)
+    };
+
+    let report = build_org_deps(&config.org_projects, osv.as_ref(), min_severity).await?;
+    print_org_deps(&report, osv.is_some(), min_severity);
+    Ok(())
+}
+
+/// Registers repositories for `org` commands. Paths are stored absolute so
+/// the commands work from any directory.
+pub async fn add_org_projects(paths: &[String]) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+
+    for path in paths {
+        let absolute = std::fs::canonicalize(path)
+            .map_err(|e| anyhow!("Cannot register '{}': {}", path, e))?;
+        let absolute = absolute.display().to_string();
+        if !config.org_projects.contains(&absolute) {
+            config.org_projects.push(absolute);
+        }
+    }
+    config_manager.save_config(&config).await?;
+
+    log_success(&format!(
+        "{} project(s) registered",
+        config.org_projects.len()
+    ));
+    Ok(())
+}
+
+pub async fn remove_org_project(path: &str) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+
+    // Accept the path as given, resolved, or just the directory name
+    let resolved = std::fs::canonicalize(path)
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+    let before = config.org_projects.len();
+    config.org_projects.retain(|project| {
+        project != path && *project != resolved && project_name(Path::new(project)) != path
+    });
+    if config.org_projects.len() == before {
+        return Err(anyhow!("'{}' is not a registered project", path));
+    }
+    config_manager.save_config(&config).await?;
+
+    log_success(&format!("Removed '{}' from the project registry", path));
+    Ok(())
+}
+
+pub async fn list_org_projects() -> Result<()> {
+    let config = ConfigManager::new_quiet().await?.get_config().await?;
+    if config.org_projects.is_empty() {
+        log_info("No projects registered, add them with `nitroterm org add <path>...`");
+        return Ok(());
+    }
+
+    println!("{}", "🏢 Registered projects".cyan().bold());
+    for project in &config.org_projects {
+        let status = if Path::new(project).is_dir() {
+            "✅"
+        } else {
+            "❌"
+        };
+        println!(
+            "  {} {:<20} {}",
+            status,
+            project_name(Path::new(project)).bold(),
+            project.dimmed()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use crate::commands::dependency_update::{
+    backup_lock_files, detect_node_package_manager, update_rules, UpdateRules,
+};
+use crate::commands::lockfiles::{Ecosystem, LockedPackage, RegistryClient};
+use crate::commands::selective_update::{classify_update, lenient_version, UpdateKind};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::progress::{ProgressBar, Spinner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use semver::Version;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Sections `--ranges` rewrites, the ones npm-check-updates looks at by
+/// default. Peer dependencies describe what a package works with and are
+/// left to the author.
+pub const DEPENDENCY_SECTIONS: [&str; 3] =
+    ["dependencies", "devDependencies", "optionalDependencies"];
+
+/// A dependency in package.json, e.g. (`devDependencies`, `vitest`, `^1.6.0`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyRange {
+    pub section: String,
+    pub name: String,
+    pub range: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RangeUpgrade {
+    pub section: String,
+    pub name: String,
+    pub from: String,
+    pub to: String,
+    pub latest: String,
+}
+
+/// Registry dependencies of a package.json. `workspace:`, `file:`, `npm:`
+/// aliases, git and URL specs point somewhere else and are skipped.
+pub fn registry_ranges(package_json: &str) -> Result<Vec<DependencyRange>> {
+    let manifest: serde_json::Value =
+        serde_json::from_str(package_json).map_err(|e| anyhow!("Invalid package.json: {}", e))?;
+    let mut ranges = Vec::new();
+    for section in DEPENDENCY_SECTIONS {
+        for (name, range) in manifest[section].as_object().into_iter().flatten() {
+            let Some(range) = range.as_str() else {
+                continue;
+            };
+            if range.contains(':') || range.contains('/') {
+                continue;
+            }
+            ranges.push(DependencyRange {
+                section: section.to_string(),
+                name: name.clone(),
+                range: range.to_string(),
+            });
+        }
+    }
+    Ok(ranges)
+}
+
+/// `^17.0.2` → `^18.3.1`, `~1.2` → `~2.0`, `1.x` → `2.x`, `>=4.0.0` →
+/// `>=5.1.0`: same prefix, precision and wildcards, new version. None when
+/// `latest` isn't newer, the range would read the same, or it's one this
+/// can't rewrite (`1 || 2`, `>=1 <2`, `*`, dist-tags).
+pub fn upgraded_range(range: &str, latest: &str) -> Option<String> {
+    let pattern = Regex::new(
+        r"^(\^|~|>=|=|v)?(\d+)(?:\.(\d+|x|X|\*))?(?:\.(\d+|x|X|\*))?(-[0-9A-Za-z.-]+)?$",
+    )
+    .unwrap();
+    let captures = pattern.captures(range.trim())?;
+    let latest = lenient_version(latest)?;
+
+    let number = |index: usize| {
+        captures
+            .get(index)
+            .and_then(|part| part.as_str().parse::<u64>().ok())
+    };
+    let mut floor = Version::new(number(2)?, number(3).unwrap_or(0), number(4).unwrap_or(0));
+    if let Some(pre) = captures.get(5) {
+        floor = Version::parse(&format!("{}{}", floor, pre.as_str())).ok()?;
+    }
+    if latest <= floor {
+        return None;
+    }
+
+    let part = |index: usize, value: u64| {
+        captures.get(index).map(|part| match part.as_str() {
+            wildcard @ ("x" | "X" | "*") => wildcard.to_string(),
+            _ => value.to_string(),
+        })
+    };
+    let mut version = latest.major.to_string();
+    for part in [part(3, latest.minor), part(4, latest.patch)]
+        .into_iter()
+        .flatten()
+    {
+        version.push('.');
+        version.push_str(&part);
+    }
+    if captures.get(4).is_some() && !latest.pre.is_empty() {
+        version.push_str(&format!("-{}", latest.pre));
+    }
+
+    let prefix = captures.get(1).map_or("", |prefix| prefix.as_str());
+    let upgraded = format!("{}{}", prefix, version);
+    (upgraded != range.trim()).then_some(upgraded)
+}
+
+/// What `--ranges` changes: every range that doesn't reach the latest
+/// version yet, unless the ignore or pin rules keep the package where it is.
+pub fn plan_range_upgrades(
+    ranges: &[DependencyRange],
+    latest: &HashMap<String, String>,
+    rules: &UpdateRules,
+) -> Vec<RangeUpgrade> {
+    ranges
+        .iter()
+        .filter_map(|dependency| {
+            let latest = latest.get(&dependency.name)?;
+            if !rules.allows("node", &dependency.name, latest) {
+                return None;
+            }
+            Some(RangeUpgrade {
+                section: dependency.section.clone(),
+                name: dependency.name.clone(),
+                from: dependency.range.clone(),
+                to: upgraded_range(&dependency.range, latest)?,
+                latest: latest.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Replaces the range of `name` in `section` when it reads `from`, editing
+/// only that string so indentation, key order and the trailing newline stay
+/// as they were. None when the entry isn't there.
+pub fn set_range(
+    package_json: &str,
+    section: &str,
+    name: &str,
+    from: &str,
+    to: &str,
+) -> Option<String> {
+    let section_pattern = Regex::new(&format!(r#""{}"\s*:\s*\{{"#, regex::escape(section))).ok()?;
+    let start = section_pattern.find(package_json)?.end();
+    let end = start + package_json[start..].find('}')?;
+
+    let entry = Regex::new(&format!(r#""{}"\s*:\s*"([^"]*)""#, regex::escape(name))).ok()?;
+    let range = entry.captures(&package_json[start..end])?.get(1)?;
+    if range.as_str() != from {
+        return None;
+    }
+    Some(format!(
+        "{}{}{}",
+        &package_json[..start + range.start()],
+        to,
+        &package_json[start + range.end()..]
+    ))
+}
+
+/// package.json with every upgrade applied.
+pub fn apply_range_upgrades(package_json: &str, upgrades: &[RangeUpgrade]) -> String {
+    upgrades
+        .iter()
+        .fold(package_json.to_string(), |content, upgrade| {
+            set_range(
+                &content,
+                &upgrade.section,
+                &upgrade.name,
+                &upgrade.from,
+                &upgrade.to,
+            )
+            .unwrap_or(content)
+        })
+}
+
+/// Looks up the `latest` dist-tag of every package once.
+pub async fn fetch_latest_npm_versions(
+    registry: &RegistryClient,
+    names: &[String],
+) -> HashMap<String, String> {
+    let mut latest = HashMap::new();
+    let mut progress = ProgressBar::new("📦 npm", names.len());
+    for name in names {
+        progress.tick(name);
+        let package = LockedPackage {
+            name: name.clone(),
+            version: String::new(),
+            ecosystem: Ecosystem::Npm,
+        };
+        match registry.latest_version(&package).await {
+            Ok(version) => {
+                latest.insert(name.clone(), version);
+            }
+            Err(e) => {
+                progress.clear();
+                log_warning(&format!("Could not check {}: {}", name, e));
+            }
+        }
+        progress.inc(1);
+    }
+    if !names.is_empty() {
+        progress.finish();
+    }
+    latest
+}
+
+fn print_upgrades(upgrades: &[RangeUpgrade]) {
+    println!("{}", "📦 package.json ranges:".cyan().bold());
+    for upgrade in upgrades {
+        let to = match classify_update(&upgrade.from, &upgrade.latest) {
+            Some(UpdateKind::Major) => upgrade.to.red(),
+            Some(UpdateKind::Minor) => upgrade.to.cyan(),
+            _ => upgrade.to.green(),
+        };
+        let section = if upgrade.section == "dependencies" {
+            String::new()
+        } else {
+            format!(" ({})", upgrade.section)
+        };
+        println!(
+            "  {:<30} {:>12} → {:<12}{}",
+            upgrade.name.green(),
+            upgrade.from,
+            to,
+            section.dimmed()
+        );
+    }
+}
+
+/// Rewrites the ranges in `dir/package.json` to the latest versions,
+/// npm-check-updates style, then runs the project's package manager so the
+/// lockfile follows.
+pub async fn run_range_upgrade(dir: &Path) -> Result<()> {
+    ensure_not_sandboxed("update-dependencies --ranges")?;
+    let path = dir.join("package.json");
+    let package_json = std::fs::read_to_string(&path)
+        .map_err(|_| anyhow!("No package.json in {}", dir.display()))?;
+    let ranges = registry_ranges(&package_json)?;
+    log_info(&format!(
+        "Checking {} dependency range(s) in package.json...",
+        ranges.len()
+    ));
+
+    let names: Vec<String> = ranges
+        .iter()
+        .map(|dependency| dependency.name.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let latest = fetch_latest_npm_versions(&RegistryClient::new()?, &names).await;
+    let rules = update_rules();
+    let upgrades = plan_range_upgrades(&ranges, &latest, rules);
+    let held = ranges
+        .iter()
+        .filter(|dependency| {
+            latest.get(&dependency.name).is_some_and(|latest| {
+                !rules.allows("node", &dependency.name, latest)
+                    && upgraded_range(&dependency.range, latest).is_some()
+            })
+        })
+        .count();
+    if held > 0 {
+        log_info(&format!(
+            "{} package(s) held back by ignore/pin rules",
+            held
+        ));
+    }
+    if upgrades.is_empty() {
+        log_success("All ranges in package.json allow the latest versions");
+        return Ok(());
+    }
+
+    print_upgrades(&upgrades);
+    let package_manager = detect_node_package_manager(dir);
+    let question = match &package_manager {
+        Some(pm) => format!("Write package.json and run {} install? (y/N): ", pm),
+        None => "Write package.json? (y/N): ".to_string(),
+    };
+    if !prompt::confirm(question.cyan())? {
+        log_info("package.json not changed");
+        return Ok(());
+    }
+
+    if let Some(pm) = &package_manager {
+        backup_lock_files(dir, pm);
+    }
+    std::fs::write(&path, apply_range_upgrades(&package_json, &upgrades))?;
+    log_success(&format!(
+        "Upgraded {} range(s) in package.json",
+        upgrades.len()
+    ));
+
+    let Some(pm) = package_manager else {
+        log_warning("No Node.js package manager found, run your install to refresh the lockfile");
+        return Ok(());
+    };
+    let spinner = Spinner::start(&format!("Running {} install...", pm));
+    match SystemRunner
+        .run(&pm, &["install"], Some(dir))
+        .and_then(|output| output.check(&format!("{} install", pm)))
+    {
+        Ok(_) => {
+            spinner.finish();
+            log_success("Lockfile refreshed");
+            Ok(())
+        }
+        Err(e) => {
+            spinner.fail();
+            Err(e)
+        }
+    }
+}
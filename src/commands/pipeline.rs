@@ -0,0 +1,476 @@
+use crate::commands::config::ConfigError;
+use crate::commands::notifications::NotificationSettings;
+use crate::commands::release_notes::get_current_branch;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::{get_repository, prompt};
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub const PROJECT_FILE: &str = ".nitrokit.toml";
+
+/// Pipelines running in the parent processes, so `run` steps can't loop.
+pub const PIPELINE_ENV: &str = "NITROTERM_PIPELINES";
+
+/// The parts of `.nitrokit.toml` nitroterm reads.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectFile {
+    /// Name to steps, e.g. `release = ["code-quality", "version auto"]`
+    #[serde(default)]
+    pub pipelines: BTreeMap<String, Vec<PipelineStep>>,
+    /// Webhooks and email for finished releases, updates and failed checks
+    #[serde(default)]
+    pub notifications: Option<NotificationSettings>,
+}
+
+/// A nitroterm command line, or a table with conditions.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum PipelineStep {
+    Command(String),
+    Detailed(StepDefinition),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StepDefinition {
+    pub command: String,
+    /// Shown instead of the command
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Only run when this holds
+    #[serde(default)]
+    pub when: Option<Condition>,
+    /// Skip when this holds
+    #[serde(default)]
+    pub skip_if: Option<Condition>,
+    /// Go on with the next step when this one fails
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+/// Holds when all of its parts do.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Condition {
+    /// The current branch is one of these
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub branch: Vec<String>,
+    /// The environment variable is set and not empty
+    #[serde(default)]
+    pub env: Option<String>,
+    /// The path exists below the project root
+    #[serde(default)]
+    pub exists: Option<String>,
+}
+
+/// `branch = "main"` or `branch = ["main", "release"]`.
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// What conditions are checked against.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineContext {
+    pub root: PathBuf,
+    pub branch: Option<String>,
+    pub env: HashMap<String, String>,
+}
+
+impl PipelineContext {
+    /// The current directory, its branch and environment.
+    pub fn current(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            branch: get_repository(&root.to_string_lossy())
+                .ok()
+                .map(|repo| get_current_branch(&repo)),
+            env: std::env::vars().collect(),
+        }
+    }
+}
+
+impl Condition {
+    pub fn holds(&self, context: &PipelineContext) -> bool {
+        let branch = self.branch.is_empty()
+            || context
+                .branch
+                .as_ref()
+                .is_some_and(|branch| self.branch.contains(branch));
+        let env = self
+            .env
+            .as_ref()
+            .is_none_or(|name| context.env.get(name).is_some_and(|value| !value.is_empty()));
+        let exists = self
+            .exists
+            .as_ref()
+            .is_none_or(|path| context.root.join(path).exists());
+        branch && env && exists
+    }
+
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.branch.is_empty() {
+            parts.push(format!("branch is {}", self.branch.join(" or ")));
+        }
+        if let Some(name) = &self.env {
+            parts.push(format!("{} is set", name));
+        }
+        if let Some(path) = &self.exists {
+            parts.push(format!("{} exists", path));
+        }
+        parts.join(" and ")
+    }
+}
+
+impl PipelineStep {
+    fn definition(&self) -> StepDefinition {
+        match self {
+            PipelineStep::Command(command) => StepDefinition {
+                command: command.clone(),
+                ..StepDefinition::default()
+            },
+            PipelineStep::Detailed(definition) => definition.clone(),
+        }
+    }
+
+    pub fn command(&self) -> &str {
+        match self {
+            PipelineStep::Command(command) => command,
+            PipelineStep::Detailed(definition) => &definition.command,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        self.definition()
+            .name
+            .unwrap_or_else(|| self.command().to_string())
+    }
+
+    /// Why the step doesn't run in `context`, `None` when it does.
+    pub fn skip_reason(&self, context: &PipelineContext) -> Option<String> {
+        let definition = self.definition();
+        if let Some(when) = definition.when.filter(|when| !when.holds(context)) {
+            return Some(format!("only when {}", when.describe()));
+        }
+        definition
+            .skip_if
+            .filter(|skip_if| skip_if.holds(context))
+            .map(|skip_if| skip_if.describe())
+    }
+
+    /// Arguments of the step; a leading `nitroterm` is optional.
+    pub fn args(&self) -> Result<Vec<String>> {
+        let mut args = split_command(self.command())?;
+        if args.first().is_some_and(|first| first == "nitroterm") {
+            args.remove(0);
+        }
+        if args.is_empty() {
+            return Err(anyhow!("Empty pipeline step"));
+        }
+        Ok(args)
+    }
+}
+
+/// Splits a command line on whitespace, keeping quoted parts together.
+pub fn split_command(line: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some('"') if c == '\\' => current.extend(chars.next()),
+            Some(_) => current.push(c),
+            None => match c {
+                '"' | '\'' => {
+                    quote = Some(c);
+                    in_arg = true;
+                }
+                '\\' => {
+                    current.extend(chars.next());
+                    in_arg = true;
+                }
+                c if c.is_whitespace() => {
+                    if in_arg {
+                        args.push(std::mem::take(&mut current));
+                        in_arg = false;
+                    }
+                }
+                c => {
+                    current.push(c);
+                    in_arg = true;
+                }
+            },
+        }
+    }
+    if quote.is_some() {
+        return Err(anyhow!("Unclosed quote in `{}`", line));
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+/// Where `.nitrokit.toml` is read from: `dir` when it has one, else the root
+/// of its repository.
+pub fn project_root(dir: &Path) -> PathBuf {
+    if dir.join(PROJECT_FILE).exists() {
+        return dir.to_path_buf();
+    }
+    get_repository(&dir.to_string_lossy())
+        .ok()
+        .and_then(|repo| repo.workdir().map(Path::to_path_buf))
+        .unwrap_or_else(|| dir.to_path_buf())
+}
+
+pub fn load_project_file(root: &Path) -> Result<Option<ProjectFile>> {
+    let path = root.join(PROJECT_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)?;
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| ConfigError::Invalid(format!("Invalid {}: {}", PROJECT_FILE, e)).into())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    /// Failed with `continue_on_error`
+    FailedAllowed,
+    Skipped(String),
+    /// An earlier step failed
+    NotRun,
+}
+
+#[derive(Debug, Clone)]
+pub struct StepResult {
+    pub label: String,
+    pub status: StepStatus,
+    pub duration: Duration,
+}
+
+/// Runs the steps one after another as `program` subcommands, with
+/// `global_args` in front of each. Stops at the first failing step unless
+/// it may fail.
+pub fn run_pipeline(
+    steps: &[PipelineStep],
+    context: &PipelineContext,
+    runner: &dyn CommandRunner,
+    program: &str,
+    global_args: &[&str],
+) -> Result<Vec<StepResult>> {
+    // Typos in a later step shouldn't show up after the first ones ran
+    let commands = steps
+        .iter()
+        .map(|step| step.args())
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut results = Vec::new();
+    let mut stopped = false;
+    for (index, (step, args)) in steps.iter().zip(commands).enumerate() {
+        let label = step.label();
+        let position = format!("[{}/{}]", index + 1, steps.len());
+        let started = Instant::now();
+        let status = if stopped {
+            StepStatus::NotRun
+        } else if let Some(reason) = step.skip_reason(context) {
+            println!(
+                "{}",
+                format!("⏭️  {} {} ({})", position, label, reason).dimmed()
+            );
+            StepStatus::Skipped(reason)
+        } else {
+            println!("{}", format!("▶️  {} {}", position, label).cyan().bold());
+            let args: Vec<&str> = global_args
+                .iter()
+                .copied()
+                .chain(args.iter().map(String::as_str))
+                .collect();
+            let success = runner
+                .run_attached(program, &args, Some(&context.root))
+                .map(|output| output.success)
+                .unwrap_or_else(|e| {
+                    eprintln!("{}", format!("❌ {}", e).red());
+                    false
+                });
+            match (success, step.definition().continue_on_error) {
+                (true, _) => StepStatus::Passed,
+                (false, true) => StepStatus::FailedAllowed,
+                (false, false) => {
+                    stopped = true;
+                    StepStatus::Failed
+                }
+            }
+        };
+        results.push(StepResult {
+            label,
+            status,
+            duration: started.elapsed(),
+        });
+    }
+    Ok(results)
+}
+
+fn print_summary(name: &str, results: &[StepResult]) {
+    println!();
+    println!("{}", format!("📋 Pipeline {}", name).cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+    for result in results {
+        let time = format!("{:.1}s", result.duration.as_secs_f64());
+        match &result.status {
+            StepStatus::Passed => println!("✅ {} {}", result.label, time.dimmed()),
+            StepStatus::Failed => println!("{} {}", format!("❌ {}", result.label).red(), time),
+            StepStatus::FailedAllowed => println!(
+                "{} {}",
+                format!("⚠️  {} (failure allowed)", result.label).yellow(),
+                time
+            ),
+            StepStatus::Skipped(reason) => {
+                println!("{}", format!("⏭️  {} ({})", result.label, reason).dimmed())
+            }
+            StepStatus::NotRun => {
+                println!("{}", format!("⏸️  {} (not run)", result.label).dimmed())
+            }
+        }
+    }
+}
+
+fn list_pipelines(project: &ProjectFile) {
+    if project.pipelines.is_empty() {
+        println!(
+            "{}",
+            format!("No pipelines defined in {}", PROJECT_FILE).yellow()
+        );
+        return;
+    }
+    println!("{}", "🚀 Pipelines".cyan().bold());
+    for (name, steps) in &project.pipelines {
+        let labels: Vec<String> = steps.iter().map(PipelineStep::label).collect();
+        println!("  {} {}", name.green().bold(), labels.join(" → ").dimmed());
+    }
+}
+
+/// `nitroterm run [pipeline]`: lists the pipelines of `.nitrokit.toml`, or
+/// runs one. With `dry_run` the steps are only listed with their skip
+/// decisions.
+pub fn run_command(name: Option<&str>, dry_run: bool) -> Result<()> {
+    let root = project_root(&std::env::current_dir()?);
+    let project = load_project_file(&root)?
+        .ok_or_else(|| anyhow!("No {} found in {}", PROJECT_FILE, root.display()))?;
+
+    let Some(name) = name else {
+        list_pipelines(&project);
+        return Ok(());
+    };
+    let steps = project.pipelines.get(name).ok_or_else(|| {
+        let names: Vec<&str> = project.pipelines.keys().map(String::as_str).collect();
+        anyhow!(
+            "Unknown pipeline '{}', {} defines: {}",
+            name,
+            PROJECT_FILE,
+            names.join(", ")
+        )
+    })?;
+
+    let running = std::env::var(PIPELINE_ENV).unwrap_or_default();
+    if running.split(',').any(|running| running == name) {
+        return Err(anyhow!(
+            "Pipeline '{}' runs itself (via {})",
+            name,
+            running.replace(',', " → ")
+        ));
+    }
+
+    let context = PipelineContext::current(&root);
+    if dry_run {
+        println!(
+            "{}",
+            format!("🚀 Pipeline {} (dry run)", name).cyan().bold()
+        );
+        for (index, step) in steps.iter().enumerate() {
+            let args = step.args()?;
+            match step.skip_reason(&context) {
+                Some(reason) => println!(
+                    "{}",
+                    format!("⏭️  [{}] {} ({})", index + 1, step.label(), reason).dimmed()
+                ),
+                None => println!("▶️  [{}] nitroterm {}", index + 1, args.join(" ")),
+            }
+        }
+        return Ok(());
+    }
+    // Steps run as child processes, which wouldn't inherit --sandbox
+    ensure_not_sandboxed("run")?;
+
+    let stack = if running.is_empty() {
+        name.to_string()
+    } else {
+        format!("{},{}", running, name)
+    };
+    std::env::set_var(PIPELINE_ENV, stack);
+
+    println!(
+        "{}",
+        format!("🚀 Pipeline {} ({} steps)", name, steps.len())
+            .cyan()
+            .bold()
+    );
+    let program = std::env::current_exe()?;
+    let mut global_args = vec!["--no-update-check"];
+    if prompt::assumes_yes() {
+        global_args.push("--yes");
+    } else if prompt::is_non_interactive() {
+        global_args.push("--non-interactive");
+    }
+    let started = Instant::now();
+    let results = run_pipeline(
+        steps,
+        &context,
+        &SystemRunner,
+        &program.to_string_lossy(),
+        &global_args,
+    )?;
+    print_summary(name, &results);
+
+    if let Some(failed) = results
+        .iter()
+        .position(|result| result.status == StepStatus::Failed)
+    {
+        return Err(anyhow!(
+            "Pipeline {} failed at step {} ({})",
+            name,
+            failed + 1,
+            results[failed].label
+        ));
+    }
+    println!(
+        "{}",
+        format!(
+            "✅ Pipeline {} finished in {:.1}s",
+            name,
+            started.elapsed().as_secs_f64()
+        )
+        .green()
+    );
+    Ok(())
+}
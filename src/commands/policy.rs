@@ -0,0 +1,385 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::org::{
+    collect_inventories, find_security_lag, OsvClient, Severity, DEFAULT_OSV_API_URL,
+};
+use crate::commands::release_risk::assess_release_risk;
+use crate::commands::translation_sync::get_sync_statistics;
+use crate::utils::error::ChecksFailed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const POLICY_FILE: &str = ".nitrokit/policy.json";
+
+/// Reports looked for when a coverage rule doesn't name one.
+const COVERAGE_REPORTS: [&str; 6] = [
+    "coverage/lcov.info",
+    "lcov.info",
+    "coverage/coverage-summary.json",
+    "coverage/cobertura.xml",
+    "cobertura.xml",
+    "coverage.xml",
+];
+
+pub const EXAMPLE_POLICY: &str = r#"{
+  "rules": [
+    { "name": "No critical vulnerabilities", "type": "vulnerabilities", "severity": "critical" },
+    { "name": "Test coverage", "type": "coverage", "min_percent": 80 },
+    { "name": "Translations complete", "type": "translations", "min_percent": 90 },
+    { "name": "Reviewable release size", "type": "release_risk", "max_score": 60 }
+  ]
+}
+"#;
+
+/// Release readiness rules, read from `.nitrokit/policy.json`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Policy {
+    pub rules: Vec<PolicyRule>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PolicyRule {
+    /// Shown in the output, defaults to a description of the check
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub check: PolicyCheck,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PolicyCheck {
+    /// No OSV advisory at or above `severity` in the locked dependencies
+    Vulnerabilities {
+        #[serde(default = "default_severity")]
+        severity: String,
+    },
+    /// Line coverage from an lcov, Cobertura or Istanbul summary report
+    Coverage {
+        min_percent: f64,
+        #[serde(default)]
+        report: Option<String>,
+    },
+    /// Every language at least `min_percent` translated
+    Translations {
+        min_percent: f64,
+        #[serde(default)]
+        messages_dir: Option<String>,
+        #[serde(default)]
+        source_file: Option<String>,
+    },
+    /// Release risk score since the last tag
+    ReleaseRisk { max_score: u32 },
+}
+
+fn default_severity() -> String {
+    "critical".to_string()
+}
+
+impl PolicyCheck {
+    pub fn describe(&self) -> String {
+        match self {
+            PolicyCheck::Vulnerabilities { severity } => {
+                format!("No {} vulnerabilities", severity)
+            }
+            PolicyCheck::Coverage { min_percent, .. } => format!("Coverage >= {}%", min_percent),
+            PolicyCheck::Translations { min_percent, .. } => {
+                format!("Translations >= {}% complete", min_percent)
+            }
+            PolicyCheck::ReleaseRisk { max_score } => format!("Release risk <= {}", max_score),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleStatus {
+    Passed,
+    Failed,
+    /// The rule couldn't be evaluated, which blocks a release as well
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleResult {
+    pub name: String,
+    pub status: RuleStatus,
+    pub detail: String,
+}
+
+impl RuleResult {
+    fn new(name: &str, status: RuleStatus, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail,
+        }
+    }
+}
+
+pub fn load_policy(root: &Path) -> Result<Option<Policy>> {
+    let path = root.join(POLICY_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let policy: Policy =
+        serde_json::from_str(&content).map_err(|e| anyhow!("Invalid {}: {}", POLICY_FILE, e))?;
+    Ok(Some(policy))
+}
+
+/// Line coverage percentage from an lcov (`LF`/`LH` records), Cobertura
+/// (`line-rate`) or Istanbul `coverage-summary.json` report.
+pub fn parse_coverage(file_name: &str, content: &str) -> Result<f64> {
+    if file_name.ends_with(".json") {
+        let json: serde_json::Value = serde_json::from_str(content)?;
+        return json
+            .pointer("/total/lines/pct")
+            .and_then(|pct| pct.as_f64())
+            .ok_or_else(|| anyhow!("No total.lines.pct in {}", file_name));
+    }
+
+    if file_name.ends_with(".xml") {
+        let regex = Regex::new(r#"<coverage[^>]*\sline-rate="([0-9.]+)""#).unwrap();
+        return regex
+            .captures(content)
+            .and_then(|captures| captures[1].parse::<f64>().ok())
+            .map(|rate| rate * 100.0)
+            .ok_or_else(|| anyhow!("No line-rate in {}", file_name));
+    }
+
+    let (mut found, mut hit) = (0u64, 0u64);
+    for line in content.lines() {
+        if let Some(value) = line.strip_prefix("LF:") {
+            found += value.trim().parse::<u64>().unwrap_or(0);
+        } else if let Some(value) = line.strip_prefix("LH:") {
+            hit += value.trim().parse::<u64>().unwrap_or(0);
+        }
+    }
+    if found == 0 {
+        return Err(anyhow!("No line records in {}", file_name));
+    }
+    Ok(hit as f64 / found as f64 * 100.0)
+}
+
+async fn check_vulnerabilities(
+    root: &Path,
+    osv: &OsvClient,
+    severity: &str,
+) -> Result<(RuleStatus, String)> {
+    let severity: Severity = severity.parse()?;
+    let projects = collect_inventories(&[root.display().to_string()]);
+    if let Some(error) = &projects[0].error {
+        return Err(anyhow!("{}", error));
+    }
+
+    let lagging = find_security_lag(osv, &projects, severity).await?;
+    if lagging.is_empty() {
+        return Ok((
+            RuleStatus::Passed,
+            format!("{} packages checked", projects[0].packages.len()),
+        ));
+    }
+
+    let packages: Vec<String> = lagging
+        .iter()
+        .map(|lag| {
+            let ids: Vec<&str> = lag.advisories.iter().map(|a| a.id.as_str()).collect();
+            format!(
+                "{} {} ({})",
+                lag.package.name,
+                lag.package.version,
+                ids.join(", ")
+            )
+        })
+        .collect();
+    Ok((RuleStatus::Failed, packages.join("; ")))
+}
+
+fn check_coverage(
+    root: &Path,
+    min_percent: f64,
+    report: Option<&str>,
+) -> Result<(RuleStatus, String)> {
+    let report = match report {
+        Some(report) => report.to_string(),
+        None => COVERAGE_REPORTS
+            .iter()
+            .find(|candidate| root.join(candidate).exists())
+            .map(|candidate| candidate.to_string())
+            .ok_or_else(|| anyhow!("No coverage report found, set `report` in the rule"))?,
+    };
+    let content = std::fs::read_to_string(root.join(&report))
+        .map_err(|e| anyhow!("Cannot read {}: {}", report, e))?;
+    let coverage = parse_coverage(&report, &content)?;
+
+    let status = if coverage >= min_percent {
+        RuleStatus::Passed
+    } else {
+        RuleStatus::Failed
+    };
+    Ok((status, format!("{:.1}% in {}", coverage, report)))
+}
+
+fn check_translations(
+    root: &Path,
+    config: &AppConfig,
+    min_percent: f64,
+    messages_dir: Option<&str>,
+    source_file: Option<&str>,
+) -> Result<(RuleStatus, String)> {
+    let messages_dir = root.join(messages_dir.unwrap_or(&config.messages_dir));
+    let source_file = source_file.unwrap_or(&config.source_file);
+    let statistics = get_sync_statistics(&messages_dir, source_file)?;
+
+    let behind: Vec<String> = statistics
+        .languages
+        .iter()
+        .filter(|language| language.progress.percentage() < min_percent)
+        .map(|language| format!("{} {:.1}%", language.code, language.progress.percentage()))
+        .collect();
+
+    if behind.is_empty() {
+        Ok((
+            RuleStatus::Passed,
+            format!("{} languages", statistics.languages.len()),
+        ))
+    } else {
+        Ok((RuleStatus::Failed, behind.join(", ")))
+    }
+}
+
+fn check_release_risk(root: &Path, max_score: u32) -> Result<(RuleStatus, String)> {
+    let report = assess_release_risk(root)?;
+    let status = if report.score <= max_score {
+        RuleStatus::Passed
+    } else {
+        RuleStatus::Failed
+    };
+    Ok((
+        status,
+        format!("score {} ({:?})", report.score, report.level),
+    ))
+}
+
+/// Evaluates every rule. A rule that can't be evaluated (missing report,
+/// unreachable OSV API) is reported as an error rather than skipped.
+pub async fn evaluate_policy(root: &Path, policy: &Policy, osv: &OsvClient) -> Vec<RuleResult> {
+    let config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+
+    let mut results = Vec::new();
+    for rule in &policy.rules {
+        let name = rule.name.clone().unwrap_or_else(|| rule.check.describe());
+        let outcome = match &rule.check {
+            PolicyCheck::Vulnerabilities { severity } => {
+                check_vulnerabilities(root, osv, severity).await
+            }
+            PolicyCheck::Coverage {
+                min_percent,
+                report,
+            } => check_coverage(root, *min_percent, report.as_deref()),
+            PolicyCheck::Translations {
+                min_percent,
+                messages_dir,
+                source_file,
+            } => check_translations(
+                root,
+                &config,
+                *min_percent,
+                messages_dir.as_deref(),
+                source_file.as_deref(),
+            ),
+            PolicyCheck::ReleaseRisk { max_score } => check_release_risk(root, *max_score),
+        };
+
+        results.push(match outcome {
+            Ok((status, detail)) => RuleResult::new(&name, status, detail),
+            Err(e) => RuleResult::new(&name, RuleStatus::Error, e.to_string()),
+        });
+    }
+    results
+}
+
+pub fn print_policy_results(results: &[RuleResult]) {
+    println!("\n{}", "📜 Release Policy".cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+    for result in results {
+        let (icon, name) = match result.status {
+            RuleStatus::Passed => ("✅", result.name.green()),
+            RuleStatus::Failed => ("❌", result.name.red()),
+            RuleStatus::Error => ("⚠️ ", result.name.yellow()),
+        };
+        println!(
+            "  {} {} {}",
+            icon,
+            name,
+            format!("— {}", result.detail).dimmed()
+        );
+    }
+
+    let blocking = results
+        .iter()
+        .filter(|result| result.status != RuleStatus::Passed)
+        .count();
+    if blocking == 0 {
+        println!(
+            "{}",
+            format!("\n✅ All {} rules passed", results.len()).green()
+        );
+    } else {
+        println!(
+            "{}",
+            format!("\n❌ {} of {} rules not satisfied", blocking, results.len()).red()
+        );
+    }
+}
+
+/// Evaluates the project's policy, if it has one, and fails when any rule
+/// does not pass.
+pub async fn enforce_policy(root: &Path) -> Result<()> {
+    let Some(policy) = load_policy(root)? else {
+        return Ok(());
+    };
+
+    let url = std::env::var("OSV_API_URL").unwrap_or_else(|_| DEFAULT_OSV_API_URL.to_string());
+    let results = evaluate_policy(root, &policy, &OsvClient::new(&url)?).await;
+    print_policy_results(&results);
+    if results
+        .iter()
+        .any(|result| result.status != RuleStatus::Passed)
+    {
+        return Err(ChecksFailed("Release policy not satisfied".to_string()).into());
+    }
+    Ok(())
+}
+
+pub async fn run_policy_check(root: &Path) -> Result<()> {
+    if load_policy(root)?.is_none() {
+        return Err(anyhow!(
+            "No {} found, create one with `nitroterm policy init`",
+            POLICY_FILE
+        ));
+    }
+    enforce_policy(root).await
+}
+
+pub fn init_policy(root: &Path) -> Result<()> {
+    let path = root.join(POLICY_FILE);
+    if path.exists() {
+        return Err(anyhow!("{} already exists", POLICY_FILE));
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, EXAMPLE_POLICY)?;
+    println!(
+        "{}",
+        format!("📄 Example policy written to {}", POLICY_FILE).green()
+    );
+    Ok(())
+}
@@ -0,0 +1,352 @@
+use crate::commands::release_notes::get_repository_info;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::{Repository, StatusOptions};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_PROVENANCE_FILE: &str = "provenance.intoto.json";
+pub const STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+pub const PREDICATE_TYPE: &str = "https://slsa.dev/provenance/v1";
+pub const BUILD_TYPE: &str = "https://nitrokit.tr/nitroterm/release/v1";
+
+/// Lockfiles recorded as build materials when present.
+pub const LOCKFILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "bun.lockb",
+    "poetry.lock",
+    "uv.lock",
+    "Pipfile.lock",
+    "go.sum",
+    "composer.lock",
+    "Gemfile.lock",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SigningMode {
+    /// Sigstore keyless signing through `cosign sign-blob` (OIDC identity)
+    Keyless,
+    /// `cosign sign-blob --key <key>`
+    Key,
+}
+
+impl std::str::FromStr for SigningMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "keyless" => Ok(SigningMode::Keyless),
+            "key" => Ok(SigningMode::Key),
+            _ => Err(anyhow!(
+                "Unknown signing mode '{}', use keyless or key",
+                value
+            )),
+        }
+    }
+}
+
+/// Who produced the build. On GitHub Actions this points at the workflow run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuilderInfo {
+    pub id: String,
+    pub invocation_id: Option<String>,
+}
+
+impl BuilderInfo {
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        if var("GITHUB_ACTIONS").as_deref() == Some("true") {
+            let server = var("GITHUB_SERVER_URL").unwrap_or_else(|| "https://github.com".into());
+            let id = match var("GITHUB_WORKFLOW_REF") {
+                Some(workflow) => format!("{}/{}", server, workflow),
+                None => format!("{}/actions", server),
+            };
+            let invocation_id = match (var("GITHUB_REPOSITORY"), var("GITHUB_RUN_ID")) {
+                (Some(repo), Some(run)) => Some(format!(
+                    "{}/{}/actions/runs/{}/attempts/{}",
+                    server,
+                    repo,
+                    run,
+                    var("GITHUB_RUN_ATTEMPT").unwrap_or_else(|| "1".into())
+                )),
+                _ => None,
+            };
+            return Self { id, invocation_id };
+        }
+
+        let host = var("HOSTNAME")
+            .or_else(|| var("COMPUTERNAME"))
+            .unwrap_or_else(|| "localhost".to_string());
+        Self {
+            id: format!("local://{}", host),
+            invocation_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProvenanceOptions {
+    pub tag: Option<String>,
+    pub output: Option<String>,
+    pub sign: Option<SigningMode>,
+    pub key: Option<String>,
+    pub upload: bool,
+}
+
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let mut file =
+        std::fs::File::open(path).map_err(|e| anyhow!("Cannot read {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Expands directories to the files directly inside them.
+pub fn collect_artifacts(paths: &[String]) -> Result<Vec<PathBuf>> {
+    let mut artifacts = Vec::new();
+    for path in paths.iter().map(PathBuf::from) {
+        if path.is_dir() {
+            let mut files: Vec<PathBuf> = std::fs::read_dir(&path)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| p.is_file())
+                .collect();
+            files.sort();
+            artifacts.extend(files);
+        } else if path.is_file() {
+            artifacts.push(path);
+        } else {
+            return Err(anyhow!("Artifact not found: {}", path.display()));
+        }
+    }
+    Ok(artifacts)
+}
+
+fn exact_tag(repo: &Repository, commit: git2::Oid) -> Option<String> {
+    let names = repo.tag_names(None).ok()?;
+    let tag = names.iter().flatten().find(|name| {
+        repo.revparse_single(&format!("refs/tags/{}", name))
+            .and_then(|object| object.peel_to_commit())
+            .map(|c| c.id() == commit)
+            .unwrap_or(false)
+    });
+    tag.map(|t| t.to_string())
+}
+
+fn is_dirty(repo: &Repository) -> bool {
+    let mut options = StatusOptions::new();
+    options.include_untracked(false);
+    repo.statuses(Some(&mut options))
+        .map(|statuses| !statuses.is_empty())
+        .unwrap_or(false)
+}
+
+/// Builds the in-toto statement with a SLSA v1 provenance predicate.
+pub fn build_statement(
+    root: &Path,
+    artifacts: &[PathBuf],
+    tag: Option<&str>,
+    builder: &BuilderInfo,
+) -> Result<Value> {
+    if artifacts.is_empty() {
+        return Err(anyhow!("No artifacts to attest"));
+    }
+    let started = chrono::Utc::now().to_rfc3339();
+
+    let repo = Repository::discover(root)
+        .map_err(|_| anyhow!("{} is not a git repository", root.display()))?;
+    let commit = repo.head()?.peel_to_commit()?;
+    let tag = tag
+        .map(|t| t.to_string())
+        .or_else(|| exact_tag(&repo, commit.id()));
+    let info = get_repository_info(&repo);
+    let source_uri = if info.is_github {
+        format!("git+https://github.com/{}/{}", info.owner, info.name)
+    } else if !info.url.is_empty() {
+        format!("git+{}", info.url)
+    } else {
+        format!("git+file://{}", root.canonicalize()?.display())
+    };
+    let source_ref = match &tag {
+        Some(tag) => format!("@refs/tags/{}", tag),
+        None => String::new(),
+    };
+
+    let mut dependencies = vec![json!({
+        "uri": format!("{}{}", source_uri, source_ref),
+        "digest": { "gitCommit": commit.id().to_string() },
+    })];
+    for lockfile in LOCKFILES {
+        let path = root.join(lockfile);
+        if path.is_file() {
+            dependencies.push(json!({
+                "name": lockfile,
+                "digest": { "sha256": sha256_file(&path)? },
+            }));
+        }
+    }
+
+    let subject = artifacts
+        .iter()
+        .map(|path| {
+            Ok(json!({
+                "name": path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+                "digest": { "sha256": sha256_file(path)? },
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut metadata = json!({
+        "startedOn": started,
+        "finishedOn": chrono::Utc::now().to_rfc3339(),
+    });
+    if let Some(invocation_id) = &builder.invocation_id {
+        metadata["invocationId"] = json!(invocation_id);
+    }
+
+    Ok(json!({
+        "_type": STATEMENT_TYPE,
+        "subject": subject,
+        "predicateType": PREDICATE_TYPE,
+        "predicate": {
+            "buildDefinition": {
+                "buildType": BUILD_TYPE,
+                "externalParameters": {
+                    "source": source_uri,
+                    "tag": tag,
+                },
+                "internalParameters": {
+                    "dirtyWorkingTree": is_dirty(&repo),
+                },
+                "resolvedDependencies": dependencies,
+            },
+            "runDetails": {
+                "builder": {
+                    "id": builder.id,
+                    "version": { "nitroterm": env!("CARGO_PKG_VERSION") },
+                },
+                "metadata": metadata,
+            },
+        },
+    }))
+}
+
+/// Signs the attestation with cosign and returns the files to publish with it.
+pub fn sign_attestation(path: &Path, mode: SigningMode, key: Option<&str>) -> Result<Vec<PathBuf>> {
+    if SystemRunner.run("cosign", &["version"], None).is_err() {
+        return Err(anyhow!(
+            "cosign is required for signing: https://docs.sigstore.dev/cosign/system_config/installation/"
+        ));
+    }
+
+    let path_str = path.to_string_lossy().to_string();
+    let (args, outputs): (Vec<String>, Vec<PathBuf>) = match mode {
+        SigningMode::Keyless => {
+            let bundle = format!("{}.bundle", path_str);
+            (
+                vec![
+                    "sign-blob".into(),
+                    "--yes".into(),
+                    "--bundle".into(),
+                    bundle.clone(),
+                    path_str,
+                ],
+                vec![PathBuf::from(bundle)],
+            )
+        }
+        SigningMode::Key => {
+            let key = key.ok_or_else(|| anyhow!("--key is required for key-based signing"))?;
+            let signature = format!("{}.sig", path_str);
+            (
+                vec![
+                    "sign-blob".into(),
+                    "--yes".into(),
+                    "--key".into(),
+                    key.to_string(),
+                    "--output-signature".into(),
+                    signature.clone(),
+                    path_str,
+                ],
+                vec![PathBuf::from(signature)],
+            )
+        }
+    };
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if !SystemRunner.run_attached("cosign", &args, None)?.success {
+        return Err(anyhow!("cosign sign-blob failed"));
+    }
+    Ok(outputs)
+}
+
+pub fn run_provenance(
+    path: Option<&str>,
+    artifacts: &[String],
+    options: ProvenanceOptions,
+) -> Result<()> {
+    ensure_not_sandboxed("provenance")?;
+    let root = Path::new(path.unwrap_or("."));
+    let output = PathBuf::from(options.output.as_deref().unwrap_or(DEFAULT_PROVENANCE_FILE));
+
+    println!("{}", "🔏 Generating build provenance...".cyan().bold());
+    let artifacts = collect_artifacts(artifacts)?;
+    let builder = BuilderInfo::detect();
+    let statement = build_statement(root, &artifacts, options.tag.as_deref(), &builder)?;
+
+    std::fs::write(&output, serde_json::to_string_pretty(&statement)?)?;
+    for subject in statement["subject"].as_array().into_iter().flatten() {
+        println!(
+            "  {} {}",
+            subject["digest"]["sha256"]
+                .as_str()
+                .unwrap_or_default()
+                .get(..12)
+                .unwrap_or_default()
+                .dimmed(),
+            subject["name"].as_str().unwrap_or_default()
+        );
+    }
+    println!("  {} {}", "Builder:".dimmed(), builder.id);
+    println!(
+        "{}",
+        format!("✅ Attestation written to {}", output.display()).green()
+    );
+
+    let mut files = vec![output.clone()];
+    if let Some(mode) = options.sign {
+        let signatures = sign_attestation(&output, mode, options.key.as_deref())?;
+        for signature in &signatures {
+            println!(
+                "{}",
+                format!("🔐 Signature written to {}", signature.display()).green()
+            );
+        }
+        files.extend(signatures);
+    }
+
+    if options.upload {
+        let tag = statement["predicate"]["buildDefinition"]["externalParameters"]["tag"]
+            .as_str()
+            .ok_or_else(|| anyhow!("HEAD is not tagged, pass --tag to upload"))?;
+        let files: Vec<String> = files
+            .iter()
+            .map(|file| file.to_string_lossy().to_string())
+            .collect();
+        let mut args = vec!["release", "upload", tag, "--clobber"];
+        args.extend(files.iter().map(String::as_str));
+        if !SystemRunner.run_attached("gh", &args, Some(root))?.success {
+            return Err(anyhow!("gh release upload failed for {}", tag));
+        }
+        println!(
+            "{}",
+            format!("📤 Attached {} file(s) to release {}", files.len(), tag).green()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,362 @@
+use crate::commands::code_quality::CheckResult;
+use crate::utils::document_locale::timezone;
+use crate::utils::log_warning;
+use crate::utils::output::{is_json_output, print_json};
+use crate::utils::theme::{label, paint, Role};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use colored::*;
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+pub const HISTORY_FILE: &str = ".nitrokit/quality-history.jsonl";
+
+/// Runs shown by `code-quality history` without `--limit`.
+pub const DEFAULT_HISTORY_LIMIT: usize = 10;
+
+/// A check counts as slower when it took this much longer than the baseline
+/// (ratio and absolute milliseconds), so jitter on quick checks isn't flagged.
+const SLOWDOWN_RATIO: f64 = 1.5;
+const SLOWDOWN_MIN_MS: u128 = 1000;
+
+/// One check of a recorded run, without its output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRecord {
+    pub name: String,
+    pub success: bool,
+    pub duration_ms: u128,
+}
+
+/// One `code-quality` run, a line of `.nitrokit/quality-history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityRun {
+    /// 1 for the first recorded run, counting up
+    pub id: usize,
+    /// Unix timestamp of the run
+    pub at: i64,
+    /// HEAD at the time of the run
+    pub commit: Option<String>,
+    pub checks: Vec<CheckRecord>,
+}
+
+impl QualityRun {
+    pub fn new(id: usize, at: i64, commit: Option<String>, results: &[CheckResult]) -> Self {
+        Self {
+            id,
+            at,
+            commit,
+            checks: results
+                .iter()
+                .map(|result| CheckRecord {
+                    name: result.check_name.clone(),
+                    success: result.success,
+                    duration_ms: result.duration_ms,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn passed(&self) -> usize {
+        self.checks.iter().filter(|check| check.success).count()
+    }
+
+    /// Share of passed checks in percent, 100 for a run without checks.
+    pub fn pass_rate(&self) -> f64 {
+        if self.checks.is_empty() {
+            return 100.0;
+        }
+        self.passed() as f64 * 100.0 / self.checks.len() as f64
+    }
+
+    pub fn duration_ms(&self) -> u128 {
+        self.checks.iter().map(|check| check.duration_ms).sum()
+    }
+
+    pub fn check(&self, name: &str) -> Option<&CheckRecord> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+
+    pub fn short_commit(&self) -> String {
+        self.commit
+            .as_deref()
+            .map(|commit| commit.chars().take(7).collect())
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+/// Recorded runs, oldest first. Lines that don't parse are skipped.
+pub fn load_history(root: &Path) -> Result<Vec<QualityRun>> {
+    let content = match fs::read_to_string(root.join(HISTORY_FILE)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(anyhow!("Could not read {}: {}", HISTORY_FILE, e)),
+    };
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Appends the results as the next run and returns it.
+pub fn append_run(
+    root: &Path,
+    results: &[CheckResult],
+    at: i64,
+    commit: Option<String>,
+) -> Result<QualityRun> {
+    let id = load_history(root)?.last().map_or(1, |run| run.id + 1);
+    let run = QualityRun::new(id, at, commit, results);
+
+    let path = root.join(HISTORY_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{}", serde_json::to_string(&run)?)?;
+    Ok(run)
+}
+
+/// Records a finished `code-quality` run with the current HEAD. Failures only
+/// warn, the history never fails the checks themselves.
+pub fn record_quality_run(root: &Path, results: &[CheckResult]) {
+    let commit = Repository::discover(root)
+        .ok()
+        .and_then(|repo| repo.head().ok()?.target())
+        .map(|oid| oid.to_string());
+    if let Err(e) = append_run(root, results, Utc::now().timestamp(), commit) {
+        log_warning(&format!("Could not update {}: {}", HISTORY_FILE, e));
+    }
+}
+
+/// A check that got notably slower than in the baseline run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Slowdown {
+    pub name: String,
+    pub before_ms: u128,
+    pub after_ms: u128,
+}
+
+/// What changed between two runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RunComparison {
+    pub baseline: usize,
+    pub current: usize,
+    /// Failing now, passing (or missing) in the baseline
+    pub newly_failing: Vec<String>,
+    /// Passing now, failing in the baseline
+    pub fixed: Vec<String>,
+    pub slower: Vec<Slowdown>,
+    /// Percentage points, negative when fewer checks pass
+    pub pass_rate_change: f64,
+}
+
+impl RunComparison {
+    pub fn has_regressions(&self) -> bool {
+        !self.newly_failing.is_empty() || !self.slower.is_empty()
+    }
+}
+
+pub fn compare_runs(baseline: &QualityRun, current: &QualityRun) -> RunComparison {
+    let mut comparison = RunComparison {
+        baseline: baseline.id,
+        current: current.id,
+        pass_rate_change: current.pass_rate() - baseline.pass_rate(),
+        ..RunComparison::default()
+    };
+    for check in &current.checks {
+        let before = baseline.check(&check.name);
+        match before {
+            Some(before) if !before.success && check.success => {
+                comparison.fixed.push(check.name.clone())
+            }
+            Some(before) if before.success == check.success => {}
+            _ if !check.success => comparison.newly_failing.push(check.name.clone()),
+            _ => {}
+        }
+        if let Some(before) = before {
+            let slower = check.duration_ms as f64 > before.duration_ms as f64 * SLOWDOWN_RATIO
+                && check.duration_ms >= before.duration_ms + SLOWDOWN_MIN_MS;
+            if slower {
+                comparison.slower.push(Slowdown {
+                    name: check.name.clone(),
+                    before_ms: before.duration_ms,
+                    after_ms: check.duration_ms,
+                });
+            }
+        }
+    }
+    comparison
+}
+
+/// The run `reference` names: a run id, else the latest run at the commit
+/// a git ref (`main`, `v1.2.0`, `HEAD~3`) points to.
+pub fn find_run<'a>(
+    runs: &'a [QualityRun],
+    reference: &str,
+    repo: Option<&Repository>,
+) -> Result<&'a QualityRun> {
+    if let Ok(id) = reference.trim_start_matches('#').parse::<usize>() {
+        if let Some(run) = runs.iter().find(|run| run.id == id) {
+            return Ok(run);
+        }
+    }
+    let repo = repo.ok_or_else(|| anyhow!("No run #{} and not a git repository", reference))?;
+    let commit = repo
+        .revparse_single(reference)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|_| anyhow!("'{}' is neither a run id nor a git ref", reference))?
+        .id()
+        .to_string();
+    runs.iter()
+        .rev()
+        .find(|run| run.commit.as_deref() == Some(commit.as_str()))
+        .ok_or_else(|| {
+            anyhow!(
+                "No code-quality run recorded at {} ({})",
+                reference,
+                &commit[..7]
+            )
+        })
+}
+
+fn format_duration(ms: u128) -> String {
+    if ms < 1000 {
+        format!("{}ms", ms)
+    } else {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    }
+}
+
+fn format_date(timestamp: i64) -> String {
+    timezone()
+        .at(timestamp)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Check names in the order they first appear, across the given runs.
+fn check_names(runs: &[QualityRun]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for check in runs.iter().flat_map(|run| &run.checks) {
+        if !names.contains(&check.name) {
+            names.push(check.name.clone());
+        }
+    }
+    names
+}
+
+fn print_comparison(comparison: &RunComparison) {
+    println!(
+        "\n{}",
+        paint(
+            &format!(
+                "🔍 Run #{} compared to #{}",
+                comparison.current, comparison.baseline
+            ),
+            Role::Accent
+        )
+        .bold()
+    );
+    println!("  Pass rate: {:+.0} points", comparison.pass_rate_change);
+    for name in &comparison.newly_failing {
+        println!("  {} now failing", label(name, Role::Error));
+    }
+    for slowdown in &comparison.slower {
+        println!(
+            "  {} slower: {} → {}",
+            label(&slowdown.name, Role::Warning),
+            format_duration(slowdown.before_ms),
+            format_duration(slowdown.after_ms)
+        );
+    }
+    for name in &comparison.fixed {
+        println!("  {} fixed", label(name, Role::Success));
+    }
+    if !comparison.has_regressions() {
+        println!("  {}", "✅ No regressions".green());
+    }
+}
+
+fn print_history(runs: &[QualityRun], shown: &[QualityRun], comparison: Option<&RunComparison>) {
+    println!("\n{}", "📈 Code quality history".cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+    println!(
+        "  {:<5} {:<17} {:<8} {:>9} {:>9}  Newly failing",
+        "Run", "Date", "Commit", "Passed", "Time"
+    );
+    for run in shown {
+        let previous = runs.iter().rev().find(|other| other.id < run.id);
+        let newly_failing = previous
+            .map(|previous| compare_runs(previous, run).newly_failing)
+            .unwrap_or_default();
+        let passed = format!("{}/{}", run.passed(), run.checks.len());
+        let passed = if run.passed() == run.checks.len() {
+            paint(&format!("{:>9}", passed), Role::Success)
+        } else {
+            paint(&format!("{:>9}", passed), Role::Error)
+        };
+        println!(
+            "  {:<5} {:<17} {:<8} {} {:>9}  {}",
+            format!("#{}", run.id),
+            format_date(run.at),
+            run.short_commit(),
+            passed,
+            format_duration(run.duration_ms()),
+            newly_failing.join(", ").red()
+        );
+    }
+
+    println!("\n{}", "⏱️  Duration per check (oldest → newest)".bold());
+    for name in check_names(shown) {
+        let durations: Vec<String> = shown
+            .iter()
+            .map(|run| match run.check(&name) {
+                Some(check) if check.success => format_duration(check.duration_ms),
+                Some(check) => format!("{}✖", format_duration(check.duration_ms)),
+                None => "-".to_string(),
+            })
+            .collect();
+        println!("  {:<20} {}", name, durations.join(" → ").dimmed());
+    }
+
+    if let Some(comparison) = comparison {
+        print_comparison(comparison);
+    }
+}
+
+/// `code-quality history`: the last `limit` runs recorded at `path`, with
+/// pass rate, duration per check and newly failing checks. `compare_to` (a
+/// run id or git ref) compares the latest run against that one.
+pub fn run_quality_history(path: &Path, limit: usize, compare_to: Option<&str>) -> Result<()> {
+    let runs = load_history(path)?;
+    let Some(latest) = runs.last() else {
+        println!(
+            "{}",
+            "ℹ️  No code-quality runs recorded yet, run `nitroterm code-quality` first".yellow()
+        );
+        return Ok(());
+    };
+    let shown = &runs[runs.len().saturating_sub(limit.max(1))..];
+
+    let comparison = match compare_to {
+        Some(reference) => {
+            let repo = Repository::discover(path).ok();
+            let baseline = find_run(&runs, reference, repo.as_ref())?;
+            Some(compare_runs(baseline, latest))
+        }
+        None => None,
+    };
+
+    if is_json_output() {
+        return print_json(&serde_json::json!({
+            "runs": shown,
+            "comparison": comparison,
+        }));
+    }
+    print_history(&runs, shown, comparison.as_ref());
+    Ok(())
+}
@@ -0,0 +1,467 @@
+use crate::commands::config::AppConfig;
+use crate::commands::github_api::GitHubApiClient;
+use crate::utils::sandbox::ensure_network_allowed;
+use crate::utils::secrets;
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub const GITLAB_API_URL: &str = "https://gitlab.com/api/v4";
+pub const BITBUCKET_API_URL: &str = "https://api.bitbucket.org/2.0";
+
+/// Config entries of the stored host credentials, also their names in the
+/// OS keychain.
+pub const GITLAB_TOKEN_NAME: &str = "gitlab_token";
+pub const BITBUCKET_PASSWORD_NAME: &str = "bitbucket_app_password";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseHost {
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+impl ReleaseHost {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReleaseHost::GitHub => "GitHub",
+            ReleaseHost::GitLab => "GitLab",
+            ReleaseHost::Bitbucket => "Bitbucket",
+        }
+    }
+}
+
+impl std::str::FromStr for ReleaseHost {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "github" => Ok(ReleaseHost::GitHub),
+            "gitlab" => Ok(ReleaseHost::GitLab),
+            "bitbucket" => Ok(ReleaseHost::Bitbucket),
+            _ => Err(anyhow!(
+                "Unknown release host '{}', use github, gitlab or bitbucket",
+                name
+            )),
+        }
+    }
+}
+
+/// Where a release gets published: the host and the project path on it
+/// (`owner/name`, or `group/subgroup/name` on GitLab).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseTarget {
+    pub host: ReleaseHost,
+    pub project: String,
+}
+
+/// Host and path of a git remote URL (`https://`, `ssh://` or scp-like
+/// `git@host:path`).
+fn split_remote(url: &str) -> Option<(String, String)> {
+    let url = url.trim().trim_end_matches('/').trim_end_matches(".git");
+    let (host, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?,
+        None => url.split_once(':')?,
+    };
+    // Drop `user@` and `:port`
+    let host = host.rsplit('@').next()?.split(':').next()?;
+    let path = path.trim_matches('/');
+    if host.is_empty() || !path.contains('/') {
+        return None;
+    }
+    Some((host.to_lowercase(), path.to_string()))
+}
+
+/// The release target of `remote_url`. GitLab instances other than
+/// gitlab.com are recognized through the configured `gitlab_url`.
+pub fn detect_release_target(remote_url: &str, gitlab_url: Option<&str>) -> Option<ReleaseTarget> {
+    let (host, path) = split_remote(remote_url)?;
+    let self_hosted = gitlab_url
+        .and_then(|url| reqwest::Url::parse(url).ok())
+        .and_then(|url| url.host_str().map(str::to_lowercase));
+
+    let host = if host == "github.com" {
+        ReleaseHost::GitHub
+    } else if host == "gitlab.com" || self_hosted.as_deref() == Some(host.as_str()) {
+        ReleaseHost::GitLab
+    } else if host == "bitbucket.org" {
+        ReleaseHost::Bitbucket
+    } else {
+        return None;
+    };
+    // Only GitLab nests projects in groups
+    if host != ReleaseHost::GitLab && path.matches('/').count() != 1 {
+        return None;
+    }
+    Some(ReleaseTarget {
+        host,
+        project: path,
+    })
+}
+
+/// How requests to the host are authenticated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostAuth {
+    Bearer(String),
+    /// GitLab's `PRIVATE-TOKEN` or `JOB-TOKEN` header
+    Header(&'static str, String),
+    Basic {
+        username: String,
+        password: String,
+    },
+}
+
+impl HostAuth {
+    /// Credentials for `host`: the environment first, then the config.
+    /// GitLab takes `GITLAB_TOKEN`, the stored token, then `CI_JOB_TOKEN`;
+    /// Bitbucket takes `BITBUCKET_ACCESS_TOKEN`, then an app password with
+    /// its username from `BITBUCKET_USERNAME`/`BITBUCKET_APP_PASSWORD` or
+    /// the config.
+    pub fn resolve(
+        host: ReleaseHost,
+        config: &AppConfig,
+        env: &HashMap<String, String>,
+    ) -> Result<Self> {
+        let var = |name: &str| env.get(name).filter(|value| !value.is_empty()).cloned();
+        match host {
+            ReleaseHost::GitHub => var("GITHUB_TOKEN")
+                .or_else(|| var("GH_TOKEN"))
+                .map(HostAuth::Bearer)
+                .ok_or_else(|| anyhow!("GITHUB_TOKEN or GH_TOKEN is required to publish releases")),
+            ReleaseHost::GitLab => {
+                if let Some(token) = var("GITLAB_TOKEN") {
+                    return Ok(HostAuth::Header("PRIVATE-TOKEN", token));
+                }
+                if let Some(stored) = &config.gitlab_token {
+                    let token = secrets::reveal(GITLAB_TOKEN_NAME, stored)?;
+                    return Ok(HostAuth::Header("PRIVATE-TOKEN", token));
+                }
+                var("CI_JOB_TOKEN")
+                    .map(|token| HostAuth::Header("JOB-TOKEN", token))
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "GITLAB_TOKEN or `nitroterm config release-host gitlab` is required to publish releases"
+                        )
+                    })
+            }
+            ReleaseHost::Bitbucket => {
+                if let Some(token) = var("BITBUCKET_ACCESS_TOKEN") {
+                    return Ok(HostAuth::Bearer(token));
+                }
+                let username =
+                    var("BITBUCKET_USERNAME").or_else(|| config.bitbucket_username.clone());
+                let password = match var("BITBUCKET_APP_PASSWORD") {
+                    Some(password) => Some(password),
+                    None => match &config.bitbucket_app_password {
+                        Some(stored) => Some(secrets::reveal(BITBUCKET_PASSWORD_NAME, stored)?),
+                        None => None,
+                    },
+                };
+                match (username, password) {
+                    (Some(username), Some(password)) => Ok(HostAuth::Basic { username, password }),
+                    _ => Err(anyhow!(
+                        "BITBUCKET_ACCESS_TOKEN, BITBUCKET_USERNAME with BITBUCKET_APP_PASSWORD, or `nitroterm config release-host bitbucket` is required to publish releases"
+                    )),
+                }
+            }
+        }
+    }
+
+    fn apply(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self {
+            HostAuth::Bearer(token) => request.bearer_auth(token),
+            HostAuth::Header(name, token) => request.header(*name, token),
+            HostAuth::Basic { username, password } => request.basic_auth(username, Some(password)),
+        }
+    }
+}
+
+/// API root of `host`. GitLab CI's `CI_API_V4_URL` and the configured
+/// `gitlab_url` point GitLab at self-hosted instances.
+pub fn api_url(host: ReleaseHost, config: &AppConfig, env: &HashMap<String, String>) -> String {
+    let var = |name: &str| env.get(name).filter(|value| !value.is_empty()).cloned();
+    let url = match host {
+        ReleaseHost::GitHub => {
+            var("GITHUB_API_URL").unwrap_or_else(|| "https://api.github.com".to_string())
+        }
+        ReleaseHost::GitLab => var("CI_API_V4_URL")
+            .or_else(|| {
+                config
+                    .gitlab_url
+                    .as_ref()
+                    .map(|url| format!("{}/api/v4", url.trim_end_matches('/')))
+            })
+            .unwrap_or_else(|| GITLAB_API_URL.to_string()),
+        ReleaseHost::Bitbucket => {
+            var("BITBUCKET_API_URL").unwrap_or_else(|| BITBUCKET_API_URL.to_string())
+        }
+    };
+    url.trim_end_matches('/').to_string()
+}
+
+/// File name of the release notes uploaded to Bitbucket Downloads, which
+/// has no release objects.
+pub fn release_notes_file_name(project: &str, tag: &str) -> String {
+    let name = project.rsplit('/').next().unwrap_or(project);
+    format!("{}-{}-release-notes.md", name, tag)
+}
+
+/// A file attached to a release next to the notes, such as the SBOM.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub content: Vec<u8>,
+}
+
+impl ReleaseAsset {
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| anyhow!("Invalid asset path: {}", path.display()))?;
+        let content =
+            std::fs::read(path).map_err(|e| anyhow!("Could not read {}: {}", path.display(), e))?;
+        Ok(Self { name, content })
+    }
+}
+
+/// Media type sent with an uploaded file, from its extension.
+pub fn content_type(file_name: &str) -> &'static str {
+    if file_name.ends_with(".md") {
+        "text/markdown"
+    } else if file_name.ends_with(".json") {
+        "application/json"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn multipart_boundary() -> String {
+    format!(
+        "nitroterm-{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default()
+    )
+}
+
+/// A `multipart/form-data` body with one file field.
+pub fn multipart_body(boundary: &str, field: &str, file_name: &str, content: &[u8]) -> Vec<u8> {
+    let mut body = format!(
+        "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+        boundary,
+        field,
+        file_name.replace('"', ""),
+        content_type(file_name)
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+/// Publishes releases on GitLab (Releases API) and Bitbucket (Downloads);
+/// GitHub goes through `GitHubApiClient`.
+pub struct ReleasePublisher {
+    client: reqwest::Client,
+    pub target: ReleaseTarget,
+    pub api_url: String,
+    auth: HostAuth,
+}
+
+impl ReleasePublisher {
+    pub fn new(target: ReleaseTarget, api_url: &str, auth: HostAuth) -> Result<Self> {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .user_agent(concat!("nitroterm/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self {
+            client,
+            target,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            auth,
+        })
+    }
+
+    pub fn from_config(target: ReleaseTarget, config: &AppConfig) -> Result<Self> {
+        let env: HashMap<String, String> = std::env::vars().collect();
+        let auth = HostAuth::resolve(target.host, config, &env)?;
+        let api_url = api_url(target.host, config, &env);
+        Self::new(target, &api_url, auth)
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value> {
+        let response = self.auth.apply(request).send().await?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let json: Value = serde_json::from_str(&body).unwrap_or(Value::Null);
+        if !status.is_success() {
+            // GitLab answers `message`, Bitbucket `error.message`
+            let message = json["message"]
+                .as_str()
+                .or_else(|| json["error"]["message"].as_str())
+                .map(str::to_string)
+                .unwrap_or(body);
+            return Err(anyhow!(
+                "{} API returned {}: {}",
+                self.target.host.display_name(),
+                status,
+                message
+            ));
+        }
+        Ok(json)
+    }
+
+    /// Publishes the release of `tag` with `assets` attached (GitHub release
+    /// assets, GitLab uploads linked from the release, files in Bitbucket
+    /// Downloads) and returns its web URL.
+    pub async fn publish(&self, tag: &str, notes: &str, assets: &[ReleaseAsset]) -> Result<String> {
+        match self.target.host {
+            ReleaseHost::GitHub => {
+                let (owner, repo) = self
+                    .target
+                    .project
+                    .split_once('/')
+                    .ok_or_else(|| anyhow!("Invalid repository '{}'", self.target.project))?;
+                let HostAuth::Bearer(token) = &self.auth else {
+                    return Err(anyhow!("GitHub releases need a token"));
+                };
+                let mut client = GitHubApiClient::new(owner, repo, token)?;
+                client.api_url = self.api_url.clone();
+                client.create_release(tag, notes, assets).await
+            }
+            ReleaseHost::GitLab => {
+                let url = self.publish_gitlab(tag, notes).await?;
+                for asset in assets {
+                    self.attach_gitlab_asset(tag, asset).await?;
+                }
+                Ok(url)
+            }
+            ReleaseHost::Bitbucket => {
+                let url = self
+                    .upload_bitbucket_download(
+                        &release_notes_file_name(&self.target.project, tag),
+                        notes.as_bytes(),
+                    )
+                    .await?;
+                for asset in assets {
+                    self.upload_bitbucket_download(&asset.name, &asset.content)
+                        .await?;
+                }
+                Ok(url)
+            }
+        }
+    }
+
+    /// `.../projects/:id/<path>`, the project id being its percent-encoded
+    /// path.
+    fn gitlab_project_url(&self, path: &[&str]) -> Result<reqwest::Url> {
+        let mut url = reqwest::Url::parse(&self.api_url)?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Invalid GitLab API URL: {}", self.api_url))?
+            .pop_if_empty()
+            .extend(["projects", &self.target.project])
+            .extend(path);
+        Ok(url)
+    }
+
+    /// `POST /projects/:id/releases`.
+    async fn publish_gitlab(&self, tag: &str, notes: &str) -> Result<String> {
+        let url = self.gitlab_project_url(&["releases"])?;
+
+        let body = json!({
+            "tag_name": tag,
+            "name": tag,
+            "description": notes,
+        });
+        let release = self.send(self.client.post(url).json(&body)).await?;
+        Ok(release["_links"]["self"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}/-/releases/{}", self.web_url(), tag)))
+    }
+
+    /// Uploads the file to the project (`POST /projects/:id/uploads`) and
+    /// links it from the release of `tag`.
+    async fn attach_gitlab_asset(&self, tag: &str, asset: &ReleaseAsset) -> Result<()> {
+        let boundary = multipart_boundary();
+        let request = self
+            .client
+            .post(self.gitlab_project_url(&["uploads"])?)
+            .header(
+                "Content-Type",
+                format!("multipart/form-data; boundary={}", boundary),
+            )
+            .body(multipart_body(
+                &boundary,
+                "file",
+                &asset.name,
+                &asset.content,
+            ));
+        let upload = self.send(request).await?;
+        // `full_path` is relative to the instance, the older `url` to the project
+        let file_url = match (upload["full_path"].as_str(), upload["url"].as_str()) {
+            (Some(path), _) => format!("{}{}", self.instance_url(), path),
+            (None, Some(path)) => format!("{}{}", self.web_url(), path),
+            (None, None) => return Err(anyhow!("GitLab returned no URL for {}", asset.name)),
+        };
+
+        let body = json!({
+            "name": asset.name,
+            "url": file_url,
+            "link_type": "other",
+        });
+        self.send(
+            self.client
+                .post(self.gitlab_project_url(&["releases", tag, "assets", "links"])?)
+                .json(&body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Uploads a file to the repository's Downloads and returns its URL.
+    async fn upload_bitbucket_download(&self, file_name: &str, content: &[u8]) -> Result<String> {
+        let mut url = reqwest::Url::parse(&self.api_url)?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Invalid Bitbucket API URL: {}", self.api_url))?
+            .pop_if_empty()
+            .push("repositories")
+            .extend(self.target.project.split('/'))
+            .push("downloads");
+
+        let boundary = multipart_boundary();
+        let request = self
+            .client
+            .post(url)
+            .header(
+                "Content-Type",
+                format!("multipart/form-data; boundary={}", boundary),
+            )
+            .body(multipart_body(&boundary, "files", file_name, content));
+        self.send(request).await?;
+        Ok(format!("{}/downloads/{}", self.web_url(), file_name))
+    }
+
+    /// The instance's web root, derived from the API URL.
+    fn instance_url(&self) -> String {
+        self.api_url
+            .trim_end_matches("/api/v4")
+            .trim_end_matches("/2.0")
+            .to_string()
+    }
+
+    /// The project's page, derived from the API URL.
+    fn web_url(&self) -> String {
+        let base = match self.target.host {
+            ReleaseHost::Bitbucket if self.api_url == BITBUCKET_API_URL => {
+                "https://bitbucket.org".to_string()
+            }
+            _ => self.instance_url(),
+        };
+        format!("{}/{}", base, self.target.project)
+    }
+}
@@ -1,68 +1,211 @@
-use crate::utils::{get_repository, log_error, log_info, log_success, write_string_to_file};
+use crate::commands::codeowners::{CodeOwners, TeamSummary};
+use crate::commands::commit_dedup::{RangeCleanup, RevertedChange};
+use crate::commands::commit_filter::CommitFilter;
+use crate::commands::github_api::GitHubApiClient;
+use crate::commands::release_notes_template::{render_template_file, template_context};
+use crate::commands::state::{record_run, Tracked};
+use crate::utils::document_locale::{timezone, DocumentLocale};
+use crate::utils::error::ExitCode;
+use crate::utils::git::{submodule_changes, SubmoduleChange};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::progress::StatusLine;
+use crate::utils::{log_error, log_info, log_success, write_string_to_file};
 use anyhow::Result;
 use chrono::TimeZone;
 use colored::*;
 use git2::Repository;
-use std::collections::HashMap;
-use std::process::Command;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// How release notes are rendered, shared by `release-notes` and `retag`.
+#[derive(Debug, Clone, Copy)]
+pub struct NotesOptions<'a> {
+    /// Headings, dates and numbers
+    pub locale: DocumentLocale,
+    /// Look up PR and issue titles and authors on GitHub
+    pub resolve_links: bool,
+    /// Handlebars template replacing the built-in layout
+    pub template: Option<&'a Path>,
+    pub limits: CommitLimits,
+    /// Commits left out
+    pub filter: &'a CommitFilter,
+}
+
+/// Why `release-notes` couldn't write the notes.
+#[derive(Debug, thiserror::Error)]
+pub enum ReleaseNotesError {
+    #[error("Not a git repository or git error: {0}")]
+    NotARepository(#[source] git2::Error),
+    #[error("Failed to write release notes: {0}")]
+    Write(#[source] std::io::Error),
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl ReleaseNotesError {
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            ReleaseNotesError::NotARepository(_) => ExitCode::Config,
+            ReleaseNotesError::Write(_) => ExitCode::Failure,
+            ReleaseNotesError::Other(error) => crate::utils::error::exit_code(error),
+        }
+    }
+}
 
-pub fn generate_release_notes() {
+/// Writes release notes with headings, dates and numbers in `locale`. With
+/// `resolve_links`, PR and issue numbers on GitHub are looked up for titles
+/// and authors. A Handlebars `template` replaces the built-in layout.
+/// `limits` keeps huge ranges readable; progress is shown while walking them.
+/// Commits matching `filter` are left out. `for_tag` documents a past
+/// release instead of the latest one. Returns the file written to `dir`,
+/// None in sandbox mode where the notes are printed instead.
+pub async fn generate_release_notes(
+    dir: &Path,
+    locale: DocumentLocale,
+    resolve_links: bool,
+    template: Option<&Path>,
+    limits: CommitLimits,
+    filter: &CommitFilter,
+    for_tag: Option<&str>,
+) -> Result<Option<PathBuf>, ReleaseNotesError> {
     log_info("Starting release notes generation...");
 
-    match get_repository(".") {
-        Ok(repo) => {
-            log_info("Repository found, analyzing commits...");
+    let repo = Repository::discover(dir).map_err(ReleaseNotesError::NotARepository)?;
+    log_info("Repository found, analyzing commits...");
 
-            // Get repository information
-            let repo_info = get_repository_info(&repo);
-            log_info(&format!("Repository: {}", repo_info.url.cyan()));
+    let (current_tag, previous_tag) = tag_range(&repo, for_tag)?;
+    let options = NotesOptions {
+        locale,
+        resolve_links,
+        template,
+        limits,
+        filter,
+    };
+    let release_notes = render_release_notes(&repo, &current_tag, &previous_tag, &options).await?;
 
-            // Get latest tag or create default
-            let (current_tag, previous_tag) = get_tag_range(&repo);
-            log_info(&format!(
-                "Generating release notes for tag: {}",
-                current_tag.cyan()
-            ));
+    if crate::utils::sandbox::is_sandboxed() {
+        log_info("Sandbox mode: printing release notes instead of writing a file");
+        println!("\n{}", release_notes);
+        return Ok(None);
+    }
 
-            if let Some(ref prev_tag) = previous_tag {
-                log_info(&format!("Comparing with previous tag: {}", prev_tag.cyan()));
-            } else {
-                log_info("No previous tag found, generating initial release notes");
-            }
+    // Clean the tag and generate filename
+    let clean_tag = clean_tag_name(&current_tag);
+    let date_str = timezone().now().format("%Y%m%d").to_string();
+    let filename = format!("ReleaseNotes_{}_{}.md", clean_tag, date_str);
+    let path = dir.join(filename);
+    write_string_to_file(&path, &release_notes).map_err(ReleaseNotesError::Write)?;
+    log_success("Release notes generated successfully!");
+    println!("{}", format!("📄 File created: {}", path.display()).green());
+    // Notes for a past release say nothing about what's new since
+    if for_tag.is_none() {
+        record_run(dir, Tracked::ReleaseNotes, |record| {
+            record.tag = Some(current_tag.clone())
+        });
+    }
+    Ok(Some(path))
+}
 
-            match get_commits_between_tags(&repo, &previous_tag, &current_tag) {
-                Ok(commits) => {
-                    let release_notes = generate_comprehensive_release_notes(
-                        &repo_info,
-                        &current_tag,
-                        &previous_tag,
-                        &commits,
-                    );
-
-                    // Clean the tag and generate filename
-                    let clean_tag = clean_tag_name(&current_tag);
-                    let now = chrono::Utc::now();
-                    let date_str = now.format("%Y%m%d").to_string();
-                    let filename = format!("ReleaseNotes_{}_{}.md", clean_tag, date_str);
-                    match write_string_to_file(&filename, &release_notes) {
-                        Ok(_) => {
-                            log_success("Release notes generated successfully!");
-                            println!("{}", format!("📄 File created: {}", filename).green());
-                        }
-                        Err(e) => {
-                            log_error(&format!("Failed to write release notes: {}", e));
-                        }
-                    }
-                }
-                Err(e) => {
-                    log_error(&format!("Failed to get commits: {}", e));
-                }
-            }
-        }
-        Err(e) => {
-            log_error(&format!("Not a git repository or git error: {}", e));
-        }
+/// Markdown notes for `current_tag` since `previous_tag`, with progress
+/// while the range is walked.
+pub async fn render_release_notes(
+    repo: &Repository,
+    current_tag: &str,
+    previous_tag: &Option<String>,
+    options: &NotesOptions<'_>,
+) -> Result<String> {
+    let locale = options.locale;
+    let limits = options.limits;
+
+    // Get repository information
+    let repo_info = get_repository_info(repo);
+    log_info(&format!("Repository: {}", repo_info.url.cyan()));
+    log_info(&format!(
+        "Generating release notes for tag: {}",
+        current_tag.cyan()
+    ));
+    if let Some(ref prev_tag) = previous_tag {
+        log_info(&format!("Comparing with previous tag: {}", prev_tag.cyan()));
+    } else {
+        log_info("No previous tag found, generating initial release notes");
     }
+
+    let mut status = StatusLine::new();
+    let walked = walk_commits_between_tags(
+        repo,
+        previous_tag,
+        current_tag,
+        limits,
+        options.filter,
+        &mut |summary| status.update(&summary.progress_message()),
+    );
+    status.finish();
+    let walk = walked.map_err(|e| anyhow::anyhow!("Failed to get commits: {}", e))?;
+
+    if walk.excluded > 0 {
+        log_info(&format!(
+            "Skipped {} commit(s) matching the exclude filters",
+            walk.excluded
+        ));
+    }
+    if !walk.summary.reverted.is_empty() {
+        log_info(&format!(
+            "Listing {} reverted change(s) under Reverted",
+            walk.summary.reverted.len()
+        ));
+    }
+    if walk.duplicates > 0 {
+        log_info(&format!(
+            "Skipped {} duplicate cherry-pick(s)",
+            walk.duplicates
+        ));
+    }
+    if walk.is_truncated() {
+        log_info(&format!(
+            "Listing {} of {} commits",
+            walk.commits.len(),
+            walk.summary.total_commits
+        ));
+    }
+    let commits = walk.commits.as_slice();
+    let references =
+        if options.resolve_links && repo_info.is_github && !crate::utils::sandbox::is_sandboxed() {
+            fetch_references(&repo_info, commits).await
+        } else {
+            HashMap::new()
+        };
+    let commits = apply_pull_request_titles(commits, &references);
+
+    Ok(match options.template {
+        None if limits.summary_only => generate_summary_release_notes(
+            &repo_info,
+            current_tag,
+            previous_tag,
+            &walk.summary,
+            locale,
+        ),
+        Some(template) => {
+            let context = template_context(
+                &repo_info,
+                current_tag,
+                previous_tag,
+                &commits,
+                &references,
+                locale,
+            );
+            render_template_file(template, &context)?
+        }
+        None => generate_comprehensive_release_notes(
+            &repo_info,
+            current_tag,
+            previous_tag,
+            &commits,
+            &references,
+            &walk.summary,
+            locale,
+        ),
+    })
 }
 
 pub fn clean_tag_name(tag: &str) -> String {
@@ -132,6 +275,28 @@ pub fn get_tag_range(repo: &Repository) -> (String, Option<String>) {
     }
 }
 
+/// `tag` and the version tag before it, to document a past release.
+pub fn get_tag_range_for(repo: &Repository, tag: &str) -> Result<(String, Option<String>)> {
+    if repo.revparse_single(&format!("refs/tags/{}", tag)).is_err() {
+        return Err(anyhow::anyhow!("Tag '{}' not found", tag));
+    }
+    let mut tags = get_all_tags(repo)?;
+    tags.retain(|other| is_version_tag(other) && other != tag);
+    let previous = tags
+        .into_iter()
+        .filter(|other| compare_version_tags(other, tag) == std::cmp::Ordering::Less)
+        .max_by(|a, b| compare_version_tags(a, b));
+    Ok((tag.to_string(), previous))
+}
+
+/// [`get_tag_range_for`] with a tag, else the latest release.
+pub fn tag_range(repo: &Repository, for_tag: Option<&str>) -> Result<(String, Option<String>)> {
+    match for_tag {
+        Some(tag) => get_tag_range_for(repo, tag),
+        None => Ok(get_tag_range(repo)),
+    }
+}
+
 pub fn get_current_commit_as_tag(repo: &Repository) -> (String, Option<String>) {
     log_info("No version tags found, analyzing current commit...");
 
@@ -319,47 +484,524 @@ pub fn compare_version_tags(a: &str, b: &str) -> std::cmp::Ordering {
     version_a.len().cmp(&version_b.len())
 }
 
-pub fn get_commits_between_tags(
+/// Commits of `previous_tag` (None without one) and `current_tag` (HEAD
+/// when the tag doesn't exist yet).
+fn range_ends(
     repo: &Repository,
     previous_tag: &Option<String>,
-    current_tag: &String,
-) -> Result<Vec<CommitInfo>, git2::Error> {
+    current_tag: &str,
+) -> Result<(Option<git2::Oid>, git2::Oid), git2::Error> {
+    let tag_commit = |tag: &str| {
+        repo.revparse_single(&format!("refs/tags/{}", tag))
+            .and_then(|object| object.peel_to_commit())
+            .map(|commit| commit.id())
+    };
+    let previous = previous_tag.as_deref().and_then(|tag| tag_commit(tag).ok());
+    let current = match tag_commit(current_tag) {
+        Ok(oid) => oid,
+        Err(_) => repo.head()?.peel_to_commit()?.id(),
+    };
+    Ok((previous, current))
+}
+
+fn range_revwalk<'repo>(
+    repo: &'repo Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<git2::Revwalk<'repo>, git2::Error> {
+    let (previous, current) = range_ends(repo, previous_tag, current_tag)?;
     let mut revwalk = repo.revwalk()?;
     revwalk.set_sorting(git2::Sort::TIME)?;
+    if let Some(previous) = previous {
+        revwalk.hide(previous)?;
+    }
+    revwalk.push(current)?;
+    Ok(revwalk)
+}
 
-    // Set range
-    if let Some(prev_tag) = previous_tag {
-        if let Ok(prev_oid) = repo.refname_to_id(&format!("refs/tags/{}", prev_tag)) {
-            revwalk.hide(prev_oid)?;
-        }
+/// Reverted changes and duplicate cherry-picks between the two tags.
+pub fn range_cleanup(
+    repo: &Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<RangeCleanup, git2::Error> {
+    let (previous, current) = range_ends(repo, previous_tag, current_tag)?;
+    RangeCleanup::scan(repo, previous, current)
+}
+
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    CommitInfo {
+        message: commit.message().unwrap_or("").to_string(),
+        author_name: commit.author().name().unwrap_or("").to_string(),
+        author_email: commit.author().email().unwrap_or("").to_string(),
+        hash: commit.id().to_string(),
+        timestamp: commit.time().seconds(),
+        stats: DiffStats::default(),
     }
+}
 
-    // Get HEAD or tag
-    let head_oid = if let Ok(tag_oid) = repo.refname_to_id(&format!("refs/tags/{}", current_tag)) {
-        tag_oid
-    } else {
-        repo.head()?.target().unwrap()
+/// Files changed, lines added and lines removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DiffStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl DiffStats {
+    fn from_diff(diff: &git2::Diff) -> Result<Self, git2::Error> {
+        let stats = diff.stats()?;
+        Ok(Self {
+            files_changed: stats.files_changed(),
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+        })
+    }
+}
+
+/// Diff of a commit against its first parent; merges count what they
+/// brought into the mainline.
+pub fn commit_diff_stats(
+    repo: &Repository,
+    commit: &git2::Commit,
+) -> Result<DiffStats, git2::Error> {
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
     };
+    let diff = repo.diff_tree_to_tree(
+        parent_tree.as_ref(),
+        Some(&commit.tree()?),
+        Some(git2::DiffOptions::new().ignore_submodules(true)),
+    )?;
+    DiffStats::from_diff(&diff)
+}
 
-    revwalk.push(head_oid)?;
+/// Paths a commit changed against its first parent, for CODEOWNERS lookups.
+pub fn commit_paths(repo: &Repository, commit: &git2::Commit) -> Result<Vec<String>, git2::Error> {
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+    let diff = repo.diff_tree_to_tree(
+        parent_tree.as_ref(),
+        Some(&commit.tree()?),
+        Some(git2::DiffOptions::new().ignore_submodules(true)),
+    )?;
+    Ok(diff
+        .deltas()
+        .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+        .map(|path| path.to_string_lossy().replace('\\', "/"))
+        .collect())
+}
+
+/// Fills in the diff stats of an already collected commit.
+fn with_diff_stats(repo: &Repository, mut commit: CommitInfo) -> CommitInfo {
+    commit.stats = git2::Oid::from_str(&commit.hash)
+        .and_then(|oid| repo.find_commit(oid))
+        .and_then(|found| commit_diff_stats(repo, &found))
+        .unwrap_or_default();
+    commit
+}
 
+pub fn get_commits_between_tags(
+    repo: &Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<Vec<CommitInfo>, git2::Error> {
     let mut commits = Vec::new();
-    for oid in revwalk {
-        let oid = oid?;
-        let commit = repo.find_commit(oid)?;
-
-        let commit_info = CommitInfo {
-            message: commit.message().unwrap_or("").to_string(),
-            author_name: commit.author().name().unwrap_or("").to_string(),
-            author_email: commit.author().email().unwrap_or("").to_string(),
-            hash: commit.id().to_string(),
-            timestamp: commit.time().seconds(),
+    for oid in range_revwalk(repo, previous_tag, current_tag)? {
+        let commit = repo.find_commit(oid?)?;
+        let mut info = commit_info(&commit);
+        info.stats = commit_diff_stats(repo, &commit)?;
+        commits.push(info);
+    }
+
+    Ok(commits)
+}
+
+/// Lines changed in one file between two tags.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileChange {
+    pub path: String,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl FileChange {
+    pub fn lines(&self) -> usize {
+        self.insertions + self.deletions
+    }
+}
+
+/// What changed between the previous and the current tag as a whole.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ChangeStatistics {
+    pub totals: DiffStats,
+    /// Most changed lines first
+    pub files: Vec<FileChange>,
+}
+
+impl ChangeStatistics {
+    pub fn from_files(mut files: Vec<FileChange>) -> Self {
+        files.sort_by(|a, b| b.lines().cmp(&a.lines()).then(a.path.cmp(&b.path)));
+        let totals = DiffStats {
+            files_changed: files.len(),
+            insertions: files.iter().map(|file| file.insertions).sum(),
+            deletions: files.iter().map(|file| file.deletions).sum(),
         };
+        Self { totals, files }
+    }
 
-        commits.push(commit_info);
+    pub fn top_files(&self, limit: usize) -> &[FileChange] {
+        &self.files[..self.files.len().min(limit)]
     }
 
-    Ok(commits)
+    /// (directory, files, lines) by the parent directory of each file, most
+    /// changed lines first. Files in the root are grouped under `.`.
+    pub fn top_directories(&self, limit: usize) -> Vec<(String, usize, usize)> {
+        let mut directories: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for file in &self.files {
+            let directory = match Path::new(&file.path).parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => {
+                    parent.to_string_lossy().replace('\\', "/")
+                }
+                _ => ".".to_string(),
+            };
+            let entry = directories.entry(directory).or_default();
+            entry.0 += 1;
+            entry.1 += file.lines();
+        }
+
+        let mut directories: Vec<(String, usize, usize)> = directories
+            .into_iter()
+            .map(|(directory, (files, lines))| (directory, files, lines))
+            .collect();
+        directories.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+        directories.truncate(limit);
+        directories
+    }
+}
+
+fn tag_tree<'repo>(repo: &'repo Repository, tag: &str) -> Result<git2::Tree<'repo>, git2::Error> {
+    repo.revparse_single(&format!("refs/tags/{}", tag))?
+        .peel_to_tree()
+}
+
+/// Trees of `previous_tag` (None without one) and `current_tag` (HEAD when
+/// the tag doesn't exist yet).
+fn range_trees<'repo>(
+    repo: &'repo Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<(Option<git2::Tree<'repo>>, git2::Tree<'repo>), git2::Error> {
+    let old_tree = match previous_tag {
+        Some(tag) => tag_tree(repo, tag).ok(),
+        None => None,
+    };
+    let new_tree = match tag_tree(repo, current_tag) {
+        Ok(tree) => tree,
+        Err(_) => repo.head()?.peel_to_tree()?,
+    };
+    Ok((old_tree, new_tree))
+}
+
+/// Submodules whose recorded commit moved between the two tags.
+pub fn range_submodule_changes(
+    repo: &Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<Vec<SubmoduleChange>, git2::Error> {
+    let (old_tree, new_tree) = range_trees(repo, previous_tag, current_tag)?;
+    submodule_changes(repo, old_tree.as_ref(), &new_tree)
+}
+
+/// Diffs the tree of `previous_tag` against `current_tag` (HEAD when the
+/// tag doesn't exist yet). Without a previous tag every file counts as added.
+pub fn range_change_statistics(
+    repo: &Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+) -> Result<ChangeStatistics, git2::Error> {
+    let (old_tree, new_tree) = range_trees(repo, previous_tag, current_tag)?;
+    let diff = repo.diff_tree_to_tree(
+        old_tree.as_ref(),
+        Some(&new_tree),
+        Some(git2::DiffOptions::new().ignore_submodules(true)),
+    )?;
+
+    let mut files = Vec::new();
+    for index in 0..diff.deltas().len() {
+        let delta = match diff.get_delta(index) {
+            Some(delta) => delta,
+            None => continue,
+        };
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // Binary files have no patch lines but still count as changed
+        let (insertions, deletions) = match git2::Patch::from_diff(&diff, index)? {
+            Some(patch) => {
+                let (_, insertions, deletions) = patch.line_stats()?;
+                (insertions, deletions)
+            }
+            None => (0, 0),
+        };
+        files.push(FileChange {
+            path,
+            insertions,
+            deletions,
+        });
+    }
+
+    Ok(ChangeStatistics::from_files(files))
+}
+
+/// Commits are read in chunks of this size; progress is reported after each.
+pub const COMMIT_CHUNK_SIZE: usize = 1000;
+
+/// How much of a commit range ends up listed in the notes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CommitLimits {
+    /// Only the newest commits are listed, the summary covers the whole range
+    pub max_commits: Option<usize>,
+    /// Counts per category and top contributors, no commit list
+    pub summary_only: bool,
+    /// Also list submodules whose recorded commit changed
+    pub submodules: bool,
+    /// Count commits per owning team from the CODEOWNERS file
+    pub by_team: bool,
+}
+
+impl CommitLimits {
+    fn retained(&self) -> Option<usize> {
+        if self.summary_only {
+            Some(0)
+        } else {
+            self.max_commits
+        }
+    }
+}
+
+/// Counts over a whole commit range, built chunk by chunk so the commits
+/// themselves don't have to be kept.
+#[derive(Debug, Clone, Default)]
+pub struct RangeSummary {
+    pub total_commits: usize,
+    /// Section title key (`new_features`, `bug_fixes`, ...) to commit count
+    pub categories: HashMap<&'static str, usize>,
+    /// Email to (name, commit count)
+    pub contributors: HashMap<String, (String, usize)>,
+    pub first_timestamp: Option<i64>,
+    pub last_timestamp: Option<i64>,
+    /// Diff between the two tags, when it could be computed
+    pub changes: Option<ChangeStatistics>,
+    /// Only filled when [`CommitLimits::submodules`] is set
+    pub submodules: Vec<SubmoduleChange>,
+    /// With [`CommitLimits::by_team`] and a CODEOWNERS file
+    pub teams: Option<TeamSummary>,
+    /// Changes reverted within the range, left out of the counts
+    pub reverted: Vec<RevertedChange>,
+}
+
+impl RangeSummary {
+    pub fn add(&mut self, chunk: &[CommitInfo]) {
+        let categorized = categorize_commits(chunk);
+        let rest = categorized.styles.len()
+            + categorized.refactor.len()
+            + categorized.perf.len()
+            + categorized.tests.len()
+            + categorized.chores.len()
+            + categorized.others.len()
+            + categorized.other.len();
+        let counts = [
+            ("breaking_changes", categorized.breaking_changes.len()),
+            ("security_updates", categorized.security.len()),
+            ("new_features", categorized.features.len()),
+            ("bug_fixes", categorized.fixes.len()),
+            ("improvements", categorized.improvements.len()),
+            ("translation_updates", categorized.translations.len()),
+            ("documentation", categorized.docs.len()),
+            ("dependencies", categorized.deps.len()),
+            ("other_changes", rest),
+        ];
+        for (key, count) in counts {
+            *self.categories.entry(key).or_insert(0) += count;
+        }
+
+        for commit in chunk {
+            let entry = self
+                .contributors
+                .entry(commit.author_email.clone())
+                .or_insert_with(|| (commit.author_name.clone(), 0));
+            entry.1 += 1;
+
+            self.first_timestamp = Some(
+                self.first_timestamp
+                    .map_or(commit.timestamp, |first| first.min(commit.timestamp)),
+            );
+            self.last_timestamp = Some(
+                self.last_timestamp
+                    .map_or(commit.timestamp, |last| last.max(commit.timestamp)),
+            );
+        }
+        self.total_commits += chunk.len();
+    }
+
+    /// Categories with commits, in the order of the built-in layout.
+    pub fn filled_categories(&self) -> Vec<(&'static str, usize)> {
+        SUMMARY_CATEGORIES
+            .iter()
+            .filter_map(|key| {
+                let count = self.categories.get(key).copied().unwrap_or(0);
+                (count > 0).then_some((*key, count))
+            })
+            .collect()
+    }
+
+    /// (email, name, commits), most commits first.
+    pub fn top_contributors(&self, limit: usize) -> Vec<(String, String, usize)> {
+        let mut contributors: Vec<(String, String, usize)> = self
+            .contributors
+            .iter()
+            .map(|(email, (name, count))| (email.clone(), name.clone(), *count))
+            .collect();
+        contributors.sort_by(|a, b| b.2.cmp(&a.2).then(a.1.cmp(&b.1)));
+        contributors.truncate(limit);
+        contributors
+    }
+
+    /// One-line progress: commits so far and the categories being filled.
+    pub fn progress_message(&self) -> String {
+        let mut message = format!("📊 {} commits processed", self.total_commits);
+        for (key, count) in self.filled_categories() {
+            message.push_str(&format!(" · {} {}", category_label(key), count));
+        }
+        message
+    }
+}
+
+/// Short English label of a summary category key, for progress and tables.
+pub fn category_label(key: &str) -> &'static str {
+    match key {
+        "breaking_changes" => "breaking",
+        "security_updates" => "security",
+        "new_features" => "features",
+        "bug_fixes" => "fixes",
+        "improvements" => "improvements",
+        "translation_updates" => "translations",
+        "documentation" => "docs",
+        "dependencies" => "deps",
+        _ => "other",
+    }
+}
+
+const SUMMARY_CATEGORIES: [&str; 9] = [
+    "breaking_changes",
+    "security_updates",
+    "new_features",
+    "bug_fixes",
+    "improvements",
+    "translation_updates",
+    "documentation",
+    "dependencies",
+    "other_changes",
+];
+
+/// Commits kept for listing (newest first) and the summary of the range.
+#[derive(Debug, Default)]
+pub struct CommitWalk {
+    pub commits: Vec<CommitInfo>,
+    pub summary: RangeSummary,
+    /// Commits skipped by the exclude filters, not part of the summary
+    pub excluded: usize,
+    /// Cherry-picks of a listed or already released change, also skipped
+    pub duplicates: usize,
+}
+
+impl CommitWalk {
+    pub fn is_truncated(&self) -> bool {
+        self.commits.len() < self.summary.total_commits
+    }
+}
+
+/// Walks the range in chunks of [`COMMIT_CHUNK_SIZE`], calling `on_chunk`
+/// after each. Commits matching `filter` are skipped, and so are reverted
+/// changes with their reverts and duplicate cherry-picks (see
+/// [`range_cleanup`]). Only `limits` worth of
+/// commits is kept in memory; the summary also gets the diff between the
+/// two tags, and the submodule changes and team counts when `limits` asks
+/// for them.
+pub fn walk_commits_between_tags(
+    repo: &Repository,
+    previous_tag: &Option<String>,
+    current_tag: &str,
+    limits: CommitLimits,
+    filter: &CommitFilter,
+    on_chunk: &mut dyn FnMut(&RangeSummary),
+) -> Result<CommitWalk, git2::Error> {
+    let retained = limits.retained();
+    let mut walk = CommitWalk::default();
+    let mut chunk = Vec::with_capacity(COMMIT_CHUNK_SIZE);
+    // A broken CODEOWNERS file shouldn't stop the release notes
+    let codeowners = match (limits.by_team, repo.workdir()) {
+        (true, Some(root)) => CodeOwners::load(root).unwrap_or_else(|e| {
+            log_error(&e.to_string());
+            None
+        }),
+        _ => None,
+    };
+    if codeowners.is_some() {
+        walk.summary.teams = Some(TeamSummary::default());
+    }
+
+    let cleanup = range_cleanup(repo, previous_tag, current_tag)?;
+    walk.duplicates = cleanup.duplicates;
+    walk.summary.reverted = cleanup.reverted;
+
+    let mut revwalk = range_revwalk(repo, previous_tag, current_tag)?.peekable();
+    while let Some(oid) = revwalk.next() {
+        let oid = oid?;
+        let found = repo.find_commit(oid)?;
+        let commit = commit_info(&found);
+        if cleanup.dropped.contains(&oid) {
+            // Listed under "Reverted" or counted in `duplicates`
+        } else if filter.excludes_commit(&commit) {
+            walk.excluded += 1;
+        } else {
+            if let (Some(codeowners), Some(teams)) = (&codeowners, &mut walk.summary.teams) {
+                let paths = commit_paths(repo, &found)?;
+                teams.add(codeowners, paths.iter().map(String::as_str));
+            }
+            chunk.push(commit);
+        }
+        if chunk.is_empty() || (chunk.len() < COMMIT_CHUNK_SIZE && revwalk.peek().is_some()) {
+            continue;
+        }
+
+        walk.summary.add(&chunk);
+        let room = retained.map_or(usize::MAX, |max| max.saturating_sub(walk.commits.len()));
+        // Diff stats only for the commits that are listed, large ranges stay fast
+        walk.commits.extend(
+            chunk
+                .drain(..)
+                .take(room)
+                .map(|commit| with_diff_stats(repo, commit)),
+        );
+        if walk.summary.total_commits >= COMMIT_CHUNK_SIZE {
+            on_chunk(&walk.summary);
+        }
+    }
+    walk.summary.changes = range_change_statistics(repo, previous_tag, current_tag).ok();
+    if limits.submodules {
+        walk.summary.submodules =
+            range_submodule_changes(repo, previous_tag, current_tag).unwrap_or_default();
+    }
+
+    Ok(walk)
 }
 
 #[derive(Debug, Clone)]
@@ -369,10 +1011,11 @@ pub struct CommitInfo {
     pub author_email: String,
     pub hash: String,
     pub timestamp: i64,
+    pub stats: DiffStats,
 }
 
 impl CommitInfo {
-    fn short_hash(&self) -> String {
+    pub fn short_hash(&self) -> String {
         if self.hash.len() >= 7 {
             self.hash[..7].to_string()
         } else {
@@ -380,35 +1023,34 @@ impl CommitInfo {
         }
     }
 
-    fn format_date(&self) -> String {
-        use chrono::{TimeZone, Utc};
-        let dt = Utc.timestamp_opt(self.timestamp, 0).single();
-        if let Some(dt) = dt {
-            dt.format("%Y-%m-%d").to_string()
-        } else {
-            "unknown".to_string()
-        }
+    pub fn format_date(&self, locale: DocumentLocale) -> String {
+        format_timestamp_date(self.timestamp, locale)
     }
 
-    fn format_time(&self) -> String {
-        use chrono::{TimeZone, Utc};
-        let dt = Utc.timestamp_opt(self.timestamp, 0).single();
-        if let Some(dt) = dt {
-            dt.format("%H:%M").to_string()
-        } else {
-            "unknown".to_string()
+    pub fn format_time(&self, locale: DocumentLocale) -> String {
+        let timezone = timezone();
+        match timezone.at(self.timestamp) {
+            Some(time) => timezone.format_time(locale, &time),
+            None => "unknown".to_string(),
         }
     }
 }
 
+fn format_timestamp_date(timestamp: i64, locale: DocumentLocale) -> String {
+    match timezone().at(timestamp) {
+        Some(time) => locale.format_short_date(time.date_naive()),
+        None => "unknown".to_string(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RepositoryInfo {
-    url: String,
-    name: String,
-    owner: String,
-    is_github: bool,
-    is_gitlab: bool,
-    is_bitbucket: bool,
+    pub url: String,
+    pub name: String,
+    pub owner: String,
+    pub is_github: bool,
+    pub is_gitlab: bool,
+    pub is_bitbucket: bool,
 }
 
 impl Default for RepositoryInfo {
@@ -424,7 +1066,7 @@ impl Default for RepositoryInfo {
     }
 }
 
-fn get_repository_info(repo: &Repository) -> RepositoryInfo {
+pub fn get_repository_info(repo: &Repository) -> RepositoryInfo {
     let mut repo_info = RepositoryInfo::default();
 
     // Try to get remote URL
@@ -445,7 +1087,7 @@ fn get_repository_info(repo: &Repository) -> RepositoryInfo {
     repo_info
 }
 
-fn parse_git_url(repo_info: &mut RepositoryInfo, url: &str) {
+pub fn parse_git_url(repo_info: &mut RepositoryInfo, url: &str) {
     // Remove .git suffix if present
     let clean_url = url.trim_end_matches(".git");
 
@@ -550,45 +1192,257 @@ impl CategorizedCommits {
     }
 }
 
+/// Splits a conventional commit header `type(scope)!: subject` and returns
+/// the lowercased type and whether it is marked breaking with `!`.
+pub fn parse_commit_type(message: &str) -> Option<(String, bool)> {
+    let header = message.lines().next()?;
+    let (prefix, _) = header.split_once(':')?;
+    let (prefix, bang) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    let commit_type = match prefix.split_once('(') {
+        Some((commit_type, scope)) if scope.ends_with(')') => commit_type,
+        Some(_) => return None,
+        None => prefix,
+    };
+
+    if commit_type.is_empty() || !commit_type.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((commit_type.to_lowercase(), bang))
+}
+
+pub fn is_breaking_change(message: &str) -> bool {
+    message.to_lowercase().contains("breaking change")
+        || parse_commit_type(message).is_some_and(|(_, bang)| bang)
+}
+
+/// The [`CategorizedCommits`] field a commit message is sorted into.
+pub fn commit_category(message: &str) -> &'static str {
+    // Check for breaking changes first
+    if is_breaking_change(message) {
+        return "breaking_changes";
+    }
+
+    // Then check for conventional commit types
+    let commit_type = parse_commit_type(message).map(|(commit_type, _)| commit_type);
+    match commit_type.as_deref() {
+        Some("feat" | "feature") => "features",
+        Some("fix" | "bugfix") => "fixes",
+        Some("docs" | "doc") => "docs",
+        Some("style" | "styles") => "styles",
+        Some("refactor" | "refact") => "refactor",
+        Some("perf" | "performance") => "perf",
+        Some("test" | "tests") => "tests",
+        Some("chore" | "build" | "ci") => "chores",
+        _ => "others",
+    }
+}
+
+/// The PR number a squash merge appends to its title, `feat: x (#42)`.
+fn squash_pull_request_number(header: &str) -> Option<u64> {
+    let rest = header.trim_end().strip_suffix(')')?;
+    let (_, number) = rest.rsplit_once("(#")?;
+    number.parse().ok()
+}
+
+/// Conventional commit lines carried in the body of a merge commit or a
+/// squash commit (GitHub's `* feat: ...` list), tagged with the PR number.
+/// Empty for regular commits.
+pub fn embedded_commits(message: &str) -> Vec<String> {
+    let mut lines = message.lines();
+    let header = lines.next().unwrap_or_default();
+    let is_merge = header.starts_with("Merge ");
+    let is_conventional = |line: &str| commit_category(line) != "others";
+
+    let mut bulleted = 0;
+    let mut embedded = Vec::new();
+    for line in lines.map(str::trim) {
+        let bullet = line.strip_prefix("* ").or_else(|| line.strip_prefix("- "));
+        if bullet.is_some() {
+            bulleted += 1;
+        }
+        // Merge bodies carry the PR title as plain text, squash bodies a list
+        match bullet.or(is_merge.then_some(line)) {
+            Some(entry) if is_conventional(entry) => embedded.push(entry.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    // A conventional squash title with a single bullet is the same change
+    let squash = !is_merge && (bulleted > 1 || !is_conventional(header));
+    if !(is_merge || squash) || embedded.is_empty() {
+        return Vec::new();
+    }
+
+    let number = merge_pull_request_number(header).or_else(|| squash_pull_request_number(header));
+    embedded
+        .into_iter()
+        .map(|entry| match number {
+            Some(number) if extract_references(&entry).is_empty() => {
+                format!("{} (#{})", entry, number)
+            }
+            _ => entry,
+        })
+        .collect()
+}
+
+/// The messages a commit is categorized by: the embedded commits of a merge
+/// or squash commit, the commit itself otherwise. A breaking change noted
+/// only in the footer keeps the whole commit so it isn't lost.
+pub fn commit_messages(message: &str) -> Vec<String> {
+    let embedded = embedded_commits(message);
+    if embedded.is_empty()
+        || (is_breaking_change(message) && !embedded.iter().any(|e| is_breaking_change(e)))
+    {
+        vec![message.to_string()]
+    } else {
+        embedded
+    }
+}
+
 pub fn categorize_commits(commits: &[CommitInfo]) -> CategorizedCommits {
     let mut categorized = CategorizedCommits::new();
 
-    for commit in commits {
-        let message = commit.message.to_lowercase();
-        let original_message = &commit.message;
-
-        // Check for breaking changes first
-        if message.contains("breaking change") || message.contains("!:") {
-            categorized.breaking_changes.push(original_message.clone());
-        }
-        // Then check for conventional commit types
-        else if message.starts_with("feat:") || message.starts_with("feature:") {
-            categorized.features.push(original_message.clone());
-        } else if message.starts_with("fix:") || message.starts_with("bugfix:") {
-            categorized.fixes.push(original_message.clone());
-        } else if message.starts_with("docs:") || message.starts_with("doc:") {
-            categorized.docs.push(original_message.clone());
-        } else if message.starts_with("style:") || message.starts_with("styles:") {
-            categorized.styles.push(original_message.clone());
-        } else if message.starts_with("refactor:") || message.starts_with("refact:") {
-            categorized.refactor.push(original_message.clone());
-        } else if message.starts_with("perf:") || message.starts_with("performance:") {
-            categorized.perf.push(original_message.clone());
-        } else if message.starts_with("test:") || message.starts_with("tests:") {
-            categorized.tests.push(original_message.clone());
-        } else if message.starts_with("chore:")
-            || message.starts_with("build:")
-            || message.starts_with("ci:")
-        {
-            categorized.chores.push(original_message.clone());
-        } else {
-            categorized.others.push(original_message.clone());
-        }
+    for message in commits
+        .iter()
+        .flat_map(|commit| commit_messages(&commit.message))
+    {
+        let bucket = match commit_category(&message) {
+            "breaking_changes" => &mut categorized.breaking_changes,
+            "features" => &mut categorized.features,
+            "fixes" => &mut categorized.fixes,
+            "docs" => &mut categorized.docs,
+            "styles" => &mut categorized.styles,
+            "refactor" => &mut categorized.refactor,
+            "perf" => &mut categorized.perf,
+            "tests" => &mut categorized.tests,
+            "chores" => &mut categorized.chores,
+            _ => &mut categorized.others,
+        };
+        bucket.push(message);
     }
 
     categorized
 }
 
+/// A listed commit in `release-notes --json` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitEntry {
+    pub hash: String,
+    pub subject: String,
+    pub author: String,
+    pub email: String,
+    pub timestamp: i64,
+    #[serde(flatten)]
+    pub stats: DiffStats,
+}
+
+/// Categorized commits of a release for `--json` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseNotesData {
+    pub repository: String,
+    pub tag: String,
+    pub previous_tag: Option<String>,
+    pub total_commits: usize,
+    /// Category to commits, newest first; empty categories are left out
+    pub categories: BTreeMap<&'static str, Vec<CommitEntry>>,
+    /// Diff between the two tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<ChangeStatistics>,
+    /// With `--submodules`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub submodules: Vec<SubmoduleChange>,
+    /// With `--by-team`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teams: Option<TeamSummary>,
+    /// Changes reverted before the release, not part of `categories`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reverted: Vec<RevertedChange>,
+}
+
+impl ReleaseNotesData {
+    pub fn new(
+        repo_info: &RepositoryInfo,
+        current_tag: &str,
+        previous_tag: &Option<String>,
+        commits: &[CommitInfo],
+        total_commits: usize,
+    ) -> Self {
+        let mut categories: BTreeMap<&'static str, Vec<CommitEntry>> = BTreeMap::new();
+        for commit in commits {
+            for message in commit_messages(&commit.message) {
+                categories
+                    .entry(commit_category(&message))
+                    .or_default()
+                    .push(CommitEntry {
+                        hash: commit.hash.clone(),
+                        subject: message.lines().next().unwrap_or_default().to_string(),
+                        author: commit.author_name.clone(),
+                        email: commit.author_email.clone(),
+                        timestamp: commit.timestamp,
+                        stats: commit.stats,
+                    });
+            }
+        }
+
+        Self {
+            repository: repo_info.url.clone(),
+            tag: current_tag.to_string(),
+            previous_tag: previous_tag.clone(),
+            total_commits,
+            categories,
+            changes: None,
+            submodules: Vec::new(),
+            teams: None,
+            reverted: Vec::new(),
+        }
+    }
+}
+
+/// Walks the same range as [`generate_release_notes`] and returns the
+/// categorized commits instead of writing Markdown.
+pub async fn release_notes_data(
+    dir: &Path,
+    resolve_links: bool,
+    limits: CommitLimits,
+    filter: &CommitFilter,
+    for_tag: Option<&str>,
+) -> Result<ReleaseNotesData> {
+    let repo = Repository::discover(dir)?;
+    let repo_info = get_repository_info(&repo);
+    let (current_tag, previous_tag) = tag_range(&repo, for_tag)?;
+    let walk = walk_commits_between_tags(
+        &repo,
+        &previous_tag,
+        &current_tag,
+        limits,
+        filter,
+        &mut |_| {},
+    )?;
+
+    let references =
+        if resolve_links && repo_info.is_github && !crate::utils::sandbox::is_sandboxed() {
+            fetch_references(&repo_info, &walk.commits).await
+        } else {
+            HashMap::new()
+        };
+    let commits = apply_pull_request_titles(&walk.commits, &references);
+    let mut data = ReleaseNotesData::new(
+        &repo_info,
+        &current_tag,
+        &previous_tag,
+        &commits,
+        walk.summary.total_commits,
+    );
+    data.changes = walk.summary.changes;
+    data.submodules = walk.summary.submodules;
+    data.teams = walk.summary.teams;
+    data.reverted = walk.summary.reverted;
+    Ok(data)
+}
+
 pub fn is_prerelease(tag: &str) -> bool {
     let lower = tag.to_lowercase();
     lower.contains("-alpha")
@@ -625,10 +1479,203 @@ pub fn generate_issues_url(repo_info: &RepositoryInfo) -> String {
     format!("{}/issues", repo_info.url.trim_end_matches(".git"))
 }
 
-fn generate_new_issue_url(repo_info: &RepositoryInfo) -> String {
+pub fn generate_new_issue_url(repo_info: &RepositoryInfo) -> String {
     format!("{}/issues/new", repo_info.url.trim_end_matches(".git"))
 }
 
+/// A PR or issue mentioned in a commit message, resolved through the GitHub API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub number: u64,
+    pub title: String,
+    pub author: Option<String>,
+    pub url: String,
+    pub is_pull_request: bool,
+}
+
+/// Parses a `GET /repos/{owner}/{repo}/issues/{number}` response.
+pub fn parse_reference_json(body: &str) -> Result<Reference> {
+    let value: serde_json::Value = serde_json::from_str(body)?;
+    let number = value["number"]
+        .as_u64()
+        .ok_or_else(|| anyhow::anyhow!("Issue response has no number"))?;
+
+    Ok(Reference {
+        number,
+        title: value["title"]
+            .as_str()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        author: value["user"]["login"]
+            .as_str()
+            .map(|login| login.to_string()),
+        url: value["html_url"].as_str().unwrap_or_default().to_string(),
+        is_pull_request: value.get("pull_request").is_some_and(|pr| !pr.is_null()),
+    })
+}
+
+/// Byte ranges and numbers of `#123` mentions. `&#123;` entities, `#1a`
+/// and `repo#1` style references are not counted.
+fn reference_spans(message: &str) -> Vec<(usize, usize, u64)> {
+    let bytes = message.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut spans = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'#' || (i > 0 && (is_word(bytes[i - 1]) || bytes[i - 1] == b'&')) {
+            i += 1;
+            continue;
+        }
+        let end = bytes[i + 1..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |offset| i + 1 + offset);
+        let bounded = end == bytes.len() || !is_word(bytes[end]);
+        match message[i + 1..end].parse::<u64>() {
+            Ok(number) if bounded => {
+                spans.push((i, end, number));
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    spans
+}
+
+/// Numbers mentioned as `#123`, in order of first appearance.
+pub fn extract_references(message: &str) -> Vec<u64> {
+    let mut numbers = Vec::new();
+    for (_, _, number) in reference_spans(message) {
+        if !numbers.contains(&number) {
+            numbers.push(number);
+        }
+    }
+    numbers
+}
+
+/// The PR number of a GitHub merge commit ("Merge pull request #123 from ...").
+pub fn merge_pull_request_number(message: &str) -> Option<u64> {
+    let rest = message.strip_prefix("Merge pull request #")?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Replaces merge commit messages with the merged PR's title, so they are
+/// categorized by its conventional prefix like squash merges are.
+pub fn apply_pull_request_titles(
+    commits: &[CommitInfo],
+    references: &HashMap<u64, Reference>,
+) -> Vec<CommitInfo> {
+    commits
+        .iter()
+        .map(|commit| {
+            let mut commit = commit.clone();
+            if let Some(reference) = merge_pull_request_number(&commit.message)
+                .and_then(|number| references.get(&number))
+                .filter(|reference| !reference.title.is_empty())
+            {
+                commit.message = format!("{} (#{})", reference.title, reference.number);
+            }
+            commit
+        })
+        .collect()
+}
+
+/// Links `#123` mentions on GitHub repositories. A trailing `(#123)` also
+/// credits the PR author: `fix: crash ([#123](...) by [@user](...))`.
+/// Numbers that couldn't be resolved still link to the issue tracker, which
+/// redirects pull request numbers.
+pub fn link_references(
+    message: &str,
+    references: &HashMap<u64, Reference>,
+    repo_info: &RepositoryInfo,
+) -> String {
+    if !repo_info.is_github {
+        return message.to_string();
+    }
+
+    let mut output = String::with_capacity(message.len());
+    let mut last = 0;
+    for (start, end, number) in reference_spans(message) {
+        output.push_str(&message[last..start]);
+        let reference = references.get(&number);
+        let url = reference
+            .map(|reference| reference.url.clone())
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| format!("{}/{}", generate_issues_url(repo_info), number));
+        output.push_str(&format!("[#{}]({})", number, url));
+
+        let in_parens =
+            start > 0 && message.as_bytes()[start - 1] == b'(' && message[end..].starts_with(')');
+        if let Some(author) = reference
+            .filter(|reference| in_parens && reference.is_pull_request)
+            .and_then(|reference| reference.author.as_ref())
+        {
+            output.push_str(&format!(" by [@{}](https://github.com/{})", author, author));
+        }
+        last = end;
+    }
+    output.push_str(&message[last..]);
+    output
+}
+
+/// Most lookups a single release notes run makes.
+const MAX_REFERENCE_LOOKUPS: usize = 200;
+
+/// Looks up the PRs and issues mentioned by `commits`. Lookups stop at the
+/// first connection failure, so offline runs fall back to plain links quickly.
+pub async fn resolve_references(
+    client: &GitHubApiClient,
+    commits: &[CommitInfo],
+) -> HashMap<u64, Reference> {
+    let mut numbers = Vec::new();
+    for commit in commits {
+        for number in extract_references(&commit.message) {
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+    }
+
+    let mut references = HashMap::new();
+    for number in numbers.into_iter().take(MAX_REFERENCE_LOOKUPS) {
+        match client.get_reference(number).await {
+            Ok(reference) => {
+                references.insert(number, reference);
+            }
+            Err(e) => {
+                let offline = e
+                    .downcast_ref::<reqwest::Error>()
+                    .is_some_and(|e| e.is_connect() || e.is_timeout());
+                if offline {
+                    log_info("GitHub is unreachable, linking PR and issue numbers only");
+                    break;
+                }
+            }
+        }
+    }
+    references
+}
+
+async fn fetch_references(
+    repo_info: &RepositoryInfo,
+    commits: &[CommitInfo],
+) -> HashMap<u64, Reference> {
+    let slug = format!("{}/{}", repo_info.owner, repo_info.name);
+    match GitHubApiClient::from_env(Some(&slug)) {
+        Ok(client) => {
+            log_info("Resolving PR and issue references...");
+            resolve_references(&client, commits).await
+        }
+        Err(_) => {
+            log_info("Set GITHUB_TOKEN to show PR titles and authors in release notes");
+            HashMap::new()
+        }
+    }
+}
+
 pub fn get_contributors_with_stats(commits: &[CommitInfo]) -> Vec<(String, String, usize)> {
     let mut contributors: HashMap<String, (String, usize)> = HashMap::new();
 
@@ -645,27 +1692,27 @@ pub fn get_contributors_with_stats(commits: &[CommitInfo]) -> Vec<(String, Strin
         .collect();
 
     // Sort by commit count, descending
-    result.sort_by(|a, b| b.2.cmp(&a.2));
+    result.sort_by_key(|(_, _, count)| std::cmp::Reverse(*count));
 
     result
 }
 
-fn format_github_username_with_stats(
+/// `12345+octocat@users.noreply.github.com` → `octocat`
+pub fn github_username(email: &str) -> Option<String> {
+    let local = email.strip_suffix("@users.noreply.github.com")?;
+    local.split('+').next_back().map(|user| user.to_string())
+}
+
+pub fn format_github_username_with_stats(
     email: &str,
     name: &str,
     commit_count: usize,
     repo_info: &RepositoryInfo,
+    locale: DocumentLocale,
 ) -> String {
-    let commits_text = if commit_count == 1 {
-        "1 commit".to_string()
-    } else {
-        format!("{} commits", commit_count)
-    };
+    let commits_text = locale.commits(commit_count);
 
-    if email.contains("@users.noreply.github.com") && repo_info.is_github {
-        // GitHub no-reply email format - fix temporary value issue
-        let temp_email = email.replace("@users.noreply.github.com", "");
-        let github_user = temp_email.split('+').next_back().unwrap_or(email);
+    if let Some(github_user) = github_username(email).filter(|_| repo_info.is_github) {
         format!(
             "- [@{}](https://github.com/{}) ({}) - {}",
             github_user, github_user, name, commits_text
@@ -682,47 +1729,405 @@ fn format_github_username_with_stats(
     }
 }
 
-fn generate_comprehensive_release_notes(
+/// Build and update commands guessed from the repository name, `None` when
+/// the project type is unknown.
+pub fn install_commands(
+    repo_info: &RepositoryInfo,
+) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    let name = repo_info.name.to_lowercase();
+    if name.contains("rust") || repo_info.url.contains("rust") {
+        Some((
+            &["cargo build --release"],
+            &["cargo update", "cargo build --release"],
+        ))
+    } else if name.contains("node") || repo_info.url.contains("node") || name.contains("js") {
+        Some((
+            &["npm install", "npm run build"],
+            &["npm update", "npm run build"],
+        ))
+    } else if name.contains("python") || repo_info.url.contains("python") {
+        Some((
+            &["pip install -r requirements.txt"],
+            &["pip install --upgrade -r requirements.txt"],
+        ))
+    } else {
+        None
+    }
+}
+
+/// Title, release date, repository, commit count and range, pre-release
+/// warning. `range` is the oldest and newest commit timestamp.
+fn notes_header(
     repo_info: &RepositoryInfo,
     current_tag: &str,
     previous_tag: &Option<String>,
-    commits: &[CommitInfo],
+    total_commits: usize,
+    range: Option<(i64, i64)>,
+    locale: DocumentLocale,
 ) -> String {
     let mut output = String::new();
+    let t = |key: &str| locale.t(key);
 
     let comparison_text = if let Some(ref prev_tag) = previous_tag {
-        format!("Changes since {}", prev_tag)
+        locale.format("changes_since", &[("tag", prev_tag)])
     } else {
-        "Initial release".to_string()
+        t("initial_release")
     };
 
     // Header
     output.push_str(&format!("# 📋 {}\n\n", comparison_text));
 
     // Release date and stats
-    let release_date = chrono::Utc::now().format("%Y-%m-%d").to_string();
-    output.push_str(&format!("- **Release Date:** {}\n", release_date));
+    let release_date = locale.format_date(timezone().now().date_naive());
+    output.push_str(&format!("- **{}:** {}\n", t("release_date"), release_date));
     output.push_str(&format!(
-        "- **Repository:** {}\n",
+        "- **{}:** {}\n",
+        t("repository"),
         repo_info.url.trim_end_matches(".git")
     ));
-    output.push_str(&format!("- **Total Commits:** {}\n", commits.len()));
+    output.push_str(&format!(
+        "- **{}:** {}\n",
+        t("total_commits"),
+        locale.format_number(total_commits)
+    ));
 
     // Add commit date range
-    if !commits.is_empty() {
-        let oldest_commit = commits.last().unwrap(); // commits are sorted by time, newest first
-        let newest_commit = commits.first().unwrap();
+    if let Some((oldest, newest)) = range {
         output.push_str(&format!(
-            "- **Commit Range:** {} to {}\n",
-            oldest_commit.format_date(),
-            newest_commit.format_date()
+            "- **{}:** {}\n",
+            t("commit_range"),
+            locale.format(
+                "range",
+                &[
+                    ("from", &format_timestamp_date(oldest, locale)),
+                    ("to", &format_timestamp_date(newest, locale)),
+                ],
+            )
         ));
     }
     output.push('\n');
 
     // Pre-release warning
     if is_prerelease(current_tag) {
-        output.push_str("🚨 **This is a pre-release version** - Use with caution in production environments.\n\n");
+        output.push_str(&format!("{}\n\n", t("prerelease_warning")));
+    }
+
+    output
+}
+
+/// Category counts and top contributors over the whole range. When only
+/// `shown` commits are listed, a notice says so.
+pub fn generate_summary_section(
+    summary: &RangeSummary,
+    shown: Option<usize>,
+    repo_info: &RepositoryInfo,
+    locale: DocumentLocale,
+) -> String {
+    let mut output = format!("## {}\n\n", locale.t("range_summary"));
+    if let Some(shown) = shown {
+        output.push_str(&format!(
+            "{}\n\n",
+            locale.format(
+                "truncated_notice",
+                &[
+                    ("total", &locale.format_number(summary.total_commits)),
+                    ("shown", &locale.format_number(shown)),
+                ],
+            )
+        ));
+    }
+
+    output.push_str(&format!("{}\n", locale.t("summary_header")));
+    output.push_str("|----------|---------|\n");
+    for (key, count) in summary.filled_categories() {
+        output.push_str(&format!(
+            "| {} | {} |\n",
+            locale.t(key),
+            locale.format_number(count)
+        ));
+    }
+    output.push('\n');
+
+    let contributors = summary.top_contributors(10);
+    if !contributors.is_empty() {
+        output.push_str(&format!("### {}\n\n", locale.t("top_contributors")));
+        for (email, name, count) in contributors {
+            output.push_str(&format!(
+                "{}\n",
+                format_github_username_with_stats(&email, &name, count, repo_info, locale)
+            ));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Totals of the diff between the tags and the files and directories with
+/// the most changed lines. Empty when nothing changed.
+pub fn generate_change_statistics_section(
+    changes: &ChangeStatistics,
+    locale: DocumentLocale,
+) -> String {
+    if changes.files.is_empty() {
+        return String::new();
+    }
+    let t = |key: &str| locale.t(key);
+    let totals = changes.totals;
+
+    let mut output = format!("## {}\n\n", t("change_statistics"));
+    output.push_str(&format!(
+        "- **{}:** {}\n",
+        t("files_changed"),
+        locale.format_number(totals.files_changed)
+    ));
+    output.push_str(&format!(
+        "- **{}:** +{}\n",
+        t("insertions"),
+        locale.format_number(totals.insertions)
+    ));
+    output.push_str(&format!(
+        "- **{}:** -{}\n\n",
+        t("deletions"),
+        locale.format_number(totals.deletions)
+    ));
+
+    output.push_str(&format!("### {}\n\n", t("most_changed_files")));
+    output.push_str(&format!("{}\n", t("changed_files_header")));
+    output.push_str("|------|-----------|-----------|\n");
+    for file in changes.top_files(10) {
+        output.push_str(&format!(
+            "| `{}` | +{} | -{} |\n",
+            file.path,
+            locale.format_number(file.insertions),
+            locale.format_number(file.deletions)
+        ));
+    }
+    output.push('\n');
+
+    output.push_str(&format!("### {}\n\n", t("most_changed_directories")));
+    output.push_str(&format!("{}\n", t("changed_directories_header")));
+    output.push_str("|-----------|-------|-------|\n");
+    for (directory, files, lines) in changes.top_directories(5) {
+        output.push_str(&format!(
+            "| `{}` | {} | {} |\n",
+            directory,
+            locale.format_number(files),
+            locale.format_number(lines)
+        ));
+    }
+    output.push('\n');
+
+    output
+}
+
+/// `old → new` commits per submodule, linked to the compare view of the
+/// submodule's repository when it's hosted on GitHub, GitLab or Bitbucket.
+/// Empty without changes.
+pub fn generate_submodule_section(changes: &[SubmoduleChange], locale: DocumentLocale) -> String {
+    if changes.is_empty() {
+        return String::new();
+    }
+    let short = |sha: &str| sha.chars().take(7).collect::<String>();
+
+    let mut output = format!("## {}\n\n", locale.t("submodule_updates"));
+    for change in changes {
+        let detail = match (&change.old, &change.new) {
+            (Some(old), Some(new)) => {
+                let range = format!("`{}` → `{}`", short(old), short(new));
+                let mut info = RepositoryInfo::default();
+                if let Some(url) = change
+                    .url
+                    .as_deref()
+                    .filter(|url| url.starts_with("https://"))
+                {
+                    info.url = url.to_string();
+                    parse_git_url(&mut info, url);
+                }
+                if info.is_github || info.is_gitlab || info.is_bitbucket {
+                    format!("[{}]({})", range, generate_compare_url(&info, old, new))
+                } else {
+                    range
+                }
+            }
+            (None, Some(new)) => {
+                locale.format("submodule_added", &[("new", &format!("`{}`", short(new)))])
+            }
+            (Some(old), None) => locale.format(
+                "submodule_removed",
+                &[("old", &format!("`{}`", short(old)))],
+            ),
+            (None, None) => continue,
+        };
+        output.push_str(&format!("- `{}`: {}\n", change.path, detail));
+    }
+    output.push('\n');
+    output
+}
+
+/// Commits per CODEOWNERS team, most active first, e.g.
+/// `- **Frontend** (owned by @org/web): 12 commits`. Empty without owned
+/// changes.
+pub fn generate_team_section(teams: &TeamSummary, locale: DocumentLocale) -> String {
+    if teams.is_empty() {
+        return String::new();
+    }
+    let mut output = format!("## {}\n\n", locale.t("changes_by_team"));
+    for team in teams.ranked() {
+        let owners = team.owners.join(", ");
+        let count = locale.format(
+            "team_commits",
+            &[("count", &locale.format_number(team.commits))],
+        );
+        match &team.area {
+            Some(area) => output.push_str(&format!(
+                "- **{}** ({}): {}\n",
+                area,
+                locale.format("owned_by", &[("owners", &owners)]),
+                count
+            )),
+            None => output.push_str(&format!("- {}: {}\n", owners, count)),
+        }
+    }
+    if teams.unowned > 0 {
+        output.push_str(&format!(
+            "- {}: {}\n",
+            locale.t("unowned_changes"),
+            locale.format(
+                "team_commits",
+                &[("count", &locale.format_number(teams.unowned))],
+            )
+        ));
+    }
+    output.push('\n');
+    output
+}
+
+/// Changes reverted before the release, e.g.
+/// `- feat: search (`1a2b3c4`, reverted in `5d6e7f8`)`. Empty without any.
+pub fn generate_reverted_section(reverted: &[RevertedChange], locale: DocumentLocale) -> String {
+    if reverted.is_empty() {
+        return String::new();
+    }
+    let short = |hash: &str| hash.chars().take(7).collect::<String>();
+    let mut output = format!("## {}\n\n", locale.t("reverted_changes"));
+    for change in reverted {
+        output.push_str(&format!(
+            "- {} (`{}`, {})\n",
+            change.subject,
+            short(&change.hash),
+            locale.format(
+                "reverted_in",
+                &[("hash", &format!("`{}`", short(&change.revert_hash)))]
+            )
+        ));
+    }
+    output.push('\n');
+    output
+}
+
+fn full_changelog_section(
+    repo_info: &RepositoryInfo,
+    current_tag: &str,
+    previous_tag: &Option<String>,
+    locale: DocumentLocale,
+) -> String {
+    let url = match previous_tag {
+        Some(prev_tag) => generate_compare_url(repo_info, prev_tag, current_tag),
+        None => generate_commits_url(repo_info, current_tag),
+    };
+    format!(
+        "## {}\n\n**{}**: {}\n\n",
+        locale.t("full_changelog"),
+        locale.t("full_changelog_label"),
+        url
+    )
+}
+
+/// Notes for `--summary-only`: counts instead of a commit list.
+pub fn generate_summary_release_notes(
+    repo_info: &RepositoryInfo,
+    current_tag: &str,
+    previous_tag: &Option<String>,
+    summary: &RangeSummary,
+    locale: DocumentLocale,
+) -> String {
+    let range = summary.first_timestamp.zip(summary.last_timestamp);
+    let mut output = notes_header(
+        repo_info,
+        current_tag,
+        previous_tag,
+        summary.total_commits,
+        range,
+        locale,
+    );
+    output.push_str(&generate_summary_section(summary, None, repo_info, locale));
+    if let Some(changes) = &summary.changes {
+        output.push_str(&generate_change_statistics_section(changes, locale));
+    }
+    output.push_str(&generate_submodule_section(&summary.submodules, locale));
+    if let Some(teams) = &summary.teams {
+        output.push_str(&generate_team_section(teams, locale));
+    }
+    output.push_str(&generate_reverted_section(&summary.reverted, locale));
+    output.push_str(&full_changelog_section(
+        repo_info,
+        current_tag,
+        previous_tag,
+        locale,
+    ));
+    output
+}
+
+/// Built-in layout. With a `summary` (the commit list was cut at
+/// `--max-commits`), totals and contributors come from the whole range.
+fn generate_comprehensive_release_notes(
+    repo_info: &RepositoryInfo,
+    current_tag: &str,
+    previous_tag: &Option<String>,
+    commits: &[CommitInfo],
+    references: &HashMap<u64, Reference>,
+    range_summary: &RangeSummary,
+    locale: DocumentLocale,
+) -> String {
+    let t = |key: &str| locale.t(key);
+    let link = |message: &str| link_references(message, references, repo_info);
+    let summary = (commits.len() < range_summary.total_commits).then_some(range_summary);
+
+    // Commits are sorted by time, newest first
+    let range = match summary {
+        Some(summary) => summary.first_timestamp.zip(summary.last_timestamp),
+        None => commits
+            .last()
+            .zip(commits.first())
+            .map(|(oldest, newest)| (oldest.timestamp, newest.timestamp)),
+    };
+    let mut output = notes_header(
+        repo_info,
+        current_tag,
+        previous_tag,
+        summary.map_or(commits.len(), |summary| summary.total_commits),
+        range,
+        locale,
+    );
+
+    if let Some(summary) = summary {
+        output.push_str(&generate_summary_section(
+            summary,
+            Some(commits.len()),
+            repo_info,
+            locale,
+        ));
+    }
+    if let Some(changes) = &range_summary.changes {
+        output.push_str(&generate_change_statistics_section(changes, locale));
+    }
+    output.push_str(&generate_submodule_section(
+        &range_summary.submodules,
+        locale,
+    ));
+    if let Some(teams) = &range_summary.teams {
+        output.push_str(&generate_team_section(teams, locale));
     }
 
     // Categorize commits
@@ -730,149 +2135,136 @@ fn generate_comprehensive_release_notes(
 
     // Breaking changes (highest priority)
     if !categorized.breaking_changes.is_empty() {
-        output.push_str("## ⚠️ Breaking Changes\n\n");
-        output.push_str("🚨 **Important:** This release contains breaking changes. Please review the migration guide before upgrading.\n\n");
+        output.push_str(&format!("## {}\n\n", t("breaking_changes")));
+        output.push_str(&format!("{}\n\n", t("breaking_notice")));
         for change in &categorized.breaking_changes {
-            output.push_str(&format!("- {}\n", change));
+            output.push_str(&format!("- {}\n", link(change)));
         }
         output.push('\n');
     }
 
     // Security updates
     if !categorized.security.is_empty() {
-        output.push_str("## 🔒 Security Updates\n\n");
-        output.push_str("🛡️ **Security patches included in this release:**\n\n");
+        output.push_str(&format!("## {}\n\n", t("security_updates")));
+        output.push_str(&format!("{}\n\n", t("security_notice")));
         for security in &categorized.security {
-            output.push_str(&format!("- {}\n", security));
+            output.push_str(&format!("- {}\n", link(security)));
         }
         output.push('\n');
     }
 
     // Features
     if !categorized.features.is_empty() {
-        output.push_str("## ✨ New Features\n\n");
+        output.push_str(&format!("## {}\n\n", t("new_features")));
         for feature in &categorized.features {
-            output.push_str(&format!("- {}\n", feature));
+            output.push_str(&format!("- {}\n", link(feature)));
         }
         output.push('\n');
     }
 
     // Bug fixes
     if !categorized.fixes.is_empty() {
-        output.push_str("## 🐛 Bug Fixes\n\n");
+        output.push_str(&format!("## {}\n\n", t("bug_fixes")));
         for fix in &categorized.fixes {
-            output.push_str(&format!("- {}\n", fix));
+            output.push_str(&format!("- {}\n", link(fix)));
         }
         output.push('\n');
     }
 
     // Improvements
     if !categorized.improvements.is_empty() {
-        output.push_str("## 🔧 Improvements\n\n");
+        output.push_str(&format!("## {}\n\n", t("improvements")));
         for improvement in &categorized.improvements {
-            output.push_str(&format!("- {}\n", improvement));
+            output.push_str(&format!("- {}\n", link(improvement)));
         }
         output.push('\n');
     }
 
     // Translations
     if !categorized.translations.is_empty() {
-        output.push_str("## 🌍 Translation Updates\n\n");
+        output.push_str(&format!("## {}\n\n", t("translation_updates")));
         for translation in &categorized.translations {
-            output.push_str(&format!("- {}\n", translation));
+            output.push_str(&format!("- {}\n", link(translation)));
         }
         output.push('\n');
     }
 
     // Documentation
     if !categorized.docs.is_empty() {
-        output.push_str("## 📚 Documentation\n\n");
+        output.push_str(&format!("## {}\n\n", t("documentation")));
         for doc in &categorized.docs {
-            output.push_str(&format!("- {}\n", doc));
+            output.push_str(&format!("- {}\n", link(doc)));
         }
         output.push('\n');
     }
 
     // Dependencies
     if !categorized.deps.is_empty() {
-        output.push_str("## 📦 Dependencies\n\n");
+        output.push_str(&format!("## {}\n\n", t("dependencies")));
         for dep in &categorized.deps {
-            output.push_str(&format!("- {}\n", dep));
+            output.push_str(&format!("- {}\n", link(dep)));
         }
         output.push('\n');
     }
 
     // Other changes (if any significant ones exist)
     if !categorized.other.is_empty() && categorized.other.len() <= 10 {
-        output.push_str("## 🔄 Other Changes\n\n");
+        output.push_str(&format!("## {}\n\n", t("other_changes")));
         for other in &categorized.other {
-            output.push_str(&format!("- {}\n", other));
+            output.push_str(&format!("- {}\n", link(other)));
         }
         output.push('\n');
     }
 
-    // Contributors with commit stats
+    output.push_str(&generate_reverted_section(&range_summary.reverted, locale));
+
+    // Contributors with commit stats, already in the summary when the list is cut
     let contributors = get_contributors_with_stats(commits);
-    if !contributors.is_empty() {
-        output.push_str("## 👥 Contributors\n\n");
-        output.push_str("Thanks to all the contributors who made this release possible:\n\n");
+    if !contributors.is_empty() && summary.is_none() {
+        output.push_str(&format!("## {}\n\n", t("contributors")));
+        output.push_str(&format!("{}\n\n", t("contributors_thanks")));
         for (email, name, commit_count) in contributors {
             let formatted_contributor =
-                format_github_username_with_stats(&email, &name, commit_count, repo_info);
+                format_github_username_with_stats(&email, &name, commit_count, repo_info, locale);
             output.push_str(&format!("{}\n", formatted_contributor));
         }
         output.push('\n');
     }
 
     // Installation instructions
-    output.push_str("## 🚀 Installation & Upgrade\n\n");
-    output.push_str("### For new projects:\n");
+    output.push_str(&format!("## {}\n\n", t("installation")));
+    output.push_str(&format!("### {}\n", t("new_projects")));
     output.push_str("```bash\n");
     output.push_str(&format!("git clone {}\n", repo_info.url));
     output.push_str(&format!("cd {}\n", repo_info.name));
     output.push_str(&format!("git checkout {}\n", current_tag));
 
     // Smart build instructions based on project type
-    if repo_info.name.to_lowercase().contains("rust") || repo_info.url.contains("rust") {
-        output.push_str("cargo build --release\n");
-    } else if repo_info.name.to_lowercase().contains("node")
-        || repo_info.url.contains("node")
-        || repo_info.name.to_lowercase().contains("js")
-    {
-        output.push_str("npm install\n");
-        output.push_str("npm run build\n");
-    } else if repo_info.name.to_lowercase().contains("python") || repo_info.url.contains("python") {
-        output.push_str("pip install -r requirements.txt\n");
-    } else {
-        output.push_str("# Follow project-specific build instructions\n");
+    match install_commands(repo_info) {
+        Some((build, _)) => build
+            .iter()
+            .for_each(|command| output.push_str(&format!("{}\n", command))),
+        None => output.push_str(&format!("{}\n", t("build_instructions"))),
     }
     output.push_str("```\n\n");
 
-    output.push_str("### For existing projects:\n");
+    output.push_str(&format!("### {}\n", t("existing_projects")));
     output.push_str("```bash\n");
     output.push_str("git pull origin main\n");
     output.push_str(&format!("git checkout {}\n", current_tag));
 
-    if repo_info.name.to_lowercase().contains("rust") || repo_info.url.contains("rust") {
-        output.push_str("cargo update\n");
-        output.push_str("cargo build --release\n");
-    } else if repo_info.name.to_lowercase().contains("node")
-        || repo_info.url.contains("node")
-        || repo_info.name.to_lowercase().contains("js")
-    {
-        output.push_str("npm update\n");
-        output.push_str("npm run build\n");
-    } else if repo_info.name.to_lowercase().contains("python") || repo_info.url.contains("python") {
-        output.push_str("pip install --upgrade -r requirements.txt\n");
-    } else {
-        output.push_str("# Follow project-specific update instructions\n");
+    match install_commands(repo_info) {
+        Some((_, update)) => update
+            .iter()
+            .for_each(|command| output.push_str(&format!("{}\n", command))),
+        None => output.push_str(&format!("{}\n", t("update_instructions"))),
     }
     output.push_str("```\n\n");
 
     // Detailed commit timeline (for smaller releases)
     if commits.len() <= 20 {
-        output.push_str("## 📊 Detailed Timeline\n\n");
-        output.push_str("| Date | Time | Commit | Author | Message |\n");
+        output.push_str(&format!("## {}\n\n", t("detailed_timeline")));
+        output.push_str(&format!("{}\n", t("timeline_header")));
         output.push_str("|------|------|--------|--------|---------|\n");
         for commit in commits.iter().take(20) {
             let short_message = commit
@@ -891,8 +2283,8 @@ fn generate_comprehensive_release_notes(
 
             output.push_str(&format!(
                 "| {} | {} | `{}` | {} | {} |\n",
-                commit.format_date(),
-                commit.format_time(),
+                commit.format_date(locale),
+                commit.format_time(locale),
                 commit.short_hash(),
                 commit.author_name,
                 short_message
@@ -902,63 +2294,68 @@ fn generate_comprehensive_release_notes(
     }
 
     // Full changelog
-    output.push_str("## 📝 Full Changelog\n\n");
-    if let Some(ref prev_tag) = previous_tag {
-        output.push_str(&format!(
-            "**Full Changelog**: {}\n",
-            generate_compare_url(repo_info, prev_tag, current_tag)
-        ));
-    } else {
-        output.push_str(&format!(
-            "**Full Changelog**: {}\n",
-            generate_commits_url(repo_info, current_tag)
-        ));
-    }
-    output.push('\n');
+    output.push_str(&full_changelog_section(
+        repo_info,
+        current_tag,
+        previous_tag,
+        locale,
+    ));
 
     // Additional information
     output.push_str("---\n\n");
-    output.push_str("### 🔗 Useful Links\n\n");
+    output.push_str(&format!("### {}\n\n", t("useful_links")));
     output.push_str(&format!(
-        "- 📖 **Documentation**: [README.md]({}#readme)\n",
+        "- {}: [README.md]({}#readme)\n",
+        t("docs_link"),
         repo_info.url.trim_end_matches(".git")
     ));
 
     if repo_info.is_github {
         output.push_str(&format!(
-            "- 💬 **Discussions**: [GitHub Discussions]({}/discussions)\n",
+            "- {}: [{}]({}/discussions)\n",
+            t("discussions_link"),
+            t("discussions"),
             repo_info.url.trim_end_matches(".git")
         ));
     }
 
     output.push_str(&format!(
-        "- 🐛 **Report Issues**: [Issues]({})\n",
+        "- {}: [{}]({})\n",
+        t("issues_link"),
+        t("issues"),
         generate_issues_url(repo_info)
     ));
     output.push('\n');
 
-    output.push_str("### 🆘 Getting Help\n\n");
-    output.push_str("If you encounter any issues with this release:\n\n");
-    output.push_str("1. Check the project documentation and README\n");
+    output.push_str(&format!("### {}\n\n", t("getting_help")));
+    output.push_str(&format!("{}\n\n", t("help_intro")));
+    output.push_str(&format!("{}\n", t("help_docs")));
     output.push_str(&format!(
-        "2. Search [existing issues]({})\n",
-        generate_issues_url(repo_info)
+        "{}\n",
+        locale.format("help_search", &[("url", &generate_issues_url(repo_info))])
     ));
     output.push_str(&format!(
-        "3. Create a [new issue]({}) with detailed information\n",
-        generate_new_issue_url(repo_info)
+        "{}\n",
+        locale.format("help_new", &[("url", &generate_new_issue_url(repo_info))])
     ));
     output.push('\n');
 
     output.push_str("---\n\n");
-    output.push_str(&format!("**Enjoy building with {}! 🚀**\n", repo_info.name));
+    output.push_str(&format!(
+        "{}\n",
+        locale.format("enjoy", &[("name", &repo_info.name)])
+    ));
 
     output
 }
 
+/// `- subject` lines of the commits in the range of the repository in
+/// `dir`, without the ones `filter` excludes.
 pub fn generate_release_notes_for_version(
+    dir: &Path,
     from_tag: Option<&str>,
     to_tag: Option<&str>,
+    filter: &CommitFilter,
 ) -> Result<String> {
     let range = match (from_tag, to_tag) {
         (Some(from), Some(to)) => format!("{}..{}", from, to),
@@ -967,13 +2364,28 @@ pub fn generate_release_notes_for_version(
         (None, None) => "HEAD".to_string(),
     };
 
-    let output = Command::new("git")
-        .args(["log", &range, "--oneline", "--pretty=format:- %s"])
-        .output()?;
+    // Author, email and full message, so the filter sees bodies and bots
+    let output = SystemRunner.run(
+        "git",
+        &["log", &range, "--pretty=format:%an%x1f%ae%x1f%B%x1e"],
+        Some(dir),
+    )?;
 
-    if !output.status.success() {
+    if !output.success {
         return Err(anyhow::anyhow!("Failed to generate git log"));
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    let lines: Vec<String> = output
+        .stdout
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(3, '\x1f');
+            let (name, email, message) = (fields.next()?, fields.next()?, fields.next()?);
+            if filter.excludes(name, email, message) {
+                return None;
+            }
+            Some(format!("- {}", message.lines().next().unwrap_or_default()))
+        })
+        .collect();
+    Ok(lines.join("\n"))
 }
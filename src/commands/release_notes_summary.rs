@@ -0,0 +1,171 @@
+use crate::commands::config::{load_saved_config, ConfigError};
+use crate::commands::release_notes::ReleaseNotesData;
+use crate::commands::translation_cache::{cache_scope, TranslationCache};
+use crate::commands::translation_glossary::Glossary;
+use crate::commands::translation_sync::{
+    create_provider, provider_settings, Language, TranslationConfig, TranslationProvider,
+};
+use crate::utils::document_locale::DocumentLocale;
+use crate::utils::{log_info, log_success};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const SUMMARY_CACHE_FILE: &str = ".nitrokit/summary-cache.json";
+
+/// Subjects per category sent to the provider; the rest are only counted.
+pub const MAX_PROMPT_COMMITS: usize = 50;
+
+/// What the provider writes about a release: an overview paragraph and a
+/// few polished bullet points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseSummary {
+    pub summary: String,
+    #[serde(default)]
+    pub highlights: Vec<String>,
+}
+
+/// The categorized commit subjects, the only thing the provider gets to
+/// see. Identical ranges give identical prompts, which keeps the cache hit.
+pub fn summary_prompt(data: &ReleaseNotesData, locale: DocumentLocale) -> String {
+    let mut commits = String::new();
+    for (category, entries) in &data.categories {
+        commits.push_str(&format!("{}:\n", category));
+        for entry in entries.iter().take(MAX_PROMPT_COMMITS) {
+            commits.push_str(&format!("- {}\n", entry.subject));
+        }
+        if entries.len() > MAX_PROMPT_COMMITS {
+            commits.push_str(&format!(
+                "- ...and {} more\n",
+                entries.len() - MAX_PROMPT_COMMITS
+            ));
+        }
+    }
+
+    format!(
+        "You are writing the introduction of the release notes for {} {}. From the \
+         categorized commits below, write a short executive summary paragraph for users and \
+         3 to 6 polished bullet points with the most important changes. Group related commits, \
+         skip chores and internal refactors unless they matter to users, and don't invent \
+         anything the commits don't say. Write in {}. Reply with JSON only, in the form \
+         {{\"summary\": \"...\", \"highlights\": [\"...\"]}}.\n\n{}",
+        data.repository.trim_end_matches(".git"),
+        data.tag,
+        Language::from_code(locale.code()).name,
+        commits
+    )
+}
+
+/// Reads the JSON reply, also when the model wrapped it in a code fence.
+pub fn parse_summary_reply(reply: &str) -> Result<ReleaseSummary> {
+    let trimmed = reply.trim();
+    let json = match (trimmed.find('{'), trimmed.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &trimmed[start..=end],
+        _ => return Err(anyhow!("Summary reply contains no JSON object")),
+    };
+    let summary: ReleaseSummary = serde_json::from_str(json)
+        .map_err(|e| anyhow!("Summary reply is not valid JSON: {}", e))?;
+    if summary.summary.trim().is_empty() {
+        return Err(anyhow!("Summary reply has an empty summary"));
+    }
+    Ok(summary)
+}
+
+/// Asks the provider for a summary of the release, or takes it from
+/// `cache` when the same commits were summarized before.
+pub async fn summarize(
+    provider: &dyn TranslationProvider,
+    cache: &mut TranslationCache,
+    data: &ReleaseNotesData,
+    locale: DocumentLocale,
+) -> Result<ReleaseSummary> {
+    let prompt = summary_prompt(data, locale);
+    let cache_key = format!("summary-{}", locale.code());
+    let scope = cache_scope(provider.name(), provider.model(), &Glossary::default());
+    if let Some(reply) = cache.get(&scope, &cache_key, &prompt) {
+        if let Ok(summary) = parse_summary_reply(reply) {
+            log_info("Reusing the cached summary of these commits");
+            return Ok(summary);
+        }
+    }
+
+    let reply = provider.complete(&prompt).await?;
+    let summary = parse_summary_reply(&reply)?;
+    cache.insert(&scope, &cache_key, &prompt, &reply);
+    Ok(summary)
+}
+
+/// The summary as a section of its own, marked as written by `provider`.
+pub fn summary_section(summary: &ReleaseSummary, provider: &str, locale: DocumentLocale) -> String {
+    let mut section = format!("## {}\n\n", locale.t("ai_summary"));
+    section.push_str(&format!(
+        "{}\n\n",
+        locale.format("ai_summary_notice", &[("provider", provider)])
+    ));
+    section.push_str(&format!("{}\n\n", summary.summary.trim()));
+    for highlight in &summary.highlights {
+        let highlight = highlight.trim().trim_start_matches(['-', '*']).trim();
+        if !highlight.is_empty() {
+            section.push_str(&format!("- {}\n", highlight));
+        }
+    }
+    if !summary.highlights.is_empty() {
+        section.push('\n');
+    }
+    section.push_str("---\n\n");
+    section
+}
+
+/// Puts `section` in front of the first `##` heading, right after the
+/// header with the release date and totals, or at the end without one.
+pub fn insert_summary(notes: &str, section: &str) -> String {
+    let position = if notes.starts_with("## ") {
+        Some(0)
+    } else {
+        notes.find("\n## ").map(|index| index + 1)
+    };
+    match position {
+        Some(index) => format!("{}{}{}", &notes[..index], section, &notes[index..]),
+        None => format!("{}\n\n{}", notes.trim_end(), section.trim_end()),
+    }
+}
+
+/// Adds a summary written by the configured translation provider to the
+/// release notes at `path`, above the commit list it was written from.
+/// Replies are cached in `dir`, so regenerating the notes for the same
+/// commits costs no further API calls.
+pub async fn summarize_release_notes(
+    dir: &Path,
+    path: &Path,
+    data: &ReleaseNotesData,
+    locale: DocumentLocale,
+) -> Result<()> {
+    if data.categories.is_empty() {
+        return Err(anyhow!("No commits to summarize"));
+    }
+    let app_config = load_saved_config().await;
+    let (provider_kind, api_key, _) = provider_settings(&app_config);
+    if api_key.is_none() {
+        return Err(ConfigError::MissingApiKey {
+            provider: provider_kind.display_name(),
+            env: provider_kind.api_key_env(),
+        }
+        .into());
+    }
+    let provider = create_provider(&TranslationConfig::from(app_config))?;
+    log_info(&format!(
+        "Summarizing {} commit(s) with {}...",
+        data.total_commits,
+        provider.name()
+    ));
+
+    let mut cache = TranslationCache::load(&dir.join(SUMMARY_CACHE_FILE));
+    let summary = summarize(provider.as_ref(), &mut cache, data, locale).await?;
+    cache.save()?;
+
+    let notes = std::fs::read_to_string(path)?;
+    let section = summary_section(&summary, provider.name(), locale);
+    std::fs::write(path, insert_summary(&notes, &section))?;
+    log_success("Summary added to the release notes");
+    Ok(())
+}
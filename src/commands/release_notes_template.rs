@@ -0,0 +1,239 @@
+use crate::commands::release_notes::{
+    categorize_commits, format_github_username_with_stats, generate_commits_url,
+    generate_compare_url, generate_issues_url, generate_new_issue_url, get_contributors_with_stats,
+    github_username, install_commands, is_prerelease, link_references, CommitInfo, Reference,
+    RepositoryInfo,
+};
+use crate::utils::document_locale::{timezone, DocumentLocale};
+use anyhow::{anyhow, Result};
+use handlebars::Handlebars;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Starting point written by `release-notes --init-template`. Mirrors the
+/// built-in layout without the installation and help blocks.
+pub const EXAMPLE_TEMPLATE: &str = r#"# 📋 {{title}}
+
+- **{{t.release_date}}:** {{release_date}}
+- **{{t.repository}}:** {{repo.url}}
+- **{{t.total_commits}}:** {{stats.total_commits}}
+{{#if prerelease}}
+
+{{t.prerelease_warning}}
+{{/if}}
+
+{{#each sections}}
+## {{title}}
+
+{{#if notice}}
+{{notice}}
+
+{{/if}}
+{{#each items}}
+- {{this}}
+{{/each}}
+{{/each}}
+{{#if commits.others}}
+## {{t.other_changes}}
+
+{{#each commits.others}}
+- {{this}}
+{{/each}}
+
+{{/if}}
+{{#if contributors}}
+## {{t.contributors}}
+
+{{#each contributors}}
+{{line}}
+{{/each}}
+
+{{/if}}
+## {{t.full_changelog}}
+
+**{{t.full_changelog_label}}**: {{links.changelog}}
+"#;
+
+/// Sections of the built-in layout, in its order: category, title, notice.
+const SECTIONS: [(&str, &str, Option<&str>); 9] = [
+    (
+        "breaking_changes",
+        "breaking_changes",
+        Some("breaking_notice"),
+    ),
+    ("security", "security_updates", Some("security_notice")),
+    ("features", "new_features", None),
+    ("fixes", "bug_fixes", None),
+    ("improvements", "improvements", None),
+    ("translations", "translation_updates", None),
+    ("docs", "documentation", None),
+    ("deps", "dependencies", None),
+    ("other", "other_changes", None),
+];
+
+/// Everything a release notes template can use. Commit messages are
+/// already linked to their PRs and issues.
+pub fn template_context(
+    repo_info: &RepositoryInfo,
+    current_tag: &str,
+    previous_tag: &Option<String>,
+    commits: &[CommitInfo],
+    references: &HashMap<u64, Reference>,
+    locale: DocumentLocale,
+) -> Value {
+    let link = |messages: &[String]| -> Vec<String> {
+        messages
+            .iter()
+            .map(|message| link_references(message, references, repo_info))
+            .collect()
+    };
+
+    let categorized = categorize_commits(commits);
+    let buckets: [(&str, &Vec<String>); 15] = [
+        ("breaking_changes", &categorized.breaking_changes),
+        ("security", &categorized.security),
+        ("features", &categorized.features),
+        ("fixes", &categorized.fixes),
+        ("improvements", &categorized.improvements),
+        ("translations", &categorized.translations),
+        ("docs", &categorized.docs),
+        ("deps", &categorized.deps),
+        ("styles", &categorized.styles),
+        ("refactor", &categorized.refactor),
+        ("perf", &categorized.perf),
+        ("tests", &categorized.tests),
+        ("chores", &categorized.chores),
+        ("others", &categorized.others),
+        ("other", &categorized.other),
+    ];
+    let by_category: Map<String, Value> = buckets
+        .iter()
+        .map(|(name, messages)| (name.to_string(), json!(link(messages))))
+        .collect();
+
+    let sections: Vec<Value> = SECTIONS
+        .iter()
+        .filter(|(category, _, _)| {
+            by_category[*category]
+                .as_array()
+                .is_some_and(|items| !items.is_empty())
+        })
+        .map(|(category, title, notice)| {
+            json!({
+                "key": category,
+                "title": locale.t(title),
+                "notice": notice.map(|notice| locale.t(notice)),
+                "items": by_category[*category],
+            })
+        })
+        .collect();
+
+    let contributors: Vec<Value> = get_contributors_with_stats(commits)
+        .into_iter()
+        .map(|(email, name, count)| {
+            json!({
+                "name": name,
+                "email": email,
+                "github": github_username(&email).filter(|_| repo_info.is_github),
+                "commits": count,
+                "commits_text": locale.commits(count),
+                "line": format_github_username_with_stats(&email, &name, count, repo_info, locale),
+            })
+        })
+        .collect();
+
+    let timeline: Vec<Value> = commits
+        .iter()
+        .map(|commit| {
+            json!({
+                "date": commit.format_date(locale),
+                "time": commit.format_time(locale),
+                "hash": commit.short_hash(),
+                "author": commit.author_name,
+                "subject": commit.message.lines().next().unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    let web_url = repo_info.url.trim_end_matches(".git");
+    let changelog = match previous_tag {
+        Some(previous) => generate_compare_url(repo_info, previous, current_tag),
+        None => generate_commits_url(repo_info, current_tag),
+    };
+
+    let (build, update) = install_commands(repo_info).unwrap_or_default();
+
+    let title = match previous_tag {
+        Some(previous) => locale.format("changes_since", &[("tag", previous)]),
+        None => locale.t("initial_release"),
+    };
+
+    json!({
+        "title": title,
+        "repo": {
+            "url": web_url,
+            "clone_url": repo_info.url,
+            "name": repo_info.name,
+            "owner": repo_info.owner,
+            "is_github": repo_info.is_github,
+            "is_gitlab": repo_info.is_gitlab,
+            "is_bitbucket": repo_info.is_bitbucket,
+        },
+        "tag": current_tag,
+        "previous_tag": previous_tag,
+        "prerelease": is_prerelease(current_tag),
+        "locale": locale.code(),
+        "release_date": locale.format_date(timezone().now().date_naive()),
+        "commit_count": commits.len(),
+        "stats": {
+            "total_commits": locale.format_number(commits.len()),
+            "contributors": contributors.len(),
+            // Commits are sorted newest first
+            "first_commit_date": commits.last().map(|commit| commit.format_date(locale)),
+            "last_commit_date": commits.first().map(|commit| commit.format_date(locale)),
+        },
+        "sections": sections,
+        "commits": by_category,
+        "contributors": contributors,
+        "timeline": timeline,
+        "install": {
+            "clone": format!("git clone {}", repo_info.url),
+            "build": build,
+            "update": update,
+        },
+        "links": {
+            "changelog": changelog,
+            "issues": generate_issues_url(repo_info),
+            "new_issue": generate_new_issue_url(repo_info),
+        },
+        "t": locale.labels(),
+    })
+}
+
+/// Renders a Handlebars template. Output is Markdown, so nothing is
+/// HTML-escaped.
+pub fn render_template(template: &str, context: &Value) -> Result<String> {
+    let mut handlebars = Handlebars::new();
+    handlebars.register_escape_fn(handlebars::no_escape);
+    handlebars
+        .register_template_string("release_notes", template)
+        .map_err(|e| anyhow!("Invalid release notes template: {}", e))?;
+    handlebars
+        .render("release_notes", context)
+        .map_err(|e| anyhow!("Failed to render release notes template: {}", e))
+}
+
+pub fn render_template_file(path: &Path, context: &Value) -> Result<String> {
+    let template = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Cannot read template {}: {}", path.display(), e))?;
+    render_template(&template, context)
+}
+
+pub fn write_example_template(path: &Path) -> Result<()> {
+    if path.exists() {
+        return Err(anyhow!("{} already exists", path.display()));
+    }
+    std::fs::write(path, EXAMPLE_TEMPLATE)?;
+    Ok(())
+}
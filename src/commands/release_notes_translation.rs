@@ -0,0 +1,191 @@
+use crate::commands::config::{AppConfig, ConfigError, ConfigManager};
+use crate::commands::translation_sync::{
+    create_provider, provider_settings, Language, TranslationConfig, TranslationProvider,
+};
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Characters per provider request; keeps replies under the output limit.
+pub const MAX_CHUNK_CHARS: usize = 4000;
+
+fn token(index: usize) -> String {
+    format!("%%NT{}%%", index)
+}
+
+/// Replaces code blocks, inline code and link targets with `%%NTn%%` tokens
+/// so the model can't translate or reflow them. Returns the text and the
+/// originals in token order.
+pub fn protect_markdown(text: &str) -> (String, Vec<String>) {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(r"(?s)```.*?```|`[^`\n]+`|\]\([^)\s]+\)|<https?://[^>\s]+>").unwrap()
+    });
+
+    let mut originals = Vec::new();
+    let protected = pattern.replace_all(text, |caps: &regex::Captures| {
+        let matched = &caps[0];
+        // Keep the link text translatable, only the target is protected
+        if let Some(target) = matched.strip_prefix("](") {
+            originals.push(target.trim_end_matches(')').to_string());
+            format!("]({})", token(originals.len() - 1))
+        } else {
+            originals.push(matched.to_string());
+            token(originals.len() - 1)
+        }
+    });
+    (protected.to_string(), originals)
+}
+
+/// Puts the protected parts back. Fails when the model dropped a token.
+pub fn restore_markdown(text: &str, originals: &[String]) -> Result<String> {
+    let mut restored = text.to_string();
+    for (index, original) in originals.iter().enumerate() {
+        let token = token(index);
+        if !restored.contains(&token) {
+            return Err(anyhow!("Translation lost protected segment {}", token));
+        }
+        restored = restored.replace(&token, original);
+    }
+    Ok(restored)
+}
+
+/// Splits Markdown into chunks of at most `max_chars`, cutting before
+/// headings where possible and never inside a line.
+pub fn split_chunks(text: &str, max_chars: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in text.split_inclusive('\n') {
+        let full = current.len() + line.len() > max_chars;
+        let heading_break = line.starts_with('#') && current.len() > max_chars / 2;
+        if !current.is_empty() && (full || heading_break) {
+            chunks.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+pub fn markdown_prompt(chunk: &str, language: &Language) -> String {
+    format!(
+        "Translate the following Markdown release notes to {}. Keep the Markdown structure \
+         exactly: headings, list markers, emphasis, tables and line breaks. Leave every token \
+         like %%NT0%% unchanged and in place, as well as emojis, version numbers, commit \
+         hashes, @mentions and #numbers. Reply with the translated Markdown only.\n\n{}",
+        language.name, chunk
+    )
+}
+
+/// Drops a ```markdown fence the model may wrap its whole reply in.
+fn unwrap_reply(reply: &str) -> &str {
+    let trimmed = reply.trim();
+    match trimmed.strip_prefix("```") {
+        Some(rest) if trimmed.ends_with("```") && rest.len() >= 3 => {
+            let body = &rest[..rest.len() - 3];
+            body.split_once('\n').map_or(body, |(_, body)| body).trim()
+        }
+        _ => trimmed,
+    }
+}
+
+/// Translates Markdown chunk by chunk. Code, inline code and links come back
+/// byte for byte; a chunk whose reply lost any of them fails the language.
+pub async fn translate_markdown(
+    provider: &dyn TranslationProvider,
+    text: &str,
+    language: &Language,
+) -> Result<String> {
+    let (protected, originals) = protect_markdown(text);
+    let mut translated = String::new();
+
+    for chunk in split_chunks(&protected, MAX_CHUNK_CHARS) {
+        let content = chunk.trim();
+        if content.is_empty() {
+            translated.push_str(&chunk);
+            continue;
+        }
+        let reply = provider
+            .complete(&markdown_prompt(content, language))
+            .await?;
+        // Replies come back trimmed, the blank lines between chunks are kept
+        let leading = chunk.len() - chunk.trim_start().len();
+        let trailing = chunk.trim_end().len();
+        translated.push_str(&chunk[..leading]);
+        translated.push_str(unwrap_reply(&reply));
+        translated.push_str(&chunk[trailing..]);
+    }
+
+    restore_markdown(&translated, &originals)
+}
+
+/// `ReleaseNotes_v1.2.0_20240101.md` → `ReleaseNotes_v1.2.0_20240101_tr.md`
+pub fn localized_path(path: &Path, code: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_name = match path.extension() {
+        Some(extension) => format!("{}_{}.{}", stem, code, extension.to_string_lossy()),
+        None => format!("{}_{}", stem, code),
+    };
+    path.with_file_name(file_name)
+}
+
+/// Writes a localized copy of the release notes per language with the
+/// configured translation provider. A failing language is skipped.
+pub async fn translate_release_notes(path: &Path, codes: &[String]) -> Result<Vec<PathBuf>> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let (provider_kind, api_key, _) = provider_settings(&app_config);
+    if api_key.is_none() {
+        return Err(ConfigError::MissingApiKey {
+            provider: provider_kind.display_name(),
+            env: provider_kind.api_key_env(),
+        }
+        .into());
+    }
+    let provider = create_provider(&TranslationConfig::from(app_config))?;
+    let notes = std::fs::read_to_string(path)?;
+
+    let mut written = Vec::new();
+    for code in codes {
+        let language = Language::from_code(code.trim());
+        log_info(&format!(
+            "Translating release notes to {} {} with {}...",
+            language.flag,
+            language.name,
+            provider.name()
+        ));
+        match translate_markdown(provider.as_ref(), &notes, &language).await {
+            Ok(translated) => {
+                let target = localized_path(path, &language.code);
+                std::fs::write(&target, translated)?;
+                println!(
+                    "{}",
+                    format!("📄 File created: {}", target.display()).green()
+                );
+                written.push(target);
+            }
+            Err(e) => log_warning(&format!("Skipped {}: {}", language.name, e)),
+        }
+    }
+
+    if written.is_empty() {
+        return Err(anyhow!("No translation could be written"));
+    }
+    log_success(&format!(
+        "Release notes translated to {} of {} language(s)",
+        written.len(),
+        codes.len()
+    ));
+    Ok(written)
+}
@@ -0,0 +1,321 @@
+use crate::commands::quality_history::HISTORY_FILE;
+use crate::commands::release_notes_summary::SUMMARY_CACHE_FILE;
+use crate::commands::state::STATE_FILE;
+use crate::commands::translation_cache::CACHE_FILE;
+use crate::utils::error::ChecksFailed;
+use crate::utils::process::CommandRunner;
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::Deserialize;
+
+/// Outcome of one pre-flight check. Warnings are shown but don't block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreflightStatus {
+    Passed,
+    Warning,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreflightCheck {
+    pub name: &'static str,
+    pub status: PreflightStatus,
+    pub detail: String,
+}
+
+fn check(name: &'static str, status: PreflightStatus, detail: impl Into<String>) -> PreflightCheck {
+    PreflightCheck {
+        name,
+        status,
+        detail: detail.into(),
+    }
+}
+
+/// Caches and run history nitroterm keeps in the project. They don't make
+/// the tree dirty.
+pub const GENERATED_FILES: [&str; 4] = [STATE_FILE, HISTORY_FILE, SUMMARY_CACHE_FILE, CACHE_FILE];
+
+/// Nothing staged, modified or untracked, so the release commit only
+/// carries the version bump.
+pub fn check_clean_tree(runner: &dyn CommandRunner) -> PreflightCheck {
+    const NAME: &str = "Working tree clean";
+    match runner.run(
+        "git",
+        &["status", "--porcelain", "--untracked-files=all"],
+        None,
+    ) {
+        Ok(output) if output.success => {
+            let changed = output
+                .stdout
+                .lines()
+                .filter(|line| !line.is_empty())
+                .filter(|line| {
+                    !line
+                        .get(3..)
+                        .is_some_and(|path| GENERATED_FILES.contains(&path))
+                })
+                .count();
+            if changed == 0 {
+                check(NAME, PreflightStatus::Passed, "no uncommitted changes")
+            } else {
+                check(
+                    NAME,
+                    PreflightStatus::Failed,
+                    format!("{} uncommitted file(s), commit or stash them", changed),
+                )
+            }
+        }
+        Ok(output) => check(NAME, PreflightStatus::Failed, output.stderr.trim()),
+        Err(e) => check(NAME, PreflightStatus::Failed, e.to_string()),
+    }
+}
+
+/// HEAD is on the release branch; skipped when none is known.
+pub fn check_release_branch(runner: &dyn CommandRunner, expected: Option<&str>) -> PreflightCheck {
+    const NAME: &str = "Release branch";
+    let current = match runner.run("git", &["rev-parse", "--abbrev-ref", "HEAD"], None) {
+        Ok(output) if output.success => output.stdout.trim().to_string(),
+        Ok(output) => return check(NAME, PreflightStatus::Failed, output.stderr.trim()),
+        Err(e) => return check(NAME, PreflightStatus::Failed, e.to_string()),
+    };
+    match expected {
+        None => check(
+            NAME,
+            PreflightStatus::Warning,
+            format!("on {}, no release branch configured", current),
+        ),
+        Some(expected) if current == expected => {
+            check(NAME, PreflightStatus::Passed, format!("on {}", current))
+        }
+        Some(expected) if current == "HEAD" => check(
+            NAME,
+            PreflightStatus::Failed,
+            format!("detached HEAD, check out {}", expected),
+        ),
+        Some(expected) => check(
+            NAME,
+            PreflightStatus::Failed,
+            format!("on {}, releases are cut from {}", current, expected),
+        ),
+    }
+}
+
+pub fn check_local_tag(runner: &dyn CommandRunner, tag: &str) -> PreflightCheck {
+    const NAME: &str = "Tag is new locally";
+    let reference = format!("refs/tags/{}", tag);
+    match runner.run(
+        "git",
+        &["rev-parse", "--verify", "--quiet", &reference],
+        None,
+    ) {
+        Ok(output) if output.success => check(
+            NAME,
+            PreflightStatus::Failed,
+            format!("{} already exists", tag),
+        ),
+        Ok(_) => check(NAME, PreflightStatus::Passed, format!("{} is free", tag)),
+        Err(e) => check(NAME, PreflightStatus::Failed, e.to_string()),
+    }
+}
+
+/// One `git ls-remote` answers both: whether origin is reachable and
+/// whether it already has the tag.
+pub fn check_remote(runner: &dyn CommandRunner, tag: &str) -> Vec<PreflightCheck> {
+    const REMOTE: &str = "Remote reachable";
+    const TAG: &str = "Tag is new on origin";
+    let reference = format!("refs/tags/{}", tag);
+    match runner.run("git", &["ls-remote", "--tags", "origin", &reference], None) {
+        Ok(output) if output.success => {
+            let tag_check = if output.stdout.trim().is_empty() {
+                check(TAG, PreflightStatus::Passed, format!("{} is free", tag))
+            } else {
+                check(
+                    TAG,
+                    PreflightStatus::Failed,
+                    format!("{} was already pushed", tag),
+                )
+            };
+            vec![check(REMOTE, PreflightStatus::Passed, "origin"), tag_check]
+        }
+        Ok(output) => vec![
+            check(
+                REMOTE,
+                PreflightStatus::Failed,
+                output
+                    .stderr
+                    .lines()
+                    .find(|line| !line.trim().is_empty())
+                    .unwrap_or("git ls-remote origin failed")
+                    .trim(),
+            ),
+            check(
+                TAG,
+                PreflightStatus::Warning,
+                "not checked, origin unreachable",
+            ),
+        ],
+        Err(e) => vec![
+            check(REMOTE, PreflightStatus::Failed, e.to_string()),
+            check(
+                TAG,
+                PreflightStatus::Warning,
+                "not checked, origin unreachable",
+            ),
+        ],
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRun {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    conclusion: String,
+}
+
+/// Summarizes `gh run list --json name,status,conclusion` for a commit.
+pub fn ci_status_from_runs(json: &str) -> Result<(PreflightStatus, String)> {
+    let runs: Vec<WorkflowRun> =
+        serde_json::from_str(json).map_err(|e| anyhow!("Unexpected gh output: {}", e))?;
+    if runs.is_empty() {
+        return Ok((
+            PreflightStatus::Warning,
+            "no workflow runs for HEAD".to_string(),
+        ));
+    }
+
+    let failed: Vec<&str> = runs
+        .iter()
+        .filter(|run| {
+            run.status == "completed"
+                && !matches!(run.conclusion.as_str(), "success" | "skipped" | "neutral")
+        })
+        .map(|run| run.name.as_str())
+        .collect();
+    if !failed.is_empty() {
+        return Ok((
+            PreflightStatus::Failed,
+            format!("failed: {}", failed.join(", ")),
+        ));
+    }
+
+    let running: Vec<&str> = runs
+        .iter()
+        .filter(|run| run.status != "completed")
+        .map(|run| run.name.as_str())
+        .collect();
+    if !running.is_empty() {
+        return Ok((
+            PreflightStatus::Failed,
+            format!("still running: {}", running.join(", ")),
+        ));
+    }
+
+    Ok((
+        PreflightStatus::Passed,
+        format!("{} workflow run(s) green", runs.len()),
+    ))
+}
+
+/// CI of HEAD through `gh`. Without gh or a GitHub remote the check can
+/// only warn.
+pub fn check_ci_status(runner: &dyn CommandRunner) -> PreflightCheck {
+    const NAME: &str = "CI green on HEAD";
+    let head = match runner.run("git", &["rev-parse", "HEAD"], None) {
+        Ok(output) if output.success => output.stdout.trim().to_string(),
+        Ok(output) => return check(NAME, PreflightStatus::Failed, output.stderr.trim()),
+        Err(e) => return check(NAME, PreflightStatus::Failed, e.to_string()),
+    };
+    let output = match runner.run(
+        "gh",
+        &[
+            "run",
+            "list",
+            "--commit",
+            &head,
+            "--json",
+            "name,status,conclusion",
+        ],
+        None,
+    ) {
+        Ok(output) => output,
+        Err(_) => {
+            return check(
+                NAME,
+                PreflightStatus::Warning,
+                "gh is not installed, CI status not checked",
+            )
+        }
+    };
+    if !output.success {
+        return check(
+            NAME,
+            PreflightStatus::Warning,
+            format!("CI status not checked: {}", output.stderr.trim()),
+        );
+    }
+    match ci_status_from_runs(&output.stdout) {
+        Ok((status, detail)) => check(NAME, status, detail),
+        Err(e) => check(NAME, PreflightStatus::Warning, e.to_string()),
+    }
+}
+
+/// Every check, in the order they are reported.
+pub fn run_preflight(
+    runner: &dyn CommandRunner,
+    tag: &str,
+    release_branch: Option<&str>,
+) -> Vec<PreflightCheck> {
+    let mut checks = vec![
+        check_clean_tree(runner),
+        check_release_branch(runner, release_branch),
+        check_local_tag(runner, tag),
+    ];
+    checks.extend(check_remote(runner, tag));
+    checks.push(check_ci_status(runner));
+    checks
+}
+
+pub fn print_preflight(checks: &[PreflightCheck]) {
+    println!("\n{}", "🛫 Pre-flight checks".cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+    for check in checks {
+        let (icon, detail) = match check.status {
+            PreflightStatus::Passed => ("✅", check.detail.green()),
+            PreflightStatus::Warning => ("⚠️ ", check.detail.yellow()),
+            PreflightStatus::Failed => ("❌", check.detail.red()),
+        };
+        println!("  {} {}: {}", icon, check.name.bold(), detail);
+    }
+}
+
+/// Fails on any failed check unless `force` is set, in which case the
+/// failures are only reported.
+pub fn enforce_preflight(checks: &[PreflightCheck], force: bool) -> Result<()> {
+    let failed: Vec<&str> = checks
+        .iter()
+        .filter(|check| check.status == PreflightStatus::Failed)
+        .map(|check| check.name)
+        .collect();
+    if failed.is_empty() {
+        return Ok(());
+    }
+    if force {
+        println!(
+            "{}",
+            format!(
+                "⚠️  --force: releasing despite {} failed check(s)",
+                failed.len()
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+    Err(ChecksFailed(format!(
+        "Pre-flight checks failed: {} (fix them or pass --force)",
+        failed.join(", ")
+    ))
+    .into())
+}
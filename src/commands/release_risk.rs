@@ -0,0 +1,308 @@
+use crate::commands::code_quality::ReportFormat;
+use crate::commands::release_notes::{
+    categorize_commits, compare_version_tags, get_all_tags, is_version_tag, CommitInfo, DiffStats,
+};
+use crate::utils::theme::{label, paint, Role};
+use anyhow::Result;
+use colored::*;
+use git2::Repository;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default)]
+pub struct RiskSignals {
+    pub breaking_changes: usize,
+    pub commits: usize,
+    pub files_changed: usize,
+    pub lines_changed: usize,
+    pub major_dependency_bumps: usize,
+    pub failing_checks: usize,
+    pub days_since_last_release: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone)]
+pub struct RiskFactor {
+    pub name: String,
+    pub value: String,
+    pub points: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseRiskReport {
+    pub last_tag: Option<String>,
+    pub factors: Vec<RiskFactor>,
+    pub score: u32,
+    pub level: RiskLevel,
+}
+
+pub fn calculate_risk_score(signals: &RiskSignals) -> (Vec<RiskFactor>, u32, RiskLevel) {
+    let mut factors = Vec::new();
+
+    // Breaking changes weigh the most: each one is a potential upgrade blocker
+    factors.push(RiskFactor {
+        name: "Breaking changes".to_string(),
+        value: signals.breaking_changes.to_string(),
+        points: std::cmp::min(signals.breaking_changes as u32 * 15, 40),
+    });
+
+    let diff_points = match signals.lines_changed {
+        0..=200 => 0,
+        201..=1000 => 5,
+        1001..=5000 => 10,
+        _ => 20,
+    };
+    factors.push(RiskFactor {
+        name: "Diff size".to_string(),
+        value: format!(
+            "{} files, {} lines, {} commits",
+            signals.files_changed, signals.lines_changed, signals.commits
+        ),
+        points: diff_points,
+    });
+
+    factors.push(RiskFactor {
+        name: "Major dependency bumps".to_string(),
+        value: signals.major_dependency_bumps.to_string(),
+        points: std::cmp::min(signals.major_dependency_bumps as u32 * 5, 20),
+    });
+
+    factors.push(RiskFactor {
+        name: "Failing checks".to_string(),
+        value: signals.failing_checks.to_string(),
+        points: std::cmp::min(signals.failing_checks as u32 * 10, 30),
+    });
+
+    let age_points = match signals.days_since_last_release {
+        None => 5,
+        Some(days) if days > 180 => 15,
+        Some(days) if days > 60 => 10,
+        Some(days) if days > 30 => 5,
+        Some(_) => 0,
+    };
+    factors.push(RiskFactor {
+        name: "Days since last release".to_string(),
+        value: signals
+            .days_since_last_release
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "never released".to_string()),
+        points: age_points,
+    });
+
+    let score = std::cmp::min(factors.iter().map(|f| f.points).sum::<u32>(), 100);
+    let level = match score {
+        0..=29 => RiskLevel::Low,
+        30..=59 => RiskLevel::Medium,
+        _ => RiskLevel::High,
+    };
+
+    (factors, score, level)
+}
+
+pub fn assess_release_risk(path: &Path) -> Result<ReleaseRiskReport> {
+    let repo = Repository::open(path)?;
+    let last_tag = get_latest_version_tag(&repo);
+    let mut signals = RiskSignals::default();
+
+    let head_commit = repo.head()?.peel_to_commit()?;
+    let tag_commit = match &last_tag {
+        Some(tag) => Some(repo.revparse_single(tag)?.peel_to_commit()?),
+        None => None,
+    };
+
+    // Commits since the last release
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(head_commit.id())?;
+    if let Some(commit) = &tag_commit {
+        revwalk.hide(commit.id())?;
+    }
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        commits.push(CommitInfo {
+            message: commit.message().unwrap_or("").to_string(),
+            author_name: commit.author().name().unwrap_or("").to_string(),
+            author_email: commit.author().email().unwrap_or("").to_string(),
+            hash: commit.id().to_string(),
+            timestamp: commit.time().seconds(),
+            stats: DiffStats::default(),
+        });
+    }
+    signals.commits = commits.len();
+    signals.breaking_changes = categorize_commits(&commits).breaking_changes.len();
+
+    // Diff size between the last release and HEAD
+    let old_tree = match &tag_commit {
+        Some(commit) => Some(commit.tree()?),
+        None => None,
+    };
+    let diff = repo.diff_tree_to_tree(old_tree.as_ref(), Some(&head_commit.tree()?), None)?;
+    let stats = diff.stats()?;
+    signals.files_changed = stats.files_changed();
+    signals.lines_changed = stats.insertions() + stats.deletions();
+
+    // Major bumps in the manifests we know how to read
+    if let Some(tag) = &last_tag {
+        for manifest in ["Cargo.toml", "package.json"] {
+            let old = read_file_at_revision(&repo, tag, manifest);
+            let new = std::fs::read_to_string(path.join(manifest)).ok();
+            if let (Some(old), Some(new)) = (old, new) {
+                signals.major_dependency_bumps +=
+                    count_major_dependency_bumps(manifest, &old, &new);
+            }
+        }
+    }
+
+    signals.failing_checks =
+        read_failing_checks(&path.join(ReportFormat::Json.default_file_name()));
+
+    signals.days_since_last_release = tag_commit.as_ref().map(|commit| {
+        let now = chrono::Utc::now().timestamp();
+        (now - commit.time().seconds()) / 86_400
+    });
+
+    let (factors, score, level) = calculate_risk_score(&signals);
+    Ok(ReleaseRiskReport {
+        last_tag,
+        factors,
+        score,
+        level,
+    })
+}
+
+fn get_latest_version_tag(repo: &Repository) -> Option<String> {
+    let mut tags = get_all_tags(repo).ok()?;
+    tags.retain(|tag| is_version_tag(tag));
+    tags.sort_by(|a, b| compare_version_tags(a, b));
+    tags.pop()
+}
+
+fn read_file_at_revision(repo: &Repository, revision: &str, file: &str) -> Option<String> {
+    let object = repo
+        .revparse_single(&format!("{}:{}", revision, file))
+        .ok()?;
+    let blob = object.as_blob()?;
+    String::from_utf8(blob.content().to_vec()).ok()
+}
+
+fn read_failing_checks(report_path: &Path) -> usize {
+    std::fs::read_to_string(report_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|report| report["summary"]["failed"].as_u64())
+        .unwrap_or(0) as usize
+}
+
+pub fn count_major_dependency_bumps(manifest: &str, old: &str, new: &str) -> usize {
+    let old_deps = parse_manifest_dependencies(manifest, old);
+    let new_deps = parse_manifest_dependencies(manifest, new);
+
+    new_deps
+        .iter()
+        .filter(|(name, new_version)| {
+            old_deps
+                .iter()
+                .find(|(old_name, _)| old_name == name)
+                .map(|(_, old_version)| is_major_bump(old_version, new_version))
+                .unwrap_or(false)
+        })
+        .count()
+}
+
+pub fn parse_manifest_dependencies(manifest: &str, content: &str) -> Vec<(String, String)> {
+    let mut deps = Vec::new();
+
+    if manifest == "package.json" {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(content) {
+            for section in ["dependencies", "devDependencies"] {
+                if let Some(obj) = json[section].as_object() {
+                    for (name, version) in obj {
+                        if let Some(version) = version.as_str() {
+                            deps.push((name.clone(), version.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+        return deps;
+    }
+
+    // Cargo.toml: `name = "1.0"` and `name = { version = "1.0", ... }`
+    let simple = regex::Regex::new(r#"^\s*([A-Za-z0-9_-]+)\s*=\s*"([^"]+)""#).unwrap();
+    let table =
+        regex::Regex::new(r#"^\s*([A-Za-z0-9_-]+)\s*=\s*\{.*version\s*=\s*"([^"]+)""#).unwrap();
+    let mut in_dependencies = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_dependencies = trimmed.ends_with("dependencies]");
+            continue;
+        }
+        if !in_dependencies {
+            continue;
+        }
+        if let Some(caps) = table.captures(line).or_else(|| simple.captures(line)) {
+            deps.push((caps[1].to_string(), caps[2].to_string()));
+        }
+    }
+
+    deps
+}
+
+pub fn is_major_bump(old: &str, new: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .trim_start_matches(|c: char| !c.is_ascii_digit())
+            .split('.')
+            .map_while(|part| part.parse::<u64>().ok())
+            .collect()
+    };
+
+    let old_parts = parse(old);
+    let new_parts = parse(new);
+
+    match (old_parts.first(), new_parts.first()) {
+        (Some(0), Some(0)) => {
+            // For 0.x versions the minor number carries breaking changes
+            new_parts.get(1).unwrap_or(&0) > old_parts.get(1).unwrap_or(&0)
+        }
+        (Some(old_major), Some(new_major)) => new_major > old_major,
+        _ => false,
+    }
+}
+
+pub fn print_risk_report(report: &ReleaseRiskReport) {
+    println!(
+        "\n{}",
+        paint("📊 Release Risk Assessment", Role::Accent).bold()
+    );
+    println!("{}", "═".repeat(40).dimmed());
+
+    if let Some(tag) = &report.last_tag {
+        println!("  {} {}", "Compared with:".dimmed(), tag.cyan());
+    }
+
+    for factor in &report.factors {
+        let points = format!("+{}", factor.points);
+        let points = if factor.points == 0 {
+            points.dimmed()
+        } else {
+            paint(&points, Role::Warning)
+        };
+        println!("  {:<26} {:<32} {}", factor.name, factor.value, points);
+    }
+
+    let summary = format!("Risk score: {}/100", report.score);
+    let summary = match report.level {
+        RiskLevel::Low => label(&format!("{} (low)", summary), Role::Success),
+        RiskLevel::Medium => label(&format!("{} (medium)", summary), Role::Warning),
+        RiskLevel::High => label(&format!("{} (high)", summary), Role::Error),
+    };
+    println!("\n  {}", summary.bold());
+}
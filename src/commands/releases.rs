@@ -0,0 +1,487 @@
+use crate::commands::release_notes::get_repository_info;
+use crate::commands::smoke_test::shell_command;
+use crate::utils::process::SupervisedCommand;
+use crate::utils::sandbox::{ensure_network_allowed, ensure_not_sandboxed};
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub browser_download_url: String,
+    #[serde(default)]
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PublishedRelease {
+    pub tag_name: String,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+static CONFIGURED_REPO: OnceLock<String> = OnceLock::new();
+
+/// Reads `github_repo` from the saved config, the fallback of [`resolve_repo`].
+pub async fn load_configured_repo() {
+    if let Some(repo) = crate::commands::config::load_saved_config()
+        .await
+        .github_repo
+    {
+        set_configured_repo(repo);
+    }
+}
+
+pub fn set_configured_repo(repo: String) {
+    let _ = CONFIGURED_REPO.set(repo);
+}
+
+/// `owner/name` from `--repo`, from the GitHub remote of the current
+/// repository, or from the configured `github_repo`.
+pub fn resolve_repo(repo: Option<&str>) -> Result<(String, String)> {
+    resolve_repo_in(Path::new("."), repo)
+}
+
+/// [`resolve_repo`] for the repository `dir` is in.
+pub fn resolve_repo_in(dir: &Path, repo: Option<&str>) -> Result<(String, String)> {
+    if let Some(repo) = repo {
+        return match repo.trim_end_matches(".git").split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() => {
+                Ok((owner.to_string(), name.to_string()))
+            }
+            _ => Err(anyhow!("Invalid repository '{}', use owner/name", repo)),
+        };
+    }
+
+    let info = Repository::discover(dir)
+        .ok()
+        .map(|repository| get_repository_info(&repository));
+    match (info, CONFIGURED_REPO.get()) {
+        (Some(info), _) if info.is_github => Ok((info.owner, info.name)),
+        (_, Some(configured)) => resolve_repo(Some(configured)),
+        (None, None) => Err(anyhow!("Not a git repository, pass --repo owner/name")),
+        (Some(_), None) => Err(anyhow!("No GitHub remote found, pass --repo owner/name")),
+    }
+}
+
+/// Name fragments that identify the running platform in asset file names.
+pub fn platform_aliases() -> (Vec<&'static str>, Vec<&'static str>) {
+    let os = match std::env::consts::OS {
+        "macos" => vec!["macos", "darwin", "apple", "osx"],
+        "windows" => vec!["windows", "win64", "win32"],
+        other => vec![other],
+    };
+    let arch = match std::env::consts::ARCH {
+        "x86_64" => vec!["x86_64", "amd64", "x64"],
+        "aarch64" => vec!["aarch64", "arm64"],
+        "x86" => vec!["i686", "i386", "x86"],
+        other => vec![other],
+    };
+    (os, arch)
+}
+
+/// Picks the asset built for the given platform. Checksums and signatures
+/// are never selected; an OS-only match is used when there is exactly one.
+pub fn select_asset<'a>(
+    assets: &'a [ReleaseAsset],
+    os_aliases: &[&str],
+    arch_aliases: &[&str],
+) -> Option<&'a ReleaseAsset> {
+    const SKIPPED: [&str; 6] = [".sha256", ".sha512", ".sig", ".asc", ".txt", ".sbom.json"];
+
+    let candidates: Vec<&ReleaseAsset> = assets
+        .iter()
+        .filter(|asset| {
+            let name = asset.name.to_lowercase();
+            !SKIPPED.iter().any(|suffix| name.ends_with(suffix))
+                && os_aliases.iter().any(|alias| name.contains(alias))
+        })
+        .collect();
+
+    candidates
+        .iter()
+        .find(|asset| {
+            let name = asset.name.to_lowercase();
+            arch_aliases.iter().any(|alias| name.contains(alias))
+        })
+        .copied()
+        .or(if candidates.len() == 1 {
+            Some(candidates[0])
+        } else {
+            None
+        })
+}
+
+/// Published (non-draft, non-prerelease) releases, oldest first. Tags that
+/// aren't semantic versions are left out.
+pub fn sort_releases(releases: Vec<PublishedRelease>) -> Vec<PublishedRelease> {
+    let mut versioned: Vec<(semver::Version, PublishedRelease)> = releases
+        .into_iter()
+        .filter(|release| !release.draft && !release.prerelease)
+        .filter_map(|release| {
+            semver::Version::parse(release.tag_name.trim_start_matches('v'))
+                .ok()
+                .map(|version| (version, release))
+        })
+        .collect();
+    versioned.sort_by(|a, b| a.0.cmp(&b.0));
+    versioned.into_iter().map(|(_, release)| release).collect()
+}
+
+/// Binary search state over releases sorted oldest first. `good` and `bad`
+/// are indexes known to pass and fail the test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bisection {
+    pub good: usize,
+    pub bad: usize,
+}
+
+impl Bisection {
+    pub fn new(good: usize, bad: usize) -> Result<Self> {
+        if good >= bad {
+            return Err(anyhow!("The good release must be older than the bad one"));
+        }
+        Ok(Self { good, bad })
+    }
+
+    /// Next release to test, or None once the first bad release is known.
+    pub fn next(&self) -> Option<usize> {
+        if self.bad - self.good <= 1 {
+            None
+        } else {
+            Some(self.good + (self.bad - self.good) / 2)
+        }
+    }
+
+    pub fn mark(&mut self, index: usize, passed: bool) {
+        if passed {
+            self.good = index;
+        } else {
+            self.bad = index;
+        }
+    }
+
+    pub fn remaining_steps(&self) -> u32 {
+        (self.bad - self.good).next_power_of_two().trailing_zeros()
+    }
+}
+
+pub struct ReleasesClient {
+    client: reqwest::Client,
+    pub api_url: String,
+    token: Option<String>,
+}
+
+impl ReleasesClient {
+    pub fn new() -> Result<Self> {
+        ensure_network_allowed()?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .user_agent("nitroterm")
+            .build()?;
+
+        Ok(Self {
+            client,
+            api_url: "https://api.github.com".to_string(),
+            token: std::env::var("GITHUB_TOKEN")
+                .or_else(|_| std::env::var("GH_TOKEN"))
+                .ok(),
+        })
+    }
+
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self.client.get(url);
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    pub async fn list_releases(&self, owner: &str, repo: &str) -> Result<Vec<PublishedRelease>> {
+        let mut releases = Vec::new();
+
+        for page in 1..=10 {
+            let url = format!(
+                "{}/repos/{}/{}/releases?per_page=100&page={}",
+                self.api_url, owner, repo, page
+            );
+            let response = self.get(&url).send().await?;
+            if !response.status().is_success() {
+                return Err(anyhow!("GitHub API returned status: {}", response.status()));
+            }
+
+            let batch: Vec<PublishedRelease> = response.json().await?;
+            let done = batch.len() < 100;
+            releases.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        Ok(releases)
+    }
+
+    pub async fn download(&self, asset: &ReleaseAsset, destination: &Path) -> Result<()> {
+        let response = self
+            .get(&asset.browser_download_url)
+            .header("Accept", "application/octet-stream")
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Download of {} failed: {}",
+                asset.name,
+                response.status()
+            ));
+        }
+
+        let bytes = response.bytes().await?;
+        std::fs::write(destination, &bytes)?;
+        Ok(())
+    }
+}
+
+pub fn cache_dir(owner: &str, repo: &str, tag: &str) -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("nitroterm")
+        .join("releases")
+        .join(owner)
+        .join(repo)
+        .join(tag)
+}
+
+pub fn is_archive(name: &str) -> bool {
+    let name = name.to_lowercase();
+    [".tar.gz", ".tgz", ".tar.xz", ".tar.bz2", ".zip"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+pub fn unpack(archive: &Path, dir: &Path) -> Result<()> {
+    let name = archive.to_string_lossy().to_lowercase();
+    let mut command = if name.ends_with(".zip") && !cfg!(windows) {
+        let mut command = std::process::Command::new("unzip");
+        command.arg("-o").arg("-q").arg(archive).arg("-d").arg(dir);
+        command
+    } else {
+        // bsdtar on Windows also handles .zip
+        let mut command = std::process::Command::new("tar");
+        command.arg("-xf").arg(archive).arg("-C").arg(dir);
+        command
+    };
+
+    let output = command.supervised_output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Could not unpack {}: {}",
+            archive.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+pub fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o755);
+    std::fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+/// A release asset downloaded (and unpacked) into the local cache.
+#[derive(Debug, Clone)]
+pub struct FetchedRelease {
+    pub version: String,
+    pub asset: PathBuf,
+    pub dir: PathBuf,
+}
+
+async fn fetch_asset(
+    client: &ReleasesClient,
+    owner: &str,
+    repo: &str,
+    release: &PublishedRelease,
+) -> Result<FetchedRelease> {
+    let (os, arch) = platform_aliases();
+    let asset = select_asset(&release.assets, &os, &arch).ok_or_else(|| {
+        anyhow!(
+            "{} has no asset for {}-{}",
+            release.tag_name,
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
+    })?;
+
+    let dir = cache_dir(owner, repo, &release.tag_name);
+    let asset_path = dir.join(&asset.name);
+
+    if asset_path.exists() {
+        println!(
+            "{}",
+            format!("♻️  Using cached {}", asset_path.display()).dimmed()
+        );
+    } else {
+        std::fs::create_dir_all(&dir)?;
+        println!(
+            "{}",
+            format!(
+                "⬇️  Downloading {} ({} KB)...",
+                asset.name,
+                asset.size / 1024
+            )
+            .blue()
+        );
+        client.download(asset, &asset_path).await?;
+
+        if is_archive(&asset.name) {
+            unpack(&asset_path, &dir)?;
+        } else {
+            make_executable(&asset_path)?;
+        }
+    }
+
+    Ok(FetchedRelease {
+        version: release.tag_name.clone(),
+        asset: asset_path,
+        dir,
+    })
+}
+
+pub fn find_release<'a>(
+    releases: &'a [PublishedRelease],
+    version: &str,
+) -> Result<&'a PublishedRelease> {
+    let wanted = version.trim_start_matches('v');
+    releases
+        .iter()
+        .find(|release| release.tag_name.trim_start_matches('v') == wanted)
+        .ok_or_else(|| anyhow!("Release {} not found", version))
+}
+
+pub async fn fetch_release(version: &str, repo: Option<&str>) -> Result<FetchedRelease> {
+    ensure_not_sandboxed("releases fetch")?;
+    let (owner, name) = resolve_repo(repo)?;
+    let client = ReleasesClient::new()?;
+
+    println!(
+        "{}",
+        format!("📦 Fetching {} from {}/{}", version, owner, name).cyan()
+    );
+    let releases = client.list_releases(&owner, &name).await?;
+    let release = find_release(&releases, version)?;
+    let fetched = fetch_asset(&client, &owner, &name, release).await?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ {} is ready in {}",
+            fetched.version,
+            fetched.dir.display()
+        )
+        .green()
+    );
+    Ok(fetched)
+}
+
+/// Fills `{version}`, `{asset}` and `{dir}` in the user's test command.
+pub fn expand_test_command(command: &str, release: &FetchedRelease) -> String {
+    command
+        .replace("{version}", &release.version)
+        .replace("{asset}", &release.asset.display().to_string())
+        .replace("{dir}", &release.dir.display().to_string())
+}
+
+fn run_test_command(command: &str, release: &FetchedRelease) -> Result<bool> {
+    let work_dir = tempfile::tempdir()?;
+    let output = shell_command(&expand_test_command(command, release))
+        .current_dir(work_dir.path())
+        .env("NITROTERM_RELEASE_VERSION", &release.version)
+        .env("NITROTERM_RELEASE_DIR", &release.dir)
+        .as_std_mut()
+        .supervised_output()?;
+    Ok(output.status.success())
+}
+
+/// Finds the first published release for which `command` fails.
+pub async fn bisect_releases(
+    command: &str,
+    good: Option<&str>,
+    bad: Option<&str>,
+    repo: Option<&str>,
+) -> Result<String> {
+    ensure_not_sandboxed("releases bisect")?;
+    let (owner, name) = resolve_repo(repo)?;
+    let client = ReleasesClient::new()?;
+    let releases = sort_releases(client.list_releases(&owner, &name).await?);
+
+    if releases.len() < 2 {
+        return Err(anyhow!("Need at least two published releases to bisect"));
+    }
+
+    let index_of = |version: &str| -> Result<usize> {
+        let release = find_release(&releases, version)?;
+        Ok(releases
+            .iter()
+            .position(|r| r.tag_name == release.tag_name)
+            .unwrap())
+    };
+    let good_index = good.map(index_of).transpose()?.unwrap_or(0);
+    let bad_index = bad.map(index_of).transpose()?.unwrap_or(releases.len() - 1);
+    let mut bisection = Bisection::new(good_index, bad_index)?;
+
+    println!(
+        "{}",
+        format!(
+            "🔎 Bisecting {} releases between {} (good) and {} (bad), ~{} steps",
+            bad_index - good_index + 1,
+            releases[good_index].tag_name,
+            releases[bad_index].tag_name,
+            bisection.remaining_steps()
+        )
+        .cyan()
+        .bold()
+    );
+
+    while let Some(index) = bisection.next() {
+        let release = &releases[index];
+        let fetched = fetch_asset(&client, &owner, &name, release).await?;
+        let passed = run_test_command(command, &fetched)?;
+
+        if passed {
+            println!("  {} {}", "✅ good".green(), release.tag_name);
+        } else {
+            println!("  {} {}", "❌ bad ".red(), release.tag_name);
+        }
+        bisection.mark(index, passed);
+    }
+
+    let first_bad = releases[bisection.bad].tag_name.clone();
+    println!();
+    println!(
+        "{}",
+        format!(
+            "🎯 First bad release: {} (last good: {})",
+            first_bad, releases[bisection.good].tag_name
+        )
+        .yellow()
+        .bold()
+    );
+    Ok(first_bad)
+}
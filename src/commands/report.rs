@@ -0,0 +1,673 @@
+use crate::commands::code_quality::{
+    write_report, CheckResult, CodeQualityConfig, CodeQualityManager, ProjectInfo, ReportFormat,
+};
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::lockfiles::{
+    direct_dependency_names, native_outdated_report, read_locked_packages, LockedPackage,
+    OutdatedPackage,
+};
+use crate::commands::release_notes::{
+    categorize_commits, compare_version_tags, get_all_tags, get_contributors_with_stats,
+    is_version_tag, CommitInfo, DiffStats,
+};
+use crate::commands::release_risk::{assess_release_risk, ReleaseRiskReport, RiskLevel};
+use crate::commands::translation_sync::{get_sync_statistics, SyncStatistics};
+use crate::utils::document_locale::{timezone, DocumentLocale};
+use crate::utils::log_warning;
+use crate::utils::output::is_json_output;
+use crate::utils::sandbox::{ensure_not_sandboxed, ensure_write_allowed};
+use anyhow::Result;
+use colored::*;
+use git2::{Repository, StatusOptions};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_REPORT_FILE: &str = "nitroterm-report.html";
+/// File written into the `code-quality --report-html` directory.
+pub const HTML_REPORT_INDEX: &str = "index.html";
+const WEEK_SECONDS: i64 = 7 * 86_400;
+const ACTIVITY_WEEKS: usize = 12;
+
+#[derive(Debug, Clone, Default)]
+pub struct ReportOptions {
+    /// Run code-quality now instead of reusing code-quality-report.json
+    pub run_checks: bool,
+    /// Query the package registries for outdated dependencies
+    pub check_outdated: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProjectReport {
+    pub project_name: String,
+    pub generated_at: String,
+    /// Detected project type and tooling, only known after code-quality ran
+    pub project_info: Option<ProjectInfo>,
+    /// Label/value pairs for the repository health overview
+    pub health: Vec<(String, String)>,
+    pub risk: Option<ReleaseRiskReport>,
+    pub last_tag: Option<String>,
+    pub unreleased: Vec<CommitInfo>,
+    /// Commits per week, oldest first
+    pub weekly_commits: Vec<(String, usize)>,
+    /// (email, name, commits)
+    pub contributors: Vec<(String, String, usize)>,
+    pub dependencies: Vec<LockedPackage>,
+    pub direct_dependencies: usize,
+    pub outdated: Option<Vec<OutdatedPackage>>,
+    pub checks: Option<Vec<CheckResult>>,
+    pub translations: Option<SyncStatistics>,
+}
+
+fn latest_version_tag(repo: &Repository) -> Option<String> {
+    let mut tags = get_all_tags(repo).ok()?;
+    tags.retain(|tag| is_version_tag(tag));
+    tags.sort_by(|a, b| compare_version_tags(a, b));
+    tags.pop()
+}
+
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    CommitInfo {
+        message: commit.message().unwrap_or("").to_string(),
+        author_name: commit.author().name().unwrap_or("").to_string(),
+        author_email: commit.author().email().unwrap_or("").to_string(),
+        hash: commit.id().to_string(),
+        timestamp: commit.time().seconds(),
+        stats: DiffStats::default(),
+    }
+}
+
+/// Buckets commit timestamps into the last `weeks` weeks, oldest first.
+pub fn weekly_activity(timestamps: &[i64], now: i64, weeks: usize) -> Vec<(String, usize)> {
+    let mut counts = vec![0; weeks];
+    for timestamp in timestamps {
+        let age = (now - timestamp).max(0) / WEEK_SECONDS;
+        if (age as usize) < weeks {
+            counts[weeks - 1 - age as usize] += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(index, count)| {
+            let week_start = now - (weeks - index) as i64 * WEEK_SECONDS;
+            let label = chrono::DateTime::from_timestamp(week_start, 0)
+                .map(|date| date.format("%m-%d").to_string())
+                .unwrap_or_default();
+            (label, count)
+        })
+        .collect()
+}
+
+/// Fills the git history, health overview and release risk of `report`.
+pub fn collect_git(root: &Path, report: &mut ProjectReport) -> Result<()> {
+    let repo = match Repository::discover(root) {
+        Ok(repo) => repo,
+        Err(_) => {
+            report
+                .health
+                .push(("Git repository".to_string(), "not found".to_string()));
+            return Ok(());
+        }
+    };
+
+    let mut commits = Vec::new();
+    let mut revwalk = repo.revwalk()?;
+    if revwalk.push_head().is_ok() {
+        for oid in revwalk {
+            commits.push(commit_info(&repo.find_commit(oid?)?));
+        }
+    }
+
+    report.last_tag = latest_version_tag(&repo);
+    let tag_commit = report
+        .last_tag
+        .as_ref()
+        .and_then(|tag| repo.revparse_single(tag).ok())
+        .and_then(|object| object.peel_to_commit().ok());
+    report.unreleased = match &tag_commit {
+        Some(tag_commit) => {
+            let mut revwalk = repo.revwalk()?;
+            revwalk.push_head()?;
+            revwalk.hide(tag_commit.id())?;
+            revwalk
+                .filter_map(|oid| oid.ok())
+                .filter_map(|oid| repo.find_commit(oid).ok())
+                .map(|commit| commit_info(&commit))
+                .collect()
+        }
+        None => commits.clone(),
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    let timestamps: Vec<i64> = commits.iter().map(|c| c.timestamp).collect();
+    report.weekly_commits = weekly_activity(&timestamps, now, ACTIVITY_WEEKS);
+    report.contributors = get_contributors_with_stats(&commits);
+
+    let branch = repo
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(|s| s.to_string()))
+        .unwrap_or_else(|| "-".to_string());
+    let dirty = repo
+        .statuses(Some(StatusOptions::new().include_untracked(true)))
+        .map(|statuses| statuses.len())
+        .unwrap_or(0);
+    let last_commit = commits
+        .first()
+        .map(|c| format!("{} days ago", (now - c.timestamp) / 86_400))
+        .unwrap_or_else(|| "-".to_string());
+    let workdir = repo.workdir().unwrap_or(root);
+
+    report.health.extend([
+        ("Branch".to_string(), branch),
+        (
+            "Latest release".to_string(),
+            report
+                .last_tag
+                .clone()
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+        ("Commits".to_string(), commits.len().to_string()),
+        (
+            "Unreleased commits".to_string(),
+            report.unreleased.len().to_string(),
+        ),
+        (
+            "Contributors".to_string(),
+            report.contributors.len().to_string(),
+        ),
+        ("Last commit".to_string(), last_commit),
+        (
+            "Working tree".to_string(),
+            if dirty == 0 {
+                "clean".to_string()
+            } else {
+                format!("{} uncommitted changes", dirty)
+            },
+        ),
+    ]);
+    for (label, file) in [
+        ("README", "README.md"),
+        ("License", "LICENSE"),
+        ("CI workflows", ".github/workflows"),
+    ] {
+        let present = if workdir.join(file).exists() {
+            "yes"
+        } else {
+            "missing"
+        };
+        report.health.push((label.to_string(), present.to_string()));
+    }
+
+    if !commits.is_empty() {
+        report.risk = assess_release_risk(workdir).ok();
+    }
+    Ok(())
+}
+
+/// Gathers everything the HTML report shows. Code quality results come from
+/// the cached JSON report unless `run_checks` is set.
+pub async fn collect_report(
+    root: &Path,
+    app_config: &AppConfig,
+    options: &ReportOptions,
+) -> Result<ProjectReport> {
+    let root_name = root
+        .canonicalize()?
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".to_string());
+    let locale: DocumentLocale = app_config.document_locale.parse().unwrap_or_default();
+    let timezone = timezone();
+    let now = timezone.now();
+    let mut report = ProjectReport {
+        project_name: root_name,
+        generated_at: format!(
+            "{} {}",
+            locale.format_short_date(now.date_naive()),
+            timezone.format_time(locale, &now)
+        ),
+        ..Default::default()
+    };
+
+    collect_git(root, &mut report)?;
+
+    report.dependencies = read_locked_packages(root)?;
+    let direct = direct_dependency_names(root);
+    report.direct_dependencies = report
+        .dependencies
+        .iter()
+        .filter(|package| direct.contains(&(package.ecosystem, package.name.to_lowercase())))
+        .count();
+    if options.check_outdated {
+        report.outdated = Some(native_outdated_report(root).await?);
+    }
+
+    let cached_checks = root.join(ReportFormat::Json.default_file_name());
+    if options.run_checks {
+        let manager = CodeQualityManager::new(CodeQualityConfig::default());
+        let results = manager.run_quality_checks(root).await?;
+        write_report(ReportFormat::Json, &results, &cached_checks).await?;
+        report.checks = Some(results);
+    } else if let Ok(content) = std::fs::read_to_string(&cached_checks) {
+        let json: serde_json::Value = serde_json::from_str(&content)?;
+        report.checks = serde_json::from_value(json["checks"].clone()).ok();
+    }
+
+    let messages_dir = root.join(&app_config.messages_dir);
+    if messages_dir.join(&app_config.source_file).exists() {
+        report.translations = Some(get_sync_statistics(&messages_dir, &app_config.source_file)?);
+    }
+
+    Ok(report)
+}
+
+pub fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Horizontal bar chart as inline SVG, so the report needs no scripts.
+pub fn svg_bar_chart(items: &[(String, f64)], max: f64, color: &str) -> String {
+    const ROW: usize = 24;
+    const LABEL_WIDTH: f64 = 160.0;
+    const BAR_WIDTH: f64 = 420.0;
+
+    let max = if max > 0.0 { max } else { 1.0 };
+    let mut svg = format!(
+        "<svg class=\"chart\" viewBox=\"0 0 640 {}\" role=\"img\">",
+        items.len() * ROW + 4
+    );
+    for (index, (label, value)) in items.iter().enumerate() {
+        let y = index * ROW;
+        let width = (value / max * BAR_WIDTH).max(0.0);
+        svg.push_str(&format!(
+            "<text x=\"0\" y=\"{}\">{}</text><rect x=\"{}\" y=\"{}\" width=\"{:.1}\" height=\"16\" rx=\"3\" fill=\"{}\"/><text x=\"{:.1}\" y=\"{}\">{}</text>",
+            y + 16,
+            html_escape(label),
+            LABEL_WIDTH,
+            y + 4,
+            width,
+            color,
+            LABEL_WIDTH + width + 6.0,
+            y + 16,
+            if value.fract() == 0.0 {
+                format!("{}", value)
+            } else {
+                format!("{:.1}", value)
+            }
+        ));
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+const STYLE: &str = r#"
+body { margin: 0; font-family: -apple-system, "Segoe UI", Roboto, sans-serif; color: #1f2937; background: #f9fafb; }
+nav { position: fixed; top: 0; bottom: 0; width: 200px; padding: 24px 16px; background: #111827; }
+nav a { display: block; color: #d1d5db; text-decoration: none; padding: 6px 8px; border-radius: 4px; }
+nav a:hover { background: #374151; color: #fff; }
+nav h1 { color: #fff; font-size: 18px; margin: 0 0 16px; }
+main { margin-left: 232px; padding: 24px 32px; max-width: 960px; }
+section { background: #fff; border: 1px solid #e5e7eb; border-radius: 8px; padding: 16px 24px; margin-bottom: 24px; }
+h2 { margin-top: 0; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 6px 8px; border-bottom: 1px solid #f3f4f6; }
+.pass { color: #15803d; } .fail { color: #b91c1c; } .muted { color: #6b7280; }
+.badge { display: inline-block; padding: 2px 8px; border-radius: 999px; font-weight: 600; }
+.low { background: #dcfce7; } .medium { background: #fef9c3; } .high { background: #fee2e2; }
+.chart { width: 100%; max-width: 640px; font-size: 12px; }
+.chart text { fill: #374151; }
+details summary { cursor: pointer; color: #6b7280; }
+pre { background: #f3f4f6; padding: 8px; border-radius: 4px; overflow-x: auto; white-space: pre-wrap; font-size: 12px; }
+"#;
+
+fn render_project_info(info: &ProjectInfo) -> String {
+    let config_files = info
+        .config_files
+        .iter()
+        .map(|file| {
+            file.strip_prefix(&info.root_path)
+                .unwrap_or(file)
+                .display()
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    let rows = [
+        ("Type", format!("{:?}", info.project_type)),
+        ("Package manager", format!("{:?}", info.package_manager)),
+        (
+            "TypeScript",
+            if info.has_typescript { "yes" } else { "no" }.to_string(),
+        ),
+        ("Frameworks", info.frameworks.join(", ")),
+        ("Config files", config_files.join(", ")),
+    ];
+
+    let mut html = String::from("<section id=\"project\"><h2>Project</h2><table>");
+    for (label, value) in rows {
+        if value.is_empty() {
+            continue;
+        }
+        html.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>",
+            label,
+            html_escape(&value)
+        ));
+    }
+    html.push_str("</table></section>");
+    html
+}
+
+fn render_overview(report: &ProjectReport) -> String {
+    let mut html = String::from("<section id=\"overview\"><h2>Repository health</h2><table>");
+    for (label, value) in &report.health {
+        html.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>",
+            html_escape(label),
+            html_escape(value)
+        ));
+    }
+    html.push_str("</table>");
+
+    if let Some(risk) = &report.risk {
+        let (class, level) = match risk.level {
+            RiskLevel::Low => ("low", "Low"),
+            RiskLevel::Medium => ("medium", "Medium"),
+            RiskLevel::High => ("high", "High"),
+        };
+        html.push_str(&format!(
+            "<h3>Release risk <span class=\"badge {}\">{} ({}/100)</span></h3><table>",
+            class, level, risk.score
+        ));
+        for factor in &risk.factors {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"muted\">+{}</td></tr>",
+                html_escape(&factor.name),
+                html_escape(&factor.value),
+                factor.points
+            ));
+        }
+        html.push_str("</table>");
+    }
+
+    if !report.weekly_commits.is_empty() {
+        let items: Vec<(String, f64)> = report
+            .weekly_commits
+            .iter()
+            .map(|(week, count)| (format!("Week of {}", week), *count as f64))
+            .collect();
+        let max = items.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        html.push_str("<h3>Commit activity</h3>");
+        html.push_str(&svg_bar_chart(&items, max, "#6366f1"));
+    }
+
+    if !report.contributors.is_empty() {
+        let items: Vec<(String, f64)> = report
+            .contributors
+            .iter()
+            .take(10)
+            .map(|(_, name, count)| (name.clone(), *count as f64))
+            .collect();
+        let max = items.first().map(|(_, v)| *v).unwrap_or(0.0);
+        html.push_str("<h3>Top contributors</h3>");
+        html.push_str(&svg_bar_chart(&items, max, "#0ea5e9"));
+    }
+
+    html.push_str("</section>");
+    html
+}
+
+fn render_release_notes(report: &ProjectReport) -> String {
+    let mut html = format!(
+        "<section id=\"release-notes\"><h2>Unreleased changes</h2><p class=\"muted\">{} commits since {}</p>",
+        report.unreleased.len(),
+        html_escape(report.last_tag.as_deref().unwrap_or("the first commit"))
+    );
+
+    let categorized = categorize_commits(&report.unreleased);
+    let groups = [
+        ("⚠️ Breaking changes", &categorized.breaking_changes),
+        ("✨ Features", &categorized.features),
+        ("🐛 Fixes", &categorized.fixes),
+        ("⚡ Performance", &categorized.perf),
+        ("♻️ Refactoring", &categorized.refactor),
+        ("📚 Documentation", &categorized.docs),
+        ("🧪 Tests", &categorized.tests),
+        ("🔧 Chores", &categorized.chores),
+        ("📦 Other", &categorized.others),
+    ];
+    for (title, messages) in groups {
+        if messages.is_empty() {
+            continue;
+        }
+        html.push_str(&format!("<h3>{}</h3><ul>", title));
+        for message in messages.iter() {
+            let subject = message.lines().next().unwrap_or("");
+            html.push_str(&format!("<li>{}</li>", html_escape(subject)));
+        }
+        html.push_str("</ul>");
+    }
+
+    html.push_str("</section>");
+    html
+}
+
+fn render_dependencies(report: &ProjectReport) -> String {
+    let mut html = format!(
+        "<section id=\"dependencies\"><h2>Dependencies</h2><p>{} locked packages, {} declared directly</p>",
+        report.dependencies.len(),
+        report.direct_dependencies
+    );
+
+    let mut per_ecosystem: Vec<(String, f64)> = Vec::new();
+    for package in &report.dependencies {
+        let name = package.ecosystem.name().to_string();
+        match per_ecosystem.iter_mut().find(|(label, _)| *label == name) {
+            Some((_, count)) => *count += 1.0,
+            None => per_ecosystem.push((name, 1.0)),
+        }
+    }
+    if !per_ecosystem.is_empty() {
+        let max = per_ecosystem.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        html.push_str(&svg_bar_chart(&per_ecosystem, max, "#f59e0b"));
+    }
+
+    match &report.outdated {
+        Some(outdated) if outdated.is_empty() => {
+            html.push_str("<p class=\"pass\">All direct dependencies are up to date.</p>");
+        }
+        Some(outdated) => {
+            html.push_str(
+                "<h3>Outdated</h3><table><tr><th>Package</th><th>Current</th><th>Latest</th><th>Registry</th></tr>",
+            );
+            for package in outdated {
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"muted\">{}</td></tr>",
+                    html_escape(&package.name),
+                    html_escape(&package.current),
+                    html_escape(&package.latest),
+                    package.ecosystem.name()
+                ));
+            }
+            html.push_str("</table>");
+        }
+        None => {
+            html.push_str("<p class=\"muted\">Registry lookup skipped (run with --outdated).</p>")
+        }
+    }
+
+    html.push_str("</section>");
+    html
+}
+
+fn render_checks(report: &ProjectReport) -> String {
+    let mut html = String::from("<section id=\"code-quality\"><h2>Code quality</h2>");
+    match &report.checks {
+        Some(checks) => {
+            let passed = checks.iter().filter(|c| c.success).count();
+            html.push_str(&format!(
+                "<p>{} of {} checks passed</p><table><tr><th>Check</th><th>Status</th><th>Command</th><th>Time</th></tr>",
+                passed,
+                checks.len()
+            ));
+            for check in checks {
+                let (class, status) = if check.success {
+                    ("pass", "PASS")
+                } else {
+                    ("fail", "FAIL")
+                };
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td class=\"{}\">{}</td><td><code>{}</code></td><td class=\"muted\">{}ms</td></tr>",
+                    html_escape(&check.check_name),
+                    class,
+                    status,
+                    html_escape(&check.command),
+                    check.duration_ms
+                ));
+                // Output folds away, failed checks start open
+                let output = match &check.error {
+                    Some(error) if !error.is_empty() => format!("{}\n{}", check.output, error),
+                    _ => check.output.clone(),
+                };
+                if !output.trim().is_empty() {
+                    html.push_str(&format!(
+                        "<tr><td colspan=\"4\"><details{}><summary>Output</summary><pre>{}</pre></details></td></tr>",
+                        if check.success { "" } else { " open" },
+                        html_escape(output.trim())
+                    ));
+                }
+            }
+            html.push_str("</table>");
+        }
+        None => html.push_str(
+            "<p class=\"muted\">No code-quality results yet (run with --run-checks).</p>",
+        ),
+    }
+    html.push_str("</section>");
+    html
+}
+
+fn render_translations(report: &ProjectReport) -> String {
+    let mut html = String::from("<section id=\"translations\"><h2>Translations</h2>");
+    match &report.translations {
+        Some(stats) if !stats.languages.is_empty() => {
+            html.push_str(&format!(
+                "<p>{} of {} languages complete, {:.1}% overall ({} keys)</p>",
+                stats.complete_languages(),
+                stats.languages.len(),
+                stats.overall_percentage(),
+                stats.total_keys
+            ));
+            let items: Vec<(String, f64)> = stats
+                .languages
+                .iter()
+                .map(|language| (language.code.clone(), language.progress.percentage()))
+                .collect();
+            html.push_str(&svg_bar_chart(&items, 100.0, "#10b981"));
+        }
+        _ => html.push_str("<p class=\"muted\">No translation files found.</p>"),
+    }
+    html.push_str("</section>");
+    html
+}
+
+/// Renders a single self-contained HTML page (inline CSS and SVG, no scripts).
+pub fn render_html(report: &ProjectReport) -> String {
+    let title = format!("{} — project report", report.project_name);
+    let project = report
+        .project_info
+        .as_ref()
+        .map(render_project_info)
+        .unwrap_or_default();
+    let nav = [
+        ("project", "Project"),
+        ("overview", "Health"),
+        ("release-notes", "Release notes"),
+        ("dependencies", "Dependencies"),
+        ("code-quality", "Code quality"),
+        ("translations", "Translations"),
+    ]
+    .iter()
+    .filter(|(id, _)| *id != "project" || !project.is_empty())
+    .map(|(id, label)| format!("<a href=\"#{}\">{}</a>", id, label))
+    .collect::<String>();
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"><title>{title}</title><style>{style}</style></head>\n<body><nav><h1>{name}</h1>{nav}</nav><main><p class=\"muted\">Generated by nitroterm {version} on {date}</p>\n{project}{overview}\n{notes}\n{deps}\n{checks}\n{translations}\n</main></body></html>\n",
+        title = html_escape(&title),
+        style = STYLE,
+        name = html_escape(&report.project_name),
+        nav = nav,
+        version = env!("CARGO_PKG_VERSION"),
+        date = html_escape(&report.generated_at),
+        project = project,
+        overview = render_overview(report),
+        notes = render_release_notes(report),
+        deps = render_dependencies(report),
+        checks = render_checks(report),
+        translations = render_translations(report),
+    )
+}
+
+async fn load_app_config() -> AppConfig {
+    match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    }
+}
+
+/// `code-quality --report-html <dir>`: the project report with these check
+/// results and the detected project, as `<dir>/index.html` for publishing as
+/// a CI artifact. Outdated dependencies are looked up too; when the lookup
+/// fails the table is left out instead of failing the run.
+pub async fn write_html_report(
+    dir: &Path,
+    root: &Path,
+    results: &[CheckResult],
+    project_info: ProjectInfo,
+) -> Result<PathBuf> {
+    ensure_write_allowed(dir)?;
+    let app_config = load_app_config().await;
+    let mut report = collect_report(root, &app_config, &ReportOptions::default()).await?;
+    report.project_info = Some(project_info);
+    report.checks = Some(results.to_vec());
+    if !report.dependencies.is_empty() {
+        match native_outdated_report(root).await {
+            Ok(outdated) => report.outdated = Some(outdated),
+            Err(e) => log_warning(&format!("Outdated dependencies not included: {}", e)),
+        }
+    }
+
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(HTML_REPORT_INDEX);
+    std::fs::write(&path, render_html(&report))?;
+
+    let message = format!("📄 HTML report written to: {}", path.display());
+    if is_json_output() {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message.green());
+    }
+    Ok(path)
+}
+
+pub async fn run_report(path: Option<&str>, output: &str, options: ReportOptions) -> Result<()> {
+    ensure_not_sandboxed("report")?;
+    let root = Path::new(path.unwrap_or("."));
+    let app_config = load_app_config().await;
+
+    println!("{}", "📊 Collecting project data...".cyan().bold());
+    let report = collect_report(root, &app_config, &options).await?;
+    std::fs::write(output, render_html(&report))?;
+
+    println!(
+        "{}",
+        format!("✅ Report written to {}", output).green().bold()
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::commands::commit_filter::CommitFilter;
+use crate::commands::create_release::TagSigning;
+use crate::commands::release_notes::{
+    get_tag_range_for, render_release_notes, CommitLimits, NotesOptions,
+};
+use crate::utils::document_locale::DocumentLocale;
+use crate::utils::get_repository;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+
+const PGP_SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----";
+const SSH_SIGNATURE: &str = "-----BEGIN SSH SIGNATURE-----";
+
+#[derive(Debug, Clone, Default)]
+pub struct RetagOptions {
+    pub locale: DocumentLocale,
+    /// Look up PR and issue titles on GitHub
+    pub resolve_links: bool,
+    /// Print the new message instead of replacing the tag
+    pub dry_run: bool,
+    /// Force-push the rewritten tag to origin
+    pub push: bool,
+}
+
+/// The tagged commit, and whether the tag carries a signature that
+/// rewriting it would drop.
+pub fn tag_target(repo: &Repository, tag: &str) -> Result<(String, bool)> {
+    let object = repo
+        .revparse_single(&format!("refs/tags/{}", tag))
+        .map_err(|_| anyhow!("Tag '{}' not found", tag))?;
+    let signed = object.as_tag().is_some_and(|annotated| {
+        annotated.message().is_some_and(|message| {
+            message.contains(PGP_SIGNATURE) || message.contains(SSH_SIGNATURE)
+        })
+    });
+    let commit = object.peel_to_commit()?.id().to_string();
+    Ok((commit, signed))
+}
+
+/// Replaces `tag` with an annotated tag on the same commit carrying
+/// `message`, signed again when it was signed before, and force-pushes it
+/// with `push`.
+pub fn rewrite_tag(
+    runner: &dyn CommandRunner,
+    tag: &str,
+    commit: &str,
+    message: &str,
+    signing: &TagSigning,
+    push: bool,
+) -> Result<()> {
+    // Verbatim, or git strips the notes' `#` headings as comments
+    let mut args = vec!["tag", "-f", "--cleanup=verbatim"];
+    args.extend(signing.tag_args());
+    args.extend([tag, commit, "-m", message]);
+    let output = runner.run("git", &args, None)?;
+    if !output.success {
+        return Err(anyhow!("Failed to update tag {}: {}", tag, output.stderr));
+    }
+    println!("{}", format!("🏷️  Updated the message of {}", tag).green());
+
+    let tag_ref = format!("refs/tags/{}", tag);
+    if push {
+        let output = runner.run("git", &["push", "--force", "origin", &tag_ref], None)?;
+        if !output.success {
+            return Err(anyhow!("Failed to push {}: {}", tag, output.stderr));
+        }
+        println!("{}", format!("🚀 Pushed {} to origin", tag).green());
+    } else {
+        println!(
+            "{}",
+            format!("💡 Publish it with: git push --force origin {}", tag_ref).dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// `retag --annotate <tag>`: regenerates the notes of `tag` (previous version
+/// tag → tag) and makes them the tag's message.
+pub async fn run_retag_annotate(
+    tag: &str,
+    filter: &CommitFilter,
+    options: RetagOptions,
+) -> Result<()> {
+    if !options.dry_run {
+        ensure_not_sandboxed("retag")?;
+    }
+    let repo = get_repository(".")?;
+    let (commit, signed) = tag_target(&repo, tag)?;
+    let (current_tag, previous_tag) = get_tag_range_for(&repo, tag)?;
+    let notes_options = NotesOptions {
+        locale: options.locale,
+        resolve_links: options.resolve_links,
+        template: None,
+        limits: CommitLimits::default(),
+        filter,
+    };
+    let notes = render_release_notes(&repo, &current_tag, &previous_tag, &notes_options).await?;
+
+    if options.dry_run {
+        println!("\n{}", notes);
+        return Ok(());
+    }
+    let signing = if signed {
+        println!(
+            "{}",
+            format!(
+                "🔏 {} was signed, signing it again with your default key",
+                tag
+            )
+            .yellow()
+        );
+        TagSigning::DefaultKey
+    } else {
+        TagSigning::None
+    };
+    rewrite_tag(&SystemRunner, tag, &commit, &notes, &signing, options.push)
+}
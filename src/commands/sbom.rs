@@ -0,0 +1,421 @@
+use crate::commands::lockfiles::{
+    parse_pnpm_lock, parse_poetry_lock, parse_yarn_lock, Ecosystem, LockedPackage,
+};
+use crate::commands::org::project_name;
+use crate::commands::verify_deps::{
+    read_pinned_packages, requirements_checksums, Checksum, PinnedPackage,
+};
+use crate::commands::version_management::{detect_current_version, Manifest};
+use crate::utils::output::{is_json_output, print_json};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Where `create-release --sbom` keeps the SBOM of each release.
+pub const RELEASE_SBOM_DIR: &str = ".nitrokit/sbom";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SbomFormat {
+    #[default]
+    CycloneDx,
+    Spdx,
+}
+
+impl SbomFormat {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SbomFormat::CycloneDx => "CycloneDX 1.5",
+            SbomFormat::Spdx => "SPDX 2.3",
+        }
+    }
+
+    /// `sbom.cdx.json` / `sbom.spdx.json`, the names the tools look for.
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            SbomFormat::CycloneDx => "cdx.json",
+            SbomFormat::Spdx => "spdx.json",
+        }
+    }
+}
+
+impl std::str::FromStr for SbomFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "cyclonedx" | "cdx" => Ok(SbomFormat::CycloneDx),
+            "spdx" => Ok(SbomFormat::Spdx),
+            other => Err(anyhow!(
+                "Unknown SBOM format: {}. Use cyclonedx or spdx",
+                other
+            )),
+        }
+    }
+}
+
+/// The project the SBOM describes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SbomProject {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+impl SbomProject {
+    /// The directory name and the version of the first manifest with one.
+    pub fn detect(dir: &Path) -> Self {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        Self {
+            name: project_name(&dir),
+            version: detect_current_version(&dir, &Manifest::ALL),
+        }
+    }
+}
+
+/// Percent-encodes a purl segment, keeping the unreserved characters.
+fn purl_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Package URL of a locked package, e.g. `pkg:npm/%40scope/name@1.0.0`.
+pub fn purl(package: &LockedPackage) -> String {
+    let (kind, name) = match package.ecosystem {
+        Ecosystem::Cargo => ("cargo", purl_encode(&package.name)),
+        Ecosystem::Npm => (
+            "npm",
+            package
+                .name
+                .split('/')
+                .map(purl_encode)
+                .collect::<Vec<_>>()
+                .join("/"),
+        ),
+        // PyPI names are case-insensitive and treat `_` like `-`
+        Ecosystem::PyPI => (
+            "pypi",
+            purl_encode(&package.name.to_lowercase().replace('_', "-")),
+        ),
+    };
+    format!("pkg:{}/{}@{}", kind, name, purl_encode(&package.version))
+}
+
+/// Requirements lockfiles besides `requirements.txt`, such as
+/// `requirements-dev.txt` or `requirements.lock`.
+fn extra_requirements_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            name.starts_with("requirements")
+                && (name.ends_with(".txt") || name.ends_with(".lock"))
+                && name != "requirements.txt"
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Every package pinned in the lockfiles of `dir`, with the checksums the
+/// lockfile records, sorted by purl and without duplicates.
+pub fn collect_components(dir: &Path) -> Result<Vec<PinnedPackage>> {
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+    let mut packages = read_pinned_packages(dir)?;
+    for path in extra_requirements_files(dir) {
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            packages.extend(requirements_checksums(&content));
+        }
+    }
+
+    // Lockfiles without checksum support, listed without hashes
+    let mut unhashed = Vec::new();
+    if read("package-lock.json").is_none() {
+        if let Some(content) = read("pnpm-lock.yaml") {
+            unhashed.extend(parse_pnpm_lock(&content));
+        } else if let Some(content) = read("yarn.lock") {
+            unhashed.extend(parse_yarn_lock(&content));
+        }
+    }
+    if let Some(content) = read("poetry.lock") {
+        unhashed.extend(parse_poetry_lock(&content));
+    }
+    packages.extend(unhashed.into_iter().map(|package| PinnedPackage {
+        package,
+        checksums: Vec::new(),
+    }));
+
+    let mut seen = HashSet::new();
+    packages.retain(|p| {
+        seen.insert((
+            p.package.ecosystem,
+            p.package.name.clone(),
+            p.package.version.clone(),
+        ))
+    });
+    packages.sort_by_key(|p| purl(&p.package));
+    Ok(packages)
+}
+
+/// A UUID derived from the project and its components, so the same
+/// lockfiles always give the same serial number.
+fn content_uuid(project: &SbomProject, components: &[PinnedPackage]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(project.name.as_bytes());
+    hasher.update(project.version.as_deref().unwrap_or("").as_bytes());
+    for component in components {
+        hasher.update(purl(&component.package).as_bytes());
+    }
+    let mut bytes: [u8; 16] = hasher.finalize()[..16].try_into().unwrap();
+    // Version 5 style (name based), RFC 4122 variant
+    bytes[6] = (bytes[6] & 0x0f) | 0x50;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Hash algorithm names of CycloneDX (`SHA-256`) and SPDX (`SHA256`).
+fn hash_algorithm(checksum: &Checksum, format: SbomFormat) -> Option<&'static str> {
+    let names = match checksum.algorithm.as_str() {
+        "sha1" => ("SHA-1", "SHA1"),
+        "sha256" => ("SHA-256", "SHA256"),
+        "sha384" => ("SHA-384", "SHA384"),
+        "sha512" => ("SHA-512", "SHA512"),
+        _ => return None,
+    };
+    Some(match format {
+        SbomFormat::CycloneDx => names.0,
+        SbomFormat::Spdx => names.1,
+    })
+}
+
+pub fn cyclonedx_document(
+    project: &SbomProject,
+    components: &[PinnedPackage],
+    timestamp: &str,
+) -> Value {
+    let mut root = Map::new();
+    root.insert("type".to_string(), json!("application"));
+    root.insert("bom-ref".to_string(), json!(project.name));
+    root.insert("name".to_string(), json!(project.name));
+    if let Some(version) = &project.version {
+        root.insert("version".to_string(), json!(version));
+    }
+
+    let entries: Vec<Value> = components
+        .iter()
+        .map(|component| {
+            let purl = purl(&component.package);
+            let mut entry = json!({
+                "type": "library",
+                "bom-ref": purl,
+                "name": component.package.name,
+                "version": component.package.version,
+                "purl": purl,
+            });
+            let hashes: Vec<Value> = component
+                .checksums
+                .iter()
+                .filter_map(|checksum| {
+                    hash_algorithm(checksum, SbomFormat::CycloneDx)
+                        .map(|alg| json!({ "alg": alg, "content": checksum.hex }))
+                })
+                .collect();
+            if !hashes.is_empty() {
+                entry["hashes"] = json!(hashes);
+            }
+            entry
+        })
+        .collect();
+
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "serialNumber": format!("urn:uuid:{}", content_uuid(project, components)),
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": "nitroterm",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+            "component": root,
+        },
+        "components": entries,
+    })
+}
+
+pub fn spdx_document(
+    project: &SbomProject,
+    components: &[PinnedPackage],
+    timestamp: &str,
+) -> Value {
+    let mut root = json!({
+        "name": project.name,
+        "SPDXID": "SPDXRef-Project",
+        "downloadLocation": "NOASSERTION",
+        "filesAnalyzed": false,
+    });
+    if let Some(version) = &project.version {
+        root["versionInfo"] = json!(version);
+    }
+
+    let mut packages = vec![root];
+    let mut relationships = vec![json!({
+        "spdxElementId": "SPDXRef-DOCUMENT",
+        "relationshipType": "DESCRIBES",
+        "relatedSpdxElement": "SPDXRef-Project",
+    })];
+    for (index, component) in components.iter().enumerate() {
+        let id = format!("SPDXRef-Package-{}", index + 1);
+        let mut entry = json!({
+            "name": component.package.name,
+            "SPDXID": id,
+            "versionInfo": component.package.version,
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+            "externalRefs": [{
+                "referenceCategory": "PACKAGE-MANAGER",
+                "referenceType": "purl",
+                "referenceLocator": purl(&component.package),
+            }],
+        });
+        let checksums: Vec<Value> = component
+            .checksums
+            .iter()
+            .filter_map(|checksum| {
+                hash_algorithm(checksum, SbomFormat::Spdx).map(
+                    |algorithm| json!({ "algorithm": algorithm, "checksumValue": checksum.hex }),
+                )
+            })
+            .collect();
+        if !checksums.is_empty() {
+            entry["checksums"] = json!(checksums);
+        }
+        packages.push(entry);
+        relationships.push(json!({
+            "spdxElementId": "SPDXRef-Project",
+            "relationshipType": "DEPENDS_ON",
+            "relatedSpdxElement": id,
+        }));
+    }
+
+    let name = match &project.version {
+        Some(version) => format!("{}-{}", project.name, version),
+        None => project.name.clone(),
+    };
+    json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": name,
+        "documentNamespace": format!(
+            "https://spdx.org/spdxdocs/{}-{}",
+            purl_encode(&name),
+            content_uuid(project, components)
+        ),
+        "creationInfo": {
+            "created": timestamp,
+            "creators": [format!("Tool: nitroterm-{}", env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": packages,
+        "relationships": relationships,
+    })
+}
+
+/// The SBOM of the project in `dir` and its component count. Fails when
+/// no supported lockfile lists anything.
+pub fn generate_sbom(dir: &Path, format: SbomFormat) -> Result<(Value, usize)> {
+    let components = collect_components(dir)?;
+    if components.is_empty() {
+        return Err(anyhow!(
+            "No packages found in {}: add a Cargo.lock, package-lock.json, pnpm-lock.yaml, yarn.lock, poetry.lock or pinned requirements file",
+            dir.display()
+        ));
+    }
+    let project = SbomProject::detect(dir);
+    let timestamp = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let document = match format {
+        SbomFormat::CycloneDx => cyclonedx_document(&project, &components, &timestamp),
+        SbomFormat::Spdx => spdx_document(&project, &components, &timestamp),
+    };
+    Ok((document, components.len()))
+}
+
+/// `create-release --sbom`: writes the SBOM of the current directory to
+/// `.nitrokit/sbom/<project>-<tag>-sbom.<ext>` and returns its path.
+pub fn write_release_sbom(format: SbomFormat, tag: &str) -> Result<PathBuf> {
+    let dir = Path::new(".");
+    let (document, count) = generate_sbom(dir, format)?;
+    let project = SbomProject::detect(dir);
+    let path = Path::new(RELEASE_SBOM_DIR).join(format!(
+        "{}-{}-sbom.{}",
+        project.name,
+        tag,
+        format.file_extension()
+    ));
+    std::fs::create_dir_all(RELEASE_SBOM_DIR)?;
+    std::fs::write(&path, serde_json::to_string_pretty(&document)?)?;
+    println!(
+        "{}",
+        format!(
+            "📦 SBOM ({}, {} components): {}",
+            format.display_name(),
+            count,
+            path.display()
+        )
+        .green()
+    );
+    Ok(path)
+}
+
+/// `nitroterm sbom`: writes the SBOM of `path` to `output` (default
+/// `sbom.cdx.json` / `sbom.spdx.json`), or prints it with `--json`.
+pub fn run_sbom(path: Option<&str>, format: SbomFormat, output: Option<&str>) -> Result<()> {
+    let dir = Path::new(path.unwrap_or("."));
+    let (document, count) = generate_sbom(dir, format)?;
+    if is_json_output() {
+        return print_json(&document);
+    }
+
+    ensure_not_sandboxed("Writing the SBOM")?;
+    let output = output
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("sbom.{}", format.file_extension())));
+    std::fs::write(&output, serde_json::to_string_pretty(&document)?)?;
+    println!(
+        "{}",
+        format!(
+            "📦 {} SBOM with {} component(s) written to {}",
+            format.display_name(),
+            count,
+            output.display()
+        )
+        .green()
+    );
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use crate::commands::code_quality::FINDING_PATTERN;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Every generated task label starts with this, so re-running the scaffold
+/// replaces our tasks and leaves the user's own ones alone.
+pub const TASK_PREFIX: &str = "nitroterm: ";
+
+/// Matches the lines `code-quality` prints for failed checks (see `Finding::to_line`).
+pub fn problem_matcher() -> Value {
+    json!({
+        "owner": "nitroterm",
+        "source": "nitroterm",
+        "fileLocation": ["autoDetect", "${workspaceFolder}"],
+        "pattern": {
+            "regexp": FINDING_PATTERN,
+            "file": 1,
+            "line": 2,
+            "column": 3,
+            "severity": 4,
+            "message": 5,
+            "code": 6,
+        },
+    })
+}
+
+fn task(label: &str, args: &[&str], matcher: Value, group: Option<Value>) -> Value {
+    let mut task = json!({
+        "label": format!("{}{}", TASK_PREFIX, label),
+        "type": "shell",
+        "command": "nitroterm",
+        "args": args,
+        "options": { "cwd": "${workspaceFolder}" },
+        "problemMatcher": matcher,
+        "presentation": { "reveal": "always", "panel": "dedicated", "clear": true },
+    });
+    if let Some(group) = group {
+        task["group"] = group;
+    }
+    task
+}
+
+pub fn vscode_tasks() -> Vec<Value> {
+    vec![
+        task(
+            "code quality",
+            &["code-quality"],
+            json!([problem_matcher()]),
+            Some(json!({ "kind": "test", "isDefault": true })),
+        ),
+        task(
+            "code quality report",
+            &["code-quality", "--format", "sarif"],
+            json!([problem_matcher()]),
+            Some(json!("test")),
+        ),
+        task(
+            "validate translations",
+            &["validate-translations"],
+            json!([]),
+            Some(json!("test")),
+        ),
+        task(
+            "translation status",
+            &["translations", "status"],
+            json!([]),
+            None,
+        ),
+        task("release notes", &["release-notes"], json!([]), None),
+        task("project report", &["report"], json!([]), None),
+    ]
+}
+
+/// Replaces previously generated tasks and keeps everything else.
+pub fn merge_tasks(existing: Option<Value>, generated: Vec<Value>) -> Result<Value> {
+    let mut document = existing.unwrap_or_else(|| json!({ "version": "2.0.0", "tasks": [] }));
+    let object = document
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("tasks.json must contain a JSON object"))?;
+    object.entry("version").or_insert_with(|| json!("2.0.0"));
+
+    let tasks = object
+        .entry("tasks")
+        .or_insert_with(|| json!([]))
+        .as_array_mut()
+        .ok_or_else(|| anyhow!("\"tasks\" in tasks.json must be an array"))?;
+    tasks.retain(|task| {
+        !task["label"]
+            .as_str()
+            .is_some_and(|label| label.starts_with(TASK_PREFIX))
+    });
+    tasks.extend(generated);
+
+    Ok(document)
+}
+
+pub fn run_scaffold_vscode(path: Option<&str>, force: bool) -> Result<()> {
+    ensure_not_sandboxed("scaffold vscode")?;
+    let root = Path::new(path.unwrap_or("."));
+    let tasks_path = root.join(".vscode").join("tasks.json");
+
+    let existing = if tasks_path.exists() {
+        let content = std::fs::read_to_string(&tasks_path)?;
+        match serde_json::from_str::<Value>(&content) {
+            Ok(value) => Some(value),
+            // tasks.json often has comments, which we can't round-trip
+            Err(_) if force => None,
+            Err(e) => {
+                return Err(anyhow!(
+                    "Could not parse {} ({}). Remove comments or use --force to overwrite it",
+                    tasks_path.display(),
+                    e
+                ))
+            }
+        }
+    } else {
+        None
+    };
+
+    let generated = vscode_tasks();
+    let count = generated.len();
+    let document = merge_tasks(existing, generated)?;
+
+    std::fs::create_dir_all(tasks_path.parent().unwrap_or(root))?;
+    std::fs::write(
+        &tasks_path,
+        format!("{}\n", serde_json::to_string_pretty(&document)?),
+    )?;
+
+    println!(
+        "{}",
+        format!(
+            "✅ Wrote {} nitroterm tasks to {}",
+            count,
+            tasks_path.display()
+        )
+        .green()
+    );
+    println!(
+        "{}",
+        "💡 Run \"Tasks: Run Test Task\" in VS Code; failed checks show up in the Problems panel"
+            .dimmed()
+    );
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use crate::commands::code_quality::CheckResult;
+use crate::utils::file_system::GitIgnore;
+use regex::Regex;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Files larger than this are skipped, they're data rather than code.
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+/// Lockfiles are full of integrity hashes that look like random strings.
+const SKIPPED_FILES: [&str; 7] = [
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Cargo.lock",
+    "composer.lock",
+    "poetry.lock",
+    "uv.lock",
+];
+const SKIPPED_DIRS: [&str; 4] = [".git", "node_modules", "target", "vendor"];
+/// Name to list in `enabled_checks` (or `--checks`) to run the scan.
+pub const SECRETS_CHECK: &str = "secrets";
+/// A line containing this marker is never reported.
+pub const ALLOW_MARKER: &str = "nitroterm:allow-secret";
+
+/// Bits per character above which a quoted token counts as random. Hex
+/// digests top out at 4.0, so commit hashes and checksums stay quiet.
+const ENTROPY_THRESHOLD: f64 = 4.5;
+/// Lower bar for values assigned to names like `api_key` or `password`.
+const ASSIGNMENT_ENTROPY_THRESHOLD: f64 = 3.5;
+
+/// A likely credential in the working tree.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SecretMatch {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub rule: &'static str,
+    /// Start of the value only, the secret itself is never printed
+    pub redacted: String,
+}
+
+struct Rule {
+    name: &'static str,
+    description: &'static str,
+    pattern: Regex,
+    /// Minimum entropy of the capture group `value`, if the rule has one
+    min_entropy: Option<f64>,
+}
+
+fn rules() -> &'static [Rule] {
+    static RULES: OnceLock<Vec<Rule>> = OnceLock::new();
+    RULES.get_or_init(|| {
+        let rule = |name, description, pattern: &str, min_entropy| Rule {
+            name,
+            description,
+            pattern: Regex::new(pattern).unwrap(),
+            min_entropy,
+        };
+        vec![
+            rule(
+                "private-key",
+                "Private key block",
+                r"-----BEGIN (?:[A-Z]+ )*PRIVATE KEY(?: BLOCK)?-----",
+                None,
+            ),
+            rule(
+                "aws-access-key",
+                "AWS access key id",
+                r"\b(?P<value>(?:AKIA|ASIA)[0-9A-Z]{16})\b",
+                None,
+            ),
+            rule(
+                "aws-secret-key",
+                "AWS secret access key",
+                r#"(?i)aws.{0,20}?(?:secret|key)\w*['"]?\s*[:=]\s*['"]?(?P<value>[0-9a-zA-Z/+]{40})\b"#,
+                Some(ASSIGNMENT_ENTROPY_THRESHOLD),
+            ),
+            rule(
+                "github-token",
+                "GitHub token",
+                r"\b(?P<value>(?:gh[pousr]_[A-Za-z0-9]{36}|github_pat_[A-Za-z0-9_]{60,}))\b",
+                None,
+            ),
+            rule(
+                "slack-token",
+                "Slack token",
+                r"\b(?P<value>xox[abposr]-[A-Za-z0-9-]{10,})",
+                None,
+            ),
+            rule(
+                "google-api-key",
+                "Google API key",
+                r"\b(?P<value>AIza[0-9A-Za-z_\-]{35})",
+                None,
+            ),
+            rule(
+                "stripe-key",
+                "Stripe secret key",
+                r"\b(?P<value>[sr]k_live_[0-9a-zA-Z]{24,})",
+                None,
+            ),
+            rule(
+                "ai-api-key",
+                "OpenAI or Anthropic API key",
+                r"\b(?P<value>sk-(?:ant-|proj-)?[A-Za-z0-9_\-]{32,})",
+                None,
+            ),
+            rule(
+                "generic-secret",
+                "Hard-coded secret",
+                r#"(?i)(?:api[_-]?key|secret|token|passw(?:or)?d|access[_-]?key|credential)\w*['"]?\s*[:=]\s*['"](?P<value>[^'"\s]{16,})['"]"#,
+                Some(ASSIGNMENT_ENTROPY_THRESHOLD),
+            ),
+            rule(
+                "high-entropy-string",
+                "High-entropy string",
+                r#"['"](?P<value>[A-Za-z0-9+/=_\-]{32,})['"]"#,
+                Some(ENTROPY_THRESHOLD),
+            ),
+        ]
+    })
+}
+
+/// Shannon entropy in bits per character.
+pub fn shannon_entropy(value: &str) -> f64 {
+    let mut counts = std::collections::HashMap::new();
+    for ch in value.chars() {
+        *counts.entry(ch).or_insert(0usize) += 1;
+    }
+    let length = value.chars().count() as f64;
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / length;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Subresource integrity hashes (`sha512-...`) in manifests and HTML are
+/// random by design.
+fn is_integrity_hash(value: &str) -> bool {
+    ["sha1-", "sha256-", "sha384-", "sha512-"]
+        .iter()
+        .any(|prefix| value.starts_with(prefix))
+}
+
+fn redact(value: &str) -> String {
+    let start: String = value.chars().take(4).collect();
+    format!("{}…", start)
+}
+
+/// Likely secrets in `content` as (line, column, rule, redacted value),
+/// one per line: the first rule that matches wins.
+pub fn scan_text(content: &str) -> Vec<(usize, usize, &'static str, String)> {
+    let mut found = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.contains(ALLOW_MARKER) {
+            continue;
+        }
+        let hit = rules().iter().find_map(|rule| {
+            rule.pattern.captures_iter(line).find_map(|caps| {
+                let whole = caps.get(0)?;
+                let value = caps.name("value").unwrap_or(whole);
+                let entropy_ok = rule
+                    .min_entropy
+                    .is_none_or(|min| shannon_entropy(value.as_str()) >= min);
+                (entropy_ok && !is_integrity_hash(value.as_str()))
+                    .then(|| (value.start() + 1, rule.name, redact(value.as_str())))
+            })
+        });
+        if let Some((column, rule, redacted)) = hit {
+            found.push((index + 1, column, rule, redacted));
+        }
+    }
+    found
+}
+
+/// Files below `root` worth scanning: no build output, dependencies,
+/// lockfiles, large or binary files, nothing `.gitignore` excludes.
+fn candidate_files(root: &Path) -> Vec<PathBuf> {
+    let gitignore = GitIgnore::new(root);
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(_) => continue,
+            };
+            if file_type.is_dir() {
+                if !SKIPPED_DIRS.contains(&name.as_str()) && !gitignore.is_ignored(&path) {
+                    pending.push(path);
+                }
+            } else if file_type.is_file()
+                && !SKIPPED_FILES.contains(&name.as_str())
+                && entry.metadata().is_ok_and(|m| m.len() <= MAX_FILE_BYTES)
+                && !gitignore.is_ignored(&path)
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Scans the working tree below `root`. Paths in the result are relative to it.
+pub fn scan_secrets(root: &Path) -> Vec<SecretMatch> {
+    let mut matches = Vec::new();
+    for path in candidate_files(root) {
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(_) => continue,
+        };
+        if bytes.contains(&0) {
+            continue;
+        }
+        let content = String::from_utf8_lossy(&bytes);
+        let file = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        for (line, column, rule, redacted) in scan_text(&content) {
+            matches.push(SecretMatch {
+                file: file.clone(),
+                line,
+                column,
+                rule,
+                redacted,
+            });
+        }
+    }
+    matches
+}
+
+/// The `secrets` check: fails on any match, one `file:line:col: error:`
+/// line per match so reports and editor problem matchers pick them up.
+pub fn run_secrets_check(root: &Path) -> CheckResult {
+    let start = std::time::Instant::now();
+    let matches = scan_secrets(root);
+    let output = matches
+        .iter()
+        .map(|found| {
+            let description = rules()
+                .iter()
+                .find(|rule| rule.name == found.rule)
+                .map_or(found.rule, |rule| rule.description);
+            format!(
+                "{}:{}:{}: error: {} ({}) [{}]",
+                found.file, found.line, found.column, description, found.redacted, found.rule
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CheckResult {
+        check_name: SECRETS_CHECK.to_string(),
+        command: "built-in secret scan".to_string(),
+        success: matches.is_empty(),
+        output: if matches.is_empty() {
+            "No secrets found".to_string()
+        } else {
+            output
+        },
+        error: None,
+        duration_ms: start.elapsed().as_millis(),
+    }
+}
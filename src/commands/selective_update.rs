@@ -0,0 +1,779 @@
+use crate::commands::cargo_workspace::bump_dependency_in_manifest;
+use crate::commands::dependency_update::{
+    backup_cargo_files, backup_deno_files, backup_lock_files, backup_python_files,
+    detect_node_package_manager, detect_python_package_manager, find_project_files, manifest_dir,
+    split_manifest, update_rules, EcosystemResult, UpdateRules, UpdateStatus,
+};
+use crate::commands::release_risk::parse_manifest_dependencies;
+use crate::utils::file_system::ScanOptions;
+use crate::utils::output::is_json_output;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::progress::Spinner;
+use crate::utils::prompt::{checklist, ChecklistItem};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::{log_info, log_success, log_warning};
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use semver::Version;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// How far an update jumps, by semver rules: for `0.x` versions a minor
+/// bump is breaking and counts as major.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateKind {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl UpdateKind {
+    pub const ALL: [UpdateKind; 3] = [UpdateKind::Patch, UpdateKind::Minor, UpdateKind::Major];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpdateKind::Patch => "patch",
+            UpdateKind::Minor => "minor",
+            UpdateKind::Major => "major",
+        }
+    }
+
+    fn heading(&self) -> String {
+        match self {
+            UpdateKind::Patch => "🟢 Patch updates".green().to_string(),
+            UpdateKind::Minor => "🟡 Minor updates".yellow().to_string(),
+            UpdateKind::Major => "🔴 Major updates (may break)".red().to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for UpdateKind {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        UpdateKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == value.trim().to_lowercase())
+            .ok_or_else(|| anyhow!("Unknown update kind '{}', use patch, minor or major", value))
+    }
+}
+
+/// A newer release of one dependency.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AvailableUpdate {
+    pub name: String,
+    pub current: String,
+    pub latest: String,
+    pub kind: UpdateKind,
+    /// Listed under devDependencies; npm-style managers need the flag again
+    pub dev: bool,
+}
+
+/// Updates found for one manifest and the tool that applies them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestUpdates {
+    pub manifest: String,
+    pub package_manager: String,
+    pub updates: Vec<AvailableUpdate>,
+}
+
+/// Parses `1.2`, `v1.2.3` or `^1.2.3` by padding and stripping what semver
+/// won't take.
+pub fn lenient_version(value: &str) -> Option<Version> {
+    let trimmed = value
+        .trim()
+        .trim_start_matches(['v', 'V', '^', '~', '=', ' ']);
+    if let Ok(version) = Version::parse(trimmed) {
+        return Some(version);
+    }
+    let (core, suffix) = match trimmed.find(['-', '+']) {
+        Some(index) => trimmed.split_at(index),
+        None => (trimmed, ""),
+    };
+    let parts = core.split('.').count();
+    if parts == 0 || parts > 3 {
+        return None;
+    }
+    let padded = format!("{}{}{}", core, ".0".repeat(3 - parts), suffix);
+    Version::parse(&padded).ok()
+}
+
+/// None when `latest` is not newer than `current` or either isn't a version.
+pub fn classify_update(current: &str, latest: &str) -> Option<UpdateKind> {
+    let current = lenient_version(current)?;
+    let latest = lenient_version(latest)?;
+    if latest <= current {
+        return None;
+    }
+    Some(if latest.major != current.major {
+        UpdateKind::Major
+    } else if latest.minor != current.minor {
+        if current.major == 0 {
+            UpdateKind::Major
+        } else {
+            UpdateKind::Minor
+        }
+    } else {
+        UpdateKind::Patch
+    })
+}
+
+fn update(name: &str, current: &str, latest: &str, dev: bool) -> Option<AvailableUpdate> {
+    Some(AvailableUpdate {
+        name: name.to_string(),
+        current: current.to_string(),
+        latest: latest.to_string(),
+        kind: classify_update(current, latest)?,
+        dev,
+    })
+}
+
+/// `npm outdated --json --long` and `pnpm outdated --format json`: an object
+/// keyed by package. Packages that aren't installed have no `current`.
+pub fn parse_npm_outdated(json: &str) -> Vec<AvailableUpdate> {
+    let Ok(Value::Object(packages)) = serde_json::from_str::<Value>(json) else {
+        return Vec::new();
+    };
+    packages
+        .iter()
+        .filter_map(|(name, info)| {
+            let current = info["current"].as_str()?;
+            let latest = info["latest"].as_str()?;
+            let kind = info["type"]
+                .as_str()
+                .or_else(|| info["dependencyType"].as_str())
+                .unwrap_or_default();
+            update(name, current, latest, kind == "devDependencies")
+        })
+        .collect()
+}
+
+/// `yarn outdated --json` (classic): one JSON object per line, the packages
+/// are the body of the `table` entry.
+pub fn parse_yarn_outdated(output: &str) -> Vec<AvailableUpdate> {
+    let Some(table) = output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .find(|entry| entry["type"] == "table")
+    else {
+        return Vec::new();
+    };
+    let head: Vec<&str> = table["data"]["head"]
+        .as_array()
+        .map(|head| head.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let column = |name: &str| head.iter().position(|column| *column == name);
+    let (Some(package), Some(current), Some(latest)) =
+        (column("Package"), column("Current"), column("Latest"))
+    else {
+        return Vec::new();
+    };
+    let package_type = column("Package Type");
+
+    table["data"]["body"]
+        .as_array()
+        .map(|rows| {
+            rows.iter()
+                .filter_map(|row| {
+                    let cell = |index: usize| row.get(index).and_then(Value::as_str);
+                    let dev = package_type.and_then(cell) == Some("devDependencies");
+                    update(cell(package)?, cell(current)?, cell(latest)?, dev)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `cargo update --dry-run --verbose`, limited to the manifest's direct
+/// dependencies. A crate can yield a compatible update and a major one.
+pub fn parse_cargo_dry_run(output: &str, direct: &[String]) -> Vec<AvailableUpdate> {
+    let updating = Regex::new(r"^\s*Updating (\S+) v(\S+) -> v(\S+)").unwrap();
+    let available = Regex::new(r"\(available: v(\S+)\)").unwrap();
+    let unchanged = Regex::new(r"^\s*Unchanged (\S+) v(\S+)").unwrap();
+
+    let mut updates: Vec<AvailableUpdate> = Vec::new();
+    for line in output.lines() {
+        let (name, current, compatible) = if let Some(caps) = updating.captures(line) {
+            (
+                caps[1].to_string(),
+                caps[2].to_string(),
+                Some(caps[3].to_string()),
+            )
+        } else if let Some(caps) = unchanged.captures(line) {
+            (caps[1].to_string(), caps[2].to_string(), None)
+        } else {
+            continue;
+        };
+        if !direct.contains(&name) {
+            continue;
+        }
+        let newest = available.captures(line).map(|caps| caps[1].to_string());
+        for latest in compatible.iter().chain(newest.iter()) {
+            if let Some(found) = update(&name, &current, latest, false) {
+                if !updates.contains(&found) {
+                    updates.push(found);
+                }
+            }
+        }
+    }
+    updates
+}
+
+/// `pip list --outdated --format=json`
+pub fn parse_pip_outdated(json: &str) -> Vec<AvailableUpdate> {
+    serde_json::from_str::<Vec<Value>>(json)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|package| {
+            update(
+                package["name"].as_str()?,
+                package["version"].as_str()?,
+                package["latest_version"].as_str()?,
+                false,
+            )
+        })
+        .collect()
+}
+
+/// `poetry show --outdated --top-level`: name, current, latest and the
+/// description, with `(!)` after packages that aren't installed.
+pub fn parse_poetry_outdated(output: &str) -> Vec<AvailableUpdate> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace().filter(|field| *field != "(!)");
+            update(fields.next()?, fields.next()?, fields.next()?, false)
+        })
+        .collect()
+}
+
+/// The box table of `bun outdated` and `deno outdated`: package, current,
+/// update and latest columns; bun marks dev dependencies with `(dev)`.
+pub fn parse_outdated_table(output: &str) -> Vec<AvailableUpdate> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line
+                .split(['│', '|'])
+                .map(str::trim)
+                .filter(|cell| !cell.is_empty())
+                .collect();
+            if cells.len() < 4 || cells[0] == "Package" {
+                return None;
+            }
+            let (name, dev) = match cells[0].strip_suffix("(dev)") {
+                Some(name) => (name.trim(), true),
+                None => (cells[0], false),
+            };
+            update(name, cells[1], cells[3], dev)
+        })
+        .collect()
+}
+
+/// `composer outdated --direct --format=json`
+pub fn parse_composer_outdated(json: &str) -> Vec<AvailableUpdate> {
+    serde_json::from_str::<Value>(json)
+        .ok()
+        .and_then(|report| report["installed"].as_array().cloned())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|package| {
+            update(
+                package["name"].as_str()?,
+                package["version"].as_str()?,
+                package["latest"].as_str()?,
+                false,
+            )
+        })
+        .collect()
+}
+
+/// `npm.cmd` and friends on Windows, where the package managers are shims.
+fn program(package_manager: &str) -> String {
+    if cfg!(target_os = "windows") && matches!(package_manager, "npm" | "pnpm" | "yarn") {
+        format!("{}.cmd", package_manager)
+    } else {
+        package_manager.to_string()
+    }
+}
+
+type OutdatedParser = fn(&str) -> Vec<AvailableUpdate>;
+
+/// Asks the package manager of `manifest` for newer releases. The outdated
+/// commands exit non-zero when they find anything, so only output counts.
+pub fn list_updates(
+    runner: &dyn CommandRunner,
+    root: &Path,
+    manifest: &str,
+    package_manager: &str,
+) -> Result<Vec<AvailableUpdate>> {
+    let (args, parse): (&[&str], OutdatedParser) = match package_manager {
+        "npm" => (&["outdated", "--json", "--long"], parse_npm_outdated),
+        "pnpm" => (&["outdated", "--format", "json"], parse_npm_outdated),
+        "yarn" => (&["outdated", "--json"], parse_yarn_outdated),
+        "bun" | "deno" => (&["outdated"], parse_outdated_table),
+        "pip" => (&["list", "--outdated", "--format=json"], parse_pip_outdated),
+        "uv" => (
+            &["pip", "list", "--outdated", "--format=json"],
+            parse_pip_outdated,
+        ),
+        "poetry" => (
+            &["show", "--outdated", "--top-level"],
+            parse_poetry_outdated,
+        ),
+        "composer" => (
+            &["outdated", "--direct", "--format=json"],
+            parse_composer_outdated,
+        ),
+        "cargo" => {
+            let content = std::fs::read_to_string(root.join(manifest))?;
+            let direct: Vec<String> = parse_manifest_dependencies(manifest, &content)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+            let output = runner
+                .run("cargo", &["update", "--dry-run", "--verbose"], Some(root))?
+                .check("cargo update --dry-run")?;
+            let combined = format!("{}\n{}", output.stdout, output.stderr);
+            return Ok(parse_cargo_dry_run(&combined, &direct));
+        }
+        _ => return Err(anyhow!("Unsupported package manager: {}", package_manager)),
+    };
+
+    let output = runner.run(&program(package_manager), args, Some(root))?;
+    if !output.success && output.stdout.trim().is_empty() {
+        return Err(anyhow!(
+            "{} outdated failed: {}",
+            package_manager,
+            output.stderr.trim()
+        ));
+    }
+    Ok(parse(&output.stdout))
+}
+
+/// The commands that apply `updates`, in order. Major Cargo updates also
+/// need their requirement bumped in Cargo.toml first.
+pub fn update_commands(package_manager: &str, updates: &[&AvailableUpdate]) -> Vec<Vec<String>> {
+    let specs = |dev: bool, separator: &str| -> Vec<String> {
+        updates
+            .iter()
+            .filter(|update| update.dev == dev)
+            .map(|update| format!("{}{}{}", update.name, separator, update.latest))
+            .collect()
+    };
+    let with_specs = |base: &[&str], specs: Vec<String>| -> Option<Vec<String>> {
+        (!specs.is_empty()).then(|| {
+            base.iter()
+                .map(|arg| arg.to_string())
+                .chain(specs)
+                .collect()
+        })
+    };
+
+    match package_manager {
+        "npm" | "pnpm" | "yarn" | "bun" | "deno" => {
+            let (install, dev_flag) = match package_manager {
+                "npm" => ("install", "--save-dev"),
+                "pnpm" => ("add", "-D"),
+                _ => ("add", "--dev"),
+            };
+            [
+                with_specs(&[package_manager, install], specs(false, "@")),
+                with_specs(&[package_manager, install, dev_flag], specs(true, "@")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect()
+        }
+        "pip" => with_specs(&["pip", "install", "--upgrade"], specs(false, "=="))
+            .into_iter()
+            .collect(),
+        "poetry" | "uv" => {
+            let (major, compatible): (Vec<&AvailableUpdate>, Vec<&AvailableUpdate>) = updates
+                .iter()
+                .partition(|update| update.kind == UpdateKind::Major);
+            let compatible = if package_manager == "poetry" {
+                with_specs(
+                    &["poetry", "update"],
+                    compatible
+                        .iter()
+                        .map(|update| update.name.clone())
+                        .collect(),
+                )
+            } else {
+                with_specs(
+                    &["uv", "lock"],
+                    compatible
+                        .iter()
+                        .flat_map(|update| ["--upgrade-package".to_string(), update.name.clone()])
+                        .collect(),
+                )
+            };
+            let (add, separator) = match package_manager {
+                "poetry" => (["poetry", "add"], "@^"),
+                _ => (["uv", "add"], ">="),
+            };
+            let major = with_specs(
+                &add,
+                major
+                    .iter()
+                    .map(|update| format!("{}{}{}", update.name, separator, update.latest))
+                    .collect(),
+            );
+            [compatible, major].into_iter().flatten().collect()
+        }
+        "composer" => {
+            let (major, compatible): (Vec<&AvailableUpdate>, Vec<&AvailableUpdate>) = updates
+                .iter()
+                .partition(|update| update.kind == UpdateKind::Major);
+            let names = compatible
+                .iter()
+                .map(|update| update.name.clone())
+                .collect();
+            let constraints = major
+                .iter()
+                .map(|update| format!("{}:^{}", update.name, update.latest))
+                .collect();
+            [
+                with_specs(&["composer", "update"], names),
+                with_specs(&["composer", "require"], constraints),
+            ]
+            .into_iter()
+            .flatten()
+            .collect()
+        }
+        "cargo" => updates
+            .iter()
+            .map(|update| {
+                let spec = format!("{}@{}", update.name, update.current);
+                let mut command = vec![
+                    "cargo".to_string(),
+                    "update".to_string(),
+                    "-p".to_string(),
+                    spec,
+                ];
+                if update.kind != UpdateKind::Major {
+                    command.extend(["--precise".to_string(), update.latest.clone()]);
+                }
+                command
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Raises the requirement of `name` in Cargo.toml so it allows `latest`.
+/// None when the dependency isn't there or is inherited from the workspace.
+pub fn bump_cargo_requirement(manifest: &str, name: &str, latest: &str) -> Option<String> {
+    let latest = lenient_version(latest)?;
+    let (_, req) = parse_manifest_dependencies("Cargo.toml", manifest)
+        .into_iter()
+        .find(|(dependency, _)| dependency == name)?;
+    bump_dependency_in_manifest(manifest, name, &req, &latest)
+}
+
+/// One update per package: the biggest jump when both a compatible and a
+/// major update were picked.
+fn dedupe_selected<'a>(updates: &[&'a AvailableUpdate]) -> Vec<&'a AvailableUpdate> {
+    let mut selected: Vec<&AvailableUpdate> = Vec::new();
+    for update in updates {
+        match selected.iter_mut().find(|kept| kept.name == update.name) {
+            Some(kept) if kept.kind < update.kind => *kept = update,
+            Some(_) => {}
+            None => selected.push(update),
+        }
+    }
+    selected
+}
+
+/// Applies the selected updates of one manifest.
+pub fn apply_updates(
+    runner: &dyn CommandRunner,
+    root: &Path,
+    manifest: &ManifestUpdates,
+    selected: &[&AvailableUpdate],
+) -> EcosystemResult {
+    let mut result = EcosystemResult::new(&manifest.manifest);
+    result.package_manager = Some(manifest.package_manager.clone());
+    let selected = dedupe_selected(selected);
+
+    if manifest.package_manager == "cargo" {
+        let path = root.join(split_manifest(&manifest.manifest).1);
+        let mut content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                result.fail(format!("Failed to read {}: {}", manifest.manifest, e));
+                return result;
+            }
+        };
+        let original = content.clone();
+        for update in selected.iter().filter(|u| u.kind == UpdateKind::Major) {
+            match bump_cargo_requirement(&content, &update.name, &update.latest) {
+                Some(bumped) => content = bumped,
+                None => log_warning(&format!(
+                    "Could not update the `{}` requirement in {}",
+                    update.name, manifest.manifest
+                )),
+            }
+        }
+        if content != original {
+            if let Err(e) = std::fs::write(&path, &content) {
+                result.fail(format!("Failed to write {}: {}", manifest.manifest, e));
+                return result;
+            }
+        }
+    }
+
+    for command in update_commands(&manifest.package_manager, &selected) {
+        let args: Vec<&str> = command[1..].iter().map(String::as_str).collect();
+        log_info(&format!("Running {}...", command.join(" ")));
+        let outcome = runner
+            .run(&program(&command[0]), &args, Some(root))
+            .and_then(|output| output.check(&command.join(" ")));
+        if let Err(e) = outcome {
+            result.fail(e.to_string());
+            return result;
+        }
+    }
+    result.status = UpdateStatus::Updated;
+    result
+}
+
+fn package_manager_for(dir: &Path, manifest: &str) -> Option<String> {
+    match manifest {
+        "package.json" => detect_node_package_manager(dir),
+        "Cargo.toml" => Some("cargo".to_string()),
+        "requirements.txt" => Some("pip".to_string()),
+        "pyproject.toml" => detect_python_package_manager(dir),
+        "composer.json" => Some("composer".to_string()),
+        "deno.json" | "deno.jsonc" => Some("deno".to_string()),
+        _ => None,
+    }
+}
+
+/// Checklist rows grouped patch → minor → major, with the kinds in
+/// `preselect` checked. Returns the rows and the (manifest, update) index of each.
+pub fn checklist_items(
+    manifests: &[ManifestUpdates],
+    preselect: &[UpdateKind],
+) -> (Vec<ChecklistItem>, Vec<(usize, usize)>) {
+    let mut rows: Vec<(usize, usize)> = manifests
+        .iter()
+        .enumerate()
+        .flat_map(|(m, manifest)| (0..manifest.updates.len()).map(move |u| (m, u)))
+        .collect();
+    rows.sort_by_key(|(m, u)| {
+        let update = &manifests[*m].updates[*u];
+        (update.kind, *m, update.name.clone())
+    });
+
+    let items = rows
+        .iter()
+        .map(|(m, u)| {
+            let manifest = &manifests[*m];
+            let update = &manifest.updates[*u];
+            let dev = if update.dev { ", dev" } else { "" };
+            ChecklistItem {
+                group: update.kind.heading(),
+                label: format!(
+                    "{:<32} {:>12} → {:<12} {}",
+                    update.name,
+                    update.current,
+                    update.latest,
+                    format!("({}{})", manifest.package_manager, dev).dimmed()
+                ),
+                checked: preselect.contains(&update.kind),
+            }
+        })
+        .collect();
+    (items, rows)
+}
+
+/// Backs up the manifest and lock file in `dir` that `package_manager`
+/// changes.
+fn backup_for(dir: &Path, package_manager: &str) -> Option<String> {
+    match package_manager {
+        "cargo" => backup_cargo_files(dir),
+        "npm" | "pnpm" | "yarn" | "bun" => backup_lock_files(dir, package_manager),
+        "deno" => backup_deno_files(dir),
+        "poetry" | "uv" => backup_python_files(dir, package_manager),
+        _ => None,
+    }
+}
+
+/// The updates of `manifest` that `rules` allow; the rest are logged as
+/// held back.
+pub fn allowed_updates(
+    rules: &UpdateRules,
+    manifest: &str,
+    updates: Vec<AvailableUpdate>,
+) -> Vec<AvailableUpdate> {
+    let ecosystem = EcosystemResult::new(manifest).ecosystem;
+    let (allowed, held): (Vec<AvailableUpdate>, Vec<AvailableUpdate>) = updates
+        .into_iter()
+        .partition(|update| rules.allows(ecosystem, &update.name, &update.latest));
+    if !held.is_empty() {
+        let mut names: Vec<&str> = held.iter().map(|update| update.name.as_str()).collect();
+        names.dedup();
+        log_info(&format!(
+            "Held back by dependency_update rules in {}: {}",
+            manifest,
+            names.join(", ")
+        ));
+    }
+    allowed
+}
+
+/// `update-dependencies` for a manifest with ignore or pin rules: instead of
+/// the bulk update, applies the allowed updates that stay within the
+/// current requirements one package at a time. `manifest` is the file name
+/// of the manifest in `root`.
+pub fn update_with_rules(root: &Path, manifest: &str, rules: &UpdateRules) -> EcosystemResult {
+    let mut result = EcosystemResult::new(manifest);
+    let Some(package_manager) = package_manager_for(root, manifest) else {
+        log_warning(&format!("No package manager found for {}", manifest));
+        return result;
+    };
+    result.package_manager = Some(package_manager.clone());
+    let runner = SystemRunner;
+
+    let spinner = Spinner::start(&format!("Checking {} for updates...", manifest));
+    let updates = match list_updates(&runner, root, manifest, &package_manager) {
+        Ok(updates) => {
+            spinner.finish();
+            allowed_updates(rules, manifest, updates)
+        }
+        Err(e) => {
+            spinner.fail();
+            result.fail(format!("Could not list updates for {}: {}", manifest, e));
+            return result;
+        }
+    };
+    let (major, compatible): (Vec<&AvailableUpdate>, Vec<&AvailableUpdate>) = updates
+        .iter()
+        .partition(|update| update.kind == UpdateKind::Major);
+
+    if !compatible.is_empty() {
+        let backup_dir = backup_for(root, &package_manager);
+        let manifest_updates = ManifestUpdates {
+            manifest: manifest.to_string(),
+            package_manager: package_manager.clone(),
+            updates: updates.clone(),
+        };
+        result = apply_updates(&runner, root, &manifest_updates, &compatible);
+        result.backup_dir = backup_dir;
+        if result.status == UpdateStatus::Updated {
+            log_success(&format!(
+                "Applied {} update(s) to {}",
+                compatible.len(),
+                manifest
+            ));
+        }
+    } else {
+        log_success(&format!(
+            "{} is up to date within its requirements",
+            manifest
+        ));
+    }
+
+    if !major.is_empty() {
+        let outdated = major
+            .iter()
+            .map(|update| format!("{} {} → {}", update.name, update.current, update.latest))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !is_json_output() {
+            println!("{}", "📊 Outdated packages:".yellow().bold());
+            println!("{}", outdated);
+        }
+        result.outdated = Some(outdated);
+    }
+    result
+}
+
+/// `update-dependencies --select`: lists available updates of every
+/// manifest `scan` finds below `root`, lets the user pick them and applies
+/// only those.
+pub fn run_selective_update(
+    root: &Path,
+    preselect: &[UpdateKind],
+    scan: &ScanOptions,
+) -> Result<Vec<EcosystemResult>> {
+    ensure_not_sandboxed("update-dependencies --select")?;
+    let runner = SystemRunner;
+
+    let mut manifests = Vec::new();
+    for manifest in find_project_files(root, scan) {
+        let dir = manifest_dir(root, &manifest);
+        let name = split_manifest(&manifest).1;
+        let Some(package_manager) = package_manager_for(&dir, name) else {
+            log_warning(&format!("No package manager found for {}", manifest));
+            continue;
+        };
+        let spinner = Spinner::start(&format!("Checking {} for updates...", manifest));
+        match list_updates(&runner, &dir, name, &package_manager) {
+            Ok(updates) => {
+                spinner.finish();
+                let updates = allowed_updates(update_rules(), &manifest, updates);
+                manifests.push(ManifestUpdates {
+                    manifest,
+                    package_manager,
+                    updates,
+                });
+            }
+            Err(e) => {
+                spinner.fail();
+                log_warning(&format!("Skipped {}: {}", manifest, e));
+            }
+        }
+    }
+
+    let (items, rows) = checklist_items(&manifests, preselect);
+    if items.is_empty() {
+        log_success("All dependencies are up to date!");
+        return Ok(Vec::new());
+    }
+    let chosen = if is_json_output() {
+        (0..items.len()).filter(|i| items[*i].checked).collect()
+    } else {
+        checklist(
+            format!("📦 {} update(s) available:", items.len())
+                .cyan()
+                .bold(),
+            &items,
+        )?
+    };
+    if chosen.is_empty() {
+        log_info("No updates selected");
+        return Ok(Vec::new());
+    }
+
+    let mut results = Vec::new();
+    for (index, manifest) in manifests.iter().enumerate() {
+        let selected: Vec<&AvailableUpdate> = chosen
+            .iter()
+            .map(|row| rows[*row])
+            .filter(|(m, _)| *m == index)
+            .map(|(_, u)| &manifest.updates[u])
+            .collect();
+        if selected.is_empty() {
+            continue;
+        }
+        let dir = manifest_dir(root, &manifest.manifest);
+        let backup_dir = backup_for(&dir, &manifest.package_manager);
+        let mut result = apply_updates(&runner, &dir, manifest, &selected);
+        result.backup_dir = backup_dir;
+        if result.status == UpdateStatus::Updated {
+            log_success(&format!(
+                "Applied {} update(s) to {}",
+                selected.len(),
+                manifest.manifest
+            ));
+        }
+        results.push(result);
+    }
+    Ok(results)
+}
@@ -0,0 +1,232 @@
+use crate::commands::provenance::sha256_file;
+use crate::commands::releases::{
+    find_release, is_archive, make_executable, platform_aliases, resolve_repo, select_asset,
+    sort_releases, unpack, PublishedRelease, ReleaseAsset, ReleasesClient,
+};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use crate::utils::version_check::compare_versions;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+/// Repository whose GitHub releases ship the nitroterm binaries.
+pub const SELF_UPDATE_REPO: &str = "mustafagenc/nitroterm";
+
+/// Combined checksum files, used when there is no `<asset>.sha256`.
+const CHECKSUM_LISTS: [&str; 4] = [
+    "sha256sums",
+    "sha256sums.txt",
+    "checksums.txt",
+    "checksums.sha256",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct SelfUpdateConfig {
+    /// Only report whether an update is available
+    pub check: bool,
+    /// Install this tag instead of the latest release (allows downgrades)
+    pub version: Option<String>,
+}
+
+/// The checksum asset published for `asset_name`: `<asset>.sha256` first,
+/// then a combined list such as `SHA256SUMS`.
+pub fn checksum_asset<'a>(
+    assets: &'a [ReleaseAsset],
+    asset_name: &str,
+) -> Option<&'a ReleaseAsset> {
+    let own = format!("{}.sha256", asset_name).to_lowercase();
+    assets
+        .iter()
+        .find(|asset| asset.name.to_lowercase() == own)
+        .or_else(|| {
+            assets
+                .iter()
+                .find(|asset| CHECKSUM_LISTS.contains(&asset.name.to_lowercase().as_str()))
+        })
+}
+
+/// Reads the sha256 for `asset_name` from `sha256sum` output. A file with a
+/// single bare hash is taken as the checksum of the asset it belongs to.
+pub fn parse_checksum(content: &str, asset_name: &str) -> Option<String> {
+    let is_hash = |token: &str| token.len() == 64 && token.chars().all(|c| c.is_ascii_hexdigit());
+
+    for line in content.lines() {
+        let mut tokens = line.split_whitespace();
+        let Some(hash) = tokens.next().filter(|token| is_hash(token)) else {
+            continue;
+        };
+        match tokens.next() {
+            None => return Some(hash.to_lowercase()),
+            Some(file) => {
+                // `*` marks binary mode in sha256sum output
+                let file = file.trim_start_matches('*').trim_start_matches("./");
+                let file = file.rsplit('/').next().unwrap_or(file);
+                if file == asset_name {
+                    return Some(hash.to_lowercase());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Moves `new` over `target`. Both must be on the same filesystem so the
+/// rename is atomic; on Windows the running binary is moved aside first.
+pub fn replace_executable(new: &Path, target: &Path) -> Result<()> {
+    make_executable(new)?;
+
+    #[cfg(windows)]
+    {
+        let old = target.with_extension("old.exe");
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(target, &old)?;
+    }
+
+    std::fs::rename(new, target).map_err(|e| {
+        anyhow!(
+            "Cannot replace {}: {} (try again with permission to write there)",
+            target.display(),
+            e
+        )
+    })
+}
+
+/// The nitroterm executable inside an unpacked archive.
+fn find_binary(dir: &Path) -> Option<PathBuf> {
+    let wanted = format!("nitroterm{}", std::env::consts::EXE_SUFFIX);
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if let Some(found) = find_binary(&path) {
+                return Some(found);
+            }
+        } else if path.file_name().is_some_and(|name| name == wanted.as_str()) {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Downloads the asset for this platform from `release`, verifies it against
+/// the published sha256 and swaps it in for `target`.
+pub async fn install_release(
+    client: &ReleasesClient,
+    release: &PublishedRelease,
+    target: &Path,
+) -> Result<()> {
+    let (os, arch) = platform_aliases();
+    let asset = select_asset(&release.assets, &os, &arch).ok_or_else(|| {
+        anyhow!(
+            "{} has no binary for {}-{}",
+            release.tag_name,
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
+    })?;
+    let checksum = checksum_asset(&release.assets, &asset.name).ok_or_else(|| {
+        anyhow!(
+            "{} publishes no sha256 checksum for {}, refusing to install it",
+            release.tag_name,
+            asset.name
+        )
+    })?;
+
+    // Staged next to the executable so the final rename stays on one filesystem
+    let parent = target
+        .parent()
+        .ok_or_else(|| anyhow!("Cannot resolve the directory of {}", target.display()))?;
+    let staging = tempfile::Builder::new()
+        .prefix(".nitroterm-update")
+        .tempdir_in(parent)
+        .map_err(|e| anyhow!("Cannot write to {}: {}", parent.display(), e))?;
+
+    println!(
+        "{}",
+        format!(
+            "⬇️  Downloading {} ({} KB)...",
+            asset.name,
+            asset.size / 1024
+        )
+        .blue()
+    );
+    let asset_path = staging.path().join(&asset.name);
+    client.download(asset, &asset_path).await?;
+
+    let checksum_path = staging.path().join(&checksum.name);
+    client.download(checksum, &checksum_path).await?;
+    let expected = parse_checksum(&std::fs::read_to_string(&checksum_path)?, &asset.name)
+        .ok_or_else(|| anyhow!("{} has no entry for {}", checksum.name, asset.name))?;
+    let actual = sha256_file(&asset_path)?;
+    if actual != expected {
+        return Err(anyhow!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            asset.name,
+            expected,
+            actual
+        ));
+    }
+    println!("{}", format!("🔒 Checksum verified ({})", actual).green());
+
+    let binary = if is_archive(&asset.name) {
+        let unpacked = staging.path().join("unpacked");
+        std::fs::create_dir_all(&unpacked)?;
+        unpack(&asset_path, &unpacked)?;
+        find_binary(&unpacked)
+            .ok_or_else(|| anyhow!("{} does not contain a nitroterm binary", asset.name))?
+    } else {
+        asset_path
+    };
+
+    replace_executable(&binary, target)
+}
+
+pub async fn run_self_update(config: SelfUpdateConfig, current_version: &str) -> Result<()> {
+    ensure_not_sandboxed("self-update")?;
+    let (owner, name) = resolve_repo(Some(SELF_UPDATE_REPO))?;
+    let client = ReleasesClient::new()?;
+
+    println!("{}", "🔎 Checking GitHub releases...".cyan());
+    let releases = client.list_releases(&owner, &name).await?;
+    let release = match &config.version {
+        Some(version) => find_release(&releases, version)?.clone(),
+        None => sort_releases(releases)
+            .pop()
+            .ok_or_else(|| anyhow!("No published releases found for {}", SELF_UPDATE_REPO))?,
+    };
+
+    let ordering = compare_versions(current_version, &release.tag_name)
+        .map_err(|e| anyhow!("Cannot compare versions: {}", e))?;
+    if ordering == Ordering::Equal || (ordering == Ordering::Greater && config.version.is_none()) {
+        println!(
+            "{}",
+            format!("✅ You're using the latest version (v{})", current_version).green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} {} → {}",
+        "Current version:".dimmed(),
+        format!("v{}", current_version).yellow(),
+        release.tag_name.green().bold()
+    );
+    if config.check {
+        println!(
+            "{}",
+            "💡 Run 'nitroterm self-update' to install it".dimmed()
+        );
+        return Ok(());
+    }
+
+    let target = std::env::current_exe()?.canonicalize()?;
+    install_release(&client, &release, &target).await?;
+
+    println!(
+        "{}",
+        format!("🎉 Updated {} to {}", target.display(), release.tag_name)
+            .green()
+            .bold()
+    );
+    Ok(())
+}
@@ -0,0 +1,497 @@
+use crate::commands::code_quality::{
+    write_report, CheckResult, CodeQualityConfig, CodeQualityManager, ReportFormat,
+};
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::release_notes::categorize_commits;
+use crate::commands::report::{collect_report, ProjectReport, ReportOptions};
+use crate::commands::translation_sync::{get_sync_statistics, SyncStatistics};
+use crate::utils::file_system::path_within;
+use anyhow::{anyhow, Result};
+use colored::*;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+pub const TOKEN_ENV: &str = "NITROTERM_API_TOKEN";
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+const MAX_HEADER_BYTES: u64 = 16 * 1024;
+/// How long a client gets to send the whole request
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    pub host: String,
+    pub port: u16,
+    pub token: Option<String>,
+    pub root: PathBuf,
+}
+
+pub struct ServerState {
+    pub root: PathBuf,
+    pub token: String,
+    pub app_config: AppConfig,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    /// Header names are lowercased
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl HttpResponse {
+    pub fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message }),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let body = serde_json::to_string_pretty(&self.body).unwrap_or_default();
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            body.len(),
+            body
+        )
+        .into_bytes()
+    }
+}
+
+/// Random hex token for when none is configured.
+pub fn generate_token() -> Result<String> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| anyhow!("Could not generate an API token"))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn decode_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match (
+                bytes.get(i + 1).and_then(|&b| (b as char).to_digit(16)),
+                bytes.get(i + 2).and_then(|&b| (b as char).to_digit(16)),
+            ) {
+                (Some(high), Some(low)) => {
+                    decoded.push((high * 16 + low) as u8);
+                    i += 2;
+                }
+                _ => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Parses the request line and headers (everything before the blank line).
+pub fn parse_request_head(head: &str) -> Result<HttpRequest> {
+    let mut lines = head.lines();
+    let request_line = lines.next().ok_or_else(|| anyhow!("Empty request"))?;
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => (method, target),
+        _ => return Err(anyhow!("Malformed request line: {}", request_line)),
+    };
+
+    let (path, query_string) = target.split_once('?').unwrap_or((target, ""));
+    let query = query_string
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_component(key), decode_component(value))
+        })
+        .collect();
+
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    Ok(HttpRequest {
+        method: method.to_uppercase(),
+        path: path.to_string(),
+        query,
+        headers,
+        body: String::new(),
+    })
+}
+
+/// Checks the `Authorization: Bearer <token>` header.
+pub fn authorize(request: &HttpRequest, token: &str) -> bool {
+    let provided = request
+        .headers
+        .get("authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .unwrap_or("");
+
+    // Compare every byte so the time taken doesn't leak the matching prefix
+    provided.len() == token.len()
+        && provided
+            .bytes()
+            .zip(token.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+pub fn stats_json(report: &ProjectReport) -> Value {
+    json!({
+        "project": report.project_name,
+        "health": report.health.iter().map(|(label, value)| json!({ "label": label, "value": value })).collect::<Vec<_>>(),
+        "last_tag": report.last_tag,
+        "unreleased_commits": report.unreleased.len(),
+        "contributors": report.contributors.iter().map(|(email, name, commits)| {
+            json!({ "email": email, "name": name, "commits": commits })
+        }).collect::<Vec<_>>(),
+        "weekly_commits": report.weekly_commits.iter().map(|(week, count)| {
+            json!({ "week": week, "commits": count })
+        }).collect::<Vec<_>>(),
+        "dependencies": {
+            "locked": report.dependencies.len(),
+            "direct": report.direct_dependencies,
+        },
+        "release_risk": report.risk.as_ref().map(|risk| json!({
+            "score": risk.score,
+            "level": format!("{:?}", risk.level).to_lowercase(),
+        })),
+        "translations": report.translations.as_ref().map(translations_json),
+        "checks": report.checks.as_ref().map(|checks| json!({
+            "total": checks.len(),
+            "passed": checks.iter().filter(|c| c.success).count(),
+        })),
+    })
+}
+
+pub fn release_notes_json(report: &ProjectReport) -> Value {
+    let categorized = categorize_commits(&report.unreleased);
+    json!({
+        "since": report.last_tag,
+        "commits": report.unreleased.iter().map(|commit| json!({
+            "hash": commit.hash,
+            "author": commit.author_name,
+            "email": commit.author_email,
+            "timestamp": commit.timestamp,
+            "message": commit.message,
+        })).collect::<Vec<_>>(),
+        "categories": {
+            "breaking_changes": categorized.breaking_changes,
+            "security": categorized.security,
+            "features": categorized.features,
+            "fixes": categorized.fixes,
+            "improvements": categorized.improvements,
+            "performance": categorized.perf,
+            "refactor": categorized.refactor,
+            "docs": categorized.docs,
+            "styles": categorized.styles,
+            "tests": categorized.tests,
+            "dependencies": categorized.deps,
+            "translations": categorized.translations,
+            "chores": categorized.chores,
+            "other": categorized.others,
+        },
+    })
+}
+
+pub fn translations_json(stats: &SyncStatistics) -> Value {
+    json!({
+        "source_file": stats.source_file,
+        "total_keys": stats.total_keys,
+        "overall_percentage": stats.overall_percentage(),
+        "languages": stats.languages.iter().map(|language| json!({
+            "code": language.code,
+            "file": language.file,
+            "translated": language.progress.translated,
+            "missing": language.progress.missing(),
+            "percentage": language.progress.percentage(),
+            "missing_keys": language.progress.missing_keys,
+            "last_modified": language.last_modified.map(|time| time.to_rfc3339()),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// Results of the last `code-quality --format json` run, if there is one.
+pub fn read_cached_checks(root: &Path) -> Result<Option<Vec<CheckResult>>> {
+    let path = root.join(ReportFormat::Json.default_file_name());
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(_) => return Ok(None),
+    };
+    let report: Value = serde_json::from_str(&content)?;
+    Ok(Some(serde_json::from_value(report["checks"].clone())?))
+}
+
+pub fn checks_json(results: &[CheckResult]) -> Value {
+    json!({
+        "passed": results.iter().filter(|r| r.success).count(),
+        "failed": results.iter().filter(|r| !r.success).count(),
+        "checks": results,
+    })
+}
+
+async fn run_checks(state: &ServerState, body: &str) -> Result<Value> {
+    let mut config = CodeQualityConfig::default();
+    if !body.trim().is_empty() {
+        let request: Value = serde_json::from_str(body)?;
+        if let Some(checks) = request.get("checks").and_then(|c| c.as_array()) {
+            config.enabled_checks = checks
+                .iter()
+                .filter_map(|c| c.as_str().map(|s| s.to_string()))
+                .collect();
+        }
+    }
+
+    let results = CodeQualityManager::new(config)
+        .run_quality_checks(&state.root)
+        .await?;
+    // Cache for GET /api/checks and the HTML report
+    write_report(
+        ReportFormat::Json,
+        &results,
+        &state.root.join(ReportFormat::Json.default_file_name()),
+    )
+    .await?;
+    Ok(checks_json(&results))
+}
+
+/// Routes one request. Everything except `/health` needs the bearer token.
+pub async fn handle_request(request: &HttpRequest, state: &ServerState) -> HttpResponse {
+    if request.path == "/health" {
+        return HttpResponse::ok(json!({
+            "status": "ok",
+            "version": env!("CARGO_PKG_VERSION"),
+        }));
+    }
+    if !authorize(request, &state.token) {
+        return HttpResponse::error(401, "Missing or invalid bearer token");
+    }
+
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/api/stats") => {
+            collect_report(&state.root, &state.app_config, &ReportOptions::default())
+                .await
+                .map(|report| stats_json(&report))
+        }
+        ("GET", "/api/release-notes") => {
+            collect_report(&state.root, &state.app_config, &ReportOptions::default())
+                .await
+                .map(|report| release_notes_json(&report))
+        }
+        ("GET", "/api/translations") => {
+            let dir = request
+                .query
+                .get("dir")
+                .cloned()
+                .unwrap_or_else(|| state.app_config.messages_dir.clone());
+            let source = request
+                .query
+                .get("source")
+                .cloned()
+                .unwrap_or_else(|| state.app_config.source_file.clone());
+            // Only catalogs of the served project
+            let dir = match path_within(&state.root, &dir)
+                .and_then(|dir| path_within(&dir, &source).map(|_| dir))
+            {
+                Ok(dir) => dir,
+                Err(e) => return HttpResponse::error(400, &e.to_string()),
+            };
+            get_sync_statistics(&dir, &source).map(|stats| translations_json(&stats))
+        }
+        ("GET", "/api/checks") => match read_cached_checks(&state.root) {
+            Ok(Some(results)) => Ok(checks_json(&results)),
+            Ok(None) => {
+                return HttpResponse::error(404, "No cached results, POST /api/checks first")
+            }
+            Err(e) => Err(e),
+        },
+        ("POST", "/api/checks") => run_checks(state, &request.body).await,
+        (_, "/api/stats" | "/api/release-notes" | "/api/translations" | "/api/checks") => {
+            return HttpResponse::error(405, "Method not allowed")
+        }
+        _ => return HttpResponse::error(404, "Unknown endpoint"),
+    };
+
+    match result {
+        Ok(body) => HttpResponse::ok(body),
+        Err(e) => HttpResponse::error(500, &e.to_string()),
+    }
+}
+
+/// A request that couldn't be read, answered with `status()`.
+#[derive(Debug, thiserror::Error)]
+pub enum RequestError {
+    #[error("Request headers too large")]
+    HeadersTooLarge,
+    #[error("Request body too large")]
+    BodyTooLarge,
+    #[error("Request timed out")]
+    TimedOut,
+    #[error(transparent)]
+    Malformed(#[from] anyhow::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+impl RequestError {
+    pub fn status(&self) -> u16 {
+        match self {
+            RequestError::HeadersTooLarge | RequestError::BodyTooLarge => 413,
+            RequestError::TimedOut => 408,
+            RequestError::Malformed(_) | RequestError::Io(_) => 400,
+        }
+    }
+}
+
+/// Reads one request, giving up after `timeout` so slow clients can't hold
+/// a connection open.
+pub async fn read_request<S: AsyncRead + Unpin>(
+    stream: &mut S,
+    timeout: Duration,
+) -> Result<HttpRequest, RequestError> {
+    tokio::time::timeout(timeout, read_request_unbounded(stream))
+        .await
+        .map_err(|_| RequestError::TimedOut)?
+}
+
+async fn read_request_unbounded<S: AsyncRead + Unpin>(
+    stream: &mut S,
+) -> Result<HttpRequest, RequestError> {
+    let mut reader = BufReader::new(stream);
+    let mut head = String::new();
+    let mut limited = (&mut reader).take(MAX_HEADER_BYTES);
+    loop {
+        let mut line = String::new();
+        if limited.read_line(&mut line).await? == 0 {
+            // Out of budget before the blank line that ends the head
+            if limited.limit() == 0 {
+                return Err(RequestError::HeadersTooLarge);
+            }
+            break;
+        }
+        if line == "\r\n" || line == "\n" {
+            break;
+        }
+        head.push_str(&line);
+    }
+
+    let mut request = parse_request_head(&head)?;
+    let length: usize = request
+        .headers
+        .get("content-length")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0);
+    if length > MAX_BODY_BYTES {
+        return Err(RequestError::BodyTooLarge);
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    request.body = String::from_utf8_lossy(&body).to_string();
+    Ok(request)
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<ServerState>) {
+    let response = match read_request(&mut stream, REQUEST_TIMEOUT).await {
+        Ok(request) => {
+            let response = handle_request(&request, &state).await;
+            println!(
+                "{}",
+                format!("{} {} → {}", request.method, request.path, response.status).dimmed()
+            );
+            response
+        }
+        Err(e) => HttpResponse::error(e.status(), &e.to_string()),
+    };
+    let _ = stream.write_all(&response.to_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+pub async fn serve(options: ServeOptions) -> Result<()> {
+    let token = options
+        .token
+        .or_else(|| std::env::var(TOKEN_ENV).ok().filter(|t| !t.is_empty()));
+    let generated = token.is_none();
+    let token = match token {
+        Some(token) => token,
+        None => generate_token()?,
+    };
+
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let state = Arc::new(ServerState {
+        root: options.root.canonicalize()?,
+        token: token.clone(),
+        app_config,
+    });
+
+    let address = format!("{}:{}", options.host, options.port);
+    let listener = TcpListener::bind(&address).await?;
+    println!(
+        "{}",
+        format!("🌐 nitroterm API listening on http://{}", address)
+            .cyan()
+            .bold()
+    );
+    println!(
+        "{}",
+        format!("📁 Project: {}", state.root.display()).dimmed()
+    );
+    if generated {
+        println!(
+            "{}",
+            format!("🔑 Token (set {} to keep it fixed): {}", TOKEN_ENV, token).yellow()
+        );
+    }
+    println!(
+        "{}",
+        "   GET /health, /api/stats, /api/release-notes, /api/translations, /api/checks; POST /api/checks"
+            .dimmed()
+    );
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(handle_connection(stream, state.clone()));
+    }
+}
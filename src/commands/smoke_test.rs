@@ -0,0 +1,198 @@
+use crate::utils::error::ChecksFailed;
+use crate::utils::process::{supervise_async, SupervisedCommand};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use serde::Deserialize;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+pub const SMOKE_TEST_FILE: &str = ".nitrokit/smoke-tests.json";
+
+/// Smoke test definitions for release artifacts, read from
+/// `.nitrokit/smoke-tests.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmokeTestConfig {
+    /// Command that builds the artifacts, run in the project root first
+    #[serde(default)]
+    pub build: Option<String>,
+    pub tests: Vec<SmokeTest>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmokeTest {
+    pub name: String,
+    /// Shell command; `{root}` is replaced with the project root
+    pub command: String,
+    /// Regex patterns that must all match stdout/stderr
+    #[serde(default)]
+    pub expect: Vec<String>,
+    #[serde(default)]
+    pub exit_code: i32,
+    #[serde(default = "default_timeout")]
+    pub timeout_seconds: u64,
+}
+
+fn default_timeout() -> u64 {
+    60
+}
+
+#[derive(Debug, Clone)]
+pub struct SmokeTestResult {
+    pub name: String,
+    pub failures: Vec<String>,
+    pub output: String,
+}
+
+impl SmokeTestResult {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+pub fn load_smoke_tests(root: &Path) -> Result<Option<SmokeTestConfig>> {
+    let path = root.join(SMOKE_TEST_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let config: SmokeTestConfig = serde_json::from_str(&content)
+        .map_err(|e| anyhow!("Invalid {}: {}", SMOKE_TEST_FILE, e))?;
+    Ok(Some(config))
+}
+
+/// Compares a finished run with the test's expectations.
+pub fn check_output(test: &SmokeTest, exit_code: Option<i32>, output: &str) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    match exit_code {
+        Some(code) if code == test.exit_code => {}
+        Some(code) => failures.push(format!("exit code {} (expected {})", code, test.exit_code)),
+        None => failures.push("terminated by a signal".to_string()),
+    }
+
+    for pattern in &test.expect {
+        match Regex::new(pattern) {
+            Ok(regex) if regex.is_match(output) => {}
+            Ok(_) => failures.push(format!("output does not match /{}/", pattern)),
+            Err(e) => failures.push(format!("invalid pattern /{}/: {}", pattern, e)),
+        }
+    }
+
+    failures
+}
+
+/// Runs `command` through the platform shell.
+pub fn shell_command(command: &str) -> tokio::process::Command {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command);
+    cmd
+}
+
+/// Runs one smoke test in a fresh temp dir, so artifacts can't depend on
+/// files lying around in the project.
+pub async fn run_smoke_test(test: &SmokeTest, root: &Path) -> Result<SmokeTestResult> {
+    let work_dir = tempfile::tempdir()?;
+    // `{root}` has to stay valid after we move into the temp dir
+    let root = root.canonicalize()?;
+    let command = test.command.replace("{root}", &root.display().to_string());
+
+    let mut cmd = shell_command(&command);
+    cmd.current_dir(work_dir.path());
+
+    let output = match supervise_async(&mut cmd, Duration::from_secs(test.timeout_seconds)).await {
+        Ok(output) => output,
+        Err(e) if e.kind() == ErrorKind::TimedOut => {
+            return Ok(SmokeTestResult {
+                name: test.name.clone(),
+                failures: vec![format!("timed out after {}s", test.timeout_seconds)],
+                output: String::new(),
+            })
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    Ok(SmokeTestResult {
+        name: test.name.clone(),
+        failures: check_output(test, output.status.code(), &combined),
+        output: combined,
+    })
+}
+
+/// Builds and smoke-tests the release artifacts. Fails if any test fails,
+/// which blocks the release.
+pub async fn run_smoke_tests(root: &Path) -> Result<()> {
+    ensure_not_sandboxed("Smoke tests")?;
+    let config = match load_smoke_tests(root)? {
+        Some(config) => config,
+        None => {
+            println!(
+                "{}",
+                format!("ℹ️  No {} found, skipping smoke tests", SMOKE_TEST_FILE).dimmed()
+            );
+            return Ok(());
+        }
+    };
+
+    println!("{}", "🧪 Running smoke tests...".cyan().bold());
+
+    if let Some(build) = &config.build {
+        println!("{}", format!("🔨 Building artifacts: {}", build).dimmed());
+        let status = shell_command(build)
+            .current_dir(root)
+            .as_std_mut()
+            .supervised_status()?;
+        if !status.success() {
+            return Err(anyhow!("Artifact build failed: {}", build));
+        }
+    }
+
+    let mut failed = 0;
+    for test in &config.tests {
+        let result = run_smoke_test(test, root).await?;
+        if result.passed() {
+            println!("  {} {}", "✅".green(), result.name);
+        } else {
+            failed += 1;
+            println!("  {} {}", "❌".red(), result.name.red());
+            for failure in &result.failures {
+                println!("     {}", failure.yellow());
+            }
+            for line in result.output.lines().take(10) {
+                println!("     {}", line.dimmed());
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(ChecksFailed(format!(
+            "{} of {} smoke tests failed",
+            failed,
+            config.tests.len()
+        ))
+        .into());
+    }
+
+    println!(
+        "{}",
+        format!("✅ All {} smoke tests passed", config.tests.len()).green()
+    );
+    Ok(())
+}
@@ -0,0 +1,384 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::lockfiles::{native_outdated_report, OutdatedPackage};
+use crate::commands::translation_sync::{get_sync_statistics, SyncStatistics};
+use crate::utils::log_warning;
+use crate::utils::sandbox::is_sandboxed;
+use anyhow::{anyhow, Result};
+use chrono::{TimeZone, Utc};
+use colored::*;
+use git2::Repository;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const STATE_FILE: &str = ".nitrokit/state.json";
+
+/// New commit subjects listed per section; the count is always complete.
+const MAX_LISTED: usize = 10;
+
+/// Commands whose last run is remembered per repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tracked {
+    ReleaseNotes,
+    Dependencies,
+    Translations,
+}
+
+impl Tracked {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Tracked::ReleaseNotes => "release_notes",
+            Tracked::Dependencies => "dependencies",
+            Tracked::Translations => "translations",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Tracked::ReleaseNotes => "📋 Release notes",
+            Tracked::Dependencies => "📦 Dependencies",
+            Tracked::Translations => "🌍 Translations",
+        }
+    }
+
+    pub fn all() -> [Tracked; 3] {
+        [
+            Tracked::ReleaseNotes,
+            Tracked::Dependencies,
+            Tracked::Translations,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Unix timestamp of the run
+    pub at: i64,
+    /// HEAD at the time of the run
+    pub commit: Option<String>,
+    pub tag: Option<String>,
+    /// Outdated packages found, as `ecosystem:name@latest`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outdated: Vec<String>,
+    /// Keys still missing afterwards, as `language:key`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untranslated: Vec<String>,
+}
+
+/// Last run of each tracked command, kept in `.nitrokit/state.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RepoState {
+    #[serde(skip)]
+    path: PathBuf,
+    runs: BTreeMap<String, RunRecord>,
+}
+
+impl RepoState {
+    pub fn load(root: &Path) -> Self {
+        let path = root.join(STATE_FILE);
+        let mut state = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<RepoState>(&content).ok())
+            .unwrap_or_default();
+        state.path = path;
+        state
+    }
+
+    pub fn get(&self, tracked: Tracked) -> Option<&RunRecord> {
+        self.runs.get(tracked.key())
+    }
+
+    pub fn set(&mut self, tracked: Tracked, record: RunRecord) {
+        self.runs.insert(tracked.key().to_string(), record);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self)?)?;
+        Ok(())
+    }
+}
+
+pub fn outdated_keys(packages: &[OutdatedPackage]) -> Vec<String> {
+    packages
+        .iter()
+        .map(|package| {
+            format!(
+                "{}:{}@{}",
+                package.ecosystem.name(),
+                package.name,
+                package.latest
+            )
+        })
+        .collect()
+}
+
+pub fn untranslated_keys(statistics: &SyncStatistics) -> Vec<String> {
+    statistics
+        .languages
+        .iter()
+        .flat_map(|language| {
+            language
+                .progress
+                .missing_keys
+                .iter()
+                .map(move |key| format!("{}:{}", language.code, key))
+        })
+        .collect()
+}
+
+/// Records a finished run with the current HEAD. Failures only warn, state
+/// tracking never fails the command itself. Nothing is written in --sandbox
+/// mode.
+pub fn record_run(root: &Path, tracked: Tracked, fill: impl FnOnce(&mut RunRecord)) {
+    if is_sandboxed() {
+        return;
+    }
+    let mut record = RunRecord {
+        at: Utc::now().timestamp(),
+        commit: Repository::discover(root)
+            .ok()
+            .and_then(|repo| repo.head().ok()?.target())
+            .map(|oid| oid.to_string()),
+        ..RunRecord::default()
+    };
+    fill(&mut record);
+
+    let mut state = RepoState::load(root);
+    state.set(tracked, record);
+    if let Err(e) = state.save() {
+        log_warning(&format!("Could not update {}: {}", STATE_FILE, e));
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusSection {
+    pub key: &'static str,
+    pub last_run: Option<i64>,
+    pub last_commit: Option<String>,
+    pub last_tag: Option<String>,
+    /// Commits on HEAD since the last run
+    pub new_commits: usize,
+    pub commit_subjects: Vec<String>,
+    /// Whether outdated packages or untranslated keys were looked up
+    pub checked: bool,
+    /// Outdated packages or untranslated keys that weren't there last time
+    pub new_items: Vec<String>,
+    /// Why new items couldn't be checked
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusReport {
+    pub head: Option<String>,
+    pub sections: Vec<StatusSection>,
+}
+
+/// Findings right now, compared against the recorded runs. `None` means
+/// "not checked", `Err` is shown on the section.
+#[derive(Debug, Default)]
+pub struct CurrentFindings {
+    pub outdated: Option<Result<Vec<String>>>,
+    pub untranslated: Option<Result<Vec<String>>>,
+}
+
+fn commits_since(repo: &Repository, commit: Option<&str>) -> Result<Vec<String>> {
+    let mut revwalk = repo.revwalk()?;
+    // Nothing committed yet
+    if revwalk.push_head().is_err() {
+        return Ok(Vec::new());
+    }
+    if let Some(commit) = commit {
+        let oid = git2::Oid::from_str(commit)?;
+        // A rewritten history may no longer contain the recorded commit
+        if repo.find_commit(oid).is_ok() {
+            revwalk.hide(oid)?;
+        }
+    }
+
+    let mut subjects = Vec::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        subjects.push(commit.summary().unwrap_or_default().to_string());
+    }
+    Ok(subjects)
+}
+
+fn new_since(current: &[String], previous: &[String]) -> Vec<String> {
+    let previous: BTreeSet<&String> = previous.iter().collect();
+    current
+        .iter()
+        .filter(|item| !previous.contains(item))
+        .cloned()
+        .collect()
+}
+
+pub fn build_status(
+    repo: &Repository,
+    state: &RepoState,
+    mut findings: CurrentFindings,
+) -> Result<StatusReport> {
+    let head = repo
+        .head()
+        .ok()
+        .and_then(|head| head.target())
+        .map(|oid| oid.to_string());
+
+    let mut sections = Vec::new();
+    for tracked in Tracked::all() {
+        let record = state.get(tracked);
+        let subjects = commits_since(repo, record.and_then(|r| r.commit.as_deref()))?;
+
+        let (current, previous) = match tracked {
+            Tracked::ReleaseNotes => (None, Vec::new()),
+            Tracked::Dependencies => (
+                findings.outdated.take(),
+                record.map(|r| r.outdated.clone()).unwrap_or_default(),
+            ),
+            Tracked::Translations => (
+                findings.untranslated.take(),
+                record.map(|r| r.untranslated.clone()).unwrap_or_default(),
+            ),
+        };
+        let checked = current.is_some();
+        let (new_items, error) = match current {
+            Some(Ok(current)) => (new_since(&current, &previous), None),
+            Some(Err(e)) => (Vec::new(), Some(e.to_string())),
+            None => (Vec::new(), None),
+        };
+
+        sections.push(StatusSection {
+            key: tracked.key(),
+            last_run: record.map(|r| r.at),
+            last_commit: record.and_then(|r| r.commit.clone()),
+            last_tag: record.and_then(|r| r.tag.clone()),
+            new_commits: subjects.len(),
+            commit_subjects: subjects.into_iter().take(MAX_LISTED).collect(),
+            checked,
+            new_items,
+            error,
+        });
+    }
+
+    Ok(StatusReport { head, sections })
+}
+
+fn format_ago(timestamp: i64) -> String {
+    let seconds = (Utc::now().timestamp() - timestamp).max(0);
+    let ago = match seconds {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", seconds / 60),
+        3600..=86399 => format!("{} h ago", seconds / 3600),
+        _ => format!("{} days ago", seconds / 86400),
+    };
+    let date = Utc
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    format!("{} ({})", ago, date)
+}
+
+fn print_status(report: &StatusReport) {
+    println!("\n{}", "📊 Since your last run".cyan().bold());
+    println!("{}", "─".repeat(50).dimmed());
+
+    for (tracked, section) in Tracked::all().iter().zip(&report.sections) {
+        println!("\n{}", tracked.title().bold());
+        match section.last_run {
+            Some(at) => {
+                let mut last = format!("Last run {}", format_ago(at));
+                if let Some(tag) = &section.last_tag {
+                    last.push_str(&format!(" at {}", tag));
+                }
+                println!("  {}", last.dimmed());
+            }
+            None => println!("  {}", "Never run in this repository".yellow()),
+        }
+
+        if section.new_commits == 0 {
+            println!("  {}", "✅ No new commits".green());
+        } else {
+            println!(
+                "  {}",
+                format!("🔀 {} new commit(s)", section.new_commits).yellow()
+            );
+            for subject in &section.commit_subjects {
+                println!("     {} {}", "•".dimmed(), subject);
+            }
+            if section.new_commits > section.commit_subjects.len() {
+                println!(
+                    "     {}",
+                    format!(
+                        "… and {} more",
+                        section.new_commits - section.commit_subjects.len()
+                    )
+                    .dimmed()
+                );
+            }
+        }
+
+        let label = match tracked {
+            Tracked::ReleaseNotes => continue,
+            Tracked::Dependencies => "newly outdated package(s)",
+            Tracked::Translations => "new untranslated key(s)",
+        };
+        if !section.checked {
+            println!("  {}", "⏭️  Not checked (--offline)".dimmed());
+        } else if let Some(error) = &section.error {
+            println!("  {}", format!("⚠️  Not checked: {}", error).yellow());
+        } else if section.new_items.is_empty() {
+            println!("  {}", format!("✅ No {}", label).green());
+        } else {
+            println!(
+                "  {}",
+                format!("🆕 {} {}", section.new_items.len(), label).yellow()
+            );
+            for item in section.new_items.iter().take(MAX_LISTED) {
+                println!("     {} {}", "•".dimmed(), item);
+            }
+        }
+    }
+}
+
+/// Dashboard of what changed since release notes, dependency audits and
+/// translation syncs last ran here. `offline` skips the registry lookups.
+pub async fn run_status(path: &Path, offline: bool, json: bool) -> Result<()> {
+    let repo = Repository::discover(path).map_err(|e| anyhow!("Not a git repository: {}", e))?;
+    let root = repo
+        .workdir()
+        .ok_or_else(|| anyhow!("Bare repositories are not supported"))?
+        .to_path_buf();
+
+    let config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let outdated = if offline {
+        None
+    } else {
+        Some(
+            native_outdated_report(&root)
+                .await
+                .map(|packages| outdated_keys(&packages)),
+        )
+    };
+    let findings = CurrentFindings {
+        outdated,
+        untranslated: Some(
+            get_sync_statistics(&root.join(&config.messages_dir), &config.source_file)
+                .map(|statistics| untranslated_keys(&statistics)),
+        ),
+    };
+
+    let report = build_status(&repo, &RepoState::load(&root), findings)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_status(&report);
+    }
+    Ok(())
+}
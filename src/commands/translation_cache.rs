@@ -0,0 +1,106 @@
+use crate::commands::translation_glossary::Glossary;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CACHE_FILE: &str = ".nitrokit/translation-cache.json";
+
+/// FNV-1a: stable across Rust versions and runs, unlike DefaultHasher.
+pub fn stable_hash(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// The scope of translations made by `provider` with `model` under
+/// `glossary`.
+pub fn cache_scope(provider: &str, model: &str, glossary: &Glossary) -> String {
+    format!(
+        "{}:{}:{:016x}",
+        provider.to_lowercase(),
+        model,
+        glossary.fingerprint()
+    )
+}
+
+/// Translation memory keyed by target language and a hash of the source
+/// text, so the same string is never sent to the API twice. Entries are
+/// scoped to what made them (provider, model, glossary), a change there
+/// translates again.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TranslationCache {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(skip)]
+    dirty: bool,
+    entries: HashMap<String, String>,
+}
+
+impl TranslationCache {
+    pub fn load(path: &Path) -> Self {
+        let mut cache = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<TranslationCache>(&content).ok())
+            .unwrap_or_default();
+        cache.path = path.to_path_buf();
+        cache
+    }
+
+    pub fn cache_key(language: &str, source_text: &str) -> String {
+        format!("{}:{:016x}", language, stable_hash(source_text))
+    }
+
+    fn entry_key(scope: &str, language: &str, source_text: &str) -> String {
+        format!("{}/{}", scope, Self::cache_key(language, source_text))
+    }
+
+    pub fn get(&self, scope: &str, language: &str, source_text: &str) -> Option<&String> {
+        self.entries
+            .get(&Self::entry_key(scope, language, source_text))
+    }
+
+    pub fn insert(&mut self, scope: &str, language: &str, source_text: &str, translation: &str) {
+        self.entries.insert(
+            Self::entry_key(scope, language, source_text),
+            translation.to_string(),
+        );
+        self.dirty = true;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self)?)?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// Deletes the cache file. Returns the number of entries that were removed.
+pub fn clear_cache(path: &Path) -> Result<usize> {
+    if !path.exists() {
+        return Ok(0);
+    }
+
+    let count = TranslationCache::load(path).len();
+    fs::remove_file(path)?;
+    Ok(count)
+}
@@ -0,0 +1,555 @@
+use crate::commands::translation_cache::TranslationCache;
+use crate::utils::sandbox::ensure_write_allowed;
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TranslationFormat {
+    Json,
+    Yaml,
+    Po,
+}
+
+impl TranslationFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "json" => Some(TranslationFormat::Json),
+            "yml" | "yaml" => Some(TranslationFormat::Yaml),
+            "po" | "pot" => Some(TranslationFormat::Po),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TranslationFormat::Json => "JSON",
+            TranslationFormat::Yaml => "YAML",
+            TranslationFormat::Po => "gettext",
+        }
+    }
+}
+
+/// One gettext catalog entry. Plural entries keep one `msgstr` per form.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PoEntry {
+    pub comments: Vec<String>,
+    pub context: Option<String>,
+    pub msgid: String,
+    pub msgid_plural: Option<String>,
+    pub msgstr: Vec<String>,
+}
+
+impl PoEntry {
+    pub fn is_header(&self) -> bool {
+        self.msgid.is_empty() && self.context.is_none()
+    }
+
+    /// msgids are whole sentences full of dots, so they can't be used as
+    /// translation paths directly. Hash context + msgid instead.
+    pub fn key(&self) -> String {
+        let id = match &self.context {
+            Some(context) => format!("{}\u{4}{}", context, self.msgid),
+            None => self.msgid.clone(),
+        };
+        TranslationCache::cache_key("po", &id)
+    }
+
+    fn translation(&self) -> &str {
+        self.msgstr.first().map(|s| s.as_str()).unwrap_or("")
+    }
+}
+
+fn unquote(text: &str) -> Result<String> {
+    let text = text.trim();
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .ok_or_else(|| anyhow!("Expected a quoted string: {}", text))?;
+
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    Ok(result)
+}
+
+fn quote(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+enum PoField {
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+pub fn parse_po(content: &str) -> Result<Vec<PoEntry>> {
+    let mut entries = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut has_msgid = false;
+    let mut field = None;
+
+    let mut flush = |entry: &mut PoEntry, has_msgid: &mut bool| {
+        if *has_msgid {
+            entries.push(std::mem::take(entry));
+        }
+        *has_msgid = false;
+    };
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        let error = |e: anyhow::Error| anyhow!("line {}: {}", number + 1, e);
+
+        if line.is_empty() {
+            flush(&mut entry, &mut has_msgid);
+            field = None;
+        } else if line.starts_with("#~") {
+            // Obsolete entries are dropped on the next write anyway
+            continue;
+        } else if line.starts_with('#') {
+            flush(&mut entry, &mut has_msgid);
+            entry.comments.push(line.to_string());
+        } else if let Some(rest) = line.strip_prefix("msgctxt ") {
+            flush(&mut entry, &mut has_msgid);
+            entry.context = Some(unquote(rest).map_err(error)?);
+            field = Some(PoField::Context);
+        } else if let Some(rest) = line.strip_prefix("msgid_plural ") {
+            entry.msgid_plural = Some(unquote(rest).map_err(error)?);
+            field = Some(PoField::IdPlural);
+        } else if let Some(rest) = line.strip_prefix("msgid ") {
+            flush(&mut entry, &mut has_msgid);
+            entry.msgid = unquote(rest).map_err(error)?;
+            has_msgid = true;
+            field = Some(PoField::Id);
+        } else if let Some(rest) = line.strip_prefix("msgstr[") {
+            let (index, value) = rest
+                .split_once(']')
+                .ok_or_else(|| anyhow!("line {}: malformed msgstr[n]", number + 1))?;
+            let index: usize = index
+                .parse()
+                .map_err(|_| anyhow!("line {}: malformed msgstr[n]", number + 1))?;
+            if entry.msgstr.len() <= index {
+                entry.msgstr.resize(index + 1, String::new());
+            }
+            entry.msgstr[index] = unquote(value).map_err(error)?;
+            field = Some(PoField::Str(index));
+        } else if let Some(rest) = line.strip_prefix("msgstr ") {
+            entry.msgstr = vec![unquote(rest).map_err(error)?];
+            field = Some(PoField::Str(0));
+        } else if line.starts_with('"') {
+            let text = unquote(line).map_err(error)?;
+            match field {
+                Some(PoField::Context) => entry
+                    .context
+                    .get_or_insert_with(String::new)
+                    .push_str(&text),
+                Some(PoField::Id) => entry.msgid.push_str(&text),
+                Some(PoField::IdPlural) => entry
+                    .msgid_plural
+                    .get_or_insert_with(String::new)
+                    .push_str(&text),
+                Some(PoField::Str(index)) => entry.msgstr[index].push_str(&text),
+                None => return Err(anyhow!("line {}: unexpected string", number + 1)),
+            }
+        } else {
+            return Err(anyhow!("line {}: unexpected content: {}", number + 1, line));
+        }
+    }
+    flush(&mut entry, &mut has_msgid);
+
+    Ok(entries)
+}
+
+fn render_field(out: &mut String, keyword: &str, value: &str) {
+    // Multi-line strings use the usual `msgid ""` + one line per segment layout
+    if value.trim_end_matches('\n').contains('\n') {
+        out.push_str(&format!("{} \"\"\n", keyword));
+        for segment in value.split_inclusive('\n') {
+            out.push_str(&quote(segment));
+            out.push('\n');
+        }
+    } else {
+        out.push_str(&format!("{} {}\n", keyword, quote(value)));
+    }
+}
+
+pub fn render_po(entries: &[PoEntry]) -> String {
+    let mut blocks = Vec::new();
+
+    for entry in entries {
+        let mut block = String::new();
+        for comment in &entry.comments {
+            block.push_str(comment);
+            block.push('\n');
+        }
+        if let Some(context) = &entry.context {
+            render_field(&mut block, "msgctxt", context);
+        }
+        render_field(&mut block, "msgid", &entry.msgid);
+        match &entry.msgid_plural {
+            Some(plural) => {
+                render_field(&mut block, "msgid_plural", plural);
+                for (index, value) in entry.msgstr.iter().enumerate() {
+                    render_field(&mut block, &format!("msgstr[{}]", index), value);
+                }
+            }
+            None => render_field(&mut block, "msgstr", entry.translation()),
+        }
+        blocks.push(block);
+    }
+
+    blocks.join("\n")
+}
+
+fn default_po_header(locale: &str) -> PoEntry {
+    PoEntry {
+        msgstr: vec![format!(
+            "Language: {}\nMIME-Version: 1.0\nContent-Type: text/plain; charset=UTF-8\nContent-Transfer-Encoding: 8bit\n",
+            locale
+        )],
+        ..Default::default()
+    }
+}
+
+/// Flat `key -> text` view of a catalog. Plural entries are left out: they
+/// are carried over as they are, not machine translated.
+fn po_messages(entries: &[PoEntry], is_source: bool) -> Value {
+    let mut messages = Map::new();
+
+    for entry in entries {
+        if entry.is_header() || entry.msgid_plural.is_some() {
+            continue;
+        }
+        let text = match entry.translation() {
+            // Templates (.pot) and untranslated source catalogs only have msgids
+            "" if is_source => entry.msgid.as_str(),
+            "" => continue,
+            text => text,
+        };
+        messages.insert(entry.key(), Value::String(text.to_string()));
+    }
+
+    Value::Object(messages)
+}
+
+fn looks_like_locale(key: &str) -> bool {
+    let mut parts = key.splitn(2, ['-', '_']);
+    let language = parts.next().unwrap_or("");
+    let region = parts.next().unwrap_or("xx");
+
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && (2..=4).contains(&region.len())
+        && region.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Rails keeps every YAML catalog under a locale key (`en:`, `tr:`).
+/// Returns the inner messages and the root key, if there was one.
+pub fn strip_locale_root(value: Value) -> (Value, Option<String>) {
+    if let Value::Object(map) = &value {
+        if map.len() == 1 {
+            let (key, inner) = map.iter().next().unwrap();
+            if inner.is_object() && looks_like_locale(key) {
+                return (inner.clone(), Some(key.clone()));
+            }
+        }
+    }
+    (value, None)
+}
+
+/// How a JSON catalog is laid out, so target files are written the way
+/// the source file is and diffs only show the keys that changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonStyle {
+    /// One level of indentation, e.g. two spaces, four spaces or a tab
+    pub indent: String,
+    pub trailing_newline: bool,
+}
+
+impl Default for JsonStyle {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            trailing_newline: false,
+        }
+    }
+}
+
+impl JsonStyle {
+    /// Takes the indentation from the first indented line. Files on a
+    /// single line get the default.
+    pub fn detect(content: &str) -> Self {
+        let indent = content.lines().skip(1).find_map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            (!indent.is_empty() && !trimmed.is_empty()).then(|| indent.to_string())
+        });
+        Self {
+            indent: indent.unwrap_or_else(|| Self::default().indent),
+            trailing_newline: content.ends_with('\n'),
+        }
+    }
+}
+
+pub fn render_json(value: &OrderedValue, style: &JsonStyle) -> Result<String> {
+    let mut output = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(style.indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut output, formatter);
+    value.serialize(&mut serializer)?;
+    let mut content = String::from_utf8(output)?;
+    if style.trailing_newline {
+        content.push('\n');
+    }
+    Ok(content)
+}
+
+/// A catalog with its keys in file order. `Value` sorts object keys, so
+/// this is what target files are written from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OrderedValue {
+    Object(IndexMap<String, OrderedValue>),
+    Array(Vec<OrderedValue>),
+    Scalar(Value),
+}
+
+impl From<&Value> for OrderedValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Object(map) => Self::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), Self::from(value)))
+                    .collect(),
+            ),
+            Value::Array(items) => Self::Array(items.iter().map(Self::from).collect()),
+            other => Self::Scalar(other.clone()),
+        }
+    }
+}
+
+/// `target` with its keys in the order of `source`, at every level. Keys
+/// the source doesn't have (anymore) follow, sorted.
+pub fn ordered_like(target: &Value, source: &OrderedValue) -> OrderedValue {
+    match (target, source) {
+        (Value::Object(target_map), OrderedValue::Object(source_map)) => {
+            let mut ordered = IndexMap::new();
+            for (key, source_value) in source_map {
+                if let Some(value) = target_map.get(key) {
+                    ordered.insert(key.clone(), ordered_like(value, source_value));
+                }
+            }
+            for (key, value) in target_map {
+                if !ordered.contains_key(key) {
+                    ordered.insert(key.clone(), OrderedValue::from(value));
+                }
+            }
+            OrderedValue::Object(ordered)
+        }
+        (Value::Array(items), OrderedValue::Array(source_items)) => OrderedValue::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| match source_items.get(index) {
+                    Some(source_item) => ordered_like(item, source_item),
+                    None => OrderedValue::from(item),
+                })
+                .collect(),
+        ),
+        _ => OrderedValue::from(target),
+    }
+}
+
+/// The source keys in file order, without the Rails locale root.
+fn source_order(source: &SourceMessages, content: &str) -> OrderedValue {
+    let parsed = match source.format {
+        TranslationFormat::Json => serde_json::from_str(content).ok(),
+        TranslationFormat::Yaml => serde_yaml::from_str(content).ok(),
+        // gettext catalogs are written in entry order
+        TranslationFormat::Po => None,
+    };
+    match parsed {
+        Some(OrderedValue::Object(root)) if source.locale_root => root
+            .into_values()
+            .next()
+            .unwrap_or(OrderedValue::Object(IndexMap::new())),
+        Some(order) => order,
+        None => OrderedValue::from(&source.messages),
+    }
+}
+
+/// The source catalog plus what is needed to write target files in the
+/// same layout.
+#[derive(Debug, Clone)]
+pub struct SourceMessages {
+    pub format: TranslationFormat,
+    pub messages: Value,
+    order: OrderedValue,
+    extension: String,
+    locale_root: bool,
+    po_entries: Vec<PoEntry>,
+    json_style: JsonStyle,
+}
+
+impl SourceMessages {
+    pub fn load(path: &Path) -> Result<Self> {
+        let format = TranslationFormat::from_path(path).ok_or_else(|| {
+            anyhow!(
+                "Unsupported source file: {} (use .json, .yml/.yaml or .po/.pot)",
+                path.display()
+            )
+        })?;
+        let content = fs::read_to_string(path)?;
+        let extension = match path.extension().and_then(|e| e.to_str()) {
+            // Translations of a template are .po files
+            Some("pot") | None => "po".to_string(),
+            Some(extension) => extension.to_lowercase(),
+        };
+
+        let mut source = Self {
+            format,
+            messages: Value::Object(Map::new()),
+            order: OrderedValue::Object(IndexMap::new()),
+            extension,
+            locale_root: false,
+            po_entries: Vec::new(),
+            json_style: JsonStyle::detect(&content),
+        };
+
+        match format {
+            TranslationFormat::Json => source.messages = serde_json::from_str(&content)?,
+            TranslationFormat::Yaml => {
+                let value: Value = serde_yaml::from_str(&content)?;
+                let (messages, root) = strip_locale_root(value);
+                source.messages = messages;
+                source.locale_root = root.is_some();
+            }
+            TranslationFormat::Po => {
+                source.po_entries = parse_po(&content)?;
+                source.messages = po_messages(&source.po_entries, true);
+            }
+        }
+        source.order = source_order(&source, &content);
+
+        Ok(source)
+    }
+
+    /// File name for a language that doesn't have a catalog yet.
+    pub fn file_name_for(&self, locale: &str) -> String {
+        format!("{}.{}", locale, self.extension)
+    }
+
+    fn target_format(&self, path: &Path) -> Result<TranslationFormat> {
+        let format = TranslationFormat::from_path(path)
+            .ok_or_else(|| anyhow!("Unsupported translation file: {}", path.display()))?;
+
+        // gettext keys only exist for gettext sources, and the other way round
+        if (format == TranslationFormat::Po) != (self.format == TranslationFormat::Po) {
+            return Err(anyhow!(
+                "{} can't be synced from a {} source file",
+                path.display(),
+                self.format.name()
+            ));
+        }
+        Ok(format)
+    }
+
+    pub fn read_target(&self, path: &Path) -> Result<Value> {
+        let format = self.target_format(path)?;
+        let content = fs::read_to_string(path)?;
+
+        Ok(match format {
+            TranslationFormat::Json => serde_json::from_str(&content)?,
+            TranslationFormat::Yaml => {
+                let value: Option<Value> = serde_yaml::from_str(&content)?;
+                // An empty YAML file is a valid, empty catalog
+                strip_locale_root(value.unwrap_or_else(|| Value::Object(Map::new()))).0
+            }
+            TranslationFormat::Po => po_messages(&parse_po(&content)?, false),
+        })
+    }
+
+    /// Writes a target catalog with its keys in source order and, for JSON,
+    /// the source file's indentation and trailing newline. Values already
+    /// in the file are written back as they were.
+    pub fn write_target(&self, path: &Path, messages: &Value, locale: &str) -> Result<()> {
+        ensure_write_allowed(path)?;
+        let ordered = ordered_like(messages, &self.order);
+        let content = match self.target_format(path)? {
+            TranslationFormat::Json => render_json(&ordered, &self.json_style)?,
+            TranslationFormat::Yaml => {
+                if self.locale_root {
+                    let mut root = IndexMap::new();
+                    root.insert(locale.to_string(), ordered);
+                    serde_yaml::to_string(&root)?
+                } else {
+                    serde_yaml::to_string(&ordered)?
+                }
+            }
+            TranslationFormat::Po => self.render_po_target(path, messages, locale)?,
+        };
+
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Rebuilds a target catalog in source order, keeping its header and
+    /// any plural translations it already has.
+    fn render_po_target(&self, path: &Path, messages: &Value, locale: &str) -> Result<String> {
+        let existing = if path.exists() {
+            parse_po(&fs::read_to_string(path)?)?
+        } else {
+            Vec::new()
+        };
+        let existing_by_key: HashMap<String, &PoEntry> =
+            existing.iter().map(|entry| (entry.key(), entry)).collect();
+
+        let header = existing
+            .iter()
+            .find(|entry| entry.is_header())
+            .cloned()
+            .unwrap_or_else(|| default_po_header(locale));
+        let mut entries = vec![header];
+
+        for source_entry in self.po_entries.iter().filter(|e| !e.is_header()) {
+            let key = source_entry.key();
+            let msgstr = if source_entry.msgid_plural.is_some() {
+                existing_by_key
+                    .get(&key)
+                    .map(|entry| entry.msgstr.clone())
+                    .unwrap_or_else(|| vec![String::new(); source_entry.msgstr.len().max(2)])
+            } else {
+                vec![messages[&key].as_str().unwrap_or("").to_string()]
+            };
+
+            entries.push(PoEntry {
+                msgstr,
+                ..source_entry.clone()
+            });
+        }
+
+        Ok(render_po(&entries))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::commands::translation_cache::stable_hash;
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub const GLOSSARY_FILE: &str = ".nitrokit/glossary.json";
+
+/// Terms translation sync must keep consistent, read from
+/// `.nitrokit/glossary.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Glossary {
+    /// Brand and product names that stay exactly as written
+    #[serde(default)]
+    pub do_not_translate: Vec<String>,
+    /// Source term to language code to approved translation
+    #[serde(default)]
+    pub terms: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// A translation that ignored the glossary.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GlossaryViolation {
+    /// A do-not-translate term is missing from the translation
+    Translated { term: String },
+    /// The approved translation of a term is missing
+    WrongTerm { term: String, expected: String },
+}
+
+impl GlossaryViolation {
+    /// Dropped brand names are never right; a term can be inflected in ways
+    /// a substring check doesn't see, so it is only reported.
+    pub fn is_error(&self) -> bool {
+        matches!(self, GlossaryViolation::Translated { .. })
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            GlossaryViolation::Translated { term } => {
+                format!("\"{}\" must not be translated", term)
+            }
+            GlossaryViolation::WrongTerm { term, expected } => {
+                format!("\"{}\" should be translated as \"{}\"", term, expected)
+            }
+        }
+    }
+}
+
+/// `term` as a whole word, optionally ignoring case.
+fn contains_word(text: &str, term: &str, ignore_case: bool) -> bool {
+    let pattern = format!(
+        r"{}(^|\W){}($|\W)",
+        if ignore_case { "(?i)" } else { "" },
+        regex::escape(term)
+    );
+    Regex::new(&pattern).is_ok_and(|regex| regex.is_match(text))
+}
+
+impl Glossary {
+    pub fn is_empty(&self) -> bool {
+        self.do_not_translate.is_empty() && self.terms.is_empty()
+    }
+
+    /// Changes whenever a term is added, removed or translated differently.
+    pub fn fingerprint(&self) -> u64 {
+        stable_hash(&serde_json::to_string(self).unwrap_or_default())
+    }
+
+    /// Do-not-translate terms that occur in `text`.
+    fn protected_in(&self, text: &str) -> Vec<&String> {
+        self.do_not_translate
+            .iter()
+            .filter(|term| contains_word(text, term, false))
+            .collect()
+    }
+
+    /// Terms with an approved translation for `language` that occur in `text`.
+    fn terms_in(&self, text: &str, language: &str) -> Vec<(&String, &String)> {
+        self.terms
+            .iter()
+            .filter_map(|(term, translations)| {
+                let expected = translations.get(language)?;
+                contains_word(text, term, true).then_some((term, expected))
+            })
+            .collect()
+    }
+
+    /// Prompt lines for the glossary entries that occur in `texts`, so the
+    /// prompt stays short for large glossaries. Empty when none do.
+    pub fn prompt_instructions(&self, language: &str, texts: &[&str]) -> String {
+        let mut protected: Vec<&String> = Vec::new();
+        let mut terms: Vec<(&String, &String)> = Vec::new();
+        for text in texts {
+            for term in self.protected_in(text) {
+                if !protected.contains(&term) {
+                    protected.push(term);
+                }
+            }
+            for entry in self.terms_in(text, language) {
+                if !terms.contains(&entry) {
+                    terms.push(entry);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        if !protected.is_empty() {
+            let quoted: Vec<String> = protected
+                .iter()
+                .map(|term| format!("\"{}\"", term))
+                .collect();
+            lines.push(format!(
+                "Never translate these names, keep them exactly as written: {}.",
+                quoted.join(", ")
+            ));
+        }
+        if !terms.is_empty() {
+            lines.push("Always use these approved translations:".to_string());
+            for (term, expected) in terms {
+                lines.push(format!("- \"{}\" → \"{}\"", term, expected));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Checks a translation of `source` into `language` against the glossary.
+    pub fn check(&self, source: &str, translation: &str, language: &str) -> Vec<GlossaryViolation> {
+        let mut violations: Vec<GlossaryViolation> = self
+            .protected_in(source)
+            .into_iter()
+            .filter(|term| !translation.contains(term.as_str()))
+            .map(|term| GlossaryViolation::Translated { term: term.clone() })
+            .collect();
+        let translation = translation.to_lowercase();
+        violations.extend(
+            self.terms_in(source, language)
+                .into_iter()
+                .filter(|(_, expected)| !translation.contains(&expected.to_lowercase()))
+                .map(|(term, expected)| GlossaryViolation::WrongTerm {
+                    term: term.clone(),
+                    expected: expected.clone(),
+                }),
+        );
+        violations
+    }
+}
+
+/// The glossary below `root`, empty when the project has none.
+pub fn load_glossary(root: &Path) -> Result<Glossary> {
+    let path = root.join(GLOSSARY_FILE);
+    if !path.exists() {
+        return Ok(Glossary::default());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content).map_err(|e| anyhow!("Invalid {}: {}", GLOSSARY_FILE, e))
+}
@@ -0,0 +1,434 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::translation_format::{strip_locale_root, SourceMessages, TranslationFormat};
+use crate::commands::translation_sync::{
+    discover_language_files, extract_all_paths, get_nested_value,
+};
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::{DiffOptions, Repository, Sort};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_HEATMAP_DAYS: i64 = 180;
+
+/// Files the key scanner looks at.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "ts", "tsx", "mjs", "cjs", "vue", "svelte", "astro", "html", "rs", "py", "php",
+    "rb", "go", "dart", "swift", "kt",
+];
+
+/// A rewrite of the string itself says more about an unstable UI area than
+/// an unrelated commit to a file that uses it.
+const STRING_CHANGE_WEIGHT: usize = 3;
+
+#[derive(Debug, Clone)]
+pub struct HeatmapOptions {
+    pub path: PathBuf,
+    pub messages_dir: Option<String>,
+    pub source_file: Option<String>,
+    pub days: i64,
+    pub top: usize,
+    pub json: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct KeyHeat {
+    pub key: String,
+    /// Source files that reference the key
+    pub files: Vec<String>,
+    /// Commits to those files in the window
+    pub file_churn: usize,
+    /// Commits that added or changed the source string in the window
+    pub string_changes: usize,
+    /// Languages with a translation that needs review when the key changes
+    pub languages: Vec<String>,
+    pub score: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AreaHeat {
+    /// Top-level key namespace (`auth`, `settings`, ...)
+    pub area: String,
+    pub keys: usize,
+    pub score: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeatmapReport {
+    pub days: i64,
+    pub keys: Vec<KeyHeat>,
+    pub areas: Vec<AreaHeat>,
+    /// Keys no scanned file references (dead strings or dynamic lookups)
+    pub unreferenced: Vec<String>,
+}
+
+/// Translation keys a file references: string literals that are known keys,
+/// or that become one under a `useTranslations("ns")` / `getTranslations`
+/// namespace.
+pub fn scan_key_references(content: &str, keys: &HashSet<String>) -> BTreeSet<String> {
+    let literal = Regex::new(r#"["'`]([A-Za-z0-9_][A-Za-z0-9_.\-]*)["'`]"#).unwrap();
+    let namespace = Regex::new(
+        r#"(?:useTranslations|getTranslations|useTranslation)\(\s*["'`]([A-Za-z0-9_.\-]+)["'`]"#,
+    )
+    .unwrap();
+
+    let namespaces: Vec<&str> = namespace
+        .captures_iter(content)
+        .map(|caps| caps.get(1).unwrap().as_str())
+        .collect();
+
+    let mut found = BTreeSet::new();
+    for caps in literal.captures_iter(content) {
+        let text = caps.get(1).unwrap().as_str();
+        if keys.contains(text) {
+            found.insert(text.to_string());
+        }
+        for ns in &namespaces {
+            let qualified = format!("{}.{}", ns, text);
+            if keys.contains(&qualified) {
+                found.insert(qualified);
+            }
+        }
+    }
+    found
+}
+
+fn is_source_file(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Tracked source files, relative to the repository root. Going through the
+/// index skips build output and anything else that is ignored.
+fn tracked_source_files(repo: &Repository, exclude_dir: &str) -> Result<Vec<String>> {
+    let index = repo.index()?;
+    Ok(index
+        .iter()
+        .filter_map(|entry| String::from_utf8(entry.path).ok())
+        .filter(|path| is_source_file(path))
+        .filter(|path| exclude_dir.is_empty() || !path.starts_with(&format!("{}/", exclude_dir)))
+        .collect())
+}
+
+fn parse_catalog(content: &str, format: TranslationFormat) -> Option<Value> {
+    match format {
+        TranslationFormat::Json => serde_json::from_str(content).ok(),
+        TranslationFormat::Yaml => serde_yaml::from_str(content)
+            .ok()
+            .map(|value| strip_locale_root(value).0),
+        // gettext keys are hashes, nothing in the code refers to them
+        TranslationFormat::Po => None,
+    }
+}
+
+fn blob_at(repo: &Repository, tree: &git2::Tree, path: &str) -> Option<String> {
+    let entry = tree.get_path(Path::new(path)).ok()?;
+    let blob = repo.find_blob(entry.id()).ok()?;
+    String::from_utf8(blob.content().to_vec()).ok()
+}
+
+/// Commits per file, and per key the commits that added or changed its
+/// source string, since `since` (unix seconds).
+pub fn collect_churn(
+    repo: &Repository,
+    source_path: &str,
+    format: TranslationFormat,
+    keys: &[String],
+    since: i64,
+) -> Result<(HashMap<String, usize>, HashMap<String, usize>)> {
+    let mut file_churn: HashMap<String, usize> = HashMap::new();
+    let mut key_churn: HashMap<String, usize> = HashMap::new();
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.set_sorting(Sort::TIME)?;
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.time().seconds() < since {
+            break;
+        }
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+
+        let diff = repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&tree),
+            Some(DiffOptions::new().ignore_submodules(true)),
+        )?;
+        let mut touched_source = false;
+        for delta in diff.deltas() {
+            if let Some(path) = delta.new_file().path().and_then(|p| p.to_str()) {
+                *file_churn.entry(path.to_string()).or_default() += 1;
+                touched_source |= path == source_path;
+            }
+        }
+
+        if touched_source {
+            let after = blob_at(repo, &tree, source_path).and_then(|c| parse_catalog(&c, format));
+            let before = parent_tree
+                .as_ref()
+                .and_then(|t| blob_at(repo, t, source_path))
+                .and_then(|c| parse_catalog(&c, format));
+            if let Some(after) = after {
+                for key in keys {
+                    let new = get_nested_value(&after, key).and_then(|v| v.as_str());
+                    let old = before
+                        .as_ref()
+                        .and_then(|b| get_nested_value(b, key))
+                        .and_then(|v| v.as_str());
+                    if new.is_some() && new != old {
+                        *key_churn.entry(key.clone()).or_default() += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((file_churn, key_churn))
+}
+
+/// Groups key scores by their first path segment, hottest first.
+pub fn summarize_areas(keys: &[KeyHeat]) -> Vec<AreaHeat> {
+    let mut areas: BTreeMap<String, AreaHeat> = BTreeMap::new();
+    for key in keys {
+        let name = key.key.split('.').next().unwrap_or(&key.key).to_string();
+        let area = areas.entry(name.clone()).or_insert(AreaHeat {
+            area: name,
+            keys: 0,
+            score: 0,
+        });
+        area.keys += 1;
+        area.score += key.score;
+    }
+    let mut areas: Vec<AreaHeat> = areas.into_values().filter(|a| a.score > 0).collect();
+    areas.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.area.cmp(&b.area)));
+    areas
+}
+
+pub fn build_heatmap(
+    repo_path: &Path,
+    messages_dir: &Path,
+    source_file: &str,
+    days: i64,
+) -> Result<HeatmapReport> {
+    let repo = Repository::discover(repo_path)
+        .map_err(|_| anyhow!("Not a git repository: {}", repo_path.display()))?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| anyhow!("Bare repositories are not supported"))?
+        .canonicalize()?;
+
+    let source_path = messages_dir.join(source_file);
+    if !source_path.exists() {
+        return Err(anyhow!("Source file not found: {}", source_path.display()));
+    }
+    let source = SourceMessages::load(&source_path)?;
+    let relative = |path: &Path| -> Result<String> {
+        let path = path.canonicalize()?;
+        let relative = path
+            .strip_prefix(&workdir)
+            .map_err(|_| anyhow!("{} is outside the repository", path.display()))?;
+        Ok(relative.to_string_lossy().replace('\\', "/"))
+    };
+    let source_rel = relative(&source_path)?;
+    let messages_rel = relative(messages_dir)?;
+
+    let keys: Vec<String> = extract_all_paths(&source.messages, "")
+        .into_iter()
+        .filter(|key| {
+            get_nested_value(&source.messages, key).is_some_and(|value| value.is_string())
+        })
+        .collect();
+    let key_set: HashSet<String> = keys.iter().cloned().collect();
+
+    // Key -> referencing files
+    let mut references: HashMap<String, Vec<String>> = HashMap::new();
+    for file in tracked_source_files(&repo, &messages_rel)? {
+        let Ok(content) = std::fs::read_to_string(workdir.join(&file)) else {
+            continue;
+        };
+        for key in scan_key_references(&content, &key_set) {
+            references.entry(key).or_default().push(file.clone());
+        }
+    }
+
+    // Key -> languages that have it translated
+    let mut translated: HashMap<&str, Vec<String>> = HashMap::new();
+    for language in discover_language_files(messages_dir, source_file).unwrap_or_default() {
+        let file = language
+            .file_name
+            .clone()
+            .unwrap_or_else(|| source.file_name_for(&language.code));
+        let Ok(target) = source.read_target(&messages_dir.join(file)) else {
+            continue;
+        };
+        for key in &keys {
+            if get_nested_value(&target, key).is_some() {
+                translated
+                    .entry(key.as_str())
+                    .or_default()
+                    .push(language.code.clone());
+            }
+        }
+    }
+
+    let since = chrono::Utc::now().timestamp() - days * 86_400;
+    let (file_churn, key_churn) = collect_churn(&repo, &source_rel, source.format, &keys, since)?;
+
+    let mut heat = Vec::new();
+    let mut unreferenced = Vec::new();
+    for key in &keys {
+        let files = references.remove(key).unwrap_or_default();
+        if files.is_empty() {
+            unreferenced.push(key.clone());
+        }
+        let churn: usize = files
+            .iter()
+            .map(|file| file_churn.get(file).copied().unwrap_or(0))
+            .sum();
+        let string_changes = key_churn.get(key).copied().unwrap_or(0);
+        heat.push(KeyHeat {
+            key: key.clone(),
+            files,
+            file_churn: churn,
+            string_changes,
+            languages: translated.remove(key.as_str()).unwrap_or_default(),
+            score: string_changes * STRING_CHANGE_WEIGHT + churn,
+        });
+    }
+    heat.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.key.cmp(&b.key)));
+
+    Ok(HeatmapReport {
+        days,
+        areas: summarize_areas(&heat),
+        keys: heat,
+        unreferenced,
+    })
+}
+
+fn heat_bar(score: usize, max: usize, width: usize) -> String {
+    let filled = if max == 0 {
+        0
+    } else {
+        (score * width).div_ceil(max).min(width)
+    };
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(width - filled));
+    match filled * 3 / width.max(1) {
+        0 => bar.green().to_string(),
+        1 => bar.yellow().to_string(),
+        _ => bar.red().to_string(),
+    }
+}
+
+pub fn print_heatmap(report: &HeatmapReport, top: usize) {
+    println!(
+        "\n{}",
+        format!("🔥 Translation Heatmap (last {} days)", report.days)
+            .cyan()
+            .bold()
+    );
+    println!("{}", "═".repeat(60).dimmed());
+
+    if report.areas.is_empty() {
+        println!(
+            "{}",
+            "✅ No churn on referenced keys in this window".green()
+        );
+    } else {
+        println!("{}", "Hot areas:".yellow().bold());
+        let max = report.areas.first().map(|a| a.score).unwrap_or(0);
+        for area in report.areas.iter().take(top) {
+            println!(
+                "  {} {:<24} {:>5} {}",
+                heat_bar(area.score, max, 20),
+                area.area,
+                area.score,
+                format!("({} keys)", area.keys).dimmed()
+            );
+        }
+
+        println!("\n{}", "Hot keys:".yellow().bold());
+        let max = report.keys.first().map(|k| k.score).unwrap_or(0);
+        for key in report.keys.iter().filter(|k| k.score > 0).take(top) {
+            println!(
+                "  {} {} {}",
+                heat_bar(key.score, max, 10),
+                key.key.bold(),
+                format!(
+                    "score {} · {} string change(s) · {} file commit(s)",
+                    key.score, key.string_changes, key.file_churn
+                )
+                .dimmed()
+            );
+            if let Some(file) = key.files.first() {
+                let more = if key.files.len() > 1 {
+                    format!(" +{}", key.files.len() - 1)
+                } else {
+                    String::new()
+                };
+                println!("      {} {}{}", "📄".dimmed(), file.dimmed(), more.dimmed());
+            }
+            if !key.languages.is_empty() {
+                println!(
+                    "      {} review: {}",
+                    "🌍".dimmed(),
+                    key.languages.join(", ").dimmed()
+                );
+            }
+        }
+    }
+
+    if !report.unreferenced.is_empty() {
+        println!(
+            "\n{}",
+            format!(
+                "ℹ️  {} keys are not referenced by any source file (dead or built dynamically)",
+                report.unreferenced.len()
+            )
+            .dimmed()
+        );
+    }
+}
+
+pub async fn run_translation_heatmap(options: HeatmapOptions) -> Result<()> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let messages_dir = options
+        .messages_dir
+        .clone()
+        .unwrap_or(app_config.messages_dir);
+    let source_file = options
+        .source_file
+        .clone()
+        .unwrap_or(app_config.source_file);
+
+    println!("{}", "🔍 Scanning source files and git history...".cyan());
+    let report = build_heatmap(
+        &options.path,
+        &options.path.join(&messages_dir),
+        &source_file,
+        options.days,
+    )?;
+    print_heatmap(&report, options.top);
+
+    if let Some(json_path) = &options.json {
+        crate::utils::sandbox::ensure_not_sandboxed("translations heatmap --json-file")?;
+        std::fs::write(json_path, serde_json::to_string_pretty(&report)?)?;
+        println!(
+            "{}",
+            format!("💾 Heatmap written to {}", json_path.display()).green()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,273 @@
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// CLDR plural categories; a language uses a subset, always with `other`.
+pub const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// An argument of an ICU MessageFormat message, `{name}`,
+/// `{price, number}` or `{count, plural, one {…} other {…}}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcuArgument {
+    pub name: String,
+    /// `plural`, `select`, `number`, …; empty for a plain `{name}`
+    pub kind: String,
+    /// Branch keys (`one`, `=0`, `female`) of plural and select arguments
+    pub branches: Vec<String>,
+}
+
+impl IcuArgument {
+    pub fn has_branches(&self) -> bool {
+        matches!(self.kind.as_str(), "plural" | "selectordinal" | "select")
+    }
+}
+
+/// Whether `text` has a plural or select block, so it has to be handled as
+/// an ICU message instead of plain text with placeholders.
+pub fn is_icu_message(text: &str) -> bool {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN
+        .get_or_init(|| {
+            Regex::new(r"\{\s*[\w.-]+\s*,\s*(plural|selectordinal|select)\s*,").unwrap()
+        })
+        .is_match(text)
+}
+
+/// A plural category or an exact match like `=0`.
+fn is_plural_key(key: &str) -> bool {
+    PLURAL_CATEGORIES.contains(&key)
+        || key
+            .strip_prefix('=')
+            .is_some_and(|number| number.parse::<u64>().is_ok())
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    arguments: Vec<IcuArgument>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(c) => Err(anyhow!(
+                "expected '{}' but found '{}' at {}",
+                expected,
+                c,
+                self.position
+            )),
+            None => Err(anyhow!("expected '{}' but the message ended", expected)),
+        }
+    }
+
+    /// A name, type or branch key: anything up to whitespace or syntax.
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !"{},'".contains(c))
+        {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    /// Text and arguments until the `}` closing a branch, or the end of
+    /// the message at the top level.
+    fn message(&mut self, nested: bool) -> Result<()> {
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    self.position += 1;
+                    self.argument()?;
+                }
+                '}' if nested => return Ok(()),
+                '}' => return Err(anyhow!("unmatched '}}' at {}", self.position)),
+                '\'' => self.quoted(),
+                _ => self.position += 1,
+            }
+        }
+        if nested {
+            Err(anyhow!("unclosed '{{'"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// `''` is an apostrophe and `'{…}'` literal braces; any other
+    /// apostrophe is plain text, as in "don't".
+    fn quoted(&mut self) {
+        self.position += 1;
+        match self.peek() {
+            Some('\'') => self.position += 1,
+            Some('{' | '}' | '#' | '|') => {
+                while let Some(c) = self.peek() {
+                    self.position += 1;
+                    if c == '\'' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The rest of an argument after its `{`.
+    fn argument(&mut self) -> Result<()> {
+        let name = self.word();
+        if name.is_empty() {
+            return Err(anyhow!("argument without a name at {}", self.position));
+        }
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            self.arguments.push(IcuArgument {
+                name,
+                kind: String::new(),
+                branches: Vec::new(),
+            });
+            return Ok(());
+        }
+
+        self.expect(',')?;
+        let kind = self.word();
+        let mut argument = IcuArgument {
+            name,
+            kind,
+            branches: Vec::new(),
+        };
+        if !argument.has_branches() {
+            // `{price, number, ::currency/EUR}`: the style is kept as is
+            while let Some(c) = self.peek() {
+                match c {
+                    '}' => break,
+                    '{' => {
+                        return Err(anyhow!(
+                            "unexpected '{{' in {{{}, {}}}",
+                            argument.name,
+                            argument.kind
+                        ))
+                    }
+                    _ => self.position += 1,
+                }
+            }
+            self.expect('}')?;
+            self.arguments.push(argument);
+            return Ok(());
+        }
+
+        self.expect(',')?;
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') {
+                self.position += 1;
+                break;
+            }
+            if self.peek().is_none() {
+                return Err(anyhow!("unclosed {{{}, {}}}", argument.name, argument.kind));
+            }
+            let key = self.word();
+            if key.is_empty() {
+                return Err(anyhow!(
+                    "expected a branch of {{{}, {}}} at {}",
+                    argument.name,
+                    argument.kind,
+                    self.position
+                ));
+            }
+            if argument.kind != "select" && key.starts_with("offset:") {
+                continue;
+            }
+            if argument.kind != "select" && !is_plural_key(&key) {
+                return Err(anyhow!("unknown plural category '{}'", key));
+            }
+            self.expect('{')?;
+            self.message(true)?;
+            self.expect('}')?;
+            argument.branches.push(key);
+        }
+        if !argument.branches.iter().any(|key| key == "other") {
+            return Err(anyhow!(
+                "{{{}, {}}} has no 'other' branch",
+                argument.name,
+                argument.kind
+            ));
+        }
+        self.arguments.push(argument);
+        Ok(())
+    }
+}
+
+/// Parses an ICU message and returns its arguments in order, including
+/// the ones inside plural and select branches.
+pub fn parse_message(text: &str) -> Result<Vec<IcuArgument>> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        position: 0,
+        arguments: Vec::new(),
+    };
+    parser.message(false)?;
+    Ok(parser.arguments)
+}
+
+/// What breaks the ICU structure of `source` in `translation`: invalid
+/// syntax, plural and select blocks that went missing, changed select
+/// keys or unknown arguments. Plural branches may differ, each language
+/// has its own categories. `None` when `source` is no ICU message.
+pub fn check_translation(source: &str, translation: &str) -> Option<String> {
+    if !is_icu_message(source) {
+        return None;
+    }
+    // A broken source can't tell what the translation should look like
+    let expected = parse_message(source).ok()?;
+    let actual = match parse_message(translation) {
+        Ok(arguments) => arguments,
+        Err(e) => return Some(format!("invalid ICU message: {}", e)),
+    };
+
+    for argument in expected.iter().filter(|argument| argument.has_branches()) {
+        let Some(found) = actual
+            .iter()
+            .find(|found| found.name == argument.name && found.kind == argument.kind)
+        else {
+            return Some(format!(
+                "{{{}, {}}} is missing",
+                argument.name, argument.kind
+            ));
+        };
+        if argument.kind == "select" {
+            let mut wanted = argument.branches.clone();
+            let mut branches = found.branches.clone();
+            wanted.sort();
+            branches.sort();
+            if wanted != branches {
+                return Some(format!(
+                    "{{{}, select}} branches changed from {} to {}",
+                    argument.name,
+                    wanted.join(" "),
+                    branches.join(" ")
+                ));
+            }
+        }
+    }
+    actual
+        .iter()
+        .find(|found| !expected.iter().any(|argument| argument.name == found.name))
+        .map(|found| format!("unknown argument {{{}}}", found.name))
+}
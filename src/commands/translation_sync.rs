@@ -1,29 +1,199 @@
-use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::config::{AppConfig, ConfigError, ConfigManager};
+use crate::commands::gemini_models::{check_model, unknown_model_error, ModelCheck};
+use crate::commands::state::{record_run, untranslated_keys, Tracked};
+use crate::commands::translation_cache::{cache_scope, TranslationCache, CACHE_FILE};
+use crate::commands::translation_format::{SourceMessages, TranslationFormat};
+use crate::commands::translation_glossary::{load_glossary, Glossary};
+use crate::commands::translation_icu::{
+    check_translation as check_icu_translation, is_icu_message,
+};
+use crate::utils::progress::ProgressBar;
+use crate::utils::prompt;
+use crate::utils::sandbox::{ensure_network_allowed, ensure_not_sandboxed};
+use crate::utils::secrets::{self, SecretStorage};
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc};
 use colored::*;
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
+use std::future::Future;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProviderKind {
+    Gemini,
+    OpenAi,
+    Anthropic,
+}
+
+impl ProviderKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProviderKind::Gemini => "gemini",
+            ProviderKind::OpenAi => "openai",
+            ProviderKind::Anthropic => "anthropic",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProviderKind::Gemini => "Gemini",
+            ProviderKind::OpenAi => "OpenAI",
+            ProviderKind::Anthropic => "Anthropic",
+        }
+    }
+
+    /// Environment variable used when no key is stored in the config
+    pub fn api_key_env(&self) -> &'static str {
+        match self {
+            ProviderKind::Gemini => "GEMINI_API_KEY",
+            ProviderKind::OpenAi => "OPENAI_API_KEY",
+            ProviderKind::Anthropic => "ANTHROPIC_API_KEY",
+        }
+    }
+
+    /// Config entry of the API key, also its name in the OS keychain
+    pub fn api_key_name(&self) -> &'static str {
+        match self {
+            ProviderKind::Gemini => "gemini_api_key",
+            ProviderKind::OpenAi => "openai_api_key",
+            ProviderKind::Anthropic => "anthropic_api_key",
+        }
+    }
+}
+
+impl std::str::FromStr for ProviderKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "gemini" => Ok(ProviderKind::Gemini),
+            "openai" => Ok(ProviderKind::OpenAi),
+            "anthropic" => Ok(ProviderKind::Anthropic),
+            other => Err(anyhow!(
+                "Unknown translation provider: {}. Use gemini, openai or anthropic",
+                other
+            )),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct TranslationConfig {
+    pub provider: ProviderKind,
     pub api_key: String,
     pub model: String,
+    /// Minimum time between two requests to a provider, across all languages
     pub delay_seconds: u64,
     pub messages_dir: PathBuf,
     pub source_file: String,
+    pub use_cache: bool,
+    /// Takes over when the primary provider errors or rate-limits
+    pub fallback: Option<FallbackProvider>,
+    /// Retries per request on 429 and 5xx replies, before any fallback
+    pub max_retries: u32,
+    /// Languages translated at the same time
+    pub concurrency: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct FallbackProvider {
+    pub provider: ProviderKind,
+    pub api_key: String,
+    pub model: String,
+}
+
+/// API key and model stored for `provider`. Keys fall back to the
+/// provider's environment variable.
+pub fn settings_for(app_config: &AppConfig, provider: ProviderKind) -> (Option<String>, String) {
+    let (api_key, model) = match provider {
+        ProviderKind::Gemini => (&app_config.gemini_api_key, &app_config.gemini_model),
+        ProviderKind::OpenAi => (&app_config.openai_api_key, &app_config.openai_model),
+        ProviderKind::Anthropic => (&app_config.anthropic_api_key, &app_config.anthropic_model),
+    };
+
+    // Keys in the keychain or encrypted with a passphrase are revealed here
+    let stored = api_key.as_deref().filter(|key| !key.is_empty());
+    let api_key = match stored.map(|stored| secrets::reveal(provider.api_key_name(), stored)) {
+        Some(Ok(key)) => Some(key),
+        Some(Err(e)) => {
+            eprintln!(
+                "{}",
+                format!(
+                    "⚠️  Could not read the {} API key: {}",
+                    provider.display_name(),
+                    e
+                )
+                .yellow()
+            );
+            None
+        }
+        None => std::env::var(provider.api_key_env()).ok(),
+    }
+    .filter(|key| !key.is_empty());
+
+    (api_key, model.clone())
+}
+
+fn api_key_slot(config: &mut AppConfig, provider: ProviderKind) -> &mut Option<String> {
+    match provider {
+        ProviderKind::Gemini => &mut config.gemini_api_key,
+        ProviderKind::OpenAi => &mut config.openai_api_key,
+        ProviderKind::Anthropic => &mut config.anthropic_api_key,
+    }
+}
+
+/// Picks the API key and model of the configured provider.
+pub fn provider_settings(app_config: &AppConfig) -> (ProviderKind, Option<String>, String) {
+    let provider = app_config
+        .translation_provider
+        .parse()
+        .unwrap_or(ProviderKind::Gemini);
+    let (api_key, model) = settings_for(app_config, provider);
+    (provider, api_key, model)
+}
+
+/// The configured fallback provider, if it differs from the primary one and
+/// has an API key.
+pub fn fallback_settings(app_config: &AppConfig) -> Option<FallbackProvider> {
+    let provider: ProviderKind = app_config
+        .translation_fallback_provider
+        .as_deref()?
+        .parse()
+        .ok()?;
+    if provider == provider_settings(app_config).0 {
+        return None;
+    }
+    let (api_key, model) = settings_for(app_config, provider);
+    Some(FallbackProvider {
+        provider,
+        api_key: api_key?,
+        model,
+    })
 }
 
 impl From<AppConfig> for TranslationConfig {
     fn from(app_config: AppConfig) -> Self {
+        let (provider, api_key, model) = provider_settings(&app_config);
+        let fallback = fallback_settings(&app_config);
         Self {
-            api_key: app_config.gemini_api_key.unwrap_or_default(),
-            model: app_config.gemini_model,
+            provider,
+            api_key: api_key.unwrap_or_default(),
+            model,
             delay_seconds: app_config.translation_delay_seconds,
             messages_dir: PathBuf::from(app_config.messages_dir),
             source_file: app_config.source_file,
+            use_cache: true,
+            fallback,
+            max_retries: app_config.translation_max_retries,
+            concurrency: app_config.translation_concurrency,
         }
     }
 }
@@ -33,6 +203,8 @@ pub struct Language {
     pub code: String,
     pub name: String,
     pub flag: String,
+    /// Existing catalog for this language; new ones follow the source format
+    pub file_name: Option<String>,
 }
 
 impl Language {
@@ -41,6 +213,7 @@ impl Language {
             code: code.to_string(),
             name: name.to_string(),
             flag: flag.to_string(),
+            file_name: None,
         }
     }
 
@@ -104,46 +277,40 @@ pub fn discover_language_files(messages_dir: &Path, source_file: &str) -> Result
 
     let mut languages = Vec::new();
 
-    // messages/ klasöründeki tüm .json dosyalarını oku
+    // messages/ klasöründeki tüm çeviri dosyalarını oku (.json, .yml/.yaml, .po)
     for entry in fs::read_dir(messages_dir)? {
         let entry = entry?;
         let path = entry.path();
 
-        // Sadece .json dosyalarını kontrol et
-        if let Some(extension) = path.extension() {
-            if extension == "json" {
-                if let Some(file_name) = path.file_name() {
-                    if let Some(file_name_str) = file_name.to_str() {
-                        // source.json'u atla
-                        if file_name_str != source_file {
-                            // Dosya isminden dil kodunu çıkar (örn: "tr.json" -> "tr")
-                            if let Some(lang_code) = file_name_str.strip_suffix(".json") {
-                                let language = Language::from_code(lang_code);
-                                languages.push(language);
-                                println!(
-                                    "{}",
-                                    format!(
-                                        "📁 Found language file: {} ({})",
-                                        file_name_str, lang_code
-                                    )
-                                    .dimmed()
-                                );
-                            }
-                        }
-                    }
-                }
-            }
+        if TranslationFormat::from_path(&path).is_none() {
+            continue;
+        }
+        // .pot dosyaları şablondur, dil dosyası değil
+        if path.extension().is_some_and(|extension| extension == "pot") {
+            continue;
+        }
+
+        let (Some(file_name), Some(lang_code)) = (
+            path.file_name().and_then(|name| name.to_str()),
+            path.file_stem().and_then(|stem| stem.to_str()),
+        ) else {
+            continue;
+        };
+
+        // source dosyasını atla
+        if file_name == source_file {
+            continue;
         }
+
+        // Dosya isminden dil kodunu çıkar (örn: "tr.yml" -> "tr")
+        let mut language = Language::from_code(lang_code);
+        language.file_name = Some(file_name.to_string());
+        languages.push(language);
     }
 
     // Alfabetik sırala
     languages.sort_by(|a, b| a.code.cmp(&b.code));
 
-    println!(
-        "{}",
-        format!("🌍 Discovered {} language files", languages.len()).blue()
-    );
-
     Ok(languages)
 }
 
@@ -151,6 +318,21 @@ pub fn discover_language_files(messages_dir: &Path, source_file: &str) -> Result
 pub async fn get_target_languages(messages_dir: &Path, source_file: &str) -> Result<Vec<Language>> {
     // Mevcut dil dosyalarını keşfet
     let existing_languages = discover_language_files(messages_dir, source_file)?;
+    for language in &existing_languages {
+        println!(
+            "{}",
+            format!(
+                "📁 Found language file: {} ({})",
+                language.file_name.as_deref().unwrap_or_default(),
+                language.code
+            )
+            .dimmed()
+        );
+    }
+    println!(
+        "{}",
+        format!("🌍 Discovered {} language files", existing_languages.len()).blue()
+    );
 
     if existing_languages.is_empty() {
         println!("{}", "ℹ️  No existing language files found.".yellow());
@@ -168,22 +350,19 @@ pub async fn get_target_languages(messages_dir: &Path, source_file: &str) -> Res
         return Ok(default_languages);
     }
 
+    // CI'da mevcut dillerle devam et
+    if prompt::is_non_interactive() {
+        return Ok(existing_languages);
+    }
+
     // Kullanıcıya yeni dil eklemek isteyip istemediğini sor
     println!("\n{}", "Would you like to add new languages? (y/n):".cyan());
-    print!("{}", "nitroterm> ".cyan().bold());
-
-    let mut input = String::new();
-    std::io::stdin().read_line(&mut input)?;
-
-    if input.trim().to_lowercase() == "y" || input.trim().to_lowercase() == "yes" {
+    if prompt::confirm("nitroterm> ".cyan().bold())? {
         println!(
             "{}",
             "Enter language codes separated by commas (e.g., 'ja,ko,zh'):".cyan()
         );
-        print!("{}", "nitroterm> ".cyan().bold());
-
-        let mut lang_input = String::new();
-        std::io::stdin().read_line(&mut lang_input)?;
+        let lang_input = prompt::ask("nitroterm> ".cyan().bold(), "")?;
 
         let new_codes: Vec<&str> = lang_input.trim().split(',').map(|s| s.trim()).collect();
         let mut all_languages = existing_languages;
@@ -247,201 +426,204 @@ struct GeminiCandidate {
     content: GeminiContent,
 }
 
-pub struct TranslationSync {
-    config: TranslationConfig,
-    client: reqwest::Client,
+#[derive(Debug, Serialize, Deserialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
 }
 
-impl TranslationSync {
-    pub fn new(config: TranslationConfig) -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .expect("Failed to create HTTP client");
-
-        Self { config, client }
-    }
-
-    pub async fn sync_translations(&self) -> Result<()> {
-        println!("{}", "🔄 Starting translation sync...".cyan().bold());
+#[derive(Debug, Serialize)]
+struct OpenAiRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    temperature: f32,
+}
 
-        // messages/ klasörünün var olup olmadığını kontrol et
-        if !self.config.messages_dir.exists() {
-            println!(
-                "{}",
-                format!(
-                    "📁 Creating messages directory: {}",
-                    self.config.messages_dir.display()
-                )
-                .blue()
-            );
-            fs::create_dir_all(&self.config.messages_dir)?;
-        }
+#[derive(Debug, Deserialize)]
+struct OpenAiResponse {
+    choices: Vec<OpenAiChoice>,
+}
 
-        // Load source JSON
-        let source_path = self.config.messages_dir.join(&self.config.source_file);
-        if !source_path.exists() {
-            return Err(anyhow!("Source file not found: {}", source_path.display()));
-        }
+#[derive(Debug, Deserialize)]
+struct OpenAiChoice {
+    message: ChatMessage,
+}
 
-        let source_content = fs::read_to_string(&source_path)?;
-        let source_json: Value = serde_json::from_str(&source_content)?;
+#[derive(Debug, Serialize)]
+struct AnthropicRequest {
+    model: String,
+    max_tokens: i32,
+    temperature: f32,
+    messages: Vec<ChatMessage>,
+}
 
-        println!(
-            "{}",
-            format!("📖 Loaded source file: {}", source_path.display()).green()
-        );
+#[derive(Debug, Deserialize)]
+struct AnthropicResponse {
+    content: Vec<AnthropicContent>,
+}
 
-        // Get all translation paths
-        let all_paths = extract_all_paths(&source_json, "");
-        println!(
-            "{}",
-            format!("🔍 Found {} translation keys", all_paths.len()).blue()
-        );
+#[derive(Debug, Deserialize)]
+struct AnthropicContent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: String,
+}
 
-        // Dinamik olarak dil dosyalarını keşfet
-        let languages =
-            get_target_languages(&self.config.messages_dir, &self.config.source_file).await?;
+pub type ProviderFuture<'a> = Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>>;
 
-        if languages.is_empty() {
-            println!("{}", "⚠️  No target languages found.".yellow());
-            return Ok(());
-        }
+/// A model backend for translation sync. Gets the full prompt and returns the
+/// raw reply text, which is then parsed as `key||translation` lines.
+pub trait TranslationProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    /// The model answering prompts, empty when there is no such thing.
+    fn model(&self) -> &str {
+        ""
+    }
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a>;
+}
 
-        // Process each language
-        for language in &languages {
-            println!(
-                "\n{}",
-                format!(
-                    "🌍 Processing {} {} ({})",
-                    language.flag, language.name, language.code
-                )
-                .yellow()
-                .bold()
-            );
+pub const GEMINI_API_URL: &str = "https://generativelanguage.googleapis.com";
+pub const OPENAI_API_URL: &str = "https://api.openai.com";
+pub const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
+
+/// A non-success reply from a provider API. Typed so [`RetryProvider`] can
+/// tell rate limits and outages apart from bad requests.
+#[derive(Debug)]
+pub struct ApiError {
+    pub provider: &'static str,
+    pub status: u16,
+    /// From the `Retry-After` header
+    pub retry_after: Option<Duration>,
+    pub message: String,
+}
 
-            match self
-                .process_language(&source_json, &all_paths, language)
-                .await
-            {
-                Ok(updated_count) => {
-                    if updated_count > 0 {
-                        println!(
-                            "{}",
-                            format!("✅ Updated {} translations", updated_count).green()
-                        );
-                    } else {
-                        println!("{}", "✅ All translations up to date".green());
-                    }
-                }
-                Err(e) => {
-                    println!(
-                        "{}",
-                        format!("❌ Failed to process {}: {}", language.name, e).red()
-                    );
-                }
-            }
+impl ApiError {
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429 || self.status >= 500
+    }
 
-            // Rate limiting
-            if self.config.delay_seconds > 0 {
-                tokio::time::sleep(Duration::from_secs(self.config.delay_seconds)).await;
-            }
+    pub async fn from_response(
+        provider: &'static str,
+        response: reqwest::Response,
+    ) -> anyhow::Error {
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, Utc::now()));
+        let message = response.text().await.unwrap_or_default();
+        ApiError {
+            provider,
+            status,
+            retry_after,
+            message,
         }
+        .into()
+    }
+}
 
-        println!("\n{}", "🎉 Translation sync completed!".green().bold());
-        Ok(())
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} API error ({}): {}",
+            self.provider,
+            self.status,
+            self.message.trim()
+        )
     }
+}
 
-    // Geri kalan metodlar aynı kalacak...
-    async fn process_language(
-        &self,
-        source_json: &Value,
-        all_paths: &[String],
-        language: &Language,
-    ) -> Result<usize> {
-        let lang_file = self
-            .config
-            .messages_dir
-            .join(format!("{}.json", language.code));
+impl std::error::Error for ApiError {}
 
-        // Load existing translations or create empty
-        let mut existing_json = if lang_file.exists() {
-            let content = fs::read_to_string(&lang_file)?;
-            serde_json::from_str(&content)?
-        } else {
-            serde_json::json!({})
-        };
+/// `Retry-After` is either delay seconds or an HTTP date.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
 
-        // Find missing translations
-        let missing_paths = self.find_missing_paths(&existing_json, all_paths);
+/// Exponential backoff for provider requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
 
-        if missing_paths.is_empty() {
-            return Ok(0);
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
         }
+    }
+}
 
-        println!(
-            "{}",
-            format!("📝 Found {} missing translations", missing_paths.len()).yellow()
-        );
-
-        // Translate missing keys in batches
-        let batch_size = 10; // Avoid overwhelming the API
-        let mut updated_count = 0;
+impl RetryPolicy {
+    /// `base_delay * 2^attempt` capped at `max_delay`, scaled to 50–100% by
+    /// `jitter` (0.0–1.0) so parallel runs don't retry in lockstep.
+    pub fn delay(&self, attempt: u32, jitter: f64) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        exponential.mul_f64(0.5 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
 
-        for chunk in missing_paths.chunks(batch_size) {
-            let translations = self.translate_batch(chunk, source_json, language).await?;
+fn jitter() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or_default();
+    f64::from(nanos % 1000) / 1000.0
+}
 
-            for (path, translation) in translations {
-                self.set_nested_value(&mut existing_json, &path, Value::String(translation))?;
-                updated_count += 1;
-            }
-        }
+fn http_client() -> Result<reqwest::Client> {
+    ensure_network_allowed()?;
+    Ok(reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()?)
+}
 
-        // Save updated translations
-        if updated_count > 0 {
-            let formatted_json = serde_json::to_string_pretty(&existing_json)?;
-            fs::write(&lang_file, formatted_json)?;
-        }
+pub struct GeminiProvider {
+    client: reqwest::Client,
+    api_key: String,
+    model: String,
+    base_url: String,
+}
 
-        Ok(updated_count)
+impl GeminiProvider {
+    pub fn new(api_key: &str, model: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client()?,
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+            base_url: GEMINI_API_URL.to_string(),
+        })
     }
 
-    async fn translate_batch(
-        &self,
-        paths: &[String],
-        source_json: &Value,
-        language: &Language,
-    ) -> Result<Vec<(String, String)>> {
-        let mut batch_text = String::new();
-        let mut path_mapping = Vec::new();
-
-        for path in paths {
-            if let Some(source_text) = self.get_nested_value(source_json, path) {
-                if let Some(text) = source_text.as_str() {
-                    batch_text.push_str(&format!("{}||{}\n", path, text));
-                    path_mapping.push(path.clone());
-                }
-            }
-        }
-
-        if batch_text.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let prompt = format!(
-            "Translate the following key-value pairs to {}. Keep the exact format with || separator and preserve any HTML tags, placeholders like {{appName}}, {{min}}, {{max}}, etc. Only translate the text content, not the keys or placeholders:\n\n{}",
-            language.name,
-            batch_text
-        );
-
-        let translated_text = self.call_gemini_api(&prompt).await?;
-        self.parse_translation_response(&translated_text, &path_mapping)
+    /// Points the provider at another endpoint (a proxy or a test server).
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
     }
 
-    async fn call_gemini_api(&self, prompt: &str) -> Result<String> {
+    async fn call_api(&self, prompt: &str) -> Result<String> {
         let url = format!(
-            "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent?key={}",
-            self.config.model, self.config.api_key
+            "{}/v1beta/models/{}:generateContent?key={}",
+            self.base_url, self.model, self.api_key
         );
 
         let request = GeminiRequest {
@@ -461,8 +643,7 @@ impl TranslationSync {
         let response = self.client.post(&url).json(&request).send().await?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await?;
-            return Err(anyhow!("Gemini API error: {}", error_text));
+            return Err(ApiError::from_response("Gemini", response).await);
         }
 
         let gemini_response: GeminiResponse = response.json().await?;
@@ -475,147 +656,1388 @@ impl TranslationSync {
 
         Err(anyhow!("No response from Gemini API"))
     }
+}
 
-    fn parse_translation_response(
-        &self,
-        response: &str,
-        paths: &[String],
-    ) -> Result<Vec<(String, String)>> {
-        let mut results = Vec::new();
-
-        for line in response.lines() {
-            if let Some((path, translation)) = line.split_once("||") {
-                let path = path.trim();
-                let translation = translation.trim();
-
-                if paths.contains(&path.to_string()) {
-                    results.push((path.to_string(), translation.to_string()));
-                }
-            }
-        }
-
-        Ok(results)
+impl TranslationProvider for GeminiProvider {
+    fn name(&self) -> &'static str {
+        "Gemini"
     }
 
-    fn find_missing_paths(&self, target: &Value, all_paths: &[String]) -> Vec<String> {
-        let mut missing = Vec::new();
-
-        for path in all_paths {
-            if self.get_nested_value(target, path).is_none() {
-                missing.push(path.clone());
-            }
-        }
+    fn model(&self) -> &str {
+        &self.model
+    }
 
-        missing
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
     }
+}
 
-    fn get_nested_value<'a>(&self, value: &'a Value, path: &str) -> Option<&'a Value> {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = value;
+pub struct OpenAiProvider {
+    client: reqwest::Client,
+    api_key: String,
+    model: String,
+    base_url: String,
+}
 
-        for part in parts {
-            match current {
-                Value::Object(map) => {
-                    current = map.get(part)?;
-                }
-                _ => return None,
-            }
-        }
+impl OpenAiProvider {
+    pub fn new(api_key: &str, model: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client()?,
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+            base_url: OPENAI_API_URL.to_string(),
+        })
+    }
 
-        Some(current)
+    /// Points the provider at another endpoint (a proxy or a test server).
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
     }
 
-    fn set_nested_value(&self, value: &mut Value, path: &str, new_value: Value) -> Result<()> {
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = value;
+    async fn call_api(&self, prompt: &str) -> Result<String> {
+        let request = OpenAiRequest {
+            model: self.model.clone(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+            temperature: 0.3,
+        };
 
-        for (i, part) in parts.iter().enumerate() {
-            if i == parts.len() - 1 {
-                // Last part, set the value
-                if let Value::Object(map) = current {
-                    map.insert(part.to_string(), new_value);
-                    return Ok(());
-                }
-            } else {
-                // Intermediate part, navigate or create
-                if !current.is_object() {
-                    *current = serde_json::json!({});
-                }
+        let response = self
+            .client
+            .post(format!("{}/v1/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&request)
+            .send()
+            .await?;
 
-                if let Value::Object(map) = current {
-                    if !map.contains_key(*part) {
-                        map.insert(part.to_string(), serde_json::json!({}));
-                    }
-                    current = map.get_mut(*part).unwrap();
-                }
-            }
+        if !response.status().is_success() {
+            return Err(ApiError::from_response("OpenAI", response).await);
         }
 
-        Err(anyhow!("Failed to set nested value"))
+        let openai_response: OpenAiResponse = response.json().await?;
+        openai_response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .ok_or_else(|| anyhow!("No response from OpenAI API"))
     }
 }
 
-fn extract_all_paths(value: &Value, prefix: &str) -> Vec<String> {
-    let mut paths = Vec::new();
+impl TranslationProvider for OpenAiProvider {
+    fn name(&self) -> &'static str {
+        "OpenAI"
+    }
 
-    if let Value::Object(map) = value {
-        for (key, val) in map {
-            let current_path = if prefix.is_empty() {
-                key.clone()
-            } else {
-                format!("{}.{}", prefix, key)
-            };
+    fn model(&self) -> &str {
+        &self.model
+    }
 
-            if val.is_string() {
-                paths.push(current_path);
-            } else {
-                paths.extend(extract_all_paths(val, &current_path));
-            }
-        }
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
     }
+}
 
-    paths
+pub struct AnthropicProvider {
+    client: reqwest::Client,
+    api_key: String,
+    model: String,
+    base_url: String,
 }
 
-pub async fn sync_translations_interactive() -> Result<()> {
-    let config_manager = ConfigManager::new().await?;
-    // Check if this is the first run
-    if config_manager.is_first_run().await? {
-        println!(
+impl AnthropicProvider {
+    pub fn new(api_key: &str, model: &str) -> Result<Self> {
+        Ok(Self {
+            client: http_client()?,
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+            base_url: ANTHROPIC_API_URL.to_string(),
+        })
+    }
+
+    /// Points the provider at another endpoint (a proxy or a test server).
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn call_api(&self, prompt: &str) -> Result<String> {
+        let request = AnthropicRequest {
+            model: self.model.clone(),
+            max_tokens: 4096,
+            temperature: 0.3,
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+        };
+
+        let response = self
+            .client
+            .post(format!("{}/v1/messages", self.base_url))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::from_response("Anthropic", response).await);
+        }
+
+        let anthropic_response: AnthropicResponse = response.json().await?;
+        let text: String = anthropic_response
+            .content
+            .into_iter()
+            .filter(|block| block.kind == "text")
+            .map(|block| block.text)
+            .collect();
+
+        if text.is_empty() {
+            return Err(anyhow!("No response from Anthropic API"));
+        }
+        Ok(text)
+    }
+}
+
+impl TranslationProvider for AnthropicProvider {
+    fn name(&self) -> &'static str {
+        "Anthropic"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
+    }
+}
+
+/// `default`, unless the environment variable `name` points elsewhere.
+pub fn api_base_url(name: &str, default: &str) -> String {
+    std::env::var(name)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .unwrap_or_else(|| default.to_string())
+}
+
+/// Endpoints can be redirected (proxies, compatible gateways) with
+/// `GEMINI_BASE_URL`, `OPENAI_BASE_URL` or `ANTHROPIC_BASE_URL`.
+pub fn build_provider(
+    provider: ProviderKind,
+    api_key: &str,
+    model: &str,
+) -> Result<Box<dyn TranslationProvider>> {
+    Ok(match provider {
+        ProviderKind::Gemini => Box::new(
+            GeminiProvider::new(api_key, model)?
+                .with_base_url(&api_base_url("GEMINI_BASE_URL", GEMINI_API_URL)),
+        ),
+        ProviderKind::OpenAi => Box::new(
+            OpenAiProvider::new(api_key, model)?
+                .with_base_url(&api_base_url("OPENAI_BASE_URL", OPENAI_API_URL)),
+        ),
+        ProviderKind::Anthropic => Box::new(
+            AnthropicProvider::new(api_key, model)?
+                .with_base_url(&api_base_url("ANTHROPIC_BASE_URL", ANTHROPIC_API_URL)),
+        ),
+    })
+}
+
+pub fn create_provider(config: &TranslationConfig) -> Result<Box<dyn TranslationProvider>> {
+    let policy = RetryPolicy {
+        max_retries: config.max_retries,
+        ..RetryPolicy::default()
+    };
+    let interval = Duration::from_secs(config.delay_seconds);
+    // Retries go through the rate limit as well
+    let with_retries = |provider: Box<dyn TranslationProvider>| -> Box<dyn TranslationProvider> {
+        let provider: Box<dyn TranslationProvider> = if interval.is_zero() {
+            provider
+        } else {
+            Box::new(RateLimitedProvider::new(provider, interval))
+        };
+        if policy.max_retries == 0 {
+            provider
+        } else {
+            Box::new(RetryProvider::new(provider, policy))
+        }
+    };
+
+    let primary = with_retries(build_provider(
+        config.provider,
+        &config.api_key,
+        &config.model,
+    )?);
+    Ok(match &config.fallback {
+        Some(fallback) => Box::new(FailoverProvider::new(
+            primary,
+            with_retries(build_provider(
+                fallback.provider,
+                &fallback.api_key,
+                &fallback.model,
+            )?),
+        )),
+        None => primary,
+    })
+}
+
+/// Retries rate-limited (429) and failing (5xx) requests with exponential
+/// backoff, waiting for `Retry-After` when the API sends one. Other errors
+/// are returned right away.
+pub struct RetryProvider {
+    inner: Box<dyn TranslationProvider>,
+    policy: RetryPolicy,
+}
+
+impl RetryProvider {
+    pub fn new(inner: Box<dyn TranslationProvider>, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    async fn call_api(&self, prompt: &str) -> Result<String> {
+        let mut attempt = 0;
+        loop {
+            let error = match self.inner.complete(prompt).await {
+                Ok(text) => return Ok(text),
+                Err(e) => e,
+            };
+            let (status, wait) = match error.downcast_ref::<ApiError>() {
+                Some(api) if api.is_retryable() && attempt < self.policy.max_retries => (
+                    api.status,
+                    api.retry_after
+                        .unwrap_or_else(|| self.policy.delay(attempt, jitter())),
+                ),
+                _ => return Err(error),
+            };
+
+            attempt += 1;
+            println!(
+                "{}",
+                format!(
+                    "⏳ {} returned {}, retry {}/{} in {:.1}s",
+                    self.inner.name(),
+                    status,
+                    attempt,
+                    self.policy.max_retries,
+                    wait.as_secs_f64()
+                )
+                .yellow()
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl TranslationProvider for RetryProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
+    }
+}
+
+/// Spaces requests at least `interval` apart, however many languages are
+/// being translated at the same time.
+pub struct RateLimitedProvider {
+    inner: Box<dyn TranslationProvider>,
+    interval: Duration,
+    next_slot: Mutex<Option<tokio::time::Instant>>,
+}
+
+impl RateLimitedProvider {
+    pub fn new(inner: Box<dyn TranslationProvider>, interval: Duration) -> Self {
+        Self {
+            inner,
+            interval,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Reserves the next free slot and returns how long to wait for it.
+    fn reserve(&self) -> Duration {
+        let now = tokio::time::Instant::now();
+        let mut next_slot = self.next_slot.lock().unwrap();
+        let slot = next_slot.map_or(now, |next| next.max(now));
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+
+    async fn call_api(&self, prompt: &str) -> Result<String> {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        self.inner.complete(prompt).await
+    }
+}
+
+impl TranslationProvider for RateLimitedProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
+    }
+}
+
+/// Sends prompts to the primary provider until it fails once (API error,
+/// rate limit, timeout), then to the fallback for the rest of the run.
+pub struct FailoverProvider {
+    primary: Box<dyn TranslationProvider>,
+    fallback: Box<dyn TranslationProvider>,
+    failed_over: AtomicBool,
+}
+
+impl FailoverProvider {
+    pub fn new(
+        primary: Box<dyn TranslationProvider>,
+        fallback: Box<dyn TranslationProvider>,
+    ) -> Self {
+        Self {
+            primary,
+            fallback,
+            failed_over: AtomicBool::new(false),
+        }
+    }
+
+    pub fn has_failed_over(&self) -> bool {
+        self.failed_over.load(Ordering::Relaxed)
+    }
+
+    async fn call_api(&self, prompt: &str) -> Result<String> {
+        if !self.has_failed_over() {
+            match self.primary.complete(prompt).await {
+                Ok(text) => return Ok(text),
+                Err(e) => {
+                    println!(
+                        "{}",
+                        format!(
+                            "⚠️  {} failed ({}), switching to {}",
+                            self.primary.name(),
+                            e,
+                            self.fallback.name()
+                        )
+                        .yellow()
+                    );
+                    self.failed_over.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+        self.fallback.complete(prompt).await
+    }
+}
+
+impl TranslationProvider for FailoverProvider {
+    fn name(&self) -> &'static str {
+        if self.has_failed_over() {
+            self.fallback.name()
+        } else {
+            self.primary.name()
+        }
+    }
+
+    fn model(&self) -> &str {
+        if self.has_failed_over() {
+            self.fallback.model()
+        } else {
+            self.primary.model()
+        }
+    }
+
+    fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+        Box::pin(self.call_api(prompt))
+    }
+}
+
+/// A batch the provider couldn't translate. Its keys stay missing, so the
+/// next sync picks them up again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchFailure {
+    pub language: String,
+    /// 1-based, out of `batches`
+    pub batch: usize,
+    pub batches: usize,
+    pub keys: Vec<String>,
+    pub error: String,
+}
+
+pub struct TranslationSync {
+    config: TranslationConfig,
+    provider: Box<dyn TranslationProvider>,
+    cache: Mutex<TranslationCache>,
+    failures: Mutex<Vec<BatchFailure>>,
+    glossary: Glossary,
+}
+
+impl TranslationSync {
+    pub fn new(config: TranslationConfig) -> Result<Self> {
+        let provider = create_provider(&config)?;
+        Ok(Self::with_provider(config, provider))
+    }
+
+    /// Uses the given provider instead of the one named in the config.
+    pub fn with_provider(
+        config: TranslationConfig,
+        provider: Box<dyn TranslationProvider>,
+    ) -> Self {
+        let cache = if config.use_cache {
+            TranslationCache::load(Path::new(CACHE_FILE))
+        } else {
+            TranslationCache::default()
+        };
+
+        Self {
+            config,
+            provider,
+            cache: Mutex::new(cache),
+            failures: Mutex::new(Vec::new()),
+            glossary: Glossary::default(),
+        }
+    }
+
+    /// Terms the prompts must respect and the translations are checked for.
+    pub fn with_glossary(mut self, glossary: Glossary) -> Self {
+        self.glossary = glossary;
+        self
+    }
+
+    /// Cached translations are only reused from the provider and model
+    /// answering now, under the same glossary.
+    fn cache_scope(&self) -> String {
+        cache_scope(self.provider.name(), self.provider.model(), &self.glossary)
+    }
+
+    /// Batches that failed so far in this run.
+    pub fn batch_failures(&self) -> Vec<BatchFailure> {
+        self.failures.lock().unwrap().clone()
+    }
+
+    pub async fn sync_translations(&self) -> Result<()> {
+        ensure_not_sandboxed("sync-translations")?;
+        println!("{}", "🔄 Starting translation sync...".cyan().bold());
+        println!(
+            "{}",
+            format!(
+                "🤖 Provider: {} ({})",
+                self.provider.name(),
+                self.config.model
+            )
+            .dimmed()
+        );
+
+        if !self.glossary.is_empty() {
+            println!(
+                "{}",
+                format!(
+                    "📘 Glossary: {} do-not-translate, {} terms",
+                    self.glossary.do_not_translate.len(),
+                    self.glossary.terms.len()
+                )
+                .dimmed()
+            );
+        }
+
+        {
+            let cache = self.cache.lock().unwrap();
+            if !cache.is_empty() {
+                println!(
+                    "{}",
+                    format!("♻️  Translation cache: {} entries", cache.len()).dimmed()
+                );
+            }
+        }
+
+        // messages/ klasörünün var olup olmadığını kontrol et
+        if !self.config.messages_dir.exists() {
+            println!(
+                "{}",
+                format!(
+                    "📁 Creating messages directory: {}",
+                    self.config.messages_dir.display()
+                )
+                .blue()
+            );
+            fs::create_dir_all(&self.config.messages_dir)?;
+        }
+
+        let source_path = self.config.messages_dir.join(&self.config.source_file);
+        if !source_path.exists() {
+            return Err(anyhow!("Source file not found: {}", source_path.display()));
+        }
+
+        // Dinamik olarak dil dosyalarını keşfet
+        let languages =
+            get_target_languages(&self.config.messages_dir, &self.config.source_file).await?;
+
+        if languages.is_empty() {
+            println!("{}", "⚠️  No target languages found.".yellow());
+            return Ok(());
+        }
+
+        self.sync_languages(&languages).await?;
+
+        println!("\n{}", "🎉 Translation sync completed!".green().bold());
+        Ok(())
+    }
+
+    /// Fills in missing keys for the given languages, without discovery or prompts.
+    pub async fn sync_languages(&self, languages: &[Language]) -> Result<()> {
+        ensure_not_sandboxed("sync-translations")?;
+
+        // Load source JSON
+        let source_path = self.config.messages_dir.join(&self.config.source_file);
+        let source = SourceMessages::load(&source_path)?;
+        let source_json = &source.messages;
+
+        println!(
+            "{}",
+            format!(
+                "📖 Loaded source file: {} ({})",
+                source_path.display(),
+                source.format.name()
+            )
+            .green()
+        );
+
+        // Get all translation paths
+        let all_paths = extract_all_paths(source_json, "");
+        println!(
+            "{}",
+            format!("🔍 Found {} translation keys", all_paths.len()).blue()
+        );
+
+        // Languages run side by side; the provider keeps the request rate down
+        let concurrency = self.config.concurrency.clamp(1, languages.len().max(1));
+        if concurrency > 1 {
+            println!(
+                "{}",
+                format!("⚡ Translating up to {} languages at a time", concurrency).dimmed()
+            );
+        }
+        let (source, all_paths) = (&source, &all_paths);
+        stream::iter(languages)
+            .for_each_concurrent(concurrency, |language| async move {
+                self.sync_language(source, all_paths, language).await
+            })
+            .await;
+
+        print_batch_failures(&self.batch_failures());
+        Ok(())
+    }
+
+    async fn sync_language(
+        &self,
+        source: &SourceMessages,
+        all_paths: &[String],
+        language: &Language,
+    ) {
+        println!(
+            "\n{}",
+            format!(
+                "🌍 Processing {} {} ({})",
+                language.flag, language.name, language.code
+            )
+            .yellow()
+            .bold()
+        );
+
+        // Named in the result, other languages may have printed in between
+        match self.process_language(source, all_paths, language).await {
+            Ok(updated_count) => {
+                if updated_count > 0 {
+                    println!(
+                        "{}",
+                        format!(
+                            "✅ {}: updated {} translations",
+                            language.name, updated_count
+                        )
+                        .green()
+                    );
+                } else {
+                    println!(
+                        "{}",
+                        format!("✅ {}: all translations up to date", language.name).green()
+                    );
+                }
+            }
+            Err(e) => {
+                println!(
+                    "{}",
+                    format!("❌ Failed to process {}: {}", language.name, e).red()
+                );
+            }
+        }
+
+        if self.config.use_cache {
+            if let Err(e) = self.cache.lock().unwrap().save() {
+                println!(
+                    "{}",
+                    format!("⚠️  Could not save translation cache: {}", e).yellow()
+                );
+            }
+        }
+    }
+
+    // Geri kalan metodlar aynı kalacak...
+    async fn process_language(
+        &self,
+        source: &SourceMessages,
+        all_paths: &[String],
+        language: &Language,
+    ) -> Result<usize> {
+        let source_json = &source.messages;
+        let file_name = language
+            .file_name
+            .clone()
+            .unwrap_or_else(|| source.file_name_for(&language.code));
+        let lang_file = self.config.messages_dir.join(file_name);
+
+        // Load existing translations or create empty
+        let mut existing_json = if lang_file.exists() {
+            source.read_target(&lang_file)?
+        } else {
+            serde_json::json!({})
+        };
+
+        // Find missing translations
+        let missing_paths = self.find_missing_paths(&existing_json, all_paths);
+
+        if missing_paths.is_empty() {
+            return Ok(0);
+        }
+
+        println!(
+            "{}",
+            format!(
+                "📝 {}: found {} missing translations",
+                language.name,
+                missing_paths.len()
+            )
+            .yellow()
+        );
+
+        // Serve what we can from the translation memory before calling the API
+        let mut updated_count = 0;
+        let mut to_translate = Vec::new();
+        {
+            let scope = self.cache_scope();
+            let cache = self.cache.lock().unwrap();
+            for path in missing_paths {
+                let cached = self
+                    .get_nested_value(source_json, &path)
+                    .and_then(|v| v.as_str())
+                    .and_then(|text| cache.get(&scope, &language.code, text))
+                    .cloned();
+
+                match cached {
+                    Some(translation) => {
+                        self.set_nested_value(
+                            &mut existing_json,
+                            source_json,
+                            &path,
+                            Value::String(translation),
+                        )?;
+                        updated_count += 1;
+                    }
+                    None => to_translate.push(path),
+                }
+            }
+        }
+
+        if updated_count > 0 {
+            println!(
+                "{}",
+                format!(
+                    "♻️  {}: reused {} translations from cache",
+                    language.name, updated_count
+                )
+                .blue()
+            );
+        }
+
+        // Translate missing keys in batches
+        let batch_size = 10; // Avoid overwhelming the API
+
+        let batches = to_translate.len().div_ceil(batch_size);
+        let mut progress = ProgressBar::new(&format!("🌐 {}", language.code), to_translate.len());
+        let mut failed_batches = 0;
+
+        for (index, chunk) in to_translate.chunks(batch_size).enumerate() {
+            progress.tick(&format!("batch {}/{}", index + 1, batches));
+            let result = self.translate_batch(chunk, source_json, language).await;
+            progress.inc(chunk.len());
+            // One failed batch doesn't cost the rest of the language
+            let translations = match result {
+                Ok(translations) => translations,
+                Err(e) => {
+                    progress.clear();
+                    println!(
+                        "{}",
+                        format!(
+                            "⚠️  {}: batch {}/{} failed: {}",
+                            language.name,
+                            index + 1,
+                            batches,
+                            e
+                        )
+                        .yellow()
+                    );
+                    self.failures.lock().unwrap().push(BatchFailure {
+                        language: language.code.clone(),
+                        batch: index + 1,
+                        batches,
+                        keys: chunk.to_vec(),
+                        error: e.to_string(),
+                    });
+                    failed_batches += 1;
+                    continue;
+                }
+            };
+
+            for (path, translation) in translations {
+                let source_text = self
+                    .get_nested_value(source_json, &path)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default();
+                // Multi-line values are sent with escaped newlines
+                let translation = if source_text.contains('\n') {
+                    translation.replace("\\n", "\n")
+                } else {
+                    translation
+                };
+                // A broken plural block fails at runtime, so the key stays missing
+                if let Some(problem) = check_icu_translation(source_text, &translation) {
+                    progress.clear();
+                    println!("{}", format!("⚠️  {}: {}", path, problem).yellow());
+                    continue;
+                }
+                let violations = self
+                    .glossary
+                    .check(source_text, &translation, &language.code);
+                if !violations.is_empty() {
+                    progress.clear();
+                    for violation in &violations {
+                        println!(
+                            "{}",
+                            format!("⚠️  {}: {}", path, violation.describe()).yellow()
+                        );
+                    }
+                }
+                // Left missing, so the next sync asks again
+                if violations.iter().any(|violation| violation.is_error()) {
+                    continue;
+                }
+                if self.config.use_cache {
+                    if let Some(text) = self
+                        .get_nested_value(source_json, &path)
+                        .and_then(|v| v.as_str())
+                    {
+                        let scope = self.cache_scope();
+                        self.cache.lock().unwrap().insert(
+                            &scope,
+                            &language.code,
+                            text,
+                            &translation,
+                        );
+                    }
+                }
+                self.set_nested_value(
+                    &mut existing_json,
+                    source_json,
+                    &path,
+                    Value::String(translation),
+                )?;
+                updated_count += 1;
+            }
+        }
+        if !to_translate.is_empty() {
+            progress.finish();
+        }
+        if batches > 0 && failed_batches == batches && updated_count == 0 {
+            return Err(anyhow!("All {} batches failed", batches));
+        }
+
+        // Save updated translations
+        if updated_count > 0 {
+            source.write_target(&lang_file, &existing_json, &language.code)?;
+        }
+
+        Ok(updated_count)
+    }
+
+    async fn translate_batch(
+        &self,
+        paths: &[String],
+        source_json: &Value,
+        language: &Language,
+    ) -> Result<Vec<(String, String)>> {
+        let Some((prompt, path_mapping)) =
+            translation_prompt(paths, source_json, language, &self.glossary)
+        else {
+            return Ok(Vec::new());
+        };
+
+        let translated_text = self.provider.complete(&prompt).await?;
+        self.parse_translation_response(&translated_text, &path_mapping)
+    }
+
+    fn parse_translation_response(
+        &self,
+        response: &str,
+        paths: &[String],
+    ) -> Result<Vec<(String, String)>> {
+        Ok(parse_translation_response(response, paths))
+    }
+
+    fn find_missing_paths(&self, target: &Value, all_paths: &[String]) -> Vec<String> {
+        find_missing_paths(target, all_paths)
+    }
+
+    fn get_nested_value<'a>(&self, value: &'a Value, path: &str) -> Option<&'a Value> {
+        get_nested_value(value, path)
+    }
+
+    fn set_nested_value(
+        &self,
+        value: &mut Value,
+        source: &Value,
+        path: &str,
+        new_value: Value,
+    ) -> Result<()> {
+        set_nested_value_like(value, source, path, new_value)
+    }
+}
+
+fn print_batch_failures(failures: &[BatchFailure]) {
+    if failures.is_empty() {
+        return;
+    }
+    let keys: usize = failures.iter().map(|failure| failure.keys.len()).sum();
+    println!(
+        "\n{}",
+        format!(
+            "⚠️  {} batch(es) failed, {} keys left untranslated (run the sync again to retry them):",
+            failures.len(),
+            keys
+        )
+        .yellow()
+        .bold()
+    );
+    for failure in failures {
+        println!(
+            "  {} {} batch {}/{} ({} keys): {}",
+            "•".dimmed(),
+            failure.language.bold(),
+            failure.batch,
+            failure.batches,
+            failure.keys.len(),
+            failure.error.dimmed()
+        );
+    }
+}
+
+/// Builds the model prompt for a batch of keys, led by the glossary entries
+/// the batch uses. Returns the prompt and the keys that have string values,
+/// or None when there is nothing to translate.
+pub fn translation_prompt(
+    paths: &[String],
+    source_json: &Value,
+    language: &Language,
+    glossary: &Glossary,
+) -> Option<(String, Vec<String>)> {
+    let mut batch_text = String::new();
+    let mut path_mapping = Vec::new();
+    let mut texts = Vec::new();
+
+    for path in paths {
+        if let Some(text) = get_nested_value(source_json, path).and_then(|v| v.as_str()) {
+            batch_text.push_str(&format!("{}||{}\n", path, text.replace('\n', "\\n")));
+            path_mapping.push(path.clone());
+            texts.push(text);
+        }
+    }
+
+    if batch_text.is_empty() {
+        return None;
+    }
+
+    let mut notes = String::new();
+    if texts.iter().any(|text| text.contains('\n')) {
+        notes.push_str(" Keep \\n line breaks as they are.");
+    }
+    if texts.iter().any(|text| is_icu_message(text)) {
+        notes.push_str(&format!(
+            " Some values are ICU messages: keep every {{name, plural, ...}} and {{name, select, ...}} block with its argument name, keyword and select keys unchanged, keep # as is, translate only the text inside the branches and use the plural categories of {} (zero, one, two, few, many, other; always include other).",
+            language.name
+        ));
+    }
+    let prompt = format!(
+        "Translate the following key-value pairs to {}. Keep the exact format with || separator and preserve any HTML tags, placeholders like {{appName}}, {{min}}, {{max}}, etc.{} Only translate the text content, not the keys or placeholders:\n\n{}",
+        language.name,
+        notes,
+        batch_text
+    );
+    let instructions = glossary.prompt_instructions(&language.code, &texts);
+    if instructions.is_empty() {
+        Some((prompt, path_mapping))
+    } else {
+        Some((format!("{}\n\n{}", instructions, prompt), path_mapping))
+    }
+}
+
+/// Picks `path||translation` lines for the requested paths out of a model
+/// response; anything else the model says is ignored.
+pub fn parse_translation_response(response: &str, paths: &[String]) -> Vec<(String, String)> {
+    let mut results = Vec::new();
+
+    for line in response.lines() {
+        if let Some((path, translation)) = line.split_once("||") {
+            let path = path.trim();
+            let translation = translation.trim();
+
+            if paths.iter().any(|p| p == path) {
+                results.push((path.to_string(), translation.to_string()));
+            }
+        }
+    }
+
+    results
+}
+
+/// The slot for `part` in `container`. Arrays take indexes and grow with
+/// nulls up to them; anything else that can't hold `part` becomes an object.
+fn child_slot<'a>(container: &'a mut Value, part: &str) -> Result<&'a mut Value> {
+    let index = part.parse::<usize>().ok().filter(|_| container.is_array());
+    if index.is_none() && !container.is_object() {
+        *container = serde_json::json!({});
+    }
+    match (container, index) {
+        (Value::Array(items), Some(index)) => {
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            Ok(&mut items[index])
+        }
+        (Value::Object(map), _) => Ok(map.entry(part.to_string()).or_insert(Value::Null)),
+        _ => Err(anyhow!("Failed to set nested value")),
+    }
+}
+
+/// Sets a dotted path, creating objects on the way. A string (or array) in
+/// the way is replaced, e.g. when a key became a group in the source file.
+/// Numeric parts index into arrays that are already there.
+pub fn set_nested_value(value: &mut Value, path: &str, new_value: Value) -> Result<()> {
+    let parts: Vec<&str> = path.split('.').collect();
+    let (last, parents) = parts
+        .split_last()
+        .ok_or_else(|| anyhow!("Failed to set nested value"))?;
+    let mut current = value;
+
+    for part in parents {
+        current = child_slot(current, part)?;
+    }
+
+    *child_slot(current, last)? = new_value;
+    Ok(())
+}
+
+/// Like `set_nested_value`, but creates arrays where `source` has them, so
+/// `steps.0` becomes the first item of a list rather than a `"0"` key.
+pub fn set_nested_value_like(
+    value: &mut Value,
+    source: &Value,
+    path: &str,
+    new_value: Value,
+) -> Result<()> {
+    let parts: Vec<&str> = path.split('.').collect();
+    let mut prefix = String::new();
+    for part in &parts[..parts.len() - 1] {
+        if !prefix.is_empty() {
+            prefix.push('.');
+        }
+        prefix.push_str(part);
+        let is_list = get_nested_value(source, &prefix).is_some_and(Value::is_array);
+        if is_list && get_nested_value(value, &prefix).is_none_or(Value::is_null) {
+            set_nested_value(value, &prefix, serde_json::json!([]))?;
+        }
+    }
+    set_nested_value(value, path, new_value)
+}
+
+/// Paths of all strings, with array items as indexes (`steps.0.title`).
+pub fn extract_all_paths(value: &Value, prefix: &str) -> Vec<String> {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(key, val)| (key.clone(), val)).collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, val)| (index.to_string(), val))
+            .collect(),
+        _ => return Vec::new(),
+    };
+
+    let mut paths = Vec::new();
+    for (key, val) in children {
+        let current_path = if prefix.is_empty() {
+            key
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        if val.is_string() {
+            paths.push(current_path);
+        } else {
+            paths.extend(extract_all_paths(val, &current_path));
+        }
+    }
+
+    paths
+}
+
+/// Paths with no value in `target`. Nulls count as missing, they fill the
+/// gaps of arrays that were only partly translated.
+pub fn find_missing_paths(target: &Value, all_paths: &[String]) -> Vec<String> {
+    let mut missing = Vec::new();
+
+    for path in all_paths {
+        if get_nested_value(target, path).is_none_or(|value| value.is_null()) {
+            missing.push(path.clone());
+        }
+    }
+
+    missing
+}
+
+pub fn get_nested_value<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let parts: Vec<&str> = path.split('.').collect();
+    let mut current = value;
+
+    for part in parts {
+        match current {
+            Value::Object(map) => {
+                current = map.get(part)?;
+            }
+            Value::Array(items) => {
+                current = items.get(part.parse::<usize>().ok()?)?;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(current)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TranslationProgress {
+    pub total_keys: usize,
+    pub translated: usize,
+    /// Keys that are absent or have an empty value
+    pub missing_keys: Vec<String>,
+}
+
+impl TranslationProgress {
+    pub fn missing(&self) -> usize {
+        self.missing_keys.len()
+    }
+
+    pub fn percentage(&self) -> f64 {
+        if self.total_keys == 0 {
+            100.0
+        } else {
+            self.translated as f64 / self.total_keys as f64 * 100.0
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.missing_keys.is_empty()
+    }
+}
+
+pub fn calculate_translation_progress(source: &Value, target: &Value) -> TranslationProgress {
+    let all_paths = extract_all_paths(source, "");
+    let mut missing_keys = find_missing_paths(target, &all_paths);
+    // Empty strings are placeholders left by hand, not translations
+    missing_keys.extend(
+        all_paths
+            .iter()
+            .filter(|path| {
+                get_nested_value(target, path)
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|text| text.trim().is_empty())
+            })
+            .cloned(),
+    );
+    missing_keys.sort();
+
+    TranslationProgress {
+        total_keys: all_paths.len(),
+        translated: all_paths.len() - missing_keys.len(),
+        missing_keys,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageStatus {
+    pub code: String,
+    pub file: String,
+    pub progress: TranslationProgress,
+    pub last_modified: Option<DateTime<Local>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncStatistics {
+    pub source_file: String,
+    pub total_keys: usize,
+    pub languages: Vec<LanguageStatus>,
+}
+
+impl SyncStatistics {
+    pub fn complete_languages(&self) -> usize {
+        self.languages
+            .iter()
+            .filter(|language| language.progress.is_complete())
+            .count()
+    }
+
+    pub fn overall_percentage(&self) -> f64 {
+        let total: usize = self.languages.iter().map(|l| l.progress.total_keys).sum();
+        let translated: usize = self.languages.iter().map(|l| l.progress.translated).sum();
+        if total == 0 {
+            100.0
+        } else {
+            translated as f64 / total as f64 * 100.0
+        }
+    }
+}
+
+/// Collects per-language progress for every target file next to the source.
+pub fn get_sync_statistics(messages_dir: &Path, source_file: &str) -> Result<SyncStatistics> {
+    let source_path = messages_dir.join(source_file);
+    if !source_path.exists() {
+        return Err(anyhow!("Source file not found: {}", source_path.display()));
+    }
+    let source = SourceMessages::load(&source_path)?;
+
+    let mut languages = Vec::new();
+    for language in discover_language_files(messages_dir, source_file)? {
+        let file = language
+            .file_name
+            .clone()
+            .unwrap_or_else(|| source.file_name_for(&language.code));
+        let path = messages_dir.join(&file);
+        let target = source.read_target(&path)?;
+        let last_modified = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(DateTime::<Local>::from);
+
+        languages.push(LanguageStatus {
+            code: language.code,
+            file,
+            progress: calculate_translation_progress(&source.messages, &target),
+            last_modified,
+        });
+    }
+
+    Ok(SyncStatistics {
+        source_file: source_file.to_string(),
+        total_keys: extract_all_paths(&source.messages, "").len(),
+        languages,
+    })
+}
+
+pub fn progress_bar(percentage: f64, width: usize) -> String {
+    let filled = ((percentage / 100.0) * width as f64).round() as usize;
+    let filled = filled.min(width);
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(width - filled));
+
+    if percentage >= 100.0 {
+        bar.green().to_string()
+    } else if percentage >= 75.0 {
+        bar.yellow().to_string()
+    } else {
+        bar.red().to_string()
+    }
+}
+
+pub fn print_translation_status(stats: &SyncStatistics) {
+    println!("{}", "🌍 Translation Status".cyan().bold());
+    println!(
+        "{}",
+        format!("Source: {} ({} keys)", stats.source_file, stats.total_keys).dimmed()
+    );
+    println!("{}", "═".repeat(100).dimmed());
+    println!(
+        "{:<10} {:<14} {:>6} {:>11} {:>8} {:>7}  {:<20}  Last modified",
+        "Language", "File", "Keys", "Translated", "Missing", "%", "Progress"
+    );
+    println!("{}", "─".repeat(100).dimmed());
+
+    for language in &stats.languages {
+        let progress = &language.progress;
+        let missing = format!("{:>8}", progress.missing());
+        let missing = if progress.missing() == 0 {
+            missing.green()
+        } else {
+            missing.red()
+        };
+        println!(
+            "{:<10} {:<14} {:>6} {:>11} {} {:>6.1}%  {}  {}",
+            language.code,
+            language.file,
+            progress.total_keys,
+            progress.translated,
+            missing,
+            progress.percentage(),
+            progress_bar(progress.percentage(), 20),
+            language
+                .last_modified
+                .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "-".to_string())
+                .dimmed()
+        );
+    }
+    println!("{}", "─".repeat(100).dimmed());
+
+    println!(
+        "{}",
+        format!(
+            "📊 {}/{} languages complete, {:.1}% overall",
+            stats.complete_languages(),
+            stats.languages.len(),
+            stats.overall_percentage()
+        )
+        .bold()
+    );
+}
+
+pub async fn show_translation_status(
+    messages_dir: Option<&str>,
+    source_file: Option<&str>,
+) -> Result<()> {
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let messages_dir = messages_dir.unwrap_or(&app_config.messages_dir);
+    let source_file = source_file.unwrap_or(&app_config.source_file);
+
+    let stats = get_sync_statistics(Path::new(messages_dir), source_file)?;
+    if crate::utils::output::is_json_output() {
+        return crate::utils::output::print_json(&stats);
+    }
+    if stats.languages.is_empty() {
+        println!(
+            "{}",
+            format!("ℹ️  No translation files found in {}", messages_dir).yellow()
+        );
+        return Ok(());
+    }
+    print_translation_status(&stats);
+    Ok(())
+}
+
+/// Syncs the messages of the project in `root`. `concurrency` overrides the
+/// configured number of languages translated at the same time.
+pub async fn sync_translations_interactive(
+    root: &Path,
+    use_cache: bool,
+    concurrency: Option<usize>,
+) -> Result<()> {
+    ensure_not_sandboxed("sync-translations")?;
+    let config_manager = ConfigManager::new().await?;
+    // Check if this is the first run
+    if config_manager.is_first_run().await? {
+        println!(
             "{}",
             "👋 Welcome to Nitroterm Translation Sync!".cyan().bold()
         );
         println!("{}", "Let's set up your configuration...".blue());
         println!();
         let app_config = config_manager.interactive_setup().await?;
-        if app_config.gemini_api_key.is_none() {
+        if provider_settings(&app_config).1.is_none() {
             println!("{}", "❌ Cannot proceed without API key!".red());
             return Ok(());
         }
-        let translation_config = TranslationConfig::from(app_config);
+        let mut translation_config = TranslationConfig::from(app_config);
+        translation_config.use_cache = use_cache;
+        if let Some(concurrency) = concurrency {
+            translation_config.concurrency = concurrency;
+        }
         println!(
             "\n{}",
             "🚀 Starting first translation sync...".green().bold()
         );
-        sync_translations_with_config(translation_config).await
+        sync_translations_with_config(root, translation_config).await
     } else {
         let app_config = config_manager.get_config().await?;
-        if app_config.gemini_api_key.is_none() {
-            println!("{}", "❌ Gemini API key not configured!".red());
+        let (provider, api_key, _) = provider_settings(&app_config);
+        if api_key.is_none() {
+            println!(
+                "{}",
+                format!("❌ {} API key not configured!", provider.display_name()).red()
+            );
             println!(
                 "{}",
                 "Run 'nitroterm config' to set up your API key.".blue()
             );
             return Ok(());
         }
-        let translation_config = TranslationConfig::from(app_config);
-        sync_translations_with_config(translation_config).await
+        let mut translation_config = TranslationConfig::from(app_config);
+        translation_config.use_cache = use_cache;
+        if let Some(concurrency) = concurrency {
+            translation_config.concurrency = concurrency;
+        }
+        sync_translations_with_config(root, translation_config).await
     }
 }
 
-pub async fn sync_translations_with_config(config: TranslationConfig) -> Result<()> {
-    let sync = TranslationSync::new(config);
-    sync.sync_translations().await
+/// Runs the sync with a relative `messages_dir` taken from `root`.
+pub async fn sync_translations_with_config(
+    root: &Path,
+    mut config: TranslationConfig,
+) -> Result<()> {
+    config.messages_dir = root.join(&config.messages_dir);
+    if config.provider == ProviderKind::Gemini {
+        let base_url = api_base_url("GEMINI_BASE_URL", GEMINI_API_URL);
+        match check_model(&base_url, &config.api_key, &config.model).await {
+            Ok(ModelCheck::Available) => {}
+            Ok(ModelCheck::Unknown { suggestions }) => {
+                return Err(unknown_model_error(&config.model, &suggestions));
+            }
+            // Offline or a flaky API: let the sync itself report it
+            Err(e) => println!(
+                "{}",
+                format!("⚠️  Could not check the Gemini model: {}", e).dimmed()
+            ),
+        }
+    }
+    let sync = TranslationSync::new(config.clone())?.with_glossary(load_glossary(root)?);
+    sync.sync_translations().await?;
+
+    // Keys the sync couldn't fill are remembered for `nitroterm status`
+    if let Ok(statistics) = get_sync_statistics(&config.messages_dir, &config.source_file) {
+        record_run(root, Tracked::Translations, |record| {
+            record.untranslated = untranslated_keys(&statistics)
+        });
+    }
+    Ok(())
 }
 
 // Config management commands
@@ -634,3 +2056,439 @@ pub async fn reset_config() -> Result<()> {
     let config_manager = ConfigManager::new().await?;
     config_manager.reset_config().await
 }
+
+pub async fn set_translation_provider(
+    name: &str,
+    api_key: Option<&str>,
+    model: Option<&str>,
+) -> Result<()> {
+    let provider = name.parse::<ProviderKind>()?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.translation_provider = provider.name().to_string();
+
+    let (key_slot, model_slot) = match provider {
+        ProviderKind::Gemini => (&mut config.gemini_api_key, &mut config.gemini_model),
+        ProviderKind::OpenAi => (&mut config.openai_api_key, &mut config.openai_model),
+        ProviderKind::Anthropic => (&mut config.anthropic_api_key, &mut config.anthropic_model),
+    };
+    if let Some(api_key) = api_key {
+        let storage = config.secret_storage.parse().unwrap_or_default();
+        let stored = secrets::protect(provider.api_key_name(), api_key, storage)?;
+        *key_slot = Some(stored);
+    }
+    if let Some(model) = model {
+        *model_slot = model.to_string();
+    }
+    let model = model_slot.clone();
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🤖 Translation provider set to {} ({})",
+            provider.display_name(),
+            model
+        )
+        .green()
+    );
+    if provider_settings(&config).1.is_none() {
+        println!(
+            "{}",
+            format!(
+                "⚠️  No API key stored. Use --api-key or set {}",
+                provider.api_key_env()
+            )
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+/// Retries per provider request on 429 and 5xx replies; 0 turns them off.
+pub async fn set_max_retries(count: u32) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.translation_max_retries = count;
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🔁 Translation requests are retried up to {} time(s)",
+            count
+        )
+        .green()
+    );
+    Ok(())
+}
+
+/// Languages translated at the same time; 1 syncs them one after another.
+pub async fn set_concurrency(count: usize) -> Result<()> {
+    if count == 0 {
+        return Err(anyhow!("Concurrency must be at least 1"));
+    }
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    config.translation_concurrency = count;
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!("⚡ Up to {} language(s) are translated at a time", count).green()
+    );
+    Ok(())
+}
+
+/// Stores the API key of `provider`, typed without echo, as plain text, in
+/// the OS keychain or encrypted. `storage` also becomes the default for
+/// keys stored later; an empty answer moves the current key there.
+pub async fn set_api_key(provider: &str, storage: Option<SecretStorage>) -> Result<()> {
+    let provider = provider.parse::<ProviderKind>()?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+    let storage = match storage {
+        Some(storage) => storage,
+        None => config.secret_storage.parse()?,
+    };
+
+    let current = api_key_slot(&mut config, provider).clone();
+    let answer = prompt::ask_secret(
+        format!(
+            "🔑 {} API key{}: ",
+            provider.display_name(),
+            if current.is_some() {
+                " [Enter keeps the current one]"
+            } else {
+                ""
+            }
+        )
+        .cyan(),
+        &format!("set {}", provider.api_key_env()),
+    )?;
+    let api_key = match (answer.is_empty(), &current) {
+        (false, _) => answer,
+        (true, Some(stored)) => secrets::reveal(provider.api_key_name(), stored)?,
+        (true, None) => return Err(anyhow!("No API key entered")),
+    };
+
+    // A key moving out of the keychain shouldn't stay behind in it
+    if let Some(stored) = &current {
+        if storage != SecretStorage::Keychain {
+            secrets::forget(provider.api_key_name(), stored)?;
+        }
+    }
+    let stored = secrets::protect(provider.api_key_name(), &api_key, storage)?;
+    *api_key_slot(&mut config, provider) = Some(stored);
+    config.secret_storage = storage.name().to_string();
+    config_manager.save_config(&config).await?;
+
+    let place = match storage {
+        SecretStorage::Plain => "in the config",
+        SecretStorage::Keychain => "in the OS keychain",
+        SecretStorage::Encrypted => "encrypted in the config",
+    };
+    println!(
+        "{}",
+        format!("🔑 {} API key stored {}", provider.display_name(), place).green()
+    );
+    Ok(())
+}
+
+/// Removes the stored API key of `provider`, from the keychain too.
+pub async fn unset_api_key(provider: &str) -> Result<()> {
+    let provider = provider.parse::<ProviderKind>()?;
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+
+    let Some(stored) = api_key_slot(&mut config, provider).take() else {
+        println!(
+            "{}",
+            format!("No {} API key stored", provider.display_name()).dimmed()
+        );
+        return Ok(());
+    };
+    secrets::forget(provider.api_key_name(), &stored)?;
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!("🗑️  {} API key removed", provider.display_name()).green()
+    );
+    if std::env::var(provider.api_key_env()).is_ok_and(|key| !key.is_empty()) {
+        println!(
+            "{}",
+            format!(
+                "💡 {} is still set in the environment",
+                provider.api_key_env()
+            )
+            .dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// Sets the provider used when the primary one fails; `none` removes it.
+pub async fn set_fallback_provider(name: &str) -> Result<()> {
+    let config_manager = ConfigManager::new().await?;
+    let mut config = config_manager.get_config().await?;
+
+    if name.eq_ignore_ascii_case("none") {
+        config.translation_fallback_provider = None;
+        config_manager.save_config(&config).await?;
+        println!("{}", "🤖 Fallback provider removed".green());
+        return Ok(());
+    }
+
+    let provider = name.parse::<ProviderKind>()?;
+    if provider == provider_settings(&config).0 {
+        return Err(anyhow!(
+            "{} is already the primary provider",
+            provider.display_name()
+        ));
+    }
+    config.translation_fallback_provider = Some(provider.name().to_string());
+    config_manager.save_config(&config).await?;
+
+    println!(
+        "{}",
+        format!(
+            "🤖 Fallback provider set to {} ({})",
+            provider.display_name(),
+            settings_for(&config, provider).1
+        )
+        .green()
+    );
+    if settings_for(&config, provider).0.is_none() {
+        println!(
+            "{}",
+            format!(
+                "⚠️  No API key stored. Use `config provider {} --api-key` or set {}",
+                provider.name(),
+                provider.api_key_env()
+            )
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+/// One sampled key translated by both providers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderComparison {
+    pub key: String,
+    pub source: String,
+    pub first: Option<String>,
+    pub second: Option<String>,
+}
+
+impl ProviderComparison {
+    pub fn is_same(&self) -> bool {
+        let normalize = |text: &Option<String>| text.as_deref().map(|t| t.trim().to_lowercase());
+        normalize(&self.first) == normalize(&self.second)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ComparisonReport {
+    pub first: &'static str,
+    pub second: &'static str,
+    pub rows: Vec<ProviderComparison>,
+    pub first_elapsed: Duration,
+    pub second_elapsed: Duration,
+}
+
+/// Up to `sample` keys spread evenly over the file, so the comparison isn't
+/// limited to the first section.
+pub fn sample_paths(paths: &[String], sample: usize) -> Vec<String> {
+    if sample == 0 || paths.is_empty() {
+        return Vec::new();
+    }
+    let step = paths.len().div_ceil(sample).max(1);
+    paths.iter().step_by(step).take(sample).cloned().collect()
+}
+
+async fn translate_with(
+    provider: &dyn TranslationProvider,
+    paths: &[String],
+    source_json: &Value,
+    language: &Language,
+    glossary: &Glossary,
+) -> Result<(HashMap<String, String>, Duration)> {
+    let started = std::time::Instant::now();
+    let mut translations = HashMap::new();
+    for chunk in paths.chunks(10) {
+        if let Some((prompt, mapping)) = translation_prompt(chunk, source_json, language, glossary)
+        {
+            let response = provider
+                .complete(&prompt)
+                .await
+                .map_err(|e| anyhow!("{} failed: {}", provider.name(), e))?;
+            translations.extend(parse_translation_response(&response, &mapping));
+        }
+    }
+    Ok((translations, started.elapsed()))
+}
+
+/// Translates the same keys with both providers.
+pub async fn compare_providers(
+    first: &dyn TranslationProvider,
+    second: &dyn TranslationProvider,
+    source_json: &Value,
+    paths: &[String],
+    language: &Language,
+    glossary: &Glossary,
+) -> Result<ComparisonReport> {
+    let (mut first_translations, first_elapsed) =
+        translate_with(first, paths, source_json, language, glossary).await?;
+    let (mut second_translations, second_elapsed) =
+        translate_with(second, paths, source_json, language, glossary).await?;
+
+    let rows = paths
+        .iter()
+        .filter_map(|path| {
+            let source = get_nested_value(source_json, path)?.as_str()?;
+            Some(ProviderComparison {
+                key: path.clone(),
+                source: source.to_string(),
+                first: first_translations.remove(path),
+                second: second_translations.remove(path),
+            })
+        })
+        .collect();
+
+    Ok(ComparisonReport {
+        first: first.name(),
+        second: second.name(),
+        rows,
+        first_elapsed,
+        second_elapsed,
+    })
+}
+
+fn fit(text: &str, width: usize) -> String {
+    let text = text.replace('\n', " ");
+    if text.chars().count() <= width {
+        format!("{:<width$}", text, width = width)
+    } else {
+        let truncated: String = text.chars().take(width - 1).collect();
+        format!("{}…", truncated)
+    }
+}
+
+pub fn print_comparison(report: &ComparisonReport, language: &Language) {
+    const WIDTH: usize = 30;
+    println!(
+        "\n{}",
+        format!(
+            "⚖️  {} vs {} → {} {}",
+            report.first, report.second, language.flag, language.name
+        )
+        .cyan()
+        .bold()
+    );
+    println!(
+        "   {} │ {} │ {}",
+        fit("Source", WIDTH).bold(),
+        fit(report.first, WIDTH).bold(),
+        fit(report.second, WIDTH).bold()
+    );
+    println!("{}", "─".repeat(WIDTH * 3 + 9).dimmed());
+
+    let missing = "(missing)".to_string();
+    for row in &report.rows {
+        let marker = if row.is_same() {
+            "=".green()
+        } else {
+            "≠".yellow()
+        };
+        println!("{} {}", marker, row.key.dimmed());
+        println!(
+            "   {} │ {} │ {}",
+            fit(&row.source, WIDTH),
+            fit(row.first.as_ref().unwrap_or(&missing), WIDTH),
+            fit(row.second.as_ref().unwrap_or(&missing), WIDTH)
+        );
+    }
+
+    let same = report.rows.iter().filter(|row| row.is_same()).count();
+    println!(
+        "\n{}",
+        format!(
+            "📊 {}/{} identical · {} {:.1}s · {} {:.1}s",
+            same,
+            report.rows.len(),
+            report.first,
+            report.first_elapsed.as_secs_f64(),
+            report.second,
+            report.second_elapsed.as_secs_f64()
+        )
+        .blue()
+    );
+}
+
+/// `sync-translations --compare-providers`: translates a sample of keys with
+/// two providers and prints them side by side. Nothing is written.
+pub async fn run_provider_comparison(
+    root: &Path,
+    providers: (ProviderKind, ProviderKind),
+    sample: usize,
+    language: Option<&str>,
+) -> Result<()> {
+    ensure_not_sandboxed("sync-translations --compare-providers")?;
+    let app_config = ConfigManager::new_quiet().await?.get_config().await?;
+
+    let mut built = Vec::new();
+    for provider in [providers.0, providers.1] {
+        let (api_key, model) = settings_for(&app_config, provider);
+        let api_key = api_key.ok_or(ConfigError::MissingApiKey {
+            provider: provider.display_name(),
+            env: provider.api_key_env(),
+        })?;
+        built.push(build_provider(provider, &api_key, &model)?);
+    }
+
+    let messages_dir = root.join(&app_config.messages_dir);
+    let source = SourceMessages::load(&messages_dir.join(&app_config.source_file))?;
+    let language = match language {
+        Some(code) => Language::from_code(code),
+        None => discover_language_files(&messages_dir, &app_config.source_file)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No target languages found, pass --lang"))?,
+    };
+
+    let paths = sample_paths(&extract_all_paths(&source.messages, ""), sample);
+    println!(
+        "{}",
+        format!(
+            "🔬 Comparing {} keys with {} and {}...",
+            paths.len(),
+            providers.0.display_name(),
+            providers.1.display_name()
+        )
+        .cyan()
+    );
+
+    let report = compare_providers(
+        built[0].as_ref(),
+        built[1].as_ref(),
+        &source.messages,
+        &paths,
+        &language,
+        &load_glossary(root)?,
+    )
+    .await?;
+    print_comparison(&report, &language);
+    Ok(())
+}
+
+pub fn clear_translation_cache() -> Result<()> {
+    ensure_not_sandboxed("Translation cache")?;
+    let removed = crate::commands::translation_cache::clear_cache(Path::new(CACHE_FILE))?;
+    println!(
+        "{}",
+        format!("🗑️  Cleared {} cached translations", removed).green()
+    );
+    Ok(())
+}
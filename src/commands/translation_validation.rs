@@ -0,0 +1,339 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::translation_format::SourceMessages;
+use crate::commands::translation_icu::{
+    check_translation as check_icu_translation, is_icu_message,
+};
+use crate::commands::translation_sync::{
+    discover_language_files, extract_all_paths, get_nested_value,
+};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    Missing,
+    Empty,
+    Placeholders,
+    HtmlTags,
+    Identical,
+}
+
+impl IssueKind {
+    /// Identical text is often fine (brand names, "OK"), so it only fails
+    /// the run in strict mode.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, IssueKind::Identical)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            IssueKind::Missing => "missing",
+            IssueKind::Empty => "empty",
+            IssueKind::Placeholders => "placeholders",
+            IssueKind::HtmlTags => "html tags",
+            IssueKind::Identical => "identical",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationIssue {
+    pub key: String,
+    pub kind: IssueKind,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageReport {
+    pub code: String,
+    pub file: String,
+    pub total_keys: usize,
+    pub issues: Vec<TranslationIssue>,
+}
+
+impl LanguageReport {
+    pub fn count(&self, kind: IssueKind) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.kind == kind)
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    pub source_file: String,
+    pub languages: Vec<LanguageReport>,
+}
+
+impl ValidationReport {
+    pub fn error_count(&self) -> usize {
+        self.issues().filter(|issue| issue.kind.is_error()).count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.issues().filter(|issue| !issue.kind.is_error()).count()
+    }
+
+    fn issues(&self) -> impl Iterator<Item = &TranslationIssue> {
+        self.languages.iter().flat_map(|language| &language.issues)
+    }
+}
+
+/// `{appName}`, `{{count}}`, `%s`, `%(name)s` and `%1$s` style placeholders, sorted.
+pub fn extract_placeholders(text: &str) -> Vec<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(r"\{\{\s*[\w.]+\s*\}\}|\{\s*[\w.]+\s*(,[^{}]*)?\}|%(\d+\$|\([\w]+\))?[sdif@]")
+            .unwrap()
+    });
+
+    let mut placeholders: Vec<String> = pattern
+        .find_iter(text)
+        .map(|m| m.as_str().replace(' ', ""))
+        .collect();
+    placeholders.sort();
+    placeholders
+}
+
+/// Opening and closing tag names (`b`, `/b`, `br`), sorted.
+pub fn extract_tags(text: &str) -> Vec<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern =
+        PATTERN.get_or_init(|| Regex::new(r"<(/?)([a-zA-Z][a-zA-Z0-9-]*)\b[^<>]*?/?>").unwrap());
+
+    let mut tags: Vec<String> = pattern
+        .captures_iter(text)
+        .map(|c| format!("{}{}", &c[1], c[2].to_lowercase()))
+        .collect();
+    tags.sort();
+    tags
+}
+
+fn difference(expected: &[String], actual: &[String]) -> String {
+    let missing: Vec<_> = expected.iter().filter(|p| !actual.contains(p)).collect();
+    let extra: Vec<_> = actual.iter().filter(|p| !expected.contains(p)).collect();
+
+    let mut parts = Vec::new();
+    if !missing.is_empty() {
+        parts.push(format!(
+            "missing {}",
+            missing
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+    }
+    if !extra.is_empty() {
+        parts.push(format!(
+            "unexpected {}",
+            extra
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ));
+    }
+    if parts.is_empty() {
+        // Same items, different counts
+        parts.push(format!(
+            "expected {}, found {}",
+            expected.len(),
+            actual.len()
+        ));
+    }
+    parts.join(", ")
+}
+
+/// Compares one target catalog against the source.
+pub fn validate_messages(source: &Value, target: &Value) -> Vec<TranslationIssue> {
+    let mut issues = Vec::new();
+    let mut issue = |key: &str, kind: IssueKind, detail: String| {
+        issues.push(TranslationIssue {
+            key: key.to_string(),
+            kind,
+            detail,
+        })
+    };
+
+    for path in extract_all_paths(source, "") {
+        let source_text = get_nested_value(source, &path)
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+        let text = match get_nested_value(target, &path).and_then(|v| v.as_str()) {
+            Some(text) => text,
+            None => {
+                issue(&path, IssueKind::Missing, "no translation".to_string());
+                continue;
+            }
+        };
+
+        if text.trim().is_empty() {
+            if !source_text.trim().is_empty() {
+                issue(&path, IssueKind::Empty, "translation is empty".to_string());
+            }
+            continue;
+        }
+
+        // Plural branches differ per language, so placeholder counts can't match
+        if is_icu_message(source_text) {
+            if let Some(problem) = check_icu_translation(source_text, text) {
+                issue(&path, IssueKind::Placeholders, problem);
+            }
+        } else {
+            let expected = extract_placeholders(source_text);
+            let actual = extract_placeholders(text);
+            if expected != actual {
+                issue(
+                    &path,
+                    IssueKind::Placeholders,
+                    difference(&expected, &actual),
+                );
+            }
+        }
+
+        let expected = extract_tags(source_text);
+        let actual = extract_tags(text);
+        if expected != actual {
+            issue(&path, IssueKind::HtmlTags, difference(&expected, &actual));
+        }
+
+        if text == source_text && source_text.chars().any(|c| c.is_alphabetic()) {
+            issue(
+                &path,
+                IssueKind::Identical,
+                "same as the source text".to_string(),
+            );
+        }
+    }
+
+    issues
+}
+
+pub fn validate_translations(messages_dir: &Path, source_file: &str) -> Result<ValidationReport> {
+    let source_path = messages_dir.join(source_file);
+    if !source_path.exists() {
+        return Err(anyhow!("Source file not found: {}", source_path.display()));
+    }
+    let source = SourceMessages::load(&source_path)?;
+    let total_keys = extract_all_paths(&source.messages, "").len();
+
+    let mut languages = Vec::new();
+    for language in discover_language_files(messages_dir, source_file)? {
+        let file = language
+            .file_name
+            .clone()
+            .unwrap_or_else(|| source.file_name_for(&language.code));
+        let target = source.read_target(&messages_dir.join(&file))?;
+
+        languages.push(LanguageReport {
+            code: language.code,
+            file,
+            total_keys,
+            issues: validate_messages(&source.messages, &target),
+        });
+    }
+
+    Ok(ValidationReport {
+        source_file: source_file.to_string(),
+        languages,
+    })
+}
+
+pub fn print_validation_report(report: &ValidationReport) {
+    const KINDS: [IssueKind; 5] = [
+        IssueKind::Missing,
+        IssueKind::Empty,
+        IssueKind::Placeholders,
+        IssueKind::HtmlTags,
+        IssueKind::Identical,
+    ];
+
+    println!("{}", "🔍 Translation Validation".cyan().bold());
+    println!("{}", "═".repeat(78).dimmed());
+    print!("{:<10} {:>6}", "Language", "Keys");
+    for kind in KINDS {
+        print!(" {:>12}", kind.label());
+    }
+    println!();
+    println!("{}", "─".repeat(78).dimmed());
+
+    for language in &report.languages {
+        print!("{:<10} {:>6}", language.code, language.total_keys);
+        for kind in KINDS {
+            let count = language.count(kind);
+            let cell = format!("{:>12}", count);
+            let cell = match count {
+                0 => cell.green(),
+                _ if kind.is_error() => cell.red(),
+                _ => cell.yellow(),
+            };
+            print!(" {}", cell);
+        }
+        println!();
+    }
+    println!("{}", "─".repeat(78).dimmed());
+
+    for language in &report.languages {
+        if language.issues.is_empty() {
+            continue;
+        }
+        println!("\n{}", format!("📄 {}", language.file).bold());
+        for issue in &language.issues {
+            let label = format!("[{}]", issue.kind.label());
+            let label = if issue.kind.is_error() {
+                label.red()
+            } else {
+                label.yellow()
+            };
+            println!("  {} {} {}", label, issue.key, issue.detail.dimmed());
+        }
+    }
+
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Summary: {} errors, {} warnings in {} languages",
+            report.error_count(),
+            report.warning_count(),
+            report.languages.len()
+        )
+        .bold()
+    );
+}
+
+/// Returns whether validation passed. Warnings only fail in strict mode.
+pub async fn run_validate_translations(
+    messages_dir: Option<&str>,
+    source_file: Option<&str>,
+    json_report: Option<&str>,
+    strict: bool,
+) -> Result<bool> {
+    // The config is optional here, so this also works in CI and --sandbox mode
+    let app_config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let messages_dir = messages_dir.unwrap_or(&app_config.messages_dir);
+    let source_file = source_file.unwrap_or(&app_config.source_file);
+
+    let report = validate_translations(Path::new(messages_dir), source_file)?;
+    print_validation_report(&report);
+
+    if let Some(path) = json_report {
+        ensure_not_sandboxed("Writing the JSON report")?;
+        std::fs::write(path, serde_json::to_string_pretty(&report)?)?;
+        println!("{}", format!("📝 JSON report written to {}", path).dimmed());
+    }
+
+    Ok(report.error_count() == 0 && (!strict || report.warning_count() == 0))
+}
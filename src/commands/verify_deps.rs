@@ -0,0 +1,445 @@
+use crate::commands::lockfiles::{Ecosystem, LockedPackage, RegistryClient};
+use crate::commands::provenance::sha256_file;
+use crate::utils::error::ChecksFailed;
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use colored::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A digest normalized to lowercase hex, whatever encoding the lockfile used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    pub algorithm: String,
+    pub hex: String,
+}
+
+impl Checksum {
+    pub fn new(algorithm: &str, hex: &str) -> Self {
+        Self {
+            algorithm: algorithm.to_lowercase(),
+            hex: hex.to_lowercase(),
+        }
+    }
+
+    /// `sha256:abc...`, as used by pip `--hash` and poetry
+    pub fn parse_prefixed(value: &str) -> Option<Self> {
+        let (algorithm, hex) = value.trim().split_once(':')?;
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        Some(Self::new(algorithm, hex))
+    }
+
+    /// Subresource integrity strings (`sha512-<base64>`). npm may list
+    /// several, separated by spaces.
+    pub fn parse_sri(value: &str) -> Vec<Self> {
+        value
+            .split_whitespace()
+            .filter_map(|entry| {
+                let (algorithm, encoded) = entry.split_once('-')?;
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(encoded)
+                    .ok()?;
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                Some(Self::new(algorithm, &hex))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.hex)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PinnedPackage {
+    pub package: LockedPackage,
+    pub checksums: Vec<Checksum>,
+}
+
+fn pinned(
+    name: &str,
+    version: &str,
+    ecosystem: Ecosystem,
+    checksums: Vec<Checksum>,
+) -> PinnedPackage {
+    PinnedPackage {
+        package: LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            ecosystem,
+        },
+        checksums,
+    }
+}
+
+/// Registry packages from Cargo.lock with their `checksum` (sha256 of the .crate).
+pub fn cargo_lock_checksums(content: &str) -> Vec<PinnedPackage> {
+    let mut packages = Vec::new();
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    let mut flush = |fields: &mut Vec<(String, String)>| {
+        let get = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        let from_registry = get("source")
+            .is_some_and(|source| source.starts_with("registry+") || source.starts_with("sparse+"));
+        if let (Some(name), Some(version), true) = (get("name"), get("version"), from_registry) {
+            let checksums = get("checksum")
+                .map(|hex| vec![Checksum::new("sha256", hex)])
+                .unwrap_or_default();
+            packages.push(pinned(name, version, Ecosystem::Cargo, checksums));
+        }
+        fields.clear();
+    };
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            flush(&mut fields);
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            fields.push((
+                key.trim().to_string(),
+                value.trim().trim_matches('"').to_string(),
+            ));
+        }
+    }
+    flush(&mut fields);
+
+    packages
+}
+
+/// Packages from package-lock.json with their `integrity` hashes.
+pub fn package_lock_checksums(content: &str) -> Result<Vec<PinnedPackage>> {
+    let json: Value = serde_json::from_str(content)?;
+    let mut packages = Vec::new();
+
+    let entries = json["packages"]
+        .as_object()
+        .or_else(|| json["dependencies"].as_object());
+    for (key, entry) in entries.into_iter().flatten() {
+        // v2/3 keys are install paths, v1 keys are package names
+        let name = if json["packages"].is_object() {
+            match key.rsplit_once("node_modules/") {
+                Some((_, name)) => name,
+                None => continue,
+            }
+        } else {
+            key.as_str()
+        };
+        if entry["link"].as_bool() == Some(true) {
+            continue;
+        }
+        if let Some(version) = entry["version"].as_str() {
+            let checksums = entry["integrity"]
+                .as_str()
+                .map(Checksum::parse_sri)
+                .unwrap_or_default();
+            packages.push(pinned(name, version, Ecosystem::Npm, checksums));
+        }
+    }
+
+    Ok(packages)
+}
+
+/// `name==version --hash=sha256:...` requirements, as written by
+/// `pip-compile --generate-hashes` or `pip freeze` plus `pip hash`.
+pub fn requirements_checksums(content: &str) -> Vec<PinnedPackage> {
+    let joined = content
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or(line))
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace("\\\n", " ");
+
+    joined
+        .lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let spec = tokens.next()?;
+            let (name, version) = spec.split_once("==")?;
+            // Drop extras and environment markers: `pkg[extra]==1.0; python_version < "3.11"`
+            let name = name.split('[').next().unwrap_or(name);
+            let version = version.split(';').next().unwrap_or(version);
+            let checksums = tokens
+                .filter_map(|token| token.strip_prefix("--hash="))
+                .filter_map(Checksum::parse_prefixed)
+                .collect();
+            Some(pinned(name, version, Ecosystem::PyPI, checksums))
+        })
+        .collect()
+}
+
+/// Collects pinned checksums from every supported lockfile in `dir`.
+pub fn read_pinned_packages(dir: &Path) -> Result<Vec<PinnedPackage>> {
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+    let mut packages = Vec::new();
+
+    if let Some(content) = read("Cargo.lock") {
+        packages.extend(cargo_lock_checksums(&content));
+    }
+    if let Some(content) = read("package-lock.json") {
+        packages.extend(package_lock_checksums(&content)?);
+    }
+    if let Some(content) = read("requirements.txt") {
+        packages.extend(requirements_checksums(&content));
+    }
+
+    let mut seen = HashSet::new();
+    packages.retain(|p| {
+        seen.insert((
+            p.package.ecosystem,
+            p.package.name.clone(),
+            p.package.version.clone(),
+        ))
+    });
+    Ok(packages)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyStatus {
+    Verified,
+    Mismatch(String),
+    Unverifiable(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyResult {
+    pub package: LockedPackage,
+    pub status: VerifyStatus,
+}
+
+/// Every pinned digest the registry can vouch for must be one it publishes.
+/// Digests in algorithms the registry doesn't publish are ignored.
+pub fn compare_checksums(pinned: &[Checksum], published: &[Checksum]) -> VerifyStatus {
+    if pinned.is_empty() {
+        return VerifyStatus::Unverifiable("no checksum in lockfile".to_string());
+    }
+    let algorithms: HashSet<&str> = published.iter().map(|c| c.algorithm.as_str()).collect();
+    let comparable: Vec<&Checksum> = pinned
+        .iter()
+        .filter(|c| algorithms.contains(c.algorithm.as_str()))
+        .collect();
+
+    if comparable.is_empty() {
+        let pinned_algorithms: Vec<&str> = pinned.iter().map(|c| c.algorithm.as_str()).collect();
+        return VerifyStatus::Unverifiable(format!(
+            "registry publishes no {} digest",
+            pinned_algorithms.join("/")
+        ));
+    }
+    match comparable.iter().find(|c| !published.contains(c)) {
+        Some(unknown) => VerifyStatus::Mismatch(format!(
+            "lockfile has {}, not published by the registry",
+            unknown
+        )),
+        None => VerifyStatus::Verified,
+    }
+}
+
+async fn published_checksums(
+    registry: &RegistryClient,
+    package: &LockedPackage,
+) -> Result<Vec<Checksum>> {
+    match package.ecosystem {
+        Ecosystem::Cargo => {
+            let url = format!(
+                "{}/{}/{}",
+                registry.crates_url, package.name, package.version
+            );
+            let json = registry.get_json(&url).await?;
+            let hex = json["version"]["checksum"]
+                .as_str()
+                .ok_or_else(|| anyhow!("No checksum in crates.io response"))?;
+            Ok(vec![Checksum::new("sha256", hex)])
+        }
+        Ecosystem::Npm => {
+            let url = format!(
+                "{}/{}/{}",
+                registry.npm_url,
+                package.name.replace('/', "%2F"),
+                package.version
+            );
+            let json = registry.get_json(&url).await?;
+            let dist = &json["dist"];
+            let mut checksums = dist["integrity"]
+                .as_str()
+                .map(Checksum::parse_sri)
+                .unwrap_or_default();
+            if let Some(shasum) = dist["shasum"].as_str() {
+                checksums.push(Checksum::new("sha1", shasum));
+            }
+            Ok(checksums)
+        }
+        Ecosystem::PyPI => {
+            let url = format!(
+                "{}/{}/{}/json",
+                registry.pypi_url, package.name, package.version
+            );
+            let json = registry.get_json(&url).await?;
+            Ok(json["urls"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|file| file["digests"]["sha256"].as_str())
+                .map(|hex| Checksum::new("sha256", hex))
+                .collect())
+        }
+    }
+}
+
+/// Downloaded `.crate` files in `$CARGO_HOME/registry/cache/<index>/`.
+fn cached_crate(name: &str, version: &str) -> Option<PathBuf> {
+    let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".cargo")))?;
+    let file = format!("{}-{}.crate", name, version);
+    std::fs::read_dir(cargo_home.join("registry").join("cache"))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join(&file))
+        .find(|path| path.is_file())
+}
+
+pub async fn verify_package(registry: &RegistryClient, pinned: &PinnedPackage) -> VerifyStatus {
+    let status = match published_checksums(registry, &pinned.package).await {
+        Ok(published) => compare_checksums(&pinned.checksums, &published),
+        Err(e) => VerifyStatus::Unverifiable(e.to_string()),
+    };
+
+    // A corrupted local cache is as bad as a tampered registry
+    if pinned.package.ecosystem == Ecosystem::Cargo && !matches!(status, VerifyStatus::Mismatch(_))
+    {
+        if let (Some(path), Some(expected)) = (
+            cached_crate(&pinned.package.name, &pinned.package.version),
+            pinned.checksums.first(),
+        ) {
+            if let Ok(actual) = sha256_file(&path) {
+                if actual != expected.hex {
+                    return VerifyStatus::Mismatch(format!(
+                        "cached {} has sha256:{}",
+                        path.display(),
+                        actual
+                    ));
+                }
+            }
+        }
+    }
+
+    status
+}
+
+pub async fn verify_dependencies(dir: &Path) -> Result<Vec<VerifyResult>> {
+    let packages = read_pinned_packages(dir)?;
+    if packages.is_empty() {
+        return Err(anyhow!(
+            "No Cargo.lock, package-lock.json or hashed requirements.txt found in {}",
+            dir.display()
+        ));
+    }
+
+    let registry = RegistryClient::new()?;
+    let mut results = Vec::new();
+    for pinned in &packages {
+        results.push(VerifyResult {
+            package: pinned.package.clone(),
+            status: verify_package(&registry, pinned).await,
+        });
+    }
+    Ok(results)
+}
+
+pub async fn run_verify_deps(path: Option<&str>, strict: bool) -> Result<()> {
+    let dir = Path::new(path.unwrap_or("."));
+    println!(
+        "{}",
+        "🔐 Verifying lockfile checksums against registries..."
+            .cyan()
+            .bold()
+    );
+    let results = verify_dependencies(dir).await?;
+
+    let mismatched: Vec<&VerifyResult> = results
+        .iter()
+        .filter(|r| matches!(r.status, VerifyStatus::Mismatch(_)))
+        .collect();
+    let unverifiable: Vec<&VerifyResult> = results
+        .iter()
+        .filter(|r| matches!(r.status, VerifyStatus::Unverifiable(_)))
+        .collect();
+    let verified = results.len() - mismatched.len() - unverifiable.len();
+
+    for result in &mismatched {
+        if let VerifyStatus::Mismatch(reason) = &result.status {
+            println!(
+                "  {} {}@{} {} - {}",
+                "❌".red(),
+                result.package.name.red().bold(),
+                result.package.version,
+                format!("({})", result.package.ecosystem.name()).dimmed(),
+                reason
+            );
+        }
+    }
+    for result in &unverifiable {
+        if let VerifyStatus::Unverifiable(reason) = &result.status {
+            println!(
+                "  {} {}@{} {} - {}",
+                "⚠️".yellow(),
+                result.package.name.yellow(),
+                result.package.version,
+                format!("({})", result.package.ecosystem.name()).dimmed(),
+                reason.dimmed()
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "📊 Summary".bold());
+    println!(
+        "  {} {}",
+        "Verified:".dimmed(),
+        verified.to_string().green()
+    );
+    println!(
+        "  {} {}",
+        "Mismatched:".dimmed(),
+        mismatched.len().to_string().red()
+    );
+    println!(
+        "  {} {}",
+        "Unverifiable:".dimmed(),
+        unverifiable.len().to_string().yellow()
+    );
+
+    if !mismatched.is_empty() {
+        return Err(ChecksFailed(format!(
+            "{} package(s) don't match their registry checksums; the mirror or cache may be tampered with or corrupted",
+            mismatched.len()
+        ))
+        .into());
+    }
+    if strict && !unverifiable.is_empty() {
+        return Err(ChecksFailed(format!(
+            "{} package(s) could not be verified (--strict)",
+            unverifiable.len()
+        ))
+        .into());
+    }
+    println!(
+        "{}",
+        "✅ All verifiable dependencies match their registry checksums".green()
+    );
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::commands::config::{AppConfig, ConfigManager};
+use crate::utils::error::ChecksFailed;
+use crate::utils::git::default_branch;
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use git2::Repository;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseVerification {
+    pub tag: String,
+    pub commit: String,
+    pub branch: String,
+    pub checks: Vec<VerificationCheck>,
+}
+
+impl ReleaseVerification {
+    pub fn is_valid(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+}
+
+fn check(name: &str, passed: bool, detail: impl Into<String>) -> VerificationCheck {
+    VerificationCheck {
+        name: name.to_string(),
+        passed,
+        detail: detail.into(),
+    }
+}
+
+/// `git verify-tag` reports on stderr; the "Good signature" line names the signer.
+fn signature_detail(stderr: &str) -> Option<String> {
+    let lines: Vec<&str> = stderr
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    lines
+        .iter()
+        .find(|line| line.contains("Good") && line.contains("signature"))
+        .or(lines.last())
+        .map(|line| line.trim_start_matches("gpg: ").to_string())
+}
+
+/// Prefers the remote branch, a local one may not be pushed yet.
+fn branch_ref(runner: &dyn CommandRunner, root: &Path, branch: &str) -> Result<Option<String>> {
+    for reference in [
+        format!("refs/remotes/origin/{}", branch),
+        format!("refs/heads/{}", branch),
+    ] {
+        let output = runner.run(
+            "git",
+            &["rev-parse", "--verify", "--quiet", &reference],
+            Some(root),
+        )?;
+        if output.success {
+            return Ok(Some(reference));
+        }
+    }
+    Ok(None)
+}
+
+/// Checks that `tag` carries a valid signature and that its commit is
+/// reachable from the release branch.
+pub fn verify_release(
+    runner: &dyn CommandRunner,
+    root: &Path,
+    tag: &str,
+    branch: &str,
+) -> Result<ReleaseVerification> {
+    let commit_ref = format!("{}^{{commit}}", tag);
+    let commit = runner
+        .run(
+            "git",
+            &["rev-parse", "--verify", "--quiet", &commit_ref],
+            Some(root),
+        )?
+        .check(&format!("Tag {} lookup", tag))
+        .map_err(|_| anyhow!("Tag {} does not exist", tag))?
+        .stdout
+        .trim()
+        .to_string();
+
+    let mut checks = Vec::new();
+
+    // Lightweight tags point at the commit directly and can't carry a signature
+    let kind = runner.run("git", &["cat-file", "-t", tag], Some(root))?;
+    if kind.stdout.trim() == "tag" {
+        let verify = runner.run("git", &["verify-tag", tag], Some(root))?;
+        let fallback = if verify.success {
+            "valid signature"
+        } else {
+            "tag is not signed"
+        };
+        let detail = signature_detail(&verify.stderr).unwrap_or_else(|| fallback.to_string());
+        checks.push(check("Signature", verify.success, detail));
+    } else {
+        checks.push(check(
+            "Signature",
+            false,
+            "lightweight tag, nothing is signed",
+        ));
+    }
+
+    match branch_ref(runner, root, branch)? {
+        Some(reference) => {
+            let contained = runner
+                .run(
+                    "git",
+                    &["merge-base", "--is-ancestor", &commit, &reference],
+                    Some(root),
+                )?
+                .success;
+            let name = reference
+                .trim_start_matches("refs/remotes/")
+                .trim_start_matches("refs/heads/");
+            let detail = if contained {
+                format!("{} is on {}", &commit[..commit.len().min(7)], name)
+            } else {
+                format!("{} is not on {}", &commit[..commit.len().min(7)], name)
+            };
+            checks.push(check("Release branch", contained, detail));
+        }
+        None => checks.push(check(
+            "Release branch",
+            false,
+            format!("branch {} not found", branch),
+        )),
+    }
+
+    Ok(ReleaseVerification {
+        tag: tag.to_string(),
+        commit,
+        branch: branch.to_string(),
+        checks,
+    })
+}
+
+fn print_verification(verification: &ReleaseVerification) {
+    println!(
+        "\n{}",
+        format!("🔏 Verifying release {}", verification.tag)
+            .cyan()
+            .bold()
+    );
+    println!("{}", "─".repeat(50).dimmed());
+    for check in &verification.checks {
+        let icon = if check.passed { "✅" } else { "❌" };
+        let detail = if check.passed {
+            check.detail.green()
+        } else {
+            check.detail.red()
+        };
+        println!("  {} {}: {}", icon, check.name.bold(), detail);
+    }
+}
+
+/// Verifies a release tag. The branch comes from the flag, then
+/// `config release --branch`, then the repository's default branch.
+pub async fn run_verify_release(path: &Path, tag: &str, branch: Option<&str>) -> Result<()> {
+    ensure_not_sandboxed("verify-release")?;
+    let repo = Repository::discover(path).map_err(|e| anyhow!("Not a git repository: {}", e))?;
+    let root = repo
+        .workdir()
+        .ok_or_else(|| anyhow!("Bare repositories are not supported"))?
+        .to_path_buf();
+
+    let config = match ConfigManager::new_quiet().await {
+        Ok(manager) => manager.get_config().await.unwrap_or_default(),
+        Err(_) => AppConfig::default(),
+    };
+    let branch = branch
+        .map(str::to_string)
+        .or(config.release_branch)
+        .or_else(|| default_branch(&repo))
+        .ok_or_else(|| anyhow!("No release branch found, pass --branch"))?;
+
+    let verification = verify_release(&SystemRunner, &root, tag, &branch)?;
+    print_verification(&verification);
+
+    if !verification.is_valid() {
+        return Err(ChecksFailed(format!("{} did not pass verification", tag)).into());
+    }
+    println!(
+        "\n{}",
+        format!("🎉 {} is signed and on {}", tag, branch).green()
+    );
+    Ok(())
+}
@@ -1,35 +1,692 @@
-use crate::commands::release_notes::generate_release_notes_for_version;
-use anyhow::Result;
+use crate::commands::commit_filter::{load_commit_filter, CommitFilter};
+use crate::commands::release_notes::{
+    category_label, compare_version_tags, extract_version_from_string,
+    generate_release_notes_for_version, get_all_tags, get_commits_between_tags, is_breaking_change,
+    is_version_tag, parse_commit_type, CommitInfo, RangeSummary,
+};
+use crate::commands::selective_update::lenient_version;
+use crate::utils::document_locale::timezone;
+use crate::utils::error::ChecksFailed;
+use crate::utils::output::{is_json_output, print_json};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use crate::utils::prompt;
+use crate::utils::sandbox::ensure_not_sandboxed;
+use anyhow::{anyhow, Result};
 use colored::*;
-use std::process::Command;
+use git2::Repository;
+use regex::Regex;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
 
-pub async fn bump_and_release(bump_type: &str, message: Option<&str>) -> Result<()> {
-    // 1. Current version'u al
-    let current_version = env!("CARGO_PKG_VERSION");
-    let new_version = bump_version(bump_type, current_version)?;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Manifest {
+    Cargo,
+    PackageJson,
+    Pyproject,
+    Composer,
+}
+
+impl Manifest {
+    pub const ALL: [Manifest; 4] = [
+        Manifest::Cargo,
+        Manifest::PackageJson,
+        Manifest::Pyproject,
+        Manifest::Composer,
+    ];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Manifest::Cargo => "Cargo.toml",
+            Manifest::PackageJson => "package.json",
+            Manifest::Pyproject => "pyproject.toml",
+            Manifest::Composer => "composer.json",
+        }
+    }
+
+    /// TOML section that holds the project version
+    fn toml_sections(&self) -> &'static [&'static str] {
+        match self {
+            Manifest::Cargo => &["[package]", "[workspace.package]"],
+            Manifest::Pyproject => &["[project]", "[tool.poetry]"],
+            Manifest::PackageJson | Manifest::Composer => &[],
+        }
+    }
+}
+
+impl std::str::FromStr for Manifest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "cargo" | "cargo.toml" => Ok(Manifest::Cargo),
+            "npm" | "package" | "package.json" => Ok(Manifest::PackageJson),
+            "pyproject" | "python" | "pyproject.toml" => Ok(Manifest::Pyproject),
+            "composer" | "composer.json" => Ok(Manifest::Composer),
+            other => Err(anyhow!(
+                "Unknown manifest: {}. Use cargo, package.json, pyproject or composer",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BumpOptions {
+    /// Only rewrite the manifests; don't commit, tag or push
+    pub no_commit: bool,
+    /// Restrict the bump to these manifests (default: every one that exists)
+    pub manifests: Option<Vec<Manifest>>,
+}
+
+/// Finds the `version = "..."` line inside the manifest's project section.
+fn toml_version_span(manifest: Manifest, content: &str) -> Option<(usize, usize)> {
+    let pattern = Regex::new(r#"^\s*version\s*=\s*["']([^"']*)["']"#).unwrap();
+    let mut in_section = false;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_section = manifest.toml_sections().contains(&trimmed);
+        } else if in_section {
+            if let Some(version) = pattern.captures(line).and_then(|c| c.get(1)) {
+                return Some((offset + version.start(), offset + version.end()));
+            }
+        }
+        offset += line.len();
+    }
+
+    None
+}
+
+/// Index of the quote that closes the JSON string opened at `open`.
+fn json_string_end(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut index = open + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 1,
+            b'"' => return Some(index),
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Finds the top-level `"version"` value of a JSON manifest. Keys are only
+/// matched in the outermost object, so a nested `"version"` (in
+/// `publishConfig`, `engines`, ...) is never picked up.
+fn json_version_span(content: &str) -> Option<(usize, usize)> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    json.get("version")?.as_str()?;
+
+    let bytes = content.as_bytes();
+    let mut depth = 0;
+    // Whether the next string in the outermost object is a key
+    let mut expect_key = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' => {
+                depth += 1;
+                expect_key = depth == 1;
+            }
+            b'[' => depth += 1,
+            b'}' | b']' => depth -= 1,
+            b',' if depth == 1 => expect_key = true,
+            b'"' => {
+                let end = json_string_end(bytes, index)?;
+                if depth == 1 && expect_key && &content[index + 1..end] == "version" {
+                    let value = content[end + 1..]
+                        .trim_start()
+                        .strip_prefix(':')?
+                        .trim_start();
+                    let open = content.len() - value.len();
+                    return Some((open + 1, json_string_end(bytes, open)?));
+                }
+                expect_key = false;
+                index = end;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+fn version_span(manifest: Manifest, content: &str) -> Option<(usize, usize)> {
+    match manifest {
+        Manifest::Cargo | Manifest::Pyproject => toml_version_span(manifest, content),
+        Manifest::PackageJson | Manifest::Composer => json_version_span(content),
+    }
+}
+
+pub fn read_manifest_version(manifest: Manifest, content: &str) -> Option<String> {
+    version_span(manifest, content).map(|(start, end)| content[start..end].to_string())
+}
+
+/// Replaces the project version in place, leaving the rest of the file
+/// (formatting, comments, dependency versions) untouched.
+pub fn set_manifest_version(
+    manifest: Manifest,
+    content: &str,
+    new_version: &str,
+) -> Result<String> {
+    let (start, end) = version_span(manifest, content)
+        .ok_or_else(|| anyhow!("No project version found in {}", manifest.file_name()))?;
+    Ok(format!(
+        "{}{}{}",
+        &content[..start],
+        new_version,
+        &content[end..]
+    ))
+}
+
+/// Keeps the root package entry of Cargo.lock in sync with Cargo.toml, so
+/// the next build doesn't leave the tree dirty.
+pub fn update_cargo_lock(content: &str, package: &str, old: &str, new: &str) -> String {
+    let pattern = Regex::new(&format!(
+        r#"name = "{}"(\r?\n)version = "{}""#,
+        regex::escape(package),
+        regex::escape(old)
+    ))
+    .unwrap();
+    // The line ending stays whatever the lockfile uses
+    pattern
+        .replacen(content, 1, |caps: &regex::Captures| {
+            format!("name = \"{}\"{}version = \"{}\"", package, &caps[1], new)
+        })
+        .into_owned()
+}
+
+fn cargo_package_name(content: &str) -> Option<String> {
+    let pattern = Regex::new(r#"^\s*name\s*=\s*"([^"]+)""#).unwrap();
+    let mut in_package = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_package = trimmed == "[package]";
+        } else if in_package {
+            if let Some(name) = pattern.captures(line) {
+                return Some(name[1].to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Version of the first manifest that declares one.
+pub fn detect_current_version(root: &Path, manifests: &[Manifest]) -> Option<String> {
+    manifests.iter().find_map(|manifest| {
+        let content = std::fs::read_to_string(root.join(manifest.file_name())).ok()?;
+        read_manifest_version(*manifest, &content)
+    })
+}
+
+/// Writes `new_version` into every selected manifest that exists and
+/// declares a version. Returns the files that changed.
+pub fn update_manifests(
+    root: &Path,
+    manifests: &[Manifest],
+    new_version: &str,
+) -> Result<Vec<PathBuf>> {
+    let mut changed = Vec::new();
+
+    for manifest in manifests {
+        let path = root.join(manifest.file_name());
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+        let old_version = match read_manifest_version(*manifest, &content) {
+            Some(version) => version,
+            None => {
+                println!(
+                    "{}",
+                    format!(
+                        "⏭️  {} has no version field, skipping",
+                        manifest.file_name()
+                    )
+                    .dimmed()
+                );
+                continue;
+            }
+        };
+
+        std::fs::write(
+            &path,
+            set_manifest_version(*manifest, &content, new_version)?,
+        )?;
+        println!(
+            "✅ Updated {} ({} → {})",
+            manifest.file_name(),
+            old_version,
+            new_version
+        );
+        changed.push(path);
+
+        if *manifest == Manifest::Cargo {
+            let lock_path = root.join("Cargo.lock");
+            if let (Some(package), Ok(lock)) = (
+                cargo_package_name(&content),
+                std::fs::read_to_string(&lock_path),
+            ) {
+                let updated = update_cargo_lock(&lock, &package, &old_version, new_version);
+                if updated != lock {
+                    std::fs::write(&lock_path, updated)?;
+                    changed.push(lock_path);
+                }
+            }
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Where a project version was read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionSource {
+    Manifest(Manifest),
+    /// A plain `VERSION` file in the project root
+    VersionFile,
+    /// The latest git tag
+    Tag(String),
+}
+
+impl VersionSource {
+    pub fn label(&self) -> String {
+        match self {
+            VersionSource::Manifest(manifest) => manifest.file_name().to_string(),
+            VersionSource::VersionFile => VERSION_FILE.to_string(),
+            VersionSource::Tag(tag) => format!("git tag {}", tag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoundVersion {
+    pub source: VersionSource,
+    pub version: String,
+}
+
+const VERSION_FILE: &str = "VERSION";
+
+/// Every version declared in the project and the one they should agree on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionConsistency {
+    pub found: Vec<FoundVersion>,
+    /// The requested version, or the highest one found
+    pub expected: Option<String>,
+}
+
+impl VersionConsistency {
+    /// Sources that disagree with the expected version. A tag that is behind
+    /// it is a bump waiting for its release, not drift.
+    pub fn drift(&self) -> Vec<&FoundVersion> {
+        let expected = match &self.expected {
+            Some(expected) => expected,
+            None => return Vec::new(),
+        };
+        self.found
+            .iter()
+            .filter(|found| match found.source {
+                VersionSource::Tag(_) => {
+                    compare_versions(&found.version, expected) == std::cmp::Ordering::Greater
+                }
+                _ => found.version != *expected,
+            })
+            .collect()
+    }
+
+    /// True when the latest tag is older than the expected version.
+    pub fn is_untagged(&self) -> bool {
+        self.expected.as_ref().is_some_and(|expected| {
+            self.found.iter().any(|found| {
+                matches!(found.source, VersionSource::Tag(_))
+                    && compare_versions(&found.version, expected) == std::cmp::Ordering::Less
+            })
+        })
+    }
+}
+
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (lenient_version(a), lenient_version(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+/// First non-empty line of a `VERSION` file, without a `v` prefix.
+pub fn read_version_file(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(|line| line.trim_start_matches('v').to_string())
+}
+
+/// Collects the versions of every manifest, the `VERSION` file and
+/// `latest_tag`. Without `expected`, the highest version found wins.
+pub fn check_version_consistency(
+    root: &Path,
+    latest_tag: Option<&str>,
+    expected: Option<&str>,
+) -> VersionConsistency {
+    let mut found: Vec<FoundVersion> = Manifest::ALL
+        .iter()
+        .filter_map(|manifest| {
+            let content = std::fs::read_to_string(root.join(manifest.file_name())).ok()?;
+            Some(FoundVersion {
+                source: VersionSource::Manifest(*manifest),
+                version: read_manifest_version(*manifest, &content)?,
+            })
+        })
+        .collect();
+    if let Some(version) = std::fs::read_to_string(root.join(VERSION_FILE))
+        .ok()
+        .and_then(|content| read_version_file(&content))
+    {
+        found.push(FoundVersion {
+            source: VersionSource::VersionFile,
+            version,
+        });
+    }
+    if let Some(tag) = latest_tag {
+        found.push(FoundVersion {
+            source: VersionSource::Tag(tag.to_string()),
+            version: tag.trim_start_matches('v').to_string(),
+        });
+    }
+
+    let expected = match expected {
+        Some(expected) => Some(expected.trim_start_matches('v').to_string()),
+        None => found
+            .iter()
+            .map(|found| found.version.clone())
+            .max_by(|a, b| compare_versions(a, b)),
+    };
+    VersionConsistency { found, expected }
+}
+
+/// Rewrites the drifting manifests and `VERSION` file to the expected
+/// version. Tags are never moved. Returns the files that changed.
+pub fn fix_version_drift(root: &Path, report: &VersionConsistency) -> Result<Vec<PathBuf>> {
+    let expected = report
+        .expected
+        .as_deref()
+        .ok_or_else(|| anyhow!("No version found to sync to"))?;
+    let drift = report.drift();
+
+    let manifests: Vec<Manifest> = drift
+        .iter()
+        .filter_map(|found| match found.source {
+            VersionSource::Manifest(manifest) => Some(manifest),
+            _ => None,
+        })
+        .collect();
+    let mut changed = update_manifests(root, &manifests, expected)?;
+
+    if let Some(found) = drift
+        .iter()
+        .find(|found| found.source == VersionSource::VersionFile)
+    {
+        let path = root.join(VERSION_FILE);
+        let content = std::fs::read_to_string(&path)?;
+        std::fs::write(&path, content.replacen(&found.version, expected, 1))?;
+        println!(
+            "✅ Updated {} ({} → {})",
+            VERSION_FILE, found.version, expected
+        );
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+/// `version check`: prints every version found and fails on drift unless
+/// `fix` rewrites the files. `root` is the project directory.
+pub async fn check_versions(root: &Path, fix: bool, expected: Option<&str>) -> Result<()> {
+    ensure_not_sandboxed("Version check")?;
+    let latest_tag = get_latest_tag(root)?;
+    let report = check_version_consistency(root, latest_tag.as_deref(), expected);
+
+    let expected = match &report.expected {
+        Some(expected) => expected.clone(),
+        None => {
+            println!(
+                "{}",
+                "⚠️  No version found in the manifests, a VERSION file or git tags".yellow()
+            );
+            return Ok(());
+        }
+    };
+
+    println!("{}", "🔍 Version consistency:".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+    let drift = report.drift();
+    for found in &report.found {
+        let status = if drift.contains(&found) {
+            format!("❌ expected {}", expected).red()
+        } else if found.version != expected {
+            "⏳ not released yet".yellow()
+        } else {
+            "✅".green()
+        };
+        println!(
+            "  {:<24} {:<12} {}",
+            found.source.label(),
+            found.version,
+            status
+        );
+    }
+    println!();
+
+    if report.is_untagged() {
+        println!(
+            "{}",
+            format!(
+                "ℹ️  v{} has no tag yet, create-release will add it",
+                expected
+            )
+            .dimmed()
+        );
+    }
+
+    if drift.is_empty() {
+        println!(
+            "{}",
+            format!("✅ All versions agree on {}", expected).green()
+        );
+        return Ok(());
+    }
+
+    if !fix {
+        return Err(ChecksFailed(format!(
+            "{} version(s) out of sync with {}, run with --fix to update them",
+            drift.len(),
+            expected
+        ))
+        .into());
+    }
+
+    if drift
+        .iter()
+        .any(|found| matches!(found.source, VersionSource::Tag(_)))
+    {
+        println!(
+            "{}",
+            "⚠️  The latest tag is newer than the requested version, tags are left alone".yellow()
+        );
+    }
+    let changed = fix_version_drift(root, &report)?;
+    println!(
+        "{}",
+        format!("🎉 Synced {} file(s) to {}", changed.len(), expected).green()
+    );
+    Ok(())
+}
+
+/// Bumps the version of the project in `root`, then commits, tags and
+/// pushes it.
+pub async fn bump_and_release(
+    root: &Path,
+    bump_type: &str,
+    message: Option<&str>,
+    options: &BumpOptions,
+) -> Result<()> {
+    ensure_not_sandboxed("Version bump")?;
+    let manifests = options
+        .manifests
+        .clone()
+        .unwrap_or_else(|| Manifest::ALL.to_vec());
+
+    // 1. Current version'u al: manifest'ten, yoksa son tag'den
+    let latest_tag = get_latest_tag(root)?;
+    let current_version = detect_current_version(root, &manifests)
+        .or_else(|| {
+            latest_tag
+                .as_ref()
+                .map(|tag| tag.trim_start_matches('v').to_string())
+        })
+        .ok_or_else(|| anyhow!("No version found in the project manifests or git tags"))?;
+    let new_version = bump_version(bump_type, &current_version)?;
 
     println!(
         "🔄 Bumping version from {} to {}",
         current_version, new_version
     );
 
-    // 2. Cargo.toml'u güncelle
-    update_cargo_toml(&new_version)?;
+    // 2. Manifest'leri güncelle
+    let changed = update_manifests(root, &manifests, &new_version)?;
+    if changed.is_empty() {
+        println!("{}", "⚠️  No manifest was updated, only tagging".yellow());
+    }
+
+    if options.no_commit {
+        println!(
+            "{}",
+            "⏭️  --no-commit: review the changes, then commit and tag yourself".dimmed()
+        );
+        return Ok(());
+    }
 
     // 3. Release notes oluştur
-    let latest_tag = get_latest_tag()?;
     let _release_notes = generate_release_notes_for_version(
+        root,
         latest_tag.as_deref(),
         Some(&format!("v{}", new_version)),
+        &load_commit_filter(&[], &[]).await,
     )?;
 
     // 4. Git commit ve tag
-    create_git_tag(&new_version, message).await?;
+    create_git_tag(root, &new_version, message, &changed).await?;
 
     println!("🎉 Successfully released version {}", new_version.green());
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BumpLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl BumpLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BumpLevel::Patch => "patch",
+            BumpLevel::Minor => "minor",
+            BumpLevel::Major => "major",
+        }
+    }
+}
+
+/// Conventional commit semantics: breaking → major, feat → minor,
+/// fix/perf → patch. Other types don't trigger a release.
+pub fn commit_bump_level(message: &str) -> Option<BumpLevel> {
+    if is_breaking_change(message) {
+        return Some(BumpLevel::Major);
+    }
+    match parse_commit_type(message)?.0.as_str() {
+        "feat" | "feature" => Some(BumpLevel::Minor),
+        "fix" | "bugfix" | "perf" => Some(BumpLevel::Patch),
+        _ => None,
+    }
+}
+
+/// The highest bump any commit asks for, with the commits that justify it.
+pub fn infer_bump(commits: &[CommitInfo]) -> Option<(BumpLevel, Vec<&CommitInfo>)> {
+    let level = commits
+        .iter()
+        .filter_map(|commit| commit_bump_level(&commit.message))
+        .max()?;
+    let justifying = commits
+        .iter()
+        .filter(|commit| commit_bump_level(&commit.message) == Some(level))
+        .collect();
+    Some((level, justifying))
+}
+
+/// Infers the bump from the commits since the last tag and applies it after
+/// confirmation. Commits matching `filter` don't count.
+pub async fn auto_bump(
+    root: &Path,
+    options: &BumpOptions,
+    assume_yes: bool,
+    filter: &CommitFilter,
+) -> Result<()> {
+    ensure_not_sandboxed("Version bump")?;
+    let repo = git2::Repository::discover(root)?;
+    let latest_tag = get_latest_tag(root)?;
+    let mut commits = get_commits_between_tags(&repo, &latest_tag, "HEAD")?;
+    commits.retain(|commit| !filter.excludes_commit(commit));
+    let since = latest_tag.as_deref().unwrap_or("the first commit");
+
+    let (level, justifying) = match infer_bump(&commits) {
+        Some(inferred) => inferred,
+        None => {
+            println!(
+                "{}",
+                format!(
+                    "ℹ️  No feat, fix or breaking commits since {} ({} commits), nothing to release",
+                    since,
+                    commits.len()
+                )
+                .yellow()
+            );
+            return Ok(());
+        }
+    };
+
+    println!(
+        "{}",
+        format!(
+            "📈 Inferred bump: {} ({} commits since {})",
+            level.name(),
+            commits.len(),
+            since
+        )
+        .cyan()
+        .bold()
+    );
+    for commit in &justifying {
+        println!(
+            "  {} {}",
+            commit.hash.chars().take(7).collect::<String>().dimmed(),
+            commit.message.lines().next().unwrap_or("")
+        );
+    }
+
+    if !assume_yes && !prompt::confirm(format!("Apply {} bump? [y/N]: ", level.name()).cyan())? {
+        println!("{}", "❌ Version bump cancelled".yellow());
+        return Ok(());
+    }
+
+    bump_and_release(root, level.name(), None, options).await
+}
+
 fn bump_version(bump_type: &str, current: &str) -> Result<String> {
     let parts: Vec<&str> = current.split('.').collect();
     if parts.len() != 3 {
@@ -50,82 +707,324 @@ fn bump_version(bump_type: &str, current: &str) -> Result<String> {
     Ok(new_version)
 }
 
-fn update_cargo_toml(new_version: &str) -> Result<()> {
-    let current_version = env!("CARGO_PKG_VERSION");
-    let cargo_content = std::fs::read_to_string("Cargo.toml")?;
-    let updated = cargo_content.replace(
-        &format!("version = \"{}\"", current_version),
-        &format!("version = \"{}\"", new_version),
-    );
-    std::fs::write("Cargo.toml", updated)?;
-    println!("✅ Updated Cargo.toml");
-    Ok(())
-}
-
-fn get_latest_tag() -> Result<Option<String>> {
-    let output = Command::new("git")
-        .args(["describe", "--tags", "--abbrev=0"])
-        .output()?;
+fn get_latest_tag(root: &Path) -> Result<Option<String>> {
+    let output = SystemRunner.run("git", &["describe", "--tags", "--abbrev=0"], Some(root))?;
 
-    if output.status.success() {
-        let tag = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        Ok(Some(tag))
+    if output.success {
+        Ok(Some(output.stdout.trim().to_string()))
     } else {
         Ok(None)
     }
 }
 
-async fn create_git_tag(version: &str, message: Option<&str>) -> Result<()> {
+async fn create_git_tag(
+    root: &Path,
+    version: &str,
+    message: Option<&str>,
+    files: &[PathBuf],
+) -> Result<()> {
     let tag_name = format!("v{}", version);
 
     // Commit changes
-    Command::new("git").args(["add", "Cargo.toml"]).output()?;
+    if !files.is_empty() {
+        let files: Vec<String> = files
+            .iter()
+            .map(|file| file.strip_prefix(root).unwrap_or(file))
+            .map(|file| file.to_string_lossy().to_string())
+            .collect();
+        let mut add = vec!["add"];
+        add.extend(files.iter().map(String::as_str));
+        SystemRunner.run("git", &add, Some(root))?;
 
-    Command::new("git")
-        .args(["commit", "-m", &format!("bump: version {}", version)])
-        .output()?;
+        let commit_message = format!("bump: version {}", version);
+        let commit = SystemRunner.run("git", &["commit", "-m", &commit_message], Some(root))?;
+        if !commit.success {
+            return Err(anyhow!(
+                "Failed to commit the version bump: {}",
+                commit.stderr.trim()
+            ));
+        }
+    }
 
     // Create tag with message
     let default_message = format!("Release {}", tag_name);
     let tag_message = message.unwrap_or(&default_message);
 
-    Command::new("git")
-        .args(["tag", "-a", &tag_name, "-m", tag_message])
-        .output()?;
+    SystemRunner.run(
+        "git",
+        &["tag", "-a", &tag_name, "-m", tag_message],
+        Some(root),
+    )?;
 
     // Push changes and tag
-    Command::new("git")
-        .args(["push", "origin", "main"])
-        .output()?;
-
-    Command::new("git")
-        .args(["push", "origin", &tag_name])
-        .output()?;
+    SystemRunner.run("git", &["push", "origin", "main"], Some(root))?;
+    SystemRunner.run("git", &["push", "origin", &tag_name], Some(root))?;
 
     println!("✅ Created and pushed tag: {}", tag_name.green());
     Ok(())
 }
 
-pub async fn show_version_history() -> Result<()> {
-    println!("{}", "📋 Version History:".cyan().bold());
-    println!("{}", "═".repeat(40).dimmed());
+/// Default number of versions `version history` lists.
+pub const HISTORY_LIMIT: usize = 10;
 
-    let output = Command::new("git")
-        .args(["tag", "--sort=-version:refname", "-l", "v*"])
-        .output()?;
+/// One version in `version history`, or the unreleased commits on HEAD.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VersionHistoryEntry {
+    /// `HEAD` for the unreleased entry
+    pub tag: String,
+    pub version: String,
+    pub timestamp: i64,
+    pub date: String,
+    /// Tagger of annotated tags, author of the tagged commit otherwise
+    pub tagger: String,
+    /// Commits since the previous version
+    pub commits: usize,
+    /// Up to three (category, commits), most commits first
+    pub highlights: Vec<(String, usize)>,
+    pub released: bool,
+}
 
-    if output.status.success() {
-        let tags = String::from_utf8_lossy(&output.stdout);
-        if tags.trim().is_empty() {
-            println!("{}", "No version tags found.".dimmed());
-        } else {
-            for tag in tags.lines().take(10) {
-                println!("  📌 {}", tag.trim().green());
-            }
+/// The commit a tag points at, with its date and who made it.
+fn tag_metadata(repo: &Repository, tag: &str) -> Option<(git2::Oid, i64, String)> {
+    let reference = repo.find_reference(&format!("refs/tags/{}", tag)).ok()?;
+    let commit = reference.peel_to_commit().ok()?;
+    let annotated = reference
+        .target()
+        .and_then(|oid| repo.find_tag(oid).ok())
+        .and_then(|tag| {
+            tag.tagger()
+                .map(|sig| (sig.when().seconds(), sig.name().map(str::to_string)))
+        });
+    match annotated {
+        Some((when, Some(name))) => Some((commit.id(), when, name)),
+        _ => Some((
+            commit.id(),
+            commit.time().seconds(),
+            commit.author().name().unwrap_or("").to_string(),
+        )),
+    }
+}
+
+/// Category counts of the commits reachable from `to` but not from `from`.
+fn range_summary(
+    repo: &Repository,
+    from: Option<git2::Oid>,
+    to: git2::Oid,
+) -> Result<RangeSummary> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(to)?;
+    if let Some(from) = from {
+        revwalk.hide(from)?;
+    }
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        commits.push(CommitInfo {
+            message: commit.message().unwrap_or("").to_string(),
+            author_name: commit.author().name().unwrap_or("").to_string(),
+            author_email: commit.author().email().unwrap_or("").to_string(),
+            hash: commit.id().to_string(),
+            timestamp: commit.time().seconds(),
+            stats: Default::default(),
+        });
+    }
+    let mut summary = RangeSummary::default();
+    summary.add(&commits);
+    Ok(summary)
+}
+
+fn highlights(summary: &RangeSummary) -> Vec<(String, usize)> {
+    let mut categories = summary.filled_categories();
+    categories.sort_by_key(|(_, count)| Reverse(*count));
+    categories
+        .into_iter()
+        .take(3)
+        .map(|(key, count)| (category_label(key).to_string(), count))
+        .collect()
+}
+
+fn history_date(timestamp: i64) -> String {
+    timezone()
+        .at(timestamp)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Version tags in the order they were made, newest first. Commits on HEAD
+/// after the latest tag come first as an unreleased entry, carrying
+/// `manifest_version` when the manifests were already bumped.
+pub fn version_history(
+    repo: &Repository,
+    manifest_version: Option<&str>,
+) -> Result<Vec<VersionHistoryEntry>> {
+    let mut tags: Vec<(String, git2::Oid, i64, String)> = get_all_tags(repo)?
+        .into_iter()
+        .filter(|tag| is_version_tag(tag))
+        .filter_map(|tag| {
+            let (oid, timestamp, tagger) = tag_metadata(repo, &tag)?;
+            Some((tag, oid, timestamp, tagger))
+        })
+        .collect();
+    tags.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| compare_version_tags(&a.0, &b.0)));
+
+    let mut entries = Vec::new();
+    let mut previous: Option<git2::Oid> = None;
+    for (tag, oid, timestamp, tagger) in tags {
+        let summary = range_summary(repo, previous, oid)?;
+        entries.push(VersionHistoryEntry {
+            version: extract_version_from_string(&tag)
+                .unwrap_or_else(|| tag.trim_start_matches('v').to_string()),
+            tag,
+            timestamp,
+            date: history_date(timestamp),
+            tagger,
+            commits: summary.total_commits,
+            highlights: highlights(&summary),
+            released: true,
+        });
+        previous = Some(oid);
+    }
+
+    if let Ok(head) = repo.head().and_then(|head| head.peel_to_commit()) {
+        let summary = range_summary(repo, previous, head.id())?;
+        if summary.total_commits > 0 {
+            let latest = entries.last().map(|entry| entry.version.clone());
+            let version = match (manifest_version, latest) {
+                (Some(manifest), Some(latest))
+                    if lenient_version(manifest) > lenient_version(&latest) =>
+                {
+                    manifest.to_string()
+                }
+                (Some(manifest), None) => manifest.to_string(),
+                _ => "unreleased".to_string(),
+            };
+            let timestamp = summary.last_timestamp.unwrap_or_default();
+            entries.push(VersionHistoryEntry {
+                tag: "HEAD".to_string(),
+                version,
+                timestamp,
+                date: history_date(timestamp),
+                tagger: head.author().name().unwrap_or("").to_string(),
+                commits: summary.total_commits,
+                highlights: highlights(&summary),
+                released: false,
+            });
         }
+    }
+
+    entries.reverse();
+    Ok(entries)
+}
+
+fn highlight_text(entry: &VersionHistoryEntry) -> String {
+    entry
+        .highlights
+        .iter()
+        .map(|(label, count)| format!("{} {}", count, label))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+fn entry_name(entry: &VersionHistoryEntry) -> String {
+    if entry.released {
+        entry.tag.clone()
     } else {
-        println!("{}", "Failed to retrieve version history.".red());
+        format!("{} (HEAD)", entry.version)
+    }
+}
+
+/// Aligned table rows, header first.
+pub fn render_history_table(entries: &[VersionHistoryEntry]) -> Vec<String> {
+    let name_width = entries
+        .iter()
+        .map(|entry| entry_name(entry).chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Version".len());
+    let tagger_width = entries
+        .iter()
+        .map(|entry| entry.tagger.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Tagger".len());
+
+    let mut lines = vec![format!(
+        "{:<name_width$}  {:<10}  {:<tagger_width$}  {:>7}  Highlights",
+        "Version", "Date", "Tagger", "Commits"
+    )];
+    for entry in entries {
+        lines.push(format!(
+            "{:<name_width$}  {:<10}  {:<tagger_width$}  {:>7}  {}",
+            entry_name(entry),
+            entry.date,
+            entry.tagger,
+            entry.commits,
+            highlight_text(entry)
+        ));
+    }
+    lines
+}
+
+/// Vertical timeline, newest version on top.
+pub fn render_history_graph(entries: &[VersionHistoryEntry]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let marker = if entry.released { "●" } else { "○" };
+        lines.push(format!(
+            "{} {}  {}  {}",
+            marker,
+            entry_name(entry),
+            entry.date,
+            entry.tagger
+        ));
+        let mut details = format!("{} commits", entry.commits);
+        if !entry.highlights.is_empty() {
+            details.push_str(&format!(" · {}", highlight_text(entry)));
+        }
+        let rail = if index + 1 < entries.len() {
+            "│"
+        } else {
+            " "
+        };
+        lines.push(format!("{}   {}", rail, details));
+        if index + 1 < entries.len() {
+            lines.push("│".to_string());
+        }
+    }
+    lines
+}
+
+/// `version history` of the repository `root` is in.
+pub async fn show_version_history(root: &Path, limit: usize, graph: bool) -> Result<()> {
+    let repo = Repository::discover(root)?;
+    let manifest_version = repo
+        .workdir()
+        .and_then(|root| detect_current_version(root, &Manifest::ALL));
+    let mut entries = version_history(&repo, manifest_version.as_deref())?;
+    entries.truncate(limit);
+
+    if is_json_output() {
+        return print_json(&entries);
+    }
+
+    println!("{}", "📋 Version History:".cyan().bold());
+    println!("{}", "═".repeat(40).dimmed());
+    if entries.is_empty() {
+        println!("{}", "No version tags found.".dimmed());
+        return Ok(());
     }
 
+    let lines = if graph {
+        render_history_graph(&entries)
+    } else {
+        render_history_table(&entries)
+    };
+    for (index, line) in lines.iter().enumerate() {
+        if !graph && index == 0 {
+            println!("  {}", line.bold());
+        } else {
+            println!("  {}", line);
+        }
+    }
     Ok(())
 }
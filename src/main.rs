@@ -11,7 +11,7 @@ mod tests;
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 fn print_banner() {
-    let banner_lines = vec![
+    let banner_lines = [
         "      ███╗   ██╗██╗████████╗██████╗  ██████╗ ██╗  ██╗██╗████████╗     ",
         "      ████╗  ██║██║╚══██╔══╝██╔══██╗██╔═══██╗██║ ██╔╝██║╚══██╔══╝     ",
         "      ██╔██╗ ██║██║   ██║   ██████╔╝██║   ██║█████╔╝ ██║   ██║        ",
@@ -20,20 +20,7 @@ fn print_banner() {
         "      ╚═╝  ╚═══╝╚═╝   ╚═╝   ╚═╝  ╚═╝ ╚═════╝ ╚═╝  ╚═╝╚═╝   ╚═╝        ",
     ];
 
-    #[cfg(windows)]
-    {
-        extern "system" {
-            fn SetConsoleTitleW(title: *const u16) -> i32;
-        }
-
-        let title = "🚀 Nitroterm Terminal Tool\0"
-            .encode_utf16()
-            .collect::<Vec<u16>>();
-
-        unsafe {
-            SetConsoleTitleW(title.as_ptr());
-        }
-    }
+    utils::terminal::set_title("🚀 Nitroterm Terminal Tool");
 
     println!(
         "{}",
@@ -55,16 +42,12 @@ fn print_banner() {
         for (line_idx, line) in banner_lines.iter().enumerate() {
         print!("{}", "║".cyan());
 
-        // Her karakteri farklı renkle boyayalım
+        // Her karakteri temanın gradyanıyla boyayalım
+        let theme = utils::theme::current_theme();
         for (char_idx, ch) in line.chars().enumerate() {
             let progress = (char_idx as f32 + line_idx as f32 * 20.0) / (line.len() as f32 + banner_lines.len() as f32 * 20.0);
 
-            // Blue to Green gradient
-            let r = (65.0 + progress * (0.0 - 65.0)) as u8;
-            let g = (105.0 + progress * (255.0 - 105.0)) as u8;
-            let b = (225.0 + progress * (127.0 - 225.0)) as u8;
-
-            print!("{}", ch.to_string().truecolor(r, g, b).bold());
+            print!("{}", theme.gradient(&ch.to_string(), progress));
         }
 
         println!("{}", "║".cyan());
@@ -135,6 +118,154 @@ fn show_menu() {
     println!();
 }
 
+fn version_bump_command(name: &'static str, about: &'static str) -> Command {
+    Command::new(name)
+        .about(about)
+        .arg(
+            clap::Arg::new("no-commit")
+                .long("no-commit")
+                .help("Only update the manifests; don't commit, tag or push")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("manifests")
+                .long("manifests")
+                .value_name("LIST")
+                .value_delimiter(',')
+                .help("Manifests to update: cargo,package.json,pyproject,composer (default: all that exist)"),
+        )
+}
+
+fn version_bump_options(matches: &clap::ArgMatches) -> commands::version_management::BumpOptions {
+    let manifests = matches.get_many::<String>("manifests").map(|names| {
+        names
+            .map(|name| name.parse::<commands::version_management::Manifest>())
+            .collect::<anyhow::Result<Vec<_>>>()
+            .unwrap_or_else(|e| {
+                eprintln!("{}", format!("❌ {}", e).red());
+                exit_for(&e);
+            })
+    });
+
+    commands::version_management::BumpOptions {
+        no_commit: matches.get_flag("no-commit"),
+        manifests,
+    }
+}
+
+/// `--exclude-author` and `--exclude-pattern`, added to the configured excludes.
+fn commit_filter_args() -> [clap::Arg; 2] {
+    [
+        clap::Arg::new("exclude-author")
+            .long("exclude-author")
+            .value_name("AUTHOR")
+            .action(clap::ArgAction::Append)
+            .help("Skip commits whose author name or email contains AUTHOR, e.g. dependabot"),
+        clap::Arg::new("exclude-pattern")
+            .long("exclude-pattern")
+            .value_name("TEXT")
+            .action(clap::ArgAction::Append)
+            .help("Skip commits whose message contains TEXT, e.g. 'chore(deps)'"),
+    ]
+}
+
+async fn commit_filter(matches: &clap::ArgMatches) -> commands::commit_filter::CommitFilter {
+    let values = |id: &str| -> Vec<String> {
+        matches
+            .get_many::<String>(id)
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default()
+    };
+    commands::commit_filter::load_commit_filter(
+        &values("exclude-author"),
+        &values("exclude-pattern"),
+    )
+    .await
+}
+
+/// `--max-depth`, `--include`, `--exclude` and `--no-gitignore` of the
+/// commands that look for projects below a directory.
+fn scan_args() -> [clap::Arg; 4] {
+    [
+        clap::Arg::new("max-depth")
+            .long("max-depth")
+            .value_name("N")
+            .value_parser(clap::value_parser!(usize))
+            .requires("recursive")
+            .help("With --recursive: directory levels to descend (default: 6)"),
+        clap::Arg::new("include")
+            .long("include")
+            .value_name("GLOB")
+            .action(clap::ArgAction::Append)
+            .help("Only paths matching GLOB, e.g. 'packages/*' (repeatable)"),
+        clap::Arg::new("exclude")
+            .long("exclude")
+            .value_name("GLOB")
+            .action(clap::ArgAction::Append)
+            .help("Skip paths matching GLOB, e.g. '**/fixtures' (repeatable)"),
+        clap::Arg::new("no-gitignore")
+            .long("no-gitignore")
+            .action(clap::ArgAction::SetTrue)
+            .help("Also scan directories .gitignore ignores"),
+    ]
+}
+
+/// The `scan_args()` of `matches`; only the directory itself unless `recursive`.
+fn scan_options(matches: &clap::ArgMatches, recursive: bool) -> utils::file_system::ScanOptions {
+    let values = |id: &str| -> Vec<String> {
+        matches
+            .get_many::<String>(id)
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default()
+    };
+    utils::file_system::ScanOptions {
+        max_depth: if recursive {
+            matches
+                .get_one::<usize>("max-depth")
+                .copied()
+                .unwrap_or(utils::file_system::DEFAULT_MAX_DEPTH)
+        } else {
+            0
+        },
+        include: values("include"),
+        exclude: values("exclude"),
+        respect_gitignore: !matches.get_flag("no-gitignore"),
+    }
+}
+
+/// Subcommands that work on the `-C` directory directly. The others, and
+/// the interactive menu, run from inside it.
+const DIRECTORY_COMMANDS: [&str; 5] = [
+    "release-notes",
+    "update-dependencies",
+    "sync-translations",
+    "version",
+    "github-labels",
+];
+
+/// The project directory from `-C`, `.` without one.
+fn project_directory(matches: Option<&clap::ArgMatches>) -> Result<std::path::PathBuf, String> {
+    let Some(dir) = matches.and_then(|m| m.get_one::<String>("directory")) else {
+        return Ok(".".into());
+    };
+    let dir = std::path::PathBuf::from(dir);
+    if !dir.is_dir() {
+        return Err(format!("Cannot use '{}': not a directory", dir.display()));
+    }
+    let subcommand = matches.and_then(|m| m.subcommand_name());
+    if subcommand.is_some_and(|name| DIRECTORY_COMMANDS.contains(&name)) {
+        return Ok(dir);
+    }
+    std::env::set_current_dir(&dir)
+        .map_err(|e| format!("Cannot change to '{}': {}", dir.display(), e))?;
+    Ok(".".into())
+}
+
+/// Exits with the code for `error`, see `utils::error::ExitCode`.
+fn exit_for(error: &anyhow::Error) -> ! {
+    std::process::exit(utils::error::exit_code(error).code())
+}
+
 fn get_user_input() -> String {
     print!("{}", "🚀 nitroterm > ".cyan().bold());
     io::stdout().flush().unwrap();
@@ -152,179 +283,2769 @@ async fn main() {
         .version(VERSION)
         .about("A terminal tool for project management and automation")
         .author("Mustafa Genc <eposta@mustafagenc.info>")
-        .subcommand(Command::new("release-notes").about("Generate release notes from git commits"))
+        .arg(
+            clap::Arg::new("sandbox")
+                .long("sandbox")
+                .help("Read-only mode: no external commands, no file writes")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("yes")
+                .short('y')
+                .long("yes")
+                .help("Never prompt: confirm with yes, use defaults, fail if input is required")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("non-interactive")
+                .long("non-interactive")
+                .help("Never prompt: answer confirmations with no, use defaults, fail if input is required (default: NITROTERM_NON_INTERACTIVE)")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("json")
+                .long("json")
+                .help("Print machine-readable JSON on stdout instead of human output")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("notify")
+                .long("notify")
+                .help("Send notifications for every event, also ones left out of [notifications] events")
+                .global(true)
+                .conflicts_with("no-notify")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("no-notify")
+                .long("no-notify")
+                .help("Send no notifications, whatever .nitrokit.toml configures")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("command-timeout")
+                .long("command-timeout")
+                .value_name("SECS")
+                .help("Kill external commands (npm, cargo, gh, linters) running longer than this, 0 for no limit (default: 900, or NITROTERM_COMMAND_TIMEOUT)")
+                .global(true)
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            clap::Arg::new("directory")
+                .short('C')
+                .long("directory")
+                .value_name("PATH")
+                .help("Run as if started in PATH, like git -C")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("profile")
+                .long("profile")
+                .value_name("NAME")
+                .help("Use the API keys, GitHub and org settings, release hosting and git identity of this config profile (default: NITROTERM_PROFILE)")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("utc")
+                .long("utc")
+                .help("Show dates and times in UTC, whatever `config timezone` says")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("no-update-check")
+                .long("no-update-check")
+                .help("Skip the update check of the interactive menu (see `config updates`)")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .subcommand(
+            Command::new("release-notes")
+                .about("Generate release notes from git commits")
+                .arg(
+                    clap::Arg::new("locale")
+                        .long("locale")
+                        .value_name("LOCALE")
+                        .help("Document language: en, tr, de (defaults to `config document-locale`)"),
+                )
+                .arg(
+                    clap::Arg::new("offline")
+                        .long("offline")
+                        .help("Don't look up PR and issue titles on GitHub, only link the numbers")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("template")
+                        .long("template")
+                        .value_name("FILE")
+                        .help("Handlebars template controlling sections, order and blocks"),
+                )
+                .arg(
+                    clap::Arg::new("init-template")
+                        .long("init-template")
+                        .value_name("FILE")
+                        .help("Write an example template to FILE and exit")
+                        .conflicts_with("template"),
+                )
+                .arg(
+                    clap::Arg::new("max-commits")
+                        .long("max-commits")
+                        .value_name("N")
+                        .help("List only the newest N commits; totals still cover the whole range")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    clap::Arg::new("summary-only")
+                        .long("summary-only")
+                        .help("Counts per category and top contributors instead of a commit list")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("template"),
+                )
+                .arg(
+                    clap::Arg::new("submodules")
+                        .long("submodules")
+                        .help("Add a section with the old → new commit of every changed submodule")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("template"),
+                )
+                .arg(
+                    clap::Arg::new("by-team")
+                        .long("by-team")
+                        .help("Add a section with the commits per owning team from CODEOWNERS")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("template"),
+                )
+                .arg(
+                    clap::Arg::new("translate")
+                        .long("translate")
+                        .value_name("LANGS")
+                        .help("Also write translated copies with the translation provider, e.g. tr,de")
+                        .value_delimiter(','),
+                )
+                .arg(
+                    clap::Arg::new("summarize")
+                        .long("summarize")
+                        .help("Add a summary and highlights written by the translation provider above the commit list")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("for")
+                        .long("for")
+                        .value_name("TAG")
+                        .help("Notes for a past release: the previous version tag → TAG"),
+                )
+                .args(commit_filter_args()),
+        )
         .subcommand(
-            Command::new("update-dependencies").about("Analyze and update project dependencies"),
+            Command::new("retag")
+                .about("Rewrite a tag's annotation, e.g. with its regenerated release notes")
+                .arg(
+                    clap::Arg::new("annotate")
+                        .long("annotate")
+                        .value_name("TAG")
+                        .required(true)
+                        .help("Replace the message of TAG with the release notes of its range"),
+                )
+                .arg(
+                    clap::Arg::new("locale")
+                        .long("locale")
+                        .value_name("LOCALE")
+                        .help("Document language: en, tr, de (defaults to `config document-locale`)"),
+                )
+                .arg(
+                    clap::Arg::new("offline")
+                        .long("offline")
+                        .help("Don't look up PR and issue titles on GitHub, only link the numbers")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Print the new message without touching the tag")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("push")
+                        .long("push")
+                        .help("Force-push the rewritten tag to origin")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("dry-run"),
+                )
+                .args(commit_filter_args()),
         )
-        .subcommand(Command::new("sync-translations").about("Sync translations using Gemini AI"))
         .subcommand(
-            Command::new("create-release")
-                .about("Create a new release")
+            Command::new("contributors")
+                .about("Rank contributors by commits and changed lines from the git history")
                 .arg(
-                    clap::Arg::new("version")
-                        .help("Release version (e.g., v1.0.0)")
-                        .required(false)
-                        .index(1),
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Repository path")
+                        .default_value("."),
                 )
                 .arg(
-                    clap::Arg::new("message")
-                        .help("Release message")
-                        .required(false)
-                        .index(2),
+                    clap::Arg::new("from")
+                        .long("from")
+                        .value_name("REF")
+                        .help("Only count commits after this ref, e.g. the previous tag"),
+                )
+                .arg(
+                    clap::Arg::new("to")
+                        .long("to")
+                        .value_name("REF")
+                        .help("Last ref to count (default: HEAD)"),
+                )
+                .arg(
+                    clap::Arg::new("markdown")
+                        .long("markdown")
+                        .help("Print a Markdown table for docs")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("json"),
                 ),
         )
         .subcommand(
-            Command::new("code-quality")
-                .about("Run code quality checks (linting, formatting, security)")
+            Command::new("diff-report")
+                .about("Markdown report of the code changes between two refs: files by directory, renames, contributors")
+                .arg(
+                    clap::Arg::new("from")
+                        .required(true)
+                        .value_name("FROM")
+                        .help("Tag or ref to compare from, e.g. the previous release")
+                        .index(1),
+                )
+                .arg(
+                    clap::Arg::new("to")
+                        .value_name("TO")
+                        .help("Tag or ref to compare to (default: HEAD)")
+                        .index(2),
+                )
                 .arg(
                     clap::Arg::new("path")
                         .short('p')
                         .long("path")
                         .value_name("PATH")
-                        .help("Project path to analyze")
-                        .required(false),
+                        .help("Repository path")
+                        .default_value("."),
                 )
                 .arg(
-                    clap::Arg::new("config")
-                        .short('c')
-                        .long("config")
+                    clap::Arg::new("depth")
+                        .long("depth")
+                        .value_name("N")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("Group files by their first N directories instead of the full path"),
+                )
+                .arg(
+                    clap::Arg::new("output")
+                        .short('o')
+                        .long("output")
                         .value_name("FILE")
-                        .help("Custom config file path")
-                        .required(false),
+                        .help("Write the report to a file instead of printing it")
+                        .conflicts_with("json"),
+                ),
+        )
+        .subcommand(
+            Command::new("status")
+                .about("What changed since release notes, dependency audits and translation syncs last ran")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Repository path")
+                        .default_value("."),
                 )
                 .arg(
-                    clap::Arg::new("skip-deps")
-                        .long("skip-deps")
-                        .help("Skip dependency installation")
+                    clap::Arg::new("offline")
+                        .long("offline")
+                        .help("Skip the registry lookup for newly outdated packages")
                         .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("doctor")
+                .about("Check the tools, API key and network each feature needs")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path, decides which package managers are needed")
+                        .default_value("."),
                 )
                 .arg(
-                    clap::Arg::new("checks")
-                        .long("checks")
-                        .value_name("LIST")
-                        .help("Enable specific checks only (comma-separated)")
+                    clap::Arg::new("feature")
+                        .long("feature")
+                        .short('f')
                         .value_delimiter(',')
-                        .required(false),
+                        .value_parser(["release", "github", "dependencies", "translation"])
+                        .help("Exit with an error when these features can't run (comma separated)"),
                 ),
         )
         .subcommand(
-            Command::new("github-labels")
-                .about("Manage GitHub repository labels with emojis and categorization")
+            Command::new("audit")
+                .about("Run cargo audit, npm audit, pip-audit and composer audit as one vulnerability report")
                 .arg(
-                    clap::Arg::new("skip-auth")
-                        .long("skip-auth")
-                        .help("Skip GitHub authentication check")
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path")
+                        .default_value("."),
+                )
+                .arg(
+                    clap::Arg::new("fail-on")
+                        .long("fail-on")
+                        .value_name("LEVEL")
+                        .help("Exit with an error when a vulnerability at or above this severity is found")
+                        .value_parser(["low", "moderate", "high", "critical"]),
+                ),
+        )
+        .subcommand(
+            Command::new("update-dependencies")
+                .about("Analyze and update project dependencies")
+                .arg(
+                    clap::Arg::new("native")
+                        .long("native")
+                        .help("Report outdated packages from lockfiles and registry APIs only")
                         .action(clap::ArgAction::SetTrue),
                 )
                 .arg(
-                    clap::Arg::new("skip-install")
-                        .long("skip-install")
-                        .help("Skip GitHub CLI installation check")
+                    clap::Arg::new("workspace")
+                        .long("workspace")
+                        .help("Cargo: report outdated requirements for every workspace crate")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("native"),
+                )
+                .arg(
+                    clap::Arg::new("package")
+                        .short('p')
+                        .long("package")
+                        .value_name("CRATE")
+                        .help("Cargo: only this workspace crate (repeatable)")
+                        .action(clap::ArgAction::Append)
+                        .conflicts_with_all(["native", "workspace"]),
+                )
+                .arg(
+                    clap::Arg::new("save")
+                        .long("save")
+                        .help("Cargo: raise version requirements in Cargo.toml to the newest releases they allow, after a preview")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("native"),
+                )
+                .arg(
+                    clap::Arg::new("incompatible")
+                        .long("incompatible")
+                        .help("Cargo, with --save: also move requirements to new major versions")
+                        .action(clap::ArgAction::SetTrue)
+                        .requires("save"),
+                )
+                .arg(
+                    clap::Arg::new("ranges")
+                        .long("ranges")
+                        .help("Node: rewrite the ranges in package.json to the latest versions, then install")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with_all(["native", "workspace", "package", "save"]),
+                )
+                .arg(
+                    clap::Arg::new("select")
+                        .long("select")
+                        .help("Pick the updates to apply from a list grouped by patch/minor/major")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with_all(["native", "workspace", "package", "save", "ranges"]),
+                )
+                .arg(
+                    clap::Arg::new("only")
+                        .long("only")
+                        .value_name("KINDS")
+                        .help("With --select: kinds checked up front, all that --yes applies (default: patch,minor)")
+                        .value_parser(["patch", "minor", "major"])
+                        .value_delimiter(',')
+                        .requires("select"),
+                )
+                .arg(
+                    clap::Arg::new("ignore")
+                        .long("ignore")
+                        .value_name("PKG")
+                        .help("Skip this package, on top of `config dependencies` (repeatable, node:PKG for one ecosystem)")
+                        .action(clap::ArgAction::Append),
+                )
+                .arg(
+                    clap::Arg::new("recursive")
+                        .short('r')
+                        .long("recursive")
+                        .help("Also update the manifests in subdirectories, each from its own directory")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with_all(["native", "workspace", "package", "save", "ranges"]),
+                )
+                .args(scan_args()),
+        )
+        .subcommand(
+            Command::new("sync-translations")
+                .about("Sync translations using Gemini AI")
+                .arg(
+                    clap::Arg::new("no-cache")
+                        .long("no-cache")
+                        .help("Don't use the local translation cache")
                         .action(clap::ArgAction::SetTrue),
                 )
                 .arg(
-                    clap::Arg::new("dry-run")
-                        .long("dry-run")
-                        .help("Show what would be done without making changes")
+                    clap::Arg::new("concurrency")
+                        .long("concurrency")
+                        .value_name("N")
+                        .help("Languages translated at the same time (overrides `config concurrency`)")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    clap::Arg::new("compare-providers")
+                        .long("compare-providers")
+                        .value_name("A,B")
+                        .help("Translate a sample of keys with two providers and compare them")
+                        .value_parser(["gemini", "openai", "anthropic"])
+                        .value_delimiter(','),
+                )
+                .arg(
+                    clap::Arg::new("sample")
+                        .long("sample")
+                        .value_name("N")
+                        .help("Number of keys to compare")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("10")
+                        .requires("compare-providers"),
+                )
+                .arg(
+                    clap::Arg::new("lang")
+                        .long("lang")
+                        .value_name("CODE")
+                        .help("Target language for the comparison (defaults to the first one found)")
+                        .requires("compare-providers"),
+                )
+                .subcommand(
+                    Command::new("cache")
+                        .about("Manage the translation cache")
+                        .subcommand(Command::new("clear").about("Delete all cached translations")),
+                ),
+        )
+        .subcommand(
+            Command::new("translations")
+                .about("Inspect translation files")
+                .subcommand(
+                    Command::new("status")
+                        .about("Show per-language translation progress")
+                        .arg(
+                            clap::Arg::new("dir")
+                                .long("dir")
+                                .value_name("DIR")
+                                .help("Messages directory (defaults to the configured one)"),
+                        )
+                        .arg(
+                            clap::Arg::new("source")
+                                .long("source")
+                                .value_name("FILE")
+                                .help("Source file name (defaults to the configured one)"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("heatmap")
+                        .about("Rank translation keys by how often their strings and the code using them change")
+                        .arg(
+                            clap::Arg::new("path")
+                                .short('p')
+                                .long("path")
+                                .value_name("PATH")
+                                .help("Project path")
+                                .default_value("."),
+                        )
+                        .arg(
+                            clap::Arg::new("dir")
+                                .long("dir")
+                                .value_name("DIR")
+                                .help("Messages directory (defaults to the configured one)"),
+                        )
+                        .arg(
+                            clap::Arg::new("source")
+                                .long("source")
+                                .value_name("FILE")
+                                .help("Source file name (defaults to the configured one)"),
+                        )
+                        .arg(
+                            clap::Arg::new("days")
+                                .long("days")
+                                .value_name("DAYS")
+                                .value_parser(clap::value_parser!(i64))
+                                .help("How far back to look at git history (default: 180)"),
+                        )
+                        .arg(
+                            clap::Arg::new("top")
+                                .long("top")
+                                .value_name("N")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("15")
+                                .help("Number of areas and keys to show"),
+                        )
+                        .arg(
+                            clap::Arg::new("json-file")
+                                .long("json-file")
+                                .value_name("FILE")
+                                .help("Write the full heatmap as JSON"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("validate-translations")
+                .about("Check translations for missing placeholders, HTML tags and empty values")
+                .arg(
+                    clap::Arg::new("dir")
+                        .long("dir")
+                        .value_name("DIR")
+                        .help("Messages directory (defaults to the configured one)"),
+                )
+                .arg(
+                    clap::Arg::new("source")
+                        .long("source")
+                        .value_name("FILE")
+                        .help("Source file name (defaults to the configured one)"),
+                )
+                .arg(
+                    clap::Arg::new("json-file")
+                        .long("json-file")
+                        .value_name("FILE")
+                        .help("Write a JSON report"),
+                )
+                .arg(
+                    clap::Arg::new("strict")
+                        .long("strict")
+                        .help("Also fail on warnings (translations identical to the source)")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("branches")
+                .about("Manage local and remote branches")
+                .subcommand(
+                    Command::new("cleanup")
+                        .about("Delete branches merged into the default branch or without recent commits")
+                        .arg(
+                            clap::Arg::new("days")
+                                .long("days")
+                                .value_name("DAYS")
+                                .value_parser(clap::value_parser!(i64))
+                                .help("Days without commits before a branch counts as stale (default: 90)"),
+                        )
+                        .arg(
+                            clap::Arg::new("dry-run")
+                                .long("dry-run")
+                                .help("Only list the branches that would be offered for deletion")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            clap::Arg::new("local")
+                                .long("local")
+                                .help("Leave remote branches alone")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("commit")
+                .about("Write a conventional commit message step by step and commit the staged changes")
+                .arg(
+                    clap::Arg::new("amend")
+                        .long("amend")
+                        .help("Replace the last commit, starting from its message")
                         .action(clap::ArgAction::SetTrue),
                 )
                 .arg(
-                    clap::Arg::new("list-only")
-                        .long("list-only")
-                        .help("Only list current labels, don't make changes")
+                    clap::Arg::new("signoff")
+                        .short('s')
+                        .long("signoff")
+                        .help("Add a Signed-off-by trailer")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("create-release")
+                .about("Create a new release")
+                .arg(
+                    clap::Arg::new("version")
+                        .help("Release version (e.g., v1.0.0)")
+                        .required(false)
+                        .index(1),
+                )
+                .arg(
+                    clap::Arg::new("message")
+                        .help("Release message")
+                        .required(false)
+                        .index(2),
+                )
+                .arg(
+                    clap::Arg::new("sign")
+                        .long("sign")
+                        .help("GPG-sign the release tag (key from `config release --signing-key`)")
                         .action(clap::ArgAction::SetTrue),
                 )
                 .arg(
-                    clap::Arg::new("delete-all")
-                        .long("delete-all")
-                        .help("Delete all existing labels before creating new ones")
+                    clap::Arg::new("sign-key")
+                        .long("sign-key")
+                        .value_name("KEY")
+                        .help("Sign the release tag with this GPG key id"),
+                )
+                .arg(
+                    clap::Arg::new("force")
+                        .long("force")
+                        .help("Release even when pre-flight checks (clean tree, branch, tag, CI) fail")
                         .action(clap::ArgAction::SetTrue),
                 )
                 .arg(
-                    clap::Arg::new("update-only")
-                        .long("update-only")
-                        .help("Only update existing labels, don't create new ones")
+                    clap::Arg::new("publish")
+                        .long("publish")
+                        .help("Publish the release on GitHub, GitLab or Bitbucket (from the origin remote)")
                         .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("sbom")
+                        .long("sbom")
+                        .value_name("FORMAT")
+                        .value_parser(["cyclonedx", "spdx"])
+                        .help("Write an SBOM to .nitrokit/sbom and attach it to the published release"),
                 ),
         )
         .subcommand(
-            Command::new("version")
-                .about("Manage project versioning")
-                .subcommand(Command::new("patch").about("Bump patch version"))
-                .subcommand(Command::new("minor").about("Bump minor version"))
-                .subcommand(Command::new("major").about("Bump major version"))
-                .subcommand(Command::new("show").about("Show current version"))
-                .subcommand(Command::new("history").about("Show version history")),
+            Command::new("verify-release")
+                .about("Check a release tag's signature and that it is on the release branch")
+                .arg(clap::Arg::new("tag").required(true).index(1))
+                .arg(
+                    clap::Arg::new("branch")
+                        .long("branch")
+                        .short('b')
+                        .value_name("BRANCH")
+                        .help("Release branch (defaults to `config release --branch`, then the default branch)"),
+                ),
         )
         .subcommand(
-            Command::new("config")
-                .about("Manage configuration settings")
+            Command::new("releases")
+                .about("Download and bisect published release binaries")
+                .arg(
+                    clap::Arg::new("repo")
+                        .long("repo")
+                        .value_name("OWNER/NAME")
+                        .help("GitHub repository (defaults to the origin remote)")
+                        .global(true),
+                )
+                .subcommand(
+                    Command::new("fetch")
+                        .about("Download a release asset for this platform into the cache")
+                        .arg(clap::Arg::new("version").required(true).index(1)),
+                )
+                .subcommand(
+                    Command::new("bisect")
+                        .about("Find the first release for which a test command fails")
+                        .arg(
+                            clap::Arg::new("command")
+                                .long("command")
+                                .value_name("CMD")
+                                .help("Test command; {dir}, {asset} and {version} are replaced")
+                                .required(true),
+                        )
+                        .arg(
+                            clap::Arg::new("good")
+                                .long("good")
+                                .value_name("VERSION")
+                                .help("Known good release (defaults to the oldest)"),
+                        )
+                        .arg(
+                            clap::Arg::new("bad")
+                                .long("bad")
+                                .value_name("VERSION")
+                                .help("Known bad release (defaults to the latest)"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("fork")
+                .about("Keep a fork in sync with its upstream repository")
+                .subcommand(
+                    Command::new("sync")
+                        .about("Update the default branch from upstream and push it to origin")
+                        .arg(
+                            clap::Arg::new("upstream")
+                                .long("upstream")
+                                .value_name("REMOTE|URL")
+                                .help("Upstream remote or URL (detected from GitHub by default)"),
+                        )
+                        .arg(
+                            clap::Arg::new("branch")
+                                .short('b')
+                                .long("branch")
+                                .value_name("BRANCH")
+                                .help("Branch to sync (defaults to upstream's default branch)"),
+                        )
+                        .arg(
+                            clap::Arg::new("rebase")
+                                .long("rebase")
+                                .help("Rebase local commits onto upstream when the branch has diverged")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            clap::Arg::new("no-push")
+                                .long("no-push")
+                                .help("Don't push the updated branch to origin")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            clap::Arg::new("dry-run")
+                                .long("dry-run")
+                                .help("Show what would happen without changing anything")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("org")
+                .about("Work across the repositories in the project registry")
+                .subcommand(
+                    Command::new("add")
+                        .about("Register repositories")
+                        .arg(
+                            clap::Arg::new("paths")
+                                .value_name("PATH")
+                                .help("Repository directories")
+                                .required(true)
+                                .num_args(1..),
+                        ),
+                )
+                .subcommand(
+                    Command::new("remove")
+                        .about("Unregister a repository")
+                        .arg(
+                            clap::Arg::new("path")
+                                .value_name("PATH|NAME")
+                                .help("Registered path or directory name")
+                                .required(true),
+                        ),
+                )
+                .subcommand(Command::new("list").about("List registered repositories"))
+                .subcommand(
+                    Command::new("deps")
+                        .about("Aggregate dependency inventories, version skew and security lag")
+                        .arg(
+                            clap::Arg::new("min-severity")
+                                .long("min-severity")
+                                .value_name("LEVEL")
+                                .help("Lowest advisory severity to report")
+                                .value_parser(["low", "moderate", "high", "critical"])
+                                .default_value("critical"),
+                        )
+                        .arg(
+                            clap::Arg::new("no-audit")
+                                .long("no-audit")
+                                .help("Skip the OSV security lookup")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("policy")
+                .about("Release readiness rules from .nitrokit/policy.json")
+                .subcommand(
+                    Command::new("check")
+                        .about("Evaluate every rule and fail if any is not satisfied")
+                        .arg(
+                            clap::Arg::new("path")
+                                .short('p')
+                                .long("path")
+                                .value_name("PATH")
+                                .help("Project directory")
+                                .default_value("."),
+                        ),
+                )
+                .subcommand(Command::new("init").about("Write an example policy file")),
+        )
+        .subcommand(
+            Command::new("self-update")
+                .about("Update nitroterm to the latest release binary from GitHub")
+                .arg(
+                    clap::Arg::new("check")
+                        .long("check")
+                        .help("Only check whether a newer release is available")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("version")
+                        .long("version")
+                        .value_name("TAG")
+                        .help("Install this release instead of the latest one"),
+                ),
+        )
+        .subcommand(
+            Command::new("maintain")
+                .about("Run scheduled maintenance tasks and post a summary to the configured webhooks")
+                .arg(
+                    clap::Arg::new("path")
+                        .long("path")
+                        .short('p')
+                        .value_name("DIR")
+                        .help("Project directory (defaults to the current directory)"),
+                )
+                .arg(
+                    clap::Arg::new("only")
+                        .long("only")
+                        .value_name("TASKS")
+                        .value_delimiter(',')
+                        .value_parser(["deps", "branches", "translations", "quality", "health"])
+                        .help("Run only these tasks (comma separated)"),
+                )
+                .arg(
+                    clap::Arg::new("skip")
+                        .long("skip")
+                        .value_name("TASKS")
+                        .value_delimiter(',')
+                        .value_parser(["deps", "branches", "translations", "quality", "health"])
+                        .help("Skip these tasks (comma separated)"),
+                )
+                .arg(
+                    clap::Arg::new("stale-days")
+                        .long("stale-days")
+                        .value_name("DAYS")
+                        .value_parser(clap::value_parser!(i64))
+                        .help("Days without commits before a branch counts as stale (default: 90)"),
+                )
+                .arg(
+                    clap::Arg::new("no-notify")
+                        .long("no-notify")
+                        .help("Don't post the summary to the notification webhooks")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("report")
+                .about("Generate a self-contained HTML project report")
+                .arg(
+                    clap::Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .default_value(commands::report::DEFAULT_REPORT_FILE)
+                        .help("HTML file to write"),
+                )
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                )
+                .arg(
+                    clap::Arg::new("run-checks")
+                        .long("run-checks")
+                        .help("Run code-quality now instead of reusing code-quality-report.json")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("outdated")
+                        .long("outdated")
+                        .help("Query package registries for outdated dependencies")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("scaffold")
+                .about("Generate editor integration files")
+                .subcommand(
+                    Command::new("vscode")
+                        .about("Write .vscode/tasks.json with nitroterm tasks and problem matchers")
+                        .arg(
+                            clap::Arg::new("path")
+                                .short('p')
+                                .long("path")
+                                .value_name("PATH")
+                                .help("Project path (defaults to the current directory)"),
+                        )
+                        .arg(
+                            clap::Arg::new("force")
+                                .long("force")
+                                .help("Overwrite a tasks.json that can't be parsed (e.g. has comments)")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("verify-deps")
+                .about("Verify lockfile checksums against npm, crates.io and PyPI")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                )
+                .arg(
+                    clap::Arg::new("strict")
+                        .long("strict")
+                        .help("Fail when a package can't be verified")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("sbom")
+                .about("Generate a Software Bill of Materials (CycloneDX or SPDX JSON) from the lockfiles")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                )
+                .arg(
+                    clap::Arg::new("format")
+                        .long("format")
+                        .short('f')
+                        .value_name("FORMAT")
+                        .value_parser(["cyclonedx", "spdx"])
+                        .default_value("cyclonedx")
+                        .help("Document format"),
+                )
+                .arg(
+                    clap::Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_name("FILE")
+                        .help("Output file (default: sbom.cdx.json or sbom.spdx.json)"),
+                ),
+        )
+        .subcommand(
+            Command::new("provenance")
+                .about("Generate a SLSA provenance attestation for release artifacts")
+                .arg(
+                    clap::Arg::new("artifacts")
+                        .help("Artifact files or directories to attest")
+                        .required(true)
+                        .num_args(1..)
+                        .index(1),
+                )
+                .arg(
+                    clap::Arg::new("tag")
+                        .long("tag")
+                        .value_name("TAG")
+                        .help("Release tag (defaults to the tag pointing at HEAD)"),
+                )
+                .arg(
+                    clap::Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .default_value(commands::provenance::DEFAULT_PROVENANCE_FILE)
+                        .help("Attestation file to write"),
+                )
+                .arg(
+                    clap::Arg::new("sign")
+                        .long("sign")
+                        .value_name("MODE")
+                        .value_parser(["keyless", "key"])
+                        .help("Sign the attestation with cosign"),
+                )
+                .arg(
+                    clap::Arg::new("key")
+                        .long("key")
+                        .value_name("KEY")
+                        .help("cosign private key for --sign key"),
+                )
+                .arg(
+                    clap::Arg::new("upload")
+                        .long("upload")
+                        .help("Attach the attestation and signature to the GitHub release")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                ),
+        )
+        .subcommand(
+            Command::new("mcp")
+                .about("Run a read-only MCP server on stdio for AI coding assistants")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                ),
+        )
+        .subcommand(
+            Command::new("serve")
+                .about("Serve release notes, checks and stats over a local HTTP API")
+                .arg(
+                    clap::Arg::new("port")
+                        .long("port")
+                        .value_name("PORT")
+                        .default_value("7070")
+                        .value_parser(clap::value_parser!(u16))
+                        .help("Port to listen on"),
+                )
+                .arg(
+                    clap::Arg::new("host")
+                        .long("host")
+                        .value_name("HOST")
+                        .default_value("127.0.0.1")
+                        .help("Address to bind (keep it local unless you know why)"),
+                )
+                .arg(
+                    clap::Arg::new("token")
+                        .long("token")
+                        .value_name("TOKEN")
+                        .help("Bearer token clients must send (defaults to NITROTERM_API_TOKEN, or a random one)"),
+                )
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path (defaults to the current directory)"),
+                ),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Export project history and analysis data")
+                .subcommand(
+                    Command::new("sqlite")
+                        .about("Write commits, tags, contributors, dependencies, check results and translation stats to SQLite")
+                        .arg(
+                            clap::Arg::new("output")
+                                .short('o')
+                                .long("output")
+                                .value_name("FILE")
+                                .default_value("nitro.db")
+                                .help("Database file to create (replaced if it exists)"),
+                        )
+                        .arg(
+                            clap::Arg::new("path")
+                                .short('p')
+                                .long("path")
+                                .value_name("PATH")
+                                .help("Project path (defaults to the current directory)"),
+                        )
+                        .arg(
+                            clap::Arg::new("quality-report")
+                                .long("quality-report")
+                                .value_name("FILE")
+                                .help("code-quality JSON report to import (default: code-quality-report.json)"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("smoke-test")
+                .about("Build and smoke-test release artifacts (.nitrokit/smoke-tests.json)"),
+        )
+        .subcommand(
+            Command::new("run")
+                .about("Run a pipeline from .nitrokit.toml, or list them")
+                .arg(
+                    clap::Arg::new("pipeline")
+                        .help("Pipeline name, e.g. release")
+                        .index(1),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("List the steps and which ones would be skipped")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("code-quality")
+                .about("Run code quality checks (linting, formatting, security)")
+                .arg(
+                    clap::Arg::new("path")
+                        .short('p')
+                        .long("path")
+                        .value_name("PATH")
+                        .help("Project path to analyze")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("config")
+                        .short('c')
+                        .long("config")
+                        .value_name("FILE")
+                        .help("Custom config file path")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("skip-deps")
+                        .long("skip-deps")
+                        .help("Skip dependency installation")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("checks")
+                        .long("checks")
+                        .value_name("LIST")
+                        .help("Enable specific checks only (comma-separated, e.g. lint,secrets)")
+                        .value_delimiter(',')
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("output")
+                        .long("output")
+                        .value_name("FORMAT")
+                        .help("Write a report in the given format (junit, sarif, json)")
+                        .value_parser(["junit", "sarif", "json"])
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("report-path")
+                        .long("report-path")
+                        .value_name("FILE")
+                        .help("Report file path (defaults to code-quality-report.<ext>)")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("report-html")
+                        .long("report-html")
+                        .value_name("DIR")
+                        .help("Write a self-contained HTML report (checks with their output, project info, outdated dependencies, translations) to DIR/index.html")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("fix")
+                        .long("fix")
+                        .help("Apply formatter and linter fixes, then list the changed files")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with_all(["output", "report-path", "report-html"]),
+                )
+                .arg(
+                    clap::Arg::new("recursive")
+                        .short('r')
+                        .long("recursive")
+                        .help("Check every project below the path (Cargo.toml, package.json, pyproject.toml) and group the results")
+                        .action(clap::ArgAction::SetTrue)
+                        .conflicts_with("fix"),
+                )
+                .args(scan_args().map(|arg| arg.requires("recursive")))
+                .subcommand(
+                    Command::new("history")
+                        .about("Pass rate, duration per check and newly failing checks of recorded runs")
+                        .arg(
+                            clap::Arg::new("limit")
+                                .long("limit")
+                                .value_name("N")
+                                .help("Number of runs to show (default: 10)")
+                                .value_parser(clap::value_parser!(usize)),
+                        )
+                        .arg(
+                            clap::Arg::new("compare-to")
+                                .long("compare-to")
+                                .value_name("REF|RUN")
+                                .help("Compare the latest run with a run id or the run at a git ref, highlighting regressions"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("github-labels")
+                .about("Manage GitHub repository labels with emojis and categorization")
+                .arg(
+                    clap::Arg::new("skip-auth")
+                        .long("skip-auth")
+                        .help("Skip GitHub authentication check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-install")
+                        .long("skip-install")
+                        .help("Skip GitHub CLI installation check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Show what would be done without making changes")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("list-only")
+                        .long("list-only")
+                        .help("Only list current labels, don't make changes")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("delete-all")
+                        .long("delete-all")
+                        .help("Delete all existing labels before creating new ones")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("update-only")
+                        .long("update-only")
+                        .help("Only update existing labels, don't create new ones")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("labels-file")
+                        .long("labels-file")
+                        .value_name("FILE")
+                        .help("YAML/JSON label set to apply instead of the built-in one"),
+                )
+                .arg(
+                    clap::Arg::new("sync")
+                        .long("sync")
+                        .help("Show a create/update/delete diff against the repo and apply it after confirmation")
+                        .conflicts_with_all(["delete-all", "update-only", "list-only"])
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("api")
+                        .long("api")
+                        .help("Use the GitHub REST API with GITHUB_TOKEN instead of the gh CLI")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("repo")
+                        .long("repo")
+                        .value_name("OWNER/NAME")
+                        .help("Repository to manage; repeat to apply the labels to several repos")
+                        .action(clap::ArgAction::Append),
+                )
+                .arg(
+                    clap::Arg::new("repos-file")
+                        .long("repos-file")
+                        .value_name("FILE")
+                        .help("File listing one owner/name per line"),
+                ),
+        )
+        .subcommand(
+            Command::new("github-milestones")
+                .about("Create, update and close GitHub milestones from a YAML/JSON file")
+                .arg(
+                    clap::Arg::new("file")
+                        .long("file")
+                        .short('f')
+                        .value_name("FILE")
+                        .help("Milestones file (defaults to .github/milestones.yml)"),
+                )
+                .arg(
+                    clap::Arg::new("sync")
+                        .long("sync")
+                        .help("Also close open milestones that are not in the file")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("close")
+                        .long("close")
+                        .value_name("TITLE")
+                        .help("Close a single milestone")
+                        .conflicts_with_all(["sync", "list"]),
+                )
+                .arg(
+                    clap::Arg::new("list")
+                        .long("list")
+                        .help("Only list milestones, don't make changes")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Show what would be done without making changes")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-auth")
+                        .long("skip-auth")
+                        .help("Skip GitHub authentication check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-install")
+                        .long("skip-install")
+                        .help("Skip GitHub CLI installation check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("api")
+                        .long("api")
+                        .help("Use the GitHub REST API with GITHUB_TOKEN instead of the gh CLI")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("repo")
+                        .long("repo")
+                        .value_name("OWNER/NAME")
+                        .help("Repository to manage (defaults to the current one)"),
+                ),
+        )
+        .subcommand(
+            Command::new("github-triage")
+                .about("Triage dashboard of open issues and PRs, with bulk labeling")
+                .arg(
+                    clap::Arg::new("stale-days")
+                        .long("stale-days")
+                        .value_name("DAYS")
+                        .value_parser(clap::value_parser!(i64).range(1..))
+                        .help("Days without activity before a pull request counts as stale (default: 14)"),
+                )
+                .arg(
+                    clap::Arg::new("labels-file")
+                        .long("labels-file")
+                        .value_name("FILE")
+                        .help("Label taxonomy with the priority labels (defaults to the github-labels set)"),
+                )
+                .arg(
+                    clap::Arg::new("label")
+                        .long("label")
+                        .value_name("LABEL")
+                        .requires("to")
+                        .help("Label to add in bulk"),
+                )
+                .arg(
+                    clap::Arg::new("to")
+                        .long("to")
+                        .value_name("TARGET")
+                        .requires("label")
+                        .help("unlabeled, missing-priority, stale or issue numbers like 12,15"),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Show which items would be labeled without changing them")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-auth")
+                        .long("skip-auth")
+                        .help("Skip GitHub authentication check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-install")
+                        .long("skip-install")
+                        .help("Skip GitHub CLI installation check")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("api")
+                        .long("api")
+                        .help("Use the GitHub REST API with GITHUB_TOKEN instead of the gh CLI")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("repo")
+                        .long("repo")
+                        .value_name("OWNER/NAME")
+                        .help("Repository to triage (defaults to the current one)"),
+                ),
+        )
+        .subcommand(
+            Command::new("github-templates")
+                .about("Scaffold issue forms and a pull request template under .github")
+                .arg(
+                    clap::Arg::new("path")
+                        .long("path")
+                        .short('p')
+                        .value_name("DIR")
+                        .help("Repository root (defaults to the current directory)"),
+                )
+                .arg(
+                    clap::Arg::new("only")
+                        .long("only")
+                        .value_name("KIND")
+                        .value_parser(["issues", "pr"])
+                        .help("Only scaffold issue templates or the PR template"),
+                )
+                .arg(
+                    clap::Arg::new("force")
+                        .long("force")
+                        .help("Overwrite existing templates")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Show which files would be written")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Create a new project from a template repository")
+                .arg(
+                    clap::Arg::new("template")
+                        .help("Template name (see `config template`), git URL or path")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    clap::Arg::new("name")
+                        .help("Project name (also the target directory)")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    clap::Arg::new("org")
+                        .long("org")
+                        .value_name("ORG")
+                        .help("Organization used for the {{org}} placeholder (default: `config github-org`)")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("license")
+                        .long("license")
+                        .value_name("LICENSE")
+                        .help("License used for the {{license}} placeholder")
+                        .default_value("MIT"),
+                )
+                .arg(
+                    clap::Arg::new("remote")
+                        .long("remote")
+                        .value_name("URL")
+                        .help("Git remote to add as origin")
+                        .required(false),
+                )
+                .arg(
+                    clap::Arg::new("skip-labels")
+                        .long("skip-labels")
+                        .help("Don't apply GitHub labels")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    clap::Arg::new("skip-quality")
+                        .long("skip-quality")
+                        .help("Don't run the initial code quality pass")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("git")
+                .about("Git helpers")
+                .subcommand(
+                    Command::new("setup-identity")
+                        .about("Configure committer identity and commit signing")
+                        .arg(
+                            clap::Arg::new("name")
+                                .long("name")
+                                .value_name("NAME")
+                                .help("Committer name (user.name)"),
+                        )
+                        .arg(
+                            clap::Arg::new("email")
+                                .long("email")
+                                .value_name("EMAIL")
+                                .help("Committer email (user.email)"),
+                        )
+                        .arg(
+                            clap::Arg::new("signing-key")
+                                .long("signing-key")
+                                .value_name("KEY")
+                                .help("GPG key id or SSH public key path used for signing"),
+                        )
+                        .arg(
+                            clap::Arg::new("signing-format")
+                                .long("signing-format")
+                                .value_name("FORMAT")
+                                .help("Signing format (detected from the key if omitted)")
+                                .value_parser(["gpg", "ssh"]),
+                        )
+                        .arg(
+                            clap::Arg::new("global")
+                                .long("global")
+                                .help("Write to the global git config instead of this repository")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            clap::Arg::new("save-profile")
+                                .long("save-profile")
+                                .value_name("NAME")
+                                .help("Save the resulting identity in this config profile"),
+                        )
+                        .arg(
+                            clap::Arg::new("skip-verify")
+                                .long("skip-verify")
+                                .help("Don't create a test commit to verify the setup")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("version")
+                .about("Manage project versioning")
+                .subcommand(version_bump_command("patch", "Bump patch version"))
+                .subcommand(version_bump_command("minor", "Bump minor version"))
+                .subcommand(version_bump_command("major", "Bump major version"))
+                .subcommand(
+                    version_bump_command(
+                        "auto",
+                        "Infer the bump from conventional commits since the last tag (--yes applies it)",
+                    )
+                    .args(commit_filter_args()),
+                )
+                .subcommand(Command::new("show").about("Show current version"))
+                .subcommand(
+                    Command::new("history")
+                        .about("Show the version tags with date, tagger, commit count and highlights")
+                        .arg(
+                            clap::Arg::new("limit")
+                                .long("limit")
+                                .value_name("N")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("10")
+                                .help("Number of versions to show, newest first"),
+                        )
+                        .arg(
+                            clap::Arg::new("graph")
+                                .long("graph")
+                                .help("Draw an ASCII timeline instead of a table")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("check")
+                        .about("Check that the manifests, VERSION file and latest tag agree on the version")
+                        .arg(
+                            clap::Arg::new("fix")
+                                .long("fix")
+                                .help("Rewrite the files that drifted (tags are left alone)")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            clap::Arg::new("to")
+                                .long("to")
+                                .value_name("VERSION")
+                                .help("Version everything should declare (default: the highest found)"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("config")
+                .about("Manage configuration settings")
                 .subcommand(Command::new("show").about("Show current configuration"))
                 .subcommand(Command::new("setup").about("Setup configuration"))
-                .subcommand(Command::new("reset").about("Reset configuration")),
+                .subcommand(
+                    Command::new("onboard")
+                        .about("Run the first-run setup wizard (project, API key, releases, quality checks)"),
+                )
+                .subcommand(Command::new("reset").about("Reset configuration"))
+                .subcommand(
+                    Command::new("template")
+                        .about("Register a project template for `nitroterm new`")
+                        .arg(clap::Arg::new("name").required(true).index(1))
+                        .arg(clap::Arg::new("url").required(true).index(2)),
+                )
+                .subcommand(
+                    Command::new("theme")
+                        .about("Set the color theme (default, colorblind, monochrome)")
+                        .arg(
+                            clap::Arg::new("name")
+                                .required(true)
+                                .value_parser(["default", "colorblind", "monochrome"])
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("github-org")
+                        .about("Set the default GitHub organization, e.g. for `new`")
+                        .arg(clap::Arg::new("org").required(true).index(1)),
+                )
+                .subcommand(
+                    Command::new("labels-file")
+                        .about("Set the default label file for `github-labels`")
+                        .arg(clap::Arg::new("path").required(true).index(1)),
+                )
+                .subcommand(
+                    Command::new("document-locale")
+                        .about("Set the language of generated documents (en, tr, de)")
+                        .arg(
+                            clap::Arg::new("locale")
+                                .required(true)
+                                .value_parser(["en", "tr", "de"])
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("profiles")
+                        .about("Manage named config profiles, picked with --profile or NITROTERM_PROFILE")
+                        .subcommand(Command::new("list").about("List profiles and their settings"))
+                        .subcommand(
+                            Command::new("create")
+                                .about("Create a profile")
+                                .arg(
+                                    clap::Arg::new("name")
+                                        .required(true)
+                                        .help("Letters, digits, - and _")
+                                        .index(1),
+                                )
+                                .arg(
+                                    clap::Arg::new("copy")
+                                        .long("copy")
+                                        .help("Start from the current API keys, repository, labels and release settings")
+                                        .action(clap::ArgAction::SetTrue),
+                                ),
+                        )
+                        .subcommand(
+                            Command::new("delete")
+                                .about("Delete a profile and its keychain entries")
+                                .arg(clap::Arg::new("name").required(true).index(1)),
+                        ),
+                )
+                .subcommand(
+                    Command::new("timezone")
+                        .about("Set the timezone of dates in generated documents and logs")
+                        .arg(
+                            clap::Arg::new("timezone")
+                                .required(true)
+                                .help("utc, local or an offset like +03:00")
+                                .allow_hyphen_values(true)
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("updates")
+                        .about("Set how often and on which channel the menu checks for updates")
+                        .arg(
+                            clap::Arg::new("interval")
+                                .long("interval")
+                                .value_name("HOURS")
+                                .value_parser(clap::value_parser!(u64))
+                                .help("Hours between update checks"),
+                        )
+                        .arg(
+                            clap::Arg::new("channel")
+                                .long("channel")
+                                .value_parser(["stable", "prerelease"])
+                                .help("Offer pre-releases as updates, or only stable releases"),
+                        )
+                        .arg(
+                            clap::Arg::new("enable")
+                                .long("enable")
+                                .action(clap::ArgAction::SetTrue)
+                                .conflicts_with("disable"),
+                        )
+                        .arg(
+                            clap::Arg::new("disable")
+                                .long("disable")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("release")
+                        .about("Set the release signing key and branch (empty value clears)")
+                        .arg(
+                            clap::Arg::new("signing-key")
+                                .long("signing-key")
+                                .value_name("KEY")
+                                .help("GPG key id for `create-release --sign`"),
+                        )
+                        .arg(
+                            clap::Arg::new("branch")
+                                .long("branch")
+                                .value_name("BRANCH")
+                                .help("Branch `verify-release` expects release tags on"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("release-host")
+                        .about("Store GitLab or Bitbucket credentials for `create-release --publish`")
+                        .arg(
+                            clap::Arg::new("host")
+                                .required(true)
+                                .value_parser(["gitlab", "bitbucket"])
+                                .index(1),
+                        )
+                        .arg(
+                            clap::Arg::new("url")
+                                .long("url")
+                                .value_name("URL")
+                                .help("Self-hosted GitLab instance, e.g. https://gitlab.example.com"),
+                        )
+                        .arg(
+                            clap::Arg::new("username")
+                                .long("username")
+                                .value_name("USER")
+                                .help("Bitbucket account the app password belongs to"),
+                        )
+                        .arg(
+                            clap::Arg::new("storage")
+                                .long("storage")
+                                .value_name("STORAGE")
+                                .value_parser(["plain", "keychain", "encrypted"])
+                                .help("Where to keep the token or app password"),
+                        )
+                        .arg(
+                            clap::Arg::new("clear")
+                                .long("clear")
+                                .help("Remove the stored settings of the host")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("release-excludes")
+                        .about("Skip commits by author or message in release notes and `version auto`")
+                        .arg(
+                            clap::Arg::new("author")
+                                .long("author")
+                                .value_name("AUTHOR")
+                                .action(clap::ArgAction::Append)
+                                .help("Author name or email to skip, e.g. dependabot"),
+                        )
+                        .arg(
+                            clap::Arg::new("pattern")
+                                .long("pattern")
+                                .value_name("TEXT")
+                                .action(clap::ArgAction::Append)
+                                .help("Message text to skip, e.g. '[skip changelog]'"),
+                        )
+                        .arg(
+                            clap::Arg::new("clear")
+                                .long("clear")
+                                .help("Remove the configured excludes first")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("dependencies")
+                        .about("Ignore or pin packages in `update-dependencies`")
+                        .arg(
+                            clap::Arg::new("ignore")
+                                .long("ignore")
+                                .value_name("PKG")
+                                .action(clap::ArgAction::Append)
+                                .help("Never update this package; node:PKG limits it to one ecosystem"),
+                        )
+                        .arg(
+                            clap::Arg::new("pin")
+                                .long("pin")
+                                .value_name("PKG@VERSION")
+                                .action(clap::ArgAction::Append)
+                                .help("Only update within this version, e.g. react@18"),
+                        )
+                        .arg(
+                            clap::Arg::new("unset")
+                                .long("unset")
+                                .value_name("PKG")
+                                .action(clap::ArgAction::Append)
+                                .help("Remove the rules for this package"),
+                        )
+                        .arg(
+                            clap::Arg::new("clear")
+                                .long("clear")
+                                .help("Remove all rules first")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("notify")
+                        .about("Add Slack, Discord, Teams or generic webhooks for notifications")
+                        .arg(clap::Arg::new("url").num_args(0..).index(1))
+                        .arg(
+                            clap::Arg::new("clear")
+                                .long("clear")
+                                .help("Remove the configured webhooks first")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("protected-branches")
+                        .about("Add branch patterns `branches cleanup` never deletes (e.g. release/*)")
+                        .arg(clap::Arg::new("pattern").num_args(0..).index(1))
+                        .arg(
+                            clap::Arg::new("clear")
+                                .long("clear")
+                                .help("Remove the configured patterns first")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("github-backend")
+                        .about("Use the gh CLI or the GitHub REST API for `github-labels`")
+                        .arg(
+                            clap::Arg::new("name")
+                                .required(true)
+                                .value_parser(["gh", "api"])
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("models")
+                        .about("List the Gemini models for your API key with their token limits and pick one")
+                        .arg(
+                            clap::Arg::new("model")
+                                .value_name("MODEL")
+                                .help("Store this model without asking, after checking it exists")
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("provider")
+                        .about("Select the translation provider (gemini, openai, anthropic)")
+                        .arg(
+                            clap::Arg::new("name")
+                                .required(true)
+                                .value_parser(["gemini", "openai", "anthropic"])
+                                .index(1),
+                        )
+                        .arg(
+                            clap::Arg::new("api-key")
+                                .long("api-key")
+                                .value_name("KEY")
+                                .help("API key for this provider"),
+                        )
+                        .arg(
+                            clap::Arg::new("model")
+                                .long("model")
+                                .value_name("MODEL")
+                                .help("Model to use with this provider"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("fallback-provider")
+                        .about("Provider to switch to when the primary one fails (or `none`)")
+                        .arg(
+                            clap::Arg::new("name")
+                                .required(true)
+                                .value_parser(["gemini", "openai", "anthropic", "none"])
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("retries")
+                        .about("Retries per translation request on rate limits and server errors (0 disables)")
+                        .arg(
+                            clap::Arg::new("count")
+                                .required(true)
+                                .value_parser(clap::value_parser!(u32))
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("concurrency")
+                        .about("Languages translated at the same time during translation sync")
+                        .arg(
+                            clap::Arg::new("count")
+                                .required(true)
+                                .value_parser(clap::value_parser!(usize))
+                                .index(1),
+                        ),
+                )
+                .subcommand(
+                    Command::new("set-key")
+                        .about("Store a provider API key (plain, in the OS keychain or encrypted)")
+                        .arg(
+                            clap::Arg::new("provider")
+                                .required(true)
+                                .value_parser(["gemini", "openai", "anthropic"])
+                                .index(1),
+                        )
+                        .arg(
+                            clap::Arg::new("storage")
+                                .long("storage")
+                                .value_name("STORAGE")
+                                .value_parser(["plain", "keychain", "encrypted"])
+                                .help("Where to keep the key; also the default for later keys"),
+                        ),
+                )
+                .subcommand(
+                    Command::new("unset-key")
+                        .about("Remove a stored provider API key")
+                        .arg(
+                            clap::Arg::new("provider")
+                                .required(true)
+                                .value_parser(["gemini", "openai", "anthropic"])
+                                .index(1),
+                        ),
+                ),
         );
 
     let matches = app.try_get_matches();
 
+    if matches.as_ref().is_ok_and(|m| m.get_flag("sandbox")) {
+        utils::sandbox::enable_sandbox();
+        println!(
+            "{}",
+            "🔒 Sandbox mode: read-only analysis, no commands or file writes".yellow()
+        );
+    }
+    if matches.as_ref().is_ok_and(|m| m.get_flag("yes")) {
+        utils::prompt::set_assume_yes(true);
+    }
+    if matches.as_ref().is_ok_and(|m| m.get_flag("non-interactive")) {
+        utils::prompt::set_non_interactive(true);
+    }
+    let update_check = !matches.as_ref().is_ok_and(|m| m.get_flag("no-update-check"));
+    if matches.as_ref().is_ok_and(|m| m.get_flag("json")) {
+        utils::output::enable_json_output();
+    }
+    if matches.as_ref().is_ok_and(|m| m.get_flag("notify")) {
+        commands::notifications::set_notify_override(Some(true));
+    } else if matches.as_ref().is_ok_and(|m| m.get_flag("no-notify")) {
+        commands::notifications::set_notify_override(Some(false));
+    }
+    if let Some(seconds) = matches
+        .as_ref()
+        .ok()
+        .and_then(|m| m.get_one::<u64>("command-timeout"))
+    {
+        utils::process::set_command_timeout(*seconds);
+    }
+    let project_dir = match project_directory(matches.as_ref().ok()) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", format!("❌ {}", e).red());
+            std::process::exit(utils::error::ExitCode::Config.code());
+        }
+    };
+    utils::process::install_interrupt_handler();
+    utils::terminal::init();
+    // `config profiles` has to work while NITROTERM_PROFILE names a profile
+    // that isn't there yet
+    let managing_profiles = matches
+        .as_ref()
+        .ok()
+        .and_then(|m| m.subcommand_matches("config"))
+        .is_some_and(|m| m.subcommand_name() == Some("profiles"));
+    if let Err(e) = commands::config::load_profile(
+        matches
+            .as_ref()
+            .ok()
+            .and_then(|m| m.get_one::<String>("profile"))
+            .map(|s| s.as_str()),
+    )
+    .await
+    {
+        if !managing_profiles {
+            eprintln!("{}", format!("❌ {}", e).red());
+            std::process::exit(utils::error::ExitCode::Config.code());
+        }
+    }
+    utils::theme::load_theme().await;
+    commands::releases::load_configured_repo().await;
+    commands::config::load_timezone(matches.as_ref().is_ok_and(|m| m.get_flag("utc"))).await;
+
     match matches {
         Ok(matches) => match matches.subcommand() {
+            Some(("translations", sub_matches)) => {
+                match sub_matches.subcommand() {
+                    Some(("status", status_matches)) => {
+                        if let Err(e) = commands::translation_sync::show_translation_status(
+                            status_matches.get_one::<String>("dir").map(|s| s.as_str()),
+                            status_matches.get_one::<String>("source").map(|s| s.as_str()),
+                        )
+                        .await
+                        {
+                            eprintln!("{}", format!("❌ Failed to read translations: {}", e).red());
+                            exit_for(&e);
+                        }
+                    }
+                    Some(("heatmap", heatmap_matches)) => {
+                        let options = commands::translation_heatmap::HeatmapOptions {
+                            path: std::path::PathBuf::from(
+                                heatmap_matches.get_one::<String>("path").unwrap(),
+                            ),
+                            messages_dir: heatmap_matches.get_one::<String>("dir").cloned(),
+                            source_file: heatmap_matches.get_one::<String>("source").cloned(),
+                            days: heatmap_matches
+                                .get_one::<i64>("days")
+                                .copied()
+                                .unwrap_or(commands::translation_heatmap::DEFAULT_HEATMAP_DAYS),
+                            top: *heatmap_matches.get_one::<usize>("top").unwrap(),
+                            json: heatmap_matches
+                                .get_one::<String>("json-file")
+                                .map(std::path::PathBuf::from),
+                        };
+                        if let Err(e) =
+                            commands::translation_heatmap::run_translation_heatmap(options).await
+                        {
+                            eprintln!("{}", format!("❌ Heatmap failed: {}", e).red());
+                            exit_for(&e);
+                        }
+                    }
+                    _ => {
+                        println!(
+                            "{}",
+                            "Usage: nitroterm translations <status|heatmap> [OPTIONS]".yellow()
+                        );
+                    }
+                }
+            }
+            Some(("validate-translations", sub_matches)) => {
+                let result = commands::translation_validation::run_validate_translations(
+                    sub_matches.get_one::<String>("dir").map(|s| s.as_str()),
+                    sub_matches.get_one::<String>("source").map(|s| s.as_str()),
+                    sub_matches.get_one::<String>("json-file").map(|s| s.as_str()),
+                    sub_matches.get_flag("strict"),
+                )
+                .await;
+
+                match result {
+                    Ok(true) => {}
+                    Ok(false) => std::process::exit(utils::error::ExitCode::ChecksFailed.code()),
+                    Err(e) => {
+                        eprintln!("{}", format!("❌ Translation validation failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+            }
+            Some(("branches", sub_matches)) => match sub_matches.subcommand() {
+                Some(("cleanup", cleanup_matches)) => {
+                    let options = commands::branches::CleanupOptions {
+                        stale_days: cleanup_matches
+                            .get_one::<i64>("days")
+                            .copied()
+                            .unwrap_or(commands::maintain::DEFAULT_STALE_DAYS),
+                        dry_run: cleanup_matches.get_flag("dry-run"),
+                        local_only: cleanup_matches.get_flag("local"),
+                    };
+                    if let Err(e) = commands::branches::run_branch_cleanup(options).await {
+                        eprintln!("{}", format!("❌ Branch cleanup failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                _ => {
+                    println!("{}", "Usage: nitroterm branches cleanup [OPTIONS]".yellow());
+                }
+            },
+            Some(("commit", sub_matches)) => {
+                let options = commands::commit::CommitOptions {
+                    amend: sub_matches.get_flag("amend"),
+                    signoff: sub_matches.get_flag("signoff"),
+                };
+                if let Err(e) = commands::commit::run_commit_wizard(options) {
+                    eprintln!("{}", format!("❌ Commit failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
             Some(("create-release", sub_matches)) => {
+                let config = match commands::config::ConfigManager::new_quiet().await {
+                    Ok(manager) => manager.get_config().await.unwrap_or_default(),
+                    Err(_) => commands::config::AppConfig::default(),
+                };
+                let signing = commands::create_release::TagSigning::resolve(
+                    sub_matches.get_flag("sign"),
+                    sub_matches.get_one::<String>("sign-key").map(|s| s.as_str()),
+                    config.release_signing_key.as_deref(),
+                );
+                let force = sub_matches.get_flag("force");
+                let publish = sub_matches.get_flag("publish");
+                let sbom = sub_matches
+                    .get_one::<String>("sbom")
+                    .and_then(|format| format.parse().ok());
                 if let Some(version) = sub_matches.get_one::<String>("version") {
                     let message = sub_matches.get_one::<String>("message").map(|s| s.as_str());
-                    if let Err(e) =
-                        commands::create_release::create_release_with_args(version, message).await
+                    if let Err(e) = commands::create_release::create_release_with_args(
+                        version, message, signing, force, publish, sbom,
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Release creation failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                } else if let Err(e) = commands::create_release::create_release_interactive(
+                    signing, force, publish, sbom,
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Release creation failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("verify-release", sub_matches)) => {
+                let tag = sub_matches.get_one::<String>("tag").unwrap();
+                let branch = sub_matches.get_one::<String>("branch").map(|s| s.as_str());
+                if let Err(e) =
+                    commands::verify_release::run_verify_release(std::path::Path::new("."), tag, branch).await
+                {
+                    eprintln!("{}", format!("❌ Release verification failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("maintain", sub_matches)) => {
+                let tasks = |name: &str| -> Vec<commands::maintain::MaintenanceTask> {
+                    sub_matches
+                        .get_many::<String>(name)
+                        .map(|values| values.map(|task| task.parse().unwrap()).collect())
+                        .unwrap_or_default()
+                };
+                let config = commands::maintain::MaintainConfig {
+                    path: sub_matches.get_one::<String>("path").cloned(),
+                    only: tasks("only"),
+                    skip: tasks("skip"),
+                    stale_days: sub_matches
+                        .get_one::<i64>("stale-days")
+                        .copied()
+                        .unwrap_or(commands::maintain::DEFAULT_STALE_DAYS),
+                    notify: !sub_matches.get_flag("no-notify"),
+                };
+                if let Err(e) = commands::maintain::run_maintain(config).await {
+                    eprintln!("{}", format!("❌ Maintenance failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("report", sub_matches)) => {
+                let options = commands::report::ReportOptions {
+                    run_checks: sub_matches.get_flag("run-checks"),
+                    check_outdated: sub_matches.get_flag("outdated"),
+                };
+                if let Err(e) = commands::report::run_report(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                    sub_matches.get_one::<String>("output").unwrap(),
+                    options,
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Report generation failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("scaffold", sub_matches)) => match sub_matches.subcommand() {
+                Some(("vscode", vscode_matches)) => {
+                    if let Err(e) = commands::scaffold::run_scaffold_vscode(
+                        vscode_matches.get_one::<String>("path").map(|s| s.as_str()),
+                        vscode_matches.get_flag("force"),
+                    ) {
+                        eprintln!("{}", format!("❌ Scaffolding failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                _ => {
+                    println!("{}", "Usage: nitroterm scaffold vscode [--force]".yellow());
+                }
+            },
+            Some(("verify-deps", sub_matches)) => {
+                if let Err(e) = commands::verify_deps::run_verify_deps(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                    sub_matches.get_flag("strict"),
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Dependency verification failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("sbom", sub_matches)) => {
+                let format = sub_matches
+                    .get_one::<String>("format")
+                    .and_then(|format| format.parse().ok())
+                    .unwrap_or_default();
+                if let Err(e) = commands::sbom::run_sbom(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                    format,
+                    sub_matches.get_one::<String>("output").map(|s| s.as_str()),
+                ) {
+                    eprintln!("{}", format!("❌ SBOM generation failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("provenance", sub_matches)) => {
+                let artifacts: Vec<String> = sub_matches
+                    .get_many::<String>("artifacts")
+                    .map(|values| values.cloned().collect())
+                    .unwrap_or_default();
+                let options = commands::provenance::ProvenanceOptions {
+                    tag: sub_matches.get_one::<String>("tag").cloned(),
+                    output: sub_matches.get_one::<String>("output").cloned(),
+                    sign: sub_matches
+                        .get_one::<String>("sign")
+                        .map(|mode| mode.parse().unwrap()),
+                    key: sub_matches.get_one::<String>("key").cloned(),
+                    upload: sub_matches.get_flag("upload"),
+                };
+                if let Err(e) = commands::provenance::run_provenance(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                    &artifacts,
+                    options,
+                ) {
+                    eprintln!("{}", format!("❌ Provenance generation failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("mcp", sub_matches)) => {
+                if let Err(e) = commands::mcp::run_mcp_server(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ MCP server failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("serve", sub_matches)) => {
+                let options = commands::serve::ServeOptions {
+                    host: sub_matches.get_one::<String>("host").unwrap().clone(),
+                    port: *sub_matches.get_one::<u16>("port").unwrap(),
+                    token: sub_matches.get_one::<String>("token").cloned(),
+                    root: std::path::PathBuf::from(
+                        sub_matches
+                            .get_one::<String>("path")
+                            .map(|s| s.as_str())
+                            .unwrap_or("."),
+                    ),
+                };
+                if let Err(e) = commands::serve::serve(options).await {
+                    eprintln!("{}", format!("❌ API server failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("export", sub_matches)) => {
+                if let Some(("sqlite", sqlite_matches)) = sub_matches.subcommand() {
+                    let options = commands::export::ExportOptions {
+                        quality_report: sqlite_matches.get_one::<String>("quality-report").cloned(),
+                        ..Default::default()
+                    };
+                    if let Err(e) = commands::export::run_export_sqlite(
+                        sqlite_matches.get_one::<String>("path").map(|s| s.as_str()),
+                        sqlite_matches.get_one::<String>("output").unwrap(),
+                        options,
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Export failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                } else {
+                    println!("{}", "Usage: nitroterm export sqlite [--output FILE]".yellow());
+                }
+            }
+            Some(("releases", sub_matches)) => {
+                let repo = sub_matches.get_one::<String>("repo").map(|s| s.as_str());
+                let result = match sub_matches.subcommand() {
+                    Some(("fetch", fetch_matches)) => {
+                        let version = fetch_matches.get_one::<String>("version").unwrap();
+                        commands::releases::fetch_release(version, repo)
+                            .await
+                            .map(|_| ())
+                    }
+                    Some(("bisect", bisect_matches)) => {
+                        let command = bisect_matches.get_one::<String>("command").unwrap();
+                        let good = bisect_matches.get_one::<String>("good").map(|s| s.as_str());
+                        let bad = bisect_matches.get_one::<String>("bad").map(|s| s.as_str());
+                        commands::releases::bisect_releases(command, good, bad, repo)
+                            .await
+                            .map(|_| ())
+                    }
+                    _ => {
+                        println!(
+                            "{}",
+                            "Usage: nitroterm releases <fetch|bisect> [OPTIONS]".yellow()
+                        );
+                        Ok(())
+                    }
+                };
+
+                if let Err(e) = result {
+                    eprintln!("{}", format!("❌ Releases command failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("fork", sub_matches)) => {
+                let result = match sub_matches.subcommand() {
+                    Some(("sync", sync_matches)) => {
+                        let config = commands::fork::ForkSyncConfig {
+                            upstream: sync_matches.get_one::<String>("upstream").cloned(),
+                            branch: sync_matches.get_one::<String>("branch").cloned(),
+                            rebase: sync_matches.get_flag("rebase"),
+                            no_push: sync_matches.get_flag("no-push"),
+                            dry_run: sync_matches.get_flag("dry-run"),
+                        };
+                        commands::fork::run_fork_sync(config).await
+                    }
+                    _ => {
+                        println!("{}", "Usage: nitroterm fork sync [OPTIONS]".yellow());
+                        Ok(())
+                    }
+                };
+
+                if let Err(e) = result {
+                    eprintln!("{}", format!("❌ Fork sync failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("org", sub_matches)) => {
+                let result = match sub_matches.subcommand() {
+                    Some(("add", add_matches)) => {
+                        let paths: Vec<String> = add_matches
+                            .get_many::<String>("paths")
+                            .unwrap()
+                            .cloned()
+                            .collect();
+                        commands::org::add_org_projects(&paths).await
+                    }
+                    Some(("remove", remove_matches)) => {
+                        commands::org::remove_org_project(
+                            remove_matches.get_one::<String>("path").unwrap(),
+                        )
+                        .await
+                    }
+                    Some(("list", _)) => commands::org::list_org_projects().await,
+                    Some(("deps", deps_matches)) => {
+                        commands::org::run_org_deps(
+                            deps_matches.get_flag("no-audit"),
+                            deps_matches.get_one::<String>("min-severity").unwrap(),
+                        )
+                        .await
+                    }
+                    _ => {
+                        println!(
+                            "{}",
+                            "Usage: nitroterm org <add|remove|list|deps> [OPTIONS]".yellow()
+                        );
+                        Ok(())
+                    }
+                };
+
+                if let Err(e) = result {
+                    eprintln!("{}", format!("❌ Org command failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("policy", sub_matches)) => {
+                let result = match sub_matches.subcommand() {
+                    Some(("check", check_matches)) => {
+                        commands::policy::run_policy_check(std::path::Path::new(
+                            check_matches.get_one::<String>("path").unwrap(),
+                        ))
+                        .await
+                    }
+                    Some(("init", _)) => commands::policy::init_policy(std::path::Path::new(".")),
+                    _ => {
+                        println!(
+                            "{}",
+                            "Usage: nitroterm policy <check|init> [OPTIONS]".yellow()
+                        );
+                        Ok(())
+                    }
+                };
+
+                if let Err(e) = result {
+                    eprintln!("{}", format!("❌ Policy check failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("self-update", sub_matches)) => {
+                let config = commands::self_update::SelfUpdateConfig {
+                    check: sub_matches.get_flag("check"),
+                    version: sub_matches.get_one::<String>("version").cloned(),
+                };
+                if let Err(e) = commands::self_update::run_self_update(config, VERSION).await {
+                    eprintln!("{}", format!("❌ Self-update failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("run", sub_matches)) => {
+                if let Err(e) = commands::pipeline::run_command(
+                    sub_matches.get_one::<String>("pipeline").map(|s| s.as_str()),
+                    sub_matches.get_flag("dry-run"),
+                ) {
+                    eprintln!("{}", format!("❌ {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("smoke-test", _)) => {
+                if let Err(e) =
+                    commands::smoke_test::run_smoke_tests(std::path::Path::new(".")).await
+                {
+                    eprintln!("{}", format!("❌ Smoke tests failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("release-notes", sub_matches)) => {
+                if let Some(path) = sub_matches.get_one::<String>("init-template") {
+                    match commands::release_notes_template::write_example_template(
+                        std::path::Path::new(path),
+                    ) {
+                        Ok(()) => println!(
+                            "{}",
+                            format!("📄 Template written to {}", path).green()
+                        ),
+                        Err(e) => {
+                            eprintln!("{}", format!("❌ Failed to write template: {}", e).red());
+                            exit_for(&e);
+                        }
+                    }
+                    return;
+                }
+                let locale = match commands::config::resolve_document_locale(
+                    sub_matches.get_one::<String>("locale").map(|s| s.as_str()),
+                )
+                .await
+                {
+                    Ok(locale) => locale,
+                    Err(e) => {
+                        eprintln!("{}", format!("❌ {}", e).red());
+                        exit_for(&e);
+                    }
+                };
+                let limits = commands::release_notes::CommitLimits {
+                    max_commits: sub_matches.get_one::<usize>("max-commits").copied(),
+                    summary_only: sub_matches.get_flag("summary-only")
+                        || commands::config::load_saved_config().await.release_notes_format
+                            == "summary",
+                    submodules: sub_matches.get_flag("submodules"),
+                    by_team: sub_matches.get_flag("by-team"),
+                };
+                let filter = commit_filter(sub_matches).await;
+                if utils::output::is_json_output() {
+                    let result = match commands::release_notes::release_notes_data(
+                        &project_dir,
+                        !sub_matches.get_flag("offline"),
+                        limits,
+                        &filter,
+                        sub_matches.get_one::<String>("for").map(|s| s.as_str()),
+                    )
+                    .await
+                    {
+                        Ok(data) => utils::output::print_json(&data),
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = result {
+                        eprintln!("{}", format!("❌ Release notes failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                    return;
+                }
+                println!("{}", "🔄 Generating release notes...".yellow());
+                let written = match commands::release_notes::generate_release_notes(
+                    &project_dir,
+                    locale,
+                    !sub_matches.get_flag("offline"),
+                    sub_matches
+                        .get_one::<String>("template")
+                        .map(std::path::Path::new),
+                    limits,
+                    &filter,
+                    sub_matches.get_one::<String>("for").map(|s| s.as_str()),
+                )
+                .await
+                {
+                    Ok(written) => written,
+                    Err(e) => {
+                        eprintln!("{}", format!("❌ Release notes failed: {}", e).red());
+                        std::process::exit(e.exit_code().code());
+                    }
+                };
+                if let (Some(path), true) = (&written, sub_matches.get_flag("summarize")) {
+                    let result = match commands::release_notes::release_notes_data(
+                        &project_dir,
+                        !sub_matches.get_flag("offline"),
+                        limits,
+                        &filter,
+                        sub_matches.get_one::<String>("for").map(|s| s.as_str()),
+                    )
+                    .await
+                    {
+                        Ok(data) => {
+                            commands::release_notes_summary::summarize_release_notes(
+                                &project_dir,
+                                path,
+                                &data,
+                                locale,
+                            )
+                            .await
+                        }
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = result {
+                        eprintln!("{}", format!("❌ Summarizing release notes failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                let languages: Vec<String> = sub_matches
+                    .get_many::<String>("translate")
+                    .map(|values| values.cloned().collect())
+                    .unwrap_or_default();
+                if let (Some(path), false) = (written, languages.is_empty()) {
+                    if let Err(e) = commands::release_notes_translation::translate_release_notes(
+                        &path, &languages,
+                    )
+                    .await
                     {
-                        eprintln!("{}", format!("❌ Release creation failed: {}", e).red());
-                        std::process::exit(1);
+                        eprintln!("{}", format!("❌ Translating release notes failed: {}", e).red());
+                        exit_for(&e);
                     }
-                } else if let Err(e) = commands::create_release::create_release_interactive().await
+                }
+            }
+            Some(("retag", sub_matches)) => {
+                let locale = match commands::config::resolve_document_locale(
+                    sub_matches.get_one::<String>("locale").map(|s| s.as_str()),
+                )
+                .await
                 {
-                    eprintln!("{}", format!("❌ Release creation failed: {}", e).red());
-                    std::process::exit(1);
+                    Ok(locale) => locale,
+                    Err(e) => {
+                        eprintln!("{}", format!("❌ {}", e).red());
+                        exit_for(&e);
+                    }
+                };
+                let options = commands::retag::RetagOptions {
+                    locale,
+                    resolve_links: !sub_matches.get_flag("offline"),
+                    dry_run: sub_matches.get_flag("dry-run"),
+                    push: sub_matches.get_flag("push"),
+                };
+                if let Err(e) = commands::retag::run_retag_annotate(
+                    sub_matches.get_one::<String>("annotate").unwrap(),
+                    &commit_filter(sub_matches).await,
+                    options,
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Retag failed: {}", e).red());
+                    exit_for(&e);
                 }
             }
-            Some(("release-notes", _)) => {
-                println!("{}", "🔄 Generating release notes...".yellow());
-                commands::release_notes::generate_release_notes();
+            Some(("contributors", sub_matches)) => {
+                let options = commands::contributors::ContributorsOptions {
+                    path: std::path::PathBuf::from(sub_matches.get_one::<String>("path").unwrap()),
+                    from: sub_matches.get_one::<String>("from").cloned(),
+                    to: sub_matches.get_one::<String>("to").cloned(),
+                    json: sub_matches.get_flag("json"),
+                    markdown: sub_matches.get_flag("markdown"),
+                };
+                if let Err(e) = commands::contributors::run_contributors(options).await {
+                    eprintln!("{}", format!("❌ Contributor stats failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("diff-report", sub_matches)) => {
+                let options = commands::diff_report::DiffReportOptions {
+                    path: std::path::PathBuf::from(sub_matches.get_one::<String>("path").unwrap()),
+                    from: sub_matches.get_one::<String>("from").cloned().unwrap(),
+                    to: sub_matches.get_one::<String>("to").cloned(),
+                    depth: sub_matches.get_one::<usize>("depth").copied(),
+                    output: sub_matches.get_one::<String>("output").cloned(),
+                    json: sub_matches.get_flag("json"),
+                };
+                if let Err(e) = commands::diff_report::run_diff_report(options).await {
+                    eprintln!("{}", format!("❌ Diff report failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("status", sub_matches)) => {
+                if let Err(e) = commands::state::run_status(
+                    std::path::Path::new(sub_matches.get_one::<String>("path").unwrap()),
+                    sub_matches.get_flag("offline"),
+                    sub_matches.get_flag("json"),
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Status failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("doctor", sub_matches)) => {
+                let features: Vec<commands::doctor::Feature> = sub_matches
+                    .get_many::<String>("feature")
+                    .map(|values| values.map(|feature| feature.parse().unwrap()).collect())
+                    .unwrap_or_default();
+                if let Err(e) = commands::doctor::run_doctor(
+                    std::path::Path::new(sub_matches.get_one::<String>("path").unwrap()),
+                    &features,
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Doctor: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("audit", sub_matches)) => {
+                if let Err(e) = commands::audit::run_audit(
+                    std::path::Path::new(sub_matches.get_one::<String>("path").unwrap()),
+                    sub_matches.get_one::<String>("fail-on").map(|s| s.as_str()),
+                    sub_matches.get_flag("json"),
+                ) {
+                    eprintln!("{}", format!("❌ Audit failed: {}", e).red());
+                    exit_for(&e);
+                }
             }
-            Some(("update-dependencies", _)) => {
-                println!("{}", "🔄 Analyzing and updating dependencies...".yellow());
-                commands::dependency_update::update_dependencies();
+            Some(("update-dependencies", sub_matches)) => {
+                let ignore: Vec<String> = sub_matches
+                    .get_many::<String>("ignore")
+                    .map(|values| values.cloned().collect())
+                    .unwrap_or_default();
+                commands::dependency_update::load_update_rules(&ignore).await;
+                let scan = scan_options(sub_matches, sub_matches.get_flag("recursive"));
+                if sub_matches.get_flag("select") {
+                    let preselect: Vec<commands::selective_update::UpdateKind> = match sub_matches
+                        .get_many::<String>("only")
+                    {
+                        Some(kinds) => kinds.filter_map(|kind| kind.parse().ok()).collect(),
+                        None => vec![
+                            commands::selective_update::UpdateKind::Patch,
+                            commands::selective_update::UpdateKind::Minor,
+                        ],
+                    };
+                    match commands::selective_update::run_selective_update(&project_dir, &preselect, &scan) {
+                        Ok(results) => {
+                            if utils::output::is_json_output() {
+                                if let Err(e) = utils::output::print_json(&results) {
+                                    eprintln!("{}", format!("❌ {}", e).red());
+                                    exit_for(&e);
+                                }
+                            }
+                            commands::dependency_update::notify_update_results(&project_dir, &results).await;
+                            if results
+                                .iter()
+                                .any(|r| r.status == commands::dependency_update::UpdateStatus::Failed)
+                            {
+                                std::process::exit(1);
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("{}", format!("❌ Dependency update failed: {}", e).red());
+                            exit_for(&e);
+                        }
+                    }
+                } else if sub_matches.get_flag("native") {
+                    println!("{}", "🔄 Checking for outdated dependencies...".yellow());
+                    if let Err(e) = commands::lockfiles::run_native_outdated(&project_dir).await {
+                        eprintln!("{}", format!("❌ Outdated check failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                } else if sub_matches.get_flag("ranges") {
+                    if let Err(e) = commands::package_json::run_range_upgrade(&project_dir).await {
+                        eprintln!("{}", format!("❌ package.json upgrade failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                } else if sub_matches.get_flag("workspace")
+                    || sub_matches.get_flag("save")
+                    || sub_matches.contains_id("package")
+                {
+                    let options = commands::cargo_workspace::CargoUpdateOptions {
+                        packages: sub_matches
+                            .get_many::<String>("package")
+                            .map(|packages| packages.cloned().collect())
+                            .unwrap_or_default(),
+                        workspace: sub_matches.get_flag("workspace"),
+                        save: sub_matches.get_flag("save"),
+                        incompatible: sub_matches.get_flag("incompatible"),
+                    };
+                    if let Err(e) = commands::cargo_workspace::run_cargo_update(&project_dir, options).await {
+                        eprintln!("{}", format!("❌ Cargo update failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                } else if utils::output::is_json_output() {
+                    let results = commands::dependency_update::update_dependencies(&project_dir, &scan);
+                    if let Err(e) = utils::output::print_json(&results) {
+                        eprintln!("{}", format!("❌ {}", e).red());
+                        exit_for(&e);
+                    }
+                    commands::dependency_update::notify_update_results(&project_dir, &results).await;
+                } else {
+                    println!("{}", "🔄 Analyzing and updating dependencies...".yellow());
+                    let results = commands::dependency_update::update_dependencies(&project_dir, &scan);
+                    commands::dependency_update::notify_update_results(&project_dir, &results).await;
+                }
             }
-            Some(("sync-translations", _)) => {
+            Some(("sync-translations", sub_matches)) => {
+                if let Some(("cache", cache_matches)) = sub_matches.subcommand() {
+                    if let Some(("clear", _)) = cache_matches.subcommand() {
+                        if let Err(e) = commands::translation_sync::clear_translation_cache() {
+                            eprintln!("{}", format!("❌ Failed to clear cache: {}", e).red());
+                            exit_for(&e);
+                        }
+                    }
+                    return;
+                }
+
+                if let Some(providers) = sub_matches.get_many::<String>("compare-providers") {
+                    let providers: Vec<commands::translation_sync::ProviderKind> =
+                        providers.filter_map(|name| name.parse().ok()).collect();
+                    if providers.len() != 2 {
+                        eprintln!(
+                            "{}",
+                            "❌ --compare-providers takes two providers, e.g. gemini,openai".red()
+                        );
+                        std::process::exit(2);
+                    }
+                    let sample = *sub_matches.get_one::<usize>("sample").unwrap();
+                    let lang = sub_matches.get_one::<String>("lang").map(|s| s.as_str());
+                    if let Err(e) = commands::translation_sync::run_provider_comparison(
+                        &project_dir,
+                        (providers[0], providers[1]),
+                        sample,
+                        lang,
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Provider comparison failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                    return;
+                }
+
+                let use_cache = !sub_matches.get_flag("no-cache");
+                let concurrency = sub_matches.get_one::<usize>("concurrency").copied();
                 println!("{}", "🌍 Syncing translations...".yellow());
-                if let Err(e) = commands::translation_sync::sync_translations_interactive().await {
+                if let Err(e) = commands::translation_sync::sync_translations_interactive(
+                    &project_dir,
+                    use_cache,
+                    concurrency,
+                )
+                .await
+                {
                     eprintln!("{}", format!("❌ Translation sync failed: {}", e).red());
-                    std::process::exit(1);
+                    exit_for(&e);
                 }
             }
             Some(("code-quality", sub_matches)) => {
                 let path = sub_matches.get_one::<String>("path").cloned();
+                if let Some(("history", history_matches)) = sub_matches.subcommand() {
+                    let root = path.map(std::path::PathBuf::from).unwrap_or_else(|| ".".into());
+                    if let Err(e) = commands::quality_history::run_quality_history(
+                        &root,
+                        history_matches
+                            .get_one::<usize>("limit")
+                            .copied()
+                            .unwrap_or(commands::quality_history::DEFAULT_HISTORY_LIMIT),
+                        history_matches.get_one::<String>("compare-to").map(|s| s.as_str()),
+                    ) {
+                        eprintln!("{}", format!("❌ Failed to show code-quality history: {}", e).red());
+                        exit_for(&e);
+                    }
+                    return;
+                }
                 let config_path = sub_matches.get_one::<String>("config").cloned();
                 let skip_deps = sub_matches.get_flag("skip-deps");
                 let checks: Option<Vec<String>> = sub_matches
                     .get_many::<String>("checks")
                     .map(|vals| vals.cloned().collect());
+                let output_format = sub_matches
+                    .get_one::<String>("output")
+                    .and_then(|f| f.parse::<commands::code_quality::ReportFormat>().ok());
+                let report_path = sub_matches.get_one::<String>("report-path").cloned();
 
-                println!("{}", "🔍 Running code quality checks...".yellow());
+                if !sub_matches.get_flag("fix") && !utils::output::is_json_output() {
+                    println!("{}", "🔍 Running code quality checks...".yellow());
+                }
                 let mut quality_config = if let Some(config_file) = &config_path {
                     match tokio::fs::read_to_string(config_file).await {
                         Ok(content) => match serde_json::from_str(&content) {
                             Ok(config) => config,
                             Err(e) => {
                                 eprintln!("{}", format!("❌ Failed to parse config file: {}", e).red());
-                                std::process::exit(1);
+                                std::process::exit(utils::error::ExitCode::Config.code());
                             }
                         },
                         Err(e) => {
                             eprintln!("{}", format!("❌ Failed to read config file: {}", e).red());
-                            std::process::exit(1);
+                            std::process::exit(utils::error::ExitCode::Config.code());
                         }
                     }
                 } else {
                     commands::code_quality::CodeQualityConfig::default()
                 };
 
+                if config_path.is_none() {
+                    let saved = commands::config::load_saved_config().await.quality_checks;
+                    if !saved.is_empty() {
+                        quality_config.enabled_checks = saved;
+                    }
+                }
+
                 if skip_deps {
                     quality_config.skip_dependencies = true;
                 }
@@ -333,73 +3054,269 @@ async fn main() {
                     quality_config.enabled_checks = check_list;
                 }
 
-                if let Err(e) = commands::code_quality::run_code_quality(path, config_path).await {
+                if sub_matches.get_flag("fix") {
+                    if let Err(e) =
+                        commands::code_quality::run_code_quality_fix(path, quality_config).await
+                    {
+                        eprintln!("{}", format!("❌ Code quality fixes failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                    return;
+                }
+
+                if let Err(e) = commands::code_quality::run_code_quality(
+                    path,
+                    quality_config,
+                    output_format,
+                    report_path,
+                    sub_matches
+                        .get_flag("recursive")
+                        .then(|| scan_options(sub_matches, true)),
+                    sub_matches.get_one::<String>("report-html").cloned(),
+                )
+                .await
+                {
                     eprintln!("{}", format!("❌ Code quality checks failed: {}", e).red());
-                    std::process::exit(1);
+                    exit_for(&e);
                 }
             }
             Some(("github-labels", sub_matches)) => {
-                let skip_auth = sub_matches.get_flag("skip-auth");
-                let skip_install = sub_matches.get_flag("skip-install");
-                let dry_run = sub_matches.get_flag("dry-run");
-                let list_only = sub_matches.get_flag("list-only");
-                let delete_all = sub_matches.get_flag("delete-all");
-                let update_only = sub_matches.get_flag("update-only");
-
-                if let Err(e) = commands::github_labels::run_github_labels(
-                    skip_auth, skip_install, dry_run, list_only, delete_all, update_only
-                ).await {
+                let config = commands::github_labels::GitHubLabelsConfig {
+                    skip_auth: sub_matches.get_flag("skip-auth"),
+                    skip_install: sub_matches.get_flag("skip-install"),
+                    dry_run: sub_matches.get_flag("dry-run"),
+                    list_only: sub_matches.get_flag("list-only"),
+                    delete_all: sub_matches.get_flag("delete-all"),
+                    update_only: sub_matches.get_flag("update-only"),
+                    labels_file: sub_matches.get_one::<String>("labels-file").cloned(),
+                    sync: sub_matches.get_flag("sync"),
+                    api: sub_matches.get_flag("api"),
+                    repo: None,
+                    dir: project_dir.clone(),
+                };
+
+                let mut repos: Vec<String> = sub_matches
+                    .get_many::<String>("repo")
+                    .map(|values| values.cloned().collect())
+                    .unwrap_or_default();
+                if let Some(file) = sub_matches.get_one::<String>("repos-file") {
+                    match std::fs::read_to_string(file)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|content| commands::github_labels::parse_repo_list(&content))
+                    {
+                        Ok(listed) => repos.extend(listed),
+                        Err(e) => {
+                            eprintln!("{}", format!("❌ Could not read {}: {}", file, e).red());
+                            exit_for(&e);
+                        }
+                    }
+                }
+
+                let result = match repos.as_slice() {
+                    [] => commands::github_labels::run_github_labels(config).await,
+                    [repo] => {
+                        commands::github_labels::run_github_labels(
+                            commands::github_labels::GitHubLabelsConfig {
+                                repo: Some(repo.clone()),
+                                ..config
+                            },
+                        )
+                        .await
+                    }
+                    _ => commands::github_labels::run_github_labels_for_repos(config, &repos).await,
+                };
+                if let Err(e) = result {
                     eprintln!("{}", format!("❌ GitHub labels management failed: {}", e).red());
-                    std::process::exit(1);
+                    exit_for(&e);
+                }
+            }
+            Some(("github-milestones", sub_matches)) => {
+                let config = commands::github_milestones::GitHubMilestonesConfig {
+                    skip_auth: sub_matches.get_flag("skip-auth"),
+                    skip_install: sub_matches.get_flag("skip-install"),
+                    dry_run: sub_matches.get_flag("dry-run"),
+                    list_only: sub_matches.get_flag("list"),
+                    file: sub_matches.get_one::<String>("file").cloned(),
+                    sync: sub_matches.get_flag("sync"),
+                    close: sub_matches.get_one::<String>("close").cloned(),
+                    api: sub_matches.get_flag("api"),
+                    repo: sub_matches.get_one::<String>("repo").cloned(),
+                };
+                if let Err(e) = commands::github_milestones::run_github_milestones(config).await {
+                    eprintln!("{}", format!("❌ GitHub milestones management failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("github-triage", sub_matches)) => {
+                let config = commands::github_triage::GitHubTriageConfig {
+                    skip_auth: sub_matches.get_flag("skip-auth"),
+                    skip_install: sub_matches.get_flag("skip-install"),
+                    dry_run: sub_matches.get_flag("dry-run"),
+                    stale_days: sub_matches
+                        .get_one::<i64>("stale-days")
+                        .copied()
+                        .unwrap_or(commands::github_triage::DEFAULT_STALE_DAYS),
+                    labels_file: sub_matches.get_one::<String>("labels-file").cloned(),
+                    label: sub_matches.get_one::<String>("label").cloned(),
+                    to: sub_matches.get_one::<String>("to").cloned(),
+                    api: sub_matches.get_flag("api"),
+                    repo: sub_matches.get_one::<String>("repo").cloned(),
+                };
+                if let Err(e) = commands::github_triage::run_github_triage(config).await {
+                    eprintln!("{}", format!("❌ GitHub triage failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("github-templates", sub_matches)) => {
+                if let Err(e) = commands::github_templates::run_github_templates(
+                    sub_matches.get_one::<String>("path").map(|s| s.as_str()),
+                    sub_matches
+                        .get_one::<String>("only")
+                        .map(|kind| kind.parse().unwrap()),
+                    sub_matches.get_flag("force"),
+                    sub_matches.get_flag("dry-run"),
+                ) {
+                    eprintln!("{}", format!("❌ Template scaffolding failed: {}", e).red());
+                    exit_for(&e);
+                }
+            }
+            Some(("new", sub_matches)) => {
+                let config = commands::new_project::NewProjectConfig {
+                    template: sub_matches.get_one::<String>("template").cloned().unwrap_or_default(),
+                    name: sub_matches.get_one::<String>("name").cloned().unwrap_or_default(),
+                    org: sub_matches.get_one::<String>("org").cloned(),
+                    license: sub_matches
+                        .get_one::<String>("license")
+                        .cloned()
+                        .unwrap_or_else(|| "MIT".to_string()),
+                    remote: sub_matches.get_one::<String>("remote").cloned(),
+                    skip_labels: sub_matches.get_flag("skip-labels"),
+                    skip_quality: sub_matches.get_flag("skip-quality"),
+                };
+
+                println!("{}", "🆕 Creating new project...".yellow());
+                if let Err(e) = commands::new_project::create_new_project(config).await {
+                    eprintln!("{}", format!("❌ Project creation failed: {}", e).red());
+                    exit_for(&e);
                 }
             }
+            Some(("git", sub_matches)) => match sub_matches.subcommand() {
+                Some(("setup-identity", identity_matches)) => {
+                    let options = commands::git_identity::IdentityOptions {
+                        name: identity_matches.get_one::<String>("name").cloned(),
+                        email: identity_matches.get_one::<String>("email").cloned(),
+                        signing_key: identity_matches.get_one::<String>("signing-key").cloned(),
+                        signing_format: identity_matches.get_one::<String>("signing-format").cloned(),
+                        global: identity_matches.get_flag("global"),
+                        save_profile: identity_matches.get_one::<String>("save-profile").cloned(),
+                        skip_verify: identity_matches.get_flag("skip-verify"),
+                    };
+
+                    if let Err(e) = commands::git_identity::setup_identity(options).await {
+                        eprintln!("{}", format!("❌ Identity setup failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                _ => {
+                    println!("{}", "Usage: nitroterm git setup-identity [OPTIONS]".yellow());
+                }
+            },
             Some(("version", sub_matches)) => match sub_matches.subcommand() {
-                Some(("patch", _)) => {
+                Some(("patch", bump_matches)) => {
                     println!("{}", "🔄 Bumping patch version...".yellow());
-                    if let Err(e) =
-                        commands::version_management::bump_and_release("patch", None).await
+                    if let Err(e) = commands::version_management::bump_and_release(
+                        &project_dir,
+                        "patch",
+                        None,
+                        &version_bump_options(bump_matches),
+                    )
+                    .await
                     {
                         eprintln!(
                             "{}",
                             format!("❌ Failed to bump patch version: {}", e).red()
                         );
-                        std::process::exit(1);
+                        exit_for(&e);
                     }
                 }
-                Some(("minor", _)) => {
+                Some(("minor", bump_matches)) => {
                     println!("{}", "🔄 Bumping minor version...".yellow());
-                    if let Err(e) =
-                        commands::version_management::bump_and_release("minor", None).await
+                    if let Err(e) = commands::version_management::bump_and_release(
+                        &project_dir,
+                        "minor",
+                        None,
+                        &version_bump_options(bump_matches),
+                    )
+                    .await
                     {
                         eprintln!(
                             "{}",
                             format!("❌ Failed to bump minor version: {}", e).red()
                         );
-                        std::process::exit(1);
+                        exit_for(&e);
                     }
                 }
-                Some(("major", _)) => {
+                Some(("major", bump_matches)) => {
                     println!("{}", "🔄 Bumping major version...".yellow());
-                    if let Err(e) =
-                        commands::version_management::bump_and_release("major", None).await
+                    if let Err(e) = commands::version_management::bump_and_release(
+                        &project_dir,
+                        "major",
+                        None,
+                        &version_bump_options(bump_matches),
+                    )
+                    .await
                     {
                         eprintln!(
                             "{}",
                             format!("❌ Failed to bump major version: {}", e).red()
                         );
-                        std::process::exit(1);
+                        exit_for(&e);
+                    }
+                }
+                Some(("auto", bump_matches)) => {
+                    if let Err(e) = commands::version_management::auto_bump(
+                        &project_dir,
+                        &version_bump_options(bump_matches),
+                        bump_matches.get_flag("yes"),
+                        &commit_filter(bump_matches).await,
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to bump version: {}", e).red());
+                        exit_for(&e);
                     }
                 }
                 Some(("show", _)) => {
                     println!("{}", format!("Current version: v{}", VERSION).cyan().bold());
                 }
-                Some(("history", _)) => {
-                    if let Err(e) = commands::version_management::show_version_history().await {
+                Some(("check", check_matches)) => {
+                    if let Err(e) = commands::version_management::check_versions(
+                        &project_dir,
+                        check_matches.get_flag("fix"),
+                        check_matches.get_one::<String>("to").map(String::as_str),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Version check failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("history", history_matches)) => {
+                    if let Err(e) = commands::version_management::show_version_history(
+                        &project_dir,
+                        history_matches
+                            .get_one::<usize>("limit")
+                            .copied()
+                            .unwrap_or(commands::version_management::HISTORY_LIMIT),
+                        history_matches.get_flag("graph"),
+                    )
+                    .await
+                    {
                         eprintln!(
                             "{}",
                             format!("❌ Failed to show version history: {}", e).red()
                         );
-                        std::process::exit(1);
+                        exit_for(&e);
                     }
                 }
                 _ => {
@@ -410,48 +3327,342 @@ async fn main() {
                 Some(("show", _)) => {
                     if let Err(e) = commands::translation_sync::show_config().await {
                         eprintln!("{}", format!("❌ Failed to show config: {}", e).red());
-                        std::process::exit(1);
+                        exit_for(&e);
                     }
                 }
                 Some(("setup", _)) => {
                     if let Err(e) = commands::translation_sync::setup_config().await {
                         eprintln!("{}", format!("❌ Failed to setup config: {}", e).red());
-                        std::process::exit(1);
+                        exit_for(&e);
+                    }
+                }
+                Some(("onboard", _)) => {
+                    let result = match commands::config::ConfigManager::new_quiet().await {
+                        Ok(manager) => commands::onboarding::run_onboarding(&manager)
+                            .await
+                            .map(|_| ()),
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = result {
+                        eprintln!("{}", format!("❌ Setup failed: {}", e).red());
+                        exit_for(&e);
                     }
                 }
                 Some(("reset", _)) => {
                     if let Err(e) = commands::translation_sync::reset_config().await {
                         eprintln!("{}", format!("❌ Failed to reset config: {}", e).red());
-                        std::process::exit(1);
+                        exit_for(&e);
+                    }
+                }
+                Some(("template", template_matches)) => {
+                    let name = template_matches.get_one::<String>("name").unwrap();
+                    let url = template_matches.get_one::<String>("url").unwrap();
+                    if let Err(e) = commands::new_project::register_template(name, url).await {
+                        eprintln!("{}", format!("❌ Failed to register template: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("theme", theme_matches)) => {
+                    let name = theme_matches.get_one::<String>("name").unwrap();
+                    if let Err(e) = commands::config::set_theme(name).await {
+                        eprintln!("{}", format!("❌ Failed to set theme: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("github-org", org_matches)) => {
+                    let org = org_matches.get_one::<String>("org").unwrap();
+                    if let Err(e) = commands::config::set_github_org(org).await {
+                        eprintln!("{}", format!("❌ Failed to set GitHub organization: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("labels-file", labels_matches)) => {
+                    let path = labels_matches.get_one::<String>("path").unwrap();
+                    if let Err(e) = commands::github_labels::set_labels_file(path).await {
+                        eprintln!("{}", format!("❌ Failed to set labels file: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("document-locale", locale_matches)) => {
+                    let locale = locale_matches.get_one::<String>("locale").unwrap();
+                    if let Err(e) = commands::config::set_document_locale(locale).await {
+                        eprintln!("{}", format!("❌ Failed to set document locale: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("profiles", profiles_matches)) => {
+                    let result = match profiles_matches.subcommand() {
+                        Some(("create", create_matches)) => {
+                            commands::config::create_profile(
+                                create_matches.get_one::<String>("name").unwrap(),
+                                create_matches.get_flag("copy"),
+                            )
+                            .await
+                        }
+                        Some(("delete", delete_matches)) => {
+                            commands::config::delete_profile(
+                                delete_matches.get_one::<String>("name").unwrap(),
+                            )
+                            .await
+                        }
+                        _ => commands::config::list_profiles().await,
+                    };
+                    if let Err(e) = result {
+                        eprintln!("{}", format!("❌ {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("timezone", timezone_matches)) => {
+                    let timezone = timezone_matches.get_one::<String>("timezone").unwrap();
+                    if let Err(e) = commands::config::set_timezone(timezone).await {
+                        eprintln!("{}", format!("❌ Failed to set timezone: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("updates", updates_matches)) => {
+                    let enabled = if updates_matches.get_flag("enable") {
+                        Some(true)
+                    } else if updates_matches.get_flag("disable") {
+                        Some(false)
+                    } else {
+                        None
+                    };
+                    if let Err(e) = commands::config::set_update_check(
+                        enabled,
+                        updates_matches.get_one::<u64>("interval").copied(),
+                        updates_matches.get_one::<String>("channel").map(|s| s.as_str()),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set update check: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("release", release_matches)) => {
+                    if let Err(e) = commands::config::set_release_settings(
+                        release_matches
+                            .get_one::<String>("signing-key")
+                            .map(|s| s.as_str()),
+                        release_matches.get_one::<String>("branch").map(|s| s.as_str()),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set release settings: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("release-host", host_matches)) => {
+                    let option = |id: &str| host_matches.get_one::<String>(id).map(|s| s.as_str());
+                    if let Err(e) = commands::config::set_release_host(
+                        option("host").unwrap(),
+                        option("url"),
+                        option("username"),
+                        option("storage").map(|storage| storage.parse().unwrap()),
+                        host_matches.get_flag("clear"),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set release host: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("release-excludes", exclude_matches)) => {
+                    let values = |id: &str| -> Vec<String> {
+                        exclude_matches
+                            .get_many::<String>(id)
+                            .map(|values| values.cloned().collect())
+                            .unwrap_or_default()
+                    };
+                    if let Err(e) = commands::config::set_release_excludes(
+                        &values("author"),
+                        &values("pattern"),
+                        exclude_matches.get_flag("clear"),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set release excludes: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("dependencies", rule_matches)) => {
+                    let values = |id: &str| -> Vec<String> {
+                        rule_matches
+                            .get_many::<String>(id)
+                            .map(|values| values.cloned().collect())
+                            .unwrap_or_default()
+                    };
+                    if let Err(e) = commands::config::set_dependency_update_rules(
+                        &values("ignore"),
+                        &values("pin"),
+                        &values("unset"),
+                        rule_matches.get_flag("clear"),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set dependency rules: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("protected-branches", protected_matches)) => {
+                    let patterns: Vec<String> = protected_matches
+                        .get_many::<String>("pattern")
+                        .map(|values| values.cloned().collect())
+                        .unwrap_or_default();
+                    if let Err(e) = commands::config::set_protected_branches(
+                        &patterns,
+                        protected_matches.get_flag("clear"),
+                    )
+                    .await
+                    {
+                        eprintln!(
+                            "{}",
+                            format!("❌ Failed to set protected branches: {}", e).red()
+                        );
+                        exit_for(&e);
+                    }
+                }
+                Some(("notify", notify_matches)) => {
+                    let urls: Vec<String> = notify_matches
+                        .get_many::<String>("url")
+                        .map(|values| values.cloned().collect())
+                        .unwrap_or_default();
+                    if let Err(e) = commands::config::set_notify_webhooks(
+                        &urls,
+                        notify_matches.get_flag("clear"),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set webhooks: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("github-backend", backend_matches)) => {
+                    let name = backend_matches.get_one::<String>("name").unwrap();
+                    if let Err(e) = commands::github_labels::set_github_backend(name).await {
+                        eprintln!("{}", format!("❌ Failed to set GitHub backend: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("models", models_matches)) => {
+                    if let Err(e) = commands::gemini_models::run_models(
+                        models_matches.get_one::<String>("model").map(|s| s.as_str()),
+                        models_matches.get_flag("json"),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Model selection failed: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("provider", provider_matches)) => {
+                    let name = provider_matches.get_one::<String>("name").unwrap();
+                    let api_key = provider_matches.get_one::<String>("api-key");
+                    let model = provider_matches.get_one::<String>("model");
+                    if let Err(e) = commands::translation_sync::set_translation_provider(
+                        name,
+                        api_key.map(|s| s.as_str()),
+                        model.map(|s| s.as_str()),
+                    )
+                    .await
+                    {
+                        eprintln!("{}", format!("❌ Failed to set provider: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("fallback-provider", fallback_matches)) => {
+                    let name = fallback_matches.get_one::<String>("name").unwrap();
+                    if let Err(e) = commands::translation_sync::set_fallback_provider(name).await {
+                        eprintln!("{}", format!("❌ Failed to set fallback provider: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("retries", retries_matches)) => {
+                    let count = *retries_matches.get_one::<u32>("count").unwrap();
+                    if let Err(e) = commands::translation_sync::set_max_retries(count).await {
+                        eprintln!("{}", format!("❌ Failed to set retries: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("concurrency", concurrency_matches)) => {
+                    let count = *concurrency_matches.get_one::<usize>("count").unwrap();
+                    if let Err(e) = commands::translation_sync::set_concurrency(count).await {
+                        eprintln!("{}", format!("❌ Failed to set concurrency: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("set-key", key_matches)) => {
+                    let provider = key_matches.get_one::<String>("provider").unwrap();
+                    let storage = key_matches
+                        .get_one::<String>("storage")
+                        .map(|storage| storage.parse().unwrap());
+                    if let Err(e) =
+                        commands::translation_sync::set_api_key(provider, storage).await
+                    {
+                        eprintln!("{}", format!("❌ Failed to store API key: {}", e).red());
+                        exit_for(&e);
+                    }
+                }
+                Some(("unset-key", key_matches)) => {
+                    let provider = key_matches.get_one::<String>("provider").unwrap();
+                    if let Err(e) = commands::translation_sync::unset_api_key(provider).await {
+                        eprintln!("{}", format!("❌ Failed to remove API key: {}", e).red());
+                        exit_for(&e);
                     }
                 }
                 _ => {
                     if let Err(e) = commands::translation_sync::show_config().await {
                         eprintln!("{}", format!("❌ Failed to show config: {}", e).red());
-                        std::process::exit(1);
+                        exit_for(&e);
                     }
                 }
             },
+            _ if utils::prompt::is_non_interactive() => {
+                eprintln!(
+                    "{}",
+                    "❌ No command given; the interactive menu is not available with --yes or --non-interactive".red()
+                );
+                std::process::exit(2);
+            }
             _ => {
-                run_interactive_mode().await;
+                run_interactive_mode(update_check).await;
             }
         },
-        Err(_) => {
-            run_interactive_mode().await;
+        Err(e) => {
+            // --help/--version and usage errors print normally instead of
+            // dropping into the menu when nobody is there to answer it, or
+            // when a pipeline step has a typo
+            if utils::prompt::is_non_interactive()
+                || std::env::args().any(|arg| arg == "--yes" || arg == "--non-interactive")
+                || std::env::var_os(commands::pipeline::PIPELINE_ENV).is_some()
+            {
+                e.exit();
+            }
+            run_interactive_mode(update_check).await;
         }
     }
 }
 
-async fn run_interactive_mode() {
+async fn run_interactive_mode(update_check: bool) {
     print_banner();
-    let _ = utils::check_for_updates(VERSION, false).await;
+    commands::onboarding::onboard_if_first_run().await;
+    if update_check {
+        let settings = utils::version_check::load_update_settings().await;
+        let _ = utils::check_for_updates(VERSION, false, &settings).await;
+    }
     loop {
         show_menu();
         let input = get_user_input();
         match input.as_str() {
             "1" | "create-release" => {
                 println!("{}", "\n🚀 Creating release...".yellow());
-                if let Err(e) = commands::create_release::create_release_interactive().await {
+                if let Err(e) = commands::create_release::create_release_interactive(
+                    commands::create_release::TagSigning::None,
+                    false,
+                    false,
+                    None,
+                )
+                .await
+                {
                     println!("{}", format!("❌ Release creation failed: {}", e).red());
                 }
                 println!("\n{}", "Press Enter to continue...".dimmed());
@@ -459,19 +3670,46 @@ async fn run_interactive_mode() {
             }
             "2" | "release-notes" => {
                 println!("{}", "\n🔄 Generating release notes...".yellow());
-                commands::release_notes::generate_release_notes();
+                let locale = commands::config::resolve_document_locale(None)
+                    .await
+                    .unwrap_or_default();
+                let limits = commands::release_notes::CommitLimits {
+                    summary_only: commands::config::load_saved_config()
+                        .await
+                        .release_notes_format
+                        == "summary",
+                    ..Default::default()
+                };
+                if let Err(e) = commands::release_notes::generate_release_notes(
+                    std::path::Path::new("."),
+                    locale,
+                    true,
+                    None,
+                    limits,
+                    &commands::commit_filter::load_commit_filter(&[], &[]).await,
+                    None,
+                )
+                .await
+                {
+                    eprintln!("{}", format!("❌ Release notes failed: {}", e).red());
+                }
                 println!("\n{}", "Press Enter to continue...".dimmed());
                 let _ = get_user_input();
             }
             "3" | "update-dependencies" => {
                 println!("{}", "\n🔄 Analyzing and updating dependencies...".yellow());
-                commands::dependency_update::update_dependencies();
+                commands::dependency_update::load_update_rules(&[]).await;
+                let results = commands::dependency_update::update_dependencies(
+                    std::path::Path::new("."),
+                    &utils::file_system::ScanOptions::top_level(),
+                );
+                commands::dependency_update::notify_update_results(std::path::Path::new("."), &results).await;
                 println!("\n{}", "Press Enter to continue...".dimmed());
                 let _ = get_user_input();
             }
             "4" | "sync-translations" => {
                 println!("{}", "\n🌍 Syncing translations...".yellow());
-                if let Err(e) = commands::translation_sync::sync_translations_interactive().await {
+                if let Err(e) = commands::translation_sync::sync_translations_interactive(std::path::Path::new("."), true, None).await {
                     println!("{}", format!("❌ Translation sync failed: {}", e).red());
                 }
                 println!("\n{}", "Press Enter to continue...".dimmed());
@@ -479,7 +3717,21 @@ async fn run_interactive_mode() {
             }
             "5" | "code-quality" => {
                 println!("{}", "\n🔍 Running code quality checks...".yellow());
-                if let Err(e) = commands::code_quality::run_code_quality(None, None).await {
+                let mut quality_config = commands::code_quality::CodeQualityConfig::default();
+                let saved = commands::config::load_saved_config().await.quality_checks;
+                if !saved.is_empty() {
+                    quality_config.enabled_checks = saved;
+                }
+                if let Err(e) = commands::code_quality::run_code_quality(
+                    None,
+                    quality_config,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                {
                     println!("{}", format!("❌ Code quality checks failed: {}", e).red());
                 }
                 println!("\n{}", "Press Enter to continue...".dimmed());
@@ -539,7 +3791,7 @@ async fn run_interactive_mode() {
                 match version_input.as_str() {
                     "1" | "patch" => {
                         if let Err(e) =
-                            commands::version_management::bump_and_release("patch", None).await
+                            commands::version_management::bump_and_release(std::path::Path::new("."), "patch", None, &Default::default()).await
                         {
                             println!(
                                 "{}",
@@ -549,7 +3801,7 @@ async fn run_interactive_mode() {
                     }
                     "2" | "minor" => {
                         if let Err(e) =
-                            commands::version_management::bump_and_release("minor", None).await
+                            commands::version_management::bump_and_release(std::path::Path::new("."), "minor", None, &Default::default()).await
                         {
                             println!(
                                 "{}",
@@ -559,7 +3811,7 @@ async fn run_interactive_mode() {
                     }
                     "3" | "major" => {
                         if let Err(e) =
-                            commands::version_management::bump_and_release("major", None).await
+                            commands::version_management::bump_and_release(std::path::Path::new("."), "major", None, &Default::default()).await
                         {
                             println!(
                                 "{}",
@@ -574,7 +3826,13 @@ async fn run_interactive_mode() {
                         );
                     }
                     "5" | "history" => {
-                        if let Err(e) = commands::version_management::show_version_history().await {
+                        if let Err(e) = commands::version_management::show_version_history(
+                            std::path::Path::new("."),
+                            commands::version_management::HISTORY_LIMIT,
+                            false,
+                        )
+                        .await
+                        {
                             println!(
                                 "{}",
                                 format!("❌ Failed to show version history: {}", e).red()
@@ -625,6 +3883,10 @@ async fn run_interactive_mode() {
                     "🔍 code-quality".green()
                 );
                 println!("  {} - Manage GitHub repository labels", "🏷️ github-labels".green());
+                println!(
+                    "  {} - Create a project from a template repository",
+                    "🆕 new".green()
+                );
                 println!("  {} - Manage configuration settings", "⚙️  config".blue());
                 println!("  {} - Manage project versioning", "🏷️  version".blue());
                 println!("  {} - Show this help information", "❓ help".blue());
@@ -635,20 +3897,137 @@ async fn run_interactive_mode() {
                     "  {} nitroterm create-release v1.0.0",
                     "Create release:".dimmed()
                 );
+                println!(
+                    "  {} nitroterm create-release patch --sign",
+                    "Signed release:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm verify-release v1.0.0",
+                    "Verify release:".dimmed()
+                );
+                println!("  {} nitroterm smoke-test", "Smoke tests:".dimmed());
+                println!(
+                    "  {} nitroterm releases bisect --command \"{{dir}}/app --check\"",
+                    "Bisect releases:".dimmed()
+                );
                 println!("  {} nitroterm release-notes", "Direct command:".dimmed());
+                println!(
+                    "  {} nitroterm release-notes --template notes.hbs",
+                    "Notes template:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm release-notes --for v1.1.0",
+                    "Past release:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm retag --annotate v1.1.0 --push",
+                    "Tag annotation:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm contributors --from v1.0.0 --markdown",
+                    "Contributors:".dimmed()
+                );
+                println!("  {} nitroterm status --offline", "Since last run:".dimmed());
+                println!("  {} nitroterm --json release-notes", "JSON output:".dimmed());
+                println!(
+                    "  {} nitroterm audit --fail-on high",
+                    "Security audit:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm doctor --feature release,github",
+                    "Environment check:".dimmed()
+                );
                 println!(
                     "  {} nitroterm sync-translations",
                     "Sync translations:".dimmed()
                 );
+                println!(
+                    "  {} nitroterm sync-translations cache clear",
+                    "Translation cache:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm report --run-checks --outdated",
+                    "HTML report:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm maintain --skip quality",
+                    "Nightly maintenance:".dimmed()
+                );
+                println!("  {} nitroterm serve --port 7070", "Local API:".dimmed());
+                println!("  {} nitroterm mcp --path .", "MCP server:".dimmed());
+                println!("  {} nitroterm scaffold vscode", "VS Code tasks:".dimmed());
+                println!("  {} nitroterm verify-deps", "Verify checksums:".dimmed());
+                println!("  {} nitroterm sbom --format spdx", "SBOM:".dimmed());
+                println!("  {} nitroterm self-update --check", "Self update:".dimmed());
+                println!("  {} nitroterm fork sync --rebase", "Sync fork:".dimmed());
+                println!(
+                    "  {} nitroterm org deps --min-severity high",
+                    "Org dependencies:".dimmed()
+                );
+                println!("  {} nitroterm policy check", "Release policy:".dimmed());
+                println!(
+                    "  {} nitroterm provenance dist/ --sign keyless --upload",
+                    "Provenance:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm export sqlite --output nitro.db",
+                    "SQLite export:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm translations status",
+                    "Translation progress:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm translations heatmap",
+                    "Translation heatmap:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm validate-translations --json report.json",
+                    "Validate translations:".dimmed()
+                );
                 println!(
                     "  {} nitroterm code-quality --path ./my-project",
                     "Code quality:".dimmed()
                 );
+                println!(
+                    "  {} nitroterm code-quality --output sarif",
+                    "Quality report:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm code-quality history --compare-to main",
+                    "Quality trends:".dimmed()
+                );
                 println!(
                     "  {} nitroterm github-labels --dry-run",
                     "GitHub labels:".dimmed()
                 );
+                println!(
+                    "  {} nitroterm github-milestones --sync --dry-run",
+                    "GitHub milestones:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm github-triage --stale-days 30",
+                    "Issue/PR triage:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm github-templates",
+                    "Issue/PR templates:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm new service my-api --org acme",
+                    "New project:".dimmed()
+                );
+                println!(
+                    "  {} nitroterm git setup-identity --profile work",
+                    "Git identity:".dimmed()
+                );
                 println!("  {} nitroterm config show", "Config management:".dimmed());
+                println!("  {} nitroterm config theme colorblind", "Color theme:".dimmed());
+                println!(
+                    "  {} nitroterm --sandbox release-notes",
+                    "Read-only analysis:".dimmed()
+                );
+                println!("  {} nitroterm --yes version auto", "No prompts (CI):".dimmed());
                 println!("  {} nitroterm version patch", "Version bump:".dimmed());
                 println!(
                     "  {} nitroterm (then select option)",
@@ -0,0 +1,124 @@
+use crate::commands::audit::{
+    cvss_base_score, detect_tools, run_audit_tools, severity_from_score, AuditTool,
+};
+use crate::commands::org::Severity;
+use crate::tests::support::MockRunner;
+use std::fs;
+use tempfile::tempdir;
+
+const CARGO_AUDIT: &str = r#"{
+  "vulnerabilities": {"found": true, "count": 1, "list": [{
+    "advisory": {"id": "RUSTSEC-2024-0001", "package": "smallvec", "title": "Buffer overflow",
+                 "cvss": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"},
+    "versions": {"patched": [">=1.6.1"], "unaffected": []},
+    "package": {"name": "smallvec", "version": "1.6.0"}
+  }]}
+}"#;
+
+const NPM_AUDIT: &str = r#"{
+  "auditReportVersion": 2,
+  "vulnerabilities": {
+    "minimist": {"name": "minimist", "severity": "moderate",
+      "via": [{"source": 1097, "name": "minimist", "title": "Prototype Pollution",
+               "url": "https://github.com/advisories/GHSA-xvch-5gv4-984h",
+               "severity": "moderate", "range": "<1.2.6"}],
+      "fixAvailable": {"name": "minimist", "version": "1.2.8", "isSemVerMajor": false}},
+    "mkdirp": {"name": "mkdirp", "severity": "moderate", "via": ["minimist"],
+      "fixAvailable": true}
+  }
+}"#;
+
+const PIP_AUDIT: &str = r#"{"dependencies": [
+  {"name": "flask", "version": "0.5", "vulns": [
+    {"id": "PYSEC-2019-179", "fix_versions": ["1.0"], "aliases": [], "description": "Denial of service\nDetails"}]},
+  {"name": "requests", "version": "2.32.0", "vulns": []}
+]}"#;
+
+const COMPOSER_AUDIT: &str = r#"{"advisories": {"guzzlehttp/psr7": [
+  {"advisoryId": "PKSA-1", "packageName": "guzzlehttp/psr7", "affectedVersions": ">=2,<2.4.5",
+   "title": "Improper header validation", "cve": "CVE-2023-29197", "severity": "high"}
+]}}"#;
+
+#[test]
+fn test_cvss_scores() {
+    let critical = cvss_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").unwrap();
+    assert_eq!(critical, 9.8);
+    let changed = cvss_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N").unwrap();
+    assert_eq!(changed, 6.1);
+    assert_eq!(
+        cvss_base_score("CVSS:3.0/AV:L/AC:H/PR:H/UI:R/S:U/C:N/I:N/A:N"),
+        Some(0.0)
+    );
+    assert_eq!(cvss_base_score("AV:N/AC:L/Au:N/C:P/I:P/A:P"), None);
+
+    assert_eq!(severity_from_score(9.8), Severity::Critical);
+    assert_eq!(severity_from_score(6.1), Severity::Moderate);
+    assert_eq!(severity_from_score(0.0), Severity::Unknown);
+}
+
+#[test]
+fn test_audit_merges_every_ecosystem() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    for file in [
+        "Cargo.lock",
+        "package-lock.json",
+        "requirements.txt",
+        "composer.lock",
+    ] {
+        fs::write(root.join(file), "").unwrap();
+    }
+    assert_eq!(detect_tools(root), AuditTool::all().to_vec());
+
+    let runner = MockRunner::new();
+    runner
+        .respond("cargo audit", CARGO_AUDIT)
+        .respond("npm audit", NPM_AUDIT)
+        .respond("pip-audit", PIP_AUDIT)
+        .fail("composer audit", "Command \"audit\" is not defined.");
+    let report = run_audit_tools(root, &runner);
+    assert_eq!(
+        runner.calls()[2],
+        "pip-audit -r requirements.txt --format json"
+    );
+
+    assert_eq!(
+        report.audited,
+        vec!["cargo audit", "npm audit", "pip-audit"]
+    );
+    assert_eq!(report.skipped[0].tool, "composer audit");
+    assert_eq!(
+        report.skipped[0].reason,
+        "Command \"audit\" is not defined."
+    );
+
+    // Sorted by severity, transitive npm entries aren't repeated
+    let summary: Vec<(&str, Severity, Option<&str>)> = report
+        .vulnerabilities
+        .iter()
+        .map(|v| (v.package.as_str(), v.severity, v.fixed.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("smallvec", Severity::Critical, Some(">=1.6.1")),
+            ("minimist", Severity::Moderate, Some("1.2.8")),
+            ("flask", Severity::Unknown, Some("1.0")),
+        ]
+    );
+    assert_eq!(report.vulnerabilities[1].advisory, "GHSA-xvch-5gv4-984h");
+    assert_eq!(report.vulnerabilities[2].title, "Denial of service");
+    assert_eq!(report.at_or_above(Severity::High), 1);
+    assert_eq!(report.at_or_above(Severity::Moderate), 2);
+
+    let composer = AuditTool::Composer.parse(COMPOSER_AUDIT).unwrap();
+    assert_eq!(composer[0].advisory, "CVE-2023-29197");
+    assert_eq!(composer[0].severity, Severity::High);
+    assert!(AuditTool::Composer
+        .parse(r#"{"advisories": []}"#)
+        .unwrap()
+        .is_empty());
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["vulnerabilities"][0]["severity"], "critical");
+}
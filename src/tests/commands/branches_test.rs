@@ -0,0 +1,89 @@
+use crate::commands::branches::{
+    checklist_items, cleanup_candidates, delete_args, delete_branches, matches_pattern,
+};
+use crate::tests::support::MockRunner;
+use crate::utils::git::BranchInfo;
+
+const DAY: i64 = 86_400;
+const NOW: i64 = 1_700_000_000;
+
+fn branch(name: &str, remote: bool, age_days: i64, merged: bool) -> BranchInfo {
+    BranchInfo {
+        name: name.to_string(),
+        remote,
+        last_commit: NOW - age_days * DAY,
+        merged,
+    }
+}
+
+#[test]
+fn test_matches_pattern() {
+    assert!(matches_pattern("main", "main"));
+    assert!(!matches_pattern("main", "main-old"));
+    assert!(matches_pattern("release/*", "release/1.2"));
+    assert!(!matches_pattern("release/*", "releases/1.2"));
+    assert!(matches_pattern("*-stable", "v2-stable"));
+    assert!(matches_pattern("hotfix/*/keep", "hotfix/login/keep"));
+    assert!(!matches_pattern("hotfix/*/keep", "hotfix/login"));
+    assert!(matches_pattern("*", "anything"));
+    // Non-ASCII names don't get sliced mid-character
+    assert!(!matches_pattern("release/*x", "release/é"));
+    assert!(matches_pattern("release/*é", "release/café"));
+}
+
+#[test]
+fn test_cleanup_candidates_skip_protected_and_current() {
+    let protected = vec!["main".to_string(), "release/*".to_string()];
+    let branches = vec![
+        branch("old-feature", false, 200, false),
+        branch("release/1.0", false, 300, true),
+        branch("origin/release/1.1", true, 300, true),
+        branch("origin/merged", true, 2, true),
+        branch("wip", false, 5, false),
+        branch("checked-out", false, 400, true),
+    ];
+    let candidates = cleanup_candidates(branches, NOW, 90, &protected, Some("checked-out"));
+    let names: Vec<&str> = candidates.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["old-feature", "origin/merged"]);
+    assert_eq!(candidates[1].short_name(), "merged");
+}
+
+#[test]
+fn test_checklist_preselects_merged_branches() {
+    let candidates = vec![
+        branch("old-feature", false, 200, false),
+        branch("origin/merged", true, 2, true),
+    ];
+    let (items, order) = checklist_items(&candidates, "main", 90, NOW);
+    assert_eq!(order, vec![1, 0]);
+    assert_eq!(items[0].group, "Merged into main");
+    assert!(items[0].checked);
+    assert!(items[0].label.contains("remote"));
+    assert_eq!(items[1].group, "No commits in 90 days");
+    assert!(!items[1].checked);
+    assert!(items[1].label.contains("200 days without commits"));
+}
+
+#[test]
+fn test_delete_branches() {
+    let local = branch("old-feature", false, 200, false);
+    let remote = branch("upstream/merged", true, 2, true);
+    assert_eq!(
+        delete_args(&remote),
+        vec!["push", "upstream", "--delete", "merged"]
+    );
+
+    let runner = MockRunner::new();
+    runner.fail("git push upstream", "error: unable to delete 'merged'");
+    let result = delete_branches(&runner, &[&local, &remote]);
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "git branch -D old-feature",
+            "git push upstream --delete merged"
+        ]
+    );
+    assert_eq!(result.deleted, vec!["old-feature"]);
+    assert_eq!(result.failed.len(), 1);
+    assert!(result.failed[0].1.contains("unable to delete"));
+}
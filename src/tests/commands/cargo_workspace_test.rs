@@ -0,0 +1,300 @@
+use crate::commands::cargo_workspace::{
+    build_reports, build_upgrade_reports, bump_dependency_in_manifest, bumped_requirement,
+    fetch_versions, is_requirement_outdated, latest_compatible, latest_versions, load_workspace,
+    parse_cargo_metadata, plan_requirements, save_requirements, upgrade_target,
+};
+use crate::commands::lockfiles::{sparse_index_path, RegistryClient};
+use crate::tests::support::{MockRunner, MockServer};
+use semver::Version;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::tempdir;
+
+fn metadata(root: &str) -> String {
+    serde_json::json!({
+        "workspace_root": root,
+        "packages": [
+            {
+                "name": "app",
+                "version": "0.1.0",
+                "manifest_path": format!("{}/Cargo.toml", root),
+                "dependencies": [
+                    {"name": "serde", "req": "^1.0", "kind": null,
+                     "source": "registry+https://github.com/rust-lang/crates.io-index", "registry": null},
+                    {"name": "core", "req": "*", "kind": null, "source": null, "registry": null}
+                ]
+            },
+            {
+                "name": "core",
+                "version": "0.1.0",
+                "manifest_path": format!("{}/core/Cargo.toml", root),
+                "dependencies": [
+                    {"name": "tokio", "req": "^1", "kind": null,
+                     "source": "registry+https://github.com/rust-lang/crates.io-index", "registry": null},
+                    {"name": "rand", "rename": "random", "req": "=0.7.3", "kind": "dev",
+                     "source": "registry+https://github.com/rust-lang/crates.io-index", "registry": null},
+                    {"name": "internal", "req": "^2", "kind": null,
+                     "source": "registry+https://corp.example/index", "registry": "https://corp.example/index"}
+                ]
+            }
+        ]
+    })
+    .to_string()
+}
+
+#[test]
+fn test_workspace_members_and_selection() {
+    let runner = MockRunner::new();
+    runner.respond("cargo metadata", &metadata("/repo"));
+    let workspace = load_workspace(std::path::Path::new("/repo"), &runner).unwrap();
+    assert_eq!(
+        runner.calls(),
+        vec!["cargo metadata --format-version 1 --no-deps"]
+    );
+
+    assert_eq!(workspace.root_package.as_deref(), Some("app"));
+    // Path dependencies and other registries are skipped
+    assert_eq!(workspace.members[0].dependencies.len(), 1);
+    let random = &workspace.members[1].dependencies[1];
+    assert_eq!(
+        (
+            random.name.as_str(),
+            random.key.as_str(),
+            random.kind.as_str()
+        ),
+        ("rand", "random", "dev")
+    );
+    assert_eq!(workspace.members[1].dependencies.len(), 2);
+
+    let names = |members: Vec<&crate::commands::cargo_workspace::WorkspaceMember>| {
+        members.iter().map(|m| m.name.clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(workspace.select_members(&[], false).unwrap()),
+        vec!["app"]
+    );
+    assert_eq!(
+        names(workspace.select_members(&[], true).unwrap()),
+        vec!["app", "core"]
+    );
+    assert_eq!(
+        names(
+            workspace
+                .select_members(&["core".to_string()], false)
+                .unwrap()
+        ),
+        vec!["core"]
+    );
+    let err = workspace
+        .select_members(&["web".to_string()], false)
+        .unwrap_err();
+    assert!(err.to_string().contains("members: app, core"));
+
+    // A virtual workspace defaults to every member
+    let mut virtual_workspace = parse_cargo_metadata(&metadata("/repo")).unwrap();
+    virtual_workspace.root_package = None;
+    assert_eq!(
+        virtual_workspace.select_members(&[], false).unwrap().len(),
+        2
+    );
+}
+
+#[test]
+fn test_requirement_bumps() {
+    let latest = Version::parse("2.3.1").unwrap();
+    assert!(is_requirement_outdated("^1.0", &latest));
+    assert!(!is_requirement_outdated("^2.1", &latest));
+    assert!(!is_requirement_outdated("^3", &latest));
+
+    assert_eq!(bumped_requirement("1.0", &latest).as_deref(), Some("2.3"));
+    assert_eq!(bumped_requirement("^1", &latest).as_deref(), Some("^2"));
+    assert_eq!(
+        bumped_requirement("=1.0.4", &latest).as_deref(),
+        Some("=2.3.1")
+    );
+    assert_eq!(bumped_requirement(">=1, <2", &latest), None);
+
+    let manifest = "[package]\nname = \"app\"\nversion = \"1.0.0\"\n\n\
+        [dependencies]\nserde = \"1.0\"\nserde_json = \"1.0\"\n\
+        tokio = { version = \"1\", features = [\"full\"] }\nshared = { workspace = true }\n\n\
+        [dev-dependencies.serde]\nversion = \"1.0.100\"\n";
+
+    let updated = bump_dependency_in_manifest(manifest, "serde", "^1.0", &latest).unwrap();
+    assert!(updated.contains("\nserde = \"2.3\"\n"));
+    assert!(updated.contains("\nserde_json = \"1.0\"\n"));
+    // The package version is not a dependency, the dev table requires a different version
+    assert!(updated.contains("version = \"1.0.0\"\n"));
+    assert!(updated.contains("version = \"1.0.100\"\n"));
+
+    let updated = bump_dependency_in_manifest(manifest, "tokio", "^1", &latest).unwrap();
+    assert!(updated.contains("tokio = { version = \"2\", features = [\"full\"] }"));
+
+    let updated = bump_dependency_in_manifest(manifest, "serde", "^1.0.100", &latest).unwrap();
+    assert!(updated.ends_with("[dev-dependencies.serde]\nversion = \"2.3.1\"\n"));
+
+    assert_eq!(
+        bump_dependency_in_manifest(manifest, "shared", "^1", &latest),
+        None
+    );
+}
+
+#[test]
+fn test_compatible_and_incompatible_upgrades() {
+    let versions: Vec<Version> = ["1.0.100", "1.0.200", "1.2.0-rc.1", "2.3.1"]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .collect();
+    let version = |v: Option<Version>| v.map(|v| v.to_string());
+
+    assert_eq!(
+        version(latest_compatible("1.0.100", &versions)).as_deref(),
+        Some("1.0.200")
+    );
+    assert_eq!(latest_compatible("3", &versions), None);
+
+    // Within 1.x by default, to the new major with --incompatible
+    assert_eq!(
+        version(upgrade_target("1.0.100", &versions, false)).as_deref(),
+        Some("1.0.200")
+    );
+    assert_eq!(
+        version(upgrade_target("1.0.100", &versions, true)).as_deref(),
+        Some("2.3.1")
+    );
+    // `1.0` already allows 1.0.200 and would read the same
+    assert_eq!(upgrade_target("1.0", &versions, false), None);
+    assert_eq!(upgrade_target("^2.3", &versions, true), None);
+}
+
+#[test]
+fn test_manifest_edits_keep_formatting() {
+    let latest = Version::parse("2.3.1").unwrap();
+    let manifest = "[dependencies]\n# Serialization\nserde   =   \"1.0\"   # keep in sync with serde_json\n\
+        serde_json = \"1.0\"\n\n[target.'cfg(unix)'.dependencies]\nnix = { version = \"0.27\", default-features = false }\n";
+
+    let updated = bump_dependency_in_manifest(manifest, "serde", "^1.0", &latest).unwrap();
+    assert_eq!(
+        updated,
+        manifest.replace("serde   =   \"1.0\"", "serde   =   \"2.3\"")
+    );
+    let updated = bump_dependency_in_manifest(manifest, "nix", "^0.27", &latest).unwrap();
+    assert!(updated.contains("nix = { version = \"2.3\", default-features = false }\n"));
+}
+
+#[test]
+fn test_planned_changes_are_previewed_not_written() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("core")).unwrap();
+    let manifest =
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1.0.100\" # pinned low\n";
+    fs::write(root.join("Cargo.toml"), manifest).unwrap();
+    fs::write(root.join("core/Cargo.toml"), "[package]\nname = \"core\"\n").unwrap();
+
+    let mut workspace = parse_cargo_metadata(&metadata(&root.display().to_string())).unwrap();
+    workspace.members[0].dependencies[0].req = "^1.0.100".to_string();
+    workspace.members[1].dependencies.clear();
+    let members = workspace.select_members(&[], true).unwrap();
+    let versions = HashMap::from([(
+        "serde".to_string(),
+        vec![
+            Version::parse("1.0.200").unwrap(),
+            Version::parse("2.0.0").unwrap(),
+        ],
+    )]);
+
+    let reports = build_upgrade_reports(&members, &versions, false);
+    let changes = plan_requirements(&workspace, &reports).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(
+        changes[0].changed_lines(),
+        vec![(
+            5,
+            "serde = \"1.0.100\" # pinned low",
+            "serde = \"1.0.200\" # pinned low"
+        )]
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+        manifest
+    );
+
+    let reports = build_upgrade_reports(&members, &versions, true);
+    let changes = plan_requirements(&workspace, &reports).unwrap();
+    assert!(changes[0].after.contains("serde = \"2.0.0\" # pinned low"));
+}
+
+#[tokio::test]
+async fn test_reports_and_save_across_members() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("core")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"core\"]\n\n[workspace.dependencies]\ntokio = \"1\"\n\n\
+         [package]\nname = \"app\"\n\n[dependencies]\nserde = \"1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("core/Cargo.toml"),
+        "[package]\nname = \"core\"\n\n[dependencies]\ntokio = { workspace = true }\n\n\
+         [dev-dependencies]\nrandom = { package = \"rand\", version = \"=0.7.3\" }\n",
+    )
+    .unwrap();
+
+    let server = MockServer::start();
+    server
+        .mock(
+            "GET",
+            "/se/rd/serde",
+            200,
+            "{\"name\":\"serde\",\"vers\":\"1.0.200\",\"yanked\":false}\n\
+             {\"name\":\"serde\",\"vers\":\"2.0.0\",\"yanked\":true}\n\
+             {\"name\":\"serde\",\"vers\":\"2.1.0-rc.1\",\"yanked\":false}\n",
+        )
+        .mock(
+            "GET",
+            "/to/ki/tokio",
+            200,
+            "{\"vers\":\"1.40.0\"}\n{\"vers\":\"2.0.1\"}\n",
+        )
+        .mock("GET", "/ra/nd/rand", 200, "{\"vers\":\"0.8.5\"}\n");
+    let mut registry = RegistryClient::new().unwrap();
+    registry.index_url = server.url();
+    assert_eq!(sparse_index_path("Serde"), "se/rd/serde");
+    assert_eq!(sparse_index_path("abc"), "3/a/abc");
+
+    let names = vec!["rand".to_string(), "serde".to_string(), "tokio".to_string()];
+    let latest: HashMap<String, Version> =
+        latest_versions(&fetch_versions(&registry, &names).await);
+    // Yanked releases and pre-releases don't count
+    assert_eq!(latest["serde"].to_string(), "1.0.200");
+    assert_eq!(latest["tokio"].to_string(), "2.0.1");
+
+    let workspace = parse_cargo_metadata(&metadata(&root.display().to_string())).unwrap();
+    let members = workspace.select_members(&[], true).unwrap();
+    let reports = build_reports(&members, &latest);
+    assert!(reports[0].outdated.is_empty());
+    let outdated: Vec<(&str, String)> = reports[1]
+        .outdated
+        .iter()
+        .map(|o| (o.key.as_str(), o.latest.to_string()))
+        .collect();
+    assert_eq!(
+        outdated,
+        vec![
+            ("tokio", "2.0.1".to_string()),
+            ("random", "0.8.5".to_string())
+        ]
+    );
+
+    let changed = save_requirements(&plan_requirements(&workspace, &reports).unwrap()).unwrap();
+    assert_eq!(changed.len(), 2);
+    // Inherited requirements are bumped in the workspace root
+    let root_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(root_manifest.contains("[workspace.dependencies]\ntokio = \"2\"\n"));
+    assert!(root_manifest.contains("serde = \"1.0\""));
+    let core_manifest = fs::read_to_string(root.join("core/Cargo.toml")).unwrap();
+    assert!(core_manifest.contains("random = { package = \"rand\", version = \"=0.8.5\" }"));
+    assert!(core_manifest.contains("tokio = { workspace = true }"));
+}
@@ -1,8 +1,13 @@
 use crate::commands::code_quality::{
-    CheckResult, CodeQualityConfig, CodeQualityManager, PackageManager, ProjectInfo, ProjectType,
-    QualityCheck,
+    changed_files, default_tool_policy, extract_findings, find_project_roots,
+    flatten_project_results, parse_numstat, render_json_report, render_junit_report,
+    render_sarif_report, tool_name, CheckResult, CodeQualityConfig, CodeQualityManager,
+    PackageManager, ProjectInfo, ProjectResults, ProjectType, QualityCheck, ReportFormat,
+    ToolPolicy, FINDING_PATTERN, MAX_RETRIES, MAX_TIMEOUT_SECONDS,
 };
+use crate::utils::file_system::ScanOptions;
 use serde_json::json;
+use std::collections::HashMap;
 use std::fs;
 use tempfile::tempdir;
 
@@ -19,7 +24,7 @@ mod tests {
         assert!(config.enabled_checks.contains(&"format".to_string()));
         assert!(config.enabled_checks.contains(&"security".to_string()));
         assert!(config.enabled_checks.contains(&"test".to_string()));
-        assert_eq!(config.skip_dependencies, false);
+        assert!(!config.skip_dependencies);
         assert_eq!(config.max_parallel_jobs, 4);
         assert_eq!(config.timeout_seconds, 300);
     }
@@ -31,6 +36,9 @@ mod tests {
             skip_dependencies: true,
             max_parallel_jobs: 8,
             timeout_seconds: 600,
+            report_path: None,
+            tool_policies: HashMap::new(),
+            custom_checks: Vec::new(),
         };
 
         assert_eq!(config.enabled_checks.len(), 2);
@@ -51,6 +59,8 @@ mod tests {
             ProjectType::JavaScript,
             ProjectType::Rust,
             ProjectType::Python,
+            ProjectType::Go,
+            ProjectType::Java,
             ProjectType::Unknown,
         ];
 
@@ -69,6 +79,9 @@ mod tests {
             PackageManager::Bun,
             PackageManager::Cargo,
             PackageManager::Pip,
+            PackageManager::GoModules,
+            PackageManager::Maven,
+            PackageManager::Gradle,
             PackageManager::Unknown,
         ];
 
@@ -172,6 +185,61 @@ serde = "1.0"
         assert!(project_info.frameworks.contains(&"Rust".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_detect_go_project() {
+        let temp_dir = tempdir().unwrap();
+        let project_path = temp_dir.path();
+
+        fs::write(
+            project_path.join("go.mod"),
+            "module example.com/test\n\ngo 1.22\n",
+        )
+        .unwrap();
+
+        let config = CodeQualityConfig::default();
+        let manager = CodeQualityManager::new(config);
+
+        let project_info = manager.detect_project_type(project_path).await.unwrap();
+
+        assert_eq!(project_info.project_type, ProjectType::Go);
+        assert_eq!(project_info.package_manager, PackageManager::GoModules);
+        assert!(project_info.frameworks.contains(&"Go".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_detect_maven_project() {
+        let temp_dir = tempdir().unwrap();
+        let project_path = temp_dir.path();
+
+        fs::write(project_path.join("pom.xml"), "<project></project>").unwrap();
+
+        let config = CodeQualityConfig::default();
+        let manager = CodeQualityManager::new(config);
+
+        let project_info = manager.detect_project_type(project_path).await.unwrap();
+
+        assert_eq!(project_info.project_type, ProjectType::Java);
+        assert_eq!(project_info.package_manager, PackageManager::Maven);
+        assert!(project_info.frameworks.contains(&"Java".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_detect_gradle_kotlin_project() {
+        let temp_dir = tempdir().unwrap();
+        let project_path = temp_dir.path();
+
+        fs::write(project_path.join("build.gradle.kts"), "plugins {}").unwrap();
+
+        let config = CodeQualityConfig::default();
+        let manager = CodeQualityManager::new(config);
+
+        let project_info = manager.detect_project_type(project_path).await.unwrap();
+
+        assert_eq!(project_info.project_type, ProjectType::Java);
+        assert_eq!(project_info.package_manager, PackageManager::Gradle);
+        assert!(project_info.frameworks.contains(&"Kotlin".to_string()));
+    }
+
     #[tokio::test]
     async fn test_detect_python_project() {
         let temp_dir = tempdir().unwrap();
@@ -323,6 +391,18 @@ serde = "1.0"
             manager.get_package_manager_command(&PackageManager::Pip),
             "pip"
         );
+        assert_eq!(
+            manager.get_package_manager_command(&PackageManager::GoModules),
+            "go"
+        );
+        assert_eq!(
+            manager.get_package_manager_command(&PackageManager::Maven),
+            "mvn"
+        );
+        assert_eq!(
+            manager.get_package_manager_command(&PackageManager::Gradle),
+            "gradle"
+        );
         assert_eq!(
             manager.get_package_manager_command(&PackageManager::Unknown),
             "npm"
@@ -342,6 +422,7 @@ serde = "1.0"
             ],
             working_dir: temp_dir.path().to_path_buf(),
             timeout: 300,
+            expected_exit_code: 0,
         };
 
         assert_eq!(check.name, "test-lint");
@@ -354,6 +435,7 @@ serde = "1.0"
     fn test_check_result_creation() {
         let result = CheckResult {
             check_name: "lint".to_string(),
+            command: "npm run lint".to_string(),
             success: true,
             output: "All checks passed".to_string(),
             error: None,
@@ -368,6 +450,7 @@ serde = "1.0"
 
         let failed_result = CheckResult {
             check_name: "format".to_string(),
+            command: "cargo fmt --check".to_string(),
             success: false,
             output: "".to_string(),
             error: Some("Formatting issues found".to_string()),
@@ -492,6 +575,61 @@ serde = "1.0"
         }
     }
 
+    #[tokio::test]
+    async fn test_go_checks_generation() {
+        let temp_dir = tempdir().unwrap();
+        let project_info = ProjectInfo {
+            project_type: ProjectType::Go,
+            package_manager: PackageManager::GoModules,
+            root_path: temp_dir.path().to_path_buf(),
+            config_files: Vec::new(),
+            has_typescript: false,
+            frameworks: vec!["Go".to_string()],
+        };
+
+        let config = CodeQualityConfig::default();
+        let manager = CodeQualityManager::new(config);
+
+        let checks = manager.generate_go_checks(&project_info).unwrap();
+
+        let test_check = checks.iter().find(|c| c.name == "test").unwrap();
+        assert_eq!(test_check.command, "go");
+        assert_eq!(test_check.args, vec!["test", "./..."]);
+        assert!(checks.iter().any(|c| c.name == "lint"));
+        let format_check = checks.iter().find(|c| c.name == "format").unwrap();
+        assert!(format_check.args[1].contains("gofmt -l ."));
+    }
+
+    #[tokio::test]
+    async fn test_java_checks_generation() {
+        let temp_dir = tempdir().unwrap();
+        let mut project_info = ProjectInfo {
+            project_type: ProjectType::Java,
+            package_manager: PackageManager::Maven,
+            root_path: temp_dir.path().to_path_buf(),
+            config_files: Vec::new(),
+            has_typescript: false,
+            frameworks: vec!["Java".to_string()],
+        };
+
+        let config = CodeQualityConfig::default();
+        let manager = CodeQualityManager::new(config);
+
+        let checks = manager.generate_java_checks(&project_info).unwrap();
+        assert!(checks.iter().all(|c| c.command == "mvn"));
+
+        // Gradle projects should prefer the bundled wrapper
+        fs::write(temp_dir.path().join("gradlew"), "#!/bin/sh").unwrap();
+        project_info.package_manager = PackageManager::Gradle;
+
+        let checks = manager.generate_java_checks(&project_info).unwrap();
+        assert!(checks.iter().any(|c| c.name == "test"));
+        assert!(checks.iter().all(|c| c.command.ends_with("gradlew")));
+        // Tests run once, in the test check
+        let args: Vec<_> = checks.iter().map(|c| c.args.join(" ")).collect();
+        assert_eq!(args, vec!["check -x test", "test"]);
+    }
+
     #[tokio::test]
     async fn test_python_checks_generation() {
         let temp_dir = tempdir().unwrap();
@@ -539,8 +677,10 @@ serde = "1.0"
 
     #[test]
     fn test_enabled_checks_filtering() {
-        let mut config = CodeQualityConfig::default();
-        config.enabled_checks = vec!["lint".to_string(), "test".to_string()];
+        let config = CodeQualityConfig {
+            enabled_checks: vec!["lint".to_string(), "test".to_string()],
+            ..CodeQualityConfig::default()
+        };
 
         assert!(config.enabled_checks.contains(&"lint".to_string()));
         assert!(config.enabled_checks.contains(&"test".to_string()));
@@ -565,6 +705,9 @@ serde = "1.0"
             skip_dependencies: true,
             max_parallel_jobs: 1,
             timeout_seconds: 10,
+            report_path: None,
+            tool_policies: HashMap::new(),
+            custom_checks: Vec::new(),
         };
 
         let manager = CodeQualityManager::new(config);
@@ -600,6 +743,9 @@ serde = "1.0"
             skip_dependencies: false,
             max_parallel_jobs: 2,
             timeout_seconds: 60,
+            report_path: None,
+            tool_policies: HashMap::new(),
+            custom_checks: Vec::new(),
         };
 
         assert_eq!(config.timeout_seconds, 60);
@@ -610,6 +756,7 @@ serde = "1.0"
             args: vec![".".to_string()],
             working_dir: std::env::temp_dir(),
             timeout: config.timeout_seconds,
+            expected_exit_code: 0,
         };
 
         assert_eq!(check.timeout, 60);
@@ -637,4 +784,434 @@ serde = "1.0"
         assert_eq!(config_back.enabled_checks, config.enabled_checks);
         assert_eq!(config_back.skip_dependencies, config.skip_dependencies);
     }
+
+    #[test]
+    fn test_tool_policy_resolution() {
+        let mut config = CodeQualityConfig::default();
+
+        // Global fallback and built-in tool defaults
+        let cargo = config.policy_for("test", "cargo");
+        assert_eq!((cargo.timeout_seconds, cargo.retries), (300, 0));
+        assert_eq!(config.policy_for("test", "pytest").timeout_seconds, 1200);
+        assert_eq!(
+            config.policy_for("lint", "/usr/bin/eslint").timeout_seconds,
+            120
+        );
+        assert_eq!(config.policy_for("labels", "gh").retries, 2);
+        assert_eq!(default_tool_policy("cargo"), None);
+        assert_eq!(tool_name("/repo/gradlew"), "gradlew");
+
+        // Configured tool policy, then check policy on top
+        config.tool_policies.insert(
+            "pytest".to_string(),
+            ToolPolicy {
+                timeout_seconds: Some(1800),
+                retries: Some(1),
+            },
+        );
+        config.tool_policies.insert(
+            "test".to_string(),
+            ToolPolicy {
+                timeout_seconds: None,
+                retries: Some(3),
+            },
+        );
+        let pytest = config.policy_for("test", "pytest");
+        assert_eq!((pytest.timeout_seconds, pytest.retries), (1800, 3));
+        assert_eq!(config.policy_for("security", "pytest").retries, 1);
+    }
+
+    #[test]
+    fn test_tool_policy_overflow_is_clamped() {
+        let config: CodeQualityConfig = serde_json::from_value(json!({
+            "enabled_checks": ["test"],
+            "skip_dependencies": false,
+            "max_parallel_jobs": 1,
+            "timeout_seconds": 300,
+            "tool_policies": {
+                "pytest": { "timeout_seconds": 999999, "retries": 50 },
+                "eslint": { "timeout_seconds": 0 }
+            }
+        }))
+        .unwrap();
+
+        let pytest = config.policy_for("test", "pytest");
+        assert_eq!(pytest.timeout_seconds, MAX_TIMEOUT_SECONDS);
+        assert_eq!(pytest.retries, MAX_RETRIES);
+        assert_eq!(config.policy_for("lint", "eslint").timeout_seconds, 300);
+
+        let warnings = config.policy_warnings();
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].starts_with("eslint"));
+        assert!(CodeQualityConfig::default().policy_warnings().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_check_timeout_and_retries() {
+        let temp_dir = tempdir().unwrap();
+        let mut config = CodeQualityConfig::default();
+        config.tool_policies.insert(
+            "flaky".to_string(),
+            ToolPolicy {
+                timeout_seconds: None,
+                retries: Some(1),
+            },
+        );
+        let manager = CodeQualityManager::new(config);
+
+        let slow = QualityCheck {
+            name: "slow".to_string(),
+            command: "sleep".to_string(),
+            args: vec!["5".to_string()],
+            working_dir: temp_dir.path().to_path_buf(),
+            timeout: 1,
+            expected_exit_code: 0,
+        };
+        let result = manager.run_check(&slow).await;
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("Timed out after 1s"));
+
+        // Fails the first time, passes on the retry
+        let flaky = QualityCheck {
+            name: "flaky".to_string(),
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "test -f marker || { touch marker; exit 1; }".to_string(),
+            ],
+            working_dir: temp_dir.path().to_path_buf(),
+            timeout: 10,
+            expected_exit_code: 0,
+        };
+        assert!(manager.run_check(&flaky).await.success);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_custom_checks_merge_with_generated() {
+        let temp_dir = tempdir().unwrap();
+        fs::create_dir_all(temp_dir.path().join("docs")).unwrap();
+        let config: CodeQualityConfig = serde_json::from_value(json!({
+            "enabled_checks": ["lint", "test"],
+            "skip_dependencies": false,
+            "max_parallel_jobs": 1,
+            "timeout_seconds": 300,
+            "custom_checks": [
+                {"name": "lint", "command": "cargo", "args": ["clippy", "--all-targets"]},
+                {"name": "docs-links", "command": "sh", "args": ["-c", "test -d ../docs; exit 3"],
+                 "working_dir": "docs", "timeout_seconds": 20, "expected_exit_code": 3}
+            ]
+        }))
+        .unwrap();
+        let manager = CodeQualityManager::new(config);
+        let project_info = ProjectInfo {
+            project_type: ProjectType::Rust,
+            package_manager: PackageManager::Cargo,
+            root_path: temp_dir.path().to_path_buf(),
+            config_files: Vec::new(),
+            has_typescript: false,
+            frameworks: Vec::new(),
+        };
+
+        let checks = manager.planned_checks(&project_info).unwrap();
+        let names: Vec<&str> = checks.iter().map(|c| c.name.as_str()).collect();
+        // The custom lint replaces the generated one, format isn't enabled,
+        // docs-links runs without being listed
+        assert_eq!(names, vec!["test", "lint", "docs-links"]);
+        assert_eq!(checks[1].args, vec!["clippy", "--all-targets"]);
+
+        let docs_links = &checks[2];
+        assert_eq!(docs_links.working_dir, temp_dir.path().join("docs"));
+        assert_eq!(docs_links.timeout, 20);
+        // Exit code 3 is the configured pass
+        assert!(manager.run_check(docs_links).await.success);
+        let mut strict = docs_links.clone();
+        strict.expected_exit_code = 0;
+        assert!(!manager.run_check(&strict).await.success);
+    }
+
+    #[test]
+    fn test_fix_variants_per_project_type() {
+        let temp_dir = tempdir().unwrap();
+        let manager = CodeQualityManager::new(CodeQualityConfig::default());
+        let mut project_info = ProjectInfo {
+            project_type: ProjectType::Rust,
+            package_manager: PackageManager::Cargo,
+            root_path: temp_dir.path().to_path_buf(),
+            config_files: Vec::new(),
+            has_typescript: false,
+            frameworks: Vec::new(),
+        };
+        let command_lines = |checks: Vec<QualityCheck>| {
+            checks
+                .iter()
+                .map(|c| format!("{} {}", c.command, c.args.join(" ")))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            command_lines(manager.generate_fix_checks(&project_info)),
+            vec![
+                "cargo clippy --fix --allow-dirty --allow-staged",
+                "cargo fmt"
+            ]
+        );
+
+        // Prettier only runs where the project uses it
+        project_info.project_type = ProjectType::React;
+        project_info.package_manager = PackageManager::Pnpm;
+        assert_eq!(
+            command_lines(manager.generate_fix_checks(&project_info)),
+            vec!["pnpm exec eslint --fix ."]
+        );
+        fs::write(
+            temp_dir.path().join("package.json"),
+            r#"{"devDependencies": {"prettier": "^3.0.0"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            command_lines(manager.generate_fix_checks(&project_info))[1],
+            "pnpm exec prettier --write ."
+        );
+
+        project_info.project_type = ProjectType::Python;
+        assert_eq!(
+            command_lines(manager.generate_fix_checks(&project_info)),
+            vec!["black ."]
+        );
+
+        // Only enabled checks get fixed
+        let config = CodeQualityConfig {
+            enabled_checks: vec!["test".to_string()],
+            ..CodeQualityConfig::default()
+        };
+        assert!(CodeQualityManager::new(config)
+            .generate_fix_checks(&project_info)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_changed_files_ignore_existing_changes() {
+        let before = parse_numstat("3\t1\tsrc/lib.rs\n2\t0\tREADME.md\n");
+        let after =
+            parse_numstat("3\t1\tsrc/lib.rs\n5\t2\tREADME.md\n4\t4\tsrc/main.rs\n-\t-\tlogo.png\n");
+        assert_eq!(after["logo.png"], (0, 0));
+
+        let changed = changed_files(&before, &after);
+        let paths: Vec<&str> = changed.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["README.md", "logo.png", "src/main.rs"]);
+        assert_eq!((changed[2].added, changed[2].removed), (4, 4));
+    }
+
+    #[test]
+    fn test_find_nested_project_roots() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        let write = |path: &str, content: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        };
+        write("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n");
+        write("crates/core/Cargo.toml", "[package]\nname = \"core\"\n");
+        write("apps/web/package.json", r#"{"workspaces": ["packages/*"]}"#);
+        write("apps/web/packages/ui/package.json", "{}");
+        write("apps/web/node_modules/left-pad/package.json", "{}");
+        // A Python tool inside a JS workspace still stands on its own
+        write("apps/web/packages/scripts/pyproject.toml", "[project]\n");
+        write("tools/cli/package.json", "{}");
+        write(".cache/package.json", "{}");
+        write("target/debug/package.json", "{}");
+
+        let roots: Vec<String> = find_project_roots(root, &ScanOptions::default())
+            .iter()
+            .map(|path| {
+                path.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            roots,
+            vec!["", "apps/web", "apps/web/packages/scripts", "tools/cli"]
+        );
+
+        let options = ScanOptions {
+            include: vec!["apps/**".to_string()],
+            exclude: vec!["scripts".to_string()],
+            ..ScanOptions::default()
+        };
+        let filtered = find_project_roots(root, &options);
+        assert_eq!(filtered, vec![root.join("apps/web")]);
+        assert_eq!(
+            find_project_roots(root, &ScanOptions::top_level()),
+            vec![root.to_path_buf()]
+        );
+
+        let projects = vec![
+            ProjectResults {
+                project: ".".to_string(),
+                results: sample_results(),
+            },
+            ProjectResults {
+                project: "tools/cli".to_string(),
+                results: sample_results(),
+            },
+        ];
+        let names: Vec<String> = flatten_project_results(&projects)
+            .into_iter()
+            .map(|r| r.check_name)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["lint", "format", "tools/cli/lint", "tools/cli/format"]
+        );
+    }
+
+    fn sample_results() -> Vec<CheckResult> {
+        vec![
+            CheckResult {
+                check_name: "lint".to_string(),
+                command: "npm run lint".to_string(),
+                success: true,
+                output: "All checks passed".to_string(),
+                error: None,
+                duration_ms: 1200,
+            },
+            CheckResult {
+                check_name: "format".to_string(),
+                command: "cargo fmt --check".to_string(),
+                success: false,
+                output: "Diff in <src/main.rs>".to_string(),
+                error: Some("formatting issues & more".to_string()),
+                duration_ms: 300,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_report_format_parsing() {
+        assert_eq!(
+            "junit".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Junit
+        );
+        assert_eq!(
+            "SARIF".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Sarif
+        );
+        assert_eq!("json".parse::<ReportFormat>().unwrap(), ReportFormat::Json);
+        assert!("html".parse::<ReportFormat>().is_err());
+        assert_eq!(
+            ReportFormat::Junit.default_file_name(),
+            "code-quality-report.xml"
+        );
+    }
+
+    #[test]
+    fn test_junit_report_rendering() {
+        let xml = render_junit_report(&sample_results());
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase name=\"lint\""));
+        assert!(xml.contains("value=\"cargo fmt --check\""));
+        assert!(xml.contains("<failure message=\"formatting issues &amp; more\">"));
+        assert!(xml.contains("Diff in &lt;src/main.rs&gt;"));
+    }
+
+    #[test]
+    fn test_sarif_report_rendering() {
+        let sarif = render_sarif_report(&sample_results()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+
+        assert_eq!(value["version"], "2.1.0");
+        let run = &value["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "nitroterm");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "format");
+        assert_eq!(results[0]["properties"]["command"], "cargo fmt --check");
+        // No file in the output, so the result points at the project
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "."
+        );
+
+        let clippy = CheckResult {
+            check_name: "api/clippy".to_string(),
+            command: "cargo clippy".to_string(),
+            success: false,
+            output: String::new(),
+            error: Some("error: unused variable: `x`\n  --> src/lib.rs:3:9".to_string()),
+            duration_ms: 10,
+        };
+        let sarif = render_sarif_report(&[clippy]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+        let location = &value["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "api/src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 9);
+    }
+
+    #[test]
+    fn test_json_report_rendering() {
+        let report = render_json_report(&sample_results()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+        assert_eq!(value["summary"]["total"], 2);
+        assert_eq!(value["summary"]["failed"], 1);
+        assert_eq!(value["summary"]["duration_ms"], 1500);
+        assert_eq!(value["checks"][1]["command"], "cargo fmt --check");
+    }
+
+    #[test]
+    fn test_extract_findings() {
+        let result = CheckResult {
+            check_name: "lint".to_string(),
+            command: "cargo clippy".to_string(),
+            success: false,
+            output: "src/app.ts(12,5): error TS2322: Type 'string' is not assignable\n\
+                     app/views.py:3:1: F401 'os' imported but unused\n"
+                .to_string(),
+            error: Some(
+                "warning: unused variable: `x`\n  --> src/main.rs:4:9\n   |\n\
+                 error[E0308]: mismatched types\n  --> src/lib.rs:10:5\n\
+                 error: could not compile `app`\n"
+                    .to_string(),
+            ),
+            duration_ms: 10,
+        };
+
+        let findings = extract_findings(&result);
+        assert_eq!(findings.len(), 4);
+
+        assert_eq!(findings[0].file, "src/app.ts");
+        assert_eq!((findings[0].line, findings[0].column), (12, 5));
+        assert_eq!(
+            findings[0].message,
+            "TS2322: Type 'string' is not assignable"
+        );
+
+        assert_eq!(findings[1].file, "app/views.py");
+        assert_eq!(findings[1].severity, "error");
+
+        assert_eq!(findings[2].file, "src/main.rs");
+        assert_eq!(findings[2].severity, "warning");
+        assert_eq!(findings[2].message, "unused variable: `x`");
+
+        assert_eq!(findings[3].file, "src/lib.rs");
+        assert_eq!(findings[3].message, "mismatched types");
+
+        // The printed line must round-trip through the editor's problem matcher
+        let pattern = regex::Regex::new(FINDING_PATTERN).unwrap();
+        let line = format!("      {}", findings[2].to_line());
+        let caps = pattern.captures(&line).unwrap();
+        assert_eq!(&caps[1], "src/main.rs");
+        assert_eq!(&caps[4], "warning");
+        assert_eq!(&caps[6], "lint");
+    }
 }
@@ -0,0 +1,147 @@
+use crate::commands::codeowners::{CodeOwners, TeamSummary};
+use std::fs;
+use tempfile::TempDir;
+
+const CODEOWNERS: &str = "\
+# Maintained by the platform team
+
+* @org/maintainers
+
+# Frontend
+/web/ @org/web
+*.css @org/web
+
+# Backend
+# (API and workers)
+/api/ @org/api # inline comment
+docs/* @org/docs
+**/migrations @org/dba
+/api/generated/
+";
+
+fn owner(codeowners: &CodeOwners, path: &str) -> Option<String> {
+    codeowners.owner_of(path).map(|rule| rule.owners.join(" "))
+}
+
+#[test]
+fn test_last_matching_rule_wins() {
+    let codeowners = CodeOwners::parse(CODEOWNERS).unwrap();
+    assert_eq!(codeowners.rules.len(), 7);
+
+    assert_eq!(owner(&codeowners, "README.md").unwrap(), "@org/maintainers");
+    assert_eq!(owner(&codeowners, "web/src/app.ts").unwrap(), "@org/web");
+    assert_eq!(
+        owner(&codeowners, "lib/theme/main.css").unwrap(),
+        "@org/web"
+    );
+    assert_eq!(owner(&codeowners, "api/server.rs").unwrap(), "@org/api");
+    assert_eq!(
+        owner(&codeowners, "api/db/migrations/001.sql").unwrap(),
+        "@org/dba"
+    );
+    // A rule without owners unassigns the paths
+    assert_eq!(owner(&codeowners, "api/generated/client.rs"), None);
+}
+
+#[test]
+fn test_pattern_anchoring() {
+    let codeowners = CodeOwners::parse(
+        "/web/ @root-web\n\
+         docs/* @docs\n\
+         build @build\n\
+         src/*.rs @rust\n\
+         /config/**/local.toml @ops\n",
+    )
+    .unwrap();
+
+    // `/web/` is anchored to the root, `build` matches at any depth
+    assert_eq!(owner(&codeowners, "web/index.html").unwrap(), "@root-web");
+    assert_eq!(owner(&codeowners, "apps/web/index.html"), None);
+    assert_eq!(owner(&codeowners, "apps/build/out.js").unwrap(), "@build");
+    assert_eq!(owner(&codeowners, "build").unwrap(), "@build");
+    // `docs/*` stops at the first level
+    assert_eq!(owner(&codeowners, "docs/intro.md").unwrap(), "@docs");
+    assert_eq!(owner(&codeowners, "docs/guides/setup.md"), None);
+    assert_eq!(owner(&codeowners, "src/main.rs").unwrap(), "@rust");
+    assert_eq!(owner(&codeowners, "src/bin/tool.rs"), None);
+    assert_eq!(owner(&codeowners, "config/local.toml").unwrap(), "@ops");
+    assert_eq!(
+        owner(&codeowners, "config/eu/prod/local.toml").unwrap(),
+        "@ops"
+    );
+}
+
+#[test]
+fn test_areas_from_comments_and_sections() {
+    let codeowners = CodeOwners::parse(CODEOWNERS).unwrap();
+    let areas: Vec<Option<&str>> = codeowners
+        .rules
+        .iter()
+        .map(|rule| rule.area.as_deref())
+        .collect();
+    assert_eq!(
+        areas,
+        vec![
+            None,
+            Some("Frontend"),
+            Some("Frontend"),
+            Some("Backend"),
+            Some("Backend"),
+            Some("Backend"),
+            Some("Backend"),
+        ]
+    );
+
+    let gitlab = CodeOwners::parse(
+        "[Documentation][2] @org/docs\n\
+         docs/\n\
+         README.md @alice\n\
+         ^[Database] @org/dba\n\
+         db/\n",
+    )
+    .unwrap();
+    assert_eq!(owner(&gitlab, "docs/intro.md").unwrap(), "@org/docs");
+    assert_eq!(owner(&gitlab, "README.md").unwrap(), "@alice");
+    assert_eq!(owner(&gitlab, "db/schema.sql").unwrap(), "@org/dba");
+    assert_eq!(
+        gitlab.owner_of("db/schema.sql").unwrap().area.as_deref(),
+        Some("Database")
+    );
+}
+
+#[test]
+fn test_team_summary_counts_commits_once_per_team() {
+    let codeowners = CodeOwners::parse(CODEOWNERS).unwrap();
+    let mut teams = TeamSummary::default();
+    teams.add(&codeowners, ["web/a.ts", "web/b.ts", "api/server.rs"]);
+    teams.add(&codeowners, ["web/c.ts"]);
+    teams.add(&codeowners, ["api/generated/client.rs"]);
+    teams.add(&codeowners, ["README.md"]);
+
+    let ranked: Vec<(Option<&str>, String, usize)> = teams
+        .ranked()
+        .iter()
+        .map(|team| (team.area.as_deref(), team.owners.join(" "), team.commits))
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![
+            (Some("Frontend"), "@org/web".to_string(), 2),
+            (Some("Backend"), "@org/api".to_string(), 1),
+            (None, "@org/maintainers".to_string(), 1),
+        ]
+    );
+    assert_eq!(teams.unowned, 1);
+}
+
+#[test]
+fn test_load_finds_github_location_first() {
+    let temp_dir = TempDir::new().unwrap();
+    assert!(CodeOwners::load(temp_dir.path()).unwrap().is_none());
+
+    fs::write(temp_dir.path().join("CODEOWNERS"), "* @root\n").unwrap();
+    fs::create_dir(temp_dir.path().join(".github")).unwrap();
+    fs::write(temp_dir.path().join(".github/CODEOWNERS"), "* @github\n").unwrap();
+    let codeowners = CodeOwners::load(temp_dir.path()).unwrap().unwrap();
+    assert_eq!(owner(&codeowners, "main.rs").unwrap(), "@github");
+}
@@ -0,0 +1,43 @@
+use crate::commands::commit_dedup::{cherry_pick_sources, revert_target, RevertTarget};
+use git2::Oid;
+
+const HASH: &str = "1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b";
+
+#[test]
+fn test_revert_target_from_git_revert() {
+    let message = format!("Revert \"feat: search\"\n\nThis reverts commit {}.\n", HASH);
+    assert_eq!(
+        revert_target(&message),
+        Some(RevertTarget {
+            hashes: vec![HASH.to_string()],
+            subject: Some("feat: search".to_string()),
+        })
+    );
+
+    // A revert of a revert points at the first revert
+    let target = revert_target("Revert \"Revert \"feat: search\"\"").unwrap();
+    assert_eq!(target.subject.as_deref(), Some("Revert \"feat: search\""));
+}
+
+#[test]
+fn test_revert_target_from_conventional_commit() {
+    let target = revert_target("revert(api)!: feat: search\n\nRefs: 1a2b3c4, 5D6E7F8\n").unwrap();
+    assert_eq!(target.subject.as_deref(), Some("feat: search"));
+    assert_eq!(target.hashes, vec!["1a2b3c4", "5d6e7f8"]);
+    assert!(target.matches(Oid::from_str(HASH).unwrap(), "anything"));
+    assert!(target.matches(Oid::zero(), " feat: search"));
+    assert!(!target.matches(Oid::zero(), "feat: search v2"));
+
+    assert_eq!(revert_target("feat: revert button"), None);
+    assert_eq!(revert_target("fix: crash\n\nRefs: 1a2b3c4"), None);
+}
+
+#[test]
+fn test_cherry_pick_sources() {
+    let message = format!("fix: crash\n\n(cherry picked from commit {})\n", HASH);
+    assert_eq!(
+        cherry_pick_sources(&message),
+        vec![Oid::from_str(HASH).unwrap()]
+    );
+    assert!(cherry_pick_sources("fix: crash\n\n(cherry picked from commit 1a2b3c4)").is_empty());
+}
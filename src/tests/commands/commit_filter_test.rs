@@ -0,0 +1,86 @@
+use crate::commands::commit_filter::CommitFilter;
+use crate::commands::config::AppConfig;
+use crate::commands::release_notes::{walk_commits_between_tags, CommitLimits};
+use tempfile::TempDir;
+
+#[test]
+fn test_filter_matches_authors_and_patterns() {
+    let config = AppConfig {
+        release_exclude_authors: vec!["Dependabot".to_string()],
+        release_exclude_patterns: vec!["[skip changelog]".to_string()],
+        ..AppConfig::default()
+    };
+    let filter = CommitFilter::from_config(
+        &config,
+        &["renovate".to_string(), " ".to_string()],
+        &["chore(deps)".to_string(), "[SKIP CHANGELOG]".to_string()],
+    );
+    assert_eq!(filter.authors, vec!["dependabot", "renovate"]);
+    assert_eq!(filter.patterns, vec!["[skip changelog]", "chore(deps)"]);
+
+    assert!(filter.excludes(
+        "dependabot[bot]",
+        "49699333+dependabot[bot]@users.noreply.github.com",
+        "fix: bump serde"
+    ));
+    assert!(filter.excludes("Jane", "jane@example.com", "chore(deps): bump tokio"));
+    assert!(filter.excludes("Jane", "jane@example.com", "docs: typo\n\n[Skip Changelog]"));
+    assert!(!filter.excludes("Jane", "jane@example.com", "feat: add charts"));
+    assert!(!CommitFilter::default().excludes("dependabot[bot]", "", "chore(deps): x"));
+}
+
+#[test]
+fn test_walk_skips_excluded_commits() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let tree_id = repo.index().unwrap().write_tree().unwrap();
+    let tree = repo.find_tree(tree_id).unwrap();
+
+    let mut parent: Option<git2::Commit> = None;
+    for (index, (author, message)) in [
+        ("Jane", "feat: add charts"),
+        ("dependabot[bot]", "chore(deps): bump serde"),
+        ("Jane", "fix: crash"),
+        ("Jane", "chore: tidy up [skip changelog]"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let signature = git2::Signature::new(
+            author,
+            "dev@example.com",
+            &git2::Time::new(index as i64 * 60, 0),
+        )
+        .unwrap();
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        let oid = repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                message,
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        parent = Some(repo.find_commit(oid).unwrap());
+    }
+
+    let filter = CommitFilter::new(
+        &["dependabot".to_string()],
+        &["[skip changelog]".to_string()],
+    );
+    let walk = walk_commits_between_tags(
+        &repo,
+        &None,
+        "HEAD",
+        CommitLimits::default(),
+        &filter,
+        &mut |_| {},
+    )
+    .unwrap();
+    let messages: Vec<&str> = walk.commits.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(messages, vec!["fix: crash", "feat: add charts"]);
+    assert_eq!(walk.excluded, 2);
+    assert_eq!(walk.summary.total_commits, 2);
+}
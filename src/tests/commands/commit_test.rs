@@ -0,0 +1,124 @@
+use crate::commands::commit::{
+    commit_args, commit_with, lint_commit_message, parse_commit_type, CommitDraft, CommitOptions,
+};
+use crate::tests::support::MockRunner;
+
+fn rules(message: &str) -> Vec<&'static str> {
+    lint_commit_message(message)
+        .into_iter()
+        .map(|found| found.rule)
+        .collect()
+}
+
+#[test]
+fn test_draft_message() {
+    let mut draft = CommitDraft {
+        commit_type: "feat".to_string(),
+        scope: Some("api".to_string()),
+        subject: "add pagination to the list endpoint".to_string(),
+        body: None,
+        breaking: None,
+    };
+    assert_eq!(
+        draft.message(),
+        "feat(api): add pagination to the list endpoint"
+    );
+
+    draft.body = Some("Large accounts timed out.".to_string());
+    draft.breaking = Some("`page` starts at 1".to_string());
+    let message = draft.message();
+    assert_eq!(
+        message,
+        "feat(api)!: add pagination to the list endpoint\n\nLarge accounts timed out.\n\nBREAKING CHANGE: `page` starts at 1"
+    );
+    assert!(lint_commit_message(&message).is_empty());
+    assert_eq!(CommitDraft::parse(&message), Some(draft.clone()));
+    let signed = format!("{}\n\nSigned-off-by: Dev <dev@example.com>", message);
+    assert_eq!(CommitDraft::parse(&signed), Some(draft));
+    assert_eq!(CommitDraft::parse("Update readme"), None);
+}
+
+#[test]
+fn test_lint_commit_message() {
+    assert!(rules("fix: handle empty config").is_empty());
+    assert!(rules("docs(readme)!: drop the v1 section").is_empty());
+    // Merge and revert messages come from git
+    assert!(rules("Merge branch 'main' into feature").is_empty());
+
+    assert_eq!(rules("Update readme"), vec!["header-format"]);
+    assert_eq!(rules("feature: add login"), vec!["type-enum"]);
+    assert_eq!(rules("Fix: handle empty config"), vec!["type-case"]);
+    assert_eq!(rules("fix(API): handle it"), vec!["scope-case"]);
+    assert_eq!(
+        rules("fix: Handle empty config."),
+        vec!["subject-case", "subject-full-stop"]
+    );
+    assert_eq!(rules("fix: "), vec!["subject-empty"]);
+    assert_eq!(
+        rules(&format!("feat: {}", "a".repeat(100))),
+        vec!["header-max-length"]
+    );
+    assert_eq!(
+        rules("fix: handle empty config\nno blank line"),
+        vec!["body-leading-blank"]
+    );
+    assert_eq!(
+        rules("feat!: drop node 16\n\nOld runtimes.\nBREAKING CHANGE: node 18 is required"),
+        vec!["footer-leading-blank"]
+    );
+    assert_eq!(
+        rules(&format!("fix: wrap lines\n\n{}", "b".repeat(101))),
+        vec!["body-max-line-length"]
+    );
+}
+
+#[test]
+fn test_parse_commit_type() {
+    assert_eq!(parse_commit_type("feat"), Some("feat"));
+    assert_eq!(parse_commit_type(" FIX "), Some("fix"));
+    assert_eq!(parse_commit_type("2"), Some("fix"));
+    assert_eq!(parse_commit_type("0"), None);
+    assert_eq!(parse_commit_type("12"), None);
+    assert_eq!(parse_commit_type("feature"), None);
+}
+
+#[test]
+fn test_commit_with() {
+    let options = CommitOptions {
+        amend: true,
+        signoff: true,
+    };
+    assert_eq!(
+        commit_args("fix: x", &options),
+        vec!["commit", "--amend", "--signoff", "-m", "fix: x"]
+    );
+
+    let runner = MockRunner::new();
+    runner
+        .fail("git diff --cached --quiet", "")
+        .respond("git commit", "[main abc1234] fix: handle empty config\n");
+    let output = commit_with(
+        &runner,
+        "fix: handle empty config",
+        &CommitOptions::default(),
+    )
+    .unwrap();
+    assert!(output.contains("abc1234"));
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "git diff --cached --quiet",
+            "git commit -m fix: handle empty config"
+        ]
+    );
+
+    // Nothing staged
+    let runner = MockRunner::new();
+    let error = commit_with(&runner, "fix: x", &CommitOptions::default()).unwrap_err();
+    assert!(error.to_string().contains("Nothing staged"));
+
+    // The message is checked before git runs
+    let runner = MockRunner::new();
+    assert!(commit_with(&runner, "Update readme", &CommitOptions::default()).is_err());
+    assert!(runner.calls().is_empty());
+}
@@ -1,4 +1,10 @@
-use crate::commands::config::{AppConfig, ConfigManager};
+use crate::commands::config::{
+    apply_profile, check_profile_name, describe_profile, profile_of, updated_profile, AppConfig,
+    ConfigManager, ConfigProfile, IdentityProfile,
+};
+use crate::commands::dependency_update::UpdateRules;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 // ...existing code at the end of the file...
@@ -16,8 +22,13 @@ mod tests {
         assert_eq!(config.gemini_api_key, None);
         assert_eq!(config.gemini_model, "gemini-1.5-flash");
         assert_eq!(config.translation_delay_seconds, 2);
+        assert_eq!(config.translation_concurrency, 4);
+        assert_eq!(config.secret_storage, "plain");
         assert_eq!(config.messages_dir, "messages");
         assert_eq!(config.source_file, "source.json");
+        assert_eq!(config.theme, "default");
+        assert_eq!(config.translation_provider, "gemini");
+        assert_eq!(config.openai_api_key, None);
     }
 
     #[test]
@@ -28,6 +39,42 @@ mod tests {
             translation_delay_seconds: 5,
             messages_dir: "test-messages".to_string(),
             source_file: "test.json".to_string(),
+            templates: HashMap::new(),
+            theme: "default".to_string(),
+            git_identity: None,
+            translation_provider: "openai".to_string(),
+            openai_api_key: Some("sk-test".to_string()),
+            openai_model: "gpt-4o".to_string(),
+            anthropic_api_key: None,
+            anthropic_model: "claude-3-5-haiku-latest".to_string(),
+            labels_file: None,
+            github_backend: "gh".to_string(),
+            document_locale: "en".to_string(),
+            timezone: "utc".to_string(),
+            notify_webhooks: Vec::new(),
+            translation_fallback_provider: None,
+            translation_max_retries: 3,
+            translation_concurrency: 2,
+            secret_storage: "plain".to_string(),
+            org_projects: Vec::new(),
+            release_signing_key: None,
+            release_branch: None,
+            gitlab_url: None,
+            gitlab_token: None,
+            bitbucket_username: None,
+            bitbucket_app_password: None,
+            release_exclude_authors: Vec::new(),
+            release_exclude_patterns: Vec::new(),
+            update_check: true,
+            update_check_interval_hours: 24,
+            update_channel: "stable".to_string(),
+            github_repo: None,
+            github_org: None,
+            release_notes_format: "full".to_string(),
+            quality_checks: Vec::new(),
+            dependency_update: UpdateRules::default(),
+            protected_branches: vec!["main".to_string()],
+            profiles: BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -41,6 +88,11 @@ mod tests {
         );
         assert_eq!(config.messages_dir, deserialized.messages_dir);
         assert_eq!(config.source_file, deserialized.source_file);
+        assert_eq!(
+            config.translation_provider,
+            deserialized.translation_provider
+        );
+        assert_eq!(config.openai_api_key, deserialized.openai_api_key);
     }
 
     #[test]
@@ -86,6 +138,58 @@ mod tests {
             translation_delay_seconds: 10,
             messages_dir: "test-messages".to_string(),
             source_file: "test-source.json".to_string(),
+            templates: HashMap::from([(
+                "service".to_string(),
+                "https://github.com/acme/service-template".to_string(),
+            )]),
+            theme: "colorblind".to_string(),
+            git_identity: Some(IdentityProfile {
+                name: "Jane Doe".to_string(),
+                email: "jane@acme.dev".to_string(),
+                signing_key: None,
+                signing_format: None,
+            }),
+            translation_provider: "anthropic".to_string(),
+            openai_api_key: None,
+            openai_model: "gpt-4o-mini".to_string(),
+            anthropic_api_key: Some("sk-ant-test".to_string()),
+            anthropic_model: "claude-3-5-sonnet-latest".to_string(),
+            labels_file: Some("/repo/.github/labels.yml".to_string()),
+            github_backend: "api".to_string(),
+            document_locale: "tr".to_string(),
+            timezone: "+03:00".to_string(),
+            notify_webhooks: vec!["https://hooks.slack.com/services/T0/B0/secret".to_string()],
+            translation_fallback_provider: Some("openai".to_string()),
+            translation_max_retries: 5,
+            translation_concurrency: 8,
+            secret_storage: "encrypted".to_string(),
+            org_projects: vec!["/srv/repos/api".to_string()],
+            release_signing_key: Some("ABCD1234".to_string()),
+            release_branch: Some("main".to_string()),
+            gitlab_url: Some("https://gitlab.example.com".to_string()),
+            gitlab_token: Some("enc:v1:c2VhbGVk".to_string()),
+            bitbucket_username: Some("acme-bot".to_string()),
+            bitbucket_app_password: Some("keychain:".to_string()),
+            release_exclude_authors: vec!["dependabot".to_string()],
+            release_exclude_patterns: vec!["[skip changelog]".to_string()],
+            update_check: false,
+            update_check_interval_hours: 168,
+            update_channel: "prerelease".to_string(),
+            github_repo: Some("acme/widget".to_string()),
+            github_org: Some("acme".to_string()),
+            release_notes_format: "summary".to_string(),
+            quality_checks: vec!["lint".to_string(), "secrets".to_string()],
+            dependency_update: {
+                let mut rules = UpdateRules::default();
+                rules.add_ignore("node:left-pad");
+                rules.add_pin("react@18").unwrap();
+                rules
+            },
+            protected_branches: vec!["main".to_string(), "hotfix/*".to_string()],
+            profiles: BTreeMap::from([(
+                "work".to_string(),
+                ConfigProfile::from([("github_repo".to_string(), json!("acme/api"))]),
+            )]),
         };
 
         let save_result = manager.save_config(&test_config).await;
@@ -101,6 +205,76 @@ mod tests {
         );
         assert_eq!(loaded_config.messages_dir, test_config.messages_dir);
         assert_eq!(loaded_config.source_file, test_config.source_file);
+        assert_eq!(loaded_config.templates, test_config.templates);
+        assert_eq!(loaded_config.theme, test_config.theme);
+        assert_eq!(loaded_config.git_identity, test_config.git_identity);
+        assert_eq!(
+            loaded_config.translation_provider,
+            test_config.translation_provider
+        );
+        assert_eq!(
+            loaded_config.anthropic_api_key,
+            test_config.anthropic_api_key
+        );
+        assert_eq!(loaded_config.anthropic_model, test_config.anthropic_model);
+        assert_eq!(loaded_config.labels_file, test_config.labels_file);
+        assert_eq!(loaded_config.github_backend, test_config.github_backend);
+        assert_eq!(loaded_config.document_locale, test_config.document_locale);
+        assert_eq!(loaded_config.timezone, test_config.timezone);
+        assert_eq!(loaded_config.notify_webhooks, test_config.notify_webhooks);
+        assert_eq!(loaded_config.org_projects, test_config.org_projects);
+        assert_eq!(
+            loaded_config.release_signing_key,
+            test_config.release_signing_key
+        );
+        assert_eq!(loaded_config.release_branch, test_config.release_branch);
+        assert_eq!(loaded_config.gitlab_url, test_config.gitlab_url);
+        assert_eq!(loaded_config.gitlab_token, test_config.gitlab_token);
+        assert_eq!(
+            loaded_config.bitbucket_username,
+            test_config.bitbucket_username
+        );
+        assert_eq!(
+            loaded_config.bitbucket_app_password,
+            test_config.bitbucket_app_password
+        );
+        assert_eq!(
+            loaded_config.release_exclude_authors,
+            test_config.release_exclude_authors
+        );
+        assert_eq!(
+            loaded_config.release_exclude_patterns,
+            test_config.release_exclude_patterns
+        );
+        assert!(!loaded_config.update_check);
+        assert_eq!(loaded_config.update_check_interval_hours, 168);
+        assert_eq!(loaded_config.update_channel, "prerelease");
+        assert_eq!(loaded_config.github_repo, test_config.github_repo);
+        assert_eq!(loaded_config.github_org, test_config.github_org);
+        assert_eq!(loaded_config.release_notes_format, "summary");
+        assert_eq!(loaded_config.quality_checks, test_config.quality_checks);
+        assert_eq!(
+            loaded_config.dependency_update,
+            test_config.dependency_update
+        );
+        assert_eq!(
+            loaded_config.protected_branches,
+            test_config.protected_branches
+        );
+        assert_eq!(
+            loaded_config.translation_fallback_provider,
+            test_config.translation_fallback_provider
+        );
+        assert_eq!(
+            loaded_config.translation_max_retries,
+            test_config.translation_max_retries
+        );
+        assert_eq!(
+            loaded_config.translation_concurrency,
+            test_config.translation_concurrency
+        );
+        assert_eq!(loaded_config.secret_storage, test_config.secret_storage);
+        assert_eq!(loaded_config.profiles, test_config.profiles);
 
         std::env::remove_var("XDG_CONFIG_HOME");
     }
@@ -182,4 +356,91 @@ mod tests {
             assert!(!file.ends_with(".json"));
         }
     }
+
+    #[test]
+    fn test_profiles_overlay_the_main_config() {
+        let base = AppConfig {
+            github_repo: Some("acme/site".to_string()),
+            gemini_api_key: Some("AIzaSy-main-secret".to_string()),
+            ..Default::default()
+        };
+        let profile = ConfigProfile::from([
+            ("github_repo".to_string(), json!("work/api")),
+            ("release_exclude_authors".to_string(), json!(["bot"])),
+            // Only the bundled settings can be switched
+            ("theme".to_string(), json!("colorblind")),
+        ]);
+
+        let config = apply_profile(&base, &profile);
+        assert_eq!(config.github_repo.as_deref(), Some("work/api"));
+        assert_eq!(config.release_exclude_authors, vec!["bot"]);
+        assert_eq!(config.gemini_api_key.as_deref(), Some("AIzaSy-main-secret"));
+        assert_eq!(config.theme, "default");
+
+        let copied = profile_of(&base);
+        assert_eq!(copied["github_repo"], json!("acme/site"));
+        assert!(!copied.contains_key("theme"));
+        assert!(describe_profile(&copied).contains(&"github_repo = acme/site".to_string()));
+        assert!(!describe_profile(&copied)
+            .iter()
+            .any(|line| line.contains("main-secret")));
+    }
+
+    #[test]
+    fn test_profiles_hold_org_hosting_and_identity_settings() {
+        let base = AppConfig {
+            org_projects: vec!["/srv/personal".to_string()],
+            ..Default::default()
+        };
+        let profile = ConfigProfile::from([
+            ("github_org".to_string(), json!("acme")),
+            ("org_projects".to_string(), json!(["/srv/acme/api"])),
+            ("gitlab_token".to_string(), json!("glpat-work-secret")),
+            (
+                "git_identity".to_string(),
+                json!({"name": "Jane Doe", "email": "jane@acme.dev"}),
+            ),
+        ]);
+
+        let config = apply_profile(&base, &profile);
+        assert_eq!(config.github_org.as_deref(), Some("acme"));
+        assert_eq!(config.org_projects, vec!["/srv/acme/api"]);
+        assert_eq!(config.gitlab_token.as_deref(), Some("glpat-work-secret"));
+        assert_eq!(
+            config.git_identity.map(|identity| identity.email),
+            Some("jane@acme.dev".to_string())
+        );
+        assert!(!describe_profile(&profile)
+            .iter()
+            .any(|line| line.contains("work-secret")));
+    }
+
+    #[test]
+    fn test_saving_under_a_profile_keeps_its_own_settings() {
+        let base = AppConfig::default();
+        let profile = ConfigProfile::from([("github_repo".to_string(), json!("work/api"))]);
+        let mut config = apply_profile(&base, &profile);
+        config.github_repo = None;
+        config.labels_file = Some("work-labels.yml".to_string());
+        config.theme = "colorblind".to_string();
+
+        // Set before: kept even when it now matches the main value
+        let updated = updated_profile(&profile, &base, &config);
+        assert_eq!(
+            updated,
+            ConfigProfile::from([
+                ("github_repo".to_string(), json!(null)),
+                ("labels_file".to_string(), json!("work-labels.yml")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert!(check_profile_name("work").is_ok());
+        assert!(check_profile_name("client_a-2").is_ok());
+        assert!(check_profile_name("").is_err());
+        assert!(check_profile_name("work/prod").is_err());
+        assert!(check_profile_name("my work").is_err());
+    }
 }
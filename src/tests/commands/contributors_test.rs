@@ -0,0 +1,66 @@
+use crate::commands::contributors::{collect_contributors, render_markdown};
+use crate::tests::support::TestCommit;
+use git2::Repository;
+use tempfile::tempdir;
+
+#[test]
+fn test_collect_contributors_with_range() {
+    let temp_dir = tempdir().unwrap();
+    let repo = Repository::init(temp_dir.path()).unwrap();
+    repo.remote("origin", "https://github.com/acme/app.git")
+        .unwrap();
+
+    let octocat = ("Octo Cat", "1+octocat@users.noreply.github.com");
+    let jane = ("Jane", "jane@example.com");
+
+    // 2024-01-01, 2024-02-01, 2024-03-01, 2024-04-01
+    TestCommit::new("change")
+        .file("a.txt", "one\ntwo\n")
+        .author(jane.0, jane.1)
+        .at(1704067200)
+        .commit(&repo);
+    let first = repo.head().unwrap().target().unwrap();
+    repo.tag_lightweight("v1.0.0", &repo.find_object(first, None).unwrap(), false)
+        .unwrap();
+    TestCommit::new("change")
+        .file("a.txt", "one\n2\nthree\n")
+        .author(octocat.0, octocat.1)
+        .at(1706745600)
+        .commit(&repo);
+    TestCommit::new("change")
+        .file("b.txt", "x\ny\n")
+        .author(octocat.0, octocat.1)
+        .at(1709251200)
+        .commit(&repo);
+    TestCommit::new("change")
+        .file("a.txt", "one\n")
+        .author(jane.0, jane.1)
+        .at(1711929600)
+        .commit(&repo);
+
+    let all = collect_contributors(&repo, None, None).unwrap();
+    assert_eq!(all.len(), 2);
+    // Tied on commits, Octo Cat changed more lines
+    assert_eq!(all[0].name, "Octo Cat");
+    assert_eq!(all[0].github.as_deref(), Some("octocat"));
+    assert_eq!((all[0].additions, all[0].deletions), (4, 1));
+    assert_eq!(all[1].github, None);
+    assert_eq!((all[1].additions, all[1].deletions), (2, 2));
+    assert_eq!(
+        (all[1].first_commit, all[1].last_commit),
+        (1704067200, 1711929600)
+    );
+
+    let since_tag = collect_contributors(&repo, Some("v1.0.0"), Some("HEAD~1")).unwrap();
+    assert_eq!(since_tag.len(), 1);
+    assert_eq!(since_tag[0].commits, 2);
+
+    let markdown = render_markdown(&all);
+    assert!(markdown.starts_with("| # | Contributor |"));
+    assert!(markdown.contains(
+        "| 1 | [@octocat](https://github.com/octocat) (Octo Cat) | 2 | +4 | -1 | 2024-02-01 | 2024-03-01 |"
+    ));
+    assert!(markdown.contains("| 2 | Jane | 2 | +2 | -2 | 2024-01-01 | 2024-04-01 |"));
+
+    assert!(collect_contributors(&repo, Some("v9.9.9"), None).is_err());
+}
@@ -297,4 +297,81 @@ serde = "1.0"
         let error_result: Result<(), Box<dyn std::error::Error>> = Err("Test error".into());
         assert!(error_result.is_err());
     }
+
+    #[test]
+    fn test_publish_release_tag_with_mock_runner() {
+        use crate::commands::create_release::{
+            get_latest_tag_safe, publish_release_tag, TagSigning,
+        };
+        use crate::tests::support::MockRunner;
+
+        let runner = MockRunner::new();
+        runner
+            .respond("git status --porcelain", " M Cargo.toml\n")
+            .respond("git describe", "v1.2.0\n")
+            .fail("git push origin", "remote: Permission denied");
+
+        let tag = publish_release_tag(&runner, "1.3.0", None, &TagSigning::None).unwrap();
+        assert_eq!(tag, "v1.3.0");
+        assert_eq!(
+            runner.calls(),
+            vec![
+                "git status --porcelain",
+                "git add Cargo.toml",
+                "git commit -m bump: version 1.3.0",
+                "git tag -a v1.3.0 -m Release v1.3.0",
+                "git push",
+                "git push origin v1.3.0",
+            ]
+        );
+        assert_eq!(
+            get_latest_tag_safe(&runner).unwrap(),
+            Some("v1.2.0".to_string())
+        );
+
+        let failing = MockRunner::new();
+        failing.fail("git tag", "fatal: tag 'v1.3.0' already exists");
+        let error =
+            publish_release_tag(&failing, "1.3.0", Some("Hotfix"), &TagSigning::None).unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_signed_release_tag() {
+        use crate::commands::create_release::{publish_release_tag, TagSigning};
+        use crate::tests::support::MockRunner;
+
+        assert_eq!(
+            TagSigning::resolve(false, None, Some("ABCD")),
+            TagSigning::None
+        );
+        assert_eq!(
+            TagSigning::resolve(true, None, None),
+            TagSigning::DefaultKey
+        );
+        assert_eq!(
+            TagSigning::resolve(true, None, Some("ABCD")),
+            TagSigning::Key("ABCD".to_string())
+        );
+        assert_eq!(
+            TagSigning::resolve(false, Some("1234"), Some("ABCD")),
+            TagSigning::Key("1234".to_string())
+        );
+
+        let runner = MockRunner::new();
+        publish_release_tag(&runner, "1.3.0", None, &TagSigning::DefaultKey).unwrap();
+        publish_release_tag(&runner, "1.3.1", None, &TagSigning::Key("ABCD".to_string())).unwrap();
+        let tags: Vec<String> = runner
+            .calls()
+            .into_iter()
+            .filter(|call| call.starts_with("git tag"))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                "git tag -s v1.3.0 -m Release v1.3.0",
+                "git tag -u ABCD v1.3.1 -m Release v1.3.1",
+            ]
+        );
+    }
 }
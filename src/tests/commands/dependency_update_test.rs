@@ -1,6 +1,14 @@
-use crate::commands::dependency_update::update_dependencies;
-use crate::commands::release_notes::generate_release_notes;
+use crate::commands::commit_filter::CommitFilter;
+use crate::commands::dependency_update::{
+    backup_cargo_files, detect_node_package_manager, detect_python_package_manager,
+    find_project_files, manifest_dir, pin_allows, pyproject_dependencies, update_dependencies,
+    without_ignored, EcosystemResult, UpdateRules,
+};
+use crate::commands::release_notes::{generate_release_notes, CommitLimits};
+use crate::utils::document_locale::DocumentLocale;
+use crate::utils::file_system::ScanOptions;
 use std::fs;
+use std::path::Path;
 use std::process::Command;
 use tempfile::TempDir;
 
@@ -21,7 +29,7 @@ fn test_dependency_update_function() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Test the function directly instead of command
-    update_dependencies();
+    update_dependencies(Path::new("."), &ScanOptions::top_level());
 
     // Restore original directory - this must succeed
     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -33,27 +41,27 @@ fn test_dependency_update_function() {
     // Test passed if no panic occurred during update_dependencies()
 }
 
-#[test]
-fn test_release_notes_function() {
+#[tokio::test]
+async fn test_release_notes_function() {
     let temp_dir = TempDir::new().unwrap();
     let temp_path = temp_dir.path();
 
     // Initialize a git repo
     Command::new("git")
-        .args(&["init"])
+        .args(["init"])
         .current_dir(temp_path)
         .output()
         .expect("Failed to init git repo");
 
     // Configure git user for this repo
     Command::new("git")
-        .args(&["config", "user.name", "Test User"])
+        .args(["config", "user.name", "Test User"])
         .current_dir(temp_path)
         .output()
         .expect("Failed to set git user name");
 
     Command::new("git")
-        .args(&["config", "user.email", "test@example.com"])
+        .args(["config", "user.email", "test@example.com"])
         .current_dir(temp_path)
         .output()
         .expect("Failed to set git user email");
@@ -62,13 +70,13 @@ fn test_release_notes_function() {
     fs::write(temp_path.join("README.md"), "# Test Project").unwrap();
 
     Command::new("git")
-        .args(&["add", "."])
+        .args(["add", "."])
         .current_dir(temp_path)
         .output()
         .expect("Failed to add files");
 
     Command::new("git")
-        .args(&["commit", "-m", "feat: initial commit"])
+        .args(["commit", "-m", "feat: initial commit"])
         .current_dir(temp_path)
         .output()
         .expect("Failed to commit");
@@ -78,7 +86,17 @@ fn test_release_notes_function() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Test the function directly
-    generate_release_notes();
+    generate_release_notes(
+        Path::new("."),
+        DocumentLocale::default(),
+        true,
+        None,
+        CommitLimits::default(),
+        &CommitFilter::default(),
+        None,
+    )
+    .await
+    .unwrap();
 
     // Restore original directory
     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -200,7 +218,7 @@ path = "src/main.rs""#,
     std::env::set_current_dir(temp_path).unwrap();
 
     // This should not panic with multiple project types
-    update_dependencies();
+    update_dependencies(Path::new("."), &ScanOptions::top_level());
 
     // Safe restore
     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -216,20 +234,20 @@ path = "src/main.rs""#,
 
 //     // Initialize git repo
 //     Command::new("git")
-//         .args(&["init"])
+//         .args(["init"])
 //         .current_dir(temp_path)
 //         .output()
 //         .expect("Failed to init git repo");
 
 //     // Configure git
 //     Command::new("git")
-//         .args(&["config", "user.name", "Test User"])
+//         .args(["config", "user.name", "Test User"])
 //         .current_dir(temp_path)
 //         .output()
 //         .unwrap();
 
 //     Command::new("git")
-//         .args(&["config", "user.email", "test@example.com"])
+//         .args(["config", "user.email", "test@example.com"])
 //         .current_dir(temp_path)
 //         .output()
 //         .unwrap();
@@ -256,13 +274,13 @@ path = "src/main.rs""#,
 //         fs::write(&file_path, content).unwrap();
 
 //         Command::new("git")
-//             .args(&["add", filename])
+//             .args(["add", filename])
 //             .current_dir(temp_path)
 //             .output()
 //             .unwrap();
 
 //         Command::new("git")
-//             .args(&["commit", "-m", message])
+//             .args(["commit", "-m", message])
 //             .current_dir(temp_path)
 //             .output()
 //             .unwrap();
@@ -272,7 +290,7 @@ path = "src/main.rs""#,
 //     let original_dir = std::env::current_dir().unwrap();
 //     std::env::set_current_dir(temp_path).unwrap();
 
-//     generate_release_notes();
+//     generate_release_notes(DocumentLocale::default(), true, None);
 
 //     // Safe restore
 //     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -316,7 +334,7 @@ fn test_package_json_only() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Should handle package.json only without errors
-    update_dependencies();
+    update_dependencies(Path::new("."), &ScanOptions::top_level());
 
     // Safe restore
     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -334,11 +352,195 @@ fn test_empty_directory() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Should handle empty directory gracefully
-    update_dependencies();
+    let results = update_dependencies(Path::new("."), &ScanOptions::top_level());
 
     // Safe restore
     if let Err(e) = std::env::set_current_dir(&original_dir) {
         eprintln!("Failed to restore directory: {}", e);
         let _ = std::env::set_current_dir("/tmp");
     }
+    assert!(results.is_empty());
+}
+
+#[test]
+fn test_ecosystem_result_json() {
+    let json = serde_json::to_value(EcosystemResult::new("Cargo.toml")).unwrap();
+    assert_eq!(json["ecosystem"], "rust");
+    assert_eq!(json["manifest"], "Cargo.toml");
+    assert_eq!(json["status"], "skipped");
+    assert!(json["outdated"].is_null());
+    assert_eq!(
+        serde_json::to_value(EcosystemResult::new("deno.json")).unwrap()["ecosystem"],
+        "deno"
+    );
+}
+
+#[test]
+fn test_detect_python_package_manager() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(
+        root.join("pyproject.toml"),
+        "[project]\nname = \"app\"\ndependencies = [\"requests>=2.31\"]\n",
+    )
+    .unwrap();
+    assert_eq!(detect_python_package_manager(root), None);
+
+    fs::write(
+        root.join("pyproject.toml"),
+        "[project]\nname = \"app\"\n\n[tool.uv]\ndev-dependencies = []\n",
+    )
+    .unwrap();
+    assert_eq!(detect_python_package_manager(root).as_deref(), Some("uv"));
+
+    // The lock file wins over the tool table
+    fs::write(root.join("poetry.lock"), "").unwrap();
+    assert_eq!(
+        detect_python_package_manager(root).as_deref(),
+        Some("poetry")
+    );
+    assert_eq!(
+        serde_json::to_value(EcosystemResult::new("pyproject.toml")).unwrap()["ecosystem"],
+        "python"
+    );
+}
+
+#[test]
+fn test_manifests_of_another_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("apps/web")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(root.join("Cargo.lock"), "version = 3\n").unwrap();
+    fs::write(root.join("apps/web/package.json"), "{}").unwrap();
+    fs::write(root.join("apps/web/pnpm-lock.yaml"), "").unwrap();
+    let cwd = std::env::current_dir().unwrap();
+
+    let manifests = find_project_files(root, &ScanOptions::default());
+    assert_eq!(manifests, vec!["Cargo.toml", "apps/web/package.json"]);
+    assert_eq!(manifest_dir(root, &manifests[0]), root);
+    let web = manifest_dir(root, &manifests[1]);
+    assert_eq!(web, root.join("apps/web"));
+    assert_eq!(detect_node_package_manager(&web).as_deref(), Some("pnpm"));
+
+    // Backups land next to the manifest, not in the working directory
+    let backup_dir = backup_cargo_files(root).unwrap();
+    assert!(Path::new(&backup_dir).starts_with(root.join("backup")));
+    assert!(Path::new(&backup_dir).join("Cargo.lock").exists());
+    assert_eq!(std::env::current_dir().unwrap(), cwd);
+}
+
+#[test]
+fn test_pyproject_dependencies() {
+    let pep621 = r#"[project]
+name = "app"
+dependencies = [
+    "requests>=2.31",
+    'rich',  # terminal output
+]
+
+[project.optional-dependencies]
+dev = ["pytest"]
+"#;
+    assert_eq!(
+        pyproject_dependencies(pep621),
+        vec!["requests>=2.31", "rich"]
+    );
+    assert_eq!(
+        pyproject_dependencies("[project]\ndependencies = [\"httpx\", \"click>=8\"]\n"),
+        vec!["httpx", "click>=8"]
+    );
+
+    let poetry = r#"[tool.poetry]
+name = "app"
+version = "1.0.0"
+
+[tool.poetry.dependencies]
+python = "^3.11"
+fastapi = "^0.110"
+
+[tool.poetry.group.dev.dependencies]
+pytest = { version = "^8.0", optional = true }
+"#;
+    assert_eq!(
+        pyproject_dependencies(poetry),
+        vec![
+            "fastapi \"^0.110\"",
+            "pytest { version = \"^8.0\", optional = true }"
+        ]
+    );
+}
+
+#[test]
+fn test_update_rules() {
+    let mut rules = UpdateRules::default();
+    rules.add_ignore("left-pad");
+    rules.add_ignore("python:requests");
+    rules.add_ignore("python:requests");
+    rules.add_pin("node:react@18").unwrap();
+    rules.add_pin("@types/node@20").unwrap();
+    // Deno specifiers keep their prefix, `npm` isn't an ecosystem key
+    rules.add_ignore("npm:chalk");
+    assert!(rules.add_pin("@types/node").is_err());
+    assert_eq!(rules.ignore["python"], vec!["requests"]);
+
+    assert!(rules.is_ignored("php", "left-pad"));
+    assert!(rules.is_ignored("python", "requests"));
+    assert!(!rules.is_ignored("node", "requests"));
+    assert!(rules.is_ignored("deno", "npm:chalk"));
+    assert!(rules.allows("node", "react", "18.3.1"));
+    assert!(!rules.allows("node", "react", "19.0.0"));
+    // A react pin for node doesn't hold back another ecosystem
+    assert!(rules.allows("deno", "react", "19.0.0"));
+    assert!(!rules.allows("node", "@types/node", "22.1.0"));
+    assert!(rules.applies_to("rust"));
+    assert_eq!(rules.describe().len(), 5);
+
+    assert!(rules.remove("node:react"));
+    assert!(!rules.remove("react"));
+    assert!(rules.allows("node", "react", "19.0.0"));
+
+    let mut scoped = UpdateRules::default();
+    scoped.add_ignore("rust:serde");
+    assert!(scoped.applies_to("rust"));
+    assert!(!scoped.applies_to("node"));
+    assert!(scoped.remove("rust:serde"));
+    assert!(scoped.is_empty());
+}
+
+#[test]
+fn test_pin_allows() {
+    assert!(pin_allows("18", "18.3.1"));
+    assert!(pin_allows("^18", "v18.0.0"));
+    assert!(!pin_allows("18", "19.0.0"));
+    assert!(pin_allows("1.2", "1.2.9"));
+    assert!(!pin_allows("1.2", "1.3.0"));
+    assert!(pin_allows("1.x", "1.9.0"));
+    assert!(!pin_allows("18", "latest"));
+}
+
+#[test]
+fn test_without_ignored() {
+    let mut rules = UpdateRules::default();
+    rules.add_ignore("left-pad");
+    let npm = "Package   Current  Wanted  Latest  Location\nleft-pad  1.1.0    1.3.0   1.3.0   node_modules/left-pad\nreact     18.2.0   18.3.1  19.0.0  node_modules/react\n";
+    let filtered = without_ignored(npm, "node", &rules);
+    assert!(!filtered.contains("left-pad"));
+    assert!(filtered.contains("react"));
+
+    // Nothing but the header left means up to date
+    let only_ignored = "Package   Current  Wanted  Latest\nleft-pad  1.1.0    1.3.0   1.3.0\n";
+    assert_eq!(without_ignored(only_ignored, "node", &rules), "");
+    let pip = "Package  Version Latest Type\n-------- ------- ------ -----\nleft-pad 1.0     2.0    wheel\n";
+    assert_eq!(without_ignored(pip, "python", &rules), "");
+    // Headerless output keeps the other packages
+    let poetry = "left-pad 1.0.0 2.0.0 Pads strings\nrich 13.7.0 13.7.1 Rich text\n";
+    assert_eq!(
+        without_ignored(poetry, "python", &rules),
+        "rich 13.7.0 13.7.1 Rich text"
+    );
 }
@@ -0,0 +1,107 @@
+use crate::commands::diff_report::{build_diff_report, directory_of, render_markdown, FileStatus};
+use git2::{IndexAddOption, Repository, Signature};
+use std::fs;
+use tempfile::tempdir;
+
+/// Commits the whole work tree, deletions included.
+fn commit_all(repo: &Repository, message: &str, author: &str) {
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"].iter(), IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.update_all(["*"].iter(), None).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now(author, &format!("{}@example.com", author)).unwrap();
+    let parent = repo.head().ok().map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<_> = parent.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        message,
+        &tree,
+        &parents,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_directory_of() {
+    assert_eq!(directory_of("README.md", None), ".");
+    assert_eq!(directory_of("src/commands/diff.rs", None), "src/commands");
+    assert_eq!(directory_of("src/commands/diff.rs", Some(1)), "src");
+    assert_eq!(
+        directory_of("src/commands/diff.rs", Some(5)),
+        "src/commands"
+    );
+}
+
+#[test]
+fn test_diff_report_between_tags() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+    fs::create_dir_all(root.join("src/utils")).unwrap();
+    let module: String = (1..=20).map(|line| format!("line {}\n", line)).collect();
+    fs::write(root.join("src/utils/old_name.rs"), &module).unwrap();
+    fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+    fs::write(root.join("obsolete.txt"), "gone\n").unwrap();
+    commit_all(&repo, "initial", "jane");
+    let head = repo.head().unwrap().target().unwrap();
+    repo.tag_lightweight("v1.0.0", &repo.find_object(head, None).unwrap(), false)
+        .unwrap();
+
+    fs::rename(
+        root.join("src/utils/old_name.rs"),
+        root.join("src/utils/new_name.rs"),
+    )
+    .unwrap();
+    fs::remove_file(root.join("obsolete.txt")).unwrap();
+    commit_all(&repo, "refactor: rename module", "jane");
+    fs::write(
+        root.join("src/main.rs"),
+        "fn main() {\n    run();\n}\n\nfn run() {}\n",
+    )
+    .unwrap();
+    fs::write(root.join("CHANGELOG.md"), "# Changelog\n").unwrap();
+    commit_all(&repo, "feat: run", "omar");
+
+    let report = build_diff_report(&repo, "v1.0.0", None, None).unwrap();
+    assert_eq!(report.to, "HEAD");
+    assert_eq!(report.commits, 2);
+    assert_eq!(report.contributors.len(), 2);
+    assert_eq!(report.totals.files_changed, 4);
+    assert_eq!(report.count(FileStatus::Renamed), 1);
+    assert_eq!(report.count(FileStatus::Added), 1);
+    assert_eq!(report.count(FileStatus::Deleted), 1);
+    assert_eq!(report.count(FileStatus::Modified), 1);
+
+    let renamed = report
+        .files()
+        .find(|file| file.status == FileStatus::Renamed)
+        .unwrap();
+    assert_eq!(renamed.path, "src/utils/new_name.rs");
+    assert_eq!(renamed.old_path.as_deref(), Some("src/utils/old_name.rs"));
+    assert_eq!((renamed.insertions, renamed.deletions), (0, 0));
+
+    let directories: Vec<&str> = report
+        .directories
+        .iter()
+        .map(|directory| directory.directory.as_str())
+        .collect();
+    assert_eq!(directories, vec!["src", ".", "src/utils"]);
+
+    let markdown = render_markdown(&report);
+    assert!(markdown.starts_with("# Changes from v1.0.0 to HEAD"));
+    assert!(markdown.contains("| 1 | 1 | 1 | 1 |"));
+    assert!(markdown.contains("| `src` | 1 | +5 | -1 |"));
+    assert!(markdown.contains("| `obsolete.txt` | deleted | +0 | -1 |"));
+    assert!(markdown.contains("- `src/utils/old_name.rs` → `src/utils/new_name.rs`"));
+    assert!(markdown.contains("## Contributors"));
+
+    let shallow = build_diff_report(&repo, "v1.0.0", Some("HEAD~1"), Some(1)).unwrap();
+    assert_eq!(shallow.commits, 1);
+    assert_eq!(shallow.totals.files_changed, 2);
+    assert!(build_diff_report(&repo, "v9.9.9", None, None).is_err());
+}
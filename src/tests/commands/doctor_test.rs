@@ -0,0 +1,77 @@
+use crate::commands::config::AppConfig;
+use crate::commands::doctor::{
+    check_api_key, check_network, check_tools, missing_for, tool_version, Feature,
+};
+use crate::tests::support::{MockRunner, MockServer};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_doctor_checks_tools_per_feature() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git --version", "git version 2.43.0\n")
+        .fail("gh --version", "gh: command not found")
+        .fail("pip --version", "pip: command not found")
+        .respond(
+            "pip3 --version",
+            "pip 24.0 from /usr/lib/python3 (python 3.12)\n",
+        )
+        .fail("npm", "")
+        .fail("yarn", "")
+        .fail("composer", "");
+    assert_eq!(
+        tool_version(&runner, "pip").as_deref(),
+        Some("pip 24.0 from /usr/lib/python3 (python 3.12)")
+    );
+
+    let mut checks = check_tools(&runner);
+    let failed: Vec<&str> = checks
+        .iter()
+        .filter(|check| !check.passed)
+        .map(|check| check.name.as_str())
+        .collect();
+    assert_eq!(failed, vec!["gh", "npm", "yarn", "composer"]);
+    assert_eq!(checks[0].detail, "git version 2.43.0");
+    assert!(checks[1].hint.contains("gh auth login"));
+
+    let config = AppConfig {
+        translation_provider: "openai".to_string(),
+        ..Default::default()
+    };
+    let api_key = check_api_key(&config);
+    if std::env::var("OPENAI_API_KEY").is_err() {
+        assert!(!api_key.passed);
+        assert!(api_key.hint.contains("OPENAI_API_KEY"));
+    }
+    checks.push(api_key);
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    assert!(missing_for(Feature::Release, root, &checks).is_empty());
+    assert_eq!(
+        missing_for(Feature::Github, root, &checks),
+        vec!["gh", "network"]
+    );
+
+    // Only the package managers of the project are required, pnpm covers Node
+    fs::write(root.join("package.json"), "{}").unwrap();
+    fs::write(root.join("composer.json"), "{}").unwrap();
+    assert_eq!(
+        missing_for(Feature::Dependencies, root, &checks),
+        vec!["composer"]
+    );
+    assert_eq!("github".parse::<Feature>().unwrap(), Feature::Github);
+}
+
+#[tokio::test]
+async fn test_network_check() {
+    let server = MockServer::start();
+    server.mock("GET", "/", 403, "{}");
+    let check = check_network(&server.url()).await;
+    assert!(check.passed);
+    assert!(check.detail.ends_with("reachable (403)"));
+
+    let check = check_network("http://127.0.0.1:9").await;
+    assert!(!check.passed);
+}
@@ -0,0 +1,117 @@
+use crate::commands::export::{export_sqlite, ExportOptions, ExportSummary};
+use crate::tests::support::TestCommit;
+use git2::Repository;
+use sqlx::{Connection, Row, SqliteConnection};
+use std::fs;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_export_sqlite() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+
+    TestCommit::new("feat: add a.txt")
+        .file("a.txt", "a.txt")
+        .author("Jane", "jane@acme.dev")
+        .at(1_700_000_000)
+        .commit(&repo);
+    TestCommit::new("feat: add b.txt")
+        .file("b.txt", "b.txt")
+        .author("John", "john@acme.dev")
+        .at(1_700_000_100)
+        .commit(&repo);
+    TestCommit::new("feat: add c.txt")
+        .file("c.txt", "c.txt")
+        .author("Jane", "jane@acme.dev")
+        .at(1_700_000_200)
+        .commit(&repo);
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.tag_lightweight("v1.0.0", head.as_object(), false)
+        .unwrap();
+
+    fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("Cargo.lock"),
+        concat!(
+            "[[package]]\nname = \"app\"\nversion = \"0.1.0\"\n\n",
+            "[[package]]\nname = \"serde\"\nversion = \"1.0.200\"\n",
+            "source = \"registry+https://github.com/rust-lang/crates.io-index\"\n\n",
+            "[[package]]\nname = \"serde_derive\"\nversion = \"1.0.200\"\n",
+            "source = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+        ),
+    )
+    .unwrap();
+    fs::write(
+        root.join("code-quality-report.json"),
+        r#"{ "checks": [{ "check_name": "lint", "command": "cargo clippy", "success": false,
+             "output": "", "error": "1 warning", "duration_ms": 1200 }] }"#,
+    )
+    .unwrap();
+    fs::create_dir(root.join("messages")).unwrap();
+    fs::write(
+        root.join("messages/source.json"),
+        r#"{ "a": "A", "b": "B" }"#,
+    )
+    .unwrap();
+    fs::write(root.join("messages/tr.json"), r#"{ "a": "A" }"#).unwrap();
+
+    let output = root.join("nitro.db");
+    let options = ExportOptions {
+        messages_dir: Some("messages".to_string()),
+        source_file: Some("source.json".to_string()),
+        ..Default::default()
+    };
+    let summary = export_sqlite(root, &output, &options).await.unwrap();
+
+    assert_eq!(
+        summary,
+        ExportSummary {
+            commits: 3,
+            tags: 1,
+            contributors: 2,
+            dependencies: 2,
+            check_results: 1,
+            translation_languages: 1,
+        }
+    );
+
+    let url = format!("sqlite:{}", output.display());
+    let mut conn = SqliteConnection::connect(&url).await.unwrap();
+
+    let row = sqlx::query(
+        "SELECT commit_count, first_commit_at, last_commit_at FROM contributors WHERE email = ?",
+    )
+    .bind("jane@acme.dev")
+    .fetch_one(&mut conn)
+    .await
+    .unwrap();
+    assert_eq!(row.get::<i64, _>("commit_count"), 2);
+    assert_eq!(row.get::<i64, _>("first_commit_at"), 1_700_000_000);
+    assert_eq!(row.get::<i64, _>("last_commit_at"), 1_700_000_200);
+
+    let row = sqlx::query("SELECT c.summary FROM tags t JOIN commits c ON c.hash = t.commit_hash")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<String, _>("summary"), "feat: add c.txt");
+
+    let row = sqlx::query("SELECT COUNT(*) AS n FROM dependencies WHERE direct = 1")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<i64, _>("n"), 1);
+
+    let row = sqlx::query("SELECT percentage FROM translation_stats WHERE language = 'tr'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(row.get::<f64, _>("percentage"), 50.0);
+
+    // Exporting again replaces the database instead of failing on duplicates
+    assert!(export_sqlite(root, &output, &options).await.is_ok());
+}
@@ -0,0 +1,141 @@
+use crate::commands::fork::{
+    detect_fork_parent, parse_ahead_behind, parse_symref_head, resolve_upstream, sync_fork,
+    BranchDivergence, ForkSyncConfig, SyncOutcome,
+};
+use crate::tests::support::{MockRunner, MockServer};
+
+fn fork_runner(current: &str, counts: &str) -> MockRunner {
+    let runner = MockRunner::new();
+    runner
+        .respond("git rev-parse --abbrev-ref HEAD", &format!("{}\n", current))
+        .respond(
+            "git ls-remote --symref upstream HEAD",
+            "ref: refs/heads/main\tHEAD\n0123abcd\tHEAD\n",
+        )
+        .respond("git rev-list --left-right --count main...", counts)
+        .respond("git rev-list --left-right --count feature...", "2\t5\n")
+        .respond("git rev-list --left-right --count docs...", "0\t5\n")
+        .respond("git for-each-ref", "docs\nfeature\nmain\n");
+    runner
+}
+
+#[test]
+fn test_parse_git_output() {
+    assert_eq!(
+        parse_symref_head("ref: refs/heads/develop\tHEAD\nabc123\tHEAD\n"),
+        Some("develop".to_string())
+    );
+    assert_eq!(parse_symref_head("abc123\tHEAD\n"), None);
+    assert_eq!(parse_ahead_behind("3\t12\n"), Some((3, 12)));
+    assert_eq!(parse_ahead_behind("3\n"), None);
+    assert_eq!(parse_ahead_behind("fatal: bad revision"), None);
+}
+
+#[test]
+fn test_resolve_upstream_remote() {
+    let existing = MockRunner::new();
+    existing.respond("git remote", "origin\nupstream\n");
+    assert_eq!(
+        resolve_upstream(&existing, None).unwrap(),
+        Some("upstream".to_string())
+    );
+
+    let missing = MockRunner::new();
+    missing.respond("git remote", "origin\n");
+    assert_eq!(resolve_upstream(&missing, None).unwrap(), None);
+
+    let url = "https://github.com/acme/widget.git";
+    assert_eq!(
+        resolve_upstream(&missing, Some(url)).unwrap(),
+        Some("upstream".to_string())
+    );
+    assert!(missing
+        .calls()
+        .contains(&format!("git remote add upstream {}", url)));
+}
+
+#[test]
+fn test_sync_fast_forwards_and_reports_diverged_branches() {
+    let runner = fork_runner("feature", "0\t4\n");
+    let report = sync_fork(&runner, "upstream", &ForkSyncConfig::default()).unwrap();
+
+    assert_eq!(report.branch, "main");
+    assert_eq!(report.outcome, SyncOutcome::FastForwarded { behind: 4 });
+    assert!(report.pushed);
+    assert_eq!(
+        report.diverged,
+        vec![BranchDivergence {
+            branch: "feature".to_string(),
+            ahead: 2,
+            behind: 5
+        }]
+    );
+
+    let calls = runner.calls();
+    let position = |command: &str| calls.iter().position(|call| call == command).unwrap();
+    assert!(position("git fetch upstream --prune") < position("git checkout main"));
+    assert!(position("git merge --ff-only upstream/main") < position("git push origin main"));
+    assert_eq!(calls.last().unwrap(), "git checkout feature");
+}
+
+#[test]
+fn test_sync_diverged_branch_requires_rebase() {
+    let runner = fork_runner("main", "2\t3\n");
+    let error = sync_fork(&runner, "upstream", &ForkSyncConfig::default()).unwrap_err();
+    assert!(error.to_string().contains("--rebase"));
+    assert!(!runner
+        .calls()
+        .iter()
+        .any(|call| call.starts_with("git push")));
+
+    let runner = fork_runner("main", "2\t3\n");
+    let config = ForkSyncConfig {
+        rebase: true,
+        ..Default::default()
+    };
+    let report = sync_fork(&runner, "upstream", &config).unwrap();
+    assert_eq!(
+        report.outcome,
+        SyncOutcome::Rebased {
+            ahead: 2,
+            behind: 3
+        }
+    );
+    let calls = runner.calls();
+    assert!(calls.contains(&"git rebase upstream/main".to_string()));
+    assert!(calls.contains(&"git push --force-with-lease origin main".to_string()));
+
+    let dirty = fork_runner("main", "0\t1\n");
+    dirty.respond("git status --porcelain", " M src/main.rs\n");
+    assert!(sync_fork(&dirty, "upstream", &ForkSyncConfig::default()).is_err());
+    assert!(!dirty
+        .calls()
+        .iter()
+        .any(|call| call.starts_with("git fetch")));
+}
+
+#[tokio::test]
+async fn test_detect_fork_parent() {
+    let server = MockServer::start();
+    server.mock(
+        "GET",
+        "/repos/someone/widget",
+        200,
+        r#"{"full_name": "someone/widget", "fork": true,
+            "parent": {"full_name": "acme/widget", "clone_url": "https://github.com/acme/widget.git"}}"#,
+    );
+
+    let parent = detect_fork_parent(&server.url(), "git@github.com:someone/widget.git")
+        .await
+        .unwrap();
+    assert_eq!(
+        parent,
+        Some("https://github.com/acme/widget.git".to_string())
+    );
+
+    // Not hosted on GitHub, nothing to ask
+    let parent = detect_fork_parent(&server.url(), "https://gitlab.com/someone/widget.git")
+        .await
+        .unwrap();
+    assert_eq!(parent, None);
+}
@@ -0,0 +1,129 @@
+use crate::commands::gemini_models::{
+    check_model, format_tokens, list_models, parse_model_choice, suggest_models,
+    unknown_model_error, GeminiModel, ModelCheck,
+};
+use crate::tests::support::{fixture, MockServer};
+
+fn model(name: &str) -> GeminiModel {
+    GeminiModel {
+        name: format!("models/{}", name),
+        display_name: String::new(),
+        description: String::new(),
+        input_token_limit: 0,
+        output_token_limit: 0,
+        supported_generation_methods: vec!["generateContent".to_string()],
+    }
+}
+
+#[test]
+fn test_format_tokens() {
+    assert_eq!(format_tokens(1_048_576), "1M");
+    assert_eq!(format_tokens(2_097_152), "2M");
+    assert_eq!(format_tokens(1_000_000), "1M");
+    assert_eq!(format_tokens(8192), "8K");
+    assert_eq!(format_tokens(32_000), "32K");
+    assert_eq!(format_tokens(4097), "4097");
+}
+
+#[test]
+fn test_suggestions_and_choices() {
+    let models = vec![
+        model("gemini-1.5-flash"),
+        model("gemini-1.5-pro"),
+        model("gemini-2.0-flash"),
+        model("gemma-3-27b-it"),
+    ];
+    assert_eq!(
+        suggest_models(&models, "gemini-1.0-flash", 2),
+        vec!["gemini-2.0-flash", "gemini-1.5-flash"]
+    );
+    assert_eq!(
+        suggest_models(&models, "gemini-1.5-pro-latest", 1),
+        vec!["gemini-1.5-pro"]
+    );
+
+    assert_eq!(
+        parse_model_choice("2", &models).as_deref(),
+        Some("gemini-1.5-pro")
+    );
+    assert_eq!(
+        parse_model_choice(" models/gemini-2.0-flash ", &models).as_deref(),
+        Some("gemini-2.0-flash")
+    );
+    assert_eq!(parse_model_choice("0", &models), None);
+    assert_eq!(parse_model_choice("gpt-4o", &models), None);
+
+    let error = unknown_model_error("gemini-1.0-flash", &["gemini-2.0-flash".to_string()]);
+    assert!(error.to_string().contains("try gemini-2.0-flash"));
+}
+
+#[tokio::test]
+async fn test_list_models_follows_pages() {
+    let server = MockServer::start();
+    server
+        .mock_once("GET", "/v1beta/models", 200, &fixture("gemini/models.json"))
+        .mock(
+            "GET",
+            "/v1beta/models",
+            200,
+            &fixture("gemini/models_page_2.json"),
+        );
+
+    let models = list_models(&server.url(), "test-key").await.unwrap();
+    let names: Vec<&str> = models.iter().map(|model| model.id()).collect();
+    // The embedding model can't generate text and is left out
+    assert_eq!(
+        names,
+        vec!["gemini-1.5-flash", "gemini-1.5-pro", "gemini-2.0-flash"]
+    );
+    assert_eq!(models[1].input_token_limit, 2_097_152);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].path.contains("key=test-key"));
+    assert!(requests[1].path.contains("pageToken=page-2"));
+}
+
+#[tokio::test]
+async fn test_check_model_suggests_on_404() {
+    let server = MockServer::start();
+    server
+        .mock(
+            "GET",
+            "/v1beta/models/gemini-1.5-flash",
+            200,
+            r#"{"name": "models/gemini-1.5-flash"}"#,
+        )
+        .mock(
+            "GET",
+            "/v1beta/models",
+            200,
+            &fixture("gemini/models_page_2.json"),
+        );
+
+    assert_eq!(
+        check_model(&server.url(), "key", "gemini-1.5-flash")
+            .await
+            .unwrap(),
+        ModelCheck::Available
+    );
+    assert_eq!(
+        check_model(&server.url(), "key", "gemini-1.0-pro")
+            .await
+            .unwrap(),
+        ModelCheck::Unknown {
+            suggestions: vec!["gemini-1.5-pro".to_string(), "gemini-2.0-flash".to_string()]
+        }
+    );
+
+    server.mock(
+        "GET",
+        "/v1beta/models/gemini-1.5-pro",
+        403,
+        r#"{"error": {"message": "API key not valid"}}"#,
+    );
+    let error = check_model(&server.url(), "bad", "gemini-1.5-pro")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("403"));
+}
@@ -0,0 +1,127 @@
+use crate::commands::config::IdentityProfile;
+use crate::commands::git_identity::{
+    apply_identity, detect_signing_format, resolve_identity, signing_format_for, verify_identity,
+    IdentityOptions, SigningFormat, SIGNING_MARKER,
+};
+use git2::{ConfigLevel, Repository};
+use tempfile::tempdir;
+
+fn profile() -> IdentityProfile {
+    IdentityProfile {
+        name: "Jane Doe".to_string(),
+        email: "jane@acme.dev".to_string(),
+        signing_key: None,
+        signing_format: None,
+    }
+}
+
+#[test]
+fn test_detect_signing_format() {
+    assert_eq!(
+        detect_signing_format("~/.ssh/id_ed25519.pub"),
+        SigningFormat::Ssh
+    );
+    assert_eq!(
+        detect_signing_format("ssh-ed25519 AAAAC3Nza... jane@acme.dev"),
+        SigningFormat::Ssh
+    );
+    assert_eq!(
+        detect_signing_format("3AA5C34371567BD2"),
+        SigningFormat::Gpg
+    );
+    assert_eq!("ssh".parse::<SigningFormat>().unwrap(), SigningFormat::Ssh);
+    assert!("x509".parse::<SigningFormat>().is_err());
+}
+
+#[test]
+fn test_resolve_identity_overrides_profile() {
+    let options = IdentityOptions {
+        email: Some("jane@personal.dev".to_string()),
+        signing_key: Some("3AA5C34371567BD2".to_string()),
+        ..Default::default()
+    };
+
+    let identity = resolve_identity(Some(&profile()), &options);
+
+    assert_eq!(identity.name, "Jane Doe");
+    assert_eq!(identity.email, "jane@personal.dev");
+    assert_eq!(
+        signing_format_for(&identity).unwrap(),
+        Some(SigningFormat::Gpg)
+    );
+}
+
+#[test]
+fn test_apply_identity_writes_repo_config() {
+    let temp_dir = tempdir().unwrap();
+    let repo = Repository::init(temp_dir.path()).unwrap();
+    let mut config = repo
+        .config()
+        .unwrap()
+        .open_level(ConfigLevel::Local)
+        .unwrap();
+
+    let mut identity = profile();
+    identity.signing_key = Some("~/.ssh/id_ed25519.pub".to_string());
+    apply_identity(&mut config, &identity).unwrap();
+
+    let config = repo.config().unwrap().snapshot().unwrap();
+    assert_eq!(config.get_str("user.name").unwrap(), "Jane Doe");
+    assert_eq!(config.get_str("user.email").unwrap(), "jane@acme.dev");
+    assert_eq!(config.get_str("gpg.format").unwrap(), "ssh");
+    assert!(config.get_bool("commit.gpgsign").unwrap());
+}
+
+#[test]
+fn test_unsigned_profile_only_removes_signing_nitroterm_set_up() {
+    let temp_dir = tempdir().unwrap();
+    let repo = Repository::init(temp_dir.path()).unwrap();
+    let mut config = repo
+        .config()
+        .unwrap()
+        .open_level(ConfigLevel::Local)
+        .unwrap();
+
+    // Signing the user configured stays as it is
+    config.set_bool("commit.gpgsign", true).unwrap();
+    apply_identity(&mut config, &profile()).unwrap();
+    assert!(config.get_bool("commit.gpgsign").unwrap());
+
+    let mut signed = profile();
+    signed.signing_key = Some("~/.ssh/id_ed25519.pub".to_string());
+    apply_identity(&mut config, &signed).unwrap();
+    apply_identity(&mut config, &profile()).unwrap();
+    let local = config.snapshot().unwrap();
+    assert!(local.get_bool("commit.gpgsign").is_err());
+    assert!(local.get_str("user.signingkey").is_err());
+    assert!(local.get_bool(SIGNING_MARKER).is_err());
+}
+
+#[test]
+fn test_apply_identity_requires_name_and_email() {
+    let temp_dir = tempdir().unwrap();
+    let repo = Repository::init(temp_dir.path()).unwrap();
+    let mut config = repo
+        .config()
+        .unwrap()
+        .open_level(ConfigLevel::Local)
+        .unwrap();
+
+    let identity = IdentityProfile {
+        email: "jane@acme.dev".to_string(),
+        ..Default::default()
+    };
+    assert!(apply_identity(&mut config, &identity).is_err());
+
+    // A bad signing format is caught before name and email are written
+    let mut identity = profile();
+    identity.signing_key = Some("ABCDEF12".to_string());
+    identity.signing_format = Some("x509".to_string());
+    assert!(apply_identity(&mut config, &identity).is_err());
+    assert!(config.snapshot().unwrap().get_str("user.name").is_err());
+}
+
+#[test]
+fn test_verify_unsigned_identity() {
+    assert!(verify_identity(&profile()).is_ok());
+}
@@ -0,0 +1,17 @@
+use crate::commands::github_api::GitHubApiClient;
+
+#[test]
+fn test_labels_url_encodes_label_names() {
+    let mut client = GitHubApiClient::new("acme", "app", "token").unwrap();
+    client.api_url = "https://github.example.com/api/v3/".to_string();
+
+    assert_eq!(
+        client.labels_url(None).unwrap().as_str(),
+        "https://github.example.com/api/v3/repos/acme/app/labels"
+    );
+    assert_eq!(
+        client.labels_url(Some("🐛 bug fix")).unwrap().as_str(),
+        "https://github.example.com/api/v3/repos/acme/app/labels/%F0%9F%90%9B%20bug%20fix"
+    );
+    assert_eq!(client.repo_slug(), "acme/app");
+}
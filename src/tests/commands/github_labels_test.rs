@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
     use crate::commands::github_labels::{
-        run_github_labels, run_github_labels_interactive, GitHubLabel, GitHubLabelsConfig,
-        GitHubLabelsManager, LabelUpdate,
+        compute_label_diff, load_labels_file, normalize_color, parse_label_list_json, parse_labels,
+        parse_repo_list, run_github_labels, run_github_labels_interactive, validate_labels,
+        GitHubLabel, GitHubLabelsConfig, GitHubLabelsManager, LabelChange, LabelDefinition,
+        LabelUpdate,
     };
     use tokio;
 
@@ -175,7 +177,7 @@ mod tests {
         let os = manager.detect_os();
 
         // Should return one of the expected OS types
-        let valid_os = vec![
+        let valid_os = [
             "macos", "ubuntu", "fedora", "centos", "linux", "windows", "unknown",
         ];
         assert!(
@@ -187,10 +189,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_dry_run_mode() {
-        let mut config = GitHubLabelsConfig::default();
-        config.dry_run = true;
-        config.skip_auth = true;
-        config.skip_install = true;
+        let config = GitHubLabelsConfig {
+            dry_run: true,
+            skip_auth: true,
+            skip_install: true,
+            ..GitHubLabelsConfig::default()
+        };
 
         let manager = GitHubLabelsManager::new(config);
 
@@ -201,10 +205,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_only_mode() {
-        let mut config = GitHubLabelsConfig::default();
-        config.list_only = true;
-        config.skip_auth = true;
-        config.skip_install = true;
+        let config = GitHubLabelsConfig {
+            list_only: true,
+            skip_auth: true,
+            skip_install: true,
+            ..GitHubLabelsConfig::default()
+        };
 
         let manager = GitHubLabelsManager::new(config);
 
@@ -213,11 +219,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_delete_all_mode() {
-        let mut config = GitHubLabelsConfig::default();
-        config.delete_all = true;
-        config.dry_run = true; // Use dry run to avoid actual deletion
-        config.skip_auth = true;
-        config.skip_install = true;
+        let config = GitHubLabelsConfig {
+            delete_all: true,
+            dry_run: true, // Use dry run to avoid actual deletion
+            skip_auth: true,
+            skip_install: true,
+            ..GitHubLabelsConfig::default()
+        };
 
         let manager = GitHubLabelsManager::new(config);
 
@@ -227,10 +235,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_only_mode() {
-        let mut config = GitHubLabelsConfig::default();
-        config.update_only = true;
-        config.skip_auth = true;
-        config.skip_install = true;
+        let config = GitHubLabelsConfig {
+            update_only: true,
+            skip_auth: true,
+            skip_install: true,
+            ..GitHubLabelsConfig::default()
+        };
 
         let manager = GitHubLabelsManager::new(config);
 
@@ -240,14 +250,13 @@ mod tests {
     #[tokio::test]
     async fn test_run_github_labels_cli_function() {
         // Test the CLI function with various configurations
-        let _result = run_github_labels(
-            true,  // skip_auth
-            true,  // skip_install
-            true,  // dry_run
-            true,  // list_only
-            false, // delete_all
-            false, // update_only
-        )
+        let _result = run_github_labels(GitHubLabelsConfig {
+            skip_auth: true,
+            skip_install: true,
+            dry_run: true,
+            list_only: true,
+            ..Default::default()
+        })
         .await;
 
         // In a real implementation, we would mock the GitHub CLI
@@ -416,6 +425,180 @@ mod tests {
         assert!(has_security, "Missing security labels");
         assert!(has_testing, "Missing testing labels");
     }
+
+    fn definition(name: &str, color: &str, aliases: &[&str]) -> LabelDefinition {
+        LabelDefinition {
+            name: name.to_string(),
+            color: color.to_string(),
+            description: String::new(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_labels_yaml_and_json() {
+        let yaml = r##"
+labels:
+  - name: "type: bug"
+    color: "#d73a49"
+    description: Something is broken
+    aliases: [bug, defect]
+  - name: "type: feature"
+    color: A2EEEF
+"##;
+        let labels = parse_labels(yaml).unwrap();
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].aliases, vec!["bug", "defect"]);
+        assert_eq!(labels[1].description, "");
+
+        let json = r#"[{"name": "docs", "color": "0075ca"}]"#;
+        let labels = parse_labels(json).unwrap();
+        assert_eq!(labels[0].name, "docs");
+
+        assert!(parse_labels("labels: 42").is_err());
+    }
+
+    #[test]
+    fn test_validate_labels() {
+        assert_eq!(normalize_color("#d73a49"), Some("D73A49".to_string()));
+        assert_eq!(normalize_color("fff"), None);
+        assert_eq!(normalize_color("GGGGGG"), None);
+
+        let valid = vec![
+            definition("bug", "D73A49", &["Bug", "defect"]),
+            definition("feature", "#a2eeef", &[]),
+        ];
+        assert!(validate_labels(&valid).is_empty());
+
+        let invalid = vec![
+            definition("bug", "red", &[]),
+            definition("Bug", "D73A49", &[]),
+            definition("feature", "A2EEEF", &["defect"]),
+            definition("enhancement", "A2EEEF", &["defect"]),
+            definition(" ", "A2EEEF", &[]),
+        ];
+        let errors = validate_labels(&invalid);
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors[0].contains("invalid color 'red'"));
+        assert!(errors[1].contains("Duplicate name 'Bug'"));
+        assert!(errors[2].contains("alias of 'enhancement'"));
+        assert!(errors[3].contains("empty name"));
+    }
+
+    #[test]
+    fn test_custom_labels_drive_the_plan() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("labels.yml");
+        std::fs::write(
+            &path,
+            "- name: \"type: bug\"\n  color: \"#d73a49\"\n  aliases: [bug]\n- name: docs\n  color: 0075ca\n",
+        )
+        .unwrap();
+
+        let labels = load_labels_file(&path).unwrap();
+        assert_eq!(labels[0].color, "D73A49");
+
+        let manager = GitHubLabelsManager::with_labels(GitHubLabelsConfig::default(), labels);
+        let updates = manager.get_existing_labels_to_update();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].old_name, "bug");
+        assert_eq!(updates[0].new_name, "type: bug");
+
+        let names: Vec<String> = manager
+            .get_new_labels_to_create()
+            .into_iter()
+            .map(|label| label.name)
+            .collect();
+        assert_eq!(names, vec!["type: bug", "docs"]);
+
+        std::fs::write(&path, "- name: docs\n  color: blue\n").unwrap();
+        let error = load_labels_file(&path).unwrap_err().to_string();
+        assert!(error.contains("invalid color 'blue'"));
+    }
+
+    #[test]
+    fn test_parse_label_list_json() {
+        let labels = parse_label_list_json(
+            r#"[{"name":"good first issue","color":"7057ff","description":"Easy"},{"name":"bug","color":"d73a49","description":null}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].name, "good first issue");
+        assert_eq!(labels[0].color, "7057FF");
+        assert_eq!(labels[1].description, "");
+        assert!(parse_label_list_json("bug\tSomething\t#d73a49").is_err());
+    }
+
+    #[test]
+    fn test_compute_label_diff() {
+        let current = vec![
+            GitHubLabel {
+                name: "bug".to_string(),
+                description: "".to_string(),
+                color: "D73A49".to_string(),
+            },
+            GitHubLabel {
+                name: "docs".to_string(),
+                description: "Docs".to_string(),
+                color: "0075ca".to_string(),
+            },
+            GitHubLabel {
+                name: "wontfix".to_string(),
+                description: "".to_string(),
+                color: "FFFFFF".to_string(),
+            },
+        ];
+        let mut docs = definition("docs", "0075CA", &[]);
+        docs.description = "Docs".to_string();
+        let desired = vec![
+            definition("type: bug", "D73A49", &["bug"]),
+            docs,
+            definition("feature", "A2EEEF", &[]),
+        ];
+
+        let changes = compute_label_diff(&desired, &current);
+        assert_eq!(changes.len(), 4);
+        match &changes[0] {
+            LabelChange::Update {
+                current_name,
+                label,
+                changes,
+            } => {
+                assert_eq!(current_name, "bug");
+                assert_eq!(label.name, "type: bug");
+                assert_eq!(changes, &vec!["name: bug → type: bug".to_string()]);
+            }
+            other => panic!("expected an update, got {:?}", other),
+        }
+        assert_eq!(changes[1], LabelChange::Unchanged("docs".to_string()));
+        assert!(matches!(&changes[2], LabelChange::Create(label) if label.name == "feature"));
+        assert!(matches!(&changes[3], LabelChange::Delete(label) if label.name == "wontfix"));
+    }
+
+    #[test]
+    fn test_builtin_desired_labels_include_renames() {
+        let manager = GitHubLabelsManager::new(GitHubLabelsConfig::default());
+        let desired = manager.desired_labels();
+
+        let bug = desired.iter().find(|l| l.name == "🐛 bug").unwrap();
+        assert_eq!(bug.aliases, vec!["bug"]);
+        assert_eq!(
+            desired.len(),
+            manager.get_existing_labels_to_update().len()
+                + manager.get_new_labels_to_create().len()
+        );
+    }
+
+    #[test]
+    fn test_parse_repo_list() {
+        let content = "# org repos\nacme/web\n\nacme/api.git  # backend\n";
+        assert_eq!(
+            parse_repo_list(content).unwrap(),
+            vec!["acme/web".to_string(), "acme/api".to_string()]
+        );
+        assert!(parse_repo_list("acme\n").is_err());
+    }
 }
 
 #[cfg(test)]
@@ -445,8 +628,10 @@ mod integration_tests {
     #[ignore] // Ignore by default since it requires authentication
     async fn test_github_authentication() {
         if env::var("GITHUB_TOKEN").is_ok() || env::var("GH_TOKEN").is_ok() {
-            let mut config = GitHubLabelsConfig::default();
-            config.skip_install = true;
+            let config = GitHubLabelsConfig {
+                skip_install: true,
+                ..GitHubLabelsConfig::default()
+            };
 
             let manager = GitHubLabelsManager::new(config);
             let result = manager.check_authentication().await;
@@ -464,10 +649,12 @@ mod integration_tests {
     #[tokio::test]
     #[ignore] // Ignore by default since it requires GitHub CLI and repo
     async fn test_list_labels_real() {
-        let mut config = GitHubLabelsConfig::default();
-        config.list_only = true;
-        config.skip_install = true;
-        config.skip_auth = true;
+        let config = GitHubLabelsConfig {
+            list_only: true,
+            skip_install: true,
+            skip_auth: true,
+            ..GitHubLabelsConfig::default()
+        };
 
         let manager = GitHubLabelsManager::new(config);
         let result = manager.list_labels().await;
@@ -479,3 +666,131 @@ mod integration_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod mocked_tests {
+    use crate::commands::github_api::GitHubApiClient;
+    use crate::commands::github_labels::{
+        compute_label_diff, ActionOutcome, GitHubLabelsConfig, GitHubLabelsManager, LabelChange,
+        LabelDefinition,
+    };
+    use crate::tests::support::{fixture, MockRunner, MockServer};
+    use std::sync::Arc;
+
+    fn desired_labels() -> Vec<LabelDefinition> {
+        vec![
+            LabelDefinition {
+                name: "🐛 bug".to_string(),
+                color: "d73a4a".to_string(),
+                description: "Something isn't working".to_string(),
+                aliases: vec!["bug".to_string()],
+            },
+            LabelDefinition {
+                name: "documentation".to_string(),
+                color: "0075ca".to_string(),
+                description: "Improvements or additions to documentation".to_string(),
+                aliases: Vec::new(),
+            },
+            LabelDefinition {
+                name: "feature".to_string(),
+                color: "a2eeef".to_string(),
+                description: "New feature or request".to_string(),
+                aliases: Vec::new(),
+            },
+        ]
+    }
+
+    fn sync_config() -> GitHubLabelsConfig {
+        GitHubLabelsConfig {
+            sync: true,
+            skip_install: true,
+            skip_auth: true,
+            repo: Some("acme/app".to_string()),
+            ..GitHubLabelsConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_label_sync_through_api() {
+        let server = MockServer::start();
+        server
+            .mock(
+                "GET",
+                "/repos/acme/app/labels",
+                200,
+                &fixture("github/labels.json"),
+            )
+            .mock("POST", "/repos/acme/app/labels", 201, "{}")
+            .mock("PATCH", "/repos/acme/app/labels/bug", 200, "{}")
+            .mock("DELETE", "/repos/acme/app/labels/wontfix", 204, "");
+
+        let mut client = GitHubApiClient::new("acme", "app", "test-token").unwrap();
+        client.api_url = server.url();
+        let manager =
+            GitHubLabelsManager::with_labels(sync_config(), desired_labels()).with_api(client);
+
+        let current = manager.fetch_current_labels().await.unwrap();
+        assert_eq!(current.len(), 3);
+        let changes = compute_label_diff(&manager.desired_labels(), &current);
+        manager.apply_label_changes(&changes).await.unwrap();
+
+        let writes = server.writes();
+        let summary: Vec<(&str, &str)> = writes
+            .iter()
+            .map(|request| (request.method.as_str(), request.path.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("PATCH", "/repos/acme/app/labels/bug"),
+                ("POST", "/repos/acme/app/labels"),
+                ("DELETE", "/repos/acme/app/labels/wontfix"),
+            ]
+        );
+        assert!(writes[0].body.contains("\"new_name\":\"🐛 bug\""));
+        assert!(writes[1].body.contains("\"name\":\"feature\""));
+    }
+
+    #[tokio::test]
+    async fn test_label_sync_through_gh() {
+        let runner = Arc::new(MockRunner::new());
+        runner
+            .respond("gh label list", &fixture("github/gh_label_list.json"))
+            .fail(
+                "gh label delete wontfix",
+                "HTTP 403: Must have admin rights",
+            );
+        let mut manager = GitHubLabelsManager::with_labels(sync_config(), desired_labels());
+        manager.runner = runner.clone();
+
+        let current = manager.fetch_current_labels().await.unwrap();
+        let changes = compute_label_diff(&manager.desired_labels(), &current);
+        assert!(changes.iter().any(
+            |change| matches!(change, LabelChange::Unchanged(name) if name == "documentation")
+        ));
+
+        let error = manager.apply_label_changes(&changes).await.unwrap_err();
+        assert_eq!(error.to_string(), "1 label changes failed");
+
+        let calls = runner.calls();
+        assert_eq!(
+            calls[0],
+            "gh label list --limit 1000 --json name,color,description --repo acme/app"
+        );
+        assert!(calls[1].starts_with("gh label edit bug --name 🐛 bug"));
+        assert!(calls[2].starts_with("gh label create feature"));
+        assert_eq!(calls[3], "gh label delete wontfix --yes --repo acme/app");
+
+        // What --json reports
+        let actions = manager.actions();
+        assert_eq!(actions.len(), 3);
+        assert_eq!(actions[0].action, "update");
+        assert_eq!(actions[0].from.as_deref(), Some("bug"));
+        assert_eq!(actions[1].from, None);
+        assert_eq!(actions[2].outcome, ActionOutcome::Failed);
+        assert!(actions[2].error.as_deref().unwrap().contains("403"));
+        let json = serde_json::to_value(&actions).unwrap();
+        assert_eq!(json[0]["outcome"], "applied");
+        assert_eq!(json[2]["label"], "wontfix");
+    }
+}
@@ -0,0 +1,94 @@
+use crate::commands::github_milestones::{
+    compute_milestone_diff, parse_milestone_list_json, parse_milestones, validate_milestones,
+    GitHubMilestonesConfig, GitHubMilestonesManager, MilestoneChange,
+};
+use crate::tests::support::{fixture, MockRunner};
+use std::sync::Arc;
+
+const MILESTONES: &str = r#"
+milestones:
+  - title: v1.2.0
+    description: Label sync and templates
+    due_on: 2025-04-15
+  - title: v1.3.0
+    due_on: 2025-06-01
+  - title: v1.1.0
+    state: closed
+"#;
+
+#[test]
+fn test_parse_and_validate_milestones() {
+    let milestones = parse_milestones(MILESTONES).unwrap();
+    assert_eq!(milestones.len(), 3);
+    assert_eq!(milestones[1].state, "open");
+    assert_eq!(milestones[0].due_on.as_deref(), Some("2025-04-15"));
+    assert!(validate_milestones(&milestones).is_empty());
+
+    let broken = parse_milestones(
+        "- title: v2\n  due_on: next week\n- title: V2\n  state: done\n- title: ''\n",
+    )
+    .unwrap();
+    let errors = validate_milestones(&broken);
+    assert_eq!(errors.len(), 4);
+    assert!(errors[0].contains("invalid due date"));
+    assert!(errors[1].contains("Duplicate"));
+}
+
+#[test]
+fn test_compute_milestone_diff() {
+    let current = parse_milestone_list_json(&fixture("github/milestones.json")).unwrap();
+    assert_eq!(current[0].due_on.as_deref(), Some("2025-03-31"));
+    assert_eq!(current[1].description, "");
+
+    let desired = parse_milestones(MILESTONES).unwrap();
+    let changes = compute_milestone_diff(&desired, &current, true);
+
+    assert!(matches!(
+        &changes[0],
+        MilestoneChange::Update { number: 3, changes, .. }
+            if changes == &vec!["description".to_string(), "due 2025-03-31 → 2025-04-15".to_string()]
+    ));
+    assert!(matches!(&changes[1], MilestoneChange::Create(d) if d.title == "v1.3.0"));
+    assert_eq!(changes[2], MilestoneChange::Unchanged("v1.1.0".to_string()));
+    assert_eq!(
+        changes[3],
+        MilestoneChange::Close {
+            number: 2,
+            title: "Backlog".to_string()
+        }
+    );
+    assert_eq!(compute_milestone_diff(&desired, &current, false).len(), 3);
+}
+
+#[tokio::test]
+async fn test_apply_milestones_through_gh() {
+    let runner = Arc::new(MockRunner::new());
+    runner.respond(
+        "gh api repos/acme/app/milestones?",
+        &fixture("github/milestones.json"),
+    );
+    let config = GitHubMilestonesConfig {
+        sync: true,
+        repo: Some("acme/app".to_string()),
+        ..GitHubMilestonesConfig::default()
+    };
+    let mut manager = GitHubMilestonesManager::new(config, parse_milestones(MILESTONES).unwrap());
+    manager.runner = runner.clone();
+
+    manager.sync_milestones().await.unwrap();
+
+    let calls = runner.calls();
+    assert_eq!(
+        calls[0],
+        "gh api repos/acme/app/milestones?state=all&per_page=100 --method GET"
+    );
+    assert_eq!(
+        calls[1],
+        "gh api repos/acme/app/milestones/3 --method PATCH -f description=Label sync and templates -f due_on=2025-04-15T00:00:00Z -f state=open -f title=v1.2.0"
+    );
+    assert!(calls[2].starts_with("gh api repos/acme/app/milestones --method POST"));
+    assert_eq!(
+        calls[3],
+        "gh api repos/acme/app/milestones/2 --method PATCH -f state=closed"
+    );
+}
@@ -0,0 +1,50 @@
+use crate::commands::github_templates::{
+    templates, write_templates, TemplateKind, TemplateOutcome,
+};
+use tempfile::tempdir;
+
+#[test]
+fn test_templates_by_kind() {
+    assert_eq!(templates(None).len(), 4);
+    assert_eq!(
+        templates(Some(TemplateKind::PullRequest))
+            .iter()
+            .map(|(path, _)| *path)
+            .collect::<Vec<_>>(),
+        vec![".github/pull_request_template.md"]
+    );
+    for (path, content) in templates(Some(TemplateKind::Issues)) {
+        if path.ends_with(".yml") {
+            assert!(
+                serde_yaml::from_str::<serde_yaml::Value>(content).is_ok(),
+                "{}",
+                path
+            );
+        }
+    }
+    assert!("docs".parse::<TemplateKind>().is_err());
+}
+
+#[test]
+fn test_write_templates_keeps_existing_files() {
+    let dir = tempdir().unwrap();
+    let pr_template = dir.path().join(".github/pull_request_template.md");
+    std::fs::create_dir_all(pr_template.parent().unwrap()).unwrap();
+    std::fs::write(&pr_template, "custom").unwrap();
+
+    let dry_run = write_templates(dir.path(), None, false, true).unwrap();
+    assert_eq!(dry_run.len(), 4);
+    assert!(!dir.path().join(".github/ISSUE_TEMPLATE").exists());
+
+    let outcomes = write_templates(dir.path(), None, false, false).unwrap();
+    assert_eq!(outcomes[3], TemplateOutcome::Skipped(pr_template.clone()));
+    assert_eq!(std::fs::read_to_string(&pr_template).unwrap(), "custom");
+    let bug_report =
+        std::fs::read_to_string(dir.path().join(".github/ISSUE_TEMPLATE/bug_report.yml")).unwrap();
+    assert!(bug_report.contains("labels: [\"🐛 bug\"]"));
+
+    write_templates(dir.path(), Some(TemplateKind::PullRequest), true, false).unwrap();
+    assert!(std::fs::read_to_string(&pr_template)
+        .unwrap()
+        .starts_with("## Summary"));
+}
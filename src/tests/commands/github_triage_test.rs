@@ -0,0 +1,116 @@
+use crate::commands::github_labels::{GitHubLabelsConfig, GitHubLabelsManager, LabelDefinition};
+use crate::commands::github_triage::{
+    parse_issue_list_json, priority_labels, GitHubTriageConfig, GitHubTriageManager, TriageItem,
+    TriageKind, TriageReport,
+};
+use crate::tests::support::{fixture, MockRunner};
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+
+fn report(stale_days: i64) -> TriageReport {
+    let items = parse_issue_list_json(&fixture("github/open_issues.json")).unwrap();
+    let taxonomy = GitHubLabelsManager::new(GitHubLabelsConfig::default()).desired_labels();
+    let now = Utc.with_ymd_and_hms(2025, 3, 30, 0, 0, 0).unwrap();
+    TriageReport::build(&items, &priority_labels(&taxonomy), stale_days, now)
+}
+
+fn numbers(items: &[TriageItem]) -> Vec<u64> {
+    items.iter().map(|item| item.number).collect()
+}
+
+#[test]
+fn test_parse_issue_list_json() {
+    let items = parse_issue_list_json(&fixture("github/open_issues.json")).unwrap();
+    assert_eq!(items.len(), 6);
+    assert_eq!(items[0].kind, TriageKind::Issue);
+    assert_eq!(items[0].author, "octocat");
+    assert_eq!(items[3].kind, TriageKind::PullRequest);
+    assert!(items[3].draft);
+    assert_eq!(items[1].labels, vec!["✨ type: feature"]);
+    assert!(parse_issue_list_json("{}").is_err());
+}
+
+#[test]
+fn test_priority_labels_from_taxonomy() {
+    let taxonomy = GitHubLabelsManager::new(GitHubLabelsConfig::default()).desired_labels();
+    let priorities = priority_labels(&taxonomy);
+    assert_eq!(priorities.len(), 4);
+    assert!(priorities.contains(&"🔴 priority: critical".to_string()));
+
+    let custom = vec![LabelDefinition {
+        name: "bug".to_string(),
+        color: "D73A4A".to_string(),
+        description: String::new(),
+        aliases: Vec::new(),
+    }];
+    assert!(priority_labels(&custom).is_empty());
+}
+
+#[test]
+fn test_build_triage_report() {
+    assert_eq!(numbers(&report(60).stale_prs), vec![31]);
+
+    let report = report(14);
+    assert_eq!(report.open_issues, 3);
+    assert_eq!(report.open_prs, 3);
+    assert_eq!(numbers(&report.unlabeled), vec![41]);
+    // Priority labels match case-insensitively
+    assert_eq!(numbers(&report.missing_priority), vec![40]);
+    // Longest idle first, the draft from yesterday isn't stale
+    assert_eq!(numbers(&report.stale_prs), vec![31, 35]);
+}
+
+#[test]
+fn test_triage_targets() {
+    let report = report(14);
+    assert_eq!(report.target("unlabeled").unwrap(), vec![41]);
+    assert_eq!(report.target("stale").unwrap(), vec![31, 35]);
+    assert_eq!(report.target("12, #15").unwrap(), vec![12, 15]);
+    assert!(report
+        .target("everything")
+        .unwrap_err()
+        .to_string()
+        .contains("missing-priority"));
+}
+
+#[tokio::test]
+async fn test_apply_label_through_gh() {
+    let runner = Arc::new(MockRunner::new());
+    let config = GitHubTriageConfig {
+        repo: Some("acme/app".to_string()),
+        ..GitHubTriageConfig::default()
+    };
+    let taxonomy = GitHubLabelsManager::new(GitHubLabelsConfig::default()).desired_labels();
+    let mut manager = GitHubTriageManager::new(config, taxonomy);
+    manager.runner = runner.clone();
+
+    manager
+        .apply_label("🟡 PRIORITY: medium", &[40, 41])
+        .await
+        .unwrap();
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "gh api repos/acme/app/issues/40/labels --method POST -f labels[]=🟡 priority: medium",
+            "gh api repos/acme/app/issues/41/labels --method POST -f labels[]=🟡 priority: medium",
+        ]
+    );
+
+    // Labels outside the taxonomy are refused before anything is sent
+    let runner = Arc::new(MockRunner::new());
+    manager.runner = runner.clone();
+    assert!(manager
+        .apply_label("priority: urgent", &[40])
+        .await
+        .unwrap_err()
+        .to_string()
+        .contains("not in the label set"));
+    assert!(runner.calls().is_empty());
+
+    manager.config.dry_run = true;
+    manager
+        .apply_label("🟢 priority: low", &[40])
+        .await
+        .unwrap();
+    assert!(runner.calls().is_empty());
+}
@@ -0,0 +1,229 @@
+use crate::commands::lockfiles::{
+    check_outdated, direct_dependency_names, is_outdated, parse_cargo_lock, parse_package_lock,
+    parse_pnpm_lock, parse_poetry_lock, parse_yarn_lock, read_locked_packages, Ecosystem,
+    LockedPackage, RegistryClient,
+};
+use crate::tests::support::MockServer;
+use std::fs;
+use tempfile::tempdir;
+
+fn versions(packages: &[crate::commands::lockfiles::LockedPackage]) -> Vec<(String, String)> {
+    packages
+        .iter()
+        .map(|p| (p.name.clone(), p.version.clone()))
+        .collect()
+}
+
+#[test]
+fn test_parse_cargo_lock_skips_local_packages() {
+    let content = r#"
+version = 3
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.190"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "abc"
+
+[[package]]
+name = "forked"
+version = "0.2.0"
+source = "git+https://github.com/acme/forked#abc"
+"#;
+
+    let packages = parse_cargo_lock(content);
+    assert_eq!(
+        versions(&packages),
+        vec![("serde".to_string(), "1.0.190".to_string())]
+    );
+    assert_eq!(packages[0].ecosystem, Ecosystem::Cargo);
+}
+
+#[test]
+fn test_parse_package_lock_v3_and_v1() {
+    let v3 = r#"{
+        "lockfileVersion": 3,
+        "packages": {
+            "": { "name": "app", "version": "1.0.0" },
+            "node_modules/react": { "version": "18.2.0" },
+            "node_modules/@types/node": { "version": "20.1.0" }
+        }
+    }"#;
+    let packages = parse_package_lock(v3).unwrap();
+    assert_eq!(packages.len(), 2);
+    assert!(packages
+        .iter()
+        .any(|p| p.name == "@types/node" && p.version == "20.1.0"));
+
+    let v1 = r#"{ "lockfileVersion": 1, "dependencies": { "lodash": { "version": "4.17.21" } } }"#;
+    assert_eq!(
+        versions(&parse_package_lock(v1).unwrap()),
+        vec![("lodash".to_string(), "4.17.21".to_string())]
+    );
+}
+
+#[test]
+fn test_parse_pnpm_lock_versions() {
+    let content = r#"lockfileVersion: '6.0'
+
+dependencies:
+  react:
+    specifier: ^18.2.0
+    version: 18.2.0
+
+packages:
+
+  /react@18.2.0:
+    resolution: {integrity: sha512-abc}
+    dependencies:
+      loose-envify: 1.4.0
+    dev: false
+
+  /@babel/core@7.22.0(supports-color@8.0.0):
+    resolution: {integrity: sha512-def}
+
+  /lodash/4.17.21:
+    resolution: {integrity: sha512-ghi}
+"#;
+
+    let packages = versions(&parse_pnpm_lock(content));
+    assert_eq!(
+        packages,
+        vec![
+            ("react".to_string(), "18.2.0".to_string()),
+            ("@babel/core".to_string(), "7.22.0".to_string()),
+            ("lodash".to_string(), "4.17.21".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_yarn_lock_classic_and_berry() {
+    let classic = r#"# yarn lockfile v1
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.22.5":
+  version "7.22.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.22.13.tgz"
+
+lodash@^4.17.21:
+  version "4.17.21"
+"#;
+    assert_eq!(
+        versions(&parse_yarn_lock(classic)),
+        vec![
+            ("@babel/code-frame".to_string(), "7.22.13".to_string()),
+            ("lodash".to_string(), "4.17.21".to_string()),
+        ]
+    );
+
+    let berry = r#"__metadata:
+  version: 6
+
+"react@npm:^18.2.0":
+  version: 18.2.0
+  resolution: "react@npm:18.2.0"
+"#;
+    assert_eq!(
+        versions(&parse_yarn_lock(berry)),
+        vec![("react".to_string(), "18.2.0".to_string())]
+    );
+}
+
+#[test]
+fn test_parse_poetry_lock() {
+    let content = r#"
+[[package]]
+name = "requests"
+version = "2.31.0"
+description = "Python HTTP for Humans."
+
+[package.dependencies]
+urllib3 = ">=1.21.1,<3"
+
+[[package]]
+name = "urllib3"
+version = "2.0.7"
+
+[metadata]
+lock-version = "2.0"
+"#;
+
+    let packages = parse_poetry_lock(content);
+    assert_eq!(
+        versions(&packages),
+        vec![
+            ("requests".to_string(), "2.31.0".to_string()),
+            ("urllib3".to_string(), "2.0.7".to_string()),
+        ]
+    );
+    assert!(packages.iter().all(|p| p.ecosystem == Ecosystem::PyPI));
+}
+
+#[test]
+fn test_read_locked_packages_and_direct_dependencies() {
+    let temp_dir = tempdir().unwrap();
+    let dir = temp_dir.path();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1.0\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("Cargo.lock"),
+        "[[package]]\nname = \"serde\"\nversion = \"1.0.190\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n\n[[package]]\nname = \"itoa\"\nversion = \"1.0.9\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+    )
+    .unwrap();
+
+    let packages = read_locked_packages(dir).unwrap();
+    assert_eq!(packages.len(), 2);
+
+    let direct = direct_dependency_names(dir);
+    assert!(direct.contains(&(Ecosystem::Cargo, "serde".to_string())));
+    assert!(!direct.contains(&(Ecosystem::Cargo, "itoa".to_string())));
+}
+
+#[test]
+fn test_is_outdated() {
+    assert!(is_outdated("1.0.0", "1.0.1"));
+    assert!(is_outdated("1.9.0", "2.0.0"));
+    assert!(!is_outdated("2.0.0", "2.0.0"));
+    assert!(!is_outdated("2.1.0", "2.0.0"));
+    // Non-semver versions (e.g. Python) fall back to plain comparison
+    assert!(is_outdated("2023.1", "2024.2"));
+}
+
+#[tokio::test]
+async fn test_check_outdated_counts_failed_lookups() {
+    let server = MockServer::start();
+    server
+        .mock("GET", "/react/latest", 200, "{\"version\":\"18.3.1\"}")
+        .mock("GET", "/lodash/latest", 200, "{\"version\":\"4.17.21\"}")
+        .mock("GET", "/missing/latest", 404, "{}");
+    let mut registry = RegistryClient::new().unwrap();
+    registry.npm_url = server.url();
+
+    let package = |name: &str, version: &str| LockedPackage {
+        name: name.to_string(),
+        version: version.to_string(),
+        ecosystem: Ecosystem::Npm,
+    };
+    let (outdated, failed) = check_outdated(
+        &registry,
+        vec![
+            package("react", "17.0.2"),
+            package("lodash", "4.17.21"),
+            package("missing", "1.0.0"),
+        ],
+    )
+    .await;
+    assert_eq!(outdated.len(), 1);
+    assert_eq!(outdated[0].name, "react");
+    assert_eq!(failed, 1);
+}
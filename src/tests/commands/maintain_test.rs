@@ -0,0 +1,63 @@
+use crate::commands::maintain::{
+    render_summary, selected_tasks, summary_title, MaintenanceTask, TaskStatus, TaskSummary,
+};
+
+#[test]
+fn test_selected_tasks() {
+    assert_eq!(selected_tasks(&[], &[]), MaintenanceTask::ALL.to_vec());
+    assert_eq!(
+        selected_tasks(
+            &[],
+            &[MaintenanceTask::Quality, MaintenanceTask::Dependencies]
+        ),
+        vec![
+            MaintenanceTask::Branches,
+            MaintenanceTask::Translations,
+            MaintenanceTask::Health
+        ]
+    );
+    assert_eq!(
+        selected_tasks(
+            &[MaintenanceTask::Health, MaintenanceTask::Branches],
+            &[MaintenanceTask::Health]
+        ),
+        vec![MaintenanceTask::Branches]
+    );
+    assert_eq!(
+        "translations".parse::<MaintenanceTask>().unwrap(),
+        MaintenanceTask::Translations
+    );
+    assert!("backups".parse::<MaintenanceTask>().is_err());
+}
+
+#[test]
+fn test_render_summary() {
+    let summaries = vec![
+        TaskSummary {
+            task: MaintenanceTask::Branches,
+            status: TaskStatus::Warning,
+            headline: "1 of 4 branches stale".to_string(),
+            details: vec!["origin/old (merged into main)".to_string()],
+        },
+        TaskSummary {
+            task: MaintenanceTask::Quality,
+            status: TaskStatus::Skipped,
+            headline: "on 'feature', quality checks only run on 'main'".to_string(),
+            details: Vec::new(),
+        },
+    ];
+
+    assert_eq!(
+        render_summary(&summaries),
+        "⚠️ Stale branches: 1 of 4 branches stale\n  • origin/old (merged into main)\n\
+         ⏭️ Quality checks: on 'feature', quality checks only run on 'main'\n"
+    );
+    assert_eq!(
+        summary_title("app", &summaries),
+        "🛠️ Maintenance for app: 1 warning(s)"
+    );
+    assert_eq!(
+        summary_title("app", &summaries[1..]),
+        "🛠️ Maintenance for app: all good"
+    );
+}
@@ -0,0 +1,137 @@
+use crate::commands::config::AppConfig;
+use crate::commands::mcp::{dependency_status, McpServer, PROTOCOL_VERSION};
+use serde_json::{json, Value};
+use std::fs;
+use tempfile::tempdir;
+
+fn tool_text(response: &Value) -> Value {
+    serde_json::from_str(response["result"]["content"][0]["text"].as_str().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_initialize_and_list_tools() {
+    let dir = tempdir().unwrap();
+    let server = McpServer::new(dir.path().to_path_buf(), AppConfig::default());
+
+    let response = server
+        .handle_message(&json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}))
+        .await
+        .unwrap();
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["protocolVersion"], PROTOCOL_VERSION);
+    assert_eq!(response["result"]["serverInfo"]["name"], "nitroterm");
+
+    // Notifications are never answered
+    assert!(server
+        .handle_message(&json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+        .await
+        .is_none());
+
+    let response = server
+        .handle_message(&json!({"jsonrpc": "2.0", "id": "list", "method": "tools/list"}))
+        .await
+        .unwrap();
+    let names: Vec<&str> = response["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"release_notes"));
+    assert!(names.contains(&"translation_status"));
+
+    let response = server
+        .handle_message(&json!({"jsonrpc": "2.0", "id": 2, "method": "resources/list"}))
+        .await
+        .unwrap();
+    assert_eq!(response["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn test_tool_calls() {
+    let dir = tempdir().unwrap();
+    let messages = dir.path().join("messages");
+    fs::create_dir_all(&messages).unwrap();
+    fs::write(messages.join("en.json"), r#"{"greeting": "Hello {name}"}"#).unwrap();
+    fs::write(messages.join("de.json"), r#"{"greeting": "Hallo"}"#).unwrap();
+    let server = McpServer::new(dir.path().to_path_buf(), AppConfig::default());
+
+    let call = |name: &str| {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": {"dir": "messages", "source": "en.json"}},
+        })
+    };
+
+    let response = server
+        .handle_message(&call("translation_status"))
+        .await
+        .unwrap();
+    assert_eq!(response["result"]["isError"], false);
+    assert_eq!(tool_text(&response)["languages"][0]["code"], "de");
+
+    let response = server
+        .handle_message(&call("validate_translations"))
+        .await
+        .unwrap();
+    assert_eq!(tool_text(&response)["error_count"], 1);
+
+    // No cached report: a tool error for the model, not a protocol error
+    let response = server
+        .handle_message(&call("quality_results"))
+        .await
+        .unwrap();
+    assert_eq!(response["result"]["isError"], true);
+    assert!(response.get("error").is_none());
+
+    let response = server.handle_message(&call("rm_rf")).await.unwrap();
+    assert_eq!(response["result"]["isError"], true);
+
+    // Paths from the client stay inside the project
+    for arguments in [
+        json!({"dir": ".."}),
+        json!({"dir": "/etc", "source": "hosts"}),
+    ] {
+        let response = server
+            .handle_message(&json!({
+                "jsonrpc": "2.0",
+                "id": 4,
+                "method": "tools/call",
+                "params": {"name": "translation_status", "arguments": arguments},
+            }))
+            .await
+            .unwrap();
+        assert_eq!(response["result"]["isError"], true);
+    }
+}
+
+#[test]
+fn test_dependency_status() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1\"\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("Cargo.lock"),
+        r#"[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "itoa"
+version = "1.0.11"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#,
+    )
+    .unwrap();
+
+    let status = dependency_status(dir.path()).unwrap();
+    assert_eq!(status["total"], 2);
+    assert_eq!(status["direct"], 1);
+    assert_eq!(status["ecosystems"]["crates.io"], 2);
+}
@@ -1,8 +1,64 @@
+pub mod audit_test;
+pub mod branches_test;
+pub mod cargo_workspace_test;
 pub mod code_quality_test;
+pub mod codeowners_test;
+pub mod commit_dedup_test;
+pub mod commit_test;
+pub mod commit_filter_test;
 pub mod config_test;
+pub mod contributors_test;
 pub mod create_release_test;
 pub mod dependency_update_test;
+pub mod diff_report_test;
+pub mod doctor_test;
+pub mod export_test;
+pub mod fork_test;
+pub mod gemini_models_test;
+pub mod git_identity_test;
+pub mod github_api_test;
 pub mod github_labels_test;
+pub mod github_milestones_test;
+pub mod github_templates_test;
+pub mod github_triage_test;
+pub mod lockfiles_test;
+pub mod maintain_test;
+pub mod mcp_test;
+pub mod new_project_test;
+pub mod notifications_test;
+pub mod onboarding_test;
+pub mod org_test;
+pub mod package_json_test;
+pub mod parser_properties_test;
+pub mod pipeline_test;
+pub mod policy_test;
+pub mod provenance_test;
+pub mod quality_history_test;
+pub mod release_hosting_test;
+pub mod release_notes_summary_test;
+pub mod release_notes_template_test;
 pub mod release_notes_test;
+pub mod release_notes_translation_test;
+pub mod release_preflight_test;
+pub mod release_risk_test;
+pub mod releases_test;
+pub mod report_test;
+pub mod retag_test;
+pub mod sbom_test;
+pub mod scaffold_test;
+pub mod secret_scan_test;
+pub mod selective_update_test;
+pub mod self_update_test;
+pub mod serve_test;
+pub mod smoke_test_test;
+pub mod state_test;
+pub mod translation_cache_test;
+pub mod translation_format_test;
+pub mod translation_glossary_test;
+pub mod translation_heatmap_test;
+pub mod translation_icu_test;
 pub mod translation_sync_test;
+pub mod translation_validation_test;
+pub mod verify_deps_test;
+pub mod verify_release_test;
 pub mod version_management_test;
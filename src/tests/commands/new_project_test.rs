@@ -0,0 +1,131 @@
+use crate::commands::new_project::{
+    apply_placeholders, resolve_template, scaffold_project, substitute_placeholders,
+    NewProjectConfig,
+};
+use git2::{IndexAddOption, Repository, Signature};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn create_template_repo(path: &Path) {
+    let repo = Repository::init(path).unwrap();
+    fs::write(
+        path.join("README.md"),
+        "# {{name}}\n\nMaintained by {{org}}.\n",
+    )
+    .unwrap();
+    fs::write(path.join("LICENSE"), "{{license}} License\n").unwrap();
+    fs::create_dir_all(path.join("src/{{name}}")).unwrap();
+    fs::write(path.join("src/{{name}}/main.rs"), "fn main() {}\n").unwrap();
+    fs::create_dir_all(path.join(".githooks")).unwrap();
+    fs::write(path.join(".githooks/pre-commit"), "#!/bin/sh\n").unwrap();
+
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"].iter(), IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Test", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_resolve_template() {
+    let templates = HashMap::from([(
+        "service".to_string(),
+        "https://github.com/acme/service-template".to_string(),
+    )]);
+
+    assert_eq!(
+        resolve_template("service", &templates),
+        "https://github.com/acme/service-template"
+    );
+    assert_eq!(
+        resolve_template("https://github.com/acme/other", &templates),
+        "https://github.com/acme/other"
+    );
+}
+
+#[test]
+fn test_substitute_placeholders() {
+    let values = [("name", "my-api"), ("org", "acme")];
+
+    assert_eq!(
+        substitute_placeholders("{{org}}/{{name}} by {{org}}", &values),
+        "acme/my-api by acme"
+    );
+    assert_eq!(
+        substitute_placeholders("{{unknown}} stays", &values),
+        "{{unknown}} stays"
+    );
+}
+
+#[test]
+fn test_apply_placeholders_renames_paths() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("{{name}}")).unwrap();
+    fs::write(root.join("{{name}}/config.txt"), "name={{name}}").unwrap();
+    fs::write(root.join("plain.txt"), "nothing here").unwrap();
+
+    let changed = apply_placeholders(root, &[("name", "demo")]).unwrap();
+
+    assert_eq!(changed, 1);
+    assert_eq!(
+        fs::read_to_string(root.join("demo/config.txt")).unwrap(),
+        "name=demo"
+    );
+    assert!(!root.join("{{name}}").exists());
+}
+
+#[test]
+fn test_scaffold_project_from_local_template() {
+    let template_dir = tempdir().unwrap();
+    create_template_repo(template_dir.path());
+
+    let output_dir = tempdir().unwrap();
+    let target = output_dir.path().join("my-api");
+    let config = NewProjectConfig {
+        template: "local".to_string(),
+        name: "my-api".to_string(),
+        org: Some("acme".to_string()),
+        license: "Apache-2.0".to_string(),
+        ..Default::default()
+    };
+
+    scaffold_project(&config, template_dir.path().to_str().unwrap(), &target).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(target.join("README.md")).unwrap(),
+        "# my-api\n\nMaintained by acme.\n"
+    );
+    assert_eq!(
+        fs::read_to_string(target.join("LICENSE")).unwrap(),
+        "Apache-2.0 License\n"
+    );
+    assert!(target.join("src/my-api/main.rs").exists());
+
+    // Fresh history with a single commit and hooks wired up
+    let repo = Repository::open(&target).unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.parent_count(), 0);
+    assert_eq!(
+        repo.config().unwrap().get_string("core.hooksPath").unwrap(),
+        ".githooks"
+    );
+}
+
+#[test]
+fn test_scaffold_project_existing_directory() {
+    let output_dir = tempdir().unwrap();
+    let config = NewProjectConfig {
+        name: "existing".to_string(),
+        ..Default::default()
+    };
+
+    let result = scaffold_project(&config, "unused", output_dir.path());
+    assert!(result.is_err());
+}
@@ -0,0 +1,163 @@
+use crate::commands::notifications::{
+    deliver, email_message, send_email, MessageTemplate, NotificationSettings, NotifyEvent,
+};
+use crate::commands::pipeline::load_project_file;
+use crate::tests::support::{MockRunner, MockServer};
+use serde_json::json;
+use std::fs;
+use tempfile::TempDir;
+
+const PROJECT: &str = r#"
+[notifications]
+webhooks = ["$NITROTERM_TEST_SLACK_WEBHOOK", "$NITROTERM_TEST_UNSET_WEBHOOK"]
+email = ["team@acme.dev", "ops@acme.dev"]
+email_from = "ci@acme.dev"
+events = ["release", "quality-failed"]
+
+[notifications.templates.release]
+title = "{{project}} {{tag}} is out"
+"#;
+
+fn settings(content: &str) -> NotificationSettings {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".nitrokit.toml"), content).unwrap();
+    load_project_file(temp_dir.path())
+        .unwrap()
+        .unwrap()
+        .notifications
+        .unwrap()
+}
+
+#[test]
+fn test_notification_settings() {
+    let settings = settings(PROJECT);
+    assert!(settings.enabled(NotifyEvent::Release, None));
+    assert!(!settings.enabled(NotifyEvent::DependencyUpdate, None));
+    // --notify and --no-notify win over `events`
+    assert!(settings.enabled(NotifyEvent::DependencyUpdate, Some(true)));
+    assert!(!settings.enabled(NotifyEvent::Release, Some(false)));
+    assert!(settings.validate().is_ok());
+
+    std::env::set_var(
+        "NITROTERM_TEST_SLACK_WEBHOOK",
+        "https://hooks.slack.com/services/x",
+    );
+    assert_eq!(
+        settings.webhook_urls(),
+        vec!["https://hooks.slack.com/services/x".to_string()]
+    );
+
+    let typo = NotificationSettings {
+        templates: [("relase".to_string(), Default::default())].into(),
+        ..Default::default()
+    };
+    assert!(typo
+        .validate()
+        .unwrap_err()
+        .to_string()
+        .contains("dependency-update"));
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".nitrokit.toml"),
+        "[notifications]\nevents = [\"deploy\"]\n",
+    )
+    .unwrap();
+    assert!(load_project_file(temp_dir.path()).is_err());
+}
+
+#[test]
+fn test_render_notification_templates() {
+    let settings = settings(PROJECT);
+    let (title, body) = settings
+        .render(
+            NotifyEvent::Release,
+            &json!({ "project": "app", "tag": "v1.2.0", "url": null, "notes": "- <b>fix</b>" }),
+        )
+        .unwrap();
+    assert_eq!(title, "app v1.2.0 is out");
+    // Default body, not HTML escaped
+    assert_eq!(body, "- <b>fix</b>");
+
+    let (title, body) = settings
+        .render(
+            NotifyEvent::QualityFailed,
+            &json!({ "project": "app", "failed": ["Lint", "Tests"], "failed_count": 2, "total": 5 }),
+        )
+        .unwrap();
+    assert_eq!(title, "❌ app: 2 of 5 checks failed");
+    assert_eq!(body, "- Lint\n- Tests");
+
+    let broken = NotificationSettings {
+        templates: [(
+            "release".to_string(),
+            MessageTemplate {
+                title: Some("{{#if tag}}".to_string()),
+                body: None,
+            },
+        )]
+        .into(),
+        ..Default::default()
+    };
+    assert!(broken.render(NotifyEvent::Release, &json!({})).is_err());
+}
+
+#[test]
+fn test_send_email_through_sendmail() {
+    let settings = settings(PROJECT);
+    let message = email_message(&settings, "v1.2.0 is out", "notes");
+    assert_eq!(
+        message,
+        "To: team@acme.dev, ops@acme.dev\nFrom: ci@acme.dev\nSubject: v1.2.0 is out\n\
+         MIME-Version: 1.0\nContent-Type: text/plain; charset=utf-8\n\nnotes\n"
+    );
+    // Non-ASCII subjects are encoded
+    assert!(email_message(&settings, "🚀 out", "").contains("Subject: =?UTF-8?B?8J+agCBvdXQ=?=\n"));
+
+    let runner = MockRunner::new();
+    send_email(&runner, &settings, "v1.2.0 is out", "notes").unwrap();
+    assert_eq!(runner.calls(), vec!["sendmail -t -i"]);
+    assert_eq!(runner.inputs(), vec![message]);
+
+    let runner = MockRunner::new();
+    runner.fail("sendmail", "No recipient addresses found");
+    assert!(send_email(&runner, &settings, "title", "body")
+        .unwrap_err()
+        .to_string()
+        .contains("No recipient addresses"));
+}
+
+#[tokio::test]
+async fn test_deliver_to_webhooks_and_email() {
+    let server = MockServer::start();
+    server.mock("POST", "/hook", 200, "ok");
+    let settings = NotificationSettings {
+        webhooks: vec![format!("{}/hook", server.url())],
+        email: vec!["team@acme.dev".to_string()],
+        ..Default::default()
+    };
+    let runner = MockRunner::new();
+    let context = json!({ "project": "app", "updated": 2, "failed": 0, "results": [] });
+
+    let sent = deliver(&runner, &settings, NotifyEvent::DependencyUpdate, &context)
+        .await
+        .unwrap();
+    assert_eq!(sent, 2);
+    let body: serde_json::Value = serde_json::from_str(&server.writes()[0].body).unwrap();
+    assert_eq!(body["title"], "📦 app: 2 updated, 0 failed");
+    assert_eq!(runner.calls(), vec!["sendmail -t -i"]);
+
+    // A failing destination doesn't keep the others from being tried
+    let runner = MockRunner::new();
+    runner.fail("sendmail", "connection refused");
+    let settings = NotificationSettings {
+        webhooks: vec![format!("{}/gone", server.url())],
+        ..settings
+    };
+    let error = deliver(&runner, &settings, NotifyEvent::DependencyUpdate, &context)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("1 of 1 notifications failed"));
+    assert!(error.contains("connection refused"));
+}
@@ -0,0 +1,91 @@
+use crate::commands::code_quality::{PackageManager, ProjectInfo, ProjectType};
+use crate::commands::config::AppConfig;
+use crate::commands::onboarding::{
+    describe_project, detect_messages_dir, parse_release_notes_format, suggested_checks,
+    OnboardingAnswers,
+};
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+fn project(project_type: ProjectType, frameworks: &[&str], has_typescript: bool) -> ProjectInfo {
+    ProjectInfo {
+        project_type,
+        package_manager: PackageManager::Unknown,
+        root_path: PathBuf::from("."),
+        config_files: Vec::new(),
+        has_typescript,
+        frameworks: frameworks.iter().map(|name| name.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_describe_project_and_suggested_checks() {
+    let next = project(ProjectType::NextJs, &["React", "Next.js"], true);
+    assert_eq!(
+        describe_project(&next, Some("pnpm")),
+        "React, Next.js, TypeScript (pnpm)"
+    );
+    assert_eq!(
+        suggested_checks(&next),
+        vec!["lint", "format", "security", "test", "typecheck", "secrets"]
+    );
+
+    let rust = project(ProjectType::Rust, &["Rust"], false);
+    assert_eq!(describe_project(&rust, None), "Rust");
+    assert!(!suggested_checks(&rust).contains(&"typecheck".to_string()));
+
+    let unknown = project(ProjectType::Unknown, &[], false);
+    assert_eq!(describe_project(&unknown, Some("npm")), "Unknown project");
+}
+
+#[test]
+fn test_detect_messages_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    assert_eq!(detect_messages_dir(temp_dir.path()), None);
+
+    std::fs::create_dir_all(temp_dir.path().join("public/locales")).unwrap();
+    assert_eq!(
+        detect_messages_dir(temp_dir.path()).as_deref(),
+        Some("public/locales")
+    );
+
+    // A file with the name doesn't count
+    std::fs::write(temp_dir.path().join("messages"), "").unwrap();
+    assert_eq!(
+        detect_messages_dir(temp_dir.path()).as_deref(),
+        Some("public/locales")
+    );
+}
+
+#[test]
+fn test_answers_apply_on_top_of_config() {
+    assert_eq!(
+        parse_release_notes_format("", "full").as_deref(),
+        Some("full")
+    );
+    assert_eq!(
+        parse_release_notes_format(" S ", "full").as_deref(),
+        Some("summary")
+    );
+    assert_eq!(parse_release_notes_format("short", "full"), None);
+
+    let mut config = AppConfig {
+        gemini_api_key: Some("existing".to_string()),
+        ..AppConfig::default()
+    };
+    let answers = OnboardingAnswers {
+        gemini_api_key: None,
+        messages_dir: Some("locales".to_string()),
+        release_notes_format: Some("summary".to_string()),
+        github_repo: Some("acme/widget".to_string()),
+        quality_checks: vec!["lint".to_string(), "secrets".to_string()],
+    };
+    answers.apply(&mut config);
+
+    // Skipped questions keep the current value
+    assert_eq!(config.gemini_api_key.as_deref(), Some("existing"));
+    assert_eq!(config.messages_dir, "locales");
+    assert_eq!(config.release_notes_format, "summary");
+    assert_eq!(config.github_repo.as_deref(), Some("acme/widget"));
+    assert_eq!(config.quality_checks, vec!["lint", "secrets"]);
+}
@@ -0,0 +1,128 @@
+use crate::commands::lockfiles::{Ecosystem, LockedPackage};
+use crate::commands::org::{
+    build_org_deps, collect_inventories, find_version_skew, OsvClient, Severity,
+};
+use crate::tests::support::MockServer;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn cargo_project(root: &Path, name: &str, deps: &[(&str, &str)]) -> String {
+    let dir = root.join(name);
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut manifest = format!("[package]\nname = \"{}\"\n\n[dependencies]\n", name);
+    let mut lock = String::from("version = 3\n");
+    for (dep, version) in deps {
+        manifest.push_str(&format!("{} = \"{}\"\n", dep, version));
+        lock.push_str(&format!(
+            "\n[[package]]\nname = \"{}\"\nversion = \"{}\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+            dep, version
+        ));
+    }
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    fs::write(dir.join("Cargo.lock"), lock).unwrap();
+    dir.display().to_string()
+}
+
+#[test]
+fn test_version_skew_across_projects() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let paths = vec![
+        cargo_project(root, "api", &[("serde", "1.0.190"), ("tokio", "1.35.0")]),
+        cargo_project(root, "web", &[("serde", "1.0.210"), ("tokio", "1.35.0")]),
+        cargo_project(root, "worker", &[("serde", "1.0.99")]),
+        root.join("gone").display().to_string(),
+    ];
+
+    let projects = collect_inventories(&paths);
+    assert_eq!(projects[0].packages.len(), 2);
+    assert_eq!(projects[3].error.as_deref(), Some("Directory not found"));
+
+    // tokio is on one version everywhere, serde is not
+    let skew = find_version_skew(&projects);
+    assert_eq!(skew.len(), 1);
+    assert_eq!(skew[0].name, "serde");
+    assert_eq!(skew[0].ecosystem, Ecosystem::Cargo);
+    let versions: Vec<&str> = skew[0].versions.iter().map(|(v, _)| v.as_str()).collect();
+    assert_eq!(versions, vec!["1.0.210", "1.0.190", "1.0.99"]);
+    assert_eq!(skew[0].versions[2].1, vec!["worker"]);
+}
+
+#[tokio::test]
+async fn test_security_lag_from_osv() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let paths = vec![
+        cargo_project(root, "api", &[("openssl", "0.10.50"), ("serde", "1.0.190")]),
+        cargo_project(root, "web", &[("openssl", "0.10.66")]),
+    ];
+
+    let server = MockServer::start();
+    // Packages are queried once each, in first-seen order: openssl 0.10.50,
+    // serde 1.0.190, openssl 0.10.66
+    server
+        .mock(
+            "POST",
+            "/querybatch",
+            200,
+            r#"{"results": [
+                {"vulns": [{"id": "RUSTSEC-0001"}, {"id": "RUSTSEC-0002"}]},
+                {},
+                {"vulns": [{"id": "RUSTSEC-0002"}]}
+            ]}"#,
+        )
+        .mock(
+            "GET",
+            "/vulns/RUSTSEC-0001",
+            200,
+            r#"{"id": "RUSTSEC-0001", "summary": "Use after free",
+                "database_specific": {"severity": "CRITICAL"},
+                "affected": [{"package": {"name": "openssl", "ecosystem": "crates.io"},
+                  "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "0.10.55"}]}]}]}"#,
+        )
+        .mock(
+            "GET",
+            "/vulns/RUSTSEC-0002",
+            200,
+            r#"{"id": "RUSTSEC-0002", "summary": "Timing leak",
+                "database_specific": {"severity": "LOW"},
+                "affected": [{"package": {"name": "openssl", "ecosystem": "crates.io"},
+                  "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}, {"fixed": "0.11.0"}]}]}]}"#,
+        );
+
+    let osv = OsvClient::new(&server.url()).unwrap();
+    let report = build_org_deps(&paths, Some(&osv), Severity::High)
+        .await
+        .unwrap();
+
+    assert_eq!(report.lagging.len(), 1);
+    let lag = &report.lagging[0];
+    assert_eq!(lag.project, "api");
+    assert_eq!(
+        lag.package,
+        LockedPackage {
+            name: "openssl".to_string(),
+            version: "0.10.50".to_string(),
+            ecosystem: Ecosystem::Cargo,
+        }
+    );
+    assert_eq!(lag.advisories.len(), 1);
+    assert_eq!(lag.fixed_in(), Some("0.10.55".to_string()));
+
+    // Each advisory is fetched once even when several packages share it
+    let fetched = server
+        .requests()
+        .iter()
+        .filter(|request| request.path.starts_with("/vulns/"))
+        .count();
+    assert_eq!(fetched, 2);
+
+    // Lowering the threshold brings in the low advisory for both repos
+    let report = build_org_deps(&paths, Some(&osv), Severity::Low)
+        .await
+        .unwrap();
+    assert_eq!(report.lagging.len(), 2);
+    assert_eq!(report.lagging[0].fixed_in(), Some("0.11.0".to_string()));
+}
@@ -0,0 +1,137 @@
+use crate::commands::dependency_update::UpdateRules;
+use crate::commands::lockfiles::RegistryClient;
+use crate::commands::package_json::{
+    apply_range_upgrades, fetch_latest_npm_versions, plan_range_upgrades, registry_ranges,
+    set_range, upgraded_range,
+};
+use crate::tests::support::MockServer;
+use std::collections::HashMap;
+
+const PACKAGE_JSON: &str = r#"{
+    "name": "web",
+    "dependencies": {
+        "react": "^17.0.2",
+        "lodash": "~4.17.0",
+        "ui": "workspace:*",
+        "left-pad": "github:stevemao/left-pad"
+    },
+    "devDependencies": {
+        "vitest": "1.x",
+        "typescript": "5.4.5"
+    },
+    "peerDependencies": {
+        "react": ">=16"
+    }
+}
+"#;
+
+#[test]
+fn test_upgraded_ranges() {
+    assert_eq!(
+        upgraded_range("^17.0.2", "18.3.1").as_deref(),
+        Some("^18.3.1")
+    );
+    assert_eq!(upgraded_range("~1.2", "2.0.4").as_deref(), Some("~2.0"));
+    assert_eq!(upgraded_range("1.x", "2.1.0").as_deref(), Some("2.x"));
+    assert_eq!(
+        upgraded_range(">=4.0.0", "5.1.0").as_deref(),
+        Some(">=5.1.0")
+    );
+    assert_eq!(upgraded_range("5.4.5", "5.5.0").as_deref(), Some("5.5.0"));
+    assert_eq!(
+        upgraded_range("^1.0.0-beta.1", "1.0.0").as_deref(),
+        Some("^1.0.0")
+    );
+
+    // Already there, older, or nothing to rewrite
+    assert_eq!(upgraded_range("^18.3.1", "18.3.1"), None);
+    assert_eq!(upgraded_range("^2", "1.9.0"), None);
+    assert_eq!(upgraded_range("1.x", "1.9.0"), None);
+    assert_eq!(upgraded_range("^1 || ^2", "3.0.0"), None);
+    assert_eq!(upgraded_range("latest", "3.0.0"), None);
+}
+
+#[test]
+fn test_plan_respects_ignore_and_pin_rules() {
+    let ranges = registry_ranges(PACKAGE_JSON).unwrap();
+    let names: Vec<(&str, &str)> = ranges
+        .iter()
+        .map(|r| (r.section.as_str(), r.name.as_str()))
+        .collect();
+    // Workspace and git specs point elsewhere, peer ranges are left alone
+    assert_eq!(
+        names,
+        vec![
+            ("dependencies", "lodash"),
+            ("dependencies", "react"),
+            ("devDependencies", "typescript"),
+            ("devDependencies", "vitest"),
+        ]
+    );
+
+    let latest = HashMap::from([
+        ("react".to_string(), "18.3.1".to_string()),
+        ("lodash".to_string(), "4.17.21".to_string()),
+        ("vitest".to_string(), "2.1.0".to_string()),
+        ("typescript".to_string(), "5.6.2".to_string()),
+    ]);
+    let mut rules = UpdateRules::default();
+    rules.add_ignore("node:typescript");
+    rules.add_pin("node:react@17").unwrap();
+
+    let upgrades = plan_range_upgrades(&ranges, &latest, &rules);
+    let planned: Vec<(&str, &str, &str)> = upgrades
+        .iter()
+        .map(|u| (u.name.as_str(), u.from.as_str(), u.to.as_str()))
+        .collect();
+    assert_eq!(
+        planned,
+        vec![("lodash", "~4.17.0", "~4.17.21"), ("vitest", "1.x", "2.x")]
+    );
+
+    // Only the ranges change, the layout stays
+    let updated = apply_range_upgrades(PACKAGE_JSON, &upgrades);
+    assert_eq!(
+        updated,
+        PACKAGE_JSON
+            .replace("\"~4.17.0\"", "\"~4.17.21\"")
+            .replace("\"1.x\"", "\"2.x\"")
+    );
+}
+
+#[test]
+fn test_set_range_stays_in_its_section() {
+    let updated = set_range(PACKAGE_JSON, "peerDependencies", "react", ">=16", ">=18").unwrap();
+    assert!(updated.contains("\"react\": \"^17.0.2\""));
+    assert!(updated.contains("\"react\": \">=18\""));
+    // The range must still read what was planned
+    assert_eq!(
+        set_range(PACKAGE_JSON, "dependencies", "react", "^16.0.0", "^18.3.1"),
+        None
+    );
+    assert_eq!(
+        set_range(
+            PACKAGE_JSON,
+            "optionalDependencies",
+            "react",
+            "^17.0.2",
+            "^18.3.1"
+        ),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_latest_npm_versions() {
+    let server = MockServer::start();
+    server
+        .mock("GET", "/react/latest", 200, "{\"version\":\"18.3.1\"}")
+        .mock("GET", "/missing/latest", 404, "{}");
+    let mut registry = RegistryClient::new().unwrap();
+    registry.npm_url = server.url();
+
+    let latest =
+        fetch_latest_npm_versions(&registry, &["missing".to_string(), "react".to_string()]).await;
+    assert_eq!(latest.len(), 1);
+    assert_eq!(latest["react"], "18.3.1");
+}
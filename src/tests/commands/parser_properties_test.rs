@@ -0,0 +1,190 @@
+//! Property tests for the parsers that see arbitrary real-world input (tag
+//! names, remote URLs, translation files, model output). The same entry
+//! points are fuzzed under `fuzz/`.
+
+use crate::commands::release_notes::{
+    clean_tag_name, compare_version_tags, parse_git_url, RepositoryInfo,
+};
+use crate::commands::translation_sync::{
+    extract_all_paths, get_nested_value, parse_translation_response, set_nested_value,
+    set_nested_value_like,
+};
+use crate::utils::version_check::compare_versions;
+use proptest::prelude::*;
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+
+/// Object keys as they show up in message catalogs, unicode included. Dots
+/// are path separators, so keys never contain them.
+fn key() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_ğüşöçİı日本😀-]{1,8}"
+}
+
+fn messages() -> impl Strategy<Value = Value> {
+    let leaf = any::<String>().prop_map(Value::String);
+    leaf.prop_recursive(6, 64, 6, |inner| {
+        prop::collection::btree_map(key(), inner, 1..6)
+            .prop_map(|map| Value::Object(map.into_iter().collect::<Map<String, Value>>()))
+    })
+}
+
+/// Catalogs with lists, e.g. steps or FAQ entries.
+fn messages_with_arrays() -> impl Strategy<Value = Value> {
+    let leaf = any::<String>().prop_map(Value::String);
+    let catalog = leaf.prop_recursive(6, 64, 6, |inner| {
+        prop_oneof![
+            prop::collection::btree_map(key(), inner.clone(), 1..6)
+                .prop_map(|map| Value::Object(map.into_iter().collect::<Map<String, Value>>())),
+            prop::collection::vec(inner, 1..6).prop_map(Value::Array),
+        ]
+    });
+    prop::collection::btree_map(key(), catalog, 1..6)
+        .prop_map(|map| Value::Object(map.into_iter().collect::<Map<String, Value>>()))
+}
+
+proptest! {
+    #[test]
+    fn clean_tag_name_never_panics(tag in any::<String>()) {
+        let cleaned = clean_tag_name(&tag);
+        prop_assert!(!cleaned.is_empty());
+        prop_assert!(!cleaned.contains(['/', ':', ' ']));
+    }
+
+    #[test]
+    fn clean_tag_name_keeps_plain_versions(major in 0u32..1000, minor in 0u32..1000, patch in 0u32..1000) {
+        let tag = format!("v{}.{}.{}", major, minor, patch);
+        prop_assert_eq!(clean_tag_name(&tag), tag);
+    }
+
+    #[test]
+    fn parse_git_url_never_panics(url in any::<String>()) {
+        let mut info = RepositoryInfo::default();
+        parse_git_url(&mut info, &url);
+    }
+
+    #[test]
+    fn parse_git_url_extracts_github_owner_and_name(
+        owner in "[A-Za-z0-9-]{1,20}",
+        name in "[A-Za-z0-9_.-]{1,20}",
+        ssh in any::<bool>(),
+    ) {
+        prop_assume!(!name.ends_with(".git") && !name.ends_with('.'));
+        let url = if ssh {
+            format!("git@github.com:{}/{}.git", owner, name)
+        } else {
+            format!("https://github.com/{}/{}", owner, name)
+        };
+        let mut info = RepositoryInfo::default();
+        parse_git_url(&mut info, &url);
+        prop_assert!(info.is_github);
+        prop_assert_eq!(info.owner, owner);
+        prop_assert_eq!(info.name, name);
+    }
+
+    #[test]
+    fn compare_version_tags_is_antisymmetric(a in any::<String>(), b in any::<String>()) {
+        prop_assert_eq!(compare_version_tags(&a, &b), compare_version_tags(&b, &a).reverse());
+        prop_assert_eq!(compare_version_tags(&a, &a), Ordering::Equal);
+    }
+
+    #[test]
+    fn compare_version_tags_sorts_any_tags(mut tags in prop::collection::vec(any::<String>(), 0..32)) {
+        tags.sort_by(|a, b| compare_version_tags(a, b));
+    }
+
+    #[test]
+    fn compare_versions_matches_numeric_order(
+        a in (0u64..500, 0u64..500, 0u64..500),
+        b in (0u64..500, 0u64..500, 0u64..500),
+    ) {
+        let current = format!("v{}.{}.{}", a.0, a.1, a.2);
+        let latest = format!("{}.{}.{}", b.0, b.1, b.2);
+        prop_assert_eq!(compare_versions(&current, &latest).unwrap(), a.cmp(&b));
+        prop_assert_eq!(
+            compare_version_tags(&current, &latest),
+            a.cmp(&b)
+        );
+    }
+
+    #[test]
+    fn compare_versions_rejects_garbage_without_panicking(a in any::<String>(), b in any::<String>()) {
+        let _ = compare_versions(&a, &b);
+    }
+
+    #[test]
+    fn extracted_paths_resolve_to_strings(source in messages()) {
+        for path in extract_all_paths(&source, "") {
+            prop_assert!(get_nested_value(&source, &path).is_some_and(|v| v.is_string()));
+        }
+    }
+
+    #[test]
+    fn set_nested_value_rebuilds_catalog(source in messages()) {
+        let mut rebuilt = json!({});
+        for path in extract_all_paths(&source, "") {
+            let value = get_nested_value(&source, &path).unwrap().clone();
+            set_nested_value(&mut rebuilt, &path, value).unwrap();
+        }
+        if source.is_object() {
+            prop_assert_eq!(rebuilt, source);
+        }
+    }
+
+    #[test]
+    fn set_nested_value_like_rebuilds_catalog_with_arrays(source in messages_with_arrays()) {
+        let mut rebuilt = json!({});
+        for path in extract_all_paths(&source, "") {
+            let value = get_nested_value(&source, &path).unwrap().clone();
+            set_nested_value_like(&mut rebuilt, &source, &path, value).unwrap();
+        }
+        prop_assert_eq!(rebuilt, source);
+    }
+
+    #[test]
+    fn set_nested_value_overwrites_anything_in_the_way(target in messages(), path in any::<String>()) {
+        let mut target = target;
+        set_nested_value(&mut target, &path, json!("new")).unwrap();
+        prop_assert_eq!(get_nested_value(&target, &path), Some(&json!("new")));
+    }
+
+    #[test]
+    fn translation_response_only_returns_requested_paths(
+        response in any::<String>(),
+        paths in prop::collection::vec("[a-z.]{1,12}", 0..8),
+    ) {
+        for (path, _) in parse_translation_response(&response, &paths) {
+            prop_assert!(paths.contains(&path));
+        }
+    }
+
+    #[test]
+    fn translation_response_round_trips(
+        pairs in prop::collection::btree_map("[a-z]{1,6}(\\.[a-z]{1,6}){0,3}", "[^\r\n]{0,40}", 1..10),
+        noise in "[^|\r\n]{0,40}",
+    ) {
+        let mut response = format!("{}\n", noise);
+        for (path, text) in &pairs {
+            response.push_str(&format!("{}||{}\r\n", path, text));
+        }
+        let paths: Vec<String> = pairs.keys().cloned().collect();
+
+        let parsed = parse_translation_response(&response, &paths);
+        prop_assert_eq!(parsed.len(), pairs.len());
+        for (path, text) in parsed {
+            prop_assert_eq!(text, pairs[&path].trim());
+        }
+    }
+}
+
+#[test]
+fn test_deeply_nested_paths() {
+    let path = (0..200)
+        .map(|i| format!("k{}", i))
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut target = json!({ "k0": "was a string" });
+    set_nested_value(&mut target, &path, json!("deep")).unwrap();
+
+    assert_eq!(extract_all_paths(&target, ""), vec![path.clone()]);
+    assert_eq!(get_nested_value(&target, &path), Some(&json!("deep")));
+}
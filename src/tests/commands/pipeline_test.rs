@@ -0,0 +1,175 @@
+use crate::commands::pipeline::{
+    load_project_file, run_pipeline, split_command, PipelineContext, PipelineStep, StepStatus,
+};
+use crate::tests::support::MockRunner;
+use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
+
+const PROJECT: &str = r#"
+[pipelines]
+release = [
+    "code-quality",
+    { command = "version auto", name = "Bump version", when = { branch = ["main", "release"] } },
+    { command = "release-notes --by-team", continue_on_error = true },
+    { command = "nitroterm create-release --publish", skip_if = { env = "DRY_RUN" } },
+]
+docs = ["sync-translations --lang 'pt BR'"]
+"#;
+
+fn context(branch: &str, env: &[(&str, &str)]) -> PipelineContext {
+    PipelineContext {
+        root: std::env::temp_dir(),
+        branch: Some(branch.to_string()),
+        env: env
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+fn release_steps() -> Vec<PipelineStep> {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".nitrokit.toml"), PROJECT).unwrap();
+    let project = load_project_file(temp_dir.path()).unwrap().unwrap();
+    assert_eq!(project.pipelines.len(), 2);
+    project.pipelines["release"].clone()
+}
+
+#[test]
+fn test_load_project_file() {
+    let steps = release_steps();
+    assert_eq!(steps.len(), 4);
+    assert_eq!(steps[0], PipelineStep::Command("code-quality".to_string()));
+    assert_eq!(steps[1].label(), "Bump version");
+    assert_eq!(steps[2].label(), "release-notes --by-team");
+    assert_eq!(
+        steps[3].args().unwrap(),
+        vec!["create-release", "--publish"]
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    assert!(load_project_file(temp_dir.path()).unwrap().is_none());
+    fs::write(
+        temp_dir.path().join(".nitrokit.toml"),
+        "[pipelines]\nrelease = [{ command = \"x\", unless = { env = \"CI\" } }]\n",
+    )
+    .unwrap();
+    assert!(load_project_file(temp_dir.path())
+        .unwrap_err()
+        .to_string()
+        .contains("Invalid .nitrokit.toml"));
+}
+
+#[test]
+fn test_split_command() {
+    assert_eq!(
+        split_command(r#"release-notes --exclude-pattern "chore(deps)" --lang 'pt BR'"#).unwrap(),
+        vec![
+            "release-notes",
+            "--exclude-pattern",
+            "chore(deps)",
+            "--lang",
+            "pt BR"
+        ]
+    );
+    assert_eq!(
+        split_command(r#"commit -m "say \"hi\"" a\ b ''"#).unwrap(),
+        vec!["commit", "-m", "say \"hi\"", "a b", ""]
+    );
+    assert!(split_command("commit -m 'open").is_err());
+    assert!(PipelineStep::Command("  ".to_string()).args().is_err());
+}
+
+#[test]
+fn test_skip_conditions() {
+    let steps = release_steps();
+    let main = context("main", &[]);
+    assert_eq!(steps[1].skip_reason(&main), None);
+    assert_eq!(
+        steps[1].skip_reason(&context("feature/x", &[])),
+        Some("only when branch is main or release".to_string())
+    );
+    assert_eq!(steps[3].skip_reason(&main), None);
+    assert_eq!(
+        steps[3].skip_reason(&context("main", &[("DRY_RUN", "")])),
+        None
+    );
+    assert_eq!(
+        steps[3].skip_reason(&context("main", &[("DRY_RUN", "1")])),
+        Some("DRY_RUN is set".to_string())
+    );
+}
+
+#[test]
+fn test_run_pipeline_stops_at_first_failure() {
+    let steps = release_steps();
+    let runner = MockRunner::new();
+    runner.fail("nitroterm --no-update-check code-quality", "lint errors");
+    let results = run_pipeline(
+        &steps,
+        &context("main", &[]),
+        &runner,
+        "nitroterm",
+        &["--no-update-check"],
+    )
+    .unwrap();
+
+    assert_eq!(
+        runner.calls(),
+        vec!["nitroterm --no-update-check code-quality"]
+    );
+    let statuses: Vec<StepStatus> = results.into_iter().map(|result| result.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            StepStatus::Failed,
+            StepStatus::NotRun,
+            StepStatus::NotRun,
+            StepStatus::NotRun
+        ]
+    );
+}
+
+#[test]
+fn test_run_pipeline_skips_and_allows_failures() {
+    let steps = release_steps();
+    let runner = MockRunner::new();
+    runner.fail("nitroterm release-notes", "no tags");
+    let results = run_pipeline(
+        &steps,
+        &context("feature/x", &[]),
+        &runner,
+        "nitroterm",
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "nitroterm code-quality",
+            "nitroterm release-notes --by-team",
+            "nitroterm create-release --publish",
+        ]
+    );
+    let statuses: Vec<StepStatus> = results.into_iter().map(|result| result.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            StepStatus::Passed,
+            StepStatus::Skipped("only when branch is main or release".to_string()),
+            StepStatus::FailedAllowed,
+            StepStatus::Passed,
+        ]
+    );
+
+    // Broken steps are reported before anything runs
+    let runner = MockRunner::new();
+    let broken = vec![
+        PipelineStep::Command("code-quality".to_string()),
+        PipelineStep::Command("commit -m 'open".to_string()),
+    ];
+    assert!(run_pipeline(&broken, &context("main", &[]), &runner, "nitroterm", &[]).is_err());
+    assert!(runner.calls().is_empty());
+}
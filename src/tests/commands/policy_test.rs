@@ -0,0 +1,128 @@
+use crate::commands::org::OsvClient;
+use crate::commands::policy::{
+    evaluate_policy, load_policy, parse_coverage, PolicyCheck, RuleStatus, EXAMPLE_POLICY,
+    POLICY_FILE,
+};
+use crate::tests::support::MockServer;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_parse_coverage_formats() {
+    let lcov =
+        "SF:src/a.rs\nLF:10\nLH:8\nend_of_record\nSF:src/b.rs\nLF:10\nLH:10\nend_of_record\n";
+    assert_eq!(parse_coverage("lcov.info", lcov).unwrap(), 90.0);
+
+    let cobertura =
+        r#"<?xml version="1.0"?><coverage lines-valid="40" line-rate="0.75" branch-rate="0.5">"#;
+    assert_eq!(parse_coverage("coverage.xml", cobertura).unwrap(), 75.0);
+
+    let istanbul = r#"{"total": {"lines": {"total": 50, "covered": 41, "pct": 82.5}}}"#;
+    assert_eq!(
+        parse_coverage("coverage/coverage-summary.json", istanbul).unwrap(),
+        82.5
+    );
+
+    assert!(parse_coverage("lcov.info", "TN:\n").is_err());
+}
+
+#[test]
+fn test_load_policy() {
+    let temp_dir = tempdir().unwrap();
+    assert!(load_policy(temp_dir.path()).unwrap().is_none());
+
+    fs::create_dir_all(temp_dir.path().join(".nitrokit")).unwrap();
+    fs::write(temp_dir.path().join(POLICY_FILE), EXAMPLE_POLICY).unwrap();
+    let policy = load_policy(temp_dir.path()).unwrap().unwrap();
+    assert_eq!(policy.rules.len(), 4);
+    assert_eq!(
+        policy.rules[0].check,
+        PolicyCheck::Vulnerabilities {
+            severity: "critical".to_string()
+        }
+    );
+
+    fs::write(
+        temp_dir.path().join(POLICY_FILE),
+        r#"{"rules": [{"type": "coverage"}]}"#,
+    )
+    .unwrap();
+    let err = load_policy(temp_dir.path()).unwrap_err();
+    assert!(err.to_string().starts_with("Invalid .nitrokit/policy.json"));
+}
+
+#[tokio::test]
+async fn test_evaluate_policy_rules() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+
+    fs::create_dir_all(root.join("coverage")).unwrap();
+    fs::write(root.join("coverage/lcov.info"), "LF:100\nLH:85\n").unwrap();
+
+    fs::create_dir_all(root.join("locales")).unwrap();
+    fs::write(root.join("locales/en.json"), r#"{"a": "A", "b": "B"}"#).unwrap();
+    fs::write(root.join("locales/tr.json"), r#"{"a": "A", "b": "B"}"#).unwrap();
+    fs::write(root.join("locales/de.json"), r#"{"a": "A"}"#).unwrap();
+
+    fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[dependencies]\nopenssl = \"0.10\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("Cargo.lock"),
+        "version = 3\n\n[[package]]\nname = \"openssl\"\nversion = \"0.10.50\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+    )
+    .unwrap();
+
+    let server = MockServer::start();
+    server
+        .mock(
+            "POST",
+            "/querybatch",
+            200,
+            r#"{"results": [{"vulns": [{"id": "RUSTSEC-0001"}]}]}"#,
+        )
+        .mock(
+            "GET",
+            "/vulns/RUSTSEC-0001",
+            200,
+            r#"{"id": "RUSTSEC-0001", "summary": "Use after free",
+                "database_specific": {"severity": "HIGH"}}"#,
+        );
+
+    fs::create_dir_all(root.join(".nitrokit")).unwrap();
+    fs::write(
+        root.join(POLICY_FILE),
+        r#"{"rules": [
+            {"type": "coverage", "min_percent": 80},
+            {"name": "Ship-ready translations", "type": "translations", "min_percent": 90,
+             "messages_dir": "locales", "source_file": "en.json"},
+            {"type": "vulnerabilities"},
+            {"type": "vulnerabilities", "severity": "high"},
+            {"type": "coverage", "min_percent": 50, "report": "missing.info"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let policy = load_policy(root).unwrap().unwrap();
+    let osv = OsvClient::new(&server.url()).unwrap();
+    let results = evaluate_policy(root, &policy, &osv).await;
+
+    assert_eq!(results[0].name, "Coverage >= 80%");
+    assert_eq!(results[0].status, RuleStatus::Passed);
+    assert_eq!(results[0].detail, "85.0% in coverage/lcov.info");
+
+    assert_eq!(results[1].name, "Ship-ready translations");
+    assert_eq!(results[1].status, RuleStatus::Failed);
+    assert_eq!(results[1].detail, "de 50.0%");
+
+    // The advisory is high, so only the stricter rule fails
+    assert_eq!(results[2].status, RuleStatus::Passed);
+    assert_eq!(results[3].status, RuleStatus::Failed);
+    assert_eq!(results[3].detail, "openssl 0.10.50 (RUSTSEC-0001)");
+
+    // A rule that can't be evaluated blocks as well
+    assert_eq!(results[4].status, RuleStatus::Error);
+    assert!(results[4].detail.starts_with("Cannot read missing.info"));
+}
@@ -0,0 +1,78 @@
+use crate::commands::provenance::{
+    build_statement, collect_artifacts, sha256_file, BuilderInfo, PREDICATE_TYPE, STATEMENT_TYPE,
+};
+use git2::{Repository, Signature};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+#[test]
+fn test_sha256_file() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("abc.txt");
+    fs::write(&path, "abc").unwrap();
+
+    assert_eq!(
+        sha256_file(&path).unwrap(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_build_statement() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+
+    fs::write(root.join("Cargo.lock"), "# lock\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("Cargo.lock")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Jane", "jane@acme.dev").unwrap();
+    let commit = repo
+        .commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "chore: init",
+            &tree,
+            &[],
+        )
+        .unwrap();
+    repo.tag_lightweight("v1.0.0", &repo.find_object(commit, None).unwrap(), false)
+        .unwrap();
+
+    let dist = root.join("dist");
+    fs::create_dir(&dist).unwrap();
+    fs::write(dist.join("app.tar.gz"), "abc").unwrap();
+    let artifacts = collect_artifacts(&[dist.to_string_lossy().to_string()]).unwrap();
+
+    let builder = BuilderInfo {
+        id: "https://github.com/acme/app/.github/workflows/release.yml@refs/tags/v1.0.0".into(),
+        invocation_id: Some("https://github.com/acme/app/actions/runs/1/attempts/1".into()),
+    };
+    let statement = build_statement(root, &artifacts, None, &builder).unwrap();
+
+    assert_eq!(statement["_type"], STATEMENT_TYPE);
+    assert_eq!(statement["predicateType"], PREDICATE_TYPE);
+    assert_eq!(statement["subject"][0]["name"], "app.tar.gz");
+    assert_eq!(
+        statement["subject"][0]["digest"]["sha256"],
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let definition = &statement["predicate"]["buildDefinition"];
+    assert_eq!(definition["externalParameters"]["tag"], "v1.0.0");
+    let dependencies = definition["resolvedDependencies"].as_array().unwrap();
+    assert_eq!(dependencies[0]["digest"]["gitCommit"], commit.to_string());
+    assert!(dependencies[0]["uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("@refs/tags/v1.0.0"));
+    assert_eq!(dependencies[1]["name"], "Cargo.lock");
+    assert_eq!(
+        statement["predicate"]["runDetails"]["metadata"]["invocationId"],
+        builder.invocation_id.clone().unwrap()
+    );
+}
@@ -0,0 +1,117 @@
+use crate::commands::code_quality::CheckResult;
+use crate::commands::quality_history::{
+    append_run, compare_runs, find_run, load_history, QualityRun, Slowdown, HISTORY_FILE,
+};
+use std::fs;
+use tempfile::TempDir;
+
+fn result(name: &str, success: bool, duration_ms: u128) -> CheckResult {
+    CheckResult {
+        check_name: name.to_string(),
+        command: format!("tool {}", name),
+        success,
+        output: String::new(),
+        error: None,
+        duration_ms,
+    }
+}
+
+fn run(id: usize, results: &[CheckResult]) -> QualityRun {
+    QualityRun::new(id, 1_700_000_000 + id as i64, None, results)
+}
+
+#[test]
+fn test_append_and_load_history() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    assert!(load_history(root).unwrap().is_empty());
+
+    let first = append_run(root, &[result("lint", true, 1200)], 100, None).unwrap();
+    let second = append_run(
+        root,
+        &[result("lint", false, 900), result("test", true, 300)],
+        200,
+        Some("abc1234def".to_string()),
+    )
+    .unwrap();
+    assert_eq!((first.id, second.id), (1, 2));
+
+    // A broken line doesn't lose the rest of the history
+    let path = root.join(HISTORY_FILE);
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, format!("{}not json\n", content)).unwrap();
+
+    let runs = load_history(root).unwrap();
+    assert_eq!(runs, vec![first, second]);
+    assert_eq!(runs[1].pass_rate(), 50.0);
+    assert_eq!(runs[1].duration_ms(), 1200);
+    assert_eq!(runs[1].short_commit(), "abc1234");
+    assert!(!runs[1].checks[0].success);
+}
+
+#[test]
+fn test_compare_runs() {
+    let baseline = run(
+        1,
+        &[
+            result("lint", true, 1000),
+            result("format", false, 100),
+            result("test", true, 4000),
+            result("audit", true, 200),
+        ],
+    );
+    let current = run(
+        2,
+        &[
+            result("lint", false, 1100),
+            result("format", true, 90),
+            result("test", true, 9000),
+            // Much slower relatively, but under a second
+            result("audit", true, 700),
+            result("secrets", false, 50),
+        ],
+    );
+
+    let comparison = compare_runs(&baseline, &current);
+    assert_eq!(comparison.newly_failing, vec!["lint", "secrets"]);
+    assert_eq!(comparison.fixed, vec!["format"]);
+    assert_eq!(
+        comparison.slower,
+        vec![Slowdown {
+            name: "test".to_string(),
+            before_ms: 4000,
+            after_ms: 9000,
+        }]
+    );
+    assert_eq!(comparison.pass_rate_change, -15.0);
+    assert!(comparison.has_regressions());
+    assert!(!compare_runs(&current, &current).has_regressions());
+}
+
+#[test]
+fn test_find_run_by_id_or_ref() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+    let tree = repo
+        .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+        .unwrap();
+    let oid = repo
+        .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+    repo.tag_lightweight("v1.0.0", repo.find_commit(oid).unwrap().as_object(), false)
+        .unwrap();
+
+    let mut at_tag = run(2, &[result("lint", true, 10)]);
+    at_tag.commit = Some(oid.to_string());
+    let runs = vec![run(1, &[]), at_tag, run(3, &[])];
+
+    assert_eq!(find_run(&runs, "3", None).unwrap().id, 3);
+    assert_eq!(find_run(&runs, "#1", Some(&repo)).unwrap().id, 1);
+    assert_eq!(find_run(&runs, "v1.0.0", Some(&repo)).unwrap().id, 2);
+    assert!(find_run(&runs, "main~5", Some(&repo))
+        .unwrap_err()
+        .to_string()
+        .contains("neither a run id nor a git ref"));
+    assert!(find_run(&runs, "9", None).is_err());
+}
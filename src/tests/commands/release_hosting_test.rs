@@ -0,0 +1,375 @@
+use crate::commands::config::AppConfig;
+use crate::commands::release_hosting::{
+    api_url, detect_release_target, multipart_body, HostAuth, ReleaseAsset, ReleaseHost,
+    ReleasePublisher, ReleaseTarget,
+};
+use crate::tests::support::MockServer;
+use std::collections::HashMap;
+
+fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+fn target(host: ReleaseHost, project: &str) -> ReleaseTarget {
+    ReleaseTarget {
+        host,
+        project: project.to_string(),
+    }
+}
+
+#[test]
+fn test_detect_release_target() {
+    assert_eq!(
+        detect_release_target("https://github.com/acme/app.git", None),
+        Some(target(ReleaseHost::GitHub, "acme/app"))
+    );
+    assert_eq!(
+        detect_release_target("git@gitlab.com:acme/platform/app.git", None),
+        Some(target(ReleaseHost::GitLab, "acme/platform/app"))
+    );
+    assert_eq!(
+        detect_release_target("https://ci@bitbucket.org/acme/app", None),
+        Some(target(ReleaseHost::Bitbucket, "acme/app"))
+    );
+
+    // Self-hosted GitLab only when configured
+    let remote = "ssh://git@gitlab.example.com:2222/team/app.git";
+    assert_eq!(detect_release_target(remote, None), None);
+    assert_eq!(
+        detect_release_target(remote, Some("https://gitlab.example.com")),
+        Some(target(ReleaseHost::GitLab, "team/app"))
+    );
+
+    assert_eq!(
+        detect_release_target("https://bitbucket.org/acme/group/app", None),
+        None
+    );
+    assert_eq!(detect_release_target("/srv/git/app.git", None), None);
+}
+
+#[test]
+fn test_host_auth_prefers_environment() {
+    let config = AppConfig {
+        gitlab_token: Some("glpat-stored".to_string()),
+        bitbucket_username: Some("acme-bot".to_string()),
+        bitbucket_app_password: Some("app-pass".to_string()),
+        ..AppConfig::default()
+    };
+
+    assert_eq!(
+        HostAuth::resolve(
+            ReleaseHost::GitLab,
+            &config,
+            &env(&[("GITLAB_TOKEN", "glpat-env")])
+        )
+        .unwrap(),
+        HostAuth::Header("PRIVATE-TOKEN", "glpat-env".to_string())
+    );
+    assert_eq!(
+        HostAuth::resolve(
+            ReleaseHost::GitLab,
+            &config,
+            &env(&[("CI_JOB_TOKEN", "job")])
+        )
+        .unwrap(),
+        HostAuth::Header("PRIVATE-TOKEN", "glpat-stored".to_string())
+    );
+    assert_eq!(
+        HostAuth::resolve(
+            ReleaseHost::GitLab,
+            &AppConfig::default(),
+            &env(&[("CI_JOB_TOKEN", "job")])
+        )
+        .unwrap(),
+        HostAuth::Header("JOB-TOKEN", "job".to_string())
+    );
+
+    assert_eq!(
+        HostAuth::resolve(
+            ReleaseHost::Bitbucket,
+            &config,
+            &env(&[("BITBUCKET_APP_PASSWORD", "env-pass")])
+        )
+        .unwrap(),
+        HostAuth::Basic {
+            username: "acme-bot".to_string(),
+            password: "env-pass".to_string()
+        }
+    );
+    assert_eq!(
+        HostAuth::resolve(
+            ReleaseHost::Bitbucket,
+            &config,
+            &env(&[("BITBUCKET_ACCESS_TOKEN", "repo-token")])
+        )
+        .unwrap(),
+        HostAuth::Bearer("repo-token".to_string())
+    );
+
+    let missing = HostAuth::resolve(ReleaseHost::Bitbucket, &AppConfig::default(), &env(&[]));
+    assert!(missing
+        .unwrap_err()
+        .to_string()
+        .contains("BITBUCKET_APP_PASSWORD"));
+    assert!(
+        HostAuth::resolve(ReleaseHost::GitHub, &config, &env(&[("GITHUB_TOKEN", "")])).is_err()
+    );
+}
+
+#[test]
+fn test_api_url_for_self_hosted_gitlab() {
+    let config = AppConfig {
+        gitlab_url: Some("https://gitlab.example.com/".to_string()),
+        ..AppConfig::default()
+    };
+    assert_eq!(
+        api_url(ReleaseHost::GitLab, &AppConfig::default(), &env(&[])),
+        "https://gitlab.com/api/v4"
+    );
+    assert_eq!(
+        api_url(ReleaseHost::GitLab, &config, &env(&[])),
+        "https://gitlab.example.com/api/v4"
+    );
+    assert_eq!(
+        api_url(
+            ReleaseHost::GitLab,
+            &config,
+            &env(&[("CI_API_V4_URL", "https://ci.example.com/api/v4")])
+        ),
+        "https://ci.example.com/api/v4"
+    );
+    assert_eq!(
+        api_url(ReleaseHost::Bitbucket, &config, &env(&[])),
+        "https://api.bitbucket.org/2.0"
+    );
+}
+
+#[test]
+fn test_multipart_body() {
+    let body = multipart_body("b0", "files", "app-v1.0.0-release-notes.md", b"# v1.0.0\n");
+    assert_eq!(
+        String::from_utf8(body).unwrap(),
+        "--b0\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"app-v1.0.0-release-notes.md\"\r\n\
+         Content-Type: text/markdown\r\n\r\n\
+         # v1.0.0\n\r\n--b0--\r\n"
+    );
+}
+
+#[tokio::test]
+async fn test_publish_gitlab_release() {
+    let server = MockServer::start();
+    server.mock(
+        "POST",
+        "/api/v4/projects/acme%2Fplatform%2Fapp/releases",
+        201,
+        r#"{"tag_name": "v1.2.0", "_links": {"self": "https://gitlab.example.com/acme/platform/app/-/releases/v1.2.0"}}"#,
+    );
+
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::GitLab, "acme/platform/app"),
+        &format!("{}/api/v4", server.url()),
+        HostAuth::Header("PRIVATE-TOKEN", "glpat".to_string()),
+    )
+    .unwrap();
+    let url = publisher
+        .publish("v1.2.0", "- feat: search", &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        url,
+        "https://gitlab.example.com/acme/platform/app/-/releases/v1.2.0"
+    );
+
+    let writes = server.writes();
+    assert_eq!(writes.len(), 1);
+    let body: serde_json::Value = serde_json::from_str(&writes[0].body).unwrap();
+    assert_eq!(body["tag_name"], "v1.2.0");
+    assert_eq!(body["description"], "- feat: search");
+
+    // Errors carry GitLab's message
+    server.mock(
+        "POST",
+        "/api/v4/projects/acme%2Fother/releases",
+        409,
+        r#"{"message": "Release already exists"}"#,
+    );
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::GitLab, "acme/other"),
+        &format!("{}/api/v4", server.url()),
+        HostAuth::Header("JOB-TOKEN", "job".to_string()),
+    )
+    .unwrap();
+    let error = publisher.publish("v1.2.0", "notes", &[]).await.unwrap_err();
+    assert!(error.to_string().contains("Release already exists"));
+}
+
+#[tokio::test]
+async fn test_publish_bitbucket_downloads() {
+    let server = MockServer::start();
+    server.mock("POST", "/2.0/repositories/acme/app/downloads", 201, "");
+
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::Bitbucket, "acme/app"),
+        &format!("{}/2.0", server.url()),
+        HostAuth::Basic {
+            username: "acme-bot".to_string(),
+            password: "app-pass".to_string(),
+        },
+    )
+    .unwrap();
+    let url = publisher
+        .publish("v1.2.0", "- fix: crash", &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        url,
+        format!(
+            "{}/acme/app/downloads/app-v1.2.0-release-notes.md",
+            server.url()
+        )
+    );
+
+    let writes = server.writes();
+    assert_eq!(writes.len(), 1);
+    assert!(writes[0]
+        .body
+        .contains("name=\"files\"; filename=\"app-v1.2.0-release-notes.md\""));
+    assert!(writes[0].body.contains("- fix: crash"));
+}
+
+#[tokio::test]
+async fn test_publish_github_release() {
+    let server = MockServer::start();
+    server.mock(
+        "POST",
+        "/repos/acme/app/releases",
+        201,
+        r#"{"html_url": "https://github.com/acme/app/releases/tag/v1.2.0"}"#,
+    );
+
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::GitHub, "acme/app"),
+        &server.url(),
+        HostAuth::Bearer("ghp".to_string()),
+    )
+    .unwrap();
+    let url = publisher.publish("v1.2.0", "notes", &[]).await.unwrap();
+    assert_eq!(url, "https://github.com/acme/app/releases/tag/v1.2.0");
+    let body: serde_json::Value = serde_json::from_str(&server.writes()[0].body).unwrap();
+    assert_eq!(body["body"], "notes");
+}
+
+fn sbom_asset() -> ReleaseAsset {
+    ReleaseAsset {
+        name: "app-v1.2.0-sbom.cdx.json".to_string(),
+        content: br#"{"bomFormat": "CycloneDX"}"#.to_vec(),
+    }
+}
+
+#[tokio::test]
+async fn test_publish_release_assets() {
+    let server = MockServer::start();
+    // GitHub: uploaded to the release's upload_url
+    server.mock(
+        "POST",
+        "/repos/acme/app/releases",
+        201,
+        &format!(
+            r#"{{"html_url": "https://github.com/acme/app/releases/tag/v1.2.0", "upload_url": "{}/uploads/repos/acme/app/releases/7/assets{{?name,label}}"}}"#,
+            server.url()
+        ),
+    );
+    server.mock(
+        "POST",
+        "/uploads/repos/acme/app/releases/7/assets",
+        201,
+        "{}",
+    );
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::GitHub, "acme/app"),
+        &server.url(),
+        HostAuth::Bearer("ghp".to_string()),
+    )
+    .unwrap();
+    publisher
+        .publish("v1.2.0", "notes", &[sbom_asset()])
+        .await
+        .unwrap();
+    let writes = server.writes();
+    assert_eq!(writes.len(), 2);
+    assert_eq!(
+        writes[1].path,
+        "/uploads/repos/acme/app/releases/7/assets?name=app-v1.2.0-sbom.cdx.json"
+    );
+    assert_eq!(writes[1].body, r#"{"bomFormat": "CycloneDX"}"#);
+
+    // GitLab: uploaded to the project, then linked from the release
+    let server = MockServer::start();
+    server.mock(
+        "POST",
+        "/api/v4/projects/acme%2Fapp/releases",
+        201,
+        r#"{"tag_name": "v1.2.0"}"#,
+    );
+    server.mock(
+        "POST",
+        "/api/v4/projects/acme%2Fapp/uploads",
+        201,
+        r#"{"full_path": "/-/project/42/uploads/abc/app-v1.2.0-sbom.cdx.json"}"#,
+    );
+    server.mock(
+        "POST",
+        "/api/v4/projects/acme%2Fapp/releases/v1.2.0/assets/links",
+        201,
+        "{}",
+    );
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::GitLab, "acme/app"),
+        &format!("{}/api/v4", server.url()),
+        HostAuth::Header("PRIVATE-TOKEN", "glpat".to_string()),
+    )
+    .unwrap();
+    publisher
+        .publish("v1.2.0", "notes", &[sbom_asset()])
+        .await
+        .unwrap();
+    let writes = server.writes();
+    assert_eq!(writes.len(), 3);
+    assert!(writes[1].body.contains(
+        "name=\"file\"; filename=\"app-v1.2.0-sbom.cdx.json\"\r\nContent-Type: application/json"
+    ));
+    let link: serde_json::Value = serde_json::from_str(&writes[2].body).unwrap();
+    assert_eq!(link["name"], "app-v1.2.0-sbom.cdx.json");
+    assert_eq!(
+        link["url"],
+        format!(
+            "{}/-/project/42/uploads/abc/app-v1.2.0-sbom.cdx.json",
+            server.url()
+        )
+    );
+
+    // Bitbucket: one more file in Downloads
+    let server = MockServer::start();
+    server.mock("POST", "/2.0/repositories/acme/app/downloads", 201, "");
+    let publisher = ReleasePublisher::new(
+        target(ReleaseHost::Bitbucket, "acme/app"),
+        &format!("{}/2.0", server.url()),
+        HostAuth::Basic {
+            username: "acme-bot".to_string(),
+            password: "app-pass".to_string(),
+        },
+    )
+    .unwrap();
+    publisher
+        .publish("v1.2.0", "notes", &[sbom_asset()])
+        .await
+        .unwrap();
+    let writes = server.writes();
+    assert_eq!(writes.len(), 2);
+    assert!(writes[1]
+        .body
+        .contains("filename=\"app-v1.2.0-sbom.cdx.json\""));
+}
@@ -0,0 +1,110 @@
+use crate::commands::release_notes::{CommitInfo, DiffStats, ReleaseNotesData, RepositoryInfo};
+use crate::commands::release_notes_summary::{
+    insert_summary, parse_summary_reply, summarize, summary_prompt, summary_section, ReleaseSummary,
+};
+use crate::commands::translation_cache::TranslationCache;
+use crate::commands::translation_sync::GeminiProvider;
+use crate::tests::support::MockServer;
+use crate::utils::document_locale::DocumentLocale;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn release_data() -> ReleaseNotesData {
+    let commits: Vec<CommitInfo> = ["feat: add dark mode", "fix: crash on empty config"]
+        .iter()
+        .map(|message| CommitInfo {
+            hash: "a1b2c3d4e5".to_string(),
+            message: message.to_string(),
+            author_name: "Octo Cat".to_string(),
+            author_email: "octocat@example.com".to_string(),
+            timestamp: 1640995200,
+            stats: DiffStats::default(),
+        })
+        .collect();
+    let repo_info = RepositoryInfo {
+        url: "https://github.com/acme/app.git".to_string(),
+        ..Default::default()
+    };
+    ReleaseNotesData::new(
+        &repo_info,
+        "v1.2.0",
+        &Some("v1.1.0".to_string()),
+        &commits,
+        2,
+    )
+}
+
+#[test]
+fn test_summary_prompt_and_reply() {
+    let prompt = summary_prompt(&release_data(), DocumentLocale::De);
+    assert!(prompt.contains("https://github.com/acme/app v1.2.0"));
+    assert!(prompt.contains("Write in German"));
+    assert!(prompt.contains("features:\n- feat: add dark mode\n"));
+    assert!(prompt.contains("fixes:\n- fix: crash on empty config\n"));
+
+    let summary = parse_summary_reply(
+        "```json\n{\"summary\": \"Dark mode arrives.\", \"highlights\": [\"Dark mode\"]}\n```",
+    )
+    .unwrap();
+    assert_eq!(summary.summary, "Dark mode arrives.");
+    assert_eq!(summary.highlights, vec!["Dark mode"]);
+    assert!(parse_summary_reply("Sure! Here is a summary.").is_err());
+    assert!(parse_summary_reply("{\"summary\": \" \"}").is_err());
+}
+
+#[test]
+fn test_summary_section_goes_above_the_commit_list() {
+    let summary = ReleaseSummary {
+        summary: "Dark mode arrives.".to_string(),
+        highlights: vec!["- Dark mode".to_string(), "Fewer crashes".to_string()],
+    };
+    let section = summary_section(&summary, "Gemini", DocumentLocale::En);
+    assert!(section.starts_with("## 🤖 Summary\n\n_Written by Gemini"));
+    assert!(section.contains("Dark mode arrives.\n\n- Dark mode\n- Fewer crashes\n\n---\n\n"));
+
+    let notes = "# 📋 Changes since v1.1.0\n\n- **Total Commits:** 2\n\n## ✨ New Features\n\n- feat: add dark mode\n";
+    let with_summary = insert_summary(notes, &section);
+    assert!(with_summary
+        .starts_with("# 📋 Changes since v1.1.0\n\n- **Total Commits:** 2\n\n## 🤖 Summary"));
+    assert!(with_summary.ends_with("---\n\n## ✨ New Features\n\n- feat: add dark mode\n"));
+
+    // A template without `##` headings gets it at the end
+    assert!(insert_summary("Just commits\n", &section).starts_with("Just commits\n\n## 🤖"));
+}
+
+#[tokio::test]
+async fn test_summarize_caches_replies() {
+    let server = MockServer::start();
+    let reply = "{\"summary\": \"Dark mode arrives.\", \"highlights\": [\"Dark mode\"]}";
+    server.mock(
+        "POST",
+        "/v1beta/models/gemini-1.5-flash:generateContent",
+        200,
+        &json!({"candidates": [{"content": {"parts": [{"text": reply}]}}]}).to_string(),
+    );
+    let provider = GeminiProvider::new("key", "gemini-1.5-flash")
+        .unwrap()
+        .with_base_url(&server.url());
+    let temp_dir = TempDir::new().unwrap();
+    let cache_path = temp_dir.path().join("summary-cache.json");
+    let data = release_data();
+
+    let mut cache = TranslationCache::load(&cache_path);
+    let summary = summarize(&provider, &mut cache, &data, DocumentLocale::En)
+        .await
+        .unwrap();
+    assert_eq!(summary.summary, "Dark mode arrives.");
+    cache.save().unwrap();
+
+    // Same commits, no second request; another locale asks again
+    let mut cache = TranslationCache::load(&cache_path);
+    let cached = summarize(&provider, &mut cache, &data, DocumentLocale::En)
+        .await
+        .unwrap();
+    assert_eq!(cached, summary);
+    assert_eq!(server.requests().len(), 1);
+    summarize(&provider, &mut cache, &data, DocumentLocale::Tr)
+        .await
+        .unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
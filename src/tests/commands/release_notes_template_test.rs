@@ -0,0 +1,99 @@
+use crate::commands::release_notes::{parse_git_url, CommitInfo, DiffStats, RepositoryInfo};
+use crate::commands::release_notes_template::{
+    render_template, template_context, write_example_template, EXAMPLE_TEMPLATE,
+};
+use crate::utils::document_locale::DocumentLocale;
+use std::collections::HashMap;
+use tempfile::tempdir;
+
+fn github_repo() -> RepositoryInfo {
+    let mut info = RepositoryInfo {
+        url: "https://github.com/acme/app.git".to_string(),
+        ..RepositoryInfo::default()
+    };
+    parse_git_url(&mut info, "https://github.com/acme/app.git");
+    info
+}
+
+fn commit(message: &str, author: &str, email: &str) -> CommitInfo {
+    CommitInfo {
+        hash: "a1b2c3d4e5".to_string(),
+        message: message.to_string(),
+        author_name: author.to_string(),
+        author_email: email.to_string(),
+        timestamp: 1640995200,
+        stats: DiffStats::default(),
+    }
+}
+
+fn sample_context(locale: DocumentLocale) -> serde_json::Value {
+    let commits = vec![
+        commit(
+            "fix: crash on empty config (#12)",
+            "Octo Cat",
+            "1+octocat@users.noreply.github.com",
+        ),
+        commit("feat: dark mode", "Jane", "jane@example.com"),
+        commit("feat!: drop node 16", "Jane", "jane@example.com"),
+    ];
+    template_context(
+        &github_repo(),
+        "v2.0.0",
+        &Some("v1.9.0".to_string()),
+        &commits,
+        &HashMap::new(),
+        locale,
+    )
+}
+
+#[test]
+fn test_custom_template_controls_order_and_blocks() {
+    let context = sample_context(DocumentLocale::En);
+
+    // Fixes before features, no contributors block, custom header
+    let template = "# {{repo.name}} {{tag}}\n\
+        {{#each commits.fixes}}* {{this}}\n{{/each}}\
+        {{#each commits.features}}+ {{this}}\n{{/each}}\
+        {{#each contributors}}{{#if github}}@{{github}}{{else}}{{name}}{{/if}}={{commits}};{{/each}}";
+    let notes = render_template(template, &context).unwrap();
+
+    assert_eq!(
+        notes,
+        "# app v2.0.0\n\
+         * fix: crash on empty config ([#12](https://github.com/acme/app/issues/12))\n\
+         + feat: dark mode\n\
+         Jane=2;@octocat=1;"
+    );
+}
+
+#[test]
+fn test_example_template_renders_sections() {
+    let context = sample_context(DocumentLocale::Tr);
+    let notes = render_template(EXAMPLE_TEMPLATE, &context).unwrap();
+
+    assert!(notes.starts_with("# 📋 "));
+    assert!(notes.contains("v1.9.0"));
+    // Breaking changes come first, with their notice
+    let breaking = notes.find("feat!: drop node 16").unwrap();
+    let fixes = notes.find("fix: crash").unwrap();
+    assert!(breaking < fixes);
+    assert!(notes.contains(&DocumentLocale::Tr.t("breaking_notice")));
+    assert!(notes.contains("- [@octocat](https://github.com/octocat) (Octo Cat)"));
+    assert!(notes.contains("https://github.com/acme/app/compare/v1.9.0...v2.0.0"));
+    // Markdown output, nothing is HTML-escaped
+    assert!(!notes.contains("&amp;") && !notes.contains("&#x3D;"));
+}
+
+#[test]
+fn test_template_errors() {
+    let context = sample_context(DocumentLocale::En);
+    let err = render_template("{{#each sections}}", &context).unwrap_err();
+    assert!(err.to_string().contains("Invalid release notes template"));
+
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("notes.hbs");
+    write_example_template(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), EXAMPLE_TEMPLATE);
+    // Never overwrites an existing template
+    assert!(write_example_template(&path).is_err());
+}
@@ -1,5 +1,10 @@
+use crate::commands::commit_filter::CommitFilter;
+use crate::commands::github_api::GitHubApiClient;
 use crate::commands::release_notes::*;
+use crate::tests::support::{fixture, MockServer};
+use crate::utils::document_locale::DocumentLocale;
 use std::fs;
+use std::path::Path;
 use tempfile::TempDir;
 
 #[test]
@@ -73,6 +78,7 @@ fn test_categorize_commits() {
             author_name: "John Doe".to_string(),
             author_email: "john@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
         CommitInfo {
             hash: "def456".to_string(),
@@ -80,6 +86,7 @@ fn test_categorize_commits() {
             author_name: "Jane Smith".to_string(),
             author_email: "jane@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
         CommitInfo {
             hash: "ghi789".to_string(),
@@ -87,6 +94,7 @@ fn test_categorize_commits() {
             author_name: "Bob Wilson".to_string(),
             author_email: "bob@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
         CommitInfo {
             hash: "jkl012".to_string(),
@@ -94,6 +102,7 @@ fn test_categorize_commits() {
             author_name: "Alice Brown".to_string(),
             author_email: "alice@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
     ];
 
@@ -126,6 +135,7 @@ fn test_categorize_commits_simple() {
         author_name: "John Doe".to_string(),
         author_email: "john@example.com".to_string(),
         timestamp: 1640995200,
+        stats: DiffStats::default(),
     }];
 
     let categorized = categorize_commits(&commits);
@@ -143,6 +153,7 @@ fn test_get_contributors_with_stats() {
             author_name: "John Doe".to_string(),
             author_email: "john@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
         CommitInfo {
             hash: "def456".to_string(),
@@ -150,6 +161,7 @@ fn test_get_contributors_with_stats() {
             author_name: "John Doe".to_string(),
             author_email: "john@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
         CommitInfo {
             hash: "ghi789".to_string(),
@@ -157,6 +169,7 @@ fn test_get_contributors_with_stats() {
             author_name: "Jane Smith".to_string(),
             author_email: "jane@example.com".to_string(),
             timestamp: 1640995200,
+            stats: DiffStats::default(),
         },
     ];
 
@@ -174,27 +187,27 @@ fn test_get_contributors_with_stats() {
     assert_eq!(contributors[1].2, 1);
 }
 
-#[test]
-fn test_simple_release_notes_generation() {
+#[tokio::test]
+async fn test_simple_release_notes_generation() {
     let temp_dir = TempDir::new().unwrap();
     let temp_path = temp_dir.path();
 
     // Initialize git repo
     std::process::Command::new("git")
-        .args(&["init"])
+        .args(["init"])
         .current_dir(temp_path)
         .output()
         .expect("Failed to init git repo");
 
     // Configure git
     std::process::Command::new("git")
-        .args(&["config", "user.name", "Test User"])
+        .args(["config", "user.name", "Test User"])
         .current_dir(temp_path)
         .output()
         .unwrap();
 
     std::process::Command::new("git")
-        .args(&["config", "user.email", "test@example.com"])
+        .args(["config", "user.email", "test@example.com"])
         .current_dir(temp_path)
         .output()
         .unwrap();
@@ -203,13 +216,13 @@ fn test_simple_release_notes_generation() {
     fs::write(temp_path.join("README.md"), "# Test Project").unwrap();
 
     std::process::Command::new("git")
-        .args(&["add", "."])
+        .args(["add", "."])
         .current_dir(temp_path)
         .output()
         .unwrap();
 
     std::process::Command::new("git")
-        .args(&["commit", "-m", "feat: initial commit"])
+        .args(["commit", "-m", "feat: initial commit"])
         .current_dir(temp_path)
         .output()
         .unwrap();
@@ -219,7 +232,17 @@ fn test_simple_release_notes_generation() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // This should not panic
-    generate_release_notes();
+    generate_release_notes(
+        Path::new("."),
+        DocumentLocale::default(),
+        true,
+        None,
+        CommitLimits::default(),
+        &CommitFilter::default(),
+        None,
+    )
+    .await
+    .unwrap();
 
     // Safe restore
     if let Err(e) = std::env::set_current_dir(&original_dir) {
@@ -246,3 +269,653 @@ fn test_simple_release_notes_generation() {
         "Release notes file should be created"
     );
 }
+
+fn github_repo() -> RepositoryInfo {
+    let mut info = RepositoryInfo {
+        url: "https://github.com/acme/app.git".to_string(),
+        ..RepositoryInfo::default()
+    };
+    parse_git_url(&mut info, "https://github.com/acme/app.git");
+    info
+}
+
+fn commit(message: &str) -> CommitInfo {
+    CommitInfo {
+        message: message.to_string(),
+        author_name: "Dev".to_string(),
+        author_email: "dev@example.com".to_string(),
+        hash: "abc1234def".to_string(),
+        timestamp: 0,
+        stats: DiffStats::default(),
+    }
+}
+
+#[test]
+fn test_extract_references() {
+    assert_eq!(
+        extract_references("fix: crash on start (#12), see #7 and #12"),
+        vec![12, 7]
+    );
+    assert_eq!(
+        merge_pull_request_number("Merge pull request #42 from octocat/dark-mode"),
+        Some(42)
+    );
+    // Entities, anchors and cross-repo references are not ours
+    assert!(extract_references("docs: &#123; page#3 other/repo#4 #12a #").is_empty());
+    assert_eq!(merge_pull_request_number("Merge branch 'main'"), None);
+}
+
+#[test]
+fn test_merge_and_squash_commits_are_unwrapped() {
+    let squash =
+        "Dashboard rework (#42)\n\n* feat: add charts\n\n* fix(api): timeout on export\n\n\
+                  * update readme\n\n---------\n\nCo-authored-by: Jane <jane@example.com>";
+    assert_eq!(
+        embedded_commits(squash),
+        vec![
+            "feat: add charts (#42)",
+            "fix(api): timeout on export (#42)"
+        ]
+    );
+    assert_eq!(
+        embedded_commits("Merge pull request #7 from acme/docs\n\ndocs: explain setup"),
+        vec!["docs: explain setup (#7)"]
+    );
+    // Regular commits and squashes of a single change stay as they are
+    assert!(embedded_commits("feat: add charts (#42)\n\n* feat: add charts").is_empty());
+    assert!(embedded_commits("fix: typo\n\n- see docs").is_empty());
+    let footer = "Rework (#9)\n\n* feat: new config\n\nBREAKING CHANGE: config moved";
+    assert_eq!(commit_messages(footer), vec![footer]);
+
+    let categorized = categorize_commits(&[
+        commit(squash),
+        commit("Merge pull request #7 from acme/docs\n\ndocs: explain setup"),
+    ]);
+    assert_eq!(categorized.features, vec!["feat: add charts (#42)"]);
+    assert_eq!(categorized.fixes, vec!["fix(api): timeout on export (#42)"]);
+    assert_eq!(categorized.docs.len(), 1);
+    assert!(categorized.others.is_empty());
+}
+
+#[test]
+fn test_link_references() {
+    let repo_info = github_repo();
+    let reference = parse_reference_json(&fixture("github/issue_pull_request.json")).unwrap();
+    let references = std::collections::HashMap::from([(42, reference)]);
+
+    assert_eq!(
+        link_references("fix: crash (#42)", &references, &repo_info),
+        "fix: crash ([#42](https://github.com/acme/app/pull/42) by [@octocat](https://github.com/octocat))"
+    );
+    // Unresolved numbers (offline, no token) still link to the tracker
+    assert_eq!(
+        link_references("fix: ümlaut in #7", &references, &repo_info),
+        "fix: ümlaut in [#7](https://github.com/acme/app/issues/7)"
+    );
+    assert_eq!(
+        link_references("fix: crash (#42)", &references, &RepositoryInfo::default()),
+        "fix: crash (#42)"
+    );
+
+    let commits = apply_pull_request_titles(
+        &[commit(
+            "Merge pull request #42 from octocat/dark-mode\n\nDark mode",
+        )],
+        &references,
+    );
+    assert_eq!(commits[0].message, "feat: add dark mode (#42)");
+    assert_eq!(categorize_commits(&commits).features.len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_references_against_mock_server() {
+    let server = MockServer::start();
+    server.mock(
+        "GET",
+        "/repos/acme/app/issues/42",
+        200,
+        &fixture("github/issue_pull_request.json"),
+    );
+    let mut client = GitHubApiClient::new("acme", "app", "test-token").unwrap();
+    client.api_url = server.url();
+
+    let references = resolve_references(
+        &client,
+        &[commit("feat: dark mode (#42)"), commit("fix: typo (#9)")],
+    )
+    .await;
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[&42].author.as_deref(), Some("octocat"));
+    assert!(references[&42].is_pull_request);
+    assert_eq!(server.requests().len(), 2);
+
+    // Nothing listening: give up after the first lookup
+    drop(server);
+    let offline = resolve_references(&client, &[commit("#1 #2 #3")]).await;
+    assert!(offline.is_empty());
+}
+
+#[test]
+fn test_walk_commits_in_chunks_with_limits() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let tree = repo
+        .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+        .unwrap();
+
+    // One chunk and a bit, every third commit a feature
+    let total = COMMIT_CHUNK_SIZE + 5;
+    let mut parent: Option<git2::Commit> = None;
+    for index in 0..total {
+        let (author, message) = match index % 3 {
+            0 => (
+                ("Jane", "jane@example.com"),
+                format!("feat: feature {}", index),
+            ),
+            _ => (("Dev", "dev@example.com"), format!("fix: bug {}", index)),
+        };
+        let signature =
+            git2::Signature::new(author.0, author.1, &git2::Time::new(index as i64 * 60, 0))
+                .unwrap();
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        let oid = repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                &message,
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        parent = Some(repo.find_commit(oid).unwrap());
+    }
+
+    let mut progress = Vec::new();
+    let limits = CommitLimits {
+        max_commits: Some(10),
+        summary_only: false,
+        submodules: false,
+        by_team: false,
+    };
+    let walk = walk_commits_between_tags(
+        &repo,
+        &None,
+        "HEAD",
+        limits,
+        &CommitFilter::default(),
+        &mut |summary| progress.push(summary.progress_message()),
+    )
+    .unwrap();
+
+    // Reported after every chunk once the range is big enough
+    assert_eq!(
+        progress,
+        vec![
+            "📊 1000 commits processed · features 333 · fixes 667",
+            "📊 1005 commits processed · features 335 · fixes 670",
+        ]
+    );
+    assert!(walk.is_truncated());
+    assert_eq!(walk.commits.len(), 10);
+    // Newest first
+    assert_eq!(walk.commits[0].message, format!("fix: bug {}", total - 1));
+    assert_eq!(walk.summary.total_commits, total);
+    assert_eq!(
+        walk.summary.filled_categories(),
+        vec![("new_features", 335), ("bug_fixes", 670)]
+    );
+    assert_eq!(walk.summary.top_contributors(1)[0].1, "Dev");
+
+    let summary_only = CommitLimits {
+        max_commits: None,
+        summary_only: true,
+
+        submodules: false,
+        by_team: false,
+    };
+    let walk = walk_commits_between_tags(
+        &repo,
+        &None,
+        "HEAD",
+        summary_only,
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+    assert!(walk.commits.is_empty());
+
+    let notes = generate_summary_release_notes(
+        &github_repo(),
+        "v1.0.0",
+        &None,
+        &walk.summary,
+        DocumentLocale::En,
+    );
+    assert!(notes.contains("- **Total Commits:** 1,005\n"));
+    assert!(notes.contains("| ✨ New Features | 335 |\n| 🐛 Bug Fixes | 670 |\n"));
+    assert!(notes.contains("### 👥 Top Contributors"));
+    assert!(!notes.contains("feat: feature"));
+
+    let section =
+        generate_summary_section(&walk.summary, Some(10), &github_repo(), DocumentLocale::En);
+    assert!(section.contains("This range has 1,005 commits; only the latest 10 are listed."));
+}
+
+#[test]
+fn test_release_notes_data_groups_by_category() {
+    assert_eq!(commit_category("feat(api)!: drop v1"), "breaking_changes");
+    assert_eq!(commit_category("feat: add --json"), "features");
+    assert_eq!(commit_category("ci: cache cargo"), "chores");
+    assert_eq!(commit_category("Update README"), "others");
+
+    let commit = |hash: &str, message: &str| CommitInfo {
+        message: message.to_string(),
+        author_name: "Ada".to_string(),
+        author_email: "ada@example.com".to_string(),
+        hash: hash.to_string(),
+        timestamp: 1_700_000_000,
+        stats: DiffStats::default(),
+    };
+    let commits = vec![
+        commit("c3", "fix: crash on empty tag\n\nDetails"),
+        commit("c2", "feat: add --json"),
+        commit("c1", "fix: typo"),
+    ];
+    let repo_info = RepositoryInfo {
+        url: "https://github.com/acme/app".to_string(),
+        ..RepositoryInfo::default()
+    };
+
+    let data = ReleaseNotesData::new(
+        &repo_info,
+        "v1.1.0",
+        &Some("v1.0.0".to_string()),
+        &commits,
+        5,
+    );
+    let json = serde_json::to_value(&data).unwrap();
+    assert_eq!(json["tag"], "v1.1.0");
+    assert_eq!(json["previous_tag"], "v1.0.0");
+    assert_eq!(json["total_commits"], 5);
+    assert_eq!(
+        json["categories"]["fixes"][0]["subject"],
+        "fix: crash on empty tag"
+    );
+    assert_eq!(json["categories"]["fixes"][1]["hash"], "c1");
+    assert_eq!(json["categories"]["features"][0]["author"], "Ada");
+    assert!(json["categories"].get("docs").is_none());
+}
+
+#[test]
+fn test_change_statistics_between_tags() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=Dev", "-c", "user.email=dev@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    };
+
+    git(&["init", "-q"]);
+    fs::write(root.join("README.md"), "# App\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-qm", "chore: initial"]);
+    git(&["tag", "v1.0.0"]);
+
+    fs::create_dir_all(root.join("src/api")).unwrap();
+    fs::write(root.join("src/api/client.rs"), "a\nb\nc\n").unwrap();
+    fs::write(root.join("README.md"), "# App 2\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-qm", "feat: api client"]);
+    git(&["tag", "v1.1.0"]);
+
+    let repo = git2::Repository::open(root).unwrap();
+    let previous = Some("v1.0.0".to_string());
+    let commits = get_commits_between_tags(&repo, &previous, "v1.1.0").unwrap();
+    assert_eq!(
+        commits[0].stats,
+        DiffStats {
+            files_changed: 2,
+            insertions: 4,
+            deletions: 1
+        }
+    );
+
+    let changes = range_change_statistics(&repo, &previous, "v1.1.0").unwrap();
+    assert_eq!(changes.totals, commits[0].stats);
+    assert_eq!(changes.files[0].path, "src/api/client.rs");
+    assert_eq!(
+        changes.top_directories(5),
+        vec![("src/api".to_string(), 1, 3), (".".to_string(), 1, 2)]
+    );
+
+    let section = generate_change_statistics_section(&changes, DocumentLocale::En);
+    assert!(section.starts_with("## 📈 Change Statistics\n\n- **Files changed:** 2\n"));
+    assert!(section.contains("| `src/api/client.rs` | +3 | -0 |\n"));
+    assert!(section.contains("| `src/api` | 1 | 3 |\n"));
+    assert!(
+        generate_change_statistics_section(&ChangeStatistics::default(), DocumentLocale::En)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_submodule_updates_between_tags() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=Dev", "-c", "user.email=dev@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    };
+    let sha = |digit: &str| digit.repeat(40);
+    let gitlink = |digit: &str, path: &str| format!("160000,{},{}", sha(digit), path);
+
+    git(&["init", "-q"]);
+    fs::write(
+        root.join(".gitmodules"),
+        "[submodule \"core\"]\n\tpath = libs/core\n\turl = https://github.com/acme/core.git\n\
+         [submodule \"new\"]\n\tpath = libs/new\n\turl = git@github.com:acme/new.git\n",
+    )
+    .unwrap();
+    git(&["add", ".gitmodules"]);
+    git(&[
+        "update-index",
+        "--add",
+        "--cacheinfo",
+        &gitlink("1", "libs/core"),
+    ]);
+    git(&[
+        "update-index",
+        "--add",
+        "--cacheinfo",
+        &gitlink("3", "vendor/old"),
+    ]);
+    git(&["commit", "-qm", "chore: add submodules"]);
+    git(&["tag", "v1.0.0"]);
+
+    git(&["update-index", "--cacheinfo", &gitlink("2", "libs/core")]);
+    git(&[
+        "update-index",
+        "--add",
+        "--cacheinfo",
+        &gitlink("4", "libs/new"),
+    ]);
+    git(&["update-index", "--force-remove", "vendor/old"]);
+    git(&["commit", "-qm", "chore: bump submodules"]);
+    git(&["tag", "v1.1.0"]);
+
+    let repo = git2::Repository::open(root).unwrap();
+    let previous = Some("v1.0.0".to_string());
+    let changes = range_submodule_changes(&repo, &previous, "v1.1.0").unwrap();
+    let moved: Vec<(&str, Option<String>, Option<String>)> = changes
+        .iter()
+        .map(|change| (change.path.as_str(), change.old.clone(), change.new.clone()))
+        .collect();
+    assert_eq!(
+        moved,
+        vec![
+            ("libs/core", Some(sha("1")), Some(sha("2"))),
+            ("libs/new", None, Some(sha("4"))),
+            ("vendor/old", Some(sha("3")), None),
+        ]
+    );
+    assert_eq!(
+        changes[0].url.as_deref(),
+        Some("https://github.com/acme/core.git")
+    );
+
+    let section = generate_submodule_section(&changes, DocumentLocale::En);
+    assert_eq!(
+        section,
+        "## 📦 Submodule Updates\n\n\
+         - `libs/core`: [`1111111` → `2222222`](https://github.com/acme/core/compare/1111111111111111111111111111111111111111...2222222222222222222222222222222222222222)\n\
+         - `libs/new`: added at `4444444`\n\
+         - `vendor/old`: removed (was `3333333`)\n\n"
+    );
+    assert!(generate_submodule_section(&[], DocumentLocale::En).is_empty());
+
+    // Only collected when asked for
+    let limits = CommitLimits {
+        submodules: true,
+        ..CommitLimits::default()
+    };
+    let walk = walk_commits_between_tags(
+        &repo,
+        &previous,
+        "v1.1.0",
+        limits,
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+    assert_eq!(walk.summary.submodules, changes);
+    let walk = walk_commits_between_tags(
+        &repo,
+        &previous,
+        "v1.1.0",
+        CommitLimits::default(),
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+    assert!(walk.summary.submodules.is_empty());
+}
+
+#[test]
+fn test_changes_by_team_from_codeowners() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=Dev", "-c", "user.email=dev@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    };
+    let commit = |path: &str, message: &str| {
+        let file = root.join(path);
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, message).unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-qm", message]);
+    };
+
+    git(&["init", "-q"]);
+    fs::create_dir(root.join(".github")).unwrap();
+    fs::write(
+        root.join(".github/CODEOWNERS"),
+        "# Frontend\n/web/ @org/web\n\n# Backend\n/api/ @org/api\n",
+    )
+    .unwrap();
+    commit("README.md", "docs: readme");
+    git(&["tag", "v1.0.0"]);
+    commit("web/app.ts", "feat: new page");
+    commit("web/style.css", "fix: colors");
+    commit("api/server.rs", "feat: endpoint");
+    commit("notes.txt", "chore: notes");
+
+    let repo = git2::Repository::open(root).unwrap();
+    let limits = CommitLimits {
+        by_team: true,
+        ..CommitLimits::default()
+    };
+    let walk = walk_commits_between_tags(
+        &repo,
+        &Some("v1.0.0".to_string()),
+        "HEAD",
+        limits,
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+    let teams = walk.summary.teams.unwrap();
+    assert_eq!(teams.unowned, 1);
+
+    assert_eq!(
+        generate_team_section(&teams, DocumentLocale::En),
+        "## 👥 Changes by Team\n\n\
+         - **Frontend** (owned by @org/web): 2 commits\n\
+         - **Backend** (owned by @org/api): 1 commits\n\
+         - Unowned: 1 commits\n\n"
+    );
+
+    // Only collected when asked for
+    let walk = walk_commits_between_tags(
+        &repo,
+        &Some("v1.0.0".to_string()),
+        "HEAD",
+        CommitLimits::default(),
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+    assert!(walk.summary.teams.is_none());
+}
+
+#[test]
+fn test_reverts_and_cherry_picks_are_left_out() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=Dev", "-c", "user.email=dev@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+    let commit = |path: &str, message: &str| {
+        fs::write(root.join(path), message).unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-qm", message]);
+        git(&["rev-parse", "HEAD"])
+    };
+
+    git(&["init", "-q", "-b", "main"]);
+    commit("README.md", "docs: readme");
+    git(&["tag", "v1.0.0"]);
+    // The fix shipped in a patch release first
+    git(&["checkout", "-qb", "release-1.0"]);
+    let fix = commit("crash.txt", "fix: crash");
+    git(&["tag", "v1.0.1"]);
+    git(&["checkout", "-q", "main"]);
+    git(&["cherry-pick", "-x", &fix]);
+
+    let search = commit("search.rs", "feat: search");
+    commit("typo.txt", "fix: typo");
+    git(&["revert", "--no-edit", &search]);
+    commit("export.rs", "feat: export");
+    commit("export.rs", "revert: feat: export");
+
+    // The same change on a topic branch merged back
+    git(&["checkout", "-qb", "topic"]);
+    git(&["checkout", "-q", "main"]);
+    let guide = commit("guide.md", "docs: guide");
+    git(&["checkout", "-q", "topic"]);
+    commit("topic.txt", "test: topic");
+    git(&["cherry-pick", &guide]);
+    git(&["checkout", "-q", "main"]);
+    git(&[
+        "merge",
+        "-q",
+        "--no-ff",
+        "-m",
+        "Merge branch topic",
+        "topic",
+    ]);
+
+    let repo = git2::Repository::open(root).unwrap();
+    let walk = walk_commits_between_tags(
+        &repo,
+        &Some("v1.0.1".to_string()),
+        "HEAD",
+        CommitLimits::default(),
+        &CommitFilter::default(),
+        &mut |_| {},
+    )
+    .unwrap();
+
+    let mut subjects: Vec<&str> = walk
+        .commits
+        .iter()
+        .map(|commit| commit.message.lines().next().unwrap())
+        .collect();
+    subjects.sort();
+    assert_eq!(
+        subjects,
+        vec![
+            "Merge branch topic",
+            "docs: guide",
+            "fix: typo",
+            "test: topic"
+        ]
+    );
+    assert_eq!(walk.duplicates, 2);
+
+    let mut reverted = walk.summary.reverted.clone();
+    reverted.sort_by(|a, b| a.subject.cmp(&b.subject));
+    assert_eq!(reverted.len(), 2);
+    assert_eq!(reverted[0].subject, "feat: export");
+    assert_eq!(reverted[1].subject, "feat: search");
+    assert_eq!(reverted[1].hash, search);
+
+    let section = generate_reverted_section(&reverted[1..], DocumentLocale::En);
+    assert_eq!(
+        section,
+        format!(
+            "## ⏪ Reverted\n\n- feat: search (`{}`, reverted in `{}`)\n\n",
+            &search[..7],
+            &reverted[1].revert_hash[..7]
+        )
+    );
+}
+
+#[test]
+fn test_tag_range_for_past_release() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+    let tree = repo
+        .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+        .unwrap();
+    let oid = repo
+        .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+    let commit = repo.find_commit(oid).unwrap();
+    for tag in ["v1.0.0", "v1.10.0", "v1.2.0", "nightly"] {
+        repo.tag_lightweight(tag, commit.as_object(), false)
+            .unwrap();
+    }
+
+    assert_eq!(
+        get_tag_range_for(&repo, "v1.10.0").unwrap(),
+        ("v1.10.0".to_string(), Some("v1.2.0".to_string()))
+    );
+    assert_eq!(
+        get_tag_range_for(&repo, "v1.2.0").unwrap(),
+        ("v1.2.0".to_string(), Some("v1.0.0".to_string()))
+    );
+    assert_eq!(get_tag_range_for(&repo, "v1.0.0").unwrap().1, None);
+    assert!(get_tag_range_for(&repo, "v2.0.0")
+        .unwrap_err()
+        .to_string()
+        .contains("not found"));
+    assert_eq!(
+        tag_range(&repo, None).unwrap(),
+        ("v1.10.0".to_string(), Some("v1.2.0".to_string()))
+    );
+}
@@ -0,0 +1,66 @@
+use crate::commands::release_notes_translation::{
+    localized_path, protect_markdown, restore_markdown, split_chunks, translate_markdown,
+};
+use crate::commands::translation_sync::{GeminiProvider, Language};
+use crate::tests::support::MockServer;
+use serde_json::json;
+use std::path::Path;
+
+const NOTES: &str = "## 🚀 Features\n\n\
+- Add `--json` output ([#12](https://github.com/acme/app/pull/12))\n\n\
+```bash\nnitroterm status --json\n```\n";
+
+#[test]
+fn test_protect_and_restore_markdown() {
+    let (protected, originals) = protect_markdown(NOTES);
+    assert_eq!(
+        protected,
+        "## 🚀 Features\n\n- Add %%NT0%% output ([#12](%%NT1%%))\n\n%%NT2%%\n"
+    );
+    assert_eq!(originals[1], "https://github.com/acme/app/pull/12");
+    assert_eq!(restore_markdown(&protected, &originals).unwrap(), NOTES);
+
+    let err = restore_markdown("## Özellikler\n\n%%NT0%% %%NT1%%", &originals).unwrap_err();
+    assert!(err.to_string().contains("%%NT2%%"));
+
+    let chunks = split_chunks("# A\none\ntwo\n# B\nthree\n", 10);
+    assert_eq!(chunks, vec!["# A\none\n", "two\n# B\n", "three\n"]);
+    assert_eq!(chunks.concat(), "# A\none\ntwo\n# B\nthree\n");
+
+    assert_eq!(
+        localized_path(Path::new("ReleaseNotes_v1.2.0_20240101.md"), "tr"),
+        Path::new("ReleaseNotes_v1.2.0_20240101_tr.md")
+    );
+}
+
+#[tokio::test]
+async fn test_translate_markdown_keeps_code_and_links() {
+    let server = MockServer::start();
+    // The model wraps its reply in a fence and keeps the tokens
+    let reply = "```markdown\n## 🚀 Özellikler\n\n- %%NT0%% çıktısı eklendi ([#12](%%NT1%%))\n\n%%NT2%%\n```";
+    server.mock(
+        "POST",
+        "/v1beta/models/gemini-1.5-flash:generateContent",
+        200,
+        &json!({"candidates": [{"content": {"parts": [{"text": reply}]}}]}).to_string(),
+    );
+    let provider = GeminiProvider::new("key", "gemini-1.5-flash")
+        .unwrap()
+        .with_base_url(&server.url());
+
+    let translated = translate_markdown(&provider, NOTES, &Language::from_code("tr"))
+        .await
+        .unwrap();
+    assert_eq!(
+        translated,
+        "## 🚀 Özellikler\n\n\
+         - `--json` çıktısı eklendi ([#12](https://github.com/acme/app/pull/12))\n\n\
+         ```bash\nnitroterm status --json\n```\n"
+    );
+
+    // Code never reaches the provider
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].body.contains("Turkish"));
+    assert!(!requests[0].body.contains("nitroterm status"));
+}
@@ -0,0 +1,150 @@
+use crate::commands::release_preflight::{
+    check_clean_tree, ci_status_from_runs, enforce_preflight, run_preflight, PreflightStatus,
+};
+use crate::tests::support::MockRunner;
+
+fn statuses(runner: &MockRunner, branch: Option<&str>) -> Vec<(&'static str, PreflightStatus)> {
+    run_preflight(runner, "v1.2.0", branch)
+        .into_iter()
+        .map(|check| (check.name, check.status))
+        .collect()
+}
+
+#[test]
+fn test_preflight_passes_on_clean_release_branch() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git rev-parse --abbrev-ref HEAD", "main\n")
+        .fail("git rev-parse --verify --quiet refs/tags/v1.2.0", "")
+        .respond("git rev-parse HEAD", "abc123\n")
+        .respond(
+            "gh run list --commit abc123",
+            r#"[{"name": "CI", "status": "completed", "conclusion": "success"},
+                {"name": "Docs", "status": "completed", "conclusion": "skipped"}]"#,
+        );
+
+    let checks = run_preflight(&runner, "v1.2.0", Some("main"));
+    assert!(checks
+        .iter()
+        .all(|check| check.status == PreflightStatus::Passed));
+    assert_eq!(checks.len(), 6);
+    assert_eq!(checks[5].detail, "2 workflow run(s) green");
+    assert!(runner
+        .calls()
+        .contains(&"git ls-remote --tags origin refs/tags/v1.2.0".to_string()));
+    assert!(enforce_preflight(&checks, false).is_ok());
+}
+
+#[test]
+fn test_preflight_failures_block_unless_forced() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git status --porcelain", " M src/main.rs\n?? notes.txt\n")
+        .respond("git rev-parse --abbrev-ref HEAD", "feature/login\n")
+        .respond(
+            "git rev-parse --verify --quiet refs/tags/v1.2.0",
+            "abc123\n",
+        )
+        .respond("git ls-remote --tags origin", "abc123\trefs/tags/v1.2.0\n")
+        .respond("git rev-parse HEAD", "abc123\n")
+        .respond(
+            "gh run list",
+            r#"[{"name": "CI", "status": "in_progress", "conclusion": ""}]"#,
+        );
+
+    let checks = run_preflight(&runner, "v1.2.0", Some("main"));
+    assert_eq!(
+        checks
+            .iter()
+            .map(|check| (check.name, check.status))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Working tree clean", PreflightStatus::Failed),
+            ("Release branch", PreflightStatus::Failed),
+            ("Tag is new locally", PreflightStatus::Failed),
+            ("Remote reachable", PreflightStatus::Passed),
+            ("Tag is new on origin", PreflightStatus::Failed),
+            ("CI green on HEAD", PreflightStatus::Failed),
+        ]
+    );
+    assert_eq!(
+        checks[0].detail,
+        "2 uncommitted file(s), commit or stash them"
+    );
+    assert_eq!(
+        checks[1].detail,
+        "on feature/login, releases are cut from main"
+    );
+    assert_eq!(checks[5].detail, "still running: CI");
+
+    let error = enforce_preflight(&checks, false).unwrap_err().to_string();
+    assert!(error.contains("Working tree clean, Release branch"));
+    assert!(error.contains("--force"));
+    assert!(enforce_preflight(&checks, true).is_ok());
+}
+
+#[test]
+fn test_own_cache_files_keep_the_tree_clean() {
+    let runner = MockRunner::new();
+    runner.respond(
+        "git status --porcelain",
+        "?? .nitrokit/state.json\n?? .nitrokit/translation-cache.json\n",
+    );
+    assert_eq!(check_clean_tree(&runner).status, PreflightStatus::Passed);
+    assert_eq!(
+        runner.calls(),
+        vec!["git status --porcelain --untracked-files=all"]
+    );
+
+    // Project settings in .nitrokit/ still count
+    let runner = MockRunner::new();
+    runner.respond(
+        "git status --porcelain",
+        "?? .nitrokit/state.json\n?? .nitrokit/policy.json\n",
+    );
+    let check = check_clean_tree(&runner);
+    assert_eq!(check.status, PreflightStatus::Failed);
+    assert_eq!(check.detail, "1 uncommitted file(s), commit or stash them");
+}
+
+#[test]
+fn test_unreachable_remote_and_missing_gh_only_warn_where_unknown() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git rev-parse --abbrev-ref HEAD", "main\n")
+        .fail("git rev-parse --verify", "")
+        .fail(
+            "git ls-remote",
+            "fatal: 'origin' does not appear to be a git repository\n",
+        )
+        .fail("gh run list", "no git remotes found");
+
+    assert_eq!(
+        statuses(&runner, None),
+        vec![
+            ("Working tree clean", PreflightStatus::Passed),
+            ("Release branch", PreflightStatus::Warning),
+            ("Tag is new locally", PreflightStatus::Passed),
+            ("Remote reachable", PreflightStatus::Failed),
+            ("Tag is new on origin", PreflightStatus::Warning),
+            ("CI green on HEAD", PreflightStatus::Warning),
+        ]
+    );
+}
+
+#[test]
+fn test_ci_status_from_runs() {
+    assert_eq!(
+        ci_status_from_runs("[]").unwrap().0,
+        PreflightStatus::Warning
+    );
+    let (status, detail) = ci_status_from_runs(
+        r#"[{"name": "CI", "status": "completed", "conclusion": "failure"},
+            {"name": "Lint", "status": "completed", "conclusion": "cancelled"},
+            {"name": "Release", "status": "queued", "conclusion": ""}]"#,
+    )
+    .unwrap();
+    assert_eq!(status, PreflightStatus::Failed);
+    assert_eq!(detail, "failed: CI, Lint");
+    assert!(ci_status_from_runs("not json").is_err());
+}
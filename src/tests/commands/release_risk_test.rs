@@ -0,0 +1,98 @@
+use crate::commands::release_risk::{
+    calculate_risk_score, count_major_dependency_bumps, is_major_bump, RiskLevel, RiskSignals,
+};
+
+#[test]
+fn test_low_risk_release() {
+    let signals = RiskSignals {
+        commits: 3,
+        files_changed: 2,
+        lines_changed: 40,
+        days_since_last_release: Some(7),
+        ..Default::default()
+    };
+
+    let (factors, score, level) = calculate_risk_score(&signals);
+    assert_eq!(factors.len(), 5);
+    assert_eq!(score, 0);
+    assert_eq!(level, RiskLevel::Low);
+}
+
+#[test]
+fn test_high_risk_release() {
+    let signals = RiskSignals {
+        breaking_changes: 3,
+        commits: 250,
+        files_changed: 120,
+        lines_changed: 12_000,
+        major_dependency_bumps: 2,
+        failing_checks: 1,
+        days_since_last_release: Some(200),
+    };
+
+    let (factors, score, level) = calculate_risk_score(&signals);
+    assert_eq!(score, 95);
+    assert_eq!(level, RiskLevel::High);
+
+    let breaking = factors
+        .iter()
+        .find(|f| f.name == "Breaking changes")
+        .unwrap();
+    assert_eq!(breaking.points, 40);
+}
+
+#[test]
+fn test_never_released_adds_points() {
+    let (_, score, _) = calculate_risk_score(&RiskSignals::default());
+    assert_eq!(score, 5);
+}
+
+#[test]
+fn test_is_major_bump() {
+    assert!(is_major_bump("1.2.3", "2.0.0"));
+    assert!(is_major_bump("^16.8.0", "^18.2.0"));
+    assert!(is_major_bump("0.11", "0.12.17"));
+    assert!(!is_major_bump("1.2.3", "1.9.0"));
+    assert!(!is_major_bump("0.4.1", "0.4.9"));
+    assert!(!is_major_bump("*", "2.0.0"));
+}
+
+#[test]
+fn test_count_major_bumps_in_cargo_toml() {
+    let old = r#"
+[package]
+name = "demo"
+version = "1.0.0"
+
+[dependencies]
+clap = { version = "3.2", features = ["derive"] }
+serde = "1.0"
+reqwest = "0.11"
+
+[dev-dependencies]
+tempfile = "2.0"
+"#;
+    let new = r#"
+[package]
+name = "demo"
+version = "2.0.0"
+
+[dependencies]
+clap = { version = "4.0", features = ["derive"] }
+serde = "1.0.190"
+reqwest = "0.12"
+
+[dev-dependencies]
+tempfile = "3.0"
+"#;
+
+    assert_eq!(count_major_dependency_bumps("Cargo.toml", old, new), 3);
+}
+
+#[test]
+fn test_count_major_bumps_in_package_json() {
+    let old = r#"{"dependencies": {"react": "^17.0.2", "lodash": "^4.17.20"}}"#;
+    let new = r#"{"dependencies": {"react": "^18.2.0", "lodash": "^4.17.21", "zod": "^3.0.0"}}"#;
+
+    assert_eq!(count_major_dependency_bumps("package.json", old, new), 1);
+}
@@ -0,0 +1,124 @@
+use crate::commands::releases::{
+    expand_test_command, resolve_repo, select_asset, sort_releases, Bisection, FetchedRelease,
+    PublishedRelease, ReleaseAsset, ReleasesClient,
+};
+use crate::tests::support::{fixture, MockServer};
+use std::path::PathBuf;
+
+fn asset(name: &str) -> ReleaseAsset {
+    ReleaseAsset {
+        name: name.to_string(),
+        browser_download_url: format!("https://example.com/{}", name),
+        size: 0,
+    }
+}
+
+fn release(tag: &str, prerelease: bool) -> PublishedRelease {
+    PublishedRelease {
+        tag_name: tag.to_string(),
+        draft: false,
+        prerelease,
+        assets: Vec::new(),
+    }
+}
+
+#[test]
+fn test_resolve_repo_from_argument() {
+    assert_eq!(
+        resolve_repo(Some("acme/widget")).unwrap(),
+        ("acme".to_string(), "widget".to_string())
+    );
+    assert!(resolve_repo(Some("widget")).is_err());
+}
+
+#[test]
+fn test_select_asset_for_platform() {
+    let assets = vec![
+        asset("app-x86_64-unknown-linux-gnu.tar.gz"),
+        asset("app-x86_64-unknown-linux-gnu.tar.gz.sha256"),
+        asset("app-aarch64-apple-darwin.tar.gz"),
+        asset("app-x86_64-apple-darwin.tar.gz"),
+        asset("app-x86_64-pc-windows-msvc.zip"),
+    ];
+
+    let linux = select_asset(&assets, &["linux"], &["x86_64", "amd64"]).unwrap();
+    assert_eq!(linux.name, "app-x86_64-unknown-linux-gnu.tar.gz");
+
+    let mac = select_asset(&assets, &["macos", "darwin"], &["aarch64", "arm64"]).unwrap();
+    assert_eq!(mac.name, "app-aarch64-apple-darwin.tar.gz");
+
+    // A single OS match is used even without an architecture in the name
+    let only_os = vec![asset("app-windows.exe")];
+    assert!(select_asset(&only_os, &["windows"], &["aarch64"]).is_some());
+    assert!(select_asset(&assets, &["freebsd"], &["x86_64"]).is_none());
+}
+
+#[test]
+fn test_sort_releases_skips_prereleases() {
+    let sorted = sort_releases(vec![
+        release("v1.10.0", false),
+        release("v1.2.0", false),
+        release("v2.0.0-beta.1", true),
+        release("nightly", false),
+        release("v1.9.1", false),
+    ]);
+
+    let tags: Vec<_> = sorted.iter().map(|r| r.tag_name.as_str()).collect();
+    assert_eq!(tags, vec!["v1.2.0", "v1.9.1", "v1.10.0"]);
+}
+
+#[test]
+fn test_bisection_finds_first_bad() {
+    // Releases 0..=9, broken from index 6 on
+    let mut bisection = Bisection::new(0, 9).unwrap();
+    assert_eq!(bisection.remaining_steps(), 4);
+
+    let mut steps = 0;
+    while let Some(index) = bisection.next() {
+        bisection.mark(index, index < 6);
+        steps += 1;
+    }
+
+    assert_eq!(bisection, Bisection { good: 5, bad: 6 });
+    assert!(steps <= 4);
+    assert!(Bisection::new(3, 3).is_err());
+}
+
+#[test]
+fn test_expand_test_command() {
+    let fetched = FetchedRelease {
+        version: "v1.2.0".to_string(),
+        asset: PathBuf::from("/cache/v1.2.0/app.tar.gz"),
+        dir: PathBuf::from("/cache/v1.2.0"),
+    };
+
+    assert_eq!(
+        expand_test_command("{dir}/app --version | grep {version}", &fetched),
+        "/cache/v1.2.0/app --version | grep v1.2.0"
+    );
+}
+
+#[tokio::test]
+async fn test_list_releases_from_recorded_fixture() {
+    let server = MockServer::start();
+    server.mock(
+        "GET",
+        "/repos/acme/app/releases",
+        200,
+        &fixture("github/releases.json"),
+    );
+    let mut client = ReleasesClient::new().unwrap();
+    client.api_url = server.url();
+
+    let releases = sort_releases(client.list_releases("acme", "app").await.unwrap());
+    let tags: Vec<&str> = releases.iter().map(|r| r.tag_name.as_str()).collect();
+    assert_eq!(tags, vec!["v1.0.0", "v1.1.0"]);
+    assert_eq!(releases[1].assets[0].size, 3145728);
+    assert_eq!(
+        server.requests()[0].path,
+        "/repos/acme/app/releases?per_page=100&page=1"
+    );
+
+    client.api_url = format!("{}/missing", server.url());
+    assert!(client.list_releases("acme", "app").await.is_err());
+}
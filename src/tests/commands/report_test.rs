@@ -0,0 +1,173 @@
+use crate::commands::code_quality::{CheckResult, PackageManager, ProjectInfo, ProjectType};
+use crate::commands::config::AppConfig;
+use crate::commands::release_notes::{CommitInfo, DiffStats};
+use crate::commands::report::{
+    collect_report, html_escape, render_html, svg_bar_chart, weekly_activity, ProjectReport,
+    ReportOptions,
+};
+use git2::{Repository, Signature};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn commit_info(message: &str) -> CommitInfo {
+    CommitInfo {
+        message: message.to_string(),
+        author_name: "Jane".to_string(),
+        author_email: "jane@acme.dev".to_string(),
+        hash: "abc1234".to_string(),
+        timestamp: 0,
+        stats: DiffStats::default(),
+    }
+}
+
+#[test]
+fn test_weekly_activity() {
+    let now = 100 * 7 * 86_400;
+    let week = 7 * 86_400;
+    let activity = weekly_activity(&[now, now - 10, now - week - 1, now - 20 * week], now, 4);
+
+    let counts: Vec<usize> = activity.iter().map(|(_, count)| *count).collect();
+    assert_eq!(counts, vec![0, 0, 1, 2]);
+}
+
+#[test]
+fn test_svg_bar_chart_escapes_labels() {
+    let chart = svg_bar_chart(
+        &[("<b>tr</b>".to_string(), 50.0), ("de".to_string(), 100.0)],
+        100.0,
+        "#10b981",
+    );
+
+    assert!(chart.starts_with("<svg"));
+    assert!(chart.contains("&lt;b&gt;tr&lt;/b&gt;"));
+    assert!(chart.contains("width=\"210.0\""));
+    assert!(chart.contains("width=\"420.0\""));
+    assert_eq!(html_escape("a & \"b\""), "a &amp; &quot;b&quot;");
+}
+
+#[test]
+fn test_render_html_sections() {
+    let report = ProjectReport {
+        project_name: "widget".to_string(),
+        generated_at: "2026-01-01 10:00".to_string(),
+        health: vec![("Branch".to_string(), "main".to_string())],
+        last_tag: Some("v1.0.0".to_string()),
+        unreleased: vec![
+            commit_info("feat: add <script> export"),
+            commit_info("fix: crash"),
+        ],
+        checks: Some(vec![CheckResult {
+            check_name: "lint".to_string(),
+            command: "cargo clippy".to_string(),
+            success: false,
+            output: String::new(),
+            error: None,
+            duration_ms: 10,
+        }]),
+        ..Default::default()
+    };
+
+    let html = render_html(&report);
+
+    for id in [
+        "overview",
+        "release-notes",
+        "dependencies",
+        "code-quality",
+        "translations",
+    ] {
+        assert!(html.contains(&format!("id=\"{}\"", id)));
+        assert!(html.contains(&format!("href=\"#{}\"", id)));
+    }
+    assert!(html.contains("feat: add &lt;script&gt; export"));
+    assert!(!html.contains("<script"));
+    assert!(html.contains("0 of 1 checks passed"));
+    assert!(html.contains("2 commits since v1.0.0"));
+}
+
+#[test]
+fn test_render_html_project_and_check_output() {
+    let check = |name: &str, success: bool, output: &str| CheckResult {
+        check_name: name.to_string(),
+        command: format!("cargo {}", name),
+        success,
+        output: output.to_string(),
+        error: None,
+        duration_ms: 10,
+    };
+    let mut report = ProjectReport {
+        project_name: "widget".to_string(),
+        checks: Some(vec![
+            check("clippy", false, "warning: unused <T>"),
+            check("fmt", true, "formatted"),
+            check("test", true, "  "),
+        ]),
+        ..Default::default()
+    };
+    let html = render_html(&report);
+    assert!(!html.contains("id=\"project\""));
+    assert!(!html.contains("href=\"#project\""));
+    // Failed output starts open, passed output folded, empty output left out
+    assert!(html
+        .contains("<details open><summary>Output</summary><pre>warning: unused &lt;T&gt;</pre>"));
+    assert!(html.contains("<details><summary>Output</summary><pre>formatted</pre>"));
+    assert_eq!(html.matches("<details").count(), 2);
+
+    report.project_info = Some(ProjectInfo {
+        project_type: ProjectType::Rust,
+        package_manager: PackageManager::Cargo,
+        root_path: "/work/widget".into(),
+        config_files: vec![
+            "/work/widget/Cargo.toml".into(),
+            "/work/widget/clippy.toml".into(),
+        ],
+        has_typescript: false,
+        frameworks: Vec::new(),
+    });
+    let html = render_html(&report);
+    assert!(html.contains("href=\"#project\""));
+    assert!(html.contains("<tr><th>Type</th><td>Rust</td></tr>"));
+    assert!(html.contains("<tr><th>Config files</th><td>Cargo.toml, clippy.toml</td></tr>"));
+    assert!(!html.contains("Frameworks"));
+}
+
+#[tokio::test]
+async fn test_collect_report_without_network() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+    fs::write(root.join("README.md"), "# widget").unwrap();
+
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("README.md")).unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("Jane", "jane@acme.dev").unwrap();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        "feat: initial",
+        &tree,
+        &[],
+    )
+    .unwrap();
+
+    fs::create_dir(root.join("messages")).unwrap();
+    fs::write(root.join("messages/source.json"), r#"{"a": "A"}"#).unwrap();
+    fs::write(root.join("messages/tr.json"), r#"{"a": "B"}"#).unwrap();
+
+    let report = collect_report(root, &AppConfig::default(), &ReportOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(report.unreleased.len(), 1);
+    assert_eq!(report.contributors.len(), 1);
+    assert_eq!(report.weekly_commits.last().unwrap().1, 1);
+    assert!(report.checks.is_none());
+    assert!(report.outdated.is_none());
+    assert_eq!(report.translations.unwrap().complete_languages(), 1);
+    assert!(report
+        .health
+        .contains(&("README".to_string(), "yes".to_string())));
+}
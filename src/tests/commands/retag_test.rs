@@ -0,0 +1,96 @@
+use crate::commands::create_release::TagSigning;
+use crate::commands::retag::{rewrite_tag, tag_target};
+use crate::tests::support::MockRunner;
+use tempfile::TempDir;
+
+#[test]
+fn test_tag_target_detects_signatures() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let signature = git2::Signature::now("Dev", "dev@example.com").unwrap();
+    let tree = repo
+        .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+        .unwrap();
+    let oid = repo
+        .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+    let commit = repo.find_commit(oid).unwrap();
+    repo.tag_lightweight("v1.0.0", commit.as_object(), false)
+        .unwrap();
+    repo.tag(
+        "v1.1.0",
+        commit.as_object(),
+        &signature,
+        "Release v1.1.0",
+        false,
+    )
+    .unwrap();
+    repo.tag(
+        "v1.2.0",
+        commit.as_object(),
+        &signature,
+        "Release v1.2.0\n-----BEGIN PGP SIGNATURE-----\n\niQ...\n-----END PGP SIGNATURE-----\n",
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(
+        tag_target(&repo, "v1.0.0").unwrap(),
+        (oid.to_string(), false)
+    );
+    assert_eq!(
+        tag_target(&repo, "v1.1.0").unwrap(),
+        (oid.to_string(), false)
+    );
+    assert_eq!(
+        tag_target(&repo, "v1.2.0").unwrap(),
+        (oid.to_string(), true)
+    );
+    assert!(tag_target(&repo, "v9.0.0").is_err());
+}
+
+#[test]
+fn test_rewrite_tag() {
+    let runner = MockRunner::new();
+    rewrite_tag(
+        &runner,
+        "v1.1.0",
+        "abc123",
+        "# Notes",
+        &TagSigning::None,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        runner.calls(),
+        vec!["git tag -f --cleanup=verbatim -a v1.1.0 abc123 -m # Notes"]
+    );
+
+    let runner = MockRunner::new();
+    rewrite_tag(
+        &runner,
+        "v1.1.0",
+        "abc123",
+        "# Notes",
+        &TagSigning::DefaultKey,
+        true,
+    )
+    .unwrap();
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "git tag -f --cleanup=verbatim -s v1.1.0 abc123 -m # Notes",
+            "git push --force origin refs/tags/v1.1.0",
+        ]
+    );
+
+    let runner = MockRunner::new();
+    runner.fail("git tag", "tag is not a valid tag name");
+    assert!(
+        rewrite_tag(&runner, "v1.1.0", "abc123", "x", &TagSigning::None, true)
+            .unwrap_err()
+            .to_string()
+            .contains("not a valid tag name")
+    );
+    assert_eq!(runner.calls().len(), 1);
+}
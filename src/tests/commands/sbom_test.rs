@@ -0,0 +1,160 @@
+use crate::commands::lockfiles::{Ecosystem, LockedPackage};
+use crate::commands::sbom::{
+    collect_components, cyclonedx_document, generate_sbom, purl, spdx_document, SbomFormat,
+    SbomProject,
+};
+use std::fs;
+use tempfile::TempDir;
+
+const CARGO_LOCK: &str = r#"
+[[package]]
+name = "my-app"
+version = "0.1.0"
+
+[[package]]
+name = "serde"
+version = "1.0.190"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "91d3c334ca1ee894a2c6f6ad698fe8c435b76d504b13d436f0685d648d6d96f7"
+"#;
+
+const PACKAGE_LOCK: &str = r#"{
+    "lockfileVersion": 3,
+    "packages": {
+        "": { "name": "app" },
+        "node_modules/left-pad": {
+            "version": "1.3.0",
+            "integrity": "sha1-qZk+NkcGgWq6PiVxeFDCbJzQ2J0="
+        },
+        "node_modules/local": { "link": true },
+        "node_modules/@acme/ui": { "version": "2.0.0" }
+    }
+}"#;
+
+fn package(name: &str, version: &str, ecosystem: Ecosystem) -> LockedPackage {
+    LockedPackage {
+        name: name.to_string(),
+        version: version.to_string(),
+        ecosystem,
+    }
+}
+
+fn project_dir() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    fs::write(dir.join("Cargo.lock"), CARGO_LOCK).unwrap();
+    fs::write(dir.join("package-lock.json"), PACKAGE_LOCK).unwrap();
+    // Ignored, package-lock.json wins
+    fs::write(
+        dir.join("yarn.lock"),
+        "left-pad@^1.0.0:\n  version \"1.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join("requirements.txt"), "Django==4.2.7\nflask>=2.0\n").unwrap();
+    fs::write(
+        dir.join("requirements-dev.txt"),
+        "pytest==7.4.3 --hash=sha256:aaaa\nDjango==4.2.7\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"my-app\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_purl() {
+    assert_eq!(
+        purl(&package("serde", "1.0.190", Ecosystem::Cargo)),
+        "pkg:cargo/serde@1.0.190"
+    );
+    assert_eq!(
+        purl(&package("@acme/ui", "2.0.0-beta.1", Ecosystem::Npm)),
+        "pkg:npm/%40acme/ui@2.0.0-beta.1"
+    );
+    assert_eq!(
+        purl(&package("Typing_Extensions", "4.8.0", Ecosystem::PyPI)),
+        "pkg:pypi/typing-extensions@4.8.0"
+    );
+    assert_eq!(
+        purl(&package("wasi", "0.11.0+wasi-snapshot", Ecosystem::Cargo)),
+        "pkg:cargo/wasi@0.11.0%2Bwasi-snapshot"
+    );
+    assert_eq!("SPDX".parse::<SbomFormat>().unwrap(), SbomFormat::Spdx);
+    assert!("swid".parse::<SbomFormat>().is_err());
+}
+
+#[test]
+fn test_collect_components_from_lockfiles() {
+    let temp_dir = project_dir();
+    let components = collect_components(temp_dir.path()).unwrap();
+    let purls: Vec<String> = components
+        .iter()
+        .map(|component| purl(&component.package))
+        .collect();
+    assert_eq!(
+        purls,
+        vec![
+            "pkg:cargo/serde@1.0.190",
+            "pkg:npm/%40acme/ui@2.0.0",
+            "pkg:npm/left-pad@1.3.0",
+            "pkg:pypi/django@4.2.7",
+            "pkg:pypi/pytest@7.4.3",
+        ]
+    );
+    assert_eq!(components[0].checksums.len(), 1);
+    assert_eq!(components[4].checksums[0].hex, "aaaa");
+
+    let empty = TempDir::new().unwrap();
+    assert!(generate_sbom(empty.path(), SbomFormat::CycloneDx)
+        .unwrap_err()
+        .to_string()
+        .contains("No packages found"));
+}
+
+#[test]
+fn test_cyclonedx_and_spdx_documents() {
+    let temp_dir = project_dir();
+    let components = collect_components(temp_dir.path()).unwrap();
+    let project = SbomProject {
+        name: "my-app".to_string(),
+        version: Some("0.1.0".to_string()),
+    };
+
+    let bom = cyclonedx_document(&project, &components, "2025-03-01T12:00:00Z");
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.5");
+    assert_eq!(bom["metadata"]["component"]["version"], "0.1.0");
+    assert_eq!(bom["components"].as_array().unwrap().len(), 5);
+    assert_eq!(bom["components"][0]["purl"], "pkg:cargo/serde@1.0.190");
+    assert_eq!(bom["components"][0]["hashes"][0]["alg"], "SHA-256");
+    assert!(bom["components"][1].get("hashes").is_none());
+    // Same lockfiles, same serial number
+    assert_eq!(
+        bom["serialNumber"],
+        cyclonedx_document(&project, &components, "2025-03-02T12:00:00Z")["serialNumber"]
+    );
+    assert_ne!(
+        bom["serialNumber"],
+        cyclonedx_document(&project, &components[1..], "2025-03-01T12:00:00Z")["serialNumber"]
+    );
+
+    let spdx = spdx_document(&project, &components, "2025-03-01T12:00:00Z");
+    assert_eq!(spdx["spdxVersion"], "SPDX-2.3");
+    assert_eq!(spdx["name"], "my-app-0.1.0");
+    let packages = spdx["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 6);
+    assert_eq!(packages[0]["SPDXID"], "SPDXRef-Project");
+    assert_eq!(packages[1]["checksums"][0]["algorithm"], "SHA256");
+    assert_eq!(packages[3]["checksums"][0]["algorithm"], "SHA1");
+    assert_eq!(
+        packages[2]["externalRefs"][0]["referenceLocator"],
+        "pkg:npm/%40acme/ui@2.0.0"
+    );
+    let relationships = spdx["relationships"].as_array().unwrap();
+    assert_eq!(relationships.len(), 6);
+    assert_eq!(relationships[0]["relationshipType"], "DESCRIBES");
+    assert_eq!(relationships[5]["relatedSpdxElement"], "SPDXRef-Package-5");
+}
@@ -0,0 +1,44 @@
+use crate::commands::scaffold::{merge_tasks, problem_matcher, vscode_tasks, TASK_PREFIX};
+use serde_json::json;
+
+#[test]
+fn test_vscode_tasks() {
+    let tasks = vscode_tasks();
+
+    assert!(tasks
+        .iter()
+        .all(|task| task["label"].as_str().unwrap().starts_with(TASK_PREFIX)));
+    let quality = &tasks[0];
+    assert_eq!(quality["command"], "nitroterm");
+    assert_eq!(quality["args"], json!(["code-quality"]));
+    assert_eq!(quality["group"]["isDefault"], true);
+    assert_eq!(quality["problemMatcher"][0], problem_matcher());
+    assert_eq!(problem_matcher()["pattern"]["severity"], 4);
+}
+
+#[test]
+fn test_merge_tasks_keeps_user_tasks() {
+    let existing = json!({
+        "version": "2.0.0",
+        "tasks": [
+            { "label": "build", "type": "shell", "command": "make" },
+            { "label": "nitroterm: stale task", "type": "shell", "command": "nitroterm" },
+        ],
+    });
+
+    let merged = merge_tasks(Some(existing), vscode_tasks()).unwrap();
+    let labels: Vec<&str> = merged["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["label"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(labels[0], "build");
+    assert!(!labels.contains(&"nitroterm: stale task"));
+    assert_eq!(labels.len(), 1 + vscode_tasks().len());
+
+    let fresh = merge_tasks(None, vscode_tasks()).unwrap();
+    assert_eq!(fresh["version"], "2.0.0");
+    assert!(merge_tasks(Some(json!({ "tasks": {} })), vec![]).is_err());
+}
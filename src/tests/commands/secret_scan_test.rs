@@ -0,0 +1,99 @@
+use crate::commands::secret_scan::{run_secrets_check, scan_secrets, scan_text, shannon_entropy};
+use tempfile::TempDir;
+
+// Fixtures are assembled at runtime so the tree itself stays clean for scanners
+fn aws_key() -> String {
+    format!("AKIA{}", "Q3VZ7XK2M4PLR8TB")
+}
+
+fn github_token() -> String {
+    format!("ghp_{}", "x7Kq2Lm9Vb4Nc8Rt1Wy6Pz3Hd5Jf0Gs7Ae2U") // nitroterm:allow-secret
+}
+
+#[test]
+fn test_shannon_entropy() {
+    assert_eq!(shannon_entropy("aaaaaaaa"), 0.0);
+    assert!((shannon_entropy("abcd") - 2.0).abs() < 1e-9);
+    // A hex digest stays below the threshold, a random base64 token doesn't
+    assert!(shannon_entropy("3f786850e387550fdab836ed7e6dc881de23001b") <= 4.0);
+    assert!(shannon_entropy("q8Zr3Tn7Wk1Xv5Bm9Ly2Hc6Jd4Fg0Ps8Ua3Ne7Qi") > 4.5); // nitroterm:allow-secret
+}
+
+#[test]
+fn test_scan_text_rules() {
+    let content = format!(
+        "const region = \"eu-west-1\";\n\
+         aws_access_key_id = {}\n\
+         -----BEGIN RSA {}-----\n\
+         token: \"{}\"\n\
+         password = \"changeme\"\n\
+         api_key = \"q8Zr3Tn7Wk1Xv5Bm9Ly2\"\n\
+         commit = \"3f786850e387550fdab836ed7e6dc881de23001b\"\n\
+         integrity = \"sha512-q8Zr3Tn7Wk1Xv5Bm9Ly2Hc6Jd4Fg0Ps8Ua3Ne7Qi==\"\n\
+         author_email = \"jane.doe@example.com\"\n\
+         blob = \"q8Zr3Tn7Wk1Xv5Bm9Ly2Hc6Jd4Fg0Ps8Ua3Ne7Qi\"\n\
+         key = \"{}\" // nitroterm:allow-secret\n",
+        aws_key(),
+        "PRIVATE KEY",
+        github_token(),
+        github_token()
+    );
+    let found = scan_text(&content);
+    let rules: Vec<(usize, &str)> = found
+        .iter()
+        .map(|(line, _, rule, _)| (*line, *rule))
+        .collect();
+    assert_eq!(
+        rules,
+        vec![
+            (2, "aws-access-key"),
+            (3, "private-key"),
+            (4, "github-token"),
+            (6, "generic-secret"),
+            (10, "high-entropy-string"),
+        ]
+    );
+
+    // Column of the value, redacted to its first characters
+    assert_eq!(found[0].1, 21);
+    assert_eq!(found[0].3, "AKIA…");
+    assert!(found
+        .iter()
+        .all(|(_, _, _, redacted)| redacted.chars().count() <= 5));
+}
+
+#[test]
+fn test_scan_respects_gitignore_and_skips_binaries() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    git2::Repository::init(root).unwrap();
+    std::fs::write(root.join(".gitignore"), ".env\nbuild/\n").unwrap();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("build")).unwrap();
+    std::fs::create_dir_all(root.join("node_modules/pkg")).unwrap();
+
+    let line = format!("AWS_KEY={}\n", aws_key());
+    std::fs::write(root.join("src/config.rs"), format!("// config\n{}", line)).unwrap();
+    std::fs::write(root.join(".env"), &line).unwrap();
+    std::fs::write(root.join("build/out.js"), &line).unwrap();
+    std::fs::write(root.join("node_modules/pkg/index.js"), &line).unwrap();
+    std::fs::write(root.join("package-lock.json"), &line).unwrap();
+    std::fs::write(root.join("image.png"), [line.as_bytes(), &[0u8]].concat()).unwrap();
+
+    let matches = scan_secrets(root);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].file, "src/config.rs");
+    assert_eq!(matches[0].line, 2);
+    assert_eq!(matches[0].rule, "aws-access-key");
+
+    let result = run_secrets_check(root);
+    assert_eq!(result.check_name, "secrets");
+    assert!(!result.success);
+    assert!(result
+        .output
+        .starts_with("src/config.rs:2:9: error: AWS access key id (AKIA…) [aws-access-key]"));
+    assert!(!result.output.contains(&aws_key()));
+
+    std::fs::remove_file(root.join("src/config.rs")).unwrap();
+    assert!(run_secrets_check(root).success);
+}
@@ -0,0 +1,362 @@
+use crate::commands::dependency_update::{UpdateRules, UpdateStatus};
+use crate::commands::selective_update::{
+    allowed_updates, apply_updates, checklist_items, classify_update, list_updates,
+    parse_cargo_dry_run, parse_composer_outdated, parse_npm_outdated, parse_outdated_table,
+    parse_pip_outdated, parse_poetry_outdated, parse_yarn_outdated, update_commands,
+    AvailableUpdate, ManifestUpdates, UpdateKind,
+};
+use crate::tests::support::MockRunner;
+use std::fs;
+use tempfile::tempdir;
+
+fn available(name: &str, current: &str, latest: &str, kind: UpdateKind) -> AvailableUpdate {
+    AvailableUpdate {
+        name: name.to_string(),
+        current: current.to_string(),
+        latest: latest.to_string(),
+        kind,
+        dev: false,
+    }
+}
+
+#[test]
+fn test_classify_update() {
+    assert_eq!(classify_update("1.2.3", "1.2.4"), Some(UpdateKind::Patch));
+    assert_eq!(classify_update("1.2.3", "1.3.0"), Some(UpdateKind::Minor));
+    assert_eq!(classify_update("1.2.3", "2.0.0"), Some(UpdateKind::Major));
+    // 0.x minors are breaking
+    assert_eq!(classify_update("0.8.5", "0.9.0"), Some(UpdateKind::Major));
+    assert_eq!(classify_update("0.8.5", "0.8.6"), Some(UpdateKind::Patch));
+    // Short and prefixed versions from composer and pip
+    assert_eq!(classify_update("v2.9", "v3.5.0"), Some(UpdateKind::Major));
+    assert_eq!(classify_update("2.31", "2.32.3"), Some(UpdateKind::Minor));
+    assert_eq!(classify_update("2.0.0", "2.0.0"), None);
+    assert_eq!(classify_update("dev-main", "1.0.0"), None);
+    assert_eq!("Major".parse::<UpdateKind>().unwrap(), UpdateKind::Major);
+    assert!("huge".parse::<UpdateKind>().is_err());
+}
+
+#[test]
+fn test_outdated_parsers() {
+    let npm = r#"{
+        "react": {"current": "18.2.0", "wanted": "18.3.1", "latest": "19.0.0", "type": "dependencies"},
+        "eslint": {"current": "8.56.0", "wanted": "8.57.0", "latest": "8.57.0", "type": "devDependencies"},
+        "missing": {"wanted": "1.0.0", "latest": "1.0.0", "type": "dependencies"}
+    }"#;
+    let mut updates = parse_npm_outdated(npm);
+    updates.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].name, "eslint");
+    assert_eq!(updates[0].kind, UpdateKind::Minor);
+    assert!(updates[0].dev);
+    assert_eq!(updates[1].kind, UpdateKind::Major);
+
+    let pnpm = r#"{"vite": {"current": "5.0.0", "latest": "5.0.12", "wanted": "5.0.12", "dependencyType": "devDependencies"}}"#;
+    assert!(parse_npm_outdated(pnpm)[0].dev);
+
+    let yarn = concat!(
+        r#"{"type":"info","data":"Color legend"}"#,
+        "\n",
+        r#"{"type":"table","data":{"head":["Package","Current","Wanted","Latest","Package Type","URL"],"body":[["lodash","4.17.20","4.17.21","4.17.21","dependencies","https://lodash.com"],["jest","29.0.0","29.7.0","29.7.0","devDependencies","https://jestjs.io"]]}}"#
+    );
+    let updates = parse_yarn_outdated(yarn);
+    assert_eq!(
+        updates
+            .iter()
+            .map(|u| (u.name.as_str(), u.kind, u.dev))
+            .collect::<Vec<_>>(),
+        vec![
+            ("lodash", UpdateKind::Patch, false),
+            ("jest", UpdateKind::Minor, true)
+        ]
+    );
+
+    let pip = r#"[{"name": "requests", "version": "2.31.0", "latest_version": "2.32.3", "latest_filetype": "wheel"}]"#;
+    assert_eq!(
+        parse_pip_outdated(pip),
+        vec![available("requests", "2.31.0", "2.32.3", UpdateKind::Minor)]
+    );
+
+    let composer = r#"{"installed": [{"name": "monolog/monolog", "version": "2.9.1", "latest": "3.5.0", "latest-status": "update-possible"}]}"#;
+    assert_eq!(parse_composer_outdated(composer)[0].kind, UpdateKind::Major);
+    assert!(parse_npm_outdated("not json").is_empty());
+}
+
+#[test]
+fn test_cargo_dry_run_lists_direct_dependencies_only() {
+    let output = "    Updating crates.io index
+     Locking 3 packages to latest compatible versions
+    Updating serde v1.0.200 -> v1.0.210
+    Updating rand v0.8.4 -> v0.8.5 (available: v0.9.0)
+    Updating libc v0.2.150 -> v0.2.160
+   Unchanged colored v2.1.0 (available: v3.0.0)
+warning: not updating lockfile due to dry run
+";
+    let direct = vec![
+        "serde".to_string(),
+        "rand".to_string(),
+        "colored".to_string(),
+    ];
+    let updates = parse_cargo_dry_run(output, &direct);
+    assert_eq!(
+        updates,
+        vec![
+            available("serde", "1.0.200", "1.0.210", UpdateKind::Patch),
+            available("rand", "0.8.4", "0.8.5", UpdateKind::Patch),
+            available("rand", "0.8.4", "0.9.0", UpdateKind::Major),
+            available("colored", "2.1.0", "3.0.0", UpdateKind::Major),
+        ]
+    );
+
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[dependencies]\nserde = \"1.0\"\n",
+    )
+    .unwrap();
+    let runner = MockRunner::new();
+    runner.respond("cargo update --dry-run", output);
+    let listed = list_updates(&runner, dir.path(), "Cargo.toml", "cargo").unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].name, "serde");
+}
+
+#[test]
+fn test_update_commands_per_package_manager() {
+    let react = available("react", "18.2.0", "19.0.0", UpdateKind::Major);
+    let mut eslint = available("eslint", "8.56.0", "8.57.0", UpdateKind::Minor);
+    eslint.dev = true;
+    let joined = |commands: Vec<Vec<String>>| -> Vec<String> {
+        commands.iter().map(|command| command.join(" ")).collect()
+    };
+
+    assert_eq!(
+        joined(update_commands("npm", &[&react, &eslint])),
+        vec![
+            "npm install react@19.0.0",
+            "npm install --save-dev eslint@8.57.0"
+        ]
+    );
+    assert_eq!(
+        joined(update_commands("pnpm", &[&eslint])),
+        vec!["pnpm add -D eslint@8.57.0"]
+    );
+
+    let requests = available("requests", "2.31.0", "2.32.3", UpdateKind::Minor);
+    assert_eq!(
+        joined(update_commands("pip", &[&requests])),
+        vec!["pip install --upgrade requests==2.32.3"]
+    );
+
+    let monolog = available("monolog/monolog", "2.9.1", "3.5.0", UpdateKind::Major);
+    let guzzle = available("guzzlehttp/guzzle", "7.8.0", "7.8.1", UpdateKind::Patch);
+    assert_eq!(
+        joined(update_commands("composer", &[&monolog, &guzzle])),
+        vec![
+            "composer update guzzlehttp/guzzle",
+            "composer require monolog/monolog:^3.5.0"
+        ]
+    );
+
+    let httpx = available("httpx", "0.26.0", "0.27.0", UpdateKind::Major);
+    assert_eq!(
+        joined(update_commands("poetry", &[&requests, &httpx])),
+        vec!["poetry update requests", "poetry add httpx@^0.27.0"]
+    );
+    assert_eq!(
+        joined(update_commands("uv", &[&requests, &httpx])),
+        vec!["uv lock --upgrade-package requests", "uv add httpx>=0.27.0"]
+    );
+
+    let serde = available("serde", "1.0.200", "1.0.210", UpdateKind::Patch);
+    assert_eq!(
+        joined(update_commands("cargo", &[&serde])),
+        vec!["cargo update -p serde@1.0.200 --precise 1.0.210"]
+    );
+}
+
+#[test]
+fn test_apply_cargo_major_bumps_requirement() {
+    let dir = tempdir().unwrap();
+    let manifest_path = dir.path().join("Cargo.toml");
+    fs::write(
+        &manifest_path,
+        "[package]\nname = \"app\"\n\n[dependencies]\nrand = \"0.8\"\nserde = { version = \"1.0\", features = [\"derive\"] }\n",
+    )
+    .unwrap();
+
+    let compatible = available("rand", "0.8.4", "0.8.5", UpdateKind::Patch);
+    let major = available("rand", "0.8.4", "0.9.0", UpdateKind::Major);
+    let serde = available("serde", "1.0.200", "1.0.210", UpdateKind::Patch);
+    let manifest = ManifestUpdates {
+        manifest: "Cargo.toml".to_string(),
+        package_manager: "cargo".to_string(),
+        updates: vec![compatible.clone(), major.clone(), serde.clone()],
+    };
+
+    let runner = MockRunner::new();
+    let result = apply_updates(
+        &runner,
+        dir.path(),
+        &manifest,
+        &[&compatible, &major, &serde],
+    );
+    assert_eq!(result.status, UpdateStatus::Updated);
+    assert_eq!(result.ecosystem, "rust");
+
+    // Both rand updates were picked, the major one wins
+    assert_eq!(
+        runner.calls(),
+        vec![
+            "cargo update -p rand@0.8.4",
+            "cargo update -p serde@1.0.200 --precise 1.0.210"
+        ]
+    );
+    let content = fs::read_to_string(&manifest_path).unwrap();
+    assert!(content.contains("rand = \"0.9\""));
+    assert!(content.contains("serde = { version = \"1.0\""));
+
+    let failing = MockRunner::new();
+    failing.fail("cargo update -p serde", "error: no matching package");
+    let result = apply_updates(&failing, dir.path(), &manifest, &[&serde]);
+    assert_eq!(result.status, UpdateStatus::Failed);
+    assert!(result
+        .error
+        .unwrap_or_default()
+        .contains("no matching package"));
+}
+
+#[test]
+fn test_checklist_items_grouped_by_kind() {
+    let manifests = vec![
+        ManifestUpdates {
+            manifest: "package.json".to_string(),
+            package_manager: "npm".to_string(),
+            updates: vec![
+                available("react", "18.2.0", "19.0.0", UpdateKind::Major),
+                available("lodash", "4.17.20", "4.17.21", UpdateKind::Patch),
+            ],
+        },
+        ManifestUpdates {
+            manifest: "Cargo.toml".to_string(),
+            package_manager: "cargo".to_string(),
+            updates: vec![available("tokio", "1.38.0", "1.40.0", UpdateKind::Minor)],
+        },
+    ];
+
+    let (items, rows) = checklist_items(&manifests, &[UpdateKind::Patch, UpdateKind::Minor]);
+    assert_eq!(rows, vec![(0, 1), (1, 0), (0, 0)]);
+    assert!(items[0].group.contains("Patch"));
+    assert!(items[2].group.contains("Major"));
+    assert!(items[0].label.contains("lodash"));
+    assert_eq!(
+        items.iter().map(|item| item.checked).collect::<Vec<_>>(),
+        vec![true, true, false]
+    );
+}
+
+#[test]
+fn test_poetry_and_uv_outdated() {
+    let poetry = "\
+fastapi     0.109.2 0.111.0 FastAPI framework, high performance
+requests (!) 2.31.0  2.32.3  Python HTTP for Humans.
+";
+    assert_eq!(
+        parse_poetry_outdated(poetry),
+        vec![
+            available("fastapi", "0.109.2", "0.111.0", UpdateKind::Major),
+            available("requests", "2.31.0", "2.32.3", UpdateKind::Minor),
+        ]
+    );
+
+    let dir = tempdir().unwrap();
+    let runner = MockRunner::new();
+    runner
+        .respond("poetry show --outdated", poetry)
+        .respond(
+            "uv pip list --outdated --format=json",
+            r#"[{"name": "rich", "version": "13.7.0", "latest_version": "13.7.1", "latest_filetype": "wheel"}]"#,
+        );
+    assert_eq!(
+        list_updates(&runner, dir.path(), "pyproject.toml", "poetry")
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        list_updates(&runner, dir.path(), "pyproject.toml", "uv").unwrap(),
+        vec![available("rich", "13.7.0", "13.7.1", UpdateKind::Patch)]
+    );
+}
+
+#[test]
+fn test_bun_and_deno_outdated_tables() {
+    let bun = "\
+bun outdated v1.1.30 (7996d06b)
+┌──────────────────┬─────────┬────────┬────────┐
+│ Package          │ Current │ Update │ Latest │
+├──────────────────┼─────────┼────────┼────────┤
+│ hono             │ 4.5.0   │ 4.6.3  │ 4.6.3  │
+├──────────────────┼─────────┼────────┼────────┤
+│ @types/bun (dev) │ 1.1.6   │ 1.1.10 │ 1.1.10 │
+└──────────────────┴─────────┴────────┴────────┘
+";
+    let updates = parse_outdated_table(bun);
+    assert_eq!(
+        updates
+            .iter()
+            .map(|u| (u.name.as_str(), u.kind, u.dev))
+            .collect::<Vec<_>>(),
+        vec![
+            ("hono", UpdateKind::Minor, false),
+            ("@types/bun", UpdateKind::Patch, true)
+        ]
+    );
+    assert_eq!(
+        update_commands("bun", &[&updates[0], &updates[1]])
+            .iter()
+            .map(|command| command.join(" "))
+            .collect::<Vec<_>>(),
+        vec!["bun add hono@4.6.3", "bun add --dev @types/bun@1.1.10"]
+    );
+
+    let deno = "\
+┌────────────────┬─────────┬────────┬────────┐
+│ Package        │ Current │ Update │ Latest │
+├────────────────┼─────────┼────────┼────────┤
+│ jsr:@std/fmt   │ 1.0.0   │ 1.0.3  │ 1.0.3  │
+├────────────────┼─────────┼────────┼────────┤
+│ npm:chalk      │ 4.1.2   │ 4.1.2  │ 5.3.0  │
+└────────────────┴─────────┴────────┴────────┘
+";
+    assert_eq!(
+        parse_outdated_table(deno),
+        vec![
+            available("jsr:@std/fmt", "1.0.0", "1.0.3", UpdateKind::Patch),
+            available("npm:chalk", "4.1.2", "5.3.0", UpdateKind::Major),
+        ]
+    );
+}
+
+#[test]
+fn test_allowed_updates_follow_rules() {
+    let mut rules = UpdateRules::default();
+    rules.add_ignore("left-pad");
+    rules.add_pin("react@18").unwrap();
+    let updates = vec![
+        available("react", "18.2.0", "19.0.0", UpdateKind::Major),
+        available("left-pad", "1.1.0", "1.3.0", UpdateKind::Minor),
+        available("lodash", "4.17.20", "4.17.21", UpdateKind::Patch),
+    ];
+    assert_eq!(
+        allowed_updates(&rules, "package.json", updates.clone()),
+        vec![available("lodash", "4.17.20", "4.17.21", UpdateKind::Patch)]
+    );
+    // Rules for other ecosystems are left alone
+    let mut node_only = UpdateRules::default();
+    node_only.add_ignore("node:left-pad");
+    assert_eq!(
+        allowed_updates(&node_only, "composer.json", updates).len(),
+        3
+    );
+}
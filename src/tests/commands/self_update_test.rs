@@ -0,0 +1,130 @@
+use crate::commands::releases::{platform_aliases, PublishedRelease, ReleaseAsset, ReleasesClient};
+use crate::commands::self_update::{
+    checksum_asset, install_release, parse_checksum, replace_executable,
+};
+use crate::tests::support::MockServer;
+use sha2::{Digest, Sha256};
+
+const HASH: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+fn asset(base: &str, name: &str) -> ReleaseAsset {
+    ReleaseAsset {
+        name: name.to_string(),
+        browser_download_url: format!("{}/download/{}", base, name),
+        size: 0,
+    }
+}
+
+#[test]
+fn test_parse_checksum_formats() {
+    // Single hash in `<asset>.sha256`
+    assert_eq!(
+        parse_checksum(&format!("{}\n", HASH), "nitroterm-linux-x86_64"),
+        Some(HASH.to_string())
+    );
+    // sha256sum output, text and binary mode
+    let sums = format!(
+        "{}  nitroterm-macos-arm64\n{} *./dist/nitroterm-linux-x86_64\n",
+        "a".repeat(64),
+        HASH.to_uppercase()
+    );
+    assert_eq!(
+        parse_checksum(&sums, "nitroterm-linux-x86_64"),
+        Some(HASH.to_string())
+    );
+    assert_eq!(parse_checksum(&sums, "nitroterm-windows-x86_64.exe"), None);
+    assert_eq!(parse_checksum("not a hash", "nitroterm"), None);
+}
+
+#[test]
+fn test_checksum_asset_prefers_own_file() {
+    let assets = vec![
+        asset("https://example.com", "nitroterm-linux-x86_64"),
+        asset("https://example.com", "SHA256SUMS"),
+        asset("https://example.com", "nitroterm-linux-x86_64.sha256"),
+    ];
+    assert_eq!(
+        checksum_asset(&assets, "nitroterm-linux-x86_64")
+            .unwrap()
+            .name,
+        "nitroterm-linux-x86_64.sha256"
+    );
+    assert_eq!(
+        checksum_asset(&assets, "nitroterm-macos-arm64")
+            .unwrap()
+            .name,
+        "SHA256SUMS"
+    );
+    assert!(checksum_asset(&assets[..1], "nitroterm-linux-x86_64").is_none());
+}
+
+#[test]
+fn test_replace_executable() {
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("nitroterm");
+    let staged = dir.path().join("nitroterm.new");
+    std::fs::write(&target, "old").unwrap();
+    std::fs::write(&staged, "new").unwrap();
+
+    replace_executable(&staged, &target).unwrap();
+    assert_eq!(std::fs::read_to_string(&target).unwrap(), "new");
+    assert!(!staged.exists());
+}
+
+#[tokio::test]
+async fn test_install_release_verifies_checksum() {
+    let (os, arch) = platform_aliases();
+    let name = format!("nitroterm-{}-{}", os[0], arch[0]);
+    let binary = "#!/bin/sh\necho nitroterm v9.9.9\n";
+    let hash = format!("{:x}", Sha256::digest(binary.as_bytes()));
+
+    let server = MockServer::start();
+    server.mock("GET", &format!("/download/{}", name), 200, binary);
+    server.mock(
+        "GET",
+        &format!("/download/{}.sha256", name),
+        200,
+        &format!("{}  {}\n", hash, name),
+    );
+    server.mock(
+        "GET",
+        "/download/SHA256SUMS",
+        200,
+        &format!("{}  {}\n", "0".repeat(64), name),
+    );
+
+    let release = PublishedRelease {
+        tag_name: "v9.9.9".to_string(),
+        draft: false,
+        prerelease: false,
+        assets: vec![
+            asset(&server.url(), &name),
+            asset(&server.url(), &format!("{}.sha256", name)),
+        ],
+    };
+    let client = ReleasesClient::new().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("nitroterm");
+    std::fs::write(&target, "old binary").unwrap();
+
+    install_release(&client, &release, &target).await.unwrap();
+    assert_eq!(std::fs::read_to_string(&target).unwrap(), binary);
+
+    // A tampered checksum leaves the installed binary alone
+    std::fs::write(&target, "old binary").unwrap();
+    let tampered = PublishedRelease {
+        assets: vec![
+            asset(&server.url(), &name),
+            asset(&server.url(), "SHA256SUMS"),
+        ],
+        ..release
+    };
+    let error = install_release(&client, &tampered, &target)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Checksum mismatch"));
+    assert_eq!(std::fs::read_to_string(&target).unwrap(), "old binary");
+
+    // Staging directories are cleaned up either way
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
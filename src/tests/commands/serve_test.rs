@@ -0,0 +1,180 @@
+use crate::commands::config::AppConfig;
+use crate::commands::serve::{
+    authorize, generate_token, handle_request, parse_request_head, read_request, HttpRequest,
+    RequestError, ServerState,
+};
+use std::fs;
+use std::time::Duration;
+use tempfile::tempdir;
+use tokio::io::AsyncWriteExt;
+
+fn request(method: &str, path: &str, token: Option<&str>) -> HttpRequest {
+    let mut head = format!("{} {} HTTP/1.1\r\nHost: localhost\r\n", method, path);
+    if let Some(token) = token {
+        head.push_str(&format!("Authorization: Bearer {}\r\n", token));
+    }
+    parse_request_head(&head).unwrap()
+}
+
+fn state(root: &std::path::Path) -> ServerState {
+    ServerState {
+        root: root.to_path_buf(),
+        token: "secret".to_string(),
+        app_config: AppConfig::default(),
+    }
+}
+
+#[test]
+fn test_parse_request_head() {
+    let request = parse_request_head(
+        "get /api/translations?dir=locales&source=en%20US.json HTTP/1.1\r\nContent-Length: 12\r\nX-Custom:  value \r\n",
+    )
+    .unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/api/translations");
+    assert_eq!(request.query.get("dir").unwrap(), "locales");
+    assert_eq!(request.query.get("source").unwrap(), "en US.json");
+    assert_eq!(request.headers.get("content-length").unwrap(), "12");
+    assert_eq!(request.headers.get("x-custom").unwrap(), "value");
+
+    // Malformed escapes stay as they are, also before multi-byte characters
+    let request = parse_request_head("GET /?dir=%aé&source=%4 HTTP/1.1\r\n").unwrap();
+    assert_eq!(request.query.get("dir").unwrap(), "%aé");
+    assert_eq!(request.query.get("source").unwrap(), "%4");
+
+    assert!(parse_request_head("").is_err());
+    assert!(parse_request_head("GET\r\n").is_err());
+}
+
+#[test]
+fn test_authorize_and_generate_token() {
+    assert!(authorize(&request("GET", "/", Some("secret")), "secret"));
+    assert!(!authorize(&request("GET", "/", Some("secre")), "secret"));
+    assert!(!authorize(&request("GET", "/", Some("wrong!")), "secret"));
+    assert!(!authorize(&request("GET", "/", None), "secret"));
+
+    let token = generate_token().unwrap();
+    assert_eq!(token.len(), 64);
+    assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(token, generate_token().unwrap());
+}
+
+#[tokio::test]
+async fn test_read_request_limits() {
+    let timeout = Duration::from_secs(5);
+    let mut valid = &b"POST /api/checks HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}"[..];
+    let request = read_request(&mut valid, timeout).await.unwrap();
+    assert_eq!(request.path, "/api/checks");
+    assert_eq!(request.body, "{}");
+
+    let huge_head = format!(
+        "GET / HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+        "a".repeat(20_000)
+    );
+    let error = read_request(&mut huge_head.as_bytes(), timeout)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, RequestError::HeadersTooLarge));
+    assert_eq!(error.status(), 413);
+
+    let mut huge_body = &b"POST / HTTP/1.1\r\nContent-Length: 999999999\r\n\r\n"[..];
+    let error = read_request(&mut huge_body, timeout).await.unwrap_err();
+    assert_eq!(error.status(), 413);
+
+    // A client that never finishes its headers
+    let (mut client, mut server) = tokio::io::duplex(64);
+    client.write_all(b"GET / HTTP/1.1\r\n").await.unwrap();
+    let error = read_request(&mut server, Duration::from_millis(50))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, RequestError::TimedOut));
+    assert_eq!(error.status(), 408);
+}
+
+#[tokio::test]
+async fn test_routing_and_auth() {
+    let dir = tempdir().unwrap();
+    let state = state(dir.path());
+
+    let health = handle_request(&request("GET", "/health", None), &state).await;
+    assert_eq!(health.status, 200);
+    assert_eq!(health.body["status"], "ok");
+
+    let unauthorized = handle_request(&request("GET", "/api/stats", None), &state).await;
+    assert_eq!(unauthorized.status, 401);
+
+    let unknown = handle_request(&request("GET", "/api/nope", Some("secret")), &state).await;
+    assert_eq!(unknown.status, 404);
+
+    let wrong_method =
+        handle_request(&request("DELETE", "/api/checks", Some("secret")), &state).await;
+    assert_eq!(wrong_method.status, 405);
+
+    let no_cache = handle_request(&request("GET", "/api/checks", Some("secret")), &state).await;
+    assert_eq!(no_cache.status, 404);
+
+    let bytes = String::from_utf8(health.to_bytes()).unwrap();
+    assert!(bytes.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(bytes.contains("Content-Type: application/json"));
+}
+
+#[tokio::test]
+async fn test_translations_endpoint() {
+    let dir = tempdir().unwrap();
+    let messages = dir.path().join("messages");
+    fs::create_dir_all(&messages).unwrap();
+    fs::write(
+        messages.join("en.json"),
+        r#"{"title": "Hello", "nav": {"home": "Home", "about": "About"}}"#,
+    )
+    .unwrap();
+    fs::write(
+        messages.join("tr.json"),
+        r#"{"title": "Merhaba", "nav": {"home": "Ana Sayfa", "about": ""}}"#,
+    )
+    .unwrap();
+
+    let response = handle_request(
+        &request(
+            "GET",
+            "/api/translations?dir=messages&source=en.json",
+            Some("secret"),
+        ),
+        &state(dir.path()),
+    )
+    .await;
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body["total_keys"], 3);
+    let languages = response.body["languages"].as_array().unwrap();
+    assert_eq!(languages.len(), 1);
+    assert_eq!(languages[0]["code"], "tr");
+    assert_eq!(languages[0]["translated"], 2);
+    assert_eq!(languages[0]["missing_keys"][0], "nav.about");
+}
+
+#[tokio::test]
+async fn test_translations_endpoint_stays_in_the_project() {
+    let outside = tempdir().unwrap();
+    fs::write(outside.path().join("en.json"), r#"{"title": "Hello"}"#).unwrap();
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("messages")).unwrap();
+
+    for query in [
+        format!("dir={}&source=en.json", outside.path().display()),
+        "dir=..&source=en.json".to_string(),
+        "dir=messages&source=../../en.json".to_string(),
+    ] {
+        let response = handle_request(
+            &request(
+                "GET",
+                &format!("/api/translations?{}", query),
+                Some("secret"),
+            ),
+            &state(dir.path()),
+        )
+        .await;
+        assert_eq!(response.status, 400, "{}", query);
+    }
+}
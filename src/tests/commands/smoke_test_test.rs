@@ -0,0 +1,73 @@
+use crate::commands::smoke_test::{
+    check_output, load_smoke_tests, run_smoke_test, SmokeTest, SMOKE_TEST_FILE,
+};
+use std::fs;
+use tempfile::tempdir;
+
+fn smoke_test(command: &str, expect: &[&str]) -> SmokeTest {
+    SmokeTest {
+        name: "version".to_string(),
+        command: command.to_string(),
+        expect: expect.iter().map(|p| p.to_string()).collect(),
+        exit_code: 0,
+        timeout_seconds: 10,
+    }
+}
+
+#[test]
+fn test_load_smoke_tests() {
+    let temp_dir = tempdir().unwrap();
+    assert!(load_smoke_tests(temp_dir.path()).unwrap().is_none());
+
+    fs::create_dir_all(temp_dir.path().join(".nitrokit")).unwrap();
+    fs::write(
+        temp_dir.path().join(SMOKE_TEST_FILE),
+        r#"{
+            "build": "cargo build --release",
+            "tests": [
+                { "name": "version", "command": "{root}/target/release/app --version", "expect": ["app \\d+\\.\\d+"] },
+                { "name": "docker", "command": "docker run --rm app:latest --help", "timeout_seconds": 120 }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let config = load_smoke_tests(temp_dir.path()).unwrap().unwrap();
+    assert_eq!(config.build.as_deref(), Some("cargo build --release"));
+    assert_eq!(config.tests.len(), 2);
+    assert_eq!(config.tests[0].exit_code, 0);
+    assert_eq!(config.tests[0].timeout_seconds, 60);
+    assert_eq!(config.tests[1].timeout_seconds, 120);
+}
+
+#[test]
+fn test_check_output() {
+    let test = smoke_test("app --version", &[r"app \d+\.\d+\.\d+"]);
+
+    assert!(check_output(&test, Some(0), "app 1.2.3\n").is_empty());
+
+    let failures = check_output(&test, Some(0), "app unknown\n");
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0].contains("does not match"));
+
+    let failures = check_output(&test, Some(2), "app 1.2.3\n");
+    assert_eq!(failures, vec!["exit code 2 (expected 0)".to_string()]);
+}
+
+#[tokio::test]
+async fn test_run_smoke_test_in_temp_dir() {
+    let root = tempdir().unwrap();
+
+    let result = run_smoke_test(
+        &smoke_test("echo nitroterm ok", &["nitroterm ok"]),
+        root.path(),
+    )
+    .await
+    .unwrap();
+    assert!(result.passed(), "{:?}", result.failures);
+
+    let result = run_smoke_test(&smoke_test("echo broken", &["nitroterm ok"]), root.path())
+        .await
+        .unwrap();
+    assert!(!result.passed());
+}
@@ -0,0 +1,64 @@
+use crate::commands::state::{
+    build_status, record_run, CurrentFindings, RepoState, Tracked, STATE_FILE,
+};
+use crate::tests::support::TestCommit;
+use git2::Repository;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_status_since_last_runs() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+    TestCommit::new("feat: first").commit(&repo);
+
+    record_run(root, Tracked::ReleaseNotes, |record| {
+        record.tag = Some("v1.0.0".to_string())
+    });
+    record_run(root, Tracked::Dependencies, |record| {
+        record.outdated = vec!["crates.io:serde@1.0.200".to_string()]
+    });
+    TestCommit::new("fix: second").commit(&repo);
+    TestCommit::new("docs: third").commit(&repo);
+
+    let state = RepoState::load(root);
+    assert!(root.join(STATE_FILE).exists());
+    assert_eq!(
+        state.get(Tracked::ReleaseNotes).unwrap().tag.as_deref(),
+        Some("v1.0.0")
+    );
+
+    let findings = CurrentFindings {
+        outdated: Some(Ok(vec![
+            "crates.io:serde@1.0.200".to_string(),
+            "npm:react@19.0.0".to_string(),
+        ])),
+        untranslated: Some(Err(anyhow::anyhow!("Source file not found"))),
+    };
+    let report = build_status(&repo, &state, findings).unwrap();
+
+    let release_notes = &report.sections[0];
+    assert_eq!(release_notes.new_commits, 2);
+    assert_eq!(
+        release_notes.commit_subjects,
+        vec!["docs: third", "fix: second"]
+    );
+
+    // Only what wasn't outdated at the last audit is new
+    let dependencies = &report.sections[1];
+    assert_eq!(dependencies.new_items, vec!["npm:react@19.0.0"]);
+
+    // Never synced: every commit is new, the check error is reported
+    let translations = &report.sections[2];
+    assert_eq!(translations.last_run, None);
+    assert_eq!(translations.new_commits, 3);
+    assert_eq!(translations.error.as_deref(), Some("Source file not found"));
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["sections"][1]["key"], "dependencies");
+
+    // A corrupt state file starts over instead of failing
+    fs::write(root.join(STATE_FILE), "{").unwrap();
+    assert!(RepoState::load(root).get(Tracked::ReleaseNotes).is_none());
+}
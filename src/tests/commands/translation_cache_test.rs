@@ -0,0 +1,85 @@
+use crate::commands::translation_cache::{cache_scope, clear_cache, TranslationCache};
+use crate::commands::translation_glossary::Glossary;
+use std::collections::BTreeMap;
+use tempfile::tempdir;
+
+const SCOPE: &str = "gemini:gemini-1.5-flash:0";
+
+#[test]
+fn test_cache_key_is_stable_and_language_scoped() {
+    let key = TranslationCache::cache_key("tr", "Hello");
+
+    assert_eq!(key, TranslationCache::cache_key("tr", "Hello"));
+    assert_ne!(key, TranslationCache::cache_key("de", "Hello"));
+    assert_ne!(key, TranslationCache::cache_key("tr", "Hello!"));
+    assert!(key.starts_with("tr:"));
+}
+
+#[test]
+fn test_cache_round_trip() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join(".nitrokit/translation-cache.json");
+
+    let mut cache = TranslationCache::load(&path);
+    assert!(cache.is_empty());
+
+    cache.insert(SCOPE, "tr", "Hello", "Merhaba");
+    cache.insert(SCOPE, "de", "Hello", "Hallo");
+    cache.save().unwrap();
+    assert!(path.exists());
+
+    let reloaded = TranslationCache::load(&path);
+    assert_eq!(reloaded.len(), 2);
+    assert_eq!(reloaded.get(SCOPE, "tr", "Hello").unwrap(), "Merhaba");
+    assert_eq!(reloaded.get(SCOPE, "de", "Hello").unwrap(), "Hallo");
+    assert!(reloaded.get(SCOPE, "fr", "Hello").is_none());
+}
+
+#[test]
+fn test_unchanged_cache_is_not_written() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("cache.json");
+
+    let mut cache = TranslationCache::load(&path);
+    cache.save().unwrap();
+
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_clear_cache() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("cache.json");
+
+    assert_eq!(clear_cache(&path).unwrap(), 0);
+
+    let mut cache = TranslationCache::load(&path);
+    cache.insert(SCOPE, "tr", "Save", "Kaydet");
+    cache.save().unwrap();
+
+    assert_eq!(clear_cache(&path).unwrap(), 1);
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_cache_is_scoped_to_provider_model_and_glossary() {
+    let glossary = Glossary::default();
+    let scope = cache_scope("Gemini", "gemini-1.5-flash", &glossary);
+    let mut cache = TranslationCache::default();
+    cache.insert(&scope, "tr", "Hello", "Merhaba");
+    assert_eq!(cache.get(&scope, "tr", "Hello").unwrap(), "Merhaba");
+
+    let other_model = cache_scope("Gemini", "gemini-1.5-pro", &glossary);
+    let other_provider = cache_scope("OpenAI", "gemini-1.5-flash", &glossary);
+    let with_term = cache_scope(
+        "Gemini",
+        "gemini-1.5-flash",
+        &Glossary {
+            do_not_translate: vec!["Nitrokit".to_string()],
+            terms: BTreeMap::new(),
+        },
+    );
+    for scope in [other_model, other_provider, with_term] {
+        assert!(cache.get(&scope, "tr", "Hello").is_none());
+    }
+}
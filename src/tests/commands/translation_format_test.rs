@@ -0,0 +1,220 @@
+use crate::commands::translation_format::{
+    ordered_like, parse_po, render_po, JsonStyle, SourceMessages, TranslationFormat,
+};
+use crate::commands::translation_sync::discover_language_files;
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+const TEMPLATE: &str = r#"msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+#: src/app.rs:10
+msgid "Hello. Welcome back!"
+msgstr ""
+
+msgctxt "menu"
+msgid "Open"
+msgstr ""
+
+msgid "One file"
+msgid_plural "%d files"
+msgstr[0] ""
+msgstr[1] ""
+"#;
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(
+        TranslationFormat::from_path(Path::new("tr.yml")),
+        Some(TranslationFormat::Yaml)
+    );
+    assert_eq!(
+        TranslationFormat::from_path(Path::new("messages.pot")),
+        Some(TranslationFormat::Po)
+    );
+    assert_eq!(TranslationFormat::from_path(Path::new("notes.txt")), None);
+}
+
+#[test]
+fn test_parse_po_entries() {
+    let entries = parse_po(TEMPLATE).unwrap();
+
+    assert_eq!(entries.len(), 4);
+    assert!(entries[0].is_header());
+    assert_eq!(entries[1].msgid, "Hello. Welcome back!");
+    assert_eq!(entries[1].comments, vec!["#: src/app.rs:10".to_string()]);
+    assert_eq!(entries[2].context.as_deref(), Some("menu"));
+    assert_eq!(entries[3].msgid_plural.as_deref(), Some("%d files"));
+    assert_eq!(entries[3].msgstr.len(), 2);
+
+    // Rendering and parsing again gives the same catalog
+    assert_eq!(parse_po(&render_po(&entries)).unwrap(), entries);
+}
+
+#[test]
+fn test_po_multiline_strings() {
+    let content = "msgid \"\"\n\"First line\\n\"\n\"Second \\\"quoted\\\"\"\nmsgstr \"\"\n";
+    let entries = parse_po(content).unwrap();
+    assert_eq!(entries[0].msgid, "First line\nSecond \"quoted\"");
+
+    let rendered = render_po(&entries);
+    assert!(rendered.starts_with("msgid \"\"\n\"First line\\n\"\n"));
+}
+
+#[test]
+fn test_yaml_locale_root_is_kept() {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("en.yml");
+    fs::write(
+        &source_path,
+        "en:\n  greeting: Hello\n  users:\n    title: Users\n",
+    )
+    .unwrap();
+
+    let source = SourceMessages::load(&source_path).unwrap();
+    assert_eq!(
+        source.messages,
+        json!({ "greeting": "Hello", "users": { "title": "Users" } })
+    );
+    assert_eq!(source.file_name_for("tr"), "tr.yml");
+
+    let target_path = temp_dir.path().join("tr.yml");
+    source
+        .write_target(&target_path, &json!({ "greeting": "Merhaba" }), "tr")
+        .unwrap();
+
+    let written = fs::read_to_string(&target_path).unwrap();
+    assert!(written.starts_with("tr:\n"));
+    assert_eq!(
+        source.read_target(&target_path).unwrap(),
+        json!({ "greeting": "Merhaba" })
+    );
+}
+
+#[test]
+fn test_json_target_follows_source_layout() {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("en.json");
+    fs::write(
+        &source_path,
+        "{\n\t\"title\": \"Hello\",\n\t\"nav\": {\n\t\t\"home\": \"Home\",\n\t\t\"about\": \"About\"\n\t},\n\t\"footer\": \"Bye\"\n}\n",
+    )
+    .unwrap();
+    let source = SourceMessages::load(&source_path).unwrap();
+    assert_eq!(
+        JsonStyle::detect("{\n    \"a\": 1\n}"),
+        JsonStyle {
+            indent: "    ".to_string(),
+            trailing_newline: false
+        }
+    );
+
+    // Written in key order by an older version, with one key missing
+    let target_path = temp_dir.path().join("tr.json");
+    fs::write(
+        &target_path,
+        "{\n\t\"footer\": \"Hoşça kal\",\n\t\"nav\": {\n\t\t\"about\": \"Hakkında\",\n\t\t\"home\": \"Ana sayfa\"\n\t},\n\t\"old\": \"Eski\"\n}\n",
+    )
+    .unwrap();
+    let mut messages = source.read_target(&target_path).unwrap();
+    messages["title"] = json!("Merhaba");
+    source.write_target(&target_path, &messages, "tr").unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&target_path).unwrap(),
+        "{\n\t\"title\": \"Merhaba\",\n\t\"nav\": {\n\t\t\"home\": \"Ana sayfa\",\n\t\t\"about\": \"Hakkında\"\n\t},\n\t\"footer\": \"Hoşça kal\",\n\t\"old\": \"Eski\"\n}\n"
+    );
+
+    // Arrays keep their items, objects inside them follow the source too
+    let ordered = ordered_like(
+        &json!({ "steps": [{ "a": "1", "b": "2" }, "extra"] }),
+        &serde_json::from_str(r#"{ "steps": [{ "b": "y", "a": "x" }] }"#).unwrap(),
+    );
+    assert_eq!(
+        serde_json::to_string(&ordered).unwrap(),
+        r#"{"steps":[{"b":"2","a":"1"},"extra"]}"#
+    );
+}
+
+#[test]
+fn test_po_target_keeps_header_and_plurals() {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("messages.pot");
+    fs::write(&source_path, TEMPLATE).unwrap();
+
+    let source = SourceMessages::load(&source_path).unwrap();
+    let messages = source.messages.as_object().unwrap();
+    // Plural entries aren't machine translated
+    assert_eq!(messages.len(), 2);
+    assert!(messages.values().any(|text| text == "Hello. Welcome back!"));
+    assert_eq!(source.file_name_for("tr"), "tr.po");
+
+    let target_path = temp_dir.path().join("tr.po");
+    fs::write(
+        &target_path,
+        "msgid \"\"\nmsgstr \"Language: tr\\n\"\n\nmsgid \"One file\"\nmsgid_plural \"%d files\"\nmsgstr[0] \"%d dosya\"\nmsgstr[1] \"%d dosya\"\n",
+    )
+    .unwrap();
+    assert!(source
+        .read_target(&target_path)
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .is_empty());
+
+    let mut translated = json!({});
+    for (key, text) in messages {
+        let text = if text == "Open" {
+            "Aç"
+        } else {
+            "Merhaba. Tekrar hoş geldin!"
+        };
+        translated[key] = json!(text);
+    }
+    source
+        .write_target(&target_path, &translated, "tr")
+        .unwrap();
+
+    let entries = parse_po(&fs::read_to_string(&target_path).unwrap()).unwrap();
+    assert_eq!(entries[0].msgstr[0], "Language: tr\n");
+    assert_eq!(entries[1].msgstr[0], "Merhaba. Tekrar hoş geldin!");
+    assert_eq!(entries[2].msgstr[0], "Aç");
+    assert_eq!(entries[3].msgstr, vec!["%d dosya", "%d dosya"]);
+    assert_eq!(source.read_target(&target_path).unwrap(), translated);
+}
+
+#[test]
+fn test_po_target_needs_po_source() {
+    let temp_dir = tempdir().unwrap();
+    let source_path = temp_dir.path().join("source.json");
+    fs::write(&source_path, r#"{ "hello": "Hello" }"#).unwrap();
+    fs::write(temp_dir.path().join("tr.po"), "").unwrap();
+
+    let source = SourceMessages::load(&source_path).unwrap();
+    assert!(source.read_target(&temp_dir.path().join("tr.po")).is_err());
+}
+
+#[test]
+fn test_discover_language_files_by_extension() {
+    let temp_dir = tempdir().unwrap();
+    for file in [
+        "en.yml",
+        "tr.yml",
+        "de.po",
+        "messages.pot",
+        "fr.json",
+        "README.md",
+    ] {
+        fs::write(temp_dir.path().join(file), "").unwrap();
+    }
+
+    let languages = discover_language_files(temp_dir.path(), "en.yml").unwrap();
+    let files: Vec<_> = languages
+        .iter()
+        .map(|l| l.file_name.clone().unwrap())
+        .collect();
+    assert_eq!(files, vec!["de.po", "fr.json", "tr.yml"]);
+}
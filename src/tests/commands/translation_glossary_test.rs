@@ -0,0 +1,162 @@
+use crate::commands::translation_glossary::{
+    load_glossary, Glossary, GlossaryViolation, GLOSSARY_FILE,
+};
+use crate::commands::translation_sync::{
+    GeminiProvider, Language, ProviderKind, TranslationConfig, TranslationSync,
+};
+use crate::tests::support::{fixture, MockServer};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use tempfile::tempdir;
+
+fn glossary() -> Glossary {
+    let mut terms = BTreeMap::new();
+    terms.insert(
+        "workspace".to_string(),
+        BTreeMap::from([
+            ("de".to_string(), "Arbeitsbereich".to_string()),
+            ("tr".to_string(), "çalışma alanı".to_string()),
+        ]),
+    );
+    Glossary {
+        do_not_translate: vec!["Nitrokit".to_string(), "GitHub".to_string()],
+        terms,
+    }
+}
+
+#[test]
+fn test_prompt_instructions_only_name_used_terms() {
+    let glossary = glossary();
+    let instructions = glossary.prompt_instructions(
+        "de",
+        &[
+            "Open your Workspace",
+            "Welcome to Nitrokit",
+            "Nitrokit rocks",
+        ],
+    );
+    assert_eq!(
+        instructions,
+        "Never translate these names, keep them exactly as written: \"Nitrokit\".\n\
+         Always use these approved translations:\n\
+         - \"workspace\" → \"Arbeitsbereich\""
+    );
+    // No approved French term, and "Workspaces" is another word
+    assert_eq!(glossary.prompt_instructions("fr", &["Workspaces"]), "");
+    assert!(Glossary::default().is_empty());
+}
+
+#[test]
+fn test_check_translation_against_glossary() {
+    let glossary = glossary();
+    assert!(glossary
+        .check(
+            "Sign in to Nitrokit with GitHub",
+            "Mit GitHub bei Nitrokit anmelden",
+            "de"
+        )
+        .is_empty());
+
+    let violations = glossary.check("Nitrokit workspace", "Nitrokit çalışma alanını aç", "tr");
+    assert!(violations.is_empty(), "inflected terms still match");
+
+    let violations = glossary.check("Nitrokit workspace", "Nitro-Kit Arbeitsraum", "de");
+    assert_eq!(
+        violations,
+        vec![
+            GlossaryViolation::Translated {
+                term: "Nitrokit".to_string()
+            },
+            GlossaryViolation::WrongTerm {
+                term: "workspace".to_string(),
+                expected: "Arbeitsbereich".to_string()
+            },
+        ]
+    );
+    assert!(violations[0].is_error());
+    assert!(!violations[1].is_error());
+    assert_eq!(
+        violations[1].describe(),
+        "\"workspace\" should be translated as \"Arbeitsbereich\""
+    );
+}
+
+#[test]
+fn test_load_glossary() {
+    let dir = tempdir().unwrap();
+    assert_eq!(load_glossary(dir.path()).unwrap(), Glossary::default());
+
+    fs::create_dir_all(dir.path().join(".nitrokit")).unwrap();
+    fs::write(
+        dir.path().join(GLOSSARY_FILE),
+        r#"{"do_not_translate": ["Nitrokit"], "terms": {"workspace": {"de": "Arbeitsbereich"}}}"#,
+    )
+    .unwrap();
+    let loaded = load_glossary(dir.path()).unwrap();
+    assert_eq!(loaded.do_not_translate, vec!["Nitrokit"]);
+    assert_eq!(loaded.terms["workspace"]["de"], "Arbeitsbereich");
+
+    fs::write(dir.path().join(GLOSSARY_FILE), "{").unwrap();
+    assert!(load_glossary(dir.path())
+        .unwrap_err()
+        .to_string()
+        .contains(GLOSSARY_FILE));
+}
+
+#[tokio::test]
+async fn test_sync_keeps_keys_that_break_the_glossary_missing() {
+    let server = MockServer::start();
+    server.mock(
+        "POST",
+        "/v1beta/models/gemini-1.5-flash:generateContent",
+        200,
+        &fixture("gemini/generate_content.json"),
+    );
+
+    let temp_dir = tempdir().unwrap();
+    let source = json!({
+        "app": { "title": "My App" },
+        "buttons": { "save": "Save", "cancel": "Cancel" }
+    });
+    fs::write(temp_dir.path().join("en.json"), source.to_string()).unwrap();
+
+    let config = TranslationConfig {
+        provider: ProviderKind::Gemini,
+        api_key: "test-key".to_string(),
+        model: "gemini-1.5-flash".to_string(),
+        delay_seconds: 0,
+        messages_dir: temp_dir.path().to_path_buf(),
+        source_file: "en.json".to_string(),
+        use_cache: false,
+        fallback: None,
+        max_retries: 0,
+        concurrency: 1,
+    };
+    let glossary = Glossary {
+        do_not_translate: vec!["App".to_string()],
+        terms: BTreeMap::from([(
+            "cancel".to_string(),
+            BTreeMap::from([("tr".to_string(), "Vazgeç".to_string())]),
+        )]),
+    };
+    let provider = GeminiProvider::new("test-key", "gemini-1.5-flash")
+        .unwrap()
+        .with_base_url(&server.url());
+    let sync = TranslationSync::with_provider(config, Box::new(provider)).with_glossary(glossary);
+    sync.sync_languages(&[Language::from_code("tr")])
+        .await
+        .unwrap();
+
+    let prompt = &server.writes()[0].body;
+    assert!(prompt.contains("keep them exactly as written: \\\"App\\\""));
+    assert!(prompt.contains("\\\"cancel\\\" → \\\"Vazgeç\\\""));
+
+    let written: Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("tr.json")).unwrap())
+            .unwrap();
+    // "Uygulamam" dropped the protected name, a different term is only reported
+    assert_eq!(written["app"].get("title"), None);
+    assert_eq!(written["buttons"]["save"], "Kaydet");
+    assert_eq!(written["buttons"]["cancel"], "İptal");
+}
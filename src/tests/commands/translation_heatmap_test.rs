@@ -0,0 +1,71 @@
+use crate::commands::translation_heatmap::{build_heatmap, scan_key_references};
+use crate::tests::support::TestCommit;
+use git2::Repository;
+use std::collections::HashSet;
+use tempfile::tempdir;
+
+#[test]
+fn test_scan_key_references() {
+    let keys: HashSet<String> = ["auth.login.title", "auth.logout", "common.save"]
+        .iter()
+        .map(|key| key.to_string())
+        .collect();
+
+    let content = r#"
+        const t = useTranslations("auth");
+        export const Login = () => <h1>{t('login.title')}</h1>;
+        const label = i18n.t(`common.save`);
+        const unrelated = "auth.missing";
+    "#;
+    let found: Vec<String> = scan_key_references(content, &keys).into_iter().collect();
+    assert_eq!(found, vec!["auth.login.title", "common.save"]);
+}
+
+#[test]
+fn test_build_heatmap_ranks_churning_keys() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let repo = Repository::init(root).unwrap();
+
+    TestCommit::new("init")
+        .file(
+            "messages/en.json",
+            r#"{"auth": {"title": "Sign in"}, "home": {"hero": "Welcome"}, "old": "Unused"}"#,
+        )
+        .file("messages/tr.json", r#"{"auth": {"title": "Giriş"}}"#)
+        .file(
+            "src/Login.tsx",
+            r#"const t = useTranslations("auth"); t("title");"#,
+        )
+        .file("src/Home.tsx", r#"t("home.hero")"#)
+        .commit(&repo);
+    TestCommit::new("copy: reword sign in")
+        .file(
+            "messages/en.json",
+            r#"{"auth": {"title": "Log in"}, "home": {"hero": "Welcome"}, "old": "Unused"}"#,
+        )
+        .commit(&repo);
+    TestCommit::new("feat: login layout")
+        .file(
+            "src/Login.tsx",
+            r#"const t = useTranslations("auth"); t("title"); // layout"#,
+        )
+        .commit(&repo);
+
+    let report = build_heatmap(root, &root.join("messages"), "en.json", 30).unwrap();
+
+    let hottest = &report.keys[0];
+    assert_eq!(hottest.key, "auth.title");
+    assert_eq!(hottest.files, vec!["src/Login.tsx"]);
+    // Added in the first commit, reworded in the second
+    assert_eq!(hottest.string_changes, 2);
+    assert_eq!(hottest.file_churn, 2);
+    assert_eq!(hottest.languages, vec!["tr"]);
+    assert_eq!(hottest.score, 2 * 3 + 2);
+
+    let hero = report.keys.iter().find(|k| k.key == "home.hero").unwrap();
+    assert_eq!((hero.string_changes, hero.file_churn), (1, 1));
+
+    assert_eq!(report.areas[0].area, "auth");
+    assert_eq!(report.unreferenced, vec!["old"]);
+}
@@ -0,0 +1,178 @@
+use crate::commands::translation_icu::{check_translation, is_icu_message, parse_message};
+use crate::commands::translation_sync::{
+    GeminiProvider, Language, ProviderKind, TranslationConfig, TranslationSync,
+};
+use crate::tests::support::MockServer;
+use serde_json::{json, Value};
+use std::fs;
+use tempfile::tempdir;
+
+const FILES: &str = "{count, plural, =0 {No files} one {# file} other {# files in {folder}}}";
+
+#[test]
+fn test_is_icu_message() {
+    assert!(is_icu_message(FILES));
+    assert!(is_icu_message(
+        "{ gender , select, female {She} other {They}}"
+    ));
+    assert!(!is_icu_message("Hello {name}"));
+    assert!(!is_icu_message("{{count}} items"));
+}
+
+#[test]
+fn test_parse_message_collects_arguments_and_branches() {
+    let arguments = parse_message(FILES).unwrap();
+    assert_eq!(arguments.len(), 2);
+    assert_eq!(arguments[0].name, "folder");
+    assert_eq!(arguments[0].kind, "");
+    assert_eq!(arguments[1].name, "count");
+    assert_eq!(arguments[1].kind, "plural");
+    assert_eq!(arguments[1].branches, vec!["=0", "one", "other"]);
+
+    let arguments = parse_message(
+        "{rank, selectordinal, offset:1 one {#st} other {#th}} for {price, number, ::currency/EUR}",
+    )
+    .unwrap();
+    assert_eq!(arguments[0].branches, vec!["one", "other"]);
+    assert_eq!(
+        (arguments[1].name.as_str(), arguments[1].kind.as_str()),
+        ("price", "number")
+    );
+
+    // Quoted braces are text, a lone apostrophe too
+    let arguments = parse_message("Don't use '{braces}' {n, plural, other {# ''x''}}").unwrap();
+    assert_eq!(arguments.len(), 1);
+
+    assert!(parse_message("{count, plural, one {# file}}")
+        .unwrap_err()
+        .to_string()
+        .contains("no 'other' branch"));
+    assert!(
+        parse_message("{count, plural, one {# file} other {# files}")
+            .unwrap_err()
+            .to_string()
+            .contains("unclosed {count, plural}")
+    );
+    assert!(
+        parse_message("{count, plural, bir {# dosya} other {# dosya}}")
+            .unwrap_err()
+            .to_string()
+            .contains("unknown plural category 'bir'")
+    );
+    assert!(parse_message("a } b").is_err());
+}
+
+#[test]
+fn test_check_translation_allows_other_plural_categories() {
+    assert_eq!(
+        check_translation(FILES, "{count, plural, other {{folder} içinde # dosya}}"),
+        None
+    );
+    assert_eq!(
+        check_translation(
+            FILES,
+            "{count, plural, =0 {Нет файлов} one {# файл} few {# файла} many {# файлов} other {# файла в {folder}}}"
+        ),
+        None
+    );
+    // Not an ICU message, nothing to check
+    assert_eq!(check_translation("Hello {name}", "Merhaba"), None);
+}
+
+#[test]
+fn test_check_translation_reports_broken_structure() {
+    assert!(check_translation(FILES, "{count, select, other {# dosya}}")
+        .unwrap()
+        .contains("{count, plural} is missing"));
+    assert!(check_translation(FILES, "{count, çoğul, other {# dosya}}")
+        .unwrap()
+        .starts_with("invalid ICU message"));
+    assert!(check_translation(FILES, "{count, plural, other {# dosya}")
+        .unwrap()
+        .starts_with("invalid ICU message"));
+    assert!(
+        check_translation(FILES, "{count, plural, other {# dosya {klasör}}}")
+            .unwrap()
+            .contains("unknown argument {klasör}")
+    );
+
+    let source = "{gender, select, female {She} male {He} other {They}} replied";
+    assert_eq!(
+        check_translation(
+            source,
+            "{gender, select, female {Elle} male {Il} other {Iel}} a répondu"
+        ),
+        None
+    );
+    assert!(check_translation(
+        source,
+        "{gender, select, femme {Elle} male {Il} other {Iel}} a répondu"
+    )
+    .unwrap()
+    .contains("branches changed"));
+}
+
+fn gemini_reply(text: &str) -> String {
+    json!({ "candidates": [{ "content": { "parts": [{ "text": text }], "role": "model" } }] })
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_sync_translates_arrays_and_plural_messages() {
+    let server = MockServer::start();
+    server.mock(
+        "POST",
+        "/v1beta/models/gemini-1.5-flash:generateContent",
+        200,
+        &gemini_reply(
+            "files||{count, plural, other {{folder} içinde # dosya}}\n\
+             deleted||{count, plural, other {# silindi}\n\
+             steps.0||Kur\n\
+             steps.1.title||Çalıştır\n\
+             steps.1.lines.0||Birinci\\nikinci",
+        ),
+    );
+
+    let temp_dir = tempdir().unwrap();
+    let source = json!({
+        "files": FILES,
+        "deleted": "{count, plural, one {# deleted} other {# deleted}}",
+        "steps": ["Install", { "title": "Run", "lines": ["First\nsecond"] }]
+    });
+    fs::write(temp_dir.path().join("en.json"), source.to_string()).unwrap();
+
+    let config = TranslationConfig {
+        provider: ProviderKind::Gemini,
+        api_key: "test-key".to_string(),
+        model: "gemini-1.5-flash".to_string(),
+        delay_seconds: 0,
+        messages_dir: temp_dir.path().to_path_buf(),
+        source_file: "en.json".to_string(),
+        use_cache: false,
+        fallback: None,
+        max_retries: 0,
+        concurrency: 1,
+    };
+    let provider = GeminiProvider::new("test-key", "gemini-1.5-flash")
+        .unwrap()
+        .with_base_url(&server.url());
+    let sync = TranslationSync::with_provider(config, Box::new(provider));
+    sync.sync_languages(&[Language::from_code("tr")])
+        .await
+        .unwrap();
+
+    let prompt = &server.requests()[0].body;
+    assert!(prompt.contains("Some values are ICU messages"));
+    assert!(prompt.contains(r"steps.1.lines.0||First\\nsecond"));
+
+    let written: Value =
+        serde_json::from_str(&fs::read_to_string(temp_dir.path().join("tr.json")).unwrap())
+            .unwrap();
+    assert_eq!(
+        written,
+        json!({
+            "files": "{count, plural, other {{folder} içinde # dosya}}",
+            "steps": ["Kur", { "title": "Çalıştır", "lines": ["Birinci\nikinci"] }]
+        })
+    );
+}
@@ -1,5 +1,19 @@
+use crate::commands::config::AppConfig;
+use crate::commands::translation_glossary::Glossary;
+use crate::commands::translation_sync::{
+    calculate_translation_progress, compare_providers, create_provider, extract_all_paths,
+    fallback_settings, find_missing_paths, get_nested_value, get_sync_statistics,
+    parse_retry_after, provider_settings, sample_paths, set_nested_value_like, ApiError,
+    FailoverProvider, GeminiProvider, Language, OpenAiProvider, ProviderFuture, ProviderKind,
+    RateLimitedProvider, RetryPolicy, RetryProvider, TranslationConfig, TranslationProvider,
+    TranslationSync,
+};
+use crate::tests::support::{fixture, MockServer};
 use serde_json::{json, Value};
 use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 use tempfile::tempdir;
 
 #[cfg(test)]
@@ -60,22 +74,19 @@ mod tests {
 
         // Extract all keys from nested JSON
         fn extract_keys(obj: &Value, prefix: &str, keys: &mut Vec<String>) {
-            match obj {
-                Value::Object(map) => {
-                    for (key, value) in map {
-                        let full_key = if prefix.is_empty() {
-                            key.clone()
-                        } else {
-                            format!("{}.{}", prefix, key)
-                        };
-
-                        match value {
-                            Value::Object(_) => extract_keys(value, &full_key, keys),
-                            _ => keys.push(full_key),
-                        }
+            if let Value::Object(map) = obj {
+                for (key, value) in map {
+                    let full_key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+
+                    match value {
+                        Value::Object(_) => extract_keys(value, &full_key, keys),
+                        _ => keys.push(full_key),
                     }
                 }
-                _ => {}
             }
         }
 
@@ -138,16 +149,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_array_paths() {
+        let source = json!({
+            "steps": ["Install", { "title": "Run", "hint": null }],
+            "errors": { "404": "Not found" }
+        });
+        let paths = extract_all_paths(&source, "");
+        assert_eq!(paths, vec!["errors.404", "steps.0", "steps.1.title"]);
+        assert_eq!(
+            get_nested_value(&source, "steps.1.title"),
+            Some(&json!("Run"))
+        );
+        assert_eq!(get_nested_value(&source, "steps.2"), None);
+
+        // Arrays follow the source, numeric object keys stay keys
+        let mut target = json!({});
+        set_nested_value_like(&mut target, &source, "steps.1.title", json!("Çalıştır")).unwrap();
+        set_nested_value_like(&mut target, &source, "errors.404", json!("Bulunamadı")).unwrap();
+        assert_eq!(
+            target,
+            json!({
+                "steps": [null, { "title": "Çalıştır" }],
+                "errors": { "404": "Bulunamadı" }
+            })
+        );
+        assert_eq!(find_missing_paths(&target, &paths), vec!["steps.0"]);
+    }
+
     #[test]
     fn test_missing_translation_detection() {
-        let source_keys = vec![
+        let source_keys = [
             "app.title".to_string(),
             "app.description".to_string(),
             "buttons.save".to_string(),
             "buttons.cancel".to_string(),
         ];
 
-        let existing_keys = vec!["app.title".to_string(), "buttons.save".to_string()];
+        let existing_keys = ["app.title".to_string(), "buttons.save".to_string()];
 
         let missing_keys: Vec<String> = source_keys
             .iter()
@@ -252,6 +291,61 @@ mod tests {
         assert!(progress_bar.contains("7/10"));
     }
 
+    #[test]
+    fn test_calculate_translation_progress() {
+        let source = json!({
+            "title": "Title",
+            "menu": { "home": "Home", "about": "About", "contact": "Contact" }
+        });
+        let target = json!({
+            "title": "Başlık",
+            "menu": { "home": "Ana Sayfa", "about": "" }
+        });
+
+        assert_eq!(
+            find_missing_paths(&target, &["menu.contact".to_string(), "title".to_string()]),
+            vec!["menu.contact"]
+        );
+
+        let progress = calculate_translation_progress(&source, &target);
+        assert_eq!(progress.total_keys, 4);
+        assert_eq!(progress.translated, 2);
+        assert_eq!(progress.missing_keys, vec!["menu.about", "menu.contact"]);
+        assert_eq!(progress.percentage(), 50.0);
+        assert!(!progress.is_complete());
+
+        let empty = calculate_translation_progress(&json!({}), &json!({}));
+        assert_eq!(empty.percentage(), 100.0);
+    }
+
+    #[test]
+    fn test_get_sync_statistics() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("source.json"),
+            r#"{ "a": "A", "b": "B", "c": "C", "d": "D" }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("tr.json"),
+            r#"{ "a": "A", "b": "B", "c": "C", "d": "D" }"#,
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("de.json"), r#"{ "a": "A" }"#).unwrap();
+
+        let stats = get_sync_statistics(temp_dir.path(), "source.json").unwrap();
+
+        assert_eq!(stats.total_keys, 4);
+        assert_eq!(stats.languages.len(), 2);
+        assert_eq!(stats.languages[0].code, "de");
+        assert_eq!(stats.languages[0].progress.missing(), 3);
+        assert!(stats.languages[0].last_modified.is_some());
+        assert_eq!(stats.complete_languages(), 1);
+        assert_eq!(stats.overall_percentage(), 62.5);
+
+        assert!(get_sync_statistics(temp_dir.path(), "missing.json").is_err());
+    }
+
     #[test]
     fn test_translation_delay_calculation() {
         let delay_seconds = 2;
@@ -415,4 +509,415 @@ mod tests {
         assert!(config.delay_seconds > 0);
         assert!(!config.output_dir.is_empty());
     }
+
+    #[test]
+    fn test_provider_kind_parsing() {
+        assert_eq!(
+            "OpenAI".parse::<ProviderKind>().unwrap(),
+            ProviderKind::OpenAi
+        );
+        assert_eq!(
+            "anthropic".parse::<ProviderKind>().unwrap(),
+            ProviderKind::Anthropic
+        );
+        assert_eq!(ProviderKind::Gemini.api_key_env(), "GEMINI_API_KEY");
+        assert!("mistral".parse::<ProviderKind>().is_err());
+    }
+
+    #[test]
+    fn test_provider_settings_use_selected_provider() {
+        let app_config = AppConfig {
+            translation_provider: "anthropic".to_string(),
+            gemini_api_key: Some("gemini-key".to_string()),
+            anthropic_api_key: Some("anthropic-key".to_string()),
+            anthropic_model: "claude-3-5-sonnet-latest".to_string(),
+            ..Default::default()
+        };
+
+        let (provider, api_key, model) = provider_settings(&app_config);
+        assert_eq!(provider, ProviderKind::Anthropic);
+        assert_eq!(api_key.as_deref(), Some("anthropic-key"));
+        assert_eq!(model, "claude-3-5-sonnet-latest");
+
+        let config = TranslationConfig::from(app_config);
+        assert_eq!(config.provider, ProviderKind::Anthropic);
+        assert_eq!(config.api_key, "anthropic-key");
+    }
+
+    #[test]
+    fn test_create_provider_matches_config() {
+        let mut config = TranslationConfig::from(AppConfig::default());
+        assert_eq!(create_provider(&config).unwrap().name(), "Gemini");
+
+        config.provider = ProviderKind::OpenAi;
+        assert_eq!(create_provider(&config).unwrap().name(), "OpenAI");
+
+        config.provider = ProviderKind::Anthropic;
+        assert_eq!(create_provider(&config).unwrap().name(), "Anthropic");
+    }
+
+    #[tokio::test]
+    async fn test_gemini_batch_against_mock_server() {
+        let server = MockServer::start();
+        server.mock(
+            "POST",
+            "/v1beta/models/gemini-1.5-flash:generateContent",
+            200,
+            &fixture("gemini/generate_content.json"),
+        );
+
+        let temp_dir = tempdir().unwrap();
+        let source = json!({
+            "app": { "title": "My App" },
+            "buttons": { "save": "Save", "cancel": "Cancel" }
+        });
+        fs::write(temp_dir.path().join("en.json"), source.to_string()).unwrap();
+        fs::write(
+            temp_dir.path().join("tr.json"),
+            json!({ "buttons": { "save": "Kaydet" } }).to_string(),
+        )
+        .unwrap();
+
+        let config = TranslationConfig {
+            provider: ProviderKind::Gemini,
+            api_key: "test-key".to_string(),
+            model: "gemini-1.5-flash".to_string(),
+            delay_seconds: 0,
+            messages_dir: temp_dir.path().to_path_buf(),
+            source_file: "en.json".to_string(),
+            use_cache: false,
+            fallback: None,
+            max_retries: 0,
+            concurrency: 1,
+        };
+        let provider = GeminiProvider::new("test-key", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&server.url());
+        let sync = TranslationSync::with_provider(config, Box::new(provider));
+
+        let mut turkish = Language::from_code("tr");
+        turkish.file_name = Some("tr.json".to_string());
+        sync.sync_languages(&[turkish]).await.unwrap();
+
+        let written: Value =
+            serde_json::from_str(&fs::read_to_string(temp_dir.path().join("tr.json")).unwrap())
+                .unwrap();
+        assert_eq!(written["app"]["title"], "Uygulamam");
+        assert_eq!(written["buttons"]["cancel"], "İptal");
+        assert_eq!(written["buttons"]["save"], "Kaydet");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].path.ends_with("?key=test-key"));
+        assert!(requests[0].body.contains("app.title||My App"));
+        assert!(!requests[0].body.contains("buttons.save"));
+    }
+
+    fn openai_reply(content: &str) -> String {
+        json!({ "choices": [{ "message": { "role": "assistant", "content": content } }] })
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_failover_provider_switches_after_rate_limit() {
+        let gemini = MockServer::start();
+        gemini.mock(
+            "POST",
+            "/v1beta/models/gemini-1.5-flash:generateContent",
+            429,
+            r#"{"error": {"code": 429, "status": "RESOURCE_EXHAUSTED"}}"#,
+        );
+        let openai = MockServer::start();
+        openai.mock(
+            "POST",
+            "/v1/chat/completions",
+            200,
+            &openai_reply("app.title||Uygulamam"),
+        );
+
+        let provider = FailoverProvider::new(
+            Box::new(
+                GeminiProvider::new("g", "gemini-1.5-flash")
+                    .unwrap()
+                    .with_base_url(&gemini.url()),
+            ),
+            Box::new(
+                OpenAiProvider::new("o", "gpt-4o-mini")
+                    .unwrap()
+                    .with_base_url(&openai.url()),
+            ),
+        );
+        assert_eq!(provider.name(), "Gemini");
+
+        for _ in 0..2 {
+            let reply = provider.complete("Translate").await.unwrap();
+            assert_eq!(reply, "app.title||Uygulamam");
+        }
+        assert!(provider.has_failed_over());
+        assert_eq!(provider.name(), "OpenAI");
+        // The failing provider isn't retried for every batch
+        assert_eq!(gemini.requests().len(), 1);
+        assert_eq!(openai.requests().len(), 2);
+
+        // Configured fallback only applies when it differs from the primary
+        let mut app_config = AppConfig {
+            translation_fallback_provider: Some("openai".to_string()),
+            openai_api_key: Some("openai-key".to_string()),
+            ..Default::default()
+        };
+        let fallback = fallback_settings(&app_config).unwrap();
+        assert_eq!(fallback.provider, ProviderKind::OpenAi);
+        assert_eq!(fallback.api_key, "openai-key");
+        app_config.translation_provider = "openai".to_string();
+        assert!(fallback_settings(&app_config).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_compare_providers_side_by_side() {
+        let paths: Vec<String> = (0..10).map(|i| format!("key{}", i)).collect();
+        assert_eq!(sample_paths(&paths, 3), vec!["key0", "key4", "key8"]);
+        assert_eq!(sample_paths(&paths, 20).len(), 10);
+        assert!(sample_paths(&paths, 0).is_empty());
+
+        let gemini = MockServer::start();
+        gemini.mock(
+            "POST",
+            "/v1beta/models/gemini-1.5-flash:generateContent",
+            200,
+            &fixture("gemini/generate_content.json"),
+        );
+        let openai = MockServer::start();
+        openai.mock(
+            "POST",
+            "/v1/chat/completions",
+            200,
+            &openai_reply("app.title||Uygulamam\nbuttons.save||Kaydet ve kapat"),
+        );
+
+        let source = json!({
+            "app": { "title": "My App" },
+            "buttons": { "save": "Save", "cancel": "Cancel" }
+        });
+        let keys = vec![
+            "app.title".to_string(),
+            "buttons.save".to_string(),
+            "buttons.cancel".to_string(),
+        ];
+        let first = GeminiProvider::new("g", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&gemini.url());
+        let second = OpenAiProvider::new("o", "gpt-4o-mini")
+            .unwrap()
+            .with_base_url(&openai.url());
+        let report = compare_providers(
+            &first,
+            &second,
+            &source,
+            &keys,
+            &Language::from_code("tr"),
+            &Glossary::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!((report.first, report.second), ("Gemini", "OpenAI"));
+        assert_eq!(report.rows.len(), 3);
+        assert!(report.rows[0].is_same());
+        assert_eq!(report.rows[1].second.as_deref(), Some("Kaydet ve kapat"));
+        assert!(!report.rows[1].is_same());
+        // OpenAI skipped a key
+        assert_eq!(report.rows[2].second, None);
+        assert!(!report.rows[2].is_same());
+    }
+
+    #[tokio::test]
+    async fn test_retry_provider_backs_off_on_rate_limits() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(0, 0.0), Duration::from_millis(500));
+        assert_eq!(policy.delay(2, 1.0), Duration::from_secs(4));
+        assert_eq!(policy.delay(10, 1.0), Duration::from_secs(60));
+
+        let now = chrono::DateTime::parse_from_rfc3339("2015-10-21T07:27:30Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(parse_retry_after("7", now), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+
+        let path = "/v1beta/models/gemini-1.5-flash:generateContent";
+        let server = MockServer::start();
+        server
+            .mock_once("POST", path, 429, r#"{"error": {"code": 429}}"#)
+            .mock_once("POST", path, 503, "overloaded")
+            .mock("POST", path, 200, &fixture("gemini/generate_content.json"));
+        let fast = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        };
+        let gemini = GeminiProvider::new("key", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&server.url());
+        let provider = RetryProvider::new(Box::new(gemini), fast);
+        let reply = provider.complete("Translate").await.unwrap();
+        assert!(reply.starts_with("app.title||Uygulamam"));
+        assert_eq!(server.requests().len(), 3);
+
+        // Bad requests aren't retried
+        let server = MockServer::start();
+        server.mock("POST", path, 400, "API key not valid");
+        let gemini = GeminiProvider::new("key", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&server.url());
+        let error = RetryProvider::new(Box::new(gemini), fast)
+            .complete("Translate")
+            .await
+            .unwrap_err();
+        assert_eq!(error.downcast_ref::<ApiError>().unwrap().status, 400);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_does_not_abort_language() {
+        let path = "/v1beta/models/gemini-1.5-flash:generateContent";
+        let server = MockServer::start();
+        server
+            .mock_once("POST", path, 200, &fixture("gemini/generate_content.json"))
+            .mock("POST", path, 500, "internal error");
+
+        // 11 keys: "app.title" and k00..k08 in the first batch, k09 in the second
+        let temp_dir = tempdir().unwrap();
+        let mut source = json!({ "app": { "title": "My App" } });
+        for i in 0..10 {
+            source[format!("k{:02}", i)] = json!(format!("Text {}", i));
+        }
+        fs::write(temp_dir.path().join("en.json"), source.to_string()).unwrap();
+
+        let config = TranslationConfig {
+            provider: ProviderKind::Gemini,
+            api_key: "test-key".to_string(),
+            model: "gemini-1.5-flash".to_string(),
+            delay_seconds: 0,
+            messages_dir: temp_dir.path().to_path_buf(),
+            source_file: "en.json".to_string(),
+            use_cache: false,
+            fallback: None,
+            max_retries: 0,
+            concurrency: 1,
+        };
+        let provider = GeminiProvider::new("test-key", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&server.url());
+        let sync = TranslationSync::with_provider(config, Box::new(provider));
+        sync.sync_languages(&[Language::from_code("tr")])
+            .await
+            .unwrap();
+
+        let written: Value =
+            serde_json::from_str(&fs::read_to_string(temp_dir.path().join("tr.json")).unwrap())
+                .unwrap();
+        assert_eq!(written["app"]["title"], "Uygulamam");
+
+        let failures = sync.batch_failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!((failures[0].batch, failures[0].batches), (2, 2));
+        assert_eq!(failures[0].keys, vec!["k09"]);
+        assert!(failures[0].error.contains("(500)"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_provider_spaces_requests() {
+        let path = "/v1beta/models/gemini-1.5-flash:generateContent";
+        let server = MockServer::start();
+        server.mock("POST", path, 200, &fixture("gemini/generate_content.json"));
+        let gemini = GeminiProvider::new("key", "gemini-1.5-flash")
+            .unwrap()
+            .with_base_url(&server.url());
+        let provider = RateLimitedProvider::new(Box::new(gemini), Duration::from_millis(100));
+
+        let started = std::time::Instant::now();
+        let (first, second, third) = tokio::join!(
+            provider.complete("Translate"),
+            provider.complete("Translate"),
+            provider.complete("Translate")
+        );
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    /// Answers with the source text and records how many prompts it
+    /// worked on at the same time.
+    #[derive(Default)]
+    struct EchoProvider {
+        running: AtomicUsize,
+        most_running: Arc<AtomicUsize>,
+    }
+
+    impl EchoProvider {
+        async fn echo(&self, prompt: &str) -> anyhow::Result<String> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.most_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(prompt
+                .lines()
+                .filter(|line| line.contains("||"))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+
+    impl TranslationProvider for EchoProvider {
+        fn name(&self) -> &'static str {
+            "Echo"
+        }
+
+        fn complete<'a>(&'a self, prompt: &'a str) -> ProviderFuture<'a> {
+            Box::pin(self.echo(prompt))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sync_languages_translates_languages_concurrently() {
+        let temp_dir = tempdir().unwrap();
+        fs::write(
+            temp_dir.path().join("en.json"),
+            json!({ "app": { "title": "My App" } }).to_string(),
+        )
+        .unwrap();
+
+        let config = TranslationConfig {
+            provider: ProviderKind::Gemini,
+            api_key: "test-key".to_string(),
+            model: "gemini-1.5-flash".to_string(),
+            delay_seconds: 0,
+            messages_dir: temp_dir.path().to_path_buf(),
+            source_file: "en.json".to_string(),
+            use_cache: false,
+            fallback: None,
+            max_retries: 0,
+            concurrency: 2,
+        };
+        let echo = EchoProvider::default();
+        let most_running = echo.most_running.clone();
+        let sync = TranslationSync::with_provider(config, Box::new(echo));
+        let languages: Vec<Language> = ["de", "fr", "tr", "es", "it"]
+            .iter()
+            .map(|code| Language::from_code(code))
+            .collect();
+        sync.sync_languages(&languages).await.unwrap();
+
+        assert_eq!(most_running.load(Ordering::SeqCst), 2);
+        for code in ["de", "fr", "tr", "es", "it"] {
+            let written: Value = serde_json::from_str(
+                &fs::read_to_string(temp_dir.path().join(format!("{}.json", code))).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(written["app"]["title"], "My App");
+        }
+    }
 }
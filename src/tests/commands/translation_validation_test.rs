@@ -0,0 +1,123 @@
+use crate::commands::translation_validation::{
+    extract_placeholders, extract_tags, validate_messages, validate_translations, IssueKind,
+};
+use serde_json::json;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_extract_placeholders() {
+    assert_eq!(
+        extract_placeholders("Hi {name}, you have {{count}} new %s in {appName}"),
+        vec!["%s", "{appName}", "{name}", "{{count}}"]
+    );
+    assert_eq!(
+        extract_placeholders("{ count, plural, one {# item} other {# items} }").len(),
+        0
+    );
+    assert!(extract_placeholders("No placeholders here").is_empty());
+}
+
+#[test]
+fn test_extract_tags() {
+    assert_eq!(
+        extract_tags(r#"Read the <a href="/terms">terms</a><br/>"#),
+        vec!["/a", "a", "br"]
+    );
+    assert!(extract_tags("1 < 2 and 3 > 2").is_empty());
+}
+
+#[test]
+fn test_validate_messages_reports_each_kind() {
+    let source = json!({
+        "welcome": "Welcome to {appName}",
+        "terms": "Accept the <b>terms</b>",
+        "nested": { "save": "Save", "cancel": "Cancel", "ok": "OK" },
+        "count": "{count} items"
+    });
+    let target = json!({
+        "welcome": "Hoş geldiniz",
+        "terms": "<b>Koşulları</b> kabul edin",
+        "nested": { "save": "  ", "ok": "OK" },
+        "count": "{count} öğe"
+    });
+
+    let issues = validate_messages(&source, &target);
+    let kinds: Vec<_> = issues
+        .iter()
+        .map(|issue| (issue.key.as_str(), issue.kind))
+        .collect();
+
+    assert!(kinds.contains(&("welcome", IssueKind::Placeholders)));
+    assert!(kinds.contains(&("nested.save", IssueKind::Empty)));
+    assert!(kinds.contains(&("nested.cancel", IssueKind::Missing)));
+    assert!(kinds.contains(&("nested.ok", IssueKind::Identical)));
+    assert!(!kinds
+        .iter()
+        .any(|(key, _)| *key == "terms" || *key == "count"));
+
+    let placeholder_issue = issues
+        .iter()
+        .find(|issue| issue.kind == IssueKind::Placeholders)
+        .unwrap();
+    assert_eq!(placeholder_issue.detail, "missing {appName}");
+    assert!(!IssueKind::Identical.is_error());
+}
+
+#[test]
+fn test_validate_messages_checks_plurals_and_arrays() {
+    let source = json!({
+        "files": "{count, plural, one {# file in {folder}} other {# files in {folder}}}",
+        "steps": ["Install", "Run {command}"]
+    });
+    let target = json!({
+        "files": "{count, plural, one {{folder} içinde # dosya} other {{folder} içinde # dosya}}",
+        "steps": ["Kur"]
+    });
+    let issues = validate_messages(&source, &target);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        (issues[0].key.as_str(), issues[0].kind),
+        ("steps.1", IssueKind::Missing)
+    );
+
+    let target = json!({
+        "files": "{count, plural, one {# dosya}",
+        "steps": ["Kur", "{command} çalıştır"]
+    });
+    let issues = validate_messages(&source, &target);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Placeholders);
+    assert!(issues[0].detail.starts_with("invalid ICU message"));
+}
+
+#[test]
+fn test_validate_translations_report() {
+    let temp_dir = tempdir().unwrap();
+    fs::write(
+        temp_dir.path().join("source.json"),
+        r#"{ "hello": "Hello <b>{name}</b>" }"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("tr.json"),
+        r#"{ "hello": "Merhaba <b>{name}</b>" }"#,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("de.json"),
+        r#"{ "hello": "Hallo <i>{name}</i>" }"#,
+    )
+    .unwrap();
+
+    let report = validate_translations(temp_dir.path(), "source.json").unwrap();
+
+    assert_eq!(report.languages.len(), 2);
+    assert_eq!(report.languages[0].code, "de");
+    assert_eq!(report.languages[0].count(IssueKind::HtmlTags), 1);
+    assert!(report.languages[1].issues.is_empty());
+    assert_eq!(report.error_count(), 1);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["languages"][0]["issues"][0]["kind"], "html_tags");
+}
@@ -0,0 +1,100 @@
+use crate::commands::lockfiles::Ecosystem;
+use crate::commands::verify_deps::{
+    cargo_lock_checksums, compare_checksums, package_lock_checksums, requirements_checksums,
+    Checksum, VerifyStatus,
+};
+
+#[test]
+fn test_parse_sri_to_hex() {
+    // sha1 of "abc"
+    let checksums = Checksum::parse_sri("sha1-qZk+NkcGgWq6PiVxeFDCbJzQ2J0=");
+    assert_eq!(
+        checksums,
+        vec![Checksum::new(
+            "sha1",
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        )]
+    );
+    assert!(Checksum::parse_sri("not-base64!").is_empty());
+    assert_eq!(
+        Checksum::parse_prefixed("sha256:ABCDEF"),
+        Some(Checksum::new("sha256", "abcdef"))
+    );
+}
+
+#[test]
+fn test_lockfile_checksums() {
+    let cargo = r#"
+[[package]]
+name = "my-app"
+version = "0.1.0"
+
+[[package]]
+name = "serde"
+version = "1.0.190"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "91d3c334ca1ee894a2c6f6ad698fe8c435b76d504b13d436f0685d648d6d96f7"
+"#;
+    let packages = cargo_lock_checksums(cargo);
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0].package.name, "serde");
+    assert_eq!(packages[0].checksums[0].algorithm, "sha256");
+
+    let npm = r#"{
+        "lockfileVersion": 3,
+        "packages": {
+            "": { "name": "app" },
+            "node_modules/left-pad": {
+                "version": "1.3.0",
+                "integrity": "sha1-qZk+NkcGgWq6PiVxeFDCbJzQ2J0="
+            },
+            "node_modules/local": { "link": true },
+            "node_modules/@acme/ui": { "version": "2.0.0" }
+        }
+    }"#;
+    let packages = package_lock_checksums(npm).unwrap();
+    assert_eq!(packages.len(), 2);
+    let left_pad = packages
+        .iter()
+        .find(|p| p.package.name == "left-pad")
+        .unwrap();
+    assert_eq!(left_pad.package.ecosystem, Ecosystem::Npm);
+    assert_eq!(left_pad.checksums.len(), 1);
+
+    let requirements = "\
+# generated by pip-compile
+requests[socks]==2.31.0 \\
+    --hash=sha256:aaaa \\
+    --hash=sha256:bbbb
+flask>=2.0
+idna==3.4  # via requests
+";
+    let packages = requirements_checksums(requirements);
+    assert_eq!(packages.len(), 2);
+    assert_eq!(packages[0].package.name, "requests");
+    assert_eq!(packages[0].checksums.len(), 2);
+    assert_eq!(packages[1].package.version, "3.4");
+    assert!(packages[1].checksums.is_empty());
+}
+
+#[test]
+fn test_compare_checksums() {
+    let pinned = vec![Checksum::new("sha256", "aaaa")];
+
+    assert_eq!(
+        compare_checksums(&pinned, &[Checksum::new("sha256", "AAAA")]),
+        VerifyStatus::Verified
+    );
+    assert!(matches!(
+        compare_checksums(&pinned, &[Checksum::new("sha256", "bbbb")]),
+        VerifyStatus::Mismatch(_)
+    ));
+    assert!(matches!(
+        compare_checksums(&pinned, &[Checksum::new("sha512", "aaaa")]),
+        VerifyStatus::Unverifiable(_)
+    ));
+    assert!(matches!(
+        compare_checksums(&[], &pinned),
+        VerifyStatus::Unverifiable(_)
+    ));
+}
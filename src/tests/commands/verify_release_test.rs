@@ -0,0 +1,64 @@
+use crate::commands::verify_release::verify_release;
+use crate::tests::support::MockRunner;
+use std::path::Path;
+
+const SHA: &str = "3f2a9c1d8e7b6a5f4e3d2c1b0a9f8e7d6c5b4a39";
+
+#[test]
+fn test_verify_signed_release_on_branch() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git rev-parse --verify --quiet v1.2.0^{commit}", SHA)
+        .respond("git cat-file -t v1.2.0", "tag\n")
+        .fail(
+            "git rev-parse --verify --quiet refs/remotes/origin/main",
+            "",
+        );
+    let verification = verify_release(&runner, Path::new("."), "v1.2.0", "main").unwrap();
+
+    assert!(verification.is_valid());
+    assert_eq!(verification.commit, SHA);
+    assert_eq!(verification.checks[0].detail, "valid signature");
+    assert_eq!(verification.checks[1].detail, "3f2a9c1 is on main");
+    assert!(runner.calls().contains(&format!(
+        "git merge-base --is-ancestor {} refs/heads/main",
+        SHA
+    )));
+}
+
+#[test]
+fn test_verify_release_reports_failures() {
+    let runner = MockRunner::new();
+    runner
+        .respond("git rev-parse --verify --quiet v1.3.0^{commit}", SHA)
+        .respond("git cat-file -t v1.3.0", "tag\n")
+        .fail(
+            "git verify-tag v1.3.0",
+            "gpg: Signature made Mon 01 Jan 2024\ngpg: BAD signature from \"Mallory\"\n",
+        )
+        .fail("git merge-base", "");
+    let verification = verify_release(&runner, Path::new("."), "v1.3.0", "main").unwrap();
+
+    assert!(!verification.is_valid());
+    assert_eq!(
+        verification.checks[0].detail,
+        "BAD signature from \"Mallory\""
+    );
+    assert_eq!(
+        verification.checks[1].detail,
+        "3f2a9c1 is not on origin/main"
+    );
+
+    // Lightweight tags can't be signed
+    let lightweight = MockRunner::new();
+    lightweight
+        .respond("git rev-parse --verify --quiet v1.0.0^{commit}", SHA)
+        .respond("git cat-file -t v1.0.0", "commit\n");
+    let verification = verify_release(&lightweight, Path::new("."), "v1.0.0", "main").unwrap();
+    assert!(!verification.checks[0].passed);
+
+    let missing = MockRunner::new();
+    missing.fail("git rev-parse", "");
+    let error = verify_release(&missing, Path::new("."), "v9.9.9", "main").unwrap_err();
+    assert!(error.to_string().contains("does not exist"));
+}
@@ -1,3 +1,10 @@
+use crate::commands::release_notes::{CommitInfo, DiffStats};
+use crate::commands::version_management::{
+    check_version_consistency, check_versions, commit_bump_level, detect_current_version,
+    fix_version_drift, infer_bump, read_manifest_version, read_version_file, render_history_graph,
+    render_history_table, set_manifest_version, update_cargo_lock, update_manifests,
+    version_history, BumpLevel, Manifest, VersionSource,
+};
 use std::fs;
 use tempfile::tempdir;
 
@@ -323,7 +330,7 @@ serde = "1.0"
             changes: Vec<String>,
         }
 
-        let version_history = vec![
+        let version_history = [
             VersionEntry {
                 version: "1.0.0".to_string(),
                 timestamp: "2025-01-01".to_string(),
@@ -461,4 +468,330 @@ serde = "1.0"
             );
         }
     }
+
+    #[test]
+    fn test_set_cargo_manifest_version() {
+        let content = "[package]\nname = \"app\"\nversion = \"1.2.3\" # keep me\n\n[dependencies]\nserde = { version = \"1.0\" }\n";
+
+        assert_eq!(
+            read_manifest_version(Manifest::Cargo, content).as_deref(),
+            Some("1.2.3")
+        );
+        let updated = set_manifest_version(Manifest::Cargo, content, "1.3.0").unwrap();
+        assert!(updated.contains("version = \"1.3.0\" # keep me"));
+        assert!(updated.contains("serde = { version = \"1.0\" }"));
+
+        // Dependency versions alone don't count as a project version
+        assert!(
+            read_manifest_version(Manifest::Cargo, "[dependencies]\nversion = \"1\"\n").is_none()
+        );
+        assert!(set_manifest_version(Manifest::Cargo, "[workspace]\n", "1.0.0").is_err());
+    }
+
+    #[test]
+    fn test_set_json_and_pyproject_versions() {
+        let package_json = "{\n  \"name\": \"app\",\n  \"version\": \"0.9.0\",\n  \"dependencies\": { \"left-pad\": \"0.9.0\" }\n}\n";
+        let updated = set_manifest_version(Manifest::PackageJson, package_json, "1.0.0").unwrap();
+        assert!(updated.contains("\"version\": \"1.0.0\""));
+        assert!(updated.contains("\"left-pad\": \"0.9.0\""));
+
+        let composer = r#"{"name": "acme/app", "require": {"php": ">=8.1"}}"#;
+        assert!(read_manifest_version(Manifest::Composer, composer).is_none());
+
+        // Nested "version" keys with the same value come first and stay
+        let nested = r#"{"publishConfig": {"version": "0.9.0"}, "tags": ["version", "0.9.0"], "version": "0.9.0"}"#;
+        assert_eq!(
+            set_manifest_version(Manifest::PackageJson, nested, "1.0.0").unwrap(),
+            r#"{"publishConfig": {"version": "0.9.0"}, "tags": ["version", "0.9.0"], "version": "1.0.0"}"#
+        );
+        let escaped = r#"{"description": "a \"version\": \"0.9.0\"", "version" : "0.9.0"}"#;
+        assert_eq!(
+            read_manifest_version(Manifest::PackageJson, escaped).as_deref(),
+            Some("0.9.0")
+        );
+        assert_eq!(
+            set_manifest_version(Manifest::PackageJson, escaped, "1.0.0").unwrap(),
+            r#"{"description": "a \"version\": \"0.9.0\"", "version" : "1.0.0"}"#
+        );
+
+        let pyproject = "[tool.poetry]\nname = \"app\"\nversion = '2.0.0'\n";
+        assert_eq!(
+            set_manifest_version(Manifest::Pyproject, pyproject, "2.1.0").unwrap(),
+            "[tool.poetry]\nname = \"app\"\nversion = '2.1.0'\n"
+        );
+    }
+
+    #[test]
+    fn test_update_manifests() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("Cargo.lock"),
+            "[[package]]\nname = \"app\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("package.json"), r#"{"version": "0.1.0"}"#).unwrap();
+
+        assert_eq!(
+            detect_current_version(root, &Manifest::ALL).as_deref(),
+            Some("0.1.0")
+        );
+
+        // Only the selected manifests change
+        let changed = update_manifests(root, &[Manifest::PackageJson], "0.2.0").unwrap();
+        assert_eq!(changed, vec![root.join("package.json")]);
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("0.1.0"));
+
+        let changed = update_manifests(root, &Manifest::ALL, "0.2.0").unwrap();
+        assert_eq!(changed.len(), 3);
+        assert!(fs::read_to_string(root.join("Cargo.lock"))
+            .unwrap()
+            .contains("version = \"0.2.0\""));
+    }
+
+    #[test]
+    fn test_manifest_names_and_cargo_lock() {
+        assert_eq!(
+            "package.json".parse::<Manifest>().unwrap(),
+            Manifest::PackageJson
+        );
+        assert_eq!("Cargo".parse::<Manifest>().unwrap(), Manifest::Cargo);
+        assert!("gemspec".parse::<Manifest>().is_err());
+
+        let lock = "[[package]]\nname = \"dep\"\nversion = \"1.0.0\"\n\n[[package]]\nname = \"app\"\nversion = \"1.0.0\"\n";
+        let updated = update_cargo_lock(lock, "app", "1.0.0", "1.1.0");
+        assert!(updated.starts_with("[[package]]\nname = \"dep\"\nversion = \"1.0.0\""));
+        assert!(updated.ends_with("name = \"app\"\nversion = \"1.1.0\"\n"));
+
+        let crlf = lock.replace('\n', "\r\n");
+        let updated = update_cargo_lock(&crlf, "app", "1.0.0", "1.1.0");
+        assert!(updated.ends_with("name = \"app\"\r\nversion = \"1.1.0\"\r\n"));
+    }
+
+    fn commit(hash: &str, message: &str) -> CommitInfo {
+        CommitInfo {
+            message: message.to_string(),
+            author_name: "Jane".to_string(),
+            author_email: "jane@acme.dev".to_string(),
+            hash: hash.to_string(),
+            timestamp: 0,
+            stats: DiffStats::default(),
+        }
+    }
+
+    #[test]
+    fn test_commit_bump_level() {
+        assert_eq!(
+            commit_bump_level("fix(ui): align button"),
+            Some(BumpLevel::Patch)
+        );
+        assert_eq!(
+            commit_bump_level("perf: faster scan"),
+            Some(BumpLevel::Patch)
+        );
+        assert_eq!(
+            commit_bump_level("feat(api): add endpoint"),
+            Some(BumpLevel::Minor)
+        );
+        assert_eq!(
+            commit_bump_level("refactor!: drop v1 API"),
+            Some(BumpLevel::Major)
+        );
+        assert_eq!(
+            commit_bump_level("feat: new config\n\nBREAKING CHANGE: renamed keys"),
+            Some(BumpLevel::Major)
+        );
+        assert_eq!(commit_bump_level("docs: update README"), None);
+        assert_eq!(commit_bump_level("Merge branch 'main'"), None);
+    }
+
+    #[test]
+    fn test_infer_bump() {
+        let commits = vec![
+            commit("a1", "fix: crash on start"),
+            commit("b2", "feat: dark mode"),
+            commit("c3", "chore: bump deps"),
+            commit("d4", "feat(cli): --json flag"),
+        ];
+
+        let (level, justifying) = infer_bump(&commits).unwrap();
+        assert_eq!(level, BumpLevel::Minor);
+        let hashes: Vec<&str> = justifying.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(hashes, vec!["b2", "d4"]);
+
+        assert!(infer_bump(&[commit("e5", "docs: typo")]).is_none());
+    }
+
+    #[test]
+    fn test_version_consistency_and_fix() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"1.2.0\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("package.json"),
+            "{\n  \"name\": \"app\",\n  \"version\": \"1.1.0\"\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("VERSION"), "v1.2.0\n").unwrap();
+        assert_eq!(
+            read_version_file("\n  v2.0.0 \n"),
+            Some("2.0.0".to_string())
+        );
+
+        // The tag is behind: an unreleased bump, not drift
+        let report = check_version_consistency(root, Some("v1.1.0"), None);
+        assert_eq!(report.expected.as_deref(), Some("1.2.0"));
+        assert_eq!(report.found.len(), 4);
+        assert!(report.is_untagged());
+        let drift = report.drift();
+        assert_eq!(drift.len(), 1);
+        assert_eq!(
+            drift[0].source,
+            VersionSource::Manifest(Manifest::PackageJson)
+        );
+
+        // Versions compare as semver, not as strings
+        let report = check_version_consistency(root, Some("v1.10.0"), None);
+        assert_eq!(report.expected.as_deref(), Some("1.10.0"));
+        assert_eq!(report.drift().len(), 3);
+
+        let changed = fix_version_drift(root, &report).unwrap();
+        assert_eq!(changed.len(), 3);
+        assert_eq!(
+            fs::read_to_string(root.join("VERSION")).unwrap(),
+            "v1.10.0\n"
+        );
+        let report = check_version_consistency(root, Some("v1.10.0"), None);
+        assert!(report.drift().is_empty());
+        assert!(!report.is_untagged());
+
+        // An explicit version older than the tag flags the tag
+        let report = check_version_consistency(root, Some("v1.10.0"), Some("v1.9.0"));
+        assert_eq!(report.expected.as_deref(), Some("1.9.0"));
+        assert_eq!(report.drift().len(), 4);
+        assert_eq!(
+            report.drift()[3].source.label(),
+            "git tag v1.10.0".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_versions_of_another_directory() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"1.2.0\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("VERSION"), "1.1.0\n").unwrap();
+
+        let error = check_versions(root, false, None).await.unwrap_err();
+        assert!(error.to_string().contains("out of sync with 1.2.0"));
+        check_versions(root, true, None).await.unwrap();
+        assert_eq!(fs::read_to_string(root.join("VERSION")).unwrap(), "1.2.0\n");
+        check_versions(root, false, None).await.unwrap();
+    }
+
+    #[test]
+    fn test_version_history_from_tags() {
+        let dir = tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let tree = repo
+            .find_tree(repo.treebuilder(None).unwrap().write().unwrap())
+            .unwrap();
+        let jane = git2::Signature::new("Jane", "jane@acme.dev", &git2::Time::new(0, 0)).unwrap();
+
+        let mut parent: Option<git2::Commit> = None;
+        let mut commit = |message: &str, minutes: i64| {
+            let signature =
+                git2::Signature::new("Dev", "dev@acme.dev", &git2::Time::new(minutes * 60, 0))
+                    .unwrap();
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            let oid = repo
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    message,
+                    &tree,
+                    &parents,
+                )
+                .unwrap();
+            parent = Some(repo.find_commit(oid).unwrap());
+            oid
+        };
+
+        commit("feat: first", 1);
+        let first = commit("fix: crash", 2);
+        commit("feat: search", 3);
+        commit("feat: export", 4);
+        let second = commit("fix: typo", 5);
+        commit("docs: readme", 6);
+
+        // v1.0.0 is annotated by Jane, v1.1.0 is lightweight
+        let target = repo.find_object(first, None).unwrap();
+        repo.tag("v1.0.0", &target, &jane, "Release v1.0.0", false)
+            .unwrap();
+        repo.tag_lightweight("v1.1.0", &repo.find_object(second, None).unwrap(), false)
+            .unwrap();
+
+        let entries = version_history(&repo, Some("1.2.0")).unwrap();
+        let summary: Vec<(&str, &str, &str, usize, bool)> = entries
+            .iter()
+            .map(|e| {
+                (
+                    e.tag.as_str(),
+                    e.version.as_str(),
+                    e.tagger.as_str(),
+                    e.commits,
+                    e.released,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("HEAD", "1.2.0", "Dev", 1, false),
+                ("v1.1.0", "1.1.0", "Dev", 3, true),
+                ("v1.0.0", "1.0.0", "Jane", 2, true),
+            ]
+        );
+        assert_eq!(entries[1].date, "1970-01-01");
+        assert_eq!(
+            entries[1].highlights,
+            vec![("features".to_string(), 2), ("fixes".to_string(), 1)]
+        );
+
+        // Manifests that weren't bumped yet leave the entry unnamed
+        let entries = version_history(&repo, Some("1.1.0")).unwrap();
+        assert_eq!(entries[0].version, "unreleased");
+
+        let table = render_history_table(&entries[1..]);
+        assert_eq!(table[0], "Version  Date        Tagger  Commits  Highlights");
+        assert_eq!(
+            table[1],
+            "v1.1.0   1970-01-01  Dev           3  2 features · 1 fixes"
+        );
+
+        let graph = render_history_graph(&entries);
+        assert_eq!(graph[0], "○ unreleased (HEAD)  1970-01-01  Dev");
+        assert_eq!(graph[1], "│   1 commits · 1 docs");
+        assert_eq!(graph[2], "│");
+        assert_eq!(
+            graph.last().unwrap(),
+            "    2 commits · 1 features · 1 fixes"
+        );
+    }
 }
@@ -0,0 +1,72 @@
+use git2::{Oid, Repository, Signature, Time};
+use std::fs;
+use std::path::Path;
+
+/// A commit on top of HEAD for tests that walk history. Made by "Dev" at
+/// the current time unless `author`/`at` say otherwise.
+pub struct TestCommit<'a> {
+    message: &'a str,
+    files: Vec<(&'a str, &'a str)>,
+    author: (&'a str, &'a str),
+    time: Option<i64>,
+}
+
+impl<'a> TestCommit<'a> {
+    pub fn new(message: &'a str) -> Self {
+        Self {
+            message,
+            files: Vec::new(),
+            author: ("Dev", "dev@example.com"),
+            time: None,
+        }
+    }
+
+    /// Writes and stages a file, creating its directories.
+    pub fn file(mut self, path: &'a str, content: &'a str) -> Self {
+        self.files.push((path, content));
+        self
+    }
+
+    pub fn author(mut self, name: &'a str, email: &'a str) -> Self {
+        self.author = (name, email);
+        self
+    }
+
+    /// Author and commit time, in seconds since the epoch.
+    pub fn at(mut self, time: i64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    pub fn commit(self, repo: &Repository) -> Oid {
+        let mut index = repo.index().unwrap();
+        if let Some(root) = repo.workdir() {
+            for (file, content) in &self.files {
+                let path = root.join(file);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, content).unwrap();
+                index.add_path(Path::new(file)).unwrap();
+            }
+        }
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+
+        let (name, email) = self.author;
+        let signature = match self.time {
+            Some(time) => Signature::new(name, email, &Time::new(time, 0)),
+            None => Signature::now(name, email),
+        }
+        .unwrap();
+        let parent = repo.head().ok().map(|head| head.peel_to_commit().unwrap());
+        let parents: Vec<_> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            self.message,
+            &tree,
+            &parents,
+        )
+        .unwrap()
+    }
+}
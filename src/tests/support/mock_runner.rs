@@ -0,0 +1,92 @@
+use crate::utils::process::{CommandOutput, CommandRunner};
+use anyhow::Result;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Records every command and answers with canned output. Responses match on
+/// the start of the command line (`"gh label list"`); anything else succeeds
+/// with empty output.
+#[derive(Default)]
+pub struct MockRunner {
+    responses: Mutex<Vec<(String, CommandOutput)>>,
+    calls: Mutex<Vec<String>>,
+    inputs: Mutex<Vec<String>>,
+}
+
+impl MockRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn respond(&self, prefix: &str, stdout: &str) -> &Self {
+        self.push(prefix, true, stdout, "")
+    }
+
+    pub fn fail(&self, prefix: &str, stderr: &str) -> &Self {
+        self.push(prefix, false, "", stderr)
+    }
+
+    fn push(&self, prefix: &str, success: bool, stdout: &str, stderr: &str) -> &Self {
+        self.responses.lock().unwrap().push((
+            prefix.to_string(),
+            CommandOutput {
+                success,
+                stdout: stdout.to_string(),
+                stderr: stderr.to_string(),
+            },
+        ));
+        self
+    }
+
+    /// Command lines in call order, e.g. `git push origin v1.2.0`.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// What `run_with_input` wrote to stdin, in call order.
+    pub fn inputs(&self) -> Vec<String> {
+        self.inputs.lock().unwrap().clone()
+    }
+}
+
+impl CommandRunner for MockRunner {
+    fn run(&self, program: &str, args: &[&str], _dir: Option<&Path>) -> Result<CommandOutput> {
+        let command_line = std::iter::once(program)
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.calls.lock().unwrap().push(command_line.clone());
+
+        Ok(self
+            .responses
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(prefix, _)| command_line.starts_with(prefix.as_str()))
+            .map(|(_, output)| output.clone())
+            .unwrap_or(CommandOutput {
+                success: true,
+                ..CommandOutput::default()
+            }))
+    }
+
+    fn run_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: &str,
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput> {
+        self.inputs.lock().unwrap().push(input.to_string());
+        self.run(program, args, dir)
+    }
+
+    fn run_attached(
+        &self,
+        program: &str,
+        args: &[&str],
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput> {
+        self.run(program, args, dir)
+    }
+}
@@ -0,0 +1,171 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path with the query string, still percent-encoded
+    pub path: String,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn path_without_query(&self) -> &str {
+        self.path.split('?').next().unwrap_or_default()
+    }
+}
+
+struct Route {
+    method: String,
+    path: String,
+    status: u16,
+    body: String,
+    /// Answers a single request, then falls through to later routes
+    once: bool,
+}
+
+#[derive(Default)]
+struct State {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// Tiny HTTP/1.1 server on a random local port. Routes match on method and
+/// path (query ignored); unmatched requests get a GitHub-style 404.
+pub struct MockServer {
+    address: String,
+    state: Arc<State>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let address = listener.local_addr().unwrap().to_string();
+        let state = Arc::new(State::default());
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread_state = state.clone();
+        let thread_shutdown = shutdown.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    handle(stream, &thread_state);
+                }
+            }
+        });
+
+        Self {
+            address,
+            state,
+            shutdown,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    pub fn mock(&self, method: &str, path: &str, status: u16, body: &str) -> &Self {
+        self.add_route(method, path, status, body, false)
+    }
+
+    /// Like `mock`, but only for the next matching request.
+    pub fn mock_once(&self, method: &str, path: &str, status: u16, body: &str) -> &Self {
+        self.add_route(method, path, status, body, true)
+    }
+
+    fn add_route(&self, method: &str, path: &str, status: u16, body: &str, once: bool) -> &Self {
+        self.state.routes.lock().unwrap().push(Route {
+            method: method.to_string(),
+            path: path.to_string(),
+            status,
+            body: body.to_string(),
+            once,
+        });
+        self
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Requests other than `GET`, in arrival order.
+    pub fn writes(&self) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method != "GET")
+            .collect()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        // Wake the accept loop so the thread can exit
+        let _ = TcpStream::connect(&self.address);
+    }
+}
+
+fn handle(mut stream: TcpStream, state: &State) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() || request_line.is_empty() {
+        return;
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    let _ = reader.read_exact(&mut body);
+
+    let request = RecordedRequest {
+        method,
+        path,
+        body: String::from_utf8_lossy(&body).to_string(),
+    };
+
+    let (status, response_body) = {
+        let mut routes = state.routes.lock().unwrap();
+        let index = routes.iter().position(|route| {
+            route.method == request.method && route.path == request.path_without_query()
+        });
+        match index {
+            Some(index) if routes[index].once => {
+                let route = routes.remove(index);
+                (route.status, route.body)
+            }
+            Some(index) => (routes[index].status, routes[index].body.clone()),
+            None => (404, r#"{"message":"Not Found"}"#.to_string()),
+        }
+    };
+    state.requests.lock().unwrap().push(request);
+
+    let response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        response_body.len(),
+        response_body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
@@ -0,0 +1,22 @@
+//! Test doubles for flows that talk to GitHub, LLM providers or external
+//! tools, so they run deterministically without network or credentials,
+//! plus throwaway git history to run them against.
+
+pub mod git_repo;
+pub mod mock_runner;
+pub mod mock_server;
+
+pub use git_repo::TestCommit;
+pub use mock_runner::MockRunner;
+pub use mock_server::MockServer;
+
+use std::path::PathBuf;
+
+/// Reads a recorded response from `src/tests/fixtures`.
+pub fn fixture(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/fixtures")
+        .join(name);
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Missing fixture {}: {}", path.display(), e))
+}
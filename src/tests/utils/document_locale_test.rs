@@ -0,0 +1,105 @@
+use crate::utils::document_locale::{DocumentLocale, DocumentTimezone};
+use chrono::{FixedOffset, NaiveDate};
+
+#[test]
+fn test_parse_document_locale() {
+    assert_eq!("tr".parse::<DocumentLocale>().unwrap(), DocumentLocale::Tr);
+    assert_eq!(
+        "de_DE.UTF-8".parse::<DocumentLocale>().unwrap(),
+        DocumentLocale::De
+    );
+    assert_eq!(
+        "en-US".parse::<DocumentLocale>().unwrap(),
+        DocumentLocale::En
+    );
+    assert!("fr".parse::<DocumentLocale>().is_err());
+}
+
+#[test]
+fn test_locale_formatting() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+
+    assert_eq!(DocumentLocale::En.format_date(date), "March 5, 2025");
+    assert_eq!(DocumentLocale::Tr.format_date(date), "5 Mart 2025");
+    assert_eq!(DocumentLocale::De.format_date(date), "5. März 2025");
+    assert_eq!(DocumentLocale::En.format_short_date(date), "2025-03-05");
+    assert_eq!(DocumentLocale::De.format_short_date(date), "05.03.2025");
+
+    assert_eq!(DocumentLocale::En.format_number(1234567), "1,234,567");
+    assert_eq!(DocumentLocale::Tr.format_number(1234), "1.234");
+    assert_eq!(DocumentLocale::De.format_number(999), "999");
+    assert_eq!(DocumentLocale::De.commits(1), "1 Commit");
+    assert_eq!(DocumentLocale::En.commits(2500), "2,500 commits");
+}
+
+#[test]
+fn test_document_templates_have_every_key() {
+    let keys = [
+        "changes_since",
+        "initial_release",
+        "release_date",
+        "new_features",
+        "bug_fixes",
+        "contributors",
+        "timeline_header",
+        "enjoy",
+    ];
+    for locale in DocumentLocale::all() {
+        for key in keys {
+            assert_ne!(locale.t(key), key, "{} is missing {}", locale.code(), key);
+        }
+    }
+    assert_eq!(
+        DocumentLocale::Tr.format("changes_since", &[("tag", "v1.0.0")]),
+        "v1.0.0 sürümünden bu yana değişiklikler"
+    );
+}
+
+#[test]
+fn test_parse_document_timezone() {
+    assert_eq!(
+        "UTC".parse::<DocumentTimezone>().unwrap(),
+        DocumentTimezone::Utc
+    );
+    assert_eq!(
+        "local".parse::<DocumentTimezone>().unwrap(),
+        DocumentTimezone::Local
+    );
+    let istanbul = DocumentTimezone::Fixed(FixedOffset::east_opt(3 * 3600).unwrap());
+    assert_eq!("+03:00".parse::<DocumentTimezone>().unwrap(), istanbul);
+    assert_eq!("+0300".parse::<DocumentTimezone>().unwrap(), istanbul);
+    assert_eq!(
+        "UTC-5".parse::<DocumentTimezone>().unwrap(),
+        DocumentTimezone::Fixed(FixedOffset::west_opt(5 * 3600).unwrap())
+    );
+    // Saved with Display, so it has to parse back
+    assert_eq!(
+        istanbul.to_string().parse::<DocumentTimezone>().unwrap(),
+        istanbul
+    );
+    assert!("Europe/Istanbul".parse::<DocumentTimezone>().is_err());
+    assert!("+15:00".parse::<DocumentTimezone>().is_err());
+}
+
+#[test]
+fn test_timezone_formatting() {
+    // 2025-03-05 22:30 UTC
+    let timestamp = 1_741_213_800;
+    let utc = DocumentTimezone::Utc.at(timestamp).unwrap();
+    assert_eq!(
+        DocumentTimezone::Utc.format_time(DocumentLocale::En, &utc),
+        "22:30"
+    );
+
+    let istanbul = DocumentTimezone::Fixed(FixedOffset::east_opt(3 * 3600).unwrap());
+    let local = istanbul.at(timestamp).unwrap();
+    // Past midnight, so the date moves on too
+    assert_eq!(
+        local.date_naive(),
+        NaiveDate::from_ymd_opt(2025, 3, 6).unwrap()
+    );
+    assert_eq!(
+        istanbul.format_time(DocumentLocale::Tr, &local),
+        "01:30 +03:00"
+    );
+}
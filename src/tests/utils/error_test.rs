@@ -0,0 +1,74 @@
+use crate::commands::config::{resolve_document_locale, ConfigError};
+use crate::commands::pipeline::load_project_file;
+use crate::commands::release_notes::ReleaseNotesError;
+use crate::commands::version_management::check_versions;
+use crate::utils::error::{exit_code, ChecksFailed, ExitCode};
+use crate::utils::process::{CommandRunner, SystemRunner};
+use anyhow::{anyhow, Context};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code(&anyhow!("something broke")), ExitCode::Failure);
+    assert_eq!(
+        exit_code(&ChecksFailed("2 version(s) out of sync".to_string()).into()),
+        ExitCode::ChecksFailed
+    );
+    // Context added on the way up doesn't hide the cause
+    let missing_key: anyhow::Error = ConfigError::MissingApiKey {
+        provider: "Gemini",
+        env: "GEMINI_API_KEY",
+    }
+    .into();
+    let missing_key = missing_key.context("Summarizing release notes");
+    assert_eq!(exit_code(&missing_key), ExitCode::Config);
+
+    let not_found = SystemRunner
+        .run("nitroterm-no-such-tool", &["--version"], None)
+        .unwrap_err();
+    assert_eq!(
+        not_found.to_string(),
+        "nitroterm-no-such-tool is not installed or not on PATH"
+    );
+    assert_eq!(exit_code(&not_found), ExitCode::MissingTool);
+
+    // Release notes errors pass through what they wrap
+    let wrapped = ReleaseNotesError::from(anyhow::Error::from(ChecksFailed(String::new())));
+    assert_eq!(wrapped.exit_code(), ExitCode::ChecksFailed);
+    assert_eq!(
+        [
+            ExitCode::Failure,
+            ExitCode::Config,
+            ExitCode::MissingTool,
+            ExitCode::ChecksFailed,
+            ExitCode::Interrupted
+        ]
+        .map(ExitCode::code),
+        [1, 2, 3, 4, 130]
+    );
+}
+
+#[tokio::test]
+async fn test_command_errors_are_typed() {
+    let locale = resolve_document_locale(Some("fr")).await.unwrap_err();
+    assert_eq!(exit_code(&locale), ExitCode::Config);
+
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join(".nitrokit.toml"), "[pipelines\n").unwrap();
+    let invalid = load_project_file(temp_dir.path())
+        .context("Pipeline failed")
+        .unwrap_err();
+    assert_eq!(exit_code(&invalid), ExitCode::Config);
+
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n",
+    )
+    .unwrap();
+    fs::write(temp_dir.path().join("VERSION"), "1.1.0\n").unwrap();
+    let drift = check_versions(temp_dir.path(), false, None)
+        .await
+        .unwrap_err();
+    assert_eq!(exit_code(&drift), ExitCode::ChecksFailed);
+}
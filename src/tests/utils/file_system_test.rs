@@ -6,6 +6,7 @@ mod tests {
     use tempfile::{tempdir, NamedTempFile};
 
     use crate::utils::file_exists;
+    use crate::utils::file_system::{find_project_files, path_within, Glob, ScanOptions};
     use crate::utils::read_file_to_string;
     use crate::utils::write_string_to_file;
 
@@ -315,4 +316,97 @@ mod tests {
             assert!(file_exists("/tmp"));
         }
     }
+
+    #[test]
+    fn test_glob_matches_names_and_paths() {
+        assert!(Glob::new("fixtures").matches(Path::new("tests/fixtures/app")));
+        assert!(Glob::new("*.bak").matches(Path::new("src/old.bak")));
+        assert!(Glob::new("packages/*").matches(Path::new("packages/web/package.json")));
+        assert!(!Glob::new("packages/*").matches(Path::new("apps/packages/web")));
+        assert!(Glob::new("**/fixtures").matches(Path::new("a/b/fixtures/package.json")));
+        assert!(Glob::new("./apps/").matches(Path::new("apps/web")));
+        assert!(!Glob::new("app?").matches(Path::new("application")));
+    }
+
+    #[test]
+    fn test_find_project_files_recursively() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        for file in [
+            "package.json",
+            "Cargo.toml",
+            "web/package.json",
+            "web/node_modules/dep/package.json",
+            "generated/package.json",
+            ".hidden/package.json",
+            "a/b/c/Cargo.toml",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "{}").unwrap();
+        }
+        let names = ["package.json", "Cargo.toml"];
+        let find = |options: &ScanOptions| -> Vec<String> {
+            find_project_files(root, &names, options)
+                .iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            find(&ScanOptions::top_level()),
+            vec!["package.json", "Cargo.toml"]
+        );
+        assert_eq!(
+            find(&ScanOptions::default()),
+            vec![
+                "package.json",
+                "Cargo.toml",
+                "a/b/c/Cargo.toml",
+                "generated/package.json",
+                "web/package.json",
+            ]
+        );
+        assert_eq!(
+            find(&ScanOptions {
+                max_depth: 1,
+                include: vec!["*.json".to_string()],
+                exclude: vec!["generated".to_string()],
+                ..ScanOptions::default()
+            }),
+            vec!["package.json", "web/package.json"]
+        );
+
+        // Ignored directories are left out in a git repository
+        git2::Repository::init(root).unwrap();
+        fs::write(root.join(".gitignore"), "generated/\n").unwrap();
+        assert!(!find(&ScanOptions::default()).contains(&"generated/package.json".to_string()));
+        assert!(find(&ScanOptions {
+            respect_gitignore: false,
+            ..ScanOptions::default()
+        })
+        .contains(&"generated/package.json".to_string()));
+    }
+
+    #[test]
+    fn test_path_within_root() {
+        let root = tempdir().unwrap();
+        fs::create_dir_all(root.path().join("messages")).unwrap();
+        let root_path = root.path().canonicalize().unwrap();
+
+        assert_eq!(
+            path_within(root.path(), "messages").unwrap(),
+            root_path.join("messages")
+        );
+        assert_eq!(path_within(root.path(), "messages/..").unwrap(), root_path);
+        assert_eq!(
+            path_within(root.path(), "..").unwrap_err().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        assert!(path_within(root.path(), "/etc").is_err());
+        assert_eq!(
+            path_within(root.path(), "missing").unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
 }
@@ -1,4 +1,14 @@
+pub mod document_locale_test;
+pub mod error_test;
 pub mod file_system_test;
 pub mod git_test;
 pub mod logging_test;
+pub mod notify_test;
+pub mod process_test;
+pub mod progress_test;
+pub mod prompt_test;
+pub mod sandbox_test;
+pub mod secrets_test;
+pub mod terminal_test;
+pub mod theme_test;
 pub mod version_check_test;
@@ -0,0 +1,65 @@
+use crate::tests::support::MockServer;
+use crate::utils::notify::{
+    send_notification, webhook_host, webhook_payload, webhook_urls, WebhookKind,
+};
+
+#[test]
+fn test_webhook_kind_and_payloads() {
+    assert_eq!(
+        WebhookKind::detect("https://hooks.slack.com/services/T0/B0/x"),
+        WebhookKind::Slack
+    );
+    assert_eq!(
+        WebhookKind::detect("https://discord.com/api/webhooks/1/abc"),
+        WebhookKind::Discord
+    );
+    assert_eq!(
+        WebhookKind::detect("https://acme.webhook.office.com/webhookb2/x"),
+        WebhookKind::Teams
+    );
+    assert_eq!(
+        WebhookKind::detect("https://ci.example.com/hook"),
+        WebhookKind::Generic
+    );
+
+    let slack = webhook_payload(WebhookKind::Slack, "Title", "line");
+    assert_eq!(slack["text"], "*Title*\nline");
+    let long = "x".repeat(3000);
+    let discord = webhook_payload(WebhookKind::Discord, "Title", &long);
+    assert_eq!(discord["content"].as_str().unwrap().chars().count(), 2000);
+    let teams = webhook_payload(WebhookKind::Teams, "Title", "a\nb");
+    assert_eq!(teams["text"], "a\n\nb");
+
+    assert_eq!(
+        webhook_host("https://hooks.slack.com/services/T0/B0/secret"),
+        "hooks.slack.com"
+    );
+    assert_eq!(
+        webhook_urls(&[
+            " https://a.example/1 ".to_string(),
+            "https://a.example/1".to_string()
+        ]),
+        vec!["https://a.example/1".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_send_notification_reports_failures() {
+    let server = MockServer::start();
+    server.mock("POST", "/hook", 200, "ok");
+    let ok = format!("{}/hook", server.url());
+    let missing = format!("{}/gone", server.url());
+
+    send_notification(std::slice::from_ref(&ok), "Nightly", "all good")
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_str(&server.writes()[0].body).unwrap();
+    assert_eq!(body["title"], "Nightly");
+
+    let error = send_notification(&[ok, missing], "Nightly", "all good")
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("1 of 2 notifications failed"));
+    assert!(!error.contains("/gone"));
+}
@@ -0,0 +1,81 @@
+use crate::utils::process::{supervise_async, supervise_within, OutputMode};
+use std::process::Command;
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+#[test]
+#[cfg(unix)]
+fn test_supervise_captures_output() {
+    for mode in [OutputMode::Captured, OutputMode::Streamed] {
+        let output = supervise_within(
+            Command::new("sh").args(["-c", "echo out; echo err >&2; exit 3"]),
+            mode,
+            Some(Duration::from_secs(30)),
+        )
+        .unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "out\n");
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "err\n");
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_supervise_kills_the_process_group_on_timeout() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_file = temp_dir.path().join("pid");
+    let script = format!("sleep 30 & echo $! > {}; wait", pid_file.display());
+
+    let start = Instant::now();
+    let error = supervise_within(
+        Command::new("sh").args(["-c", &script]),
+        OutputMode::Captured,
+        Some(Duration::from_millis(300)),
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+    assert!(error.to_string().contains("sh timed out"));
+    assert!(start.elapsed() < Duration::from_secs(10));
+
+    // The background `sleep` went down with the shell that started it
+    let pid = std::fs::read_to_string(&pid_file).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while is_running(pid.trim()) && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(!is_running(pid.trim()));
+}
+
+/// Zombies count as gone, they only wait for init to reap them.
+#[cfg(unix)]
+fn is_running(pid: &str) -> bool {
+    match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+        Ok(stat) => !stat.contains(") Z "),
+        Err(_) if cfg!(target_os = "linux") => false,
+        Err(_) => Command::new("kill")
+            .args(["-0", pid])
+            .status()
+            .is_ok_and(|status| status.success()),
+    }
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_supervise_async_timeout() {
+    let error = supervise_async(
+        tokio::process::Command::new("sleep").arg("30"),
+        Duration::from_millis(200),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+
+    let output = supervise_async(
+        tokio::process::Command::new("echo").arg("done"),
+        Duration::from_secs(30),
+    )
+    .await
+    .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "done\n");
+}
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::progress::{is_interactive_output, render_progress, ProgressBar, Spinner};
+
+    #[test]
+    fn test_render_progress() {
+        assert_eq!(
+            render_progress("Checks", 0, 4, 8, ""),
+            "Checks [░░░░░░░░] 0/4"
+        );
+        assert_eq!(
+            render_progress("🌐 tr", 2, 4, 8, "batch 2/2"),
+            "🌐 tr [████░░░░] 2/4 batch 2/2"
+        );
+        // Overshooting and empty work both render a full bar
+        assert_eq!(render_progress("x", 9, 4, 4, ""), "x [████] 9/4");
+        assert_eq!(render_progress("x", 0, 0, 4, ""), "x [████] 0/0");
+    }
+
+    #[test]
+    fn test_spinner_and_bar_lifecycle() {
+        // Piped test runs take the plain-line path; a terminal gets the animation
+        let _ = is_interactive_output();
+
+        let spinner = Spinner::start("Scanning for outdated packages...");
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(spinner.finish().as_millis() >= 20);
+
+        let mut progress = ProgressBar::new("Checks", 2);
+        progress.tick("Running clippy...");
+        progress.inc(1);
+        progress.inc(5);
+        progress.finish();
+        Spinner::start("Running security audit...").fail();
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::prompt::{
+        ask, ask_required, checklist, confirm, is_non_interactive, render_checklist,
+        set_assume_yes, set_non_interactive, ChecklistItem, ChecklistKey, ChecklistState,
+    };
+
+    fn item(group: &str, label: &str, checked: bool) -> ChecklistItem {
+        ChecklistItem {
+            group: group.to_string(),
+            label: label.to_string(),
+            checked,
+        }
+    }
+
+    #[test]
+    fn test_non_interactive_prompts_never_read_stdin() {
+        set_non_interactive(true);
+        assert!(is_non_interactive());
+
+        // Only --yes confirms or takes preselected items
+        let items = vec![item("Patch", "serde", true), item("Major", "rand", false)];
+        assert!(!confirm("Delete 3 labels? (y/N): ").unwrap());
+        assert!(checklist("Branches:", &items).unwrap().is_empty());
+        set_assume_yes(true);
+
+        assert!(confirm("Proceed? (y/N): ").unwrap());
+        assert_eq!(ask("Model [current: flash]: ", "flash").unwrap(), "flash");
+
+        let err = ask_required("Gemini API Key: ", "set GEMINI_API_KEY").unwrap_err();
+        assert!(err.to_string().contains("non-interactive"));
+        assert!(err.to_string().contains("GEMINI_API_KEY"));
+
+        assert_eq!(checklist("Updates:", &items).unwrap(), vec![0]);
+
+        set_assume_yes(false);
+        set_non_interactive(false);
+    }
+
+    #[test]
+    fn test_checklist_keys() {
+        let items = vec![
+            item("Patch", "serde", true),
+            item("Patch", "tokio", false),
+            item("Major", "rand", false),
+        ];
+        let mut state = ChecklistState::new(&items);
+        assert_eq!(state.selected(), vec![0]);
+
+        // The cursor wraps around both ends
+        assert_eq!(state.handle(ChecklistKey::Up), None);
+        assert_eq!(state.cursor, 2);
+        state.handle(ChecklistKey::Toggle);
+        state.handle(ChecklistKey::Down);
+        assert_eq!(state.cursor, 0);
+        state.handle(ChecklistKey::Toggle);
+        assert_eq!(state.selected(), vec![2]);
+
+        state.handle(ChecklistKey::ToggleAll);
+        assert_eq!(state.selected(), vec![0, 1, 2]);
+        state.handle(ChecklistKey::ToggleAll);
+        assert!(state.selected().is_empty());
+        state.handle(ChecklistKey::Toggle);
+        assert_eq!(state.handle(ChecklistKey::Confirm), Some(vec![0]));
+        assert_eq!(state.handle(ChecklistKey::Cancel), Some(Vec::new()));
+
+        // One heading per group, the cursor row is marked
+        let lines = render_checklist(&items, &state);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].contains("Patch"));
+        assert!(lines[1].contains('❯') && lines[1].contains("serde"));
+        assert!(lines[3].contains("Major"));
+    }
+}
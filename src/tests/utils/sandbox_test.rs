@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::sandbox::{
+        check_command, check_network, check_sandbox, ensure_not_sandboxed, is_sandboxed,
+    };
+    use std::path::Path;
+
+    #[test]
+    fn test_sandbox_disabled_by_default() {
+        assert!(!is_sandboxed());
+        assert!(ensure_not_sandboxed("release-notes").is_ok());
+    }
+
+    #[test]
+    fn test_check_sandbox_blocks_actions() {
+        assert!(check_sandbox(false, "code-quality").is_ok());
+
+        let err = check_sandbox(true, "code-quality").unwrap_err();
+        assert!(err.to_string().contains("code-quality"));
+        assert!(err.to_string().contains("--sandbox"));
+    }
+
+    #[test]
+    fn test_sandbox_denies_every_command() {
+        assert!(check_command(false, "npm", &["install"]).is_ok());
+        assert!(check_command(false, "git", &["log", "--oneline"]).is_ok());
+
+        for (program, args) in [
+            ("npm", &["install"][..]),
+            ("git", &["log", "--oneline"][..]),
+            ("git", &["--version"][..]),
+        ] {
+            let err = check_command(true, program, args).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+            assert!(err.to_string().contains(program));
+        }
+    }
+
+    #[test]
+    fn test_check_network_blocks_requests() {
+        assert!(check_network(false).is_ok());
+
+        let err = check_network(true).unwrap_err();
+        assert!(err.to_string().contains("--sandbox"));
+    }
+
+    #[test]
+    fn test_http_clients_check_the_sandbox() {
+        let mut unguarded = Vec::new();
+        let mut dirs = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(&dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    if !path.ends_with("tests") {
+                        dirs.push(path);
+                    }
+                    continue;
+                }
+                if path.extension().is_none_or(|ext| ext != "rs") {
+                    continue;
+                }
+                let source = std::fs::read_to_string(&path).unwrap();
+                // Everything from the enclosing `fn` up to the client
+                for (index, _) in source.match_indices("reqwest::Client::builder()") {
+                    let function = source[..index].rfind("fn ").unwrap_or(0);
+                    if !source[function..index].contains("ensure_network_allowed()") {
+                        unguarded.push(path.display().to_string());
+                    }
+                }
+            }
+        }
+        assert!(
+            unguarded.is_empty(),
+            "HTTP clients built without ensure_network_allowed: {:?}",
+            unguarded
+        );
+    }
+}
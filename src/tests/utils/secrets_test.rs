@@ -0,0 +1,81 @@
+use crate::commands::config::AppConfig;
+use crate::commands::translation_sync::{settings_for, ProviderKind};
+use crate::utils::secrets::{
+    decrypt, encrypt, keychain_entry, Keychain, SecretStorage, KEYCHAIN_MARKER, PASSPHRASE_ENV,
+};
+
+#[test]
+fn test_encrypt_roundtrip() {
+    let stored = encrypt("AIzaSy-secret", "correct horse").unwrap();
+    assert!(stored.starts_with("enc:v1:"));
+    assert!(!stored.contains("AIzaSy"));
+    assert_eq!(SecretStorage::of(&stored), SecretStorage::Encrypted);
+    assert_eq!(decrypt(&stored, "correct horse").unwrap(), "AIzaSy-secret");
+
+    // Fresh salt and nonce every time
+    assert_ne!(stored, encrypt("AIzaSy-secret", "correct horse").unwrap());
+}
+
+#[test]
+fn test_decrypt_rejects_wrong_passphrase_and_damage() {
+    let stored = encrypt("AIzaSy-secret", "correct horse").unwrap();
+    assert!(decrypt(&stored, "battery staple")
+        .unwrap_err()
+        .to_string()
+        .contains("Wrong passphrase"));
+
+    let mut damaged = stored.clone();
+    damaged.truncate(stored.len() - 4);
+    damaged.push_str("AAAA");
+    assert!(decrypt(&damaged, "correct horse").is_err());
+    assert!(decrypt("enc:v1:c2hvcnQ=", "correct horse").is_err());
+    assert!(decrypt("AIzaSy-plain", "correct horse").is_err());
+}
+
+#[test]
+fn test_secret_storage_parsing() {
+    assert_eq!(SecretStorage::of("AIzaSy-plain"), SecretStorage::Plain);
+    assert_eq!(SecretStorage::of(KEYCHAIN_MARKER), SecretStorage::Keychain);
+    // Saved under a profile, the value names the profile's own entry
+    let stored = format!("{}work/gemini_api_key", KEYCHAIN_MARKER);
+    assert_eq!(SecretStorage::of(&stored), SecretStorage::Keychain);
+    assert_eq!(
+        keychain_entry("gemini_api_key", &stored),
+        "work/gemini_api_key"
+    );
+    assert_eq!(
+        keychain_entry("gemini_api_key", KEYCHAIN_MARKER),
+        "gemini_api_key"
+    );
+    assert_eq!(
+        "Keychain".parse::<SecretStorage>().unwrap(),
+        SecretStorage::Keychain
+    );
+    assert_eq!(SecretStorage::Encrypted.name(), "encrypted");
+    assert!("vault".parse::<SecretStorage>().is_err());
+}
+
+#[test]
+fn test_keychain_goes_through_the_os_store() {
+    keyring::set_default_credential_builder(keyring::mock::default_credential_builder());
+    Keychain.store("gemini_api_key", "AIzaSy-secret").unwrap();
+    // Mock entries don't share state, so a fresh lookup finds nothing
+    assert!(Keychain
+        .load("gemini_api_key")
+        .unwrap_err()
+        .to_string()
+        .contains("not found"));
+    assert!(Keychain.delete("gemini_api_key").is_ok());
+}
+
+#[test]
+fn test_settings_for_decrypts_stored_key() {
+    std::env::set_var(PASSPHRASE_ENV, "correct horse");
+    let config = AppConfig {
+        gemini_api_key: Some(encrypt("AIzaSy-secret", "correct horse").unwrap()),
+        ..AppConfig::default()
+    };
+    let (api_key, _) = settings_for(&config, ProviderKind::Gemini);
+    std::env::remove_var(PASSPHRASE_ENV);
+    assert_eq!(api_key.as_deref(), Some("AIzaSy-secret"));
+}
@@ -0,0 +1,56 @@
+use crate::utils::terminal::{detect_color_support, fit_color, ColorSupport};
+use colored::Color;
+use std::collections::HashMap;
+
+fn detect(vars: &[(&str, &str)], is_terminal: bool, ansi_enabled: bool) -> ColorSupport {
+    let env: HashMap<String, String> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    detect_color_support(|name| env.get(name).cloned(), is_terminal, ansi_enabled)
+}
+
+#[test]
+fn test_detect_color_support() {
+    assert_eq!(
+        detect(&[("COLORTERM", "truecolor")], true, true),
+        ColorSupport::TrueColor
+    );
+    assert_eq!(
+        detect(&[("WT_SESSION", "1")], true, true),
+        ColorSupport::TrueColor
+    );
+    assert_eq!(
+        detect(&[("TERM", "xterm-256color")], true, true),
+        ColorSupport::Ansi16
+    );
+
+    // Legacy Windows console without Virtual Terminal Processing
+    assert_eq!(detect(&[], true, false), ColorSupport::None);
+    assert_eq!(
+        detect(&[("NO_COLOR", "1"), ("COLORTERM", "24bit")], true, true),
+        ColorSupport::None
+    );
+    assert_eq!(detect(&[("TERM", "dumb")], true, true), ColorSupport::None);
+    assert_eq!(detect(&[], false, true), ColorSupport::None);
+    assert_eq!(
+        detect(&[("CLICOLOR_FORCE", "1")], false, true),
+        ColorSupport::Ansi16
+    );
+}
+
+#[test]
+fn test_fit_color_falls_back_to_basic_colors() {
+    assert_eq!(
+        fit_color((230, 159, 0), Color::Yellow, ColorSupport::TrueColor),
+        Color::TrueColor {
+            r: 230,
+            g: 159,
+            b: 0
+        }
+    );
+    assert_eq!(
+        fit_color((230, 159, 0), Color::Yellow, ColorSupport::Ansi16),
+        Color::Yellow
+    );
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::theme::{Role, Theme};
+    use colored::Color;
+
+    #[test]
+    fn test_theme_parsing() {
+        assert_eq!("default".parse::<Theme>().unwrap(), Theme::Default);
+        assert_eq!("colorblind".parse::<Theme>().unwrap(), Theme::Colorblind);
+        assert_eq!(
+            "colorblind-safe".parse::<Theme>().unwrap(),
+            Theme::Colorblind
+        );
+        assert_eq!("MONOCHROME".parse::<Theme>().unwrap(), Theme::Monochrome);
+        assert!("neon".parse::<Theme>().is_err());
+    }
+
+    #[test]
+    fn test_theme_names_round_trip() {
+        for theme in Theme::all() {
+            assert_eq!(theme.name().parse::<Theme>().unwrap(), theme);
+        }
+    }
+
+    #[test]
+    fn test_default_theme_colors() {
+        let theme = Theme::Default;
+
+        assert_eq!(theme.paint("ok", Role::Success).fgcolor, Some(Color::Green));
+        assert_eq!(theme.paint("bad", Role::Error).fgcolor, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_colorblind_theme_avoids_red_green() {
+        let theme = Theme::Colorblind;
+
+        for role in [Role::Success, Role::Error] {
+            let color = theme.paint("x", role).fgcolor;
+            assert!(matches!(color, Some(Color::TrueColor { .. })));
+        }
+        assert_ne!(
+            theme.paint("x", Role::Success).fgcolor,
+            theme.paint("x", Role::Error).fgcolor
+        );
+    }
+
+    #[test]
+    fn test_monochrome_theme_has_no_colors() {
+        let theme = Theme::Monochrome;
+
+        for role in [
+            Role::Success,
+            Role::Warning,
+            Role::Error,
+            Role::Info,
+            Role::Accent,
+        ] {
+            assert_eq!(theme.paint("x", role).fgcolor, None);
+        }
+        assert_eq!(theme.gradient("x", 0.5).fgcolor, None);
+    }
+
+    #[test]
+    fn test_role_symbols_are_distinct() {
+        let symbols = [
+            Role::Success.symbol(),
+            Role::Warning.symbol(),
+            Role::Error.symbol(),
+            Role::Info.symbol(),
+        ];
+
+        for (i, a) in symbols.iter().enumerate() {
+            for b in &symbols[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+}
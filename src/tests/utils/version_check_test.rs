@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::utils::version_check::{
-        GitHubRelease, VersionCache, CACHE_FILE, CHECK_INTERVAL_HOURS, GITHUB_API_URL,
+        cache_expired, latest_for_channel, GitHubRelease, UpdateChannel, UpdateCheckSettings,
+        VersionCache, CACHE_FILE, CHECK_INTERVAL_HOURS, GITHUB_API_URL,
     };
     use std::fs;
     use std::path::PathBuf;
@@ -10,11 +11,7 @@ mod tests {
 
     // Helper function to clean version string
     fn clean_version_string(version: &str) -> String {
-        if version.starts_with('v') {
-            version[1..].to_string()
-        } else {
-            version.to_string()
-        }
+        version.strip_prefix('v').unwrap_or(version).to_string()
     }
 
     // Helper function to compare versions
@@ -55,6 +52,7 @@ mod tests {
                 .as_secs(),
             latest_version: version.to_string(),
             check_interval_hours: CHECK_INTERVAL_HOURS,
+            channel: UpdateChannel::Stable,
         };
 
         if let Ok(json) = serde_json::to_string(&cache) {
@@ -104,6 +102,7 @@ mod tests {
             last_check: 1640995200, // 2022-01-01 00:00:00 UTC
             latest_version: "v1.0.0".to_string(),
             check_interval_hours: 24,
+            channel: UpdateChannel::Stable,
         };
 
         assert_eq!(cache.last_check, 1640995200);
@@ -183,6 +182,7 @@ mod tests {
             last_check: 1640995200,
             latest_version: "v1.0.0".to_string(),
             check_interval_hours: 24,
+            channel: UpdateChannel::Stable,
         };
 
         let json = serde_json::to_string(&cache);
@@ -272,6 +272,7 @@ mod tests {
                 .as_secs(),
             latest_version: "v1.0.0".to_string(),
             check_interval_hours: 24,
+            channel: UpdateChannel::Stable,
         };
 
         let json = serde_json::to_string(&recent_cache).unwrap();
@@ -296,6 +297,7 @@ mod tests {
             last_check: now - (25 * 3600), // 25 hours ago
             latest_version: "v1.0.0".to_string(),
             check_interval_hours: 24,
+            channel: UpdateChannel::Stable,
         };
 
         let json = serde_json::to_string(&old_cache).unwrap();
@@ -314,7 +316,6 @@ mod tests {
         assert!(!CACHE_FILE.is_empty());
         assert!(CACHE_FILE.contains("nitroterm"));
 
-        assert!(CHECK_INTERVAL_HOURS > 0);
         assert_eq!(CHECK_INTERVAL_HOURS, 24);
     }
 
@@ -445,6 +446,7 @@ mod tests {
             last_check: now - (12 * 3600), // 12 hours ago
             latest_version: "v1.0.0".to_string(),
             check_interval_hours: 6, // 6 hour interval
+            channel: UpdateChannel::Stable,
         };
 
         let json = serde_json::to_string(&custom_cache).unwrap();
@@ -453,4 +455,69 @@ mod tests {
         // Should check because 12 hours > 6 hour interval
         assert!(should_check_for_updates_with_path(&cache_path));
     }
+
+    #[test]
+    fn test_cache_expires_on_interval_or_channel_switch() {
+        let now = 1_700_000_000;
+        let cache = VersionCache {
+            last_check: now - 3 * 3600,
+            latest_version: "v1.2.0".to_string(),
+            check_interval_hours: 24,
+            channel: UpdateChannel::Stable,
+        };
+        let daily = UpdateCheckSettings::default();
+        assert!(!cache_expired(&cache, now, &daily));
+
+        let hourly = UpdateCheckSettings {
+            interval_hours: 2,
+            ..daily
+        };
+        assert!(cache_expired(&cache, now, &hourly));
+
+        let prerelease = UpdateCheckSettings {
+            channel: UpdateChannel::Prerelease,
+            ..daily
+        };
+        assert!(cache_expired(&cache, now, &prerelease));
+
+        // A clock set back doesn't underflow
+        assert!(!cache_expired(&cache, now - 10 * 3600, &daily));
+    }
+
+    #[test]
+    fn test_latest_release_per_channel() {
+        let release = |tag: &str, prerelease: bool| GitHubRelease {
+            tag_name: tag.to_string(),
+            name: tag.to_string(),
+            published_at: "2024-01-01T00:00:00Z".to_string(),
+            html_url: String::new(),
+            prerelease,
+        };
+        let releases = || {
+            vec![
+                release("v1.9.0", false),
+                release("v2.0.0-beta.2", true),
+                release("nightly", true),
+                release("v1.10.0", false),
+            ]
+        };
+
+        let stable = latest_for_channel(releases(), UpdateChannel::Stable).unwrap();
+        assert_eq!(stable.tag_name, "v1.10.0");
+        let prerelease = latest_for_channel(releases(), UpdateChannel::Prerelease).unwrap();
+        assert_eq!(prerelease.tag_name, "v2.0.0-beta.2");
+
+        assert_eq!(
+            "beta".parse::<UpdateChannel>().unwrap(),
+            UpdateChannel::Prerelease
+        );
+        assert!("nightly".parse::<UpdateChannel>().is_err());
+
+        // Caches written before channels existed are read as stable
+        let old: VersionCache = serde_json::from_str(
+            r#"{"last_check": 1, "latest_version": "v1.0.0", "check_interval_hours": 24}"#,
+        )
+        .unwrap();
+        assert_eq!(old.channel, UpdateChannel::Stable);
+    }
 }
@@ -0,0 +1,248 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, Utc};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Language of generated documents (release notes, reports). Independent of
+/// the UI locale, so a Turkish-speaking maintainer can still publish English notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentLocale {
+    #[default]
+    En,
+    Tr,
+    De,
+}
+
+static TEMPLATES: OnceLock<[Value; 3]> = OnceLock::new();
+
+fn templates() -> &'static [Value; 3] {
+    TEMPLATES.get_or_init(|| {
+        let parse = |content: &str| serde_json::from_str(content).unwrap_or(Value::Null);
+        [
+            parse(include_str!("../../locales/documents/en.json")),
+            parse(include_str!("../../locales/documents/tr.json")),
+            parse(include_str!("../../locales/documents/de.json")),
+        ]
+    })
+}
+
+impl std::str::FromStr for DocumentLocale {
+    type Err = anyhow::Error;
+
+    /// Accepts `tr`, `tr-TR`, `de_DE.UTF-8` and the like.
+    fn from_str(value: &str) -> Result<Self> {
+        let language = value
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        DocumentLocale::all()
+            .into_iter()
+            .find(|locale| locale.code() == language)
+            .ok_or_else(|| anyhow!("Unsupported document locale '{}', use en, tr or de", value))
+    }
+}
+
+impl DocumentLocale {
+    pub fn code(&self) -> &'static str {
+        match self {
+            DocumentLocale::En => "en",
+            DocumentLocale::Tr => "tr",
+            DocumentLocale::De => "de",
+        }
+    }
+
+    pub fn all() -> [DocumentLocale; 3] {
+        [DocumentLocale::En, DocumentLocale::Tr, DocumentLocale::De]
+    }
+
+    fn value(&self, key: &str) -> &'static Value {
+        let templates = templates();
+        let localized = &templates[*self as usize][key];
+        if localized.is_null() {
+            &templates[0][key]
+        } else {
+            localized
+        }
+    }
+
+    /// Template text, falling back to English and then to the key itself.
+    pub fn t(&self, key: &str) -> String {
+        self.value(key).as_str().unwrap_or(key).to_string()
+    }
+
+    /// Every text of the locale, English where it has no translation.
+    pub fn labels(&self) -> serde_json::Map<String, Value> {
+        let templates = templates();
+        let mut labels = templates[0].as_object().cloned().unwrap_or_default();
+        if let Some(localized) = templates[*self as usize].as_object() {
+            labels.extend(localized.clone());
+        }
+        labels
+    }
+
+    /// Fills `{name}` placeholders in a template.
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.t(key), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+
+    /// `March 5, 2025`, `5 Mart 2025`, `5. März 2025`
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        let month = self.value("months")[date.month0() as usize]
+            .as_str()
+            .unwrap_or_default();
+        self.format(
+            "date_format",
+            &[
+                ("day", &date.day().to_string()),
+                ("month", month),
+                ("year", &date.year().to_string()),
+            ],
+        )
+    }
+
+    /// Numeric dates for tables: `2025-03-05`, `05.03.2025`
+    pub fn format_short_date(&self, date: NaiveDate) -> String {
+        date.format(&self.t("short_date_format")).to_string()
+    }
+
+    pub fn format_time(&self, time: NaiveDateTime) -> String {
+        time.format(&self.t("time_format")).to_string()
+    }
+
+    /// `1,234,567` in English, `1.234.567` in Turkish and German.
+    pub fn format_number(&self, number: usize) -> String {
+        let digits = number.to_string();
+        let separator = self.t("thousands_separator");
+        let mut output = String::new();
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                output.push_str(&separator);
+            }
+            output.push(digit);
+        }
+        output
+    }
+
+    pub fn commits(&self, count: usize) -> String {
+        if count == 1 {
+            self.t("commit_one")
+        } else {
+            self.format("commit_other", &[("count", &self.format_number(count))])
+        }
+    }
+}
+
+/// Timezone of the dates and times in generated documents and logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentTimezone {
+    #[default]
+    Utc,
+    /// The system timezone, daylight saving time included
+    Local,
+    Fixed(FixedOffset),
+}
+
+static TIMEZONE: OnceLock<DocumentTimezone> = OnceLock::new();
+
+/// Sets the timezone for the rest of the run, see [`timezone`].
+pub fn set_timezone(timezone: DocumentTimezone) {
+    let _ = TIMEZONE.set(timezone);
+}
+
+/// The `timezone` config key, or UTC with `--utc`.
+pub fn timezone() -> DocumentTimezone {
+    TIMEZONE.get().copied().unwrap_or_default()
+}
+
+/// `+03:00`, `+0300`, `+3` or `-05:30`.
+fn parse_offset(value: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match value.chars().next()? {
+        '+' => (1, &value[1..]),
+        '-' => (-1, &value[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some(parts) => parts,
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+impl std::str::FromStr for DocumentTimezone {
+    type Err = anyhow::Error;
+
+    /// `utc`, `local`, or an offset like `+03:00` or `UTC-5`.
+    fn from_str(value: &str) -> Result<Self> {
+        let value = value.trim();
+        match value.to_lowercase().as_str() {
+            "utc" | "gmt" | "z" => return Ok(DocumentTimezone::Utc),
+            "local" => return Ok(DocumentTimezone::Local),
+            _ => {}
+        }
+        let offset = value
+            .strip_prefix("UTC")
+            .or_else(|| value.strip_prefix("utc"))
+            .unwrap_or(value);
+        parse_offset(offset)
+            .map(DocumentTimezone::Fixed)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Unsupported timezone '{}', use utc, local or an offset like +03:00",
+                    value
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for DocumentTimezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentTimezone::Utc => write!(f, "utc"),
+            DocumentTimezone::Local => write!(f, "local"),
+            DocumentTimezone::Fixed(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl DocumentTimezone {
+    /// The offset from UTC at `time`, which for `Local` depends on the date.
+    pub fn offset_at(&self, time: DateTime<Utc>) -> FixedOffset {
+        match self {
+            DocumentTimezone::Utc => Utc.fix(),
+            DocumentTimezone::Local => time.with_timezone(&Local).offset().fix(),
+            DocumentTimezone::Fixed(offset) => *offset,
+        }
+    }
+
+    pub fn convert(&self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        time.with_timezone(&self.offset_at(time))
+    }
+
+    /// A Unix timestamp in this timezone, None when it's out of range.
+    pub fn at(&self, timestamp: i64) -> Option<DateTime<FixedOffset>> {
+        DateTime::from_timestamp(timestamp, 0).map(|time| self.convert(time))
+    }
+
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.convert(Utc::now())
+    }
+
+    /// `14:05` in UTC, the default; other timezones show their offset,
+    /// `17:05 +03:00`.
+    pub fn format_time(&self, locale: DocumentLocale, time: &DateTime<FixedOffset>) -> String {
+        let formatted = locale.format_time(time.naive_local());
+        match self {
+            DocumentTimezone::Utc => formatted,
+            _ => format!("{} {}", formatted, time.format("%:z")),
+        }
+    }
+}
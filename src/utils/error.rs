@@ -0,0 +1,58 @@
+use crate::commands::config::ConfigError;
+use crate::commands::release_notes::ReleaseNotesError;
+use crate::utils::process::ProcessError;
+
+/// How `nitroterm` exits when a command fails. The codes are part of the
+/// CLI, scripts and CI jobs rely on them (see the README).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    /// Anything not covered below
+    Failure,
+    /// Invalid arguments, settings, project files or missing credentials
+    Config,
+    /// git, gh, a package manager or another required tool isn't installed
+    MissingTool,
+    /// The command ran, but its checks found problems
+    ChecksFailed,
+    /// Ctrl+C
+    Interrupted,
+}
+
+impl ExitCode {
+    pub fn code(self) -> i32 {
+        match self {
+            ExitCode::Failure => 1,
+            ExitCode::Config => 2,
+            ExitCode::MissingTool => 3,
+            ExitCode::ChecksFailed => 4,
+            ExitCode::Interrupted => 130,
+        }
+    }
+}
+
+/// A check command (`version check`, `code-quality`, `audit`, `policy
+/// check`, ...) found problems. The message says which.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct ChecksFailed(pub String);
+
+/// The exit code for `error`, from the first typed error in its chain.
+/// Errors that only carry a message are a plain failure.
+pub fn exit_code(error: &anyhow::Error) -> ExitCode {
+    error
+        .chain()
+        .find_map(|cause| {
+            if let Some(error) = cause.downcast_ref::<ProcessError>() {
+                Some(error.exit_code())
+            } else if let Some(error) = cause.downcast_ref::<ReleaseNotesError>() {
+                Some(error.exit_code())
+            } else if cause.is::<ConfigError>() {
+                Some(ExitCode::Config)
+            } else if cause.is::<ChecksFailed>() {
+                Some(ExitCode::ChecksFailed)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(ExitCode::Failure)
+}
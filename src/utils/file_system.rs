@@ -1,15 +1,250 @@
+use crate::utils::sandbox::ensure_write_allowed;
+use regex::Regex;
 use std::fs;
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-pub fn file_exists(path: &str) -> bool {
-    Path::new(path).exists()
+/// Build output, dependencies and virtualenvs, never scanned for projects.
+pub const SKIPPED_DIRS: [&str; 7] = [
+    "node_modules",
+    "target",
+    "vendor",
+    "dist",
+    "build",
+    "venv",
+    "__pycache__",
+];
+/// How deep recursive scans descend unless told otherwise.
+pub const DEFAULT_MAX_DEPTH: usize = 6;
+
+pub fn file_exists(path: impl AsRef<Path>) -> bool {
+    path.as_ref().exists()
 }
 
-pub fn read_file_to_string(path: &str) -> Result<String, io::Error> {
+pub fn read_file_to_string(path: impl AsRef<Path>) -> Result<String, io::Error> {
     fs::read_to_string(path)
 }
 
-pub fn write_string_to_file(path: &str, content: &str) -> Result<(), io::Error> {
+pub fn write_string_to_file(path: impl AsRef<Path>, content: &str) -> Result<(), io::Error> {
+    ensure_write_allowed(path.as_ref())?;
     fs::write(path, content)
 }
+
+/// `relative` below `root`, with `..` and symlinks resolved. Fails when it
+/// doesn't exist or ends up outside `root`, e.g. an absolute path. For
+/// paths that come from a request rather than the user.
+pub fn path_within(root: &Path, relative: &str) -> Result<PathBuf, io::Error> {
+    let root = root.canonicalize()?;
+    let path = root
+        .join(relative)
+        .canonicalize()
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", relative, e)))?;
+    if path.starts_with(&root) {
+        Ok(path)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is outside {}", relative, root.display()),
+        ))
+    }
+}
+
+/// Which directories below a root a scan looks at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanOptions {
+    /// Directory levels below the root to descend, 0 for the root only
+    pub max_depth: usize,
+    /// Globs of paths to keep, everything when empty
+    pub include: Vec<String>,
+    /// Globs of paths to leave out, a directory with everything below it
+    pub exclude: Vec<String>,
+    /// Skip what `.gitignore` ignores
+    pub respect_gitignore: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+        }
+    }
+}
+
+impl ScanOptions {
+    /// Only the root itself, how the commands behave without `--recursive`.
+    pub fn top_level() -> Self {
+        Self {
+            max_depth: 0,
+            ..Self::default()
+        }
+    }
+}
+
+/// A glob where `*` and `?` stay within one path component and `**` spans
+/// any number of them. Matches the way `.gitignore` lines do: without a `/`
+/// it matches any file or directory name, with one the path or one of its
+/// parent directories, so `packages/*` covers `packages/web/package.json`.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    regex: Regex,
+    anchored: bool,
+}
+
+impl Glob {
+    pub fn new(pattern: &str) -> Self {
+        let pattern = pattern
+            .trim()
+            .trim_start_matches("./")
+            .trim_end_matches('/');
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        regex.push_str("(?:.*/)?");
+                    } else {
+                        regex.push_str(".*");
+                    }
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        regex.push('$');
+        Self {
+            regex: Regex::new(&regex).expect("escaped glob is a valid regex"),
+            anchored: pattern.contains('/'),
+        }
+    }
+
+    pub fn matches(&self, relative: &Path) -> bool {
+        let components: Vec<String> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().to_string())
+            .collect();
+        if !self.anchored {
+            return components.iter().any(|name| self.regex.is_match(name));
+        }
+        (1..=components.len()).any(|end| self.regex.is_match(&components[..end].join("/")))
+    }
+}
+
+/// The `include`/`exclude` globs of a scan.
+#[derive(Debug, Clone, Default)]
+pub struct PathFilter {
+    include: Vec<Glob>,
+    exclude: Vec<Glob>,
+}
+
+impl PathFilter {
+    pub fn new(options: &ScanOptions) -> Self {
+        let globs = |patterns: &[String]| -> Vec<Glob> {
+            patterns
+                .iter()
+                .filter(|pattern| !pattern.trim().is_empty())
+                .map(|pattern| Glob::new(pattern))
+                .collect()
+        };
+        Self {
+            include: globs(&options.include),
+            exclude: globs(&options.exclude),
+        }
+    }
+
+    pub fn excluded(&self, relative: &Path) -> bool {
+        self.exclude.iter().any(|glob| glob.matches(relative))
+    }
+
+    /// Not excluded, and matching an include glob when there are any.
+    pub fn included(&self, relative: &Path) -> bool {
+        !self.excluded(relative)
+            && (self.include.is_empty() || self.include.iter().any(|glob| glob.matches(relative)))
+    }
+}
+
+/// Answers whether git ignores a path, for paths inside a repository.
+pub struct GitIgnore {
+    repo: Option<(git2::Repository, PathBuf)>,
+}
+
+impl GitIgnore {
+    pub fn new(root: &Path) -> Self {
+        let repo = git2::Repository::discover(root).ok().and_then(|repo| {
+            let workdir = repo.workdir()?;
+            let workdir = fs::canonicalize(workdir).unwrap_or_else(|_| workdir.to_path_buf());
+            Some((repo, workdir))
+        });
+        Self { repo }
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Some((repo, workdir)) = &self.repo else {
+            return false;
+        };
+        let absolute = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        match absolute.strip_prefix(workdir) {
+            Ok(relative) if !relative.as_os_str().is_empty() => {
+                repo.is_path_ignored(relative).unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// `root` and the directories below it, as paths relative to `root` (the
+/// empty path for `root` itself) with parents before their children.
+/// Hidden, build output and dependency directories are skipped, and so are
+/// ignored and excluded ones.
+pub fn walk_dirs(root: &Path, options: &ScanOptions) -> Vec<PathBuf> {
+    let filter = PathFilter::new(options);
+    let gitignore = options.respect_gitignore.then(|| GitIgnore::new(root));
+
+    let mut dirs = Vec::new();
+    let mut pending = vec![(PathBuf::new(), 0)];
+    while let Some((relative, depth)) = pending.pop() {
+        dirs.push(relative.clone());
+        if depth >= options.max_depth {
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(root.join(&relative)) else {
+            continue;
+        };
+        let mut children: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                !name.starts_with('.') && !SKIPPED_DIRS.contains(&name.as_str())
+            })
+            .map(|entry| relative.join(entry.file_name()))
+            .filter(|child| !filter.excluded(child))
+            .filter(|child| {
+                gitignore
+                    .as_ref()
+                    .is_none_or(|gitignore| !gitignore.is_ignored(&root.join(child)))
+            })
+            .collect();
+        // Reversed so the stack hands them out in order
+        children.sort_by(|a, b| b.cmp(a));
+        pending.extend(children.into_iter().map(|child| (child, depth + 1)));
+    }
+    dirs
+}
+
+/// Files named one of `names` at or below `root`, relative to it. Within a
+/// directory they come in the order of `names`.
+pub fn find_project_files(root: &Path, names: &[&str], options: &ScanOptions) -> Vec<PathBuf> {
+    let filter = PathFilter::new(options);
+    walk_dirs(root, options)
+        .into_iter()
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .filter(|file| root.join(file).is_file() && filter.included(file))
+        .collect()
+}
@@ -1,5 +1,180 @@
-use git2::Repository;
+use git2::{BranchType, FileMode, Repository, Tree};
+use serde::Serialize;
 
+/// Opens the repository containing `path`, searching parent directories like
+/// git does. Works from subdirectories and linked worktrees.
 pub fn get_repository(path: &str) -> Result<Repository, git2::Error> {
-    Repository::open(path)
+    // An empty path would silently mean the current directory
+    if path.is_empty() {
+        return Err(git2::Error::from_str("No repository path given"));
+    }
+    Repository::discover(path)
+}
+
+/// A submodule registered in `.gitmodules`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmoduleInfo {
+    pub name: String,
+    pub path: String,
+    pub url: Option<String>,
+}
+
+pub fn list_submodules(repo: &Repository) -> Result<Vec<SubmoduleInfo>, git2::Error> {
+    Ok(repo
+        .submodules()?
+        .iter()
+        .map(|submodule| SubmoduleInfo {
+            name: submodule.name().unwrap_or_default().to_string(),
+            path: submodule.path().to_string_lossy().replace('\\', "/"),
+            url: submodule.url().map(|url| url.to_string()),
+        })
+        .collect())
+}
+
+/// A submodule whose recorded commit differs between two trees. `old` is
+/// None for an added submodule, `new` for a removed one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubmoduleChange {
+    pub path: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    /// From `.gitmodules` of the working tree, when still registered
+    pub url: Option<String>,
+}
+
+/// Submodule pointer changes from `old_tree` (nothing when None) to `new_tree`.
+pub fn submodule_changes(
+    repo: &Repository,
+    old_tree: Option<&Tree>,
+    new_tree: &Tree,
+) -> Result<Vec<SubmoduleChange>, git2::Error> {
+    let registered = list_submodules(repo).unwrap_or_default();
+    let diff = repo.diff_tree_to_tree(old_tree, Some(new_tree), None)?;
+
+    let mut changes = Vec::new();
+    for delta in diff.deltas() {
+        let commit_id = |file: git2::DiffFile| {
+            (file.mode() == FileMode::Commit && !file.id().is_zero()).then(|| file.id().to_string())
+        };
+        let old = commit_id(delta.old_file());
+        let new = commit_id(delta.new_file());
+        if old.is_none() && new.is_none() {
+            continue;
+        }
+        let path = delta
+            .new_file()
+            .path()
+            .or_else(|| delta.old_file().path())
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        let url = registered
+            .iter()
+            .find(|submodule| submodule.path == path)
+            .and_then(|submodule| submodule.url.clone());
+        changes.push(SubmoduleChange {
+            path,
+            old,
+            new,
+            url,
+        });
+    }
+    Ok(changes)
+}
+
+/// The branch `origin/HEAD` points at, else `main` or `master` if one exists.
+pub fn default_branch(repo: &Repository) -> Option<String> {
+    if let Ok(reference) = repo.find_reference("refs/remotes/origin/HEAD") {
+        if let Some(name) = reference
+            .symbolic_target()
+            .and_then(|target| target.strip_prefix("refs/remotes/origin/"))
+        {
+            return Some(name.to_string());
+        }
+    }
+    ["main", "master"]
+        .into_iter()
+        .find(|name| {
+            repo.find_branch(name, BranchType::Local).is_ok()
+                || repo
+                    .find_branch(&format!("origin/{}", name), BranchType::Remote)
+                    .is_ok()
+        })
+        .map(|name| name.to_string())
+}
+
+/// Name of the checked-out branch, `None` on a detached or unborn HEAD.
+pub fn current_branch(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    head.shorthand().map(|name| name.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchInfo {
+    /// `feature` for local branches, `origin/feature` for remote ones
+    pub name: String,
+    pub remote: bool,
+    /// Commit time of the branch tip
+    pub last_commit: i64,
+    /// Fully contained in the default branch
+    pub merged: bool,
+}
+
+impl BranchInfo {
+    pub fn age_days(&self, now: i64) -> i64 {
+        (now - self.last_commit) / 86_400
+    }
+
+    pub fn is_stale(&self, now: i64, stale_days: i64) -> bool {
+        self.merged || self.age_days(now) >= stale_days
+    }
+
+    /// The name without the remote, `feature` for `origin/feature`.
+    pub fn short_name(&self) -> &str {
+        if self.remote {
+            self.name
+                .split_once('/')
+                .map_or(self.name.as_str(), |(_, rest)| rest)
+        } else {
+            &self.name
+        }
+    }
+}
+
+/// Local and remote branches except `default` itself and `origin/HEAD`.
+pub fn list_branches(repo: &Repository, default: &str) -> Result<Vec<BranchInfo>, git2::Error> {
+    let default_tip = repo
+        .find_branch(default, BranchType::Local)
+        .or_else(|_| repo.find_branch(&format!("origin/{}", default), BranchType::Remote))?
+        .get()
+        .peel_to_commit()?
+        .id();
+
+    let mut branches = Vec::new();
+    for branch in repo.branches(None)? {
+        let (branch, kind) = branch?;
+        let Some(name) = branch.name()?.map(|name| name.to_string()) else {
+            continue;
+        };
+        let short_name = name.split_once('/').map_or(name.as_str(), |(_, rest)| rest);
+        let remote = kind == BranchType::Remote;
+        if (!remote && name == default)
+            || (remote && (short_name == default || short_name == "HEAD"))
+        {
+            continue;
+        }
+
+        let tip = branch.get().peel_to_commit()?;
+        let merged = tip.id() == default_tip || repo.graph_descendant_of(default_tip, tip.id())?;
+        branches.push(BranchInfo {
+            name,
+            remote,
+            last_commit: tip.time().seconds(),
+            merged,
+        });
+    }
+    branches.sort_by_key(|branch| branch.last_commit);
+    Ok(branches)
 }
@@ -1,4 +1,5 @@
-use chrono::Utc;
+use crate::utils::document_locale::timezone;
+use crate::utils::theme::{label, Role};
 use colored::*;
 
 #[derive(Debug)]
@@ -10,14 +11,26 @@ pub enum LogLevel {
 }
 
 pub fn log(level: LogLevel, message: &str) {
-    let timestamp = Utc::now().format("%H:%M:%S");
+    let timestamp = timezone().now().format("%H:%M:%S");
+
+    // stdout is reserved for the JSON document
+    if crate::utils::output::is_json_output() {
+        let label = match level {
+            LogLevel::Info => "INFO",
+            LogLevel::Warning => "WARNING",
+            LogLevel::Error => "ERROR",
+            LogLevel::Success => "SUCCESS",
+        };
+        eprintln!("[{}] {} {}", timestamp, label, message);
+        return;
+    }
 
     match level {
         LogLevel::Info => {
             println!(
                 "{} {} {}",
                 format!("[{}]", timestamp).dimmed(),
-                "ℹ️ INFO".blue().bold(),
+                label("INFO", Role::Info).bold(),
                 message
             );
         }
@@ -25,7 +38,7 @@ pub fn log(level: LogLevel, message: &str) {
             println!(
                 "{} {} {}",
                 format!("[{}]", timestamp).dimmed(),
-                "⚠️ WARNING".yellow().bold(),
+                label("WARNING", Role::Warning).bold(),
                 message
             );
         }
@@ -33,7 +46,7 @@ pub fn log(level: LogLevel, message: &str) {
             println!(
                 "{} {} {}",
                 format!("[{}]", timestamp).dimmed(),
-                "❌ ERROR".red().bold(),
+                label("ERROR", Role::Error).bold(),
                 message
             );
         }
@@ -41,7 +54,7 @@ pub fn log(level: LogLevel, message: &str) {
             println!(
                 "{} {} {}",
                 format!("[{}]", timestamp).dimmed(),
-                "✅ SUCCESS".green().bold(),
+                label("SUCCESS", Role::Success).bold(),
                 message
             );
         }
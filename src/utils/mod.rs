@@ -1,6 +1,17 @@
+pub mod document_locale;
+pub mod error;
 pub mod file_system;
 pub mod git;
 pub mod logging;
+pub mod notify;
+pub mod output;
+pub mod process;
+pub mod progress;
+pub mod prompt;
+pub mod sandbox;
+pub mod secrets;
+pub mod terminal;
+pub mod theme;
 pub mod version_check;
 
 pub use file_system::{file_exists, read_file_to_string, write_string_to_file};
@@ -0,0 +1,119 @@
+use crate::utils::sandbox::{ensure_network_allowed, ensure_not_sandboxed};
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Extra webhook URLs, comma separated, for CI where the config database
+/// isn't available.
+pub const WEBHOOK_ENV: &str = "NITROTERM_WEBHOOKS";
+
+/// Discord rejects messages longer than this.
+const DISCORD_LIMIT: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebhookKind {
+    Slack,
+    Discord,
+    Teams,
+    Generic,
+}
+
+impl WebhookKind {
+    pub fn detect(url: &str) -> Self {
+        let url = url.to_lowercase();
+        if url.contains("hooks.slack.com") {
+            WebhookKind::Slack
+        } else if url.contains("discord.com/api/webhooks")
+            || url.contains("discordapp.com/api/webhooks")
+        {
+            WebhookKind::Discord
+        } else if url.contains("webhook.office.com") || url.contains("logic.azure.com") {
+            WebhookKind::Teams
+        } else {
+            WebhookKind::Generic
+        }
+    }
+}
+
+/// Request body in the format each service expects. `body` is markdown.
+pub fn webhook_payload(kind: WebhookKind, title: &str, body: &str) -> Value {
+    match kind {
+        WebhookKind::Slack => json!({ "text": format!("*{}*\n{}", title, body) }),
+        WebhookKind::Discord => {
+            let content = format!("**{}**\n{}", title, body);
+            let content: String = if content.chars().count() > DISCORD_LIMIT {
+                let mut truncated: String = content.chars().take(DISCORD_LIMIT - 1).collect();
+                truncated.push('…');
+                truncated
+            } else {
+                content
+            };
+            json!({ "content": content })
+        }
+        WebhookKind::Teams => json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": title,
+            "title": title,
+            // MessageCard markdown needs blank lines between paragraphs
+            "text": body.replace('\n', "\n\n"),
+        }),
+        WebhookKind::Generic => json!({ "title": title, "text": body }),
+    }
+}
+
+/// Webhook URLs contain their secret, so logs only show the host.
+pub fn webhook_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host.to_string()))
+        .unwrap_or_else(|| "invalid webhook".to_string())
+}
+
+/// Configured webhooks plus the ones in `NITROTERM_WEBHOOKS`, without duplicates.
+pub fn webhook_urls(configured: &[String]) -> Vec<String> {
+    let from_env = std::env::var(WEBHOOK_ENV).unwrap_or_default();
+    let mut urls: Vec<String> = Vec::new();
+    for url in configured
+        .iter()
+        .map(|url| url.trim())
+        .chain(from_env.split(',').map(|url| url.trim()))
+    {
+        if !url.is_empty() && !urls.iter().any(|known| known == url) {
+            urls.push(url.to_string());
+        }
+    }
+    urls
+}
+
+/// Posts the message to every webhook. All of them are tried before the
+/// failures are reported.
+pub async fn send_notification(urls: &[String], title: &str, body: &str) -> Result<()> {
+    ensure_not_sandboxed("Notifications")?;
+    ensure_network_allowed()?;
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(15))
+        .user_agent("nitroterm")
+        .build()?;
+
+    let mut failures = Vec::new();
+    for url in urls {
+        let payload = webhook_payload(WebhookKind::detect(url), title, body);
+        match client.post(url).json(&payload).send().await {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => failures.push(format!("{} ({})", webhook_host(url), response.status())),
+            Err(e) => failures.push(format!("{} ({})", webhook_host(url), e.without_url())),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "{} of {} notifications failed: {}",
+            failures.len(),
+            urls.len(),
+            failures.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Turns on `--json`: commands print one JSON document on stdout, logs go to
+/// stderr and colors, spinners and human summaries are left out.
+pub fn enable_json_output() {
+    JSON_OUTPUT.store(true, Ordering::Relaxed);
+    colored::control::set_override(false);
+}
+
+pub fn is_json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Prints a line of human output; with `--json` it goes to stderr so stdout
+/// only carries the JSON document.
+pub fn print_human(line: impl std::fmt::Display) {
+    if is_json_output() {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,500 @@
+use crate::utils::error::ExitCode;
+use crate::utils::output::is_json_output;
+use crate::utils::sandbox::ensure_command_allowed;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// External commands running longer than this are killed, unless
+/// `--command-timeout` or `NITROTERM_COMMAND_TIMEOUT` say otherwise.
+pub const DEFAULT_COMMAND_TIMEOUT_SECS: u64 = 900;
+const COMMAND_TIMEOUT_ENV: &str = "NITROTERM_COMMAND_TIMEOUT";
+/// Longest pause between checks whether a command exited.
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Seconds, `u64::MAX` until set: the environment or the default apply then.
+static COMMAND_TIMEOUT: AtomicU64 = AtomicU64::new(u64::MAX);
+/// Children that are still running, and whether each leads its own process
+/// group; killed on Ctrl+C.
+static RUNNING: Mutex<Vec<(u32, bool)>> = Mutex::new(Vec::new());
+
+/// `--command-timeout`; 0 lets commands run as long as they take.
+pub fn set_command_timeout(seconds: u64) {
+    COMMAND_TIMEOUT.store(seconds, Ordering::Relaxed);
+}
+
+pub fn command_timeout() -> Option<Duration> {
+    let seconds = match COMMAND_TIMEOUT.load(Ordering::Relaxed) {
+        u64::MAX => std::env::var(COMMAND_TIMEOUT_ENV)
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(DEFAULT_COMMAND_TIMEOUT_SECS),
+        seconds => seconds,
+    };
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+fn timed_out(program: &str, timeout: Duration) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::TimedOut,
+        format!(
+            "{} timed out after {}s (see --command-timeout)",
+            program,
+            timeout.as_secs()
+        ),
+    )
+}
+
+/// Kills `pid`, with everything in its process group when it leads one.
+/// Windows kills the whole tree either way.
+fn kill_process(pid: u32, group: bool) {
+    // `kill -- -0` would take nitroterm's own process group down
+    if pid == 0 {
+        return;
+    }
+    #[cfg(unix)]
+    let mut command = {
+        let target = if group {
+            format!("-{}", pid)
+        } else {
+            pid.to_string()
+        };
+        let mut command = Command::new("kill");
+        command.args(["-KILL", "--", &target]);
+        command
+    };
+    #[cfg(windows)]
+    let mut command = {
+        let _ = group;
+        let mut command = Command::new("taskkill");
+        command.args(["/F", "/T", "/PID", &pid.to_string()]);
+        command
+    };
+    let _ = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Kills every child that is still running, see `install_interrupt_handler`.
+pub fn terminate_running() {
+    let running: Vec<(u32, bool)> = match RUNNING.lock() {
+        Ok(mut running) => running.drain(..).collect(),
+        Err(_) => return,
+    };
+    for (pid, group) in running {
+        kill_process(pid, group);
+    }
+}
+
+/// On Ctrl+C, kills the children that are still running before exiting with
+/// 130, so no package manager or linter keeps going in the background.
+pub fn install_interrupt_handler() {
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            terminate_running();
+            eprintln!("\n{}", "⏹️  Interrupted".yellow());
+            std::process::exit(ExitCode::Interrupted.code());
+        }
+    });
+}
+
+/// Keeps a child on the list `terminate_running` kills, and kills it when
+/// dropped before it was seen to exit.
+struct ChildGuard {
+    pid: u32,
+    group: bool,
+    finished: bool,
+}
+
+impl ChildGuard {
+    fn register(pid: u32, group: bool) -> Self {
+        if let Ok(mut running) = RUNNING.lock() {
+            running.push((pid, group));
+        }
+        Self {
+            pid,
+            group,
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        if let Ok(mut running) = RUNNING.lock() {
+            running.retain(|(pid, _)| *pid != self.pid);
+        }
+        if !self.finished {
+            kill_process(self.pid, self.group);
+        }
+    }
+}
+
+/// Where a supervised command's output goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Collected for the caller, nothing is shown
+    Captured,
+    /// Collected, and shown line by line as it arrives
+    Streamed,
+}
+
+/// Reads a pipe to the end on its own thread, echoing lines when `echo`.
+fn drain(
+    pipe: Option<impl Read + Send + 'static>,
+    echo: bool,
+    is_stderr: bool,
+) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut collected = Vec::new();
+        let Some(pipe) = pipe else {
+            return collected;
+        };
+        let mut reader = BufReader::new(pipe);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line).unwrap_or(0) > 0 {
+            if echo {
+                let text = String::from_utf8_lossy(&line);
+                // stdout carries the JSON document with --json
+                if is_stderr || is_json_output() {
+                    eprint!("{}", text);
+                } else {
+                    print!("{}", text);
+                }
+            }
+            collected.append(&mut line);
+        }
+        collected
+    })
+}
+
+/// Waits for `child`, giving up once `timeout` has passed.
+fn wait_until(child: &mut Child, timeout: Option<Duration>) -> io::Result<Option<ExitStatus>> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(Some);
+    };
+    let deadline = Instant::now() + timeout;
+    let mut interval = Duration::from_millis(1);
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(None);
+        }
+        std::thread::sleep(interval.min(deadline - now));
+        interval = (interval * 2).min(MAX_POLL_INTERVAL);
+    }
+}
+
+/// Refuses `command` in sandbox mode, see `sandbox::check_command`.
+fn ensure_allowed(command: &Command) -> io::Result<()> {
+    let program = command.get_program().to_string_lossy();
+    let args: Vec<String> = command
+        .get_args()
+        .map(|arg| arg.to_string_lossy().to_string())
+        .collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    ensure_command_allowed(&program, &args)
+}
+
+fn supervise_with(
+    command: &mut Command,
+    mode: OutputMode,
+    input: Option<&str>,
+    isolate: bool,
+    timeout: Option<Duration>,
+) -> io::Result<Output> {
+    ensure_allowed(command)?;
+    let program = command.get_program().to_string_lossy().to_string();
+    command
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    if isolate {
+        std::os::unix::process::CommandExt::process_group(command, 0);
+    }
+    let mut child = command.spawn()?;
+    let mut guard = ChildGuard::register(child.id(), isolate && cfg!(unix));
+
+    let echo = mode == OutputMode::Streamed;
+    let stdout = drain(child.stdout.take(), echo, false);
+    let stderr = drain(child.stderr.take(), echo, true);
+    if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input) {
+        stdin.write_all(input.as_bytes())?;
+    }
+
+    match wait_until(&mut child, timeout)? {
+        Some(status) => {
+            guard.finish();
+            Ok(Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            })
+        }
+        None => {
+            let _ = child.kill();
+            drop(guard);
+            let _ = child.wait();
+            Err(timed_out(&program, timeout.unwrap_or_default()))
+        }
+    }
+}
+
+/// `Command::output`, killed after `command_timeout()` or on Ctrl+C.
+/// Captured commands are tools that never need the terminal (package
+/// managers, linters), so they run in their own process group and what they
+/// started is killed with them. Streamed ones stay on the terminal, e.g. for
+/// a `sudo` password prompt.
+pub fn supervise(command: &mut Command, mode: OutputMode) -> io::Result<Output> {
+    supervise_within(command, mode, command_timeout())
+}
+
+/// `supervise` with a `timeout` of its own.
+pub fn supervise_within(
+    command: &mut Command,
+    mode: OutputMode,
+    timeout: Option<Duration>,
+) -> io::Result<Output> {
+    supervise_with(command, mode, None, mode == OutputMode::Captured, timeout)
+}
+
+/// `Command::status` for commands that share the terminal, killed after
+/// `command_timeout()`. Ctrl+C reaches them through the terminal.
+pub fn supervise_attached(command: &mut Command) -> io::Result<ExitStatus> {
+    ensure_allowed(command)?;
+    let program = command.get_program().to_string_lossy().to_string();
+    let mut child = command.spawn()?;
+    let mut guard = ChildGuard::register(child.id(), false);
+    let timeout = command_timeout();
+    match wait_until(&mut child, timeout)? {
+        Some(status) => {
+            guard.finish();
+            Ok(status)
+        }
+        None => {
+            let _ = child.kill();
+            drop(guard);
+            let _ = child.wait();
+            Err(timed_out(&program, timeout.unwrap_or_default()))
+        }
+    }
+}
+
+/// `supervise` and `supervise_attached` as methods, for
+/// `Command::new(..).arg(..)` chains.
+pub trait SupervisedCommand {
+    fn supervised_output(&mut self) -> io::Result<Output>;
+    fn streamed_output(&mut self) -> io::Result<Output>;
+    fn supervised_status(&mut self) -> io::Result<ExitStatus>;
+}
+
+impl SupervisedCommand for Command {
+    fn supervised_output(&mut self) -> io::Result<Output> {
+        supervise(self, OutputMode::Captured)
+    }
+
+    fn streamed_output(&mut self) -> io::Result<Output> {
+        supervise(self, OutputMode::Streamed)
+    }
+
+    fn supervised_status(&mut self) -> io::Result<ExitStatus> {
+        supervise_attached(self)
+    }
+}
+
+/// `supervise` for tokio commands, with a `timeout` of their own.
+pub async fn supervise_async(
+    command: &mut tokio::process::Command,
+    timeout: Duration,
+) -> io::Result<Output> {
+    ensure_allowed(command.as_std())?;
+    let program = command.as_std().get_program().to_string_lossy().to_string();
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    #[cfg(unix)]
+    command.process_group(0);
+    let child = command.spawn()?;
+    // No id means the child already exited; pid 0 would be our own group
+    let mut guard = child.id().map(|pid| ChildGuard::register(pid, cfg!(unix)));
+    match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        Ok(output) => {
+            if let Some(guard) = guard.as_mut() {
+                guard.finish();
+            }
+            output
+        }
+        Err(_) => Err(timed_out(&program, timeout)),
+    }
+}
+
+fn command_output(output: Output) -> CommandOutput {
+    CommandOutput {
+        success: output.status.success(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    }
+}
+
+/// An external command that couldn't be run at all.
+#[derive(Debug, thiserror::Error)]
+pub enum ProcessError {
+    #[error("{program} is not installed or not on PATH")]
+    NotFound { program: String },
+    #[error("Failed to run {program}: {source}")]
+    Failed {
+        program: String,
+        #[source]
+        source: io::Error,
+    },
+}
+
+impl ProcessError {
+    pub fn new(program: &str, source: io::Error) -> Self {
+        let program = program.to_string();
+        if source.kind() == io::ErrorKind::NotFound {
+            ProcessError::NotFound { program }
+        } else {
+            ProcessError::Failed { program, source }
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            ProcessError::NotFound { .. } => ExitCode::MissingTool,
+            ProcessError::Failed { .. } => ExitCode::Failure,
+        }
+    }
+}
+
+/// Captured result of an external command.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandOutput {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    /// Turns a non-zero exit into an error carrying stderr.
+    pub fn check(self, description: &str) -> Result<Self> {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(anyhow!("{} failed: {}", description, self.stderr.trim()))
+        }
+    }
+}
+
+/// Seam for everything that shells out (`git`, `gh`, package managers), so
+/// flows can be exercised in tests without the real tools or credentials.
+pub trait CommandRunner: Send + Sync {
+    fn run(&self, program: &str, args: &[&str], dir: Option<&Path>) -> Result<CommandOutput>;
+
+    /// Like `run`, with `input` written to the command's stdin. For secrets
+    /// that must not show up in the argument list.
+    fn run_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: &str,
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput>;
+
+    /// Like `run`, but the command shares the terminal: its output is shown
+    /// as it happens and it can prompt. Only `success` is filled in.
+    fn run_attached(
+        &self,
+        program: &str,
+        args: &[&str],
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput>;
+}
+
+/// Runs commands on the host.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[&str], dir: Option<&Path>) -> Result<CommandOutput> {
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = dir {
+            command.current_dir(dir);
+        }
+        // git may ask for a passphrase on the terminal, so it stays attached to it
+        let output = supervise_with(
+            &mut command,
+            OutputMode::Captured,
+            None,
+            false,
+            command_timeout(),
+        )
+        .map_err(|e| ProcessError::new(program, e))?;
+        Ok(command_output(output))
+    }
+
+    fn run_with_input(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: &str,
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput> {
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = dir {
+            command.current_dir(dir);
+        }
+        let output = supervise_with(
+            &mut command,
+            OutputMode::Captured,
+            Some(input),
+            false,
+            command_timeout(),
+        )
+        .map_err(|e| ProcessError::new(program, e))?;
+        Ok(command_output(output))
+    }
+
+    fn run_attached(
+        &self,
+        program: &str,
+        args: &[&str],
+        dir: Option<&Path>,
+    ) -> Result<CommandOutput> {
+        let mut command = Command::new(program);
+        command.args(args);
+        if let Some(dir) = dir {
+            command.current_dir(dir);
+        }
+        let status = supervise_attached(&mut command).map_err(|e| ProcessError::new(program, e))?;
+
+        Ok(CommandOutput {
+            success: status.success(),
+            ..CommandOutput::default()
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use colored::*;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const BAR_WIDTH: usize = 24;
+/// Clears the current terminal line
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// Animations only make sense on a terminal; piped output and CI logs get
+/// plain lines instead of carriage-return redraws.
+pub fn is_interactive_output() -> bool {
+    std::io::stdout().is_terminal() && std::env::var("TERM").map_or(true, |term| term != "dumb")
+}
+
+/// With `--json` nothing is drawn, stdout only carries the JSON document.
+fn is_silent() -> bool {
+    crate::utils::output::is_json_output()
+}
+
+/// `label [█████░░░░░] 5/10 message`, without colors.
+pub fn render_progress(
+    label: &str,
+    position: usize,
+    total: usize,
+    width: usize,
+    message: &str,
+) -> String {
+    let filled = (position.min(total) * width)
+        .checked_div(total)
+        .unwrap_or(width);
+    let line = format!(
+        "{} [{}{}] {}/{}",
+        label,
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        position,
+        total
+    );
+    if message.is_empty() {
+        line
+    } else {
+        format!("{} {}", line, message)
+    }
+}
+
+/// Spinner for a spawned command. Ends as the usual
+/// `⏳ message... ✅ Completed in 1.23s` line, so logs look the same with or
+/// without a terminal.
+pub struct Spinner {
+    message: String,
+    started: Instant,
+    running: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    pub fn start(message: &str) -> Self {
+        let running = Arc::new(AtomicBool::new(true));
+        let handle = if is_silent() {
+            None
+        } else if is_interactive_output() {
+            let running = running.clone();
+            let message = message.to_string();
+            Some(std::thread::spawn(move || {
+                let started = Instant::now();
+                let mut frame = 0;
+                while running.load(Ordering::Relaxed) {
+                    print!(
+                        "{}{} {} {}",
+                        CLEAR_LINE,
+                        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()].cyan(),
+                        message.yellow(),
+                        format!("{}s", started.elapsed().as_secs()).dimmed()
+                    );
+                    let _ = std::io::stdout().flush();
+                    frame += 1;
+                    std::thread::sleep(Duration::from_millis(80));
+                }
+            }))
+        } else {
+            print!("{}", format!("⏳ {}", message).yellow());
+            let _ = std::io::stdout().flush();
+            None
+        };
+
+        Self {
+            message: message.to_string(),
+            started: Instant::now(),
+            running,
+            handle,
+        }
+    }
+
+    fn stop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+            print!("{}{}", CLEAR_LINE, format!("⏳ {}", self.message).yellow());
+        }
+    }
+
+    /// Prints the completion time and returns it.
+    pub fn finish(mut self) -> Duration {
+        self.stop();
+        let elapsed = self.started.elapsed();
+        if is_silent() {
+            return elapsed;
+        }
+        println!(
+            " {}",
+            format!("✅ Completed in {:.2}s", elapsed.as_secs_f64()).green()
+        );
+        elapsed
+    }
+
+    pub fn fail(mut self) {
+        self.stop();
+        if !is_silent() {
+            println!(" {}", "❌ Failed".red());
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        if self.handle.is_some() {
+            self.stop();
+            println!();
+        }
+    }
+}
+
+/// Progress bar for a known amount of work (translation batches, quality
+/// checks). Without a terminal every `tick` prints a line instead.
+pub struct ProgressBar {
+    label: String,
+    total: usize,
+    position: usize,
+    interactive: bool,
+}
+
+impl ProgressBar {
+    pub fn new(label: &str, total: usize) -> Self {
+        Self {
+            label: label.to_string(),
+            total,
+            position: 0,
+            interactive: is_interactive_output() && !is_silent(),
+        }
+    }
+
+    fn draw(&self, message: &str) {
+        print!(
+            "{}{}",
+            CLEAR_LINE,
+            render_progress(&self.label, self.position, self.total, BAR_WIDTH, message).cyan()
+        );
+        let _ = std::io::stdout().flush();
+    }
+
+    /// Shows what is being worked on now.
+    pub fn tick(&self, message: &str) {
+        if is_silent() {
+            return;
+        }
+        if self.interactive {
+            self.draw(message);
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "  ▶ [{}/{}] {}",
+                    (self.position + 1).min(self.total),
+                    self.total,
+                    message
+                )
+                .yellow()
+            );
+        }
+    }
+
+    pub fn inc(&mut self, delta: usize) {
+        self.position = (self.position + delta).min(self.total);
+        if self.interactive {
+            self.draw("");
+        }
+    }
+
+    /// Removes the bar so other output can be printed; the next `tick`
+    /// draws it again.
+    pub fn clear(&self) {
+        if self.interactive {
+            print!("{}", CLEAR_LINE);
+            let _ = std::io::stdout().flush();
+        }
+    }
+
+    /// Leaves the full bar on screen.
+    pub fn finish(&self) {
+        if self.interactive {
+            self.draw("");
+            println!();
+        }
+    }
+}
+
+/// Status line for work of unknown size, such as walking a huge commit
+/// range. Without a terminal every `update` prints a line instead.
+pub struct StatusLine {
+    interactive: bool,
+    drawn: bool,
+}
+
+impl StatusLine {
+    pub fn new() -> Self {
+        Self {
+            interactive: is_interactive_output() && !is_silent(),
+            drawn: false,
+        }
+    }
+
+    pub fn update(&mut self, message: &str) {
+        if is_silent() {
+            return;
+        }
+        if self.interactive {
+            print!("{}{}", CLEAR_LINE, message.cyan());
+            let _ = std::io::stdout().flush();
+        } else {
+            println!("{}", format!("  ▶ {}", message).yellow());
+        }
+        self.drawn = true;
+    }
+
+    /// Keeps the last status on screen and moves to the next line.
+    pub fn finish(&self) {
+        if self.interactive && self.drawn {
+            println!();
+        }
+    }
+}
+
+impl Default for StatusLine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,304 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute};
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set to `1` or `true` to run without prompts, same as `--non-interactive`.
+pub const NON_INTERACTIVE_ENV: &str = "NITROTERM_NON_INTERACTIVE";
+
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Turns off prompts: confirmations are answered with no, checklists select
+/// nothing, questions take their default and questions without one fail
+/// instead of waiting on stdin.
+pub fn set_non_interactive(enabled: bool) {
+    NON_INTERACTIVE.store(enabled, Ordering::Relaxed);
+}
+
+/// `--yes`: no prompts either, but confirmations are answered with yes and
+/// checklists take what starts checked.
+pub fn set_assume_yes(enabled: bool) {
+    ASSUME_YES.store(enabled, Ordering::Relaxed);
+}
+
+pub fn assumes_yes() -> bool {
+    ASSUME_YES.load(Ordering::Relaxed)
+}
+
+pub fn is_non_interactive() -> bool {
+    NON_INTERACTIVE.load(Ordering::Relaxed)
+        || assumes_yes()
+        || std::env::var(NON_INTERACTIVE_ENV)
+            .is_ok_and(|value| matches!(value.trim().to_lowercase().as_str(), "1" | "true"))
+}
+
+fn read_line(message: &dyn Display) -> Result<String> {
+    print!("{}", message);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Asks a y/N question. `--yes` answers yes, other non-interactive runs
+/// answer no so nothing destructive happens unattended.
+pub fn confirm(message: impl Display) -> Result<bool> {
+    if is_non_interactive() {
+        if assumes_yes() {
+            println!("{}y", message);
+        } else {
+            println!("{}n {}", message, "(pass --yes to confirm)".dimmed());
+        }
+        return Ok(assumes_yes());
+    }
+    let answer = read_line(&message)?.to_lowercase();
+    Ok(answer == "y" || answer == "yes")
+}
+
+/// Reads a line, falling back to `default` when the answer is empty.
+/// Non-interactive runs take the default without asking.
+pub fn ask(message: impl Display, default: &str) -> Result<String> {
+    if is_non_interactive() {
+        println!("{}{}", message, default);
+        return Ok(default.to_string());
+    }
+    let answer = read_line(&message)?;
+    Ok(if answer.is_empty() {
+        default.to_string()
+    } else {
+        answer
+    })
+}
+
+/// Reads a line that has no sensible default. Non-interactive runs fail
+/// right away instead of waiting for input that will never come.
+pub fn ask_required(message: impl Display, hint: &str) -> Result<String> {
+    if is_non_interactive() {
+        return Err(anyhow!("Input required in non-interactive mode: {}", hint));
+    }
+    read_line(&message)
+}
+
+/// Reads a line without echoing it, for API keys and passphrases. Fails in
+/// non-interactive runs like `ask_required`; piped input is read as is.
+pub fn ask_secret(message: impl Display, hint: &str) -> Result<String> {
+    if is_non_interactive() {
+        return Err(anyhow!("Input required in non-interactive mode: {}", hint));
+    }
+    if !io::stdin().is_terminal() {
+        return read_line(&message);
+    }
+
+    print!("{}", message);
+    io::stdout().flush()?;
+    terminal::enable_raw_mode()?;
+    let _raw_mode = RawMode;
+    let mut input = String::new();
+    let result = loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => break Ok(input.trim().to_string()),
+            KeyCode::Esc => break Err(anyhow!("Cancelled")),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                break Err(anyhow!("Cancelled"))
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+    };
+    print!("\r\n");
+    result
+}
+
+/// One row of [`checklist`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChecklistItem {
+    /// Heading the item is listed under; items of a group must be adjacent
+    pub group: String,
+    pub label: String,
+    pub checked: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecklistKey {
+    Up,
+    Down,
+    Toggle,
+    ToggleAll,
+    Confirm,
+    Cancel,
+}
+
+/// Cursor and selection of a checklist, kept apart from the terminal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChecklistState {
+    pub cursor: usize,
+    pub checked: Vec<bool>,
+}
+
+impl ChecklistState {
+    pub fn new(items: &[ChecklistItem]) -> Self {
+        Self {
+            cursor: 0,
+            checked: items.iter().map(|item| item.checked).collect(),
+        }
+    }
+
+    pub fn selected(&self) -> Vec<usize> {
+        (0..self.checked.len())
+            .filter(|index| self.checked[*index])
+            .collect()
+    }
+
+    /// Applies a key press. Returns the checked indices once confirmed, an
+    /// empty list when cancelled, None while still selecting.
+    pub fn handle(&mut self, key: ChecklistKey) -> Option<Vec<usize>> {
+        let count = self.checked.len();
+        match key {
+            ChecklistKey::Up if count > 0 => self.cursor = (self.cursor + count - 1) % count,
+            ChecklistKey::Down if count > 0 => self.cursor = (self.cursor + 1) % count,
+            ChecklistKey::Toggle if count > 0 => {
+                self.checked[self.cursor] = !self.checked[self.cursor];
+            }
+            ChecklistKey::ToggleAll => {
+                let all = self.checked.iter().all(|checked| *checked);
+                self.checked.iter_mut().for_each(|checked| *checked = !all);
+            }
+            ChecklistKey::Confirm => return Some(self.selected()),
+            ChecklistKey::Cancel => return Some(Vec::new()),
+            _ => {}
+        }
+        None
+    }
+}
+
+/// Lines of the checklist: group headings, then `❯ [x] label` rows.
+pub fn render_checklist(items: &[ChecklistItem], state: &ChecklistState) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut group = None;
+    for (index, item) in items.iter().enumerate() {
+        if group != Some(&item.group) {
+            group = Some(&item.group);
+            lines.push(item.group.bold().to_string());
+        }
+        let pointer = if index == state.cursor { "❯" } else { " " };
+        let mark = if state.checked[index] {
+            "[x]".green().to_string()
+        } else {
+            "[ ]".dimmed().to_string()
+        };
+        let label = if index == state.cursor {
+            item.label.cyan().to_string()
+        } else {
+            item.label.clone()
+        };
+        lines.push(format!("{} {} {}", pointer.cyan(), mark, label));
+    }
+    lines
+}
+
+fn checklist_key(event: Event) -> Option<ChecklistKey> {
+    let Event::Key(key) = event else {
+        return None;
+    };
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(ChecklistKey::Cancel)
+        }
+        KeyCode::Up | KeyCode::Char('k') => Some(ChecklistKey::Up),
+        KeyCode::Down | KeyCode::Char('j') => Some(ChecklistKey::Down),
+        KeyCode::Char(' ') => Some(ChecklistKey::Toggle),
+        KeyCode::Char('a') => Some(ChecklistKey::ToggleAll),
+        KeyCode::Enter => Some(ChecklistKey::Confirm),
+        KeyCode::Esc | KeyCode::Char('q') => Some(ChecklistKey::Cancel),
+        _ => None,
+    }
+}
+
+/// Leaves raw mode however the checklist ends.
+struct RawMode;
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(io::stdout(), cursor::Show);
+    }
+}
+
+/// Lets the user pick items: arrows (or j/k) move, space toggles, `a`
+/// toggles all, enter confirms, esc cancels. Returns the chosen indices.
+/// `--yes` takes the items that start checked, other non-interactive runs
+/// none.
+pub fn checklist(title: impl Display, items: &[ChecklistItem]) -> Result<Vec<usize>> {
+    let mut state = ChecklistState::new(items);
+    if is_non_interactive() {
+        println!("{}", title);
+        for line in render_checklist(items, &state) {
+            println!("{}", line);
+        }
+        if !assumes_yes() {
+            println!(
+                "{}",
+                "Nothing selected, pass --yes to take the checked items".dimmed()
+            );
+            return Ok(Vec::new());
+        }
+        return Ok(state.selected());
+    }
+    if !io::stdin().is_terminal() {
+        return Err(anyhow!(
+            "Selecting needs a terminal, pass --yes to take the preselected items"
+        ));
+    }
+
+    println!("{}", title);
+    println!(
+        "{}",
+        "↑/↓ move · space toggle · a all · enter confirm · esc cancel".dimmed()
+    );
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    let _raw_mode = RawMode;
+    execute!(stdout, cursor::Hide)?;
+
+    let mut drawn = 0;
+    loop {
+        if drawn > 0 {
+            execute!(
+                stdout,
+                cursor::MoveUp(drawn as u16),
+                terminal::Clear(ClearType::FromCursorDown)
+            )?;
+        }
+        let lines = render_checklist(items, &state);
+        // Raw mode doesn't return the carriage on a newline
+        for line in &lines {
+            write!(stdout, "{}\r\n", line)?;
+        }
+        stdout.flush()?;
+        drawn = lines.len();
+
+        if let Some(key) = checklist_key(event::read()?) {
+            if let Some(selected) = state.handle(key) {
+                return Ok(selected);
+            }
+        }
+    }
+}
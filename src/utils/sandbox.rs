@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SANDBOX: AtomicBool = AtomicBool::new(false);
+
+/// Turns on read-only mode: no external commands, no file writes, no network.
+/// Analysis is limited to git2 and the native manifest parsers.
+pub fn enable_sandbox() {
+    SANDBOX.store(true, Ordering::Relaxed);
+}
+
+pub fn is_sandboxed() -> bool {
+    SANDBOX.load(Ordering::Relaxed)
+}
+
+/// Fails when sandbox mode is on. Call this before anything that runs a
+/// process, writes to disk or talks to the network.
+pub fn ensure_not_sandboxed(action: &str) -> Result<()> {
+    check_sandbox(is_sandboxed(), action)
+}
+
+pub fn check_sandbox(sandboxed: bool, action: &str) -> Result<()> {
+    if sandboxed {
+        return Err(anyhow!(
+            "{} is not available in --sandbox mode (it runs external commands or writes files)",
+            action
+        ));
+    }
+    Ok(())
+}
+
+/// Refuses every external command in sandbox mode; repository queries go
+/// through git2 instead. The process helpers call this for every command
+/// they start.
+pub fn ensure_command_allowed(program: &str, args: &[&str]) -> io::Result<()> {
+    check_command(is_sandboxed(), program, args)
+}
+
+pub fn check_command(sandboxed: bool, program: &str, args: &[&str]) -> io::Result<()> {
+    if sandboxed {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "Running `{} {}` is not available in --sandbox mode",
+                program,
+                args.join(" ")
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Refuses network access in sandbox mode. Every HTTP client constructor
+/// calls this before building its client.
+pub fn ensure_network_allowed() -> Result<()> {
+    check_network(is_sandboxed())
+}
+
+pub fn check_network(sandboxed: bool) -> Result<()> {
+    if sandboxed {
+        return Err(anyhow!("Network access is not available in --sandbox mode"));
+    }
+    Ok(())
+}
+
+/// Refuses file writes in sandbox mode. The shared write helpers call this.
+pub fn ensure_write_allowed(path: &Path) -> io::Result<()> {
+    if is_sandboxed() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "Writing {} is not available in --sandbox mode",
+                path.display()
+            ),
+        ));
+    }
+    Ok(())
+}
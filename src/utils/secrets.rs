@@ -0,0 +1,249 @@
+use crate::commands::config::active_profile;
+use crate::utils::prompt;
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::num::NonZeroU32;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Passphrase for encrypted secrets, so scripts and CI don't get asked.
+pub const PASSPHRASE_ENV: &str = "NITROTERM_PASSPHRASE";
+
+/// Stored in the config instead of a secret that lives in the OS keychain.
+pub const KEYCHAIN_MARKER: &str = "keychain:";
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+const KEYCHAIN_SERVICE: &str = "nitroterm";
+
+const SALT_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+const PBKDF2_ITERATIONS: u32 = 100_000;
+
+/// Where an API key is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SecretStorage {
+    /// In the config database as written
+    #[default]
+    Plain,
+    /// macOS Keychain, the Secret Service on Linux or the Windows
+    /// Credential Manager
+    Keychain,
+    /// In the config database, AES-256-GCM with a key derived from a passphrase
+    Encrypted,
+}
+
+impl SecretStorage {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SecretStorage::Plain => "plain",
+            SecretStorage::Keychain => "keychain",
+            SecretStorage::Encrypted => "encrypted",
+        }
+    }
+
+    /// How a value read from the config is kept.
+    pub fn of(stored: &str) -> Self {
+        if stored.starts_with(KEYCHAIN_MARKER) {
+            SecretStorage::Keychain
+        } else if stored.starts_with(ENCRYPTED_PREFIX) {
+            SecretStorage::Encrypted
+        } else {
+            SecretStorage::Plain
+        }
+    }
+}
+
+impl FromStr for SecretStorage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "plain" => Ok(SecretStorage::Plain),
+            "keychain" => Ok(SecretStorage::Keychain),
+            "encrypted" => Ok(SecretStorage::Encrypted),
+            other => Err(anyhow!(
+                "Unknown secret storage: {}. Use plain, keychain or encrypted",
+                other
+            )),
+        }
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<LessSafeKey> {
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&AES_256_GCM, &key).map_err(|_| anyhow!("Invalid key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// `enc:v1:` and base64 of salt, nonce and ciphertext. Every call uses a
+/// fresh salt and nonce.
+pub fn encrypt(secret: &str, passphrase: &str) -> Result<String> {
+    let random = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    random
+        .fill(&mut salt)
+        .and_then(|_| random.fill(&mut nonce))
+        .map_err(|_| anyhow!("No random numbers available"))?;
+
+    let mut data = secret.as_bytes().to_vec();
+    derive_key(passphrase, &salt)?
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Encryption failed"))?;
+
+    let mut sealed = salt.to_vec();
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&data);
+    Ok(format!(
+        "{}{}",
+        ENCRYPTED_PREFIX,
+        base64::engine::general_purpose::STANDARD.encode(sealed)
+    ))
+}
+
+pub fn decrypt(stored: &str, passphrase: &str) -> Result<String> {
+    let encoded = stored
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or_else(|| anyhow!("Not an encrypted secret"))?;
+    let sealed = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|_| anyhow!("Encrypted secret is damaged"))?;
+    if sealed.len() < SALT_LEN + NONCE_LEN + TAG_LEN {
+        return Err(anyhow!("Encrypted secret is damaged"));
+    }
+
+    let (salt, rest) = sealed.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| anyhow!("Encrypted secret is damaged"))?;
+    let mut data = ciphertext.to_vec();
+    let plain = derive_key(passphrase, salt)?
+        .open_in_place(nonce, Aad::empty(), &mut data)
+        .map_err(|_| anyhow!("Wrong passphrase or damaged secret"))?;
+    Ok(String::from_utf8(plain.to_vec())?)
+}
+
+/// The OS keychain through the `keyring` crate: the macOS Keychain, the
+/// Secret Service on Linux and the Windows Credential Manager. Secrets go
+/// through the platform API, never through a process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Keychain;
+
+impl Keychain {
+    fn entry(&self, name: &str) -> Result<keyring::Entry> {
+        keyring::Entry::new(KEYCHAIN_SERVICE, name)
+            .map_err(|e| anyhow!("OS keychain is not available: {}", e))
+    }
+
+    pub fn store(&self, name: &str, secret: &str) -> Result<()> {
+        self.entry(name)?
+            .set_password(secret)
+            .map_err(|e| anyhow!("Could not store {} in the keychain: {}", name, e))
+    }
+
+    pub fn load(&self, name: &str) -> Result<String> {
+        match self.entry(name)?.get_password() {
+            Ok(secret) if !secret.is_empty() => Ok(secret),
+            Ok(_) | Err(keyring::Error::NoEntry) => {
+                Err(anyhow!("{} not found in the keychain", name))
+            }
+            Err(e) => Err(anyhow!("Could not read {} from the keychain: {}", name, e)),
+        }
+    }
+
+    /// Deleting an entry that isn't there is fine.
+    pub fn delete(&self, name: &str) -> Result<()> {
+        match self.entry(name)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(anyhow!(
+                "Could not delete {} from the keychain: {}",
+                name,
+                e
+            )),
+        }
+    }
+}
+
+/// Asked once per run.
+static PASSPHRASE: Mutex<Option<String>> = Mutex::new(None);
+
+/// The passphrase from `NITROTERM_PASSPHRASE` or the terminal. A new one
+/// (`confirm`) has to be typed twice.
+pub fn passphrase(confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        if !passphrase.is_empty() {
+            return Ok(passphrase);
+        }
+    }
+    if let Some(passphrase) = PASSPHRASE.lock().unwrap().clone() {
+        return Ok(passphrase);
+    }
+
+    let hint = format!("set {}", PASSPHRASE_ENV);
+    let passphrase = prompt::ask_secret("🔐 Passphrase for stored API keys: ", &hint)?;
+    if passphrase.is_empty() {
+        return Err(anyhow!("A passphrase is required"));
+    }
+    if confirm && prompt::ask_secret("🔐 Repeat the passphrase: ", &hint)? != passphrase {
+        return Err(anyhow!("Passphrases don't match"));
+    }
+    *PASSPHRASE.lock().unwrap() = Some(passphrase.clone());
+    Ok(passphrase)
+}
+
+/// The value to write to the config for the secret `name` (e.g.
+/// `gemini_api_key`), storing it in the keychain first if asked to. With a
+/// profile active the keychain entry is the profile's own
+/// (`work/gemini_api_key`), and the value says so.
+pub fn protect(name: &str, secret: &str, storage: SecretStorage) -> Result<String> {
+    match storage {
+        SecretStorage::Plain => Ok(secret.to_string()),
+        SecretStorage::Keychain => match active_profile() {
+            Some(profile) => {
+                let entry = format!("{}/{}", profile, name);
+                Keychain.store(&entry, secret)?;
+                Ok(format!("{}{}", KEYCHAIN_MARKER, entry))
+            }
+            None => {
+                Keychain.store(name, secret)?;
+                Ok(KEYCHAIN_MARKER.to_string())
+            }
+        },
+        SecretStorage::Encrypted => encrypt(secret, &passphrase(true)?),
+    }
+}
+
+/// The keychain entry behind a stored value: the one it names, or `name`.
+pub fn keychain_entry<'a>(name: &'a str, stored: &'a str) -> &'a str {
+    stored
+        .strip_prefix(KEYCHAIN_MARKER)
+        .filter(|entry| !entry.is_empty())
+        .unwrap_or(name)
+}
+
+/// The secret behind a value from the config, asking for the passphrase
+/// or the keychain when needed.
+pub fn reveal(name: &str, stored: &str) -> Result<String> {
+    match SecretStorage::of(stored) {
+        SecretStorage::Plain => Ok(stored.to_string()),
+        SecretStorage::Keychain => Keychain.load(keychain_entry(name, stored)),
+        SecretStorage::Encrypted => decrypt(stored, &passphrase(false)?),
+    }
+}
+
+/// Removes what `protect` put outside the config.
+pub fn forget(name: &str, stored: &str) -> Result<()> {
+    match SecretStorage::of(stored) {
+        SecretStorage::Keychain => Keychain.delete(keychain_entry(name, stored)),
+        _ => Ok(()),
+    }
+}
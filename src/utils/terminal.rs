@@ -0,0 +1,164 @@
+use colored::Color;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How many colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi16,
+    None,
+}
+
+impl ColorSupport {
+    const ALL: [ColorSupport; 3] = [
+        ColorSupport::TrueColor,
+        ColorSupport::Ansi16,
+        ColorSupport::None,
+    ];
+}
+
+/// Index into `ColorSupport::ALL`; truecolor until `init` looked at the terminal.
+static COLOR_SUPPORT: AtomicU8 = AtomicU8::new(0);
+
+/// Terminals that render 24-bit colors without advertising `COLORTERM`.
+const TRUECOLOR_PROGRAMS: [&str; 4] = ["iTerm.app", "vscode", "WezTerm", "ghostty"];
+
+/// Decides the color level from the environment. `ansi_enabled` is false on
+/// Windows consoles that refused Virtual Terminal Processing.
+pub fn detect_color_support(
+    env: impl Fn(&str) -> Option<String>,
+    is_terminal: bool,
+    ansi_enabled: bool,
+) -> ColorSupport {
+    let set = |name: &str| env(name).is_some_and(|value| !value.is_empty());
+    let forced = env("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0");
+
+    if set("NO_COLOR") || !ansi_enabled {
+        return ColorSupport::None;
+    }
+    if !forced && (!is_terminal || env("TERM").is_some_and(|term| term == "dumb")) {
+        return ColorSupport::None;
+    }
+
+    let colorterm = env("COLORTERM").unwrap_or_default().to_lowercase();
+    let program = env("TERM_PROGRAM").unwrap_or_default();
+    if colorterm == "truecolor"
+        || colorterm == "24bit"
+        || set("WT_SESSION")
+        || TRUECOLOR_PROGRAMS.contains(&program.as_str())
+    {
+        ColorSupport::TrueColor
+    } else {
+        ColorSupport::Ansi16
+    }
+}
+
+/// `rgb` when the terminal shows 24-bit colors, the closest basic color otherwise.
+pub fn fit_color(rgb: (u8, u8, u8), fallback: Color, support: ColorSupport) -> Color {
+    match support {
+        ColorSupport::TrueColor => Color::TrueColor {
+            r: rgb.0,
+            g: rgb.1,
+            b: rgb.2,
+        },
+        ColorSupport::Ansi16 | ColorSupport::None => fallback,
+    }
+}
+
+pub fn color_support() -> ColorSupport {
+    ColorSupport::ALL
+        .get(COLOR_SUPPORT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(ColorSupport::TrueColor)
+}
+
+/// Turns on escape sequence handling on Windows and picks the color level.
+/// Without any, colored output is switched off so no raw escapes leak out.
+pub fn init() {
+    let ansi_enabled = enable_ansi();
+    let support = detect_color_support(
+        |name| std::env::var(name).ok(),
+        std::io::stdout().is_terminal(),
+        ansi_enabled,
+    );
+    let index = ColorSupport::ALL
+        .iter()
+        .position(|s| *s == support)
+        .unwrap_or(0);
+    COLOR_SUPPORT.store(index as u8, Ordering::Relaxed);
+
+    if support == ColorSupport::None {
+        colored::control::set_override(false);
+    }
+}
+
+/// Sets the terminal window title.
+pub fn set_title(title: &str) {
+    #[cfg(windows)]
+    windows::set_console_title(title);
+
+    #[cfg(not(windows))]
+    if color_support() != ColorSupport::None && std::io::stdout().is_terminal() {
+        // OSC 0 sets both the window and the tab title
+        print!("\x1b]0;{}\x07", title);
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }
+}
+
+#[cfg(windows)]
+fn enable_ansi() -> bool {
+    windows::enable_virtual_terminal_processing()
+}
+
+#[cfg(not(windows))]
+fn enable_ansi() -> bool {
+    true
+}
+
+#[cfg(windows)]
+mod windows {
+    use std::ffi::c_void;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+    const INVALID_HANDLE_VALUE: *mut c_void = -1isize as *mut c_void;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(std_handle: u32) -> *mut c_void;
+        fn GetConsoleMode(handle: *mut c_void, mode: *mut u32) -> i32;
+        fn SetConsoleMode(handle: *mut c_void, mode: u32) -> i32;
+        fn SetConsoleTitleW(title: *const u16) -> i32;
+    }
+
+    /// False on legacy consoles (before Windows 10) and when stdout is not a console.
+    pub fn enable_virtual_terminal_processing() -> bool {
+        // SAFETY: the handle comes from GetStdHandle and is checked before
+        // use, `mode` is a valid pointer for the duration of the call
+        unsafe {
+            let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+            if handle.is_null() || handle == INVALID_HANDLE_VALUE {
+                return false;
+            }
+            let mut mode = 0u32;
+            if GetConsoleMode(handle, &mut mode) == 0 {
+                return false;
+            }
+            mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+                || SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+        }
+    }
+
+    pub fn set_console_title(title: &str) {
+        let wide: Vec<u16> = title
+            .encode_utf16()
+            .filter(|unit| *unit != 0)
+            .chain(std::iter::once(0))
+            .collect();
+        // SAFETY: `wide` is NUL-terminated and outlives the call
+        unsafe {
+            SetConsoleTitleW(wide.as_ptr());
+        }
+    }
+}
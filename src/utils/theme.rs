@@ -0,0 +1,160 @@
+use crate::utils::terminal::{color_support, fit_color};
+use colored::*;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Theme {
+    Default,
+    Colorblind,
+    Monochrome,
+}
+
+/// Semantic roles for output. Each role also has a symbol so the state is
+/// readable without color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    Success,
+    Warning,
+    Error,
+    Info,
+    Accent,
+}
+
+static CURRENT_THEME: AtomicU8 = AtomicU8::new(0);
+
+impl Theme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Colorblind => "colorblind",
+            Theme::Monochrome => "monochrome",
+        }
+    }
+
+    pub fn all() -> [Theme; 3] {
+        [Theme::Default, Theme::Colorblind, Theme::Monochrome]
+    }
+
+    pub fn paint(&self, text: &str, role: Role) -> ColoredString {
+        match self {
+            Theme::Default => match role {
+                Role::Success => text.green(),
+                Role::Warning => text.yellow(),
+                Role::Error => text.red(),
+                Role::Info => text.blue(),
+                Role::Accent => text.cyan(),
+            },
+            // Okabe-Ito palette, distinguishable with the common color vision deficiencies
+            Theme::Colorblind => match role {
+                Role::Success => rgb(text, (0, 114, 178), Color::Blue),
+                Role::Warning => rgb(text, (230, 159, 0), Color::Yellow),
+                Role::Error => rgb(text, (213, 94, 0), Color::Magenta),
+                Role::Info => rgb(text, (86, 180, 233), Color::BrightBlue),
+                Role::Accent => rgb(text, (0, 158, 115), Color::Cyan),
+            },
+            Theme::Monochrome => match role {
+                Role::Error | Role::Warning => text.bold(),
+                _ => text.normal(),
+            },
+        }
+    }
+
+    /// Color for a position (0.0..=1.0) in the banner gradient.
+    pub fn gradient(&self, text: &str, progress: f32) -> ColoredString {
+        let ((from, to), (first, second)) = match self {
+            Theme::Default => (
+                ((65.0, 105.0, 225.0), (0.0, 255.0, 127.0)),
+                (Color::Blue, Color::Green),
+            ),
+            Theme::Colorblind => (
+                ((0.0, 114.0, 178.0), (86.0, 180.0, 233.0)),
+                (Color::Blue, Color::BrightBlue),
+            ),
+            Theme::Monochrome => return text.bold(),
+        };
+
+        // Without truecolor the gradient becomes two halves
+        let fallback = if progress < 0.5 { first } else { second };
+        let mix = |a: f32, b: f32| (a + progress * (b - a)) as u8;
+        rgb(
+            text,
+            (mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2)),
+            fallback,
+        )
+        .bold()
+    }
+}
+
+fn rgb(text: &str, color: (u8, u8, u8), fallback: Color) -> ColoredString {
+    text.color(fit_color(color, fallback, color_support()))
+}
+
+impl std::str::FromStr for Theme {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(Theme::Default),
+            "colorblind" | "colorblind-safe" => Ok(Theme::Colorblind),
+            "monochrome" | "mono" | "none" => Ok(Theme::Monochrome),
+            other => Err(anyhow::anyhow!(
+                "Unknown theme: {}. Use 'default', 'colorblind' or 'monochrome'",
+                other
+            )),
+        }
+    }
+}
+
+impl Role {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Role::Success => "✔",
+            Role::Warning => "▲",
+            Role::Error => "✖",
+            Role::Info => "ℹ",
+            Role::Accent => "•",
+        }
+    }
+}
+
+pub fn set_theme(theme: Theme) {
+    let index = Theme::all().iter().position(|t| *t == theme).unwrap_or(0);
+    CURRENT_THEME.store(index as u8, Ordering::Relaxed);
+
+    // Monochrome also strips the colors that are not routed through the theme
+    if theme == Theme::Monochrome {
+        colored::control::set_override(false);
+    }
+}
+
+pub fn current_theme() -> Theme {
+    Theme::all()
+        .get(CURRENT_THEME.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(Theme::Default)
+}
+
+pub fn paint(text: &str, role: Role) -> ColoredString {
+    current_theme().paint(text, role)
+}
+
+/// Text with the role symbol in front, e.g. "✖ FAIL".
+pub fn label(text: &str, role: Role) -> ColoredString {
+    paint(&format!("{} {}", role.symbol(), text), role)
+}
+
+/// Picks the theme from `NITROTERM_THEME`, falling back to the saved config
+/// when there is one.
+pub async fn load_theme() {
+    let configured = match std::env::var("NITROTERM_THEME") {
+        Ok(value) => Some(value),
+        Err(_) => match crate::commands::config::ConfigManager::open_existing().await {
+            Ok(Some(manager)) => manager.get_config().await.ok().map(|c| c.theme),
+            _ => None,
+        },
+    };
+
+    if let Some(theme) = configured.and_then(|name| name.parse::<Theme>().ok()) {
+        set_theme(theme);
+    }
+}
@@ -1,5 +1,7 @@
+use crate::commands::config::{AppConfig, ConfigManager};
 use colored::*;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -11,31 +13,122 @@ pub struct GitHubRelease {
     pub prerelease: bool,
 }
 
+/// Which releases count as updates: `stable` skips pre-releases.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Prerelease,
+}
+
+impl std::str::FromStr for UpdateChannel {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value.to_lowercase().as_str() {
+            "stable" => Ok(UpdateChannel::Stable),
+            "prerelease" | "pre-release" | "beta" => Ok(UpdateChannel::Prerelease),
+            _ => Err(anyhow::anyhow!(
+                "Unknown update channel '{}', use stable or prerelease",
+                value
+            )),
+        }
+    }
+}
+
+impl UpdateChannel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => "stable",
+            UpdateChannel::Prerelease => "prerelease",
+        }
+    }
+}
+
+/// Update check preferences from the config (`config updates`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateCheckSettings {
+    pub enabled: bool,
+    pub interval_hours: u64,
+    pub channel: UpdateChannel,
+}
+
+impl Default for UpdateCheckSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: CHECK_INTERVAL_HOURS,
+            channel: UpdateChannel::Stable,
+        }
+    }
+}
+
+impl From<&AppConfig> for UpdateCheckSettings {
+    fn from(config: &AppConfig) -> Self {
+        Self {
+            enabled: config.update_check,
+            interval_hours: config.update_check_interval_hours,
+            channel: config.update_channel.parse().unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct VersionCache {
     pub last_check: u64,
     pub latest_version: String,
     pub check_interval_hours: u64,
+    /// Channel `latest_version` was looked up on; a switch invalidates the cache
+    #[serde(default)]
+    pub channel: UpdateChannel,
 }
 
 pub const GITHUB_API_URL: &str =
     "https://api.github.com/repos/mustafagenc/nitroterm/releases/latest";
+pub const GITHUB_RELEASES_URL: &str =
+    "https://api.github.com/repos/mustafagenc/nitroterm/releases?per_page=30";
 pub const CACHE_FILE: &str = ".nitroterm_version_cache.json";
 pub const CHECK_INTERVAL_HOURS: u64 = 24; // Check once per day
 
+/// Loads the update check settings, defaults when the config can't be read.
+pub async fn load_update_settings() -> UpdateCheckSettings {
+    match ConfigManager::new_quiet().await {
+        Ok(manager) => manager
+            .get_config()
+            .await
+            .map(|config| UpdateCheckSettings::from(&config))
+            .unwrap_or_default(),
+        Err(_) => UpdateCheckSettings::default(),
+    }
+}
+
 pub async fn check_for_updates(
     current_version: &str,
     force_check: bool,
+    settings: &UpdateCheckSettings,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Check if we should perform update check
-    if !force_check && !should_check_for_updates() {
+    // Sandbox mode never talks to the network or writes the cache
+    if crate::utils::sandbox::is_sandboxed() || (!force_check && !settings.enabled) {
         return Ok(());
     }
 
-    match fetch_latest_version().await {
+    // Within the interval the cached result is shown, without a request
+    if !force_check && !should_check_for_updates(settings) {
+        if let Some(cache) = load_version_cache() {
+            if compare_versions(current_version, &cache.latest_version)
+                .is_ok_and(|ordering| ordering == std::cmp::Ordering::Less)
+            {
+                show_cached_update(&cache.latest_version, current_version);
+            }
+        }
+        return Ok(());
+    }
+
+    match fetch_latest_version(settings.channel).await {
         Ok(latest_release) => {
             // Save to cache
-            save_version_cache(&latest_release.tag_name);
+            save_version_cache(&latest_release.tag_name, settings);
 
             // Compare versions
             if let Ok(comparison) = compare_versions(current_version, &latest_release.tag_name) {
@@ -70,57 +163,99 @@ pub async fn check_for_updates(
     Ok(())
 }
 
-pub async fn fetch_latest_version() -> Result<GitHubRelease, Box<dyn std::error::Error>> {
+pub async fn fetch_latest_version(
+    channel: UpdateChannel,
+) -> Result<GitHubRelease, Box<dyn std::error::Error>> {
+    crate::utils::sandbox::ensure_network_allowed()?;
     let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(10))
         .user_agent("nitroterm")
         .build()?;
 
-    let response = client.get(GITHUB_API_URL).send().await?;
+    // `releases/latest` never returns a pre-release, the list has to be scanned
+    let url = match channel {
+        UpdateChannel::Stable => GITHUB_API_URL,
+        UpdateChannel::Prerelease => GITHUB_RELEASES_URL,
+    };
+    let response = client.get(url).send().await?;
 
     if !response.status().is_success() {
         return Err(format!("GitHub API returned status: {}", response.status()).into());
     }
 
-    let release: GitHubRelease = response.json().await?;
-    Ok(release)
+    match channel {
+        UpdateChannel::Stable => Ok(response.json::<GitHubRelease>().await?),
+        UpdateChannel::Prerelease => {
+            let releases: Vec<GitHubRelease> = response.json().await?;
+            latest_for_channel(releases, channel).ok_or_else(|| "No releases found".into())
+        }
+    }
+}
+
+/// Highest semver release the channel accepts; unparsable tags are skipped.
+pub fn latest_for_channel(
+    releases: Vec<GitHubRelease>,
+    channel: UpdateChannel,
+) -> Option<GitHubRelease> {
+    releases
+        .into_iter()
+        .filter(|release| channel == UpdateChannel::Prerelease || !release.prerelease)
+        .filter_map(|release| {
+            semver::Version::parse(&clean_version_string(&release.tag_name))
+                .ok()
+                .map(|version| (version, release))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+/// Whether the cache is too old, or from another channel, to be trusted.
+pub fn cache_expired(cache: &VersionCache, now: u64, settings: &UpdateCheckSettings) -> bool {
+    let hours_since_check = now.saturating_sub(cache.last_check) / 3600;
+    cache.channel != settings.channel || hours_since_check >= settings.interval_hours
 }
 
-pub fn should_check_for_updates() -> bool {
+pub fn should_check_for_updates(settings: &UpdateCheckSettings) -> bool {
     match load_version_cache() {
         Some(cache) => {
             let now = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs();
-
-            let hours_since_check = (now - cache.last_check) / 3600;
-            hours_since_check >= cache.check_interval_hours
+            cache_expired(&cache, now, settings)
         }
         None => true, // No cache, should check
     }
 }
 
+/// The cache lives next to the config database, not in the working directory.
+pub fn cache_path() -> PathBuf {
+    ConfigManager::get_config_dir()
+        .map(|dir| dir.join(CACHE_FILE))
+        .unwrap_or_else(|_| PathBuf::from(CACHE_FILE))
+}
+
 pub fn load_version_cache() -> Option<VersionCache> {
-    if let Ok(content) = std::fs::read_to_string(CACHE_FILE) {
+    if let Ok(content) = std::fs::read_to_string(cache_path()) {
         serde_json::from_str(&content).ok()
     } else {
         None
     }
 }
 
-pub fn save_version_cache(latest_version: &str) {
+pub fn save_version_cache(latest_version: &str, settings: &UpdateCheckSettings) {
     let cache = VersionCache {
         last_check: SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs(),
         latest_version: latest_version.to_string(),
-        check_interval_hours: CHECK_INTERVAL_HOURS,
+        check_interval_hours: settings.interval_hours,
+        channel: settings.channel,
     };
 
     if let Ok(json) = serde_json::to_string_pretty(&cache) {
-        let _ = std::fs::write(CACHE_FILE, json);
+        let _ = std::fs::write(cache_path(), json);
     }
 }
 
@@ -141,6 +276,16 @@ pub fn clean_version_string(version: &str) -> String {
     version.trim_start_matches('v').to_string()
 }
 
+fn show_cached_update(latest_version: &str, current_version: &str) {
+    println!(
+        "{} {} → {} {}",
+        "🎉 New version available:".green(),
+        current_version.yellow(),
+        latest_version.green().bold(),
+        "(run 'nitroterm self-update')".dimmed()
+    );
+}
+
 pub fn show_update_available(release: &GitHubRelease, current_version: &str) {
     println!();
     println!("{}", "🎉 NEW VERSION AVAILABLE!".green().bold());
@@ -168,7 +313,7 @@ pub fn show_update_available(release: &GitHubRelease, current_version: &str) {
     println!();
     println!(
         "{}",
-        "💡 Tip: Run 'nitroterm --check-updates' to check again".dimmed()
+        "💡 Tip: Run 'nitroterm self-update' to install it".dimmed()
     );
     println!("{}", "═".repeat(50).dimmed());
     println!();